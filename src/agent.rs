@@ -0,0 +1,241 @@
+//! Moving around mazes, for games built on them.
+//!
+//! An [`Agent`] is something that walks a maze one step at a time, such as the player. It keeps
+//! track of where it is, everywhere it has been, and whether it has reached its goal, and only
+//! lets itself move through passages.
+//!
+//! The agent doesn't hold onto the maze. Each move is checked against the maze passed in, so the
+//! maze can keep changing while the game is running (e.g. doors opening and closing).
+//!
+//! # Examples
+//!
+//! ```
+//! use mazelib::agent::{Agent, MoveResult};
+//! use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+//! use mazelib::implm::presets::Inline2DMazeBuilder;
+//! use mazelib::interface::cell::ConnectionType;
+//! use mazelib::interface::coordinate::MazeCoordinator;
+//!
+//! let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+//! maze.make_passage_between([0, 0].into(), [1, 0].into());
+//! maze.make_passage_between([1, 0].into(), [2, 0].into());
+//!
+//! let mut player = Agent::new(CoordinateTuplet([0, 0])).with_goal(CoordinateTuplet([2, 0]));
+//!
+//! assert_eq!(MoveResult::OffMaze, player.try_move(&maze, BoxDirection::WEST));
+//! assert_eq!(MoveResult::Blocked(ConnectionType::UNVISITED), player.try_move(&maze, BoxDirection::SOUTH));
+//! assert_eq!(MoveResult::Moved(CoordinateTuplet([1, 0])), player.try_move(&maze, BoxDirection::EAST));
+//! assert_eq!(MoveResult::ReachedGoal(CoordinateTuplet([2, 0])), player.try_move(&maze, BoxDirection::EAST));
+//!
+//! assert!(player.is_at_goal());
+//! assert_eq!(3, player.history().len());
+//! ```
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+use crate::implm::point::cube::{CubeCoordinateSpace, CubeEdge};
+use crate::implm::point::delta::{DeltaCoordinateSpace, DeltaEdge};
+use crate::implm::point::hex::{HexCoordinateSpace, HexDirection};
+use crate::implm::point::polar::{PolarCoordinateSpace, PolarEdge};
+use crate::implm::point::upsilon::{UpsilonCoordinateSpace, UpsilonEdge};
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::{CoordinateSpace, Point};
+
+/// Something that walks a maze one step at a time, such as the player.
+///
+/// *See the [module-level docs][self].*
+///
+/// The agent starts out having visited only its starting point. Its [history][Self::history]
+/// grows by one point for every successful move, so it always ends with the agent's current
+/// position.
+#[derive(Clone, Debug)]
+pub struct Agent<Pt: Point> {
+    /// Every point the agent has stood on, in order. Never empty.
+    history: Vec<Pt>,
+    /// Every distinct point in `history`.
+    visited: HashSet<Pt>,
+    /// The point the agent is trying to reach, if any.
+    goal: Option<Pt>,
+}
+
+impl <Pt: Point> Agent<Pt> {
+    /// Construct a new agent standing at `start`.
+    #[must_use]
+    pub fn new(start: Pt) -> Self {
+        Self { history: vec![start], visited: HashSet::from([start]), goal: None }
+    }
+
+    /// Set the point the agent is trying to reach.
+    #[must_use]
+    pub fn with_goal(mut self, goal: Pt) -> Self {
+        self.goal = Some(goal);
+
+        return self
+    }
+
+    /// Return the point the agent is standing on.
+    #[must_use]
+    pub fn position(&self) -> Pt {
+        *self.history.last().expect("[Bug] An agent's history is never empty")
+    }
+
+    /// Return the point the agent is trying to reach, if any.
+    #[must_use]
+    pub fn goal(&self) -> Option<Pt> {
+        self.goal
+    }
+
+    /// Return true if the agent is standing on its goal.
+    ///
+    /// Always false if the agent has no goal.
+    #[must_use]
+    pub fn is_at_goal(&self) -> bool {
+        self.goal == Some(self.position())
+    }
+
+    /// Return every point the agent has stood on, in order, from its starting point to its
+    /// current position.
+    #[must_use]
+    pub fn history(&self) -> &[Pt] {
+        &self.history
+    }
+
+    /// Return true if the agent has ever stood on `pt`.
+    #[must_use]
+    pub fn has_visited(&self, pt: Pt) -> bool {
+        self.visited.contains(&pt)
+    }
+
+    /// Return the number of distinct points the agent has stood on.
+    #[must_use]
+    pub fn visited_count(&self) -> usize {
+        self.visited.len()
+    }
+
+    /// Try to take a step in `direction`.
+    ///
+    /// The agent only moves if [`get_connection()`][MazeCoordinator::get_connection] reports a
+    /// passage between its position and the neighbouring point.
+    pub fn try_move<M: MazeCoordinator>(&mut self, maze: &M, direction: <M::CoordSpace as Directional>::Direction) -> MoveResult<Pt>
+    where M::CoordSpace: CoordinateSpace<PtType = Pt> + Directional {
+        let from = self.position();
+
+        return match maze.coord_space().step(from, direction) {
+            Some(to) => self.move_if_connected(maze.get_connection(from, to), to),
+            None     => MoveResult::OffMaze,
+        }
+    }
+
+    /// Try to take a step to `to`.
+    ///
+    /// This works for every coordinate space, including those without [directions][Directional].
+    /// Unlike [`try_move()`][Self::try_move], [portals][MazeCoordinator::portals] may be taken,
+    /// so `to` need only be one of the maze's [neighbours][MazeCoordinator::neighbours_of_pt] of
+    /// the agent's position.
+    pub fn try_move_to<M: MazeCoordinator>(&mut self, maze: &M, to: Pt) -> MoveResult<Pt>
+    where M::CoordSpace: CoordinateSpace<PtType = Pt> {
+        let from = self.position();
+
+        if maze.neighbours_of_pt(from).contains(&to) == false {
+            return MoveResult::OffMaze
+        }
+
+        return self.move_if_connected(maze.get_connection(from, to), to)
+    }
+
+    /// Move the agent to `to` if `connection` is a passage.
+    fn move_if_connected(&mut self, connection: ConnectionType, to: Pt) -> MoveResult<Pt> {
+        if connection != ConnectionType::PASSAGE {
+            return MoveResult::Blocked(connection)
+        }
+
+        self.history.push(to);
+        self.visited.insert(to);
+
+        return if self.goal == Some(to) { MoveResult::ReachedGoal(to) } else { MoveResult::Moved(to) }
+    }
+}
+
+/// The outcome of an [`Agent`]'s attempt to move.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MoveResult<Pt: Point> {
+    /// The agent moved to this point.
+    Moved(Pt),
+    /// The agent moved to this point, which is its goal.
+    ReachedGoal(Pt),
+    /// The agent couldn't move, as the connection in the way isn't a passage.
+    Blocked(ConnectionType),
+    /// The agent couldn't move, as there is no point there.
+    OffMaze,
+}
+
+impl <Pt: Point> MoveResult<Pt> {
+    /// Return true if the agent moved.
+    #[must_use]
+    pub fn has_moved(&self) -> bool {
+        matches!(self, Self::Moved(_) | Self::ReachedGoal(_))
+    }
+}
+
+/// Coordinate spaces whose points can be stepped between by direction.
+///
+/// This is what lets an [`Agent`] [move by direction][Agent::try_move].
+pub trait Directional: CoordinateSpace {
+    /// The directions one can step in.
+    type Direction: Copy + Debug;
+
+    /// Return the point one step from `pt` in `direction`, or `None` if there is no such point in
+    /// this space.
+    fn step(&self, pt: Self::PtType, direction: Self::Direction) -> Option<Self::PtType>;
+}
+
+impl <const DIMENSION: usize> Directional for BoxCoordinateSpace<DIMENSION> {
+    type Direction = BoxDirection<DIMENSION>;
+
+    fn step(&self, pt: Self::PtType, direction: Self::Direction) -> Option<Self::PtType> {
+        self.neighbour(pt, direction)
+    }
+}
+
+impl Directional for HexCoordinateSpace {
+    type Direction = HexDirection;
+
+    fn step(&self, pt: Self::PtType, direction: Self::Direction) -> Option<Self::PtType> {
+        self.neighbour(pt, direction)
+    }
+}
+
+impl Directional for DeltaCoordinateSpace {
+    type Direction = DeltaEdge;
+
+    fn step(&self, pt: Self::PtType, direction: Self::Direction) -> Option<Self::PtType> {
+        self.neighbour(pt, direction)
+    }
+}
+
+impl Directional for UpsilonCoordinateSpace {
+    type Direction = UpsilonEdge;
+
+    fn step(&self, pt: Self::PtType, direction: Self::Direction) -> Option<Self::PtType> {
+        self.neighbour(pt, direction)
+    }
+}
+
+impl Directional for PolarCoordinateSpace {
+    type Direction = PolarEdge;
+
+    fn step(&self, pt: Self::PtType, direction: Self::Direction) -> Option<Self::PtType> {
+        self.neighbour(pt, direction)
+    }
+}
+
+impl Directional for CubeCoordinateSpace {
+    type Direction = CubeEdge;
+
+    fn step(&self, pt: Self::PtType, direction: Self::Direction) -> Option<Self::PtType> {
+        Some(self.neighbour(pt, direction))
+    }
+}
\ No newline at end of file
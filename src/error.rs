@@ -0,0 +1,92 @@
+//! Errors for invalid maze configurations and operations.
+//!
+//! Most of the library panics when misused, as misuse is usually a bug in the calling program.
+//! When the configuration comes from somewhere else, such as a user, the `try_` variants of those
+//! functions return a [`MazeError`] instead so that it can be reported.
+//!
+//! # Examples
+//!
+//! ```
+//! use mazelib::error::MazeError;
+//! use mazelib::implm::point::boxy::BoxCoordinateSpace;
+//!
+//! assert_eq!(MazeError::ZeroDimension, BoxCoordinateSpace::try_new_checked([5, 0]).unwrap_err());
+//! ```
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// An error raised when a maze is configured or operated on incorrectly.
+///
+/// The `Display` message of each variant is the same as the message the panicking version of the
+/// function would panic with.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum MazeError {
+    /// A dimension of a coordinate space was zero.
+    ZeroDimension,
+    /// The number of points in a coordinate space does not fit within a `usize`.
+    TooManyPoints,
+    /// The number of cells in a maze does not fit within a `usize`.
+    TooManyCells,
+    /// A dimension of a maze's cell space does not fit within a `usize` once scaled.
+    ScaledDimensionsTooLarge {
+        /// The axis that is too long.
+        axis: usize,
+    },
+    /// A dimension of a maze's cell space does not fit within a `usize` once scaled and padded.
+    FullDimensionsTooLarge {
+        /// The axis that is too long.
+        axis: usize,
+    },
+    /// A scale factor was zero.
+    ZeroScaleFactor {
+        /// The axis of the scale factor.
+        axis: usize,
+    },
+    /// A maze with [diagonal adjacency][crate::implm::point::boxy::BoxCoordinateSpace#diagonal-adjacency]
+    /// has a scale factor less than 3.
+    DiagonalScaleFactorTooSmall {
+        /// The axis of the scale factor.
+        axis: usize,
+    },
+    /// A maze with [diagonal adjacency][crate::implm::point::boxy::BoxCoordinateSpace#diagonal-adjacency]
+    /// has a spacing less than 3.
+    DiagonalSpacingTooSmall {
+        /// The axis of the spacing.
+        axis: usize,
+        /// The gap along the axis, where `0` is the gap between the first and second points.
+        gap: usize,
+    },
+    /// Inline cells can't represent [diagonal adjacency][crate::implm::point::boxy::BoxCoordinateSpace#diagonal-adjacency].
+    DiagonalsUnsupported,
+    /// Two points that were required to be adjacent are not.
+    ///
+    /// The points are stored in their `Debug` representation, so that this error is independent
+    /// of the type of point.
+    NotAdjacent {
+        /// The first point.
+        from: String,
+        /// The second point.
+        to: String,
+    },
+}
+
+impl Display for MazeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ZeroDimension => write!(f, "All dimensions must be non-zero"),
+            Self::TooManyPoints => write!(f, "The dimensions specified are too large. The number of points in the space does not fit within a usize."),
+            Self::TooManyCells => write!(f, "The full dimensions specified are too large. The number of cells in the maze does not fit within a usize."),
+            Self::ScaledDimensionsTooLarge { axis } => write!(f, "The scaled dimensions do not all fit within a usize (axis {} is too long)", axis),
+            Self::FullDimensionsTooLarge { axis } => write!(f, "The full dimensions do not all fit within a usize (axis {} is too long)", axis),
+            Self::ZeroScaleFactor { axis } => write!(f, "All scale factors must be non-zero (the scale factor of axis {} is zero)", axis),
+            Self::DiagonalScaleFactorTooSmall { axis } => write!(f, "Diagonal mazes must have scale factors of at least 3 (the scale factor of axis {} is too small)", axis),
+            Self::DiagonalSpacingTooSmall { axis, gap } => write!(f, "Diagonal mazes must have spacings of at least 3 (gap {} along axis {} is too small)", gap, axis),
+            Self::DiagonalsUnsupported => write!(f, "Inline cells do not support diagonal adjacency"),
+            Self::NotAdjacent { from, to } => write!(f, "{} and {} are not adjacent", from, to),
+        }
+    }
+}
+
+impl Error for MazeError {}
\ No newline at end of file
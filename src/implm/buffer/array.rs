@@ -1,5 +1,8 @@
+use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
+use std::ops::Range;
 
 use crate::interface::buffer::MazeBuffer;
 use crate::interface::cell::{CellID, CellValue};
@@ -12,6 +15,7 @@ use crate::interface::cell::{CellID, CellValue};
 /// which is a more flexible and ergonomic alternative. `ArrayBuffer` is only warranted
 /// in specific niche scenarios. It primarily exists to provide a second, contrasting
 /// implementation of `MazeBuffer`.
+#[derive(Clone)]
 pub struct ArrayBuffer<CellVal: CellValue, const MAX_CELLS: usize> {
     size: NonZeroUsize,
     buf: [CellVal; MAX_CELLS],
@@ -47,6 +51,43 @@ impl <CellVal: CellValue, const CELLS: usize> MazeBuffer<CellVal> for ArrayBuffe
 
         return self.buf[cell.0] = new_value;
     }
+
+    fn cell_count(&self) -> NonZeroUsize {
+        self.size
+    }
+
+    fn get_slice(&self, cells: Range<CellID>) -> Cow<'_, [CellVal]> {
+        Cow::Borrowed(&self.buf[..usize::from(self.size)][cells.start.0..cells.end.0])
+    }
+
+    fn set_range(&mut self, cells: Range<CellID>, new_value: CellVal) {
+        self.buf[..usize::from(self.size)][cells.start.0..cells.end.0].fill(new_value)
+    }
+
+    fn fill(&mut self, new_value: CellVal) {
+        self.buf[..usize::from(self.size)].fill(new_value)
+    }
+}
+
+impl <CellVal: CellValue, const CELLS: usize> ArrayBuffer<CellVal, CELLS> {
+    /// Return the cells in use, excluding the unused capacity at the end of the array.
+    fn cells(&self) -> &[CellVal] {
+        &self.buf[..usize::from(self.size)]
+    }
+}
+
+impl <CellVal: CellValue, const CELLS: usize> PartialEq for ArrayBuffer<CellVal, CELLS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells() == other.cells()
+    }
+}
+
+impl <CellVal: CellValue, const CELLS: usize> Eq for ArrayBuffer<CellVal, CELLS> {}
+
+impl <CellVal: CellValue + Hash, const CELLS: usize> Hash for ArrayBuffer<CellVal, CELLS> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cells().hash(state)
+    }
 }
 
 impl <CellVal: CellValue, const CELLS: usize> Debug for ArrayBuffer<CellVal, CELLS> {
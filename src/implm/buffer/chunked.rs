@@ -0,0 +1,165 @@
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, CellValue};
+
+/// A [buffer][MazeBuffer] that divides its cells into fixed-size chunks, and only allocates each
+/// chunk when one of its cells is first written to.
+///
+/// Chunks that have never been written to are treated as though all of their cells had their
+/// default (unvisited) value. This makes `ChunkedBuffer` much more memory-efficient than a
+/// [`VecBuffer`][crate::implm::buffer::VecBuffer] for mazes where most of the cells are never
+/// touched, such as mazes generated inside a small [mask][crate::implm::point::masked].
+///
+/// `CHUNK_SIZE` is the number of cells in each chunk. Smaller chunks waste less memory on
+/// untouched cells around the edges of the used area, but cost more per cell to track.
+///
+/// # Examples
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// # use mazelib::implm::buffer::ChunkedBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::interface::buffer::MazeBuffer;
+/// # use mazelib::interface::cell::CellID;
+/// #
+/// let mut buffer = ChunkedBuffer::<BlockCellValue, 64>::new(NonZeroUsize::new(1000).unwrap());
+///
+/// assert_eq!(0, buffer.allocated_chunk_count());
+///
+/// buffer.set(CellID(500), BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, ..Default::default() });
+///
+/// assert_eq!(1, buffer.allocated_chunk_count());
+/// assert_eq!(BlockCellValue::default(), buffer.get(CellID(0)));
+/// ```
+#[derive(Clone)]
+pub struct ChunkedBuffer<CellVal: CellValue, const CHUNK_SIZE: usize = 4096> {
+    /// The number of cells in the buffer.
+    size: NonZeroUsize,
+    /// Every chunk, or `None` if it has not been written to yet.
+    chunks: Vec<Option<Box<[CellVal; CHUNK_SIZE]>>>,
+}
+
+impl <CellVal: CellValue, const CHUNK_SIZE: usize> ChunkedBuffer<CellVal, CHUNK_SIZE> {
+    /// Return the number of chunks that have been allocated.
+    #[must_use]
+    pub fn allocated_chunk_count(&self) -> usize {
+        self.chunks.iter().filter(|chunk| chunk.is_some()).count()
+    }
+
+    /// Return the total number of chunks, allocated or not.
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Split a cell ID into the index of its chunk and its index within that chunk.
+    fn locate(&self, cell: CellID) -> (usize, usize) {
+        if cell.0 >= usize::from(self.size) {
+            panic!("Cell is out of bounds");
+        }
+
+        return (cell.0 / CHUNK_SIZE, cell.0 % CHUNK_SIZE)
+    }
+
+    /// Return a chunk, or `None` if every one of its cells has the default value (whether or not
+    /// it has been allocated).
+    fn chunk(&self, chunk: usize) -> Option<&[CellVal; CHUNK_SIZE]> {
+        self.chunks[chunk].as_deref().filter(|chunk| chunk.iter().any(|cell| *cell != CellVal::default()))
+    }
+
+    /// Get a chunk for mutation, allocating it if necessary.
+    fn chunk_mut(&mut self, chunk: usize) -> &mut [CellVal; CHUNK_SIZE] {
+        self.chunks[chunk].get_or_insert_with(|| Box::new([CellVal::default(); CHUNK_SIZE]))
+    }
+}
+
+impl <CellVal: CellValue, const CHUNK_SIZE: usize> MazeBuffer<CellVal> for ChunkedBuffer<CellVal, CHUNK_SIZE> {
+    /// Construct a new buffer. No chunks are allocated until they are written to.
+    ///
+    /// # Panics
+    ///
+    /// If `CHUNK_SIZE` is zero.
+    fn new(cell_count: NonZeroUsize) -> Self {
+        assert!(CHUNK_SIZE > 0, "The chunk size must be non-zero");
+
+        // Round up, so the last chunk may be only partially used
+        let chunk_count = (usize::from(cell_count) - 1) / CHUNK_SIZE + 1;
+
+        Self { size: cell_count, chunks: vec![None; chunk_count] }
+    }
+
+    fn get(&self, cell: CellID) -> CellVal {
+        let (chunk, offset) = self.locate(cell);
+
+        return match &self.chunks[chunk] {
+            Some(chunk) => chunk[offset],
+            None => CellVal::default(),
+        }
+    }
+
+    /// Get the value of a given cell for mutation.
+    ///
+    /// As the cell may be written to through the reference, this always allocates the cell's
+    /// chunk.
+    fn get_mut(&mut self, cell: CellID) -> &mut CellVal {
+        let (chunk, offset) = self.locate(cell);
+
+        return &mut self.chunk_mut(chunk)[offset]
+    }
+
+    /// Set the value of a given cell.
+    ///
+    /// Setting a cell in an unallocated chunk to its default value does not allocate the chunk.
+    fn set(&mut self, cell: CellID, new_value: CellVal) {
+        let (chunk, offset) = self.locate(cell);
+
+        if self.chunks[chunk].is_none() && new_value == CellVal::default() {
+            return
+        }
+
+        self.chunk_mut(chunk)[offset] = new_value
+    }
+
+    fn cell_count(&self) -> NonZeroUsize {
+        self.size
+    }
+
+    /// Set every cell in the buffer to the same value.
+    ///
+    /// Filling the buffer with the default value frees every chunk.
+    fn fill(&mut self, new_value: CellVal) {
+        if new_value == CellVal::default() {
+            self.chunks.fill(None);
+        } else {
+            self.chunks.fill_with(|| Some(Box::new([new_value; CHUNK_SIZE])));
+        }
+    }
+}
+
+/// Buffers are equal if their cells are, regardless of which chunks have been allocated.
+impl <CellVal: CellValue, const CHUNK_SIZE: usize> PartialEq for ChunkedBuffer<CellVal, CHUNK_SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && (0..self.chunk_count()).all(|chunk| self.chunk(chunk) == other.chunk(chunk))
+    }
+}
+
+impl <CellVal: CellValue, const CHUNK_SIZE: usize> Eq for ChunkedBuffer<CellVal, CHUNK_SIZE> {}
+
+impl <CellVal: CellValue + Hash, const CHUNK_SIZE: usize> Hash for ChunkedBuffer<CellVal, CHUNK_SIZE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+
+        for chunk in 0..self.chunk_count() {
+            self.chunk(chunk).hash(state);
+        }
+    }
+}
+
+impl <CellVal: CellValue, const CHUNK_SIZE: usize> Debug for ChunkedBuffer<CellVal, CHUNK_SIZE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ChunkedBuffer(size = {}, chunks allocated = {}/{})", self.size, self.allocated_chunk_count(), self.chunk_count())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,244 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use crate::implm::buffer::VecBuffer;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, CellValue, ConnectionType};
+
+/// The number of cells in a buffer in each [state][CellValue::state].
+///
+/// # See Also
+///
+/// [`CountingBuffer`], which keeps these up to date as the maze changes.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Occupancy {
+    /// The number of cells in the [`ConnectionType::UNVISITED`] state.
+    pub unvisited: usize,
+    /// The number of cells in the [`ConnectionType::PASSAGE`] state.
+    pub passage: usize,
+    /// The number of cells in the [`ConnectionType::WALL`] state.
+    pub wall: usize,
+    /// The number of cells in the [`ConnectionType::BOUNDARY`] state.
+    pub boundary: usize,
+}
+
+impl Occupancy {
+    /// Count the cells of a buffer by scanning every one of them.
+    ///
+    /// This is *O(n)*. For *O(1)* statistics, use a [`CountingBuffer`].
+    #[must_use]
+    pub fn of<CellVal: CellValue>(buffer: &impl MazeBuffer<CellVal>) -> Self {
+        let mut occupancy = Self::default();
+
+        for value in buffer.get_slice(CellID(0)..CellID(usize::from(buffer.cell_count()))).iter() {
+            *occupancy.count_mut(value.state()) += 1;
+        }
+
+        return occupancy
+    }
+
+    /// Return the number of cells in a given state.
+    #[must_use]
+    pub fn count(&self, state: ConnectionType) -> usize {
+        match state {
+            ConnectionType::UNVISITED => self.unvisited,
+            ConnectionType::PASSAGE   => self.passage,
+            ConnectionType::WALL      => self.wall,
+            ConnectionType::BOUNDARY  => self.boundary,
+        }
+    }
+
+    /// Return the total number of cells counted.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.unvisited + self.passage + self.wall + self.boundary
+    }
+
+    /// Return the fraction (0--1) of cells that have been visited.
+    #[must_use]
+    pub fn visited_fraction(&self) -> f64 {
+        1.0 - self.unvisited as f64 / self.total() as f64
+    }
+
+    fn count_mut(&mut self, state: ConnectionType) -> &mut usize {
+        match state {
+            ConnectionType::UNVISITED => &mut self.unvisited,
+            ConnectionType::PASSAGE   => &mut self.passage,
+            ConnectionType::WALL      => &mut self.wall,
+            ConnectionType::BOUNDARY  => &mut self.boundary,
+        }
+    }
+}
+
+/// A [buffer][MazeBuffer] that keeps count of how many of its cells are in each state, so its
+/// [occupancy][Self::occupancy] can be queried in *O(1)*.
+///
+/// This is handy for progress reporting, or for generators that need to know how much of the
+/// maze is left to visit. The counts are maintained as cells are written to, with a small
+/// overhead on every write.
+///
+/// `CountingBuffer` wraps another buffer (a [`VecBuffer`] by default) which stores the cells.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::CountingBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// #
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<CountingBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3]))
+///     .padding([[0, 0], [0, 0]])
+///     .build();
+///
+/// assert_eq!(25, maze.buffer().occupancy().unvisited);
+///
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+///
+/// assert_eq!(3, maze.buffer().occupancy().passage);
+/// ```
+#[derive(Clone)]
+pub struct CountingBuffer<CellVal: CellValue, Buffer: MazeBuffer<CellVal> = VecBuffer<CellVal>> {
+    /// The buffer actually storing the cells.
+    inner: Buffer,
+    /// The number of cells in each state, excluding any changes to the pending cell.
+    occupancy: Cell<Occupancy>,
+    /// A cell handed out by [`MazeBuffer::get_mut()`] that may have changed since, along with its
+    /// state when it was handed out.
+    ///
+    /// Its new state is counted the next time the buffer is accessed.
+    pending: Cell<Option<(CellID, ConnectionType)>>,
+    _cell: PhantomData<CellVal>,
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal>> CountingBuffer<CellVal, Buffer> {
+    /// Wrap an existing buffer, counting its cells.
+    ///
+    /// This scans every cell of the buffer, so is *O(n)*.
+    #[must_use]
+    pub fn wrap(inner: Buffer) -> Self {
+        let occupancy = Occupancy::of(&inner);
+
+        Self { inner, occupancy: Cell::new(occupancy), pending: Cell::new(None), _cell: PhantomData }
+    }
+
+    /// Return the number of cells in each state.
+    #[must_use]
+    pub fn occupancy(&self) -> Occupancy {
+        self.settle();
+
+        self.occupancy.get()
+    }
+
+    /// Return the wrapped buffer.
+    #[must_use]
+    pub fn into_inner(self) -> Buffer {
+        self.inner
+    }
+
+    /// Count the new state of the pending cell, if there is one.
+    fn settle(&self) {
+        if let Some((cell, old_state)) = self.pending.take() {
+            self.recount(old_state, self.inner.get(cell).state(), 1);
+        }
+    }
+
+    /// Move `count` cells from state `from` to state `to`.
+    fn recount(&self, from: ConnectionType, to: ConnectionType, count: usize) {
+        let mut occupancy = self.occupancy.get();
+
+        *occupancy.count_mut(from) -= count;
+        *occupancy.count_mut(to) += count;
+
+        self.occupancy.set(occupancy);
+    }
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal>> MazeBuffer<CellVal> for CountingBuffer<CellVal, Buffer> {
+    fn new(cell_count: NonZeroUsize) -> Self {
+        let mut occupancy = Occupancy::default();
+        *occupancy.count_mut(CellVal::default().state()) = usize::from(cell_count);
+
+        Self { inner: Buffer::new(cell_count), occupancy: Cell::new(occupancy), pending: Cell::new(None), _cell: PhantomData }
+    }
+
+    fn get(&self, cell: CellID) -> CellVal {
+        self.inner.get(cell)
+    }
+
+    fn get_mut(&mut self, cell: CellID) -> &mut CellVal {
+        self.settle();
+
+        self.pending.set(Some((cell, self.inner.get(cell).state())));
+
+        return self.inner.get_mut(cell)
+    }
+
+    fn set(&mut self, cell: CellID, new_value: CellVal) {
+        self.settle();
+
+        self.recount(self.inner.get(cell).state(), new_value.state(), 1);
+
+        self.inner.set(cell, new_value)
+    }
+
+    fn cell_count(&self) -> NonZeroUsize {
+        self.inner.cell_count()
+    }
+
+    fn get_slice(&self, cells: Range<CellID>) -> Cow<'_, [CellVal]> {
+        self.inner.get_slice(cells)
+    }
+
+    fn set_range(&mut self, cells: Range<CellID>, new_value: CellVal) {
+        self.settle();
+
+        for value in self.inner.get_slice(cells.clone()).iter() {
+            self.recount(value.state(), new_value.state(), 1);
+        }
+
+        self.inner.set_range(cells, new_value)
+    }
+
+    fn fill(&mut self, new_value: CellVal) {
+        self.pending.set(None);
+
+        let mut occupancy = Occupancy::default();
+        *occupancy.count_mut(new_value.state()) = usize::from(self.cell_count());
+        self.occupancy.set(occupancy);
+
+        self.inner.fill(new_value)
+    }
+}
+
+/// Buffers are equal if their cells are.
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal> + PartialEq> PartialEq for CountingBuffer<CellVal, Buffer> {
+    fn eq(&self, other: &Self) -> bool {
+        self.settle();
+        other.settle();
+
+        self.inner == other.inner
+    }
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal> + Eq> Eq for CountingBuffer<CellVal, Buffer> {}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal> + Hash> Hash for CountingBuffer<CellVal, Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.settle();
+
+        self.inner.hash(state)
+    }
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal>> Debug for CountingBuffer<CellVal, Buffer> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CountingBuffer({:?}, {:?})", self.inner, self.occupancy())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,290 @@
+#![cfg(any(feature = "mmap", doc))]
+
+use std::borrow::Cow;
+use std::fmt::{Debug, Formatter};
+use std::fs::OpenOptions;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::marker::PhantomData;
+use std::mem::{align_of, size_of};
+use std::num::NonZeroUsize;
+use std::ops::Range;
+use std::path::Path;
+
+use memmap2::MmapMut;
+
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, CellValue};
+use crate::internal::util::invalid_data;
+
+/// Identifies a file as a memory-mapped maze buffer.
+const MAGIC: [u8; 8] = *b"MZLBMMAP";
+
+/// The size of the header at the start of the mapping, in bytes.
+///
+/// The header is laid out as:
+/// * bytes 0--8: [`MAGIC`]
+/// * bytes 8--16: the size of each cell in bytes (little-endian `u64`)
+/// * bytes 16--24: the number of cells (little-endian `u64`)
+/// * bytes 24--32: reserved, always zero
+///
+/// Mappings are always page-aligned, so the cells that follow the header are suitably aligned for
+/// any cell type with an alignment of at most this.
+const HEADER_LEN: usize = 32;
+
+/// A [buffer][MazeBuffer] that stores its cells in a memory-mapped file.
+///
+/// Only the pages of the maze that are in use need to be resident in memory, with the operating
+/// system paging the rest in and out of the file as needed. This allows mazes larger than the
+/// available memory to be generated and exported.
+///
+/// Since every change is written through to the file, the maze also survives its process. A
+/// process that is interrupted partway through generation can [re-open][Self::open] the file and
+/// pick up where it left off.
+///
+/// Buffers constructed with [`MazeBuffer::new()`] (which is what maze coordinators do by default)
+/// are backed by anonymous memory rather than a file. To store a maze in a file, create the buffer
+/// yourself with [`Self::create()`] or [`Self::open()`] and hand it to the coordinator's builder
+/// (e.g. [`build_with_buffer()`][crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinatorBuilder::build_with_buffer]).
+///
+/// Cells are stored in their in-memory representation, so the files are not portable. They may
+/// only be read back by the same build of the program that wrote them.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::MmapBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let path = std::env::temp_dir().join("mazelib-mmap-buffer-example.bin");
+///
+/// let builder = BoxSpaceBlockCellMazeCoordinator::<MmapBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([4, 4]));
+///
+/// let mut maze = builder.build_with_buffer(MmapBuffer::create(&path, builder.cells_required()).unwrap());
+/// maze.make_passage([1, 1].into());
+/// maze.buffer().flush().unwrap();
+/// drop(maze);
+///
+/// // Later (perhaps in another process)...
+/// let maze = builder.build_with_buffer(unsafe { MmapBuffer::open(&path) }.unwrap());
+///
+/// assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get([1, 1].into()).cell_type);
+/// #
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct MmapBuffer<CellVal: CellValue> {
+    /// The mapping, including the header.
+    map: MmapMut,
+    /// The number of cells stored after the header.
+    cell_count: NonZeroUsize,
+    _cell: PhantomData<CellVal>,
+}
+
+impl <CellVal: CellValue> MmapBuffer<CellVal> {
+    /// Create a new file at `path` and map it as a buffer.
+    ///
+    /// If a file already exists at `path` it is truncated. Every cell is initialised to its
+    /// default value.
+    ///
+    /// # Parameters
+    ///
+    /// `path`       --- where to create the file.
+    /// `cell_count` --- the number of cells the buffer must store. See [`MazeBuffer::new()`].
+    ///
+    /// # Errors
+    ///
+    /// Any IO errors encountered while creating or mapping the file.
+    ///
+    /// # Panics
+    ///
+    /// If the size of the file would not fit in a `usize`.
+    pub fn create(path: impl AsRef<Path>, cell_count: NonZeroUsize) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+
+        file.set_len(Self::file_len(cell_count) as u64)?;
+
+        let map = unsafe { MmapMut::map_mut(&file)? };
+
+        return Ok(Self::initialise(map, cell_count))
+    }
+
+    /// Map an existing buffer file, such as one previously made with [`Self::create()`].
+    ///
+    /// The cells are left exactly as they were in the file.
+    ///
+    /// # Errors
+    ///
+    /// Any IO errors encountered while opening or mapping the file. If the file is not a buffer
+    /// file, or it was written for a cell type of a different size, an error of the kind
+    /// [`InvalidData`][io::ErrorKind::InvalidData] is returned.
+    ///
+    /// # Safety
+    ///
+    /// The file must have been written by an `MmapBuffer` of the same cell type, by the same build
+    /// of the program. Only the size of the cells can be checked, so reading any other file
+    /// is undefined behaviour.
+    ///
+    /// Like all memory-mapped files, the file must not be modified by any other process (or any
+    /// other mapping) while the buffer is in use.
+    pub unsafe fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+
+        let map = MmapMut::map_mut(&file)?;
+
+        if map.len() < HEADER_LEN || map[0..8] != MAGIC {
+            return Err(invalid_data("The file is not a buffer file"));
+        }
+
+        let cell_size = Self::read_header_field(&map, 1);
+        let cell_count = Self::read_header_field(&map, 2);
+
+        if cell_size != size_of::<CellVal>() as u64 {
+            return Err(invalid_data("The file was written for a different cell type"));
+        }
+
+        let cell_count = usize::try_from(cell_count).ok().and_then(NonZeroUsize::new).ok_or_else(|| invalid_data("The file has an invalid cell count"))?;
+
+        if Self::checked_file_len(cell_count) != Some(map.len()) {
+            return Err(invalid_data("The file's length does not match its cell count"));
+        }
+
+        return Ok(Self { map, cell_count, _cell: PhantomData })
+    }
+
+    /// Write all modified cells back to the file, blocking until they are written.
+    ///
+    /// Modified cells are always written back eventually, including when the buffer is dropped
+    /// or the process exits. This only guarantees that they have been written, such as before
+    /// a checkpoint.
+    ///
+    /// Does nothing for buffers backed by anonymous memory.
+    ///
+    /// # Errors
+    ///
+    /// Any IO errors encountered while writing.
+    pub fn flush(&self) -> io::Result<()> {
+        self.map.flush()
+    }
+
+    /// Start writing all modified cells back to the file, without waiting for them to be written.
+    ///
+    /// # Errors
+    ///
+    /// Any IO errors encountered while starting the writes.
+    pub fn flush_async(&self) -> io::Result<()> {
+        self.map.flush_async()
+    }
+
+    /// The total length of a mapping for `cell_count` cells.
+    fn file_len(cell_count: NonZeroUsize) -> usize {
+        Self::checked_file_len(cell_count).expect("The size of the buffer does not fit within a usize")
+    }
+
+    /// The total length of a mapping for `cell_count` cells, or `None` if it overflows.
+    fn checked_file_len(cell_count: NonZeroUsize) -> Option<usize> {
+        size_of::<CellVal>().checked_mul(usize::from(cell_count)).and_then(|cells_len| cells_len.checked_add(HEADER_LEN))
+    }
+
+    /// Write the header and default cells into a freshly-made mapping.
+    fn initialise(mut map: MmapMut, cell_count: NonZeroUsize) -> Self {
+        assert!(align_of::<CellVal>() <= HEADER_LEN, "The cell type's alignment is too large to be memory-mapped");
+
+        map[0..8].copy_from_slice(&MAGIC);
+        map[8..16].copy_from_slice(&(size_of::<CellVal>() as u64).to_le_bytes());
+        map[16..24].copy_from_slice(&(usize::from(cell_count) as u64).to_le_bytes());
+        map[24..32].fill(0);
+
+        let cells = unsafe { map.as_mut_ptr().add(HEADER_LEN).cast::<CellVal>() };
+
+        for i in 0..usize::from(cell_count) {
+            unsafe { cells.add(i).write(CellVal::default()) };
+        }
+
+        return Self { map, cell_count, _cell: PhantomData }
+    }
+
+    /// Read the `index`th 8-byte field of the header.
+    fn read_header_field(map: &MmapMut, index: usize) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&map[index * 8..(index + 1) * 8]);
+
+        return u64::from_le_bytes(bytes)
+    }
+
+    fn cells(&self) -> &[CellVal] {
+        // The header is always followed by exactly `cell_count` initialised, aligned cells
+        unsafe { std::slice::from_raw_parts(self.map.as_ptr().add(HEADER_LEN).cast::<CellVal>(), usize::from(self.cell_count)) }
+    }
+
+    fn cells_mut(&mut self) -> &mut [CellVal] {
+        // The header is always followed by exactly `cell_count` initialised, aligned cells
+        unsafe { std::slice::from_raw_parts_mut(self.map.as_mut_ptr().add(HEADER_LEN).cast::<CellVal>(), usize::from(self.cell_count)) }
+    }
+}
+
+impl <CellVal: CellValue> MazeBuffer<CellVal> for MmapBuffer<CellVal> {
+    /// Construct a new buffer backed by anonymous memory.
+    ///
+    /// Use [`MmapBuffer::create()`] to back the buffer with a file instead.
+    ///
+    /// # Panics
+    ///
+    /// If the memory could not be mapped.
+    fn new(cell_count: NonZeroUsize) -> Self {
+        let map = MmapMut::map_anon(Self::file_len(cell_count)).expect("Could not map memory for the buffer");
+
+        Self::initialise(map, cell_count)
+    }
+
+    fn get(&self, cell: CellID) -> CellVal {
+        self.cells()[cell.0]
+    }
+
+    fn get_mut(&mut self, cell: CellID) -> &mut CellVal {
+        &mut self.cells_mut()[cell.0]
+    }
+
+    fn set(&mut self, cell: CellID, new_value: CellVal) {
+        self.cells_mut()[cell.0] = new_value
+    }
+
+    fn cell_count(&self) -> NonZeroUsize {
+        self.cell_count
+    }
+
+    fn get_slice(&self, cells: Range<CellID>) -> Cow<'_, [CellVal]> {
+        Cow::Borrowed(&self.cells()[cells.start.0..cells.end.0])
+    }
+
+    fn set_range(&mut self, cells: Range<CellID>, new_value: CellVal) {
+        self.cells_mut()[cells.start.0..cells.end.0].fill(new_value)
+    }
+
+    fn fill(&mut self, new_value: CellVal) {
+        self.cells_mut().fill(new_value)
+    }
+}
+
+impl <CellVal: CellValue> PartialEq for MmapBuffer<CellVal> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells() == other.cells()
+    }
+}
+
+impl <CellVal: CellValue> Eq for MmapBuffer<CellVal> {}
+
+impl <CellVal: CellValue + Hash> Hash for MmapBuffer<CellVal> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cells().hash(state)
+    }
+}
+
+impl <CellVal: CellValue> Debug for MmapBuffer<CellVal> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MmapBuffer(size = {})", self.cell_count)
+    }
+}
\ No newline at end of file
@@ -1,14 +1,34 @@
 //! Some basic built-in buffer implementations.
 //!
-//! In almost all cases you will want to use a [`VecBuffer`].
+//! In almost all cases you will want to use a [`VecBuffer`]. For mazes too large to fit in memory,
+//! see `MmapBuffer` (requires the `mmap` feature). For mazes that only use a small part of
+//! their cell space, see [`ChunkedBuffer`]. For mazes generated by several threads at once, see
+//! [`SyncBuffer`]. For storing many finished mazes compactly, see [`RleBuffer`]. To keep track of
+//! how much of a maze has been generated, see [`CountingBuffer`]. To be told about every change to a
+//! maze as it happens, see [`ObservedBuffer`]. To undo and redo changes, see [`UndoBuffer`].
 //!
 //! # See Also
 //!
 //! * [`MazeBuffer`][crate::interface::buffer::MazeBuffer] --- the interface trait
 
 pub use self::array::ArrayBuffer;
+pub use self::chunked::ChunkedBuffer;
+pub use self::counting::{CountingBuffer, Occupancy};
+#[cfg(any(feature = "mmap", doc))]
+pub use self::mmap::MmapBuffer;
+pub use self::observed::{CellObserver, ObservedBuffer};
+pub use self::rle::RleBuffer;
+pub use self::sync::SyncBuffer;
+pub use self::undo::UndoBuffer;
 pub use self::vec::VecBuffer;
 
 mod vec;
 mod array;
+mod chunked;
+mod counting;
+mod mmap;
+mod observed;
+mod rle;
+mod sync;
+mod undo;
 
@@ -0,0 +1,219 @@
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use crate::implm::buffer::VecBuffer;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, CellValue};
+
+/// A listener for changes to the cells of an [`ObservedBuffer`].
+///
+/// This is implemented for all closures that take the same parameters as
+/// [`on_cell_changed()`][Self::on_cell_changed].
+pub trait CellObserver<CellVal: CellValue>: Send {
+    /// Called whenever a cell changes.
+    ///
+    /// # Parameters
+    ///
+    /// `cell` --- the cell that changed.
+    /// `old`  --- its value before the change.
+    /// `new`  --- its value after the change.
+    fn on_cell_changed(&mut self, cell: CellID, old: CellVal, new: CellVal);
+}
+
+impl <CellVal: CellValue, F: FnMut(CellID, CellVal, CellVal) + Send> CellObserver<CellVal> for F {
+    fn on_cell_changed(&mut self, cell: CellID, old: CellVal, new: CellVal) {
+        self(cell, old, new)
+    }
+}
+
+/// A [buffer][MazeBuffer] that reports every change to its cells to a [`CellObserver`].
+///
+/// Every write a coordinator makes goes through its buffer, so this sees exactly what was done to
+/// the maze, cell by cell. This is handy for repainting only the parts of a maze that changed, or
+/// for recording what a generator did step by step.
+///
+/// The observer is only told about writes that change a cell's value. Changes made through
+/// [`MazeBuffer::get_mut()`] are reported the next time the buffer is accessed (or
+/// [flushed][Self::flush]), as the new value isn't known until then.
+///
+/// `ObservedBuffer` wraps another buffer (a [`VecBuffer`] by default) which stores the cells. A
+/// buffer constructed with [`MazeBuffer::new()`] has no observer, so construct it with
+/// [`Self::wrap()`] and pass it to the coordinator's builder (e.g.
+/// [`build_with_buffer()`][crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinatorBuilder::build_with_buffer]).
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::mpsc;
+/// # use mazelib::implm::buffer::{ObservedBuffer, VecBuffer};
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::buffer::MazeBuffer;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// #
+/// let (sender, receiver) = mpsc::channel();
+///
+/// let builder = BoxSpaceBlockCellMazeCoordinator::<ObservedBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3]));
+/// let buffer = ObservedBuffer::wrap(VecBuffer::new(builder.cells_required()), move |cell, _, new: BlockCellValue| {
+///     sender.send((cell, new.cell_type)).unwrap();
+/// });
+/// let mut maze = builder.build_with_buffer(buffer);
+///
+/// maze.make_passage([1, 1].into());
+/// maze.buffer().flush();
+///
+/// let changes: Vec<_> = receiver.try_iter().collect();
+///
+/// // The passage, then the walls around it
+/// assert_eq!((maze.map_pt_to_cell_id([1, 1].into()), BlockCellPrimaryValue::PASSAGE), changes[0]);
+/// assert_eq!(5, changes.len());
+/// ```
+pub struct ObservedBuffer<CellVal: CellValue, Buffer: MazeBuffer<CellVal> = VecBuffer<CellVal>> {
+    /// The buffer actually storing the cells.
+    inner: Buffer,
+    /// The observer to report changes to, if any.
+    observer: RefCell<Option<Box<dyn CellObserver<CellVal>>>>,
+    /// A cell handed out by [`MazeBuffer::get_mut()`] that may have changed since, along with its
+    /// value when it was handed out.
+    ///
+    /// Any change is reported the next time the buffer is accessed.
+    pending: Cell<Option<(CellID, CellVal)>>,
+    _cell: PhantomData<CellVal>,
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal>> ObservedBuffer<CellVal, Buffer> {
+    /// Wrap an existing buffer, reporting every change to its cells to `observer`.
+    #[must_use]
+    pub fn wrap(inner: Buffer, observer: impl CellObserver<CellVal> + 'static) -> Self {
+        Self { inner, observer: RefCell::new(Some(Box::new(observer))), pending: Cell::new(None), _cell: PhantomData }
+    }
+
+    /// Replace the observer, returning whether there was one already.
+    pub fn set_observer(&mut self, observer: impl CellObserver<CellVal> + 'static) -> bool {
+        self.settle();
+
+        self.observer.replace(Some(Box::new(observer))).is_some()
+    }
+
+    /// Stop reporting changes, returning whether there was an observer.
+    pub fn remove_observer(&mut self) -> bool {
+        self.settle();
+
+        self.observer.take().is_some()
+    }
+
+    /// Report any change made through [`MazeBuffer::get_mut()`] that hasn't been reported yet.
+    pub fn flush(&self) {
+        self.settle()
+    }
+
+    /// Return the wrapped buffer, reporting any outstanding change first.
+    #[must_use]
+    pub fn into_inner(self) -> Buffer {
+        self.settle();
+
+        self.inner
+    }
+
+    /// Report any change to the pending cell.
+    fn settle(&self) {
+        if let Some((cell, old)) = self.pending.take() {
+            self.notify(cell, old, self.inner.get(cell));
+        }
+    }
+
+    /// Report a change to `cell` to the observer, if the value changed.
+    fn notify(&self, cell: CellID, old: CellVal, new: CellVal) {
+        if old == new {
+            return
+        }
+
+        if let Some(observer) = self.observer.borrow_mut().as_mut() {
+            observer.on_cell_changed(cell, old, new);
+        }
+    }
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal>> MazeBuffer<CellVal> for ObservedBuffer<CellVal, Buffer> {
+    fn new(cell_count: NonZeroUsize) -> Self {
+        Self { inner: Buffer::new(cell_count), observer: RefCell::new(None), pending: Cell::new(None), _cell: PhantomData }
+    }
+
+    fn get(&self, cell: CellID) -> CellVal {
+        self.settle();
+
+        self.inner.get(cell)
+    }
+
+    fn get_mut(&mut self, cell: CellID) -> &mut CellVal {
+        self.settle();
+
+        self.pending.set(Some((cell, self.inner.get(cell))));
+
+        return self.inner.get_mut(cell)
+    }
+
+    fn set(&mut self, cell: CellID, new_value: CellVal) {
+        self.settle();
+
+        let old_value = self.inner.get(cell);
+
+        self.inner.set(cell, new_value);
+
+        self.notify(cell, old_value, new_value)
+    }
+
+    fn cell_count(&self) -> NonZeroUsize {
+        self.inner.cell_count()
+    }
+
+    fn get_slice(&self, cells: Range<CellID>) -> Cow<'_, [CellVal]> {
+        self.settle();
+
+        self.inner.get_slice(cells)
+    }
+
+    fn set_range(&mut self, cells: Range<CellID>, new_value: CellVal) {
+        self.settle();
+
+        let old_values = self.inner.get_slice(cells.clone()).into_owned();
+
+        self.inner.set_range(cells.clone(), new_value);
+
+        for (cell, old_value) in (cells.start.0..cells.end.0).zip(old_values) {
+            self.notify(CellID(cell), old_value, new_value);
+        }
+    }
+}
+
+/// Buffers are equal if their cells are. The observer is not compared.
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal> + PartialEq> PartialEq for ObservedBuffer<CellVal, Buffer> {
+    fn eq(&self, other: &Self) -> bool {
+        self.settle();
+        other.settle();
+
+        self.inner == other.inner
+    }
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal> + Eq> Eq for ObservedBuffer<CellVal, Buffer> {}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal> + Hash> Hash for ObservedBuffer<CellVal, Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.settle();
+
+        self.inner.hash(state)
+    }
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal>> Debug for ObservedBuffer<CellVal, Buffer> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ObservedBuffer({:?})", self.inner)
+    }
+}
\ No newline at end of file
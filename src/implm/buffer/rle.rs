@@ -0,0 +1,252 @@
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, CellValue};
+
+/// A [buffer][MazeBuffer] that stores its cells run-length encoded.
+///
+/// Finished mazes are mostly made of long runs of identical cells (such as walls and passages),
+/// which this stores as a single value and the position the run starts at. This usually takes a
+/// small fraction of the memory of a [`VecBuffer`][crate::implm::buffer::VecBuffer], so it is
+/// well-suited to holding many finished mazes in memory at once, such as for batch analysis.
+///
+/// Reading a cell is *O(log r)*, where *r* is the number of runs. Writing is supported, but it is
+/// *O(r)* and fragments the runs, so this buffer is a poor choice for generating mazes in.
+/// Instead, generate the maze with another buffer and then [compress][Self::compress] it.
+///
+/// # Examples
+///
+/// ```
+/// # use rand::SeedableRng;
+/// # use rand_chacha::ChaCha8Rng;
+/// # use mazelib::implm::buffer::{RleBuffer, VecBuffer};
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::generate::HuntAndKillGenerator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::buffer::MazeBuffer;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// # use mazelib::interface::generate::MazeGenerator;
+/// #
+/// let space = BoxCoordinateSpace::new_checked([20, 20]);
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).build();
+/// HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(1));
+///
+/// let builder = BoxSpaceBlockCellMazeCoordinator::<RleBuffer<BlockCellValue>, 2>::builder(space);
+/// let cell_count = usize::from(builder.cells_required());
+///
+/// let compressed = builder.build_with_buffer(RleBuffer::compress(maze.buffer().iter_cells().map(|(_, value)| value)));
+///
+/// assert!(compressed.buffer().run_count() < cell_count);
+/// assert_eq!(maze.get([4, 7].into()), compressed.get([4, 7].into()));
+/// ```
+#[derive(Clone)]
+pub struct RleBuffer<CellVal: CellValue> {
+    /// The number of cells in the buffer.
+    size: NonZeroUsize,
+    /// The first cell of each run, in ascending order. The first run always starts at 0.
+    run_starts: Vec<usize>,
+    /// The value of every cell in each run.
+    run_values: Vec<CellVal>,
+}
+
+impl <CellVal: CellValue> RleBuffer<CellVal> {
+    /// Construct a buffer holding the given cells, in order.
+    ///
+    /// # Panics
+    ///
+    /// If `cells` is empty.
+    #[must_use]
+    pub fn compress(cells: impl IntoIterator<Item = CellVal>) -> Self {
+        let mut run_starts = Vec::new();
+        let mut run_values: Vec<CellVal> = Vec::new();
+        let mut size = 0;
+
+        for value in cells {
+            if run_values.last() != Some(&value) {
+                run_starts.push(size);
+                run_values.push(value);
+            }
+
+            size += 1;
+        }
+
+        let size = NonZeroUsize::new(size).expect("There must be at least one cell");
+
+        return Self { size, run_starts, run_values }
+    }
+
+    /// Return the number of runs the cells are currently stored in.
+    #[must_use]
+    pub fn run_count(&self) -> usize {
+        self.run_starts.len()
+    }
+
+    /// Merge all adjacent runs that have the same value.
+    ///
+    /// [`get_mut()`][MazeBuffer::get_mut] always splits the cell into its own run, which may leave
+    /// the buffer storing more runs than it needs to once the cell has been modified.
+    pub fn compact(&mut self) {
+        let mut i = 1;
+
+        while i < self.run_values.len() {
+            if self.run_values[i] == self.run_values[i - 1] {
+                self.run_starts.remove(i);
+                self.run_values.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Return the start and value of every run, with adjacent runs that have the same value
+    /// merged, as [`compact()`][Self::compact] would.
+    fn compacted_runs(&self) -> impl Iterator<Item = (usize, CellVal)> + '_ {
+        self.run_starts.iter().copied().zip(self.run_values.iter().copied())
+            .enumerate()
+            .filter(|(i, (_, value))| *i == 0 || self.run_values[i - 1] != *value)
+            .map(|(_, run)| run)
+    }
+
+    /// Return the index of the run containing a cell.
+    fn find_run(&self, cell: CellID) -> usize {
+        if cell.0 >= usize::from(self.size) {
+            panic!("Cell is out of bounds");
+        }
+
+        // The first run starts at 0, so at least one run always starts at or before the cell
+        return self.run_starts.partition_point(|start| *start <= cell.0) - 1
+    }
+
+    /// Split the run containing a cell so that the cell is in a run of its own, and return the
+    /// index of that run.
+    fn isolate(&mut self, cell: CellID) -> usize {
+        let run = self.split_before(cell.0);
+
+        if cell.0 + 1 < usize::from(self.size) {
+            self.split_before(cell.0 + 1);
+        }
+
+        return run
+    }
+
+    /// Split the run containing a cell so that a run starts at the cell, and return the index of
+    /// that run.
+    fn split_before(&mut self, cell: usize) -> usize {
+        let run = self.find_run(CellID(cell));
+
+        if self.run_starts[run] == cell {
+            return run
+        }
+
+        self.run_starts.insert(run + 1, cell);
+        self.run_values.insert(run + 1, self.run_values[run]);
+
+        return run + 1
+    }
+
+    /// Merge the run at index `run` with its neighbours, if they have the same value.
+    fn merge_around(&mut self, run: usize) {
+        // Merge with the following run first, so the index of this run stays valid
+        if self.run_values.get(run + 1) == Some(&self.run_values[run]) {
+            self.run_starts.remove(run + 1);
+            self.run_values.remove(run + 1);
+        }
+
+        if run > 0 && self.run_values[run - 1] == self.run_values[run] {
+            self.run_starts.remove(run);
+            self.run_values.remove(run);
+        }
+    }
+}
+
+impl <CellVal: CellValue> MazeBuffer<CellVal> for RleBuffer<CellVal> {
+    fn new(cell_count: NonZeroUsize) -> Self {
+        Self { size: cell_count, run_starts: vec![0], run_values: vec![CellVal::default()] }
+    }
+
+    fn get(&self, cell: CellID) -> CellVal {
+        self.run_values[self.find_run(cell)]
+    }
+
+    /// Get the value of a given cell for mutation.
+    ///
+    /// This splits the cell into a run of its own. See [`RleBuffer::compact()`].
+    fn get_mut(&mut self, cell: CellID) -> &mut CellVal {
+        let run = self.isolate(cell);
+
+        return &mut self.run_values[run]
+    }
+
+    /// Set the value of a given cell.
+    ///
+    /// Unlike [`get_mut()`][Self::get_mut], this merges the cell back into any neighbouring runs
+    /// with the same value.
+    fn set(&mut self, cell: CellID, new_value: CellVal) {
+        if self.get(cell) == new_value {
+            return
+        }
+
+        let run = self.isolate(cell);
+        self.run_values[run] = new_value;
+
+        self.merge_around(run);
+    }
+
+    fn cell_count(&self) -> NonZeroUsize {
+        self.size
+    }
+
+    /// Set every cell in a range of consecutive cells to the same value.
+    ///
+    /// The range is replaced with a single run, so this is much faster than setting each cell.
+    fn set_range(&mut self, cells: Range<CellID>, new_value: CellVal) {
+        if cells.start.0 >= cells.end.0 {
+            return
+        }
+
+        let first = self.split_before(cells.start.0);
+
+        let last = if cells.end.0 < usize::from(self.size) { self.split_before(cells.end.0) } else { self.run_count() };
+
+        self.run_starts.drain(first + 1..last);
+        self.run_values.drain(first + 1..last);
+        self.run_values[first] = new_value;
+
+        self.merge_around(first);
+    }
+
+    fn fill(&mut self, new_value: CellVal) {
+        self.run_starts = vec![0];
+        self.run_values = vec![new_value];
+    }
+}
+
+/// Buffers are equal if their cells are, regardless of how the cells are split into runs.
+impl <CellVal: CellValue> PartialEq for RleBuffer<CellVal> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.compacted_runs().eq(other.compacted_runs())
+    }
+}
+
+impl <CellVal: CellValue> Eq for RleBuffer<CellVal> {}
+
+impl <CellVal: CellValue + Hash> Hash for RleBuffer<CellVal> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+
+        for run in self.compacted_runs() {
+            run.hash(state);
+        }
+    }
+}
+
+impl <CellVal: CellValue> Debug for RleBuffer<CellVal> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RleBuffer(size = {}, runs = {})", self.size, self.run_count())
+    }
+}
\ No newline at end of file
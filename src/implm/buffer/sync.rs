@@ -0,0 +1,152 @@
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::interface::buffer::{MazeBuffer, SyncMazeBuffer};
+use crate::interface::cell::{CellID, CellValue};
+
+/// A [buffer][MazeBuffer] that can be shared between threads, for generating mazes in parallel.
+///
+/// The cells are divided into shards of `SHARD_SIZE` consecutive cells, each guarded by its own
+/// lock. Threads working on different parts of the maze will usually be accessing different
+/// shards, and so will rarely contend with each other.
+///
+/// Accesses through a unique reference (i.e. the [`MazeBuffer`] methods) don't need to lock at
+/// all, so when used from a single thread a `SyncBuffer` is only marginally slower than a
+/// [`VecBuffer`][crate::implm::buffer::VecBuffer].
+///
+/// # See Also
+///
+/// [`SyncMazeBuffer`] --- the shared access interface.
+pub struct SyncBuffer<CellVal: CellValue, const SHARD_SIZE: usize = 1024> {
+    /// The number of cells in the buffer.
+    size: NonZeroUsize,
+    /// The cells, in shards of `SHARD_SIZE`. The last shard may be smaller.
+    shards: Vec<Mutex<Vec<CellVal>>>,
+}
+
+impl <CellVal: CellValue, const SHARD_SIZE: usize> SyncBuffer<CellVal, SHARD_SIZE> {
+    /// Split a cell ID into the index of its shard and its index within that shard.
+    fn locate(&self, cell: CellID) -> (usize, usize) {
+        if cell.0 >= usize::from(self.size) {
+            panic!("Cell is out of bounds");
+        }
+
+        return (cell.0 / SHARD_SIZE, cell.0 % SHARD_SIZE)
+    }
+
+    /// Lock a shard.
+    fn lock(&self, shard: usize) -> MutexGuard<'_, Vec<CellVal>> {
+        // Cells are plain values that are never left half-written, so a poisoned shard is still
+        // perfectly usable
+        self.shards[shard].lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Get a shard for mutation, without locking.
+    fn shard_mut(&mut self, shard: usize) -> &mut Vec<CellVal> {
+        self.shards[shard].get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl <CellVal: CellValue, const SHARD_SIZE: usize> MazeBuffer<CellVal> for SyncBuffer<CellVal, SHARD_SIZE> {
+    /// Construct a new buffer.
+    ///
+    /// # Panics
+    ///
+    /// If `SHARD_SIZE` is zero.
+    fn new(cell_count: NonZeroUsize) -> Self {
+        assert!(SHARD_SIZE > 0, "The shard size must be non-zero");
+
+        let cell_count_usize = usize::from(cell_count);
+
+        let shards = (0..cell_count_usize).step_by(SHARD_SIZE)
+            .map(|start| Mutex::new(vec![CellVal::default(); SHARD_SIZE.min(cell_count_usize - start)]))
+            .collect();
+
+        Self { size: cell_count, shards }
+    }
+
+    fn get(&self, cell: CellID) -> CellVal {
+        self.get_shared(cell)
+    }
+
+    fn get_mut(&mut self, cell: CellID) -> &mut CellVal {
+        let (shard, offset) = self.locate(cell);
+
+        return &mut self.shard_mut(shard)[offset]
+    }
+
+    fn set(&mut self, cell: CellID, new_value: CellVal) {
+        *self.get_mut(cell) = new_value
+    }
+
+    fn cell_count(&self) -> NonZeroUsize {
+        self.size
+    }
+
+    fn fill(&mut self, new_value: CellVal) {
+        for shard in 0..self.shards.len() {
+            self.shard_mut(shard).fill(new_value);
+        }
+    }
+}
+
+impl <CellVal: CellValue, const SHARD_SIZE: usize> SyncMazeBuffer<CellVal> for SyncBuffer<CellVal, SHARD_SIZE> {
+    fn get_shared(&self, cell: CellID) -> CellVal {
+        let (shard, offset) = self.locate(cell);
+
+        return self.lock(shard)[offset]
+    }
+
+    fn set_shared(&self, cell: CellID, new_value: CellVal) {
+        let (shard, offset) = self.locate(cell);
+
+        self.lock(shard)[offset] = new_value
+    }
+
+    fn update_shared(&self, cell: CellID, update: impl FnOnce(&mut CellVal)) {
+        let (shard, offset) = self.locate(cell);
+
+        update(&mut self.lock(shard)[offset])
+    }
+}
+
+/// Cloning locks each shard in turn, so cells changed by other threads while cloning may or may
+/// not be included.
+impl <CellVal: CellValue, const SHARD_SIZE: usize> Clone for SyncBuffer<CellVal, SHARD_SIZE> {
+    fn clone(&self) -> Self {
+        let shards = (0..self.shards.len()).map(|shard| Mutex::new(self.lock(shard).clone())).collect();
+
+        Self { size: self.size, shards }
+    }
+}
+
+impl <CellVal: CellValue, const SHARD_SIZE: usize> PartialEq for SyncBuffer<CellVal, SHARD_SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        if std::ptr::eq(self, other) {
+            // Locking the same shard twice would deadlock
+            return true
+        }
+
+        self.size == other.size && (0..self.shards.len()).all(|shard| *self.lock(shard) == *other.lock(shard))
+    }
+}
+
+impl <CellVal: CellValue, const SHARD_SIZE: usize> Eq for SyncBuffer<CellVal, SHARD_SIZE> {}
+
+impl <CellVal: CellValue + Hash, const SHARD_SIZE: usize> Hash for SyncBuffer<CellVal, SHARD_SIZE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+
+        for shard in 0..self.shards.len() {
+            self.lock(shard).hash(state);
+        }
+    }
+}
+
+impl <CellVal: CellValue, const SHARD_SIZE: usize> Debug for SyncBuffer<CellVal, SHARD_SIZE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SyncBuffer(size = {}, shards = {})", self.size, self.shards.len())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,316 @@
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use crate::implm::buffer::VecBuffer;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, CellValue};
+
+/// A [buffer][MazeBuffer] that records changes to its cells in transactions, so they can be undone
+/// and redone.
+///
+/// Open a transaction with [`begin()`][Self::begin], make your changes through the coordinator as
+/// usual, then either [`commit()`][Self::commit] them as a single step that can be
+/// [undone][Self::undo], or [`rollback()`][Self::rollback] to discard them. This gives
+/// interactive maze editors undo and redo without any bookkeeping of their own. Reach the buffer
+/// through the coordinator's `buffer_mut()`.
+///
+/// Changes made outside of a transaction aren't recorded. As the recorded history no longer
+/// matches the maze after such a change, it is [cleared][Self::clear_history].
+///
+/// `UndoBuffer` wraps another buffer (a [`VecBuffer`] by default) which stores the cells.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::UndoBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// #
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<UndoBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+///
+/// maze.buffer_mut().begin();
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+/// maze.buffer_mut().commit();
+///
+/// assert!(maze.is_passage_between([0, 0].into(), [1, 0].into()));
+///
+/// maze.buffer_mut().undo();
+///
+/// assert!(maze.is_unvisited_between([0, 0].into(), [1, 0].into()));
+///
+/// maze.buffer_mut().redo();
+///
+/// assert!(maze.is_passage_between([0, 0].into(), [1, 0].into()));
+/// ```
+#[derive(Clone)]
+pub struct UndoBuffer<CellVal: CellValue, Buffer: MazeBuffer<CellVal> = VecBuffer<CellVal>> {
+    /// The buffer actually storing the cells.
+    inner: Buffer,
+    /// The recorded transactions.
+    history: RefCell<History<CellVal>>,
+    /// A cell handed out by [`MazeBuffer::get_mut()`] that may have changed since, along with its
+    /// value when it was handed out.
+    ///
+    /// Any change is recorded the next time the buffer is accessed.
+    pending: Cell<Option<(CellID, CellVal)>>,
+    _cell: PhantomData<CellVal>,
+}
+
+/// A change to a single cell: its ID, old value, and new value.
+type Change<CellVal> = (CellID, CellVal, CellVal);
+
+#[derive(Clone)]
+struct History<CellVal: CellValue> {
+    /// The changes made in the open transaction, if there is one.
+    transaction: Option<Vec<Change<CellVal>>>,
+    /// Committed transactions, oldest first.
+    undo: Vec<Vec<Change<CellVal>>>,
+    /// Undone transactions, most recently undone last.
+    redo: Vec<Vec<Change<CellVal>>>,
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal>> UndoBuffer<CellVal, Buffer> {
+    /// Wrap an existing buffer, with an empty history.
+    #[must_use]
+    pub fn wrap(inner: Buffer) -> Self {
+        Self { inner, history: RefCell::new(History { transaction: None, undo: Vec::new(), redo: Vec::new() }), pending: Cell::new(None), _cell: PhantomData }
+    }
+
+    /// Open a transaction. Changes will be recorded until it is [committed][Self::commit] or
+    /// [rolled back][Self::rollback].
+    ///
+    /// # Panics
+    ///
+    /// If a transaction is already open.
+    pub fn begin(&mut self) {
+        self.settle();
+
+        let history = self.history.get_mut();
+
+        assert!(history.transaction.is_none(), "A transaction is already open");
+
+        history.transaction = Some(Vec::new());
+    }
+
+    /// Close the open transaction, keeping its changes as a single step that can be
+    /// [undone][Self::undo].
+    ///
+    /// Returns whether the transaction changed anything. Transactions that didn't aren't kept.
+    /// Committing a transaction that changed something clears the redo history.
+    ///
+    /// # Panics
+    ///
+    /// If no transaction is open.
+    pub fn commit(&mut self) -> bool {
+        self.settle();
+
+        let history = self.history.get_mut();
+        let transaction = history.transaction.take().expect("No transaction is open");
+
+        if transaction.is_empty() {
+            return false
+        }
+
+        history.undo.push(transaction);
+        history.redo.clear();
+
+        return true
+    }
+
+    /// Close the open transaction, reverting all of its changes.
+    ///
+    /// # Panics
+    ///
+    /// If no transaction is open.
+    pub fn rollback(&mut self) {
+        self.settle();
+
+        let transaction = self.history.get_mut().transaction.take().expect("No transaction is open");
+
+        self.revert(&transaction);
+    }
+
+    /// Revert the most recently committed transaction, returning whether there was one.
+    ///
+    /// # Panics
+    ///
+    /// If a transaction is open.
+    pub fn undo(&mut self) -> bool {
+        self.settle();
+
+        assert!(self.history.get_mut().transaction.is_none(), "Transactions must be closed before undoing");
+
+        let Some(transaction) = self.history.get_mut().undo.pop() else { return false };
+
+        self.revert(&transaction);
+
+        self.history.get_mut().redo.push(transaction);
+
+        return true
+    }
+
+    /// Reapply the most recently undone transaction, returning whether there was one.
+    ///
+    /// # Panics
+    ///
+    /// If a transaction is open.
+    pub fn redo(&mut self) -> bool {
+        self.settle();
+
+        assert!(self.history.get_mut().transaction.is_none(), "Transactions must be closed before redoing");
+
+        let Some(transaction) = self.history.get_mut().redo.pop() else { return false };
+
+        for (cell, _, new) in transaction.iter() {
+            self.inner.set(*cell, *new);
+        }
+
+        self.history.get_mut().undo.push(transaction);
+
+        return true
+    }
+
+    /// Return whether a transaction is open.
+    #[must_use]
+    pub fn in_transaction(&self) -> bool {
+        self.history.borrow().transaction.is_some()
+    }
+
+    /// Return whether there is a transaction that can be [undone][Self::undo].
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        self.history.borrow().undo.is_empty() == false
+    }
+
+    /// Return whether there is a transaction that can be [redone][Self::redo].
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        self.history.borrow().redo.is_empty() == false
+    }
+
+    /// Forget every committed and undone transaction. The open transaction, if any, is kept.
+    pub fn clear_history(&mut self) {
+        let history = self.history.get_mut();
+
+        history.undo.clear();
+        history.redo.clear();
+    }
+
+    /// Return the wrapped buffer, discarding the history.
+    #[must_use]
+    pub fn into_inner(self) -> Buffer {
+        self.inner
+    }
+
+    /// Write back the old values of a transaction's changes, most recent first.
+    fn revert(&mut self, transaction: &[Change<CellVal>]) {
+        for (cell, old, _) in transaction.iter().rev() {
+            self.inner.set(*cell, *old);
+        }
+    }
+
+    /// Record any change to the pending cell.
+    fn settle(&self) {
+        if let Some((cell, old)) = self.pending.take() {
+            self.record(cell, old, self.inner.get(cell));
+        }
+    }
+
+    /// Record a change to `cell`, if the value changed.
+    fn record(&self, cell: CellID, old: CellVal, new: CellVal) {
+        if old == new {
+            return
+        }
+
+        let mut history = self.history.borrow_mut();
+
+        match history.transaction.as_mut() {
+            Some(transaction) => transaction.push((cell, old, new)),
+            None => {
+                history.undo.clear();
+                history.redo.clear();
+            }
+        }
+    }
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal>> MazeBuffer<CellVal> for UndoBuffer<CellVal, Buffer> {
+    fn new(cell_count: NonZeroUsize) -> Self {
+        Self::wrap(Buffer::new(cell_count))
+    }
+
+    fn get(&self, cell: CellID) -> CellVal {
+        self.settle();
+
+        self.inner.get(cell)
+    }
+
+    fn get_mut(&mut self, cell: CellID) -> &mut CellVal {
+        self.settle();
+
+        self.pending.set(Some((cell, self.inner.get(cell))));
+
+        return self.inner.get_mut(cell)
+    }
+
+    fn set(&mut self, cell: CellID, new_value: CellVal) {
+        self.settle();
+
+        self.record(cell, self.inner.get(cell), new_value);
+
+        self.inner.set(cell, new_value)
+    }
+
+    fn cell_count(&self) -> NonZeroUsize {
+        self.inner.cell_count()
+    }
+
+    fn get_slice(&self, cells: Range<CellID>) -> Cow<'_, [CellVal]> {
+        self.settle();
+
+        self.inner.get_slice(cells)
+    }
+
+    fn set_range(&mut self, cells: Range<CellID>, new_value: CellVal) {
+        self.settle();
+
+        for (cell, old_value) in (cells.start.0..cells.end.0).zip(self.inner.get_slice(cells.clone()).iter()) {
+            self.record(CellID(cell), *old_value, new_value);
+        }
+
+        self.inner.set_range(cells, new_value)
+    }
+}
+
+/// Buffers are equal if their cells are. The history is not compared.
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal> + PartialEq> PartialEq for UndoBuffer<CellVal, Buffer> {
+    fn eq(&self, other: &Self) -> bool {
+        self.settle();
+        other.settle();
+
+        self.inner == other.inner
+    }
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal> + Eq> Eq for UndoBuffer<CellVal, Buffer> {}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal> + Hash> Hash for UndoBuffer<CellVal, Buffer> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.settle();
+
+        self.inner.hash(state)
+    }
+}
+
+impl <CellVal: CellValue, Buffer: MazeBuffer<CellVal>> Debug for UndoBuffer<CellVal, Buffer> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UndoBuffer({:?})", self.inner)
+    }
+}
\ No newline at end of file
@@ -1,5 +1,7 @@
+use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
 use std::num::NonZeroUsize;
+use std::ops::Range;
 
 use crate::interface::buffer::MazeBuffer;
 use crate::interface::cell::{CellID, CellValue};
@@ -7,6 +9,7 @@ use crate::interface::cell::{CellID, CellValue};
 /// A [buffer][MazeBuffer] that stores its cells in a [`Vec`] allocated on the heap.
 ///
 /// This is the buffer implementation that you will almost always want to use.
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct VecBuffer<CellVal: CellValue> {
     buf: Vec<CellVal>,
 }
@@ -27,6 +30,23 @@ impl <CellVal: CellValue> MazeBuffer<CellVal> for VecBuffer<CellVal> {
     fn set(&mut self, cell: CellID, new_value: CellVal) {
         self.buf[cell.0] = new_value
     }
+
+    fn cell_count(&self) -> NonZeroUsize {
+        // The buffer is never resized, and is constructed with a non-zero length
+        NonZeroUsize::new(self.buf.len()).expect("[Bug] The buffer is never empty")
+    }
+
+    fn get_slice(&self, cells: Range<CellID>) -> Cow<'_, [CellVal]> {
+        Cow::Borrowed(&self.buf[cells.start.0..cells.end.0])
+    }
+
+    fn set_range(&mut self, cells: Range<CellID>, new_value: CellVal) {
+        self.buf[cells.start.0..cells.end.0].fill(new_value)
+    }
+
+    fn fill(&mut self, new_value: CellVal) {
+        self.buf.fill(new_value)
+    }
 }
 
 impl <CellVal: CellValue> Debug for VecBuffer<CellVal> {
@@ -13,7 +13,7 @@ use std::fmt::{Debug, Formatter};
 use std::ops::{Index, IndexMut};
 
 use crate::implm::point::boxy::CoordinateTuplet;
-use crate::interface::cell::{CellLocation, CellValue};
+use crate::interface::cell::{CellLocation, CellValue, ConnectionType, Terrain};
 
 /// [`CellLocation`] for block cells.
 ///
@@ -126,6 +126,14 @@ pub struct BlockCellValue {
     /// Whether this cell has been marked or flagged. This is a
     /// general-use field, with no specific meaning.
     pub marked: bool,
+
+    /// The vertical connector (staircase) in this cell, if any. For
+    /// more information see [`VerticalConnector`].
+    pub connector: VerticalConnector,
+
+    /// The kind of ground this cell is made of. For more information
+    /// see [`Terrain`].
+    pub terrain: Terrain,
 }
 
 /*
@@ -195,6 +203,58 @@ pub enum BlockCellPrimaryValue {
     PASSAGE,
 }
 
+/// The vertical connector (such as a staircase or ladder) in a [block cell][super], which links
+/// it to the cells directly above and/or below it.
+///
+/// Only three-dimensional mazes have vertical connectors. The third axis is vertical, and *up* is
+/// towards higher positions along it. Coordinators add connectors to every cell along a passage
+/// they carve between two layers, and remove them again when it's walled off. Exporters can then
+/// draw them as stairs, rather than as ordinary passages.
+///
+/// Connectors are purely descriptive. Whether two points are connected is still determined by
+/// the [primary value][BlockCellPrimaryValue] of the cells between them.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum VerticalConnector {
+    /// The cell is not connected vertically.
+    #[default]
+    NONE,
+
+    /// The cell is connected to the cell above it.
+    UP,
+
+    /// The cell is connected to the cell below it.
+    DOWN,
+
+    /// The cell is connected to the cells both above and below it.
+    UP_AND_DOWN,
+}
+
+impl VerticalConnector {
+    /// Construct the connector that goes in the given directions.
+    #[must_use]
+    pub fn from_directions(up: bool, down: bool) -> Self {
+        match (up, down) {
+            (false, false) => Self::NONE,
+            (true,  false) => Self::UP,
+            (false, true ) => Self::DOWN,
+            (true,  true ) => Self::UP_AND_DOWN,
+        }
+    }
+
+    /// Return whether the connector leads to the cell above.
+    #[must_use]
+    pub fn goes_up(&self) -> bool {
+        matches!(self, Self::UP | Self::UP_AND_DOWN)
+    }
+
+    /// Return whether the connector leads to the cell below.
+    #[must_use]
+    pub fn goes_down(&self) -> bool {
+        matches!(self, Self::DOWN | Self::UP_AND_DOWN)
+    }
+}
+
 impl CellValue for BlockCellValue {
     fn is_fully_visited(&self) -> bool {
         self.cell_type != BlockCellPrimaryValue::UNVISITED
@@ -207,4 +267,17 @@ impl CellValue for BlockCellValue {
     fn set_marked(&mut self, marked: bool) {
         self.marked = marked
     }
+
+    fn terrain(&self) -> Terrain {
+        self.terrain
+    }
+
+    fn state(&self) -> ConnectionType {
+        match self.cell_type {
+            BlockCellPrimaryValue::PASSAGE   => ConnectionType::PASSAGE,
+            BlockCellPrimaryValue::WALL      => ConnectionType::WALL,
+            BlockCellPrimaryValue::BOUNDARY  => ConnectionType::BOUNDARY,
+            BlockCellPrimaryValue::UNVISITED => ConnectionType::UNVISITED,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,24 @@
+//! Cube cells are [edge cells][super::edge] for mazes on the surface of a cube.
+//!
+//! Each cube cell stores whether there is a wall on each of its four edges, indexed by
+//! [`CubeEdge`]. The edges are relative to the cell's own face, as faces are rotated relative to
+//! each other.
+
+use crate::implm::cell::edge::{CellEdge, EdgeCellLocation, EdgeCellValue};
+use crate::implm::point::cube::{CubeCoordinate, CubeEdge};
+
+/// [`CellLocation`][crate::interface::cell::CellLocation] for cube cells.
+///
+/// *See also: [`CubeCellValue`]*
+pub type CubeCellLocation = EdgeCellLocation<CubeCoordinate>;
+
+/// A square cell type where each cell tracks the state of its own four edges.
+///
+/// (i.e. whether an edge is a wall or a passage)
+pub type CubeCellValue = EdgeCellValue<CubeEdge, 4>;
+
+impl CellEdge for CubeEdge {
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+//! Delta cells are triangular [edge cells][super::edge] for delta mazes.
+//!
+//! Each delta cell stores whether there is a wall on each of its three edges, indexed by
+//! [`DeltaEdge`].
+
+use crate::implm::cell::edge::{CellEdge, EdgeCellLocation, EdgeCellValue};
+use crate::implm::point::delta::{DeltaCoordinate, DeltaEdge};
+
+/// [`CellLocation`][crate::interface::cell::CellLocation] for delta cells.
+///
+/// *See also: [`DeltaCellValue`]*
+pub type DeltaCellLocation = EdgeCellLocation<DeltaCoordinate>;
+
+/// A triangular cell type where each cell tracks the state of its own three edges.
+///
+/// (i.e. whether an edge is a wall or a passage)
+pub type DeltaCellValue = EdgeCellValue<DeltaEdge, 3>;
+
+impl CellEdge for DeltaEdge {
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
\ No newline at end of file
@@ -0,0 +1,174 @@
+//! Edge cells are [inline cells][super::inline] for coordinate spaces that aren't boxes.
+//!
+//! Like inline cells, each edge cell stores whether there is a wall on each of its edges. Rather
+//! than having two edges per axis, it has one edge per neighbour, identified by the space's own
+//! edge (or direction) type. [Hex cells][super::hex], [delta cells][super::delta],
+//! [cube cells][super::cube], [upsilon cells][super::upsilon], and [polar cells][super::polar]
+//! are all edge cells.
+
+use std::fmt::{Debug, Formatter};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::point::boxy::BoxDirection;
+use crate::interface::cell::{CellLocation, CellValue};
+use crate::interface::point::Point;
+
+/// [`CellLocation`] for edge cells.
+///
+/// *See also: [`EdgeCellValue`]*
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EdgeCellLocation<Pt: Point>(pub Pt);
+
+impl <Pt: Point> CellLocation for EdgeCellLocation<Pt> {}
+
+impl <Pt: Point> Debug for EdgeCellLocation<Pt> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cl{:?}", self.0)
+    }
+}
+
+impl <Pt: Point> From<Pt> for EdgeCellLocation<Pt> {
+    fn from(pt: Pt) -> Self {
+        Self(pt)
+    }
+}
+
+/// An edge of an [`EdgeCellValue`].
+pub trait CellEdge: Copy + PartialEq + Eq + Hash + Send + Sync + Debug {
+    /// Return the position of this edge in [`EdgeCellValue::edges`].
+    #[must_use]
+    fn index(&self) -> usize;
+}
+
+/// A cell type where each cell tracks the state of its own `N` edges, of type `E`.
+///
+/// (i.e. whether an edge is a wall or a passage)
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EdgeCellValue<E: CellEdge, const N: usize> {
+    /// The connection type or edge type between this cell and its respective neighbour. This is
+    /// purely from this cell's perspective, and does not account for the neighbour's
+    /// corresponding edge type.
+    ///
+    /// The edges are indexed by [`CellEdge::index()`]. You may find [`get_edge()`][Self::get_edge]
+    /// and [`set_edge()`][Self::set_edge] more convenient.
+    pub edges: [InlineCellValueEdge; N],
+
+    /// Whether this cell has been marked or flagged. This is a general-use field, with no specific
+    /// meaning.
+    pub marked: bool,
+
+    _edge: PhantomData<E>,
+}
+
+impl <E: CellEdge, const N: usize> EdgeCellValue<E, N> {
+    /// Construct a new, unmarked cell with the given edges.
+    #[must_use]
+    pub fn new(edges: [InlineCellValueEdge; N]) -> Self {
+        Self { edges, marked: false, _edge: PhantomData }
+    }
+
+    /// Return the type of the given edge.
+    #[must_use]
+    pub fn get_edge(&self, edge: E) -> InlineCellValueEdge {
+        self.edges[edge.index()]
+    }
+
+    /// Set the type of the given edge.
+    pub fn set_edge(&mut self, edge: E, edge_type: InlineCellValueEdge) {
+        self.edges[edge.index()] = edge_type;
+    }
+}
+
+impl <E: CellEdge, const N: usize> CellValue for EdgeCellValue<E, N> {
+    fn is_fully_visited(&self) -> bool {
+        self.edges.into_iter().all(|edge| edge != InlineCellValueEdge::UNVISITED)
+    }
+
+    fn is_marked(&self) -> bool {
+        self.marked
+    }
+
+    fn set_marked(&mut self, marked: bool) {
+        self.marked = marked
+    }
+}
+
+impl <E: CellEdge, const N: usize> Default for EdgeCellValue<E, N> {
+    fn default() -> Self {
+        Self::new([InlineCellValueEdge::UNVISITED; N])
+    }
+}
+
+// Not derived, so as to leave out the marker
+impl <E: CellEdge, const N: usize> Debug for EdgeCellValue<E, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EdgeCellValue").field("edges", &self.edges).field("marked", &self.marked).finish()
+    }
+}
+
+/// A cell value that stores the type of each of its edges individually, such as an
+/// [`EdgeCellValue`] or an [`InlineCellValue`].
+///
+/// This is what lets an
+/// [`EdgeCellMazeCoordinator`][crate::implm::coordinate::edge::EdgeCellMazeCoordinator] carve
+/// passages between cells.
+pub trait EdgeCell: CellValue {
+    /// The type identifying each edge of the cell.
+    type Edge: Copy;
+
+    /// Return the type of the given edge.
+    #[must_use]
+    fn get_edge(&self, edge: Self::Edge) -> InlineCellValueEdge;
+
+    /// Set the type of the given edge.
+    fn set_edge(&mut self, edge: Self::Edge, edge_type: InlineCellValueEdge);
+
+    /// Return an iterator over the type of every edge.
+    fn edges(&self) -> impl Iterator<Item = InlineCellValueEdge>;
+
+    /// Return an iterator over every edge mutably.
+    fn edges_mut(&mut self) -> impl Iterator<Item = &mut InlineCellValueEdge>;
+}
+
+impl <E: CellEdge, const N: usize> EdgeCell for EdgeCellValue<E, N> {
+    type Edge = E;
+
+    fn get_edge(&self, edge: E) -> InlineCellValueEdge {
+        self.get_edge(edge)
+    }
+
+    fn set_edge(&mut self, edge: E, edge_type: InlineCellValueEdge) {
+        self.set_edge(edge, edge_type)
+    }
+
+    fn edges(&self) -> impl Iterator<Item = InlineCellValueEdge> {
+        self.edges.into_iter()
+    }
+
+    fn edges_mut(&mut self) -> impl Iterator<Item = &mut InlineCellValueEdge> {
+        self.edges.iter_mut()
+    }
+}
+
+/// The edges of an inline cell are identified by the direction they face.
+impl <const DIMENSION: usize> EdgeCell for InlineCellValue<DIMENSION> {
+    type Edge = BoxDirection<DIMENSION>;
+
+    fn get_edge(&self, edge: Self::Edge) -> InlineCellValueEdge {
+        self.edges[edge.axis()][usize::from(edge.is_positive())]
+    }
+
+    fn set_edge(&mut self, edge: Self::Edge, edge_type: InlineCellValueEdge) {
+        self.edges[edge.axis()][usize::from(edge.is_positive())] = edge_type;
+    }
+
+    fn edges(&self) -> impl Iterator<Item = InlineCellValueEdge> {
+        self.edges.into_iter().flatten()
+    }
+
+    fn edges_mut(&mut self) -> impl Iterator<Item = &mut InlineCellValueEdge> {
+        self.edges.iter_mut().flatten()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+//! Graph cells are [block cells][super::block] for arbitrary graphs.
+//!
+//! Every node of the graph and every edge of the graph is its own cell, which is either a passage,
+//! a wall, a boundary, or unvisited. As with block cells, two points are only connected if both
+//! of them and the edge between them are passages. Graph cells therefore reuse
+//! [`BlockCellValue`][super::block::BlockCellValue] for their values.
+
+use std::fmt::{Debug, Formatter};
+
+use crate::implm::point::graph::GraphPoint;
+use crate::interface::cell::CellLocation;
+
+/// [`CellLocation`] for graph cells.
+///
+/// *See also: [`BlockCellValue`][super::block::BlockCellValue]*
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GraphCellLocation {
+    /// The cell of the node with this index.
+    NODE(usize),
+    /// The cell of the edge with this index.
+    EDGE(usize),
+}
+
+impl CellLocation for GraphCellLocation {}
+
+impl Debug for GraphCellLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NODE(node) => write!(f, "cl(n{})", node),
+            Self::EDGE(edge) => write!(f, "cl(e{})", edge),
+        }
+    }
+}
+
+impl From<GraphPoint> for GraphCellLocation {
+    fn from(pt: GraphPoint) -> Self {
+        Self::NODE(pt.0)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+//! Hex cells are hexagonal [edge cells][super::edge] for sigma mazes.
+//!
+//! Each hex cell stores whether there is a wall on each of its six edges, indexed by
+//! [`HexDirection`] (clockwise from north).
+
+use crate::implm::cell::edge::{CellEdge, EdgeCellLocation, EdgeCellValue};
+use crate::implm::point::hex::{HexCoordinate, HexDirection};
+
+/// [`CellLocation`][crate::interface::cell::CellLocation] for hex cells.
+///
+/// *See also: [`HexCellValue`]*
+pub type HexCellLocation = EdgeCellLocation<HexCoordinate>;
+
+/// A hexagonal cell type where each cell tracks the state of its own six edges.
+///
+/// (i.e. whether an edge is a wall or a passage)
+pub type HexCellValue = EdgeCellValue<HexDirection, 6>;
+
+impl CellEdge for HexDirection {
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
\ No newline at end of file
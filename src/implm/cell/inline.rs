@@ -13,7 +13,7 @@ use std::fmt::{Debug, Formatter};
 use std::ops::{Index, IndexMut};
 
 use crate::implm::point::boxy::CoordinateTuplet;
-use crate::interface::cell::{CellLocation, CellValue};
+use crate::interface::cell::{CellLocation, CellValue, ConnectionType, Terrain};
 
 /// [`CellLocation`] for inline cells.
 ///
@@ -132,6 +132,23 @@ pub struct InlineCellValue<const DIMENSION: usize> {
     /// Whether this cell has been marked or flagged. This is a
     /// general-use field, with no specific meaning.
     pub marked: bool,
+
+    /// The axis along which a tunnel passes beneath this cell, if
+    /// there is one.
+    ///
+    /// Tunnels are used to make *weave* mazes, where passages cross
+    /// over and under each other. A tunnel joins the two neighbours
+    /// of this cell along its axis, without entering this cell. This
+    /// cell's own edges along that axis remain walls.
+    ///
+    /// # See Also
+    ///
+    /// [`BoxSpaceInlineCellMazeCoordinator::make_tunnel_between()`][crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator::make_tunnel_between]
+    pub tunnel: Option<usize>,
+
+    /// The kind of ground this cell is made of. For more information
+    /// see [`Terrain`].
+    pub terrain: Terrain,
 }
 
 /// The types of edges that an [inline cell][super] can have.
@@ -219,11 +236,31 @@ impl <const DIMENSION: usize> CellValue for InlineCellValue<DIMENSION> {
     fn set_marked(&mut self, marked: bool) {
         self.marked = marked
     }
+
+    fn terrain(&self) -> Terrain {
+        self.terrain
+    }
+
+    /// Points that have not been fully visited are unvisited, points surrounded by boundaries are
+    /// boundaries, and points surrounded by walls are walls. All others are passages.
+    fn state(&self) -> ConnectionType {
+        let mut edges = self.edges.into_iter().flatten();
+
+        if self.is_fully_visited() == false {
+            ConnectionType::UNVISITED
+        } else if edges.clone().all(|edge| edge == InlineCellValueEdge::BOUNDARY) {
+            ConnectionType::BOUNDARY
+        } else if edges.any(|edge| edge == InlineCellValueEdge::PASSAGE) {
+            ConnectionType::PASSAGE
+        } else {
+            ConnectionType::WALL
+        }
+    }
 }
 
 impl <const DIMENSION: usize> Default for InlineCellValue<DIMENSION> {
     #[must_use]
     fn default() -> Self {
-        Self { edges: [[InlineCellValueEdge::UNVISITED; 2]; DIMENSION], marked: false }
+        Self { edges: [[InlineCellValueEdge::UNVISITED; 2]; DIMENSION], marked: false, tunnel: None, terrain: Terrain::DEFAULT }
     }
 }
\ No newline at end of file
@@ -10,7 +10,32 @@
 //! 
 //! [Inline cell][self::inline]:
 //! TODO
+//!
+//! [Hex cell][self::hex]:
+//! TODO
+//!
+//! [Delta cell][self::delta]:
+//! TODO
+//!
+//! [Polar cell][self::polar]:
+//! TODO
+//!
+//! [Graph cell][self::graph]:
+//! TODO
+//!
+//! [Cube cell][self::cube]:
+//! TODO
+//!
+//! [Upsilon cell][self::upsilon]:
+//! TODO
 #![doc = embed_doc_image::embed_image!("box-space-block-cell-coordinator-example", "src/doc/img/coordinate/box-space-block-cell/example-large.png")]
 
 pub mod block;
-pub mod inline;
\ No newline at end of file
+pub mod cube;
+pub mod delta;
+pub mod edge;
+pub mod graph;
+pub mod hex;
+pub mod inline;
+pub mod polar;
+pub mod upsilon;
\ No newline at end of file
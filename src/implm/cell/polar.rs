@@ -0,0 +1,25 @@
+//! Polar cells are [edge cells][super::edge] for polar (theta) mazes.
+//!
+//! Each polar cell stores whether there is a wall on each of its edges, indexed by [`PolarEdge`].
+//! This includes a second outward edge for when the ring outside it is subdivided. Edges that
+//! don't lead anywhere (such as [`PolarEdge::OUTWARD_CLOCKWISE`] when the next ring isn't
+//! subdivided) are still tracked, they just never become passages.
+
+use crate::implm::cell::edge::{CellEdge, EdgeCellLocation, EdgeCellValue};
+use crate::implm::point::polar::{PolarCoordinate, PolarEdge};
+
+/// [`CellLocation`][crate::interface::cell::CellLocation] for polar cells.
+///
+/// *See also: [`PolarCellValue`]*
+pub type PolarCellLocation = EdgeCellLocation<PolarCoordinate>;
+
+/// A polar cell type where each cell tracks the state of its own edges.
+///
+/// (i.e. whether an edge is a wall or a passage)
+pub type PolarCellValue = EdgeCellValue<PolarEdge, 5>;
+
+impl CellEdge for PolarEdge {
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
\ No newline at end of file
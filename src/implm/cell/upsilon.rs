@@ -0,0 +1,25 @@
+//! Upsilon cells are [edge cells][super::edge] for upsilon mazes.
+//!
+//! Each upsilon cell stores whether there is a wall on each of its edges, indexed by
+//! [`UpsilonEdge`]. Every cell has room for the eight edges of an octagon, but square cells only
+//! use four of them. Square cells don't have diagonal edges, so they are always
+//! [`InlineCellValueEdge::BOUNDARY`][crate::implm::cell::inline::InlineCellValueEdge::BOUNDARY].
+
+use crate::implm::cell::edge::{CellEdge, EdgeCellLocation, EdgeCellValue};
+use crate::implm::point::upsilon::{UpsilonCoordinate, UpsilonEdge};
+
+/// [`CellLocation`][crate::interface::cell::CellLocation] for upsilon cells.
+///
+/// *See also: [`UpsilonCellValue`]*
+pub type UpsilonCellLocation = EdgeCellLocation<UpsilonCoordinate>;
+
+/// An octagonal or square cell type where each cell tracks the state of its own edges.
+///
+/// (i.e. whether an edge is a wall or a passage)
+pub type UpsilonCellValue = EdgeCellValue<UpsilonEdge, 8>;
+
+impl CellEdge for UpsilonEdge {
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
\ No newline at end of file
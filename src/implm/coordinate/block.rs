@@ -10,24 +10,27 @@
 //! ![A pixellated-looking maze, where every cell is one pixel][box-space-block-cell-coordinator-example]
 #![doc = embed_doc_image::embed_image!("box-space-block-cell-coordinator-example", "src/doc/img/coordinate/box-space-block-cell/example-large.png")]
 
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
 use std::num::NonZeroUsize;
+use std::ops::Range;
 use embed_doc_image::embed_doc_image;
 
-use crate::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue};
+use crate::error::MazeError;
+use crate::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue, VerticalConnector};
 use crate::implm::cell::block::BlockCellLocation;
 use crate::implm::cell::block::BlockCellPrimaryValue::{BOUNDARY, PASSAGE, UNVISITED, WALL};
-use crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
-use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::export::text::{BoxSpaceBlockCellTextMazeExporter, ExportToString};
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
 use crate::interface::buffer::MazeBuffer;
 use crate::interface::cell::{CellID, ConnectionType};
 use crate::interface::coordinate::MazeCoordinator;
-use crate::interface::export::MazeExporter;
 use crate::interface::point::CoordinateSpace;
 use crate::internal::array_util::{ArrayZipMap, CheckedProduct, CheckedSum};
 use crate::internal::noise_util::pt;
-use crate::internal::util::{NONZERO_USIZE_TWO, try_usize_array_to_nonzero_usize_array};
+use crate::internal::util::NONZERO_USIZE_ONE;
+use crate::mark::Marks;
+use crate::path::{CellPath, Path};
 
 /// A maze coordinator that maps a box-like coordinate space to box-like cells.
 /// 
@@ -37,12 +40,27 @@ use crate::internal::util::{NONZERO_USIZE_TWO, try_usize_array_to_nonzero_usize_
 /// 
 /// Every point is mapped to a box of cells (a square, cube, etc.). The dimensionality of the box is
 /// the same as the coordinate space, so for a 2D maze they would be squares. Every box is exactly
-/// the same size. The width, height, depth, etc. of these boxes is controlled by the coordinator's
-/// *scale factors*. Every scale factor must be at least 1 (so every point maps to at least one
-/// cell). Additionally, there may be some *padding* cells. These exist on the edge of the cell
+/// the same size by default. The width, height, depth, etc. of these boxes is controlled by the
+/// coordinator's *scale factors*. Every scale factor must be at least 1 (so every point maps to at least one
+/// cell). Individual gaps between points can also be given their own
+/// [spacing][BoxSpaceBlockCellMazeCoordinatorBuilder::spacing], so that some rows or columns are
+/// further apart than others. Additionally, there may be some *padding* cells. These exist on the edge of the cell
 /// space and are not mapped by any point. Each edge may have zero or more padding cells, and
 /// different edges can have different amounts. They're useful for adding borders to mazes.
 /// 
+/// If the coordinate space [wraps around][BoxCoordinateSpace#wrapping-adjacency] an axis, passages
+/// between the first and last points along it are carved through the padding on both sides. These
+/// show up as matching openings in opposite borders.
+/// 
+/// If the coordinate space has [diagonal adjacency][BoxCoordinateSpace#diagonal-adjacency],
+/// diagonal passages are carved through the cells at the corners between points. By default, only
+/// one of the two diagonals at each corner may be carved, and the other is reported as a
+/// [boundary][ConnectionType::BOUNDARY] once it has been. This can be changed with [`diagonals_may_cross()`][BoxSpaceBlockCellMazeCoordinatorBuilder::diagonals_may_cross].
+/// 
+/// In three-dimensional mazes, the third axis is vertical. Passages between layers are marked with
+/// [vertical connectors][VerticalConnector] (staircases), so that exporters can draw them
+/// differently from ordinary passages.
+/// 
 /// TODO insert annotated diagram
 ///
 /// # Examples
@@ -69,6 +87,7 @@ use crate::internal::util::{NONZERO_USIZE_TWO, try_usize_array_to_nonzero_usize_
 /// 
 /// TODO add image showing result
 #[embed_doc_image("box-space-block-cell-coordinator-example", "src/doc/img/coordinate/box-space-block-cell/example-large.png")]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct BoxSpaceBlockCellMazeCoordinator<Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> {
     /// The maze buffer the maze is stored in.
     buffer: Buffer,
@@ -78,6 +97,10 @@ pub struct BoxSpaceBlockCellMazeCoordinator<Buffer: MazeBuffer<BlockCellValue>,
     /// 
     /// The scale factors are ordered from most minor axis to most major.
     scale_factors: [NonZeroUsize; DIMENSION],
+    /// The position of each point along each axis of the cell space, indexed by the point's
+    /// position along that axis. Derived from the scale factors, spacing, and padding. Cached for
+    /// performance.
+    point_positions: [Vec<usize>; DIMENSION],
     /// The number of cells on the edge of the maze that are not mapped to any point, for each
     /// direction.
     ///
@@ -88,11 +111,18 @@ pub struct BoxSpaceBlockCellMazeCoordinator<Buffer: MazeBuffer<BlockCellValue>,
     /// The dimensions of the cell space, derived from the coordinate space's dimensions, scaled,
     /// and padded. Cached for performance.
     full_dimensions: [NonZeroUsize; DIMENSION],
+    /// The difference between the IDs of two cells that are adjacent along each axis. Derived from
+    /// the full dimensions. Cached for performance.
+    strides: [usize; DIMENSION],
+    /// Whether diagonal passages may cross each other.
+    diagonals_may_cross: bool,
+    /// The maze's named points, annotations, and portals.
+    marks: Marks<CoordinateTuplet<DIMENSION>>,
 }
 
 // Constructor (private - use the builder)
 impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
-    /// Construct a new maze from a given coordinate space, scale factor, and padding.
+    /// Construct a new maze from a given coordinate space, scale factor, spacing, and padding.
     ///
     /// # Parameters
     ///
@@ -102,6 +132,8 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
     ///                     for every point (a 3:1 ratio). Each axis is scaled independently.
     ///                     The ordering of the scale factors is the same as the standard ordering
     ///                     of the coordinate axes.
+    /// `spacing`       --- the number of cells between each pair of consecutive points, for
+    ///                     each axis. Axes that are `None` use their scale factor for every gap.
     /// `padding`       --- How many extra cells to place between edge-adjacent points
     ///                     and the edge of the maze. For example, a value of 1 will yield
     ///                     a 1-cell thick "border" along that edge of the maze. The ordering
@@ -109,29 +141,60 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
     ///                     the coordinate axes. For the inner elements, the side closest
     ///                     to zero comes before the side furthest from zero.
     ///
-    /// # Type Parameter
-    ///
-    /// `Buffer` --- the type of buffer to use. A buffer instance will be automatically
-    ///              constructed from this type.
-    #[must_use]
-    fn new(space: BoxCoordinateSpace<DIMENSION>, scale_factors: [NonZeroUsize; DIMENSION], padding: [[usize; 2]; DIMENSION]) -> Self {
-        // Arithmetic is so easy and beautiful and succinct
-        let full_dimensions = space.dimensions()
-            .zip_map(&scale_factors, |dim, scale_factor| {
-                // NonZeroUsize::new only returns None if the scaled dimension == usize::MAX (which I don't
-                // think is mathematically even possible, but in such a hypothetical case) the +1 would cause
-                // the sum to overflow to zero
-                (usize::from(*dim) - 1).checked_mul(usize::from(*scale_factor))
-                    .and_then(|scaled_dim| NonZeroUsize::new(scaled_dim + 1))
-                    .expect("The scaled dimensions do not all fit within a usize")
-            })
-            .zip_map(&padding, |scaled_dim, padding| {
-                padding.checked_sum().and_then(|summed_padding| scaled_dim.checked_add(summed_padding)).expect("The full dimensions do not all fit within a usize")
-            });
+    /// `make_buffer`   --- constructs the buffer to store the maze in, given the number of cells
+    ///                     required. It is only called if the layout is valid.
+    fn try_new(space: BoxCoordinateSpace<DIMENSION>, scale_factors: [NonZeroUsize; DIMENSION], spacing: &[Option<Vec<NonZeroUsize>>; DIMENSION], padding: [[usize; 2]; DIMENSION], diagonals_may_cross: bool, make_buffer: impl FnOnce(NonZeroUsize) -> Buffer) -> Result<Self, MazeError> {
+        let (point_positions, full_dimensions) = Self::layout(space, scale_factors, spacing, padding)?;
+
+        let cells_required = full_dimensions.checked_product().ok_or(MazeError::TooManyCells)?;
 
-        let cells_required = full_dimensions.checked_product().expect("The full dimensions specified are too large. The number of cells in the maze does not fit within a usize.");
+        // The product of all the full dimensions fits, so every partial product does too
+        let mut strides = [1; DIMENSION];
 
-        Self { buffer: Buffer::new(cells_required), space, scale_factors, full_dimensions, padding }
+        for i in 1..DIMENSION {
+            strides[i] = strides[i - 1] * usize::from(full_dimensions[i - 1]);
+        }
+
+        return Ok(Self { buffer: make_buffer(cells_required), space, scale_factors, point_positions, full_dimensions, strides, padding, diagonals_may_cross, marks: Marks::new() })
+    }
+
+    /// Compute the position of each point along each axis, and the full dimensions of the cell
+    /// space. See [`Self::try_new()`] for the parameters.
+    fn layout(space: BoxCoordinateSpace<DIMENSION>, scale_factors: [NonZeroUsize; DIMENSION], spacing: &[Option<Vec<NonZeroUsize>>; DIMENSION], padding: [[usize; 2]; DIMENSION]) -> Result<([Vec<usize>; DIMENSION], [NonZeroUsize; DIMENSION]), MazeError> {
+        // The position of each point along each axis, before padding
+        let scaled_positions: [Vec<usize>; DIMENSION] = std::array::from_fn(|axis| {
+            let point_count = usize::from(space.dimensions()[axis]);
+
+            let gaps = spacing[axis].clone().unwrap_or_else(|| vec![scale_factors[axis]; point_count - 1]);
+
+            let mut positions = Vec::with_capacity(point_count);
+            let mut position = 0usize;
+
+            positions.push(position);
+
+            for gap in gaps {
+                position = position.checked_add(usize::from(gap)).ok_or(MazeError::ScaledDimensionsTooLarge { axis })?;
+                positions.push(position);
+            }
+
+            Ok(positions)
+        }).try_map(|positions| positions)?;
+
+        // NonZeroUsize::new only returns None if the scaled dimension == usize::MAX, as the +1
+        // would cause the sum to overflow to zero
+        let full_dimensions: [NonZeroUsize; DIMENSION] = std::array::from_fn(|axis| {
+            let scaled_dim = scaled_positions[axis].last().expect("[Bug] Every axis has at least one point")
+                .checked_add(1)
+                .and_then(NonZeroUsize::new)
+                .ok_or(MazeError::ScaledDimensionsTooLarge { axis })?;
+
+            padding[axis].checked_sum().and_then(|summed_padding| scaled_dim.checked_add(summed_padding)).ok_or(MazeError::FullDimensionsTooLarge { axis })
+        }).try_map(|full_dimension| full_dimension)?;
+
+        // Points are always within the full dimensions, so this can't overflow
+        let point_positions = std::array::from_fn(|axis| scaled_positions[axis].iter().map(|position| position + padding[axis][0]).collect());
+
+        return Ok((point_positions, full_dimensions))
     }
 }
 
@@ -142,6 +205,12 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
         &self.buffer
     }
 
+    /// Return the maze's buffer mutably. *See [Buffers][MazeCoordinator#buffers].*
+    #[must_use]
+    pub fn buffer_mut(&mut self) -> &mut Buffer {
+        &mut self.buffer
+    }
+
     /// The dimensions of the coordinate space, scaled by the scale factors, plus padding.
     #[must_use]
     pub fn get_full_dimensions(&self) -> [NonZeroUsize; DIMENSION] {
@@ -155,11 +224,42 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
     ///
     /// Does not affect the distance of points from the outer edge of the maze (see
     /// [`padding()`][Self::padding]).
+    ///
+    /// Axes with [variable spacing][BoxSpaceBlockCellMazeCoordinatorBuilder::spacing] ignore their
+    /// scale factor. Use [`spacing()`][Self::spacing] to get the actual distance between points.
     #[must_use]
     pub fn scale_factors(&self) -> [NonZeroUsize; DIMENSION] {
         self.scale_factors
     }
 
+    /// The number of cells between each pair of consecutive points along `axis`.
+    ///
+    /// The first entry is the distance between the first and second points, and so on, so there is
+    /// one fewer entry than there are points along the axis. If the axis doesn't have
+    /// [variable spacing][BoxSpaceBlockCellMazeCoordinatorBuilder::spacing], every entry is the
+    /// axis's [scale factor][Self::scale_factors].
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is not less than `DIMENSION`.
+    #[must_use]
+    pub fn spacing(&self, axis: usize) -> Vec<NonZeroUsize> {
+        assert!(axis < DIMENSION, "The axis must be less than the dimension");
+
+        self.point_positions[axis].windows(2)
+            .map(|pair| NonZeroUsize::new(pair[1] - pair[0]).expect("[Bug] Two points were mapped to the same cell"))
+            .collect()
+    }
+
+    /// Return whether every axis has the same spacing between all of its points, equal to its
+    /// [scale factor][Self::scale_factors].
+    ///
+    /// Formats that can only record scale factors can't represent mazes for which this is false.
+    #[must_use]
+    pub fn is_uniformly_spaced(&self) -> bool {
+        (0..DIMENSION).all(|axis| self.spacing(axis).iter().all(|gap| *gap == self.scale_factors[axis]))
+    }
+
     /// The number of cells between the edge of the maze and the outermost cell that is mapped to
     /// a point. Useful for borders when the scale factor is greater than one.
     #[must_use]
@@ -167,19 +267,125 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
         self.padding
     }
 
+    /// Return whether diagonal passages may cross each other.
+    #[must_use]
+    pub fn diagonals_may_cross(&self) -> bool {
+        self.diagonals_may_cross
+    }
+
+    /// The difference between the [IDs][CellID] of two cells that are adjacent along each axis.
+    ///
+    /// Cells are stored with the first axis varying fastest, so the first stride is always 1, the
+    /// second is the full width of the maze, and so on.
+    #[must_use]
+    pub fn strides(&self) -> [usize; DIMENSION] {
+        self.strides
+    }
+
     /// Map a point to a cell location.
     #[must_use]
+    #[inline]
     pub fn map_pt_to_cell_loc(&self, pt: pt!()) -> <Self as MazeCoordinator>::CellLoc {
         let mut pt: [usize; DIMENSION] = pt.into();
 
         for i in 0..DIMENSION {
-            pt[i] *= usize::from(self.scale_factors[i]);
-            pt[i] += self.padding[i][0];
+            pt[i] = self.point_positions[i][pt[i]];
         }
 
         BlockCellLocation(pt.into())
     }
 
+    /// Map a point directly to the [ID][CellID] of its cell in the [buffer][Self::buffer].
+    ///
+    /// This is equivalent to mapping the point to a cell location and then to an ID, but is
+    /// faster as it skips building the location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::buffer::MazeBuffer;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// #
+    /// let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3]))
+    ///     .scale_factors_checked([2, 2])
+    ///     .padding([[1, 1], [1, 1]])
+    ///     .build();
+    ///
+    /// // The full dimensions are 7×7, and [1, 2] is at cell (3, 5)
+    /// assert_eq!([1, 7], maze.strides());
+    /// assert_eq!(3 + 5 * 7, maze.map_pt_to_cell_id([1, 2].into()).0);
+    /// assert_eq!(maze.get([1, 2].into()), maze.buffer().get(maze.map_pt_to_cell_id([1, 2].into())));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn map_pt_to_cell_id(&self, pt: pt!()) -> CellID {
+        CellID((0..DIMENSION).map(|i| self.point_positions[i][pt[i]] * self.strides[i]).sum())
+    }
+
+    /// Map a path of points to the path of cells it runs through, including the cells between
+    /// each point.
+    ///
+    /// This is for drawing a route (such as a solution) at the resolution of the cells, without
+    /// having to know about scale factors, spacing, or padding. Each step between two adjacent
+    /// points passes through the same cells that
+    /// [`make_passage_between()`][MazeCoordinator::make_passage_between] would carve, including
+    /// out through the padding and back in when the points are adjacent by wrapping around. A step
+    /// through a [portal][MazeCoordinator::portals] jumps straight to the next point's cell.
+    ///
+    /// # Panics
+    ///
+    /// If two consecutive points are neither adjacent nor joined by a portal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::{BlockCellLocation, BlockCellValue};
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// use mazelib::path::Path;
+    ///
+    /// let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3]))
+    ///     .scale_factors_checked([2, 2])
+    ///     .padding([[1, 1], [1, 1]])
+    ///     .build();
+    ///
+    /// let path = Path::from_vec(vec![[0, 0].into(), [1, 0].into(), [1, 1].into()]);
+    ///
+    /// let cells = maze.map_path_to_cell_path(&path);
+    ///
+    /// let expected: [BlockCellLocation<2>; 5] = [[1, 1].into(), [2, 1].into(), [3, 1].into(), [3, 2].into(), [3, 3].into()];
+    ///
+    /// assert_eq!(expected, cells.0[..]);
+    /// ```
+    #[must_use]
+    pub fn map_path_to_cell_path(&self, path: &Path<pt!()>) -> CellPath<<Self as MazeCoordinator>::CellLoc> {
+        let pts = &path[..];
+
+        let mut cells = Path::starting_at(self.map_pt_to_cell_loc(pts[0]));
+
+        for step in pts.windows(2) {
+            let [from, to] = [step[0], step[1]];
+
+            if self.space.are_adjacent(from, to) {
+                for cell in self.get_cells_between(from, to).into_iter().skip(1) {
+                    cells.push(cell);
+                }
+            } else {
+                assert!(self.marks.portals.contains(from, to), "{:?} and {:?} are neither adjacent nor joined by a portal", from, to);
+
+                cells.push(self.map_pt_to_cell_loc(to));
+            }
+        }
+
+        return CellPath(cells)
+    }
+
     /// Get the value of the point `pt` for mutation.
     ///
     /// # See Also
@@ -187,7 +393,7 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
     /// [`get()`][Self::get] for non-mutable borrowing.
     #[must_use]
     pub fn get_mut(&mut self, pt: pt!()) -> &mut <Self as MazeCoordinator>::CellVal {
-        self.get_cell_value_mut(self.map_pt_to_cell_loc(pt))
+        self.buffer.get_mut(self.map_pt_to_cell_id(pt))
     }
 
     /// Sugar for
@@ -269,57 +475,209 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
 
 // Internal functions
 impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
-    //noinspection RsSelfConvention
-    /// Get the axis in which two points are adjacent.
+    /// Get every cell on the straight line from `from` to `to`, including both ends, in order.
     ///
-    /// The number represents the order of the axis (e.g.
-    /// x is 0, y is 1, etc.). This is the same as in the index
-    /// of the axis for the point (`pt[index]` gives the position of
-    /// the point along that axis).
+    /// If the points are adjacent by [wrapping around][BoxCoordinateSpace#wrapping-adjacency], the
+    /// line runs out through the padding on one side of the maze and back in through the other.
     ///
-    /// Returns None if the points are identical or not adjacent.
+    /// If the points are [diagonally adjacent][BoxCoordinateSpace#diagonal-adjacency], the line
+    /// runs through the cells at the corner between them.
+    ///
+    /// # Panics
+    ///
+    /// If the points are not adjacent.
     #[must_use]
-    fn get_axis_of_adjacency(pt1: pt!(), pt2: pt!()) -> Option<usize> {
-        for i in 0..DIMENSION {
-            if pt1[i].abs_diff(pt2[i]) == 1 {
-                return Some(i)
+    pub(crate) fn get_cells_between(&self, from: pt!(), to: pt!()) -> Vec<<Self as MazeCoordinator>::CellLoc> {
+        if let Some(axes) = self.space.axes_of_diagonal_adjacency(from, to) {
+            return self.get_cells_between_diagonally(from, to, axes)
+        }
+
+        let axis_of_adjacency = self.space.axis_of_adjacency(from, to).expect("from and to are not adjacent");
+        let is_wrapped = self.space.is_wrapped_between(from, to);
+
+        let from = self.map_pt_to_cell_loc(from);
+        let to = self.map_pt_to_cell_loc(to);
+
+        let from_pos = from[axis_of_adjacency];
+        let to_pos = to[axis_of_adjacency];
+        let full_dimension = usize::from(self.full_dimensions[axis_of_adjacency]);
+
+        let positions: Vec<usize> = match (is_wrapped, from_pos < to_pos) {
+            (false, true)  => (from_pos..=to_pos).collect(),
+            (false, false) => (to_pos..=from_pos).rev().collect(),
+            (true,  true)  => (0..=from_pos).rev().chain((to_pos..full_dimension).rev()).collect(),
+            (true,  false) => (from_pos..full_dimension).chain(0..=to_pos).collect(),
+        };
+
+        return positions.into_iter().map(|i| from.at(axis_of_adjacency, i)).collect()
+    }
+
+    /// Get every cell on the line from `from` to `to`, which are diagonally adjacent along `axes`,
+    /// including both ends, in order.
+    ///
+    /// The line steps along both axes at once wherever it can, and only ever steps along both axes
+    /// to leave `from` and enter `to`. This keeps the intermediate cells strictly inside the corner
+    /// between the points, so they're never shared with any direct passages, or (as long as the
+    /// scale factors are at least 3) with the crossing diagonal.
+    #[must_use]
+    fn get_cells_between_diagonally(&self, from: pt!(), to: pt!(), axes: [usize; 2]) -> Vec<<Self as MazeCoordinator>::CellLoc> {
+        let mut current = self.map_pt_to_cell_loc(from);
+        let to = self.map_pt_to_cell_loc(to);
+
+        let mut cells = vec![current];
+
+        while current != to {
+            let remaining = axes.map(|axis| current[axis].abs_diff(to[axis]));
+
+            let step_both = (remaining[0] > 1 && remaining[1] > 1) || (remaining[0] == 1 && remaining[1] == 1);
+
+            for (i, axis) in axes.into_iter().enumerate() {
+                if step_both || remaining[i] > 1 {
+                    current = if current[axis] < to[axis] { current.offset(axis, 1) } else { current.offset(axis, -1) };
+                }
             }
+
+            cells.push(current);
         }
 
-        return None
+        return cells
+    }
+
+    /// Get the connection between `from` and `to` as drawn by the cells on the line between them,
+    /// ignoring any crossing passages.
+    #[must_use]
+    fn get_drawn_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        let cell_types = self.get_cells_between(from, to).into_iter().map(|loc| self.get_cell_value(loc).cell_type);
+
+        cell_types.fold(ConnectionType::PASSAGE, |connection, cell_type| match (connection, cell_type) {
+            (ConnectionType::BOUNDARY,  _) | (_, BOUNDARY ) => ConnectionType::BOUNDARY,
+            (ConnectionType::UNVISITED, _) | (_, UNVISITED) => ConnectionType::UNVISITED,
+            (ConnectionType::WALL,      _) | (_, WALL     ) => ConnectionType::WALL,
+            (ConnectionType::PASSAGE,            PASSAGE  ) => ConnectionType::PASSAGE,
+        })
     }
 
     /// Convert a [`crate::interface::cell::CellLocation`] to a [`CellID`]
     #[must_use]
+    #[inline]
     fn cell_loc_to_id(&self, cell_loc: <Self as MazeCoordinator>::CellLoc) -> CellID {
-        let mut offset = cell_loc[0];
+        CellID((0..DIMENSION).map(|i| cell_loc[i] * self.strides[i]).sum())
+    }
 
-        for i in 1..DIMENSION {
-            offset += cell_loc[i] * usize::from(self.full_dimensions[i - 1]);
+    /// Set the type of every cell on a line of cells, as given by [`Self::get_cells_between()`],
+    /// writing runs of consecutive cells in bulk.
+    fn set_cell_value_types(&mut self, cells: &[<Self as MazeCoordinator>::CellLoc], cell_type: BlockCellPrimaryValue) {
+        // The cells are in order along the line, so consecutive cells are either next to each other
+        // in the buffer (in either direction), or start a new run
+        let mut run: Option<Range<usize>> = None;
+
+        for cell in cells {
+            let id = self.cell_loc_to_id(*cell).0;
+
+            match &mut run {
+                Some(run) if id == run.end => run.end += 1,
+                Some(run) if id + 1 == run.start => run.start -= 1,
+                _ => if let Some(run) = run.replace(id..id + 1) {
+                    self.set_cell_value_types_in_range(CellID(run.start)..CellID(run.end), cell_type);
+                },
+            }
         }
 
-        CellID(offset)
+        if let Some(run) = run {
+            self.set_cell_value_types_in_range(CellID(run.start)..CellID(run.end), cell_type);
+        }
     }
 
-    fn set_unvisited_neighbours_to_wall(&mut self, cell_loc: <Self as MazeCoordinator>::CellLoc) {
-        for i in 0..DIMENSION {
-            if cell_loc[i] > 0 {
-                let neighbour = self.get_cell_value_mut(cell_loc.offset(i, -1));
+    /// Set the type of `length` consecutive cells along the first axis, starting at `start`.
+    pub(crate) fn set_cell_value_types_along(&mut self, start: <Self as MazeCoordinator>::CellLoc, length: usize, cell_type: BlockCellPrimaryValue) {
+        let start = self.cell_loc_to_id(start).0;
+
+        self.set_cell_value_types_in_range(CellID(start)..CellID(start + length), cell_type);
+    }
+
+    /// Set the type of every cell in a range, leaving the rest of each cell untouched.
+    fn set_cell_value_types_in_range(&mut self, cells: Range<CellID>, cell_type: BlockCellPrimaryValue) {
+        // Only the types change, so cells that start off identical stay identical. Usually every
+        // cell in the range is, so the whole range can be written at once.
+        let mut runs = Vec::new();
+
+        {
+            let old_values = self.buffer.get_slice(cells.clone());
+            let mut run_start = 0;
 
-                if neighbour.cell_type == UNVISITED {
-                    neighbour.cell_type = WALL;
+            for i in 1..=old_values.len() {
+                if i == old_values.len() || old_values[i] != old_values[run_start] {
+                    // Cells that already have the type don't need rewriting
+                    if old_values[run_start].cell_type != cell_type {
+                        runs.push((CellID(cells.start.0 + run_start)..CellID(cells.start.0 + i), BlockCellValue { cell_type, ..old_values[run_start] }));
+                    }
+
+                    run_start = i;
                 }
             }
+        }
 
-            if cell_loc[i] + 1 < usize::from(self.full_dimensions[i]) {
-                let neighbour = self.get_cell_value_mut(cell_loc.offset(i, 1));
+        for (run, new_value) in runs {
+            self.buffer.set_range(run, new_value);
+        }
+    }
+
+    /// Add or remove vertical connectors along a line of cells, as given by
+    /// [`Self::get_cells_between()`].
+    ///
+    /// Only three-dimensional mazes have vertical connectors. Every step of the line that moves
+    /// along the third axis links the cells on either side of it. Steps that wrap around the maze
+    /// go the opposite way to what their positions suggest.
+    fn set_connectors_along(&mut self, cells: &[<Self as MazeCoordinator>::CellLoc], connected: bool) {
+        if DIMENSION != 3 {
+            return
+        }
 
-                if neighbour.cell_type == UNVISITED {
-                    neighbour.cell_type = WALL;
+        for (below, above) in cells.iter().zip(cells.iter().skip(1)) {
+            if below[2] == above[2] {
+                continue
+            }
+
+            let is_up = (below[2] < above[2]) == (below[2].abs_diff(above[2]) == 1);
+            let (below, above) = if is_up { (*below, *above) } else { (*above, *below) };
+
+            let connector = self.get_cell_value(below).connector;
+            self.get_cell_value_mut(below).connector = VerticalConnector::from_directions(connected, connector.goes_down());
+
+            let connector = self.get_cell_value(above).connector;
+            self.get_cell_value_mut(above).connector = VerticalConnector::from_directions(connector.goes_up(), connected);
+        }
+    }
+
+    /// Set every unvisited cell that is adjacent to any of `cells` to a wall.
+    ///
+    /// Most neighbours have already been visited, so they are only read, and the few that need to
+    /// change are written individually.
+    fn set_unvisited_neighbours_to_wall(&mut self, cells: &[<Self as MazeCoordinator>::CellLoc]) {
+        let strides = self.strides;
+
+        for cell_loc in cells {
+            let id = self.cell_loc_to_id(*cell_loc).0;
+
+            for i in 0..DIMENSION {
+                if cell_loc[i] > 0 {
+                    self.set_to_wall_if_unvisited(CellID(id - strides[i]));
+                }
+
+                if cell_loc[i] + 1 < usize::from(self.full_dimensions[i]) {
+                    self.set_to_wall_if_unvisited(CellID(id + strides[i]));
                 }
             }
         }
     }
+
+    fn set_to_wall_if_unvisited(&mut self, cell: CellID) {
+        let value = self.buffer.get(cell);
+
+        if value.cell_type == UNVISITED {
+            self.buffer.set(cell, BlockCellValue { cell_type: WALL, ..value });
+        }
+    }
 }
 
 impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> MazeCoordinator for BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
@@ -331,17 +689,36 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> MazeCoordinato
         &self.space
     }
 
+    fn marks(&self) -> &Marks<pt!()> {
+        &self.marks
+    }
+
+    fn marks_mut(&mut self) -> &mut Marks<pt!()> {
+        &mut self.marks
+    }
+
     fn get(&self, pt: pt!()) -> Self::CellVal {
-        self.get_cell_value(self.map_pt_to_cell_loc(pt))
+        self.buffer.get(self.map_pt_to_cell_id(pt))
     }
 
-    fn get_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
-        match [self.get(from).cell_type, self.get(to).cell_type] {
-            [BOUNDARY,  _] | [_, BOUNDARY ] => ConnectionType::BOUNDARY,
-            [UNVISITED, _] | [_, UNVISITED] => ConnectionType::UNVISITED,
-            [WALL,      _] | [_, WALL     ] => ConnectionType::WALL,
-            [PASSAGE,            PASSAGE  ] => ConnectionType::PASSAGE,
+    /// The connection is determined by every cell on the line from `from` to `to`, including any
+    /// intermediate cells.
+    ///
+    /// If [diagonals may not cross][Self::diagonals_may_cross], a diagonal connection that hasn't
+    /// been carved is a boundary if the diagonal crossing it has been.
+    fn get_adjacent_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        let connection = self.get_drawn_connection(from, to);
+
+        if let (Some([axis, _]), false) = (self.space.axes_of_diagonal_adjacency(from, to), self.diagonals_may_cross) {
+            let crossing_from = from.at(axis, to[axis]);
+            let crossing_to = to.at(axis, from[axis]);
+
+            if connection != ConnectionType::PASSAGE && self.get_drawn_connection(crossing_from, crossing_to) == ConnectionType::PASSAGE {
+                return ConnectionType::BOUNDARY
+            }
         }
+
+        return connection
     }
 
     //noinspection RsUnnecessaryQualifications
@@ -350,7 +727,7 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> MazeCoordinato
         let cell_loc = self.map_pt_to_cell_loc(pt);
 
         self.set_cell_value_type(cell_loc, PASSAGE);
-        self.set_unvisited_neighbours_to_wall(cell_loc);
+        self.set_unvisited_neighbours_to_wall(&[cell_loc]);
     }
 
     //noinspection RsUnnecessaryQualifications
@@ -361,34 +738,20 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> MazeCoordinato
     /// will be set to [`BlockCellPrimaryValue::WALL`]. Note that this excludes `to`, so that
     /// maze carvers will be able to progress. If you wish for `to` to also be surrounded by
     /// walls, simply call [`Self::make_passage()`] on `to` as well.
+    ///
+    /// In three-dimensional mazes, if the passage moves between layers then
+    /// [vertical connectors][VerticalConnector] are added to the cells along it.
     fn make_passage_between(&mut self, from: pt!(), to: pt!()) {
-        let axis_of_adjacency = Self::get_axis_of_adjacency(from, to).expect("from and to are not adjacent");
-
-        let from = self.map_pt_to_cell_loc(from);
-        let to = self.map_pt_to_cell_loc(to);
-
-        let from_pos = from[axis_of_adjacency];
-        let to_pos = to[axis_of_adjacency];
+        let cells = self.get_cells_between(from, to);
 
-        if from_pos < to_pos {
-            // Skip out on the end so we don't add walls around it
-            for i in from_pos..to_pos {
-                let cell = from.at(axis_of_adjacency, i);
+        self.set_cell_value_types(&cells, PASSAGE);
 
-                self.set_cell_value_type(cell, PASSAGE);
-                self.set_unvisited_neighbours_to_wall(cell);
-            }
-        } else {
-            // Skip out on the end so we don't add walls around it
-            for i in (to_pos + 1)..=from_pos {
-                let cell = from.at(axis_of_adjacency, i);
+        // Skip out on the end so we don't add walls around it
+        let (_, path) = cells.split_last().expect("There are always at least two cells between two points");
 
-                self.set_cell_value_type(cell, PASSAGE);
-                self.set_unvisited_neighbours_to_wall(cell);
-            }
-        }
+        self.set_unvisited_neighbours_to_wall(path);
 
-        self.set_cell_value_type(to, PASSAGE);
+        self.set_connectors_along(&cells, true);
     }
 
     //noinspection RsUnnecessaryQualifications
@@ -400,24 +763,14 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> MazeCoordinato
     //noinspection RsUnnecessaryQualifications
     /// Set `from` and `to` to [`BlockCellPrimaryValue::WALL`]. If the scale factor along the axis of
     /// adjacency is greater than 1, then all intermediate cells will be set to walls too.
+    ///
+    /// Any [vertical connectors][VerticalConnector] along the passage are removed.
     fn make_wall_between(&mut self, from: pt!(), to: pt!()) {
-        let axis_of_adjacency = Self::get_axis_of_adjacency(from, to).expect("from and to are not adjacent");
-
-        let from = self.map_pt_to_cell_loc(from);
-        let to = self.map_pt_to_cell_loc(to);
+        let cells = self.get_cells_between(from, to);
 
-        let from_pos = from[axis_of_adjacency];
-        let to_pos = to[axis_of_adjacency];
+        self.set_cell_value_types(&cells, WALL);
 
-        if from_pos < to_pos {
-            for i in from_pos..=to_pos {
-                self.set_cell_value_type(from.at(axis_of_adjacency, i), WALL);
-            }
-        } else {
-            for i in to_pos..=from_pos {
-                self.set_cell_value_type(from.at(axis_of_adjacency, i), WALL);
-            }
-        }
+        self.set_connectors_along(&cells, false);
     }
 
     //noinspection RsUnnecessaryQualifications
@@ -429,24 +782,14 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> MazeCoordinato
     //noinspection RsUnnecessaryQualifications
     /// Set `from` and `to` to [`BlockCellPrimaryValue::BOUNDARY`]. If the scale factor along the axis
     /// of adjacency is greater than 1, then all intermediate cells will be set to boundaries too.
+    ///
+    /// Any [vertical connectors][VerticalConnector] along the passage are removed.
     fn make_boundary_between(&mut self, from: pt!(), to: pt!()) {
-        let axis_of_adjacency = Self::get_axis_of_adjacency(from, to).expect("from and to are not adjacent");
+        let cells = self.get_cells_between(from, to);
 
-        let from = self.map_pt_to_cell_loc(from);
-        let to = self.map_pt_to_cell_loc(to);
-
-        let from_pos = from[axis_of_adjacency];
-        let to_pos = to[axis_of_adjacency];
+        self.set_cell_value_types(&cells, BOUNDARY);
 
-        if from_pos < to_pos {
-            for i in from_pos..=to_pos {
-                self.set_cell_value_type(from.at(axis_of_adjacency, i), BOUNDARY);
-            }
-        } else {
-            for i in to_pos..=from_pos {
-                self.set_cell_value_type(from.at(axis_of_adjacency, i), BOUNDARY);
-            }
-        }
+        self.set_connectors_along(&cells, false);
     }
 }
 
@@ -466,8 +809,12 @@ pub struct BoxSpaceBlockCellMazeCoordinatorBuilder<Buffer: MazeBuffer<BlockCellV
     space: BoxCoordinateSpace<DIMENSION>,
     /// The number of cells a point corresponds to, for each axis.
     /// 
-    /// The scale factors are ordered from most minor axis to most major.
-    scale_factors: [NonZeroUsize; DIMENSION],
+    /// The scale factors are ordered from most minor axis to most major. They are checked to be
+    /// non-zero when the maze is built.
+    scale_factors: [usize; DIMENSION],
+    /// The number of cells between each pair of consecutive points, for the axes that don't use
+    /// their scale factor.
+    spacing: [Option<Vec<NonZeroUsize>>; DIMENSION],
     /// The number of cells on the edge of the maze that are not mapped to any point, for each
     /// direction.
     /// 
@@ -475,6 +822,8 @@ pub struct BoxSpaceBlockCellMazeCoordinatorBuilder<Buffer: MazeBuffer<BlockCellV
     /// on the negative edge is the first in each pair, and the number on the positive edge is
     /// second.
     padding: [[usize; 2]; DIMENSION],
+    /// Whether diagonal passages may cross each other.
+    diagonals_may_cross: bool,
 }
 
 impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockCellMazeCoordinatorBuilder<Buffer, DIMENSION> {
@@ -484,24 +833,33 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
     /// 
     /// `space` --- the coordinate space to use for the maze.
     fn new(space: BoxCoordinateSpace<DIMENSION>) -> Self {
+        // Diagonals need room for two passages to cross between points
+        let scale_factor = if space.diagonal() { 3 } else { 2 };
+
         Self {
             _buffer: PhantomData,
             space,
-            scale_factors: [NONZERO_USIZE_TWO; DIMENSION],
+            scale_factors: [scale_factor; DIMENSION],
+            spacing: std::array::from_fn(|_| None),
             padding: [[1, 1]; DIMENSION],
+            diagonals_may_cross: false,
         }
     }
 
     /// Set the number of cells a point corresponds to, for each axis.
     /// 
     /// The scale factors are ordered from most minor axis to most major.
+    ///
+    /// The default is 2, or 3 if the coordinate space has
+    /// [diagonal adjacency][BoxCoordinateSpace#diagonal-adjacency]. Diagonal mazes must have scale
+    /// factors of at least 3, so that crossing diagonals don't share cells.
     /// 
     /// # See Also
     ///
     /// [`Self::scale_factors_checked()`]. If you're using integer literals, you may with to use
     /// this instead.
     pub fn scale_factors(mut self, scale_factors: [NonZeroUsize; DIMENSION]) -> Self {
-        self.scale_factors = scale_factors;
+        self.scale_factors = scale_factors.map(usize::from);
 
         return self
     }
@@ -510,17 +868,113 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
     /// 
     /// The scale factors are ordered from most minor axis to most major.
     ///
-    /// All scale factors must be non-zero.
-    ///
-    /// # Panics
-    ///
-    /// If `count` is zero.
+    /// All scale factors must be non-zero. This is checked when the maze is built.
     /// 
     /// # See Also
     /// 
     /// [`Self::scale_factors()`], which takes `NonZeroUsize`s.
-    pub fn scale_factors_checked(self, scale_factors: [usize; DIMENSION]) -> Self {
-        self.scale_factors(try_usize_array_to_nonzero_usize_array(scale_factors).expect("All scale factors must be non-zero"))
+    pub fn scale_factors_checked(mut self, scale_factors: [usize; DIMENSION]) -> Self {
+        self.scale_factors = scale_factors;
+
+        return self
+    }
+
+    /// Set the number of cells between each pair of consecutive points along `axis`, instead of
+    /// using its scale factor.
+    ///
+    /// The first entry is the distance between the first and second points, and so on, so there
+    /// must be one fewer entry than there are points along the axis. The points themselves are
+    /// still evenly spaced in the coordinate space, only the cells they map to move. A larger
+    /// spacing makes the passages and walls between those points longer or thicker.
+    ///
+    /// If the axis [wraps around][BoxCoordinateSpace#wrapping-adjacency], the gap between the last
+    /// and first points is the padding, as usual.
+    ///
+    /// # Examples
+    ///
+    /// A wide gap down the middle of the maze:
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// #
+    /// let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([4, 3]))
+    ///     .spacing_checked(0, vec![2, 4, 2])
+    ///     .build();
+    ///
+    /// assert_eq!([1, 3, 7, 9], [0, 1, 2, 3].map(|x| maze.map_pt_to_cell_loc([x, 0].into())[0]));
+    /// assert_eq!([11, 7], maze.get_full_dimensions().map(usize::from));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is not less than `DIMENSION`, or `spacing` doesn't have exactly one fewer entry
+    /// than there are points along the axis.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::spacing_checked()`]. If you're using integer literals, you may wish to use this
+    /// instead.
+    ///
+    /// [`Self::spacing_fn()`], to set the spacing of every axis at once.
+    pub fn spacing(mut self, axis: usize, spacing: Vec<NonZeroUsize>) -> Self {
+        assert!(axis < DIMENSION, "The axis must be less than the dimension");
+        assert!(spacing.len() + 1 == usize::from(self.space.dimensions()[axis]), "There must be exactly one spacing for each gap between points");
+
+        self.spacing[axis] = Some(spacing);
+
+        return self
+    }
+
+    /// Set the number of cells between each pair of consecutive points along `axis`, instead of
+    /// using its scale factor.
+    ///
+    /// All spacings must be non-zero.
+    ///
+    /// # Panics
+    ///
+    /// If any spacing is zero, or as for [`Self::spacing()`].
+    ///
+    /// # See Also
+    ///
+    /// [`Self::spacing()`], which takes `NonZeroUsize`s.
+    pub fn spacing_checked(self, axis: usize, spacing: Vec<usize>) -> Self {
+        let spacing = spacing.into_iter().map(|gap| NonZeroUsize::new(gap).expect("All spacings must be non-zero")).collect();
+
+        self.spacing(axis, spacing)
+    }
+
+    /// Set the spacing between every pair of consecutive points on every axis from a function.
+    ///
+    /// `spacing` is called with an axis and the position of a point along it, and returns the
+    /// number of cells between that point and the next one along the axis.
+    ///
+    /// # Examples
+    ///
+    /// A grand central hallway, with its neighbouring rows pushed away from it:
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// #
+    /// let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([5, 5]))
+    ///     .spacing_fn(|axis, position| NonZeroUsize::new(if axis == 1 && (position == 1 || position == 2) { 3 } else { 2 }).unwrap())
+    ///     .build();
+    ///
+    /// assert_eq!(vec![2, 3, 3, 2], maze.spacing(1).into_iter().map(usize::from).collect::<Vec<_>>());
+    /// assert_eq!(vec![2, 2, 2, 2], maze.spacing(0).into_iter().map(usize::from).collect::<Vec<_>>());
+    /// ```
+    pub fn spacing_fn(mut self, spacing: impl Fn(usize, usize) -> NonZeroUsize) -> Self {
+        for axis in 0..DIMENSION {
+            let gap_count = usize::from(self.space.dimensions()[axis]) - 1;
+
+            self.spacing[axis] = Some((0..gap_count).map(|position| spacing(axis, position)).collect());
+        }
+
+        return self
     }
 
     /// Set the number of cells on the edge of the maze that are not mapped to any point, for each
@@ -535,10 +989,408 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
         return self
     }
 
+    /// Set whether [diagonal passages][BoxCoordinateSpace#diagonal-adjacency] may cross each
+    /// other. Disabled by default.
+    ///
+    /// Crossing passages touch at the corner between their points, so they are drawn as an
+    /// intersection rather than as one passage passing over the other.
+    ///
+    /// This has no effect if the coordinate space doesn't have diagonal adjacency.
+    pub fn diagonals_may_cross(mut self, diagonals_may_cross: bool) -> Self {
+        self.diagonals_may_cross = diagonals_may_cross;
+
+        return self
+    }
+
     /// Finalise the [`BoxSpaceBlockCellMazeCoordinator`].
+    ///
+    /// # Panics
+    ///
+    /// If any scale factor is zero, the coordinate space has
+    /// [diagonal adjacency][BoxCoordinateSpace#diagonal-adjacency] and any scale factor or spacing
+    /// is less than 3, or the dimensions of the maze's cell space do not fit within a `usize`.
+    /// The panic message names the offending axis.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::try_build()`], which returns an error instead of panicking.
     #[must_use]
     pub fn build(&self) -> BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
-        BoxSpaceBlockCellMazeCoordinator::new(self.space, self.scale_factors, self.padding)
+        self.try_build().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Finalise the [`BoxSpaceBlockCellMazeCoordinator`], returning an error if the configuration
+    /// is invalid.
+    ///
+    /// # Errors
+    ///
+    /// The first problem found with the configuration, in this order:
+    /// * [`MazeError::ZeroScaleFactor`] if a scale factor is zero.
+    /// * [`MazeError::DiagonalScaleFactorTooSmall`] or [`MazeError::DiagonalSpacingTooSmall`] if the
+    ///   coordinate space has [diagonal adjacency][BoxCoordinateSpace#diagonal-adjacency] and a
+    ///   scale factor or spacing is less than 3.
+    /// * [`MazeError::ScaledDimensionsTooLarge`] or [`MazeError::FullDimensionsTooLarge`] if the
+    ///   length of an axis does not fit within a `usize` before or after padding.
+    /// * [`MazeError::TooManyCells`] if the number of cells does not fit within a `usize`.
+    ///
+    /// Each error names the axis (and gap) responsible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::error::MazeError;
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// #
+    /// let result = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([5, 5]))
+    ///     .padding([[usize::MAX, 1], [1, 1]])
+    ///     .try_build();
+    ///
+    /// assert_eq!(MazeError::FullDimensionsTooLarge { axis: 0 }, result.unwrap_err());
+    /// ```
+    pub fn try_build(&self) -> Result<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, MazeError> {
+        let scale_factors = self.validate()?;
+
+        BoxSpaceBlockCellMazeCoordinator::try_new(self.space, scale_factors, &self.spacing, self.padding, self.diagonals_may_cross, Buffer::new)
+    }
+
+    /// Finalise the [`BoxSpaceBlockCellMazeCoordinator`], storing it in an existing buffer instead
+    /// of constructing a new one.
+    ///
+    /// The buffer's contents are kept, so this can be used to resume work on a maze stored in a
+    /// persistent buffer, such as an [`MmapBuffer`][crate::implm::buffer::MmapBuffer]. The builder
+    /// must be configured exactly as it was when the maze was first built.
+    ///
+    /// # Parameters
+    ///
+    /// `buffer` --- the buffer to store the maze in. It must hold at least
+    ///              [`cells_required()`][Self::cells_required] cells.
+    ///
+    /// # Panics
+    ///
+    /// See [`Self::build()`].
+    #[must_use]
+    pub fn build_with_buffer(&self, buffer: Buffer) -> BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
+        self.try_build_with_buffer(buffer).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Finalise the [`BoxSpaceBlockCellMazeCoordinator`] in an existing buffer, returning an error
+    /// if the configuration is invalid. See [`Self::build_with_buffer()`].
+    ///
+    /// # Errors
+    ///
+    /// See the panics of [`Self::build()`].
+    pub fn try_build_with_buffer(&self, buffer: Buffer) -> Result<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, MazeError> {
+        let scale_factors = self.validate()?;
+
+        BoxSpaceBlockCellMazeCoordinator::try_new(self.space, scale_factors, &self.spacing, self.padding, self.diagonals_may_cross, |_| buffer)
+    }
+
+    /// Return the number of cells the maze will need, as currently configured.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells does not fit within a `usize`.
+    #[must_use]
+    pub fn cells_required(&self) -> NonZeroUsize {
+        self.try_cells_required().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Return the number of cells the maze will need, as currently configured, returning an error
+    /// if the configuration is invalid.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::try_build()`].
+    pub fn try_cells_required(&self) -> Result<NonZeroUsize, MazeError> {
+        let (_, full_dimensions) = BoxSpaceBlockCellMazeCoordinator::<Buffer, DIMENSION>::layout(self.space, self.validate()?, &self.spacing, self.padding)?;
+
+        return full_dimensions.checked_product().ok_or(MazeError::TooManyCells)
+    }
+
+    /// Check the parts of the configuration that don't affect the layout, returning the scale
+    /// factors once they're known to be non-zero.
+    fn validate(&self) -> Result<[NonZeroUsize; DIMENSION], MazeError> {
+        let scale_factors = std::array::from_fn(|axis| NonZeroUsize::new(self.scale_factors[axis]).ok_or(MazeError::ZeroScaleFactor { axis }))
+            .try_map(|scale_factor| scale_factor)?;
+
+        if self.space.diagonal() == false {
+            return Ok(scale_factors)
+        }
+
+        if let Some(axis) = (0..DIMENSION).find(|axis| self.scale_factors[*axis] < 3) {
+            return Err(MazeError::DiagonalScaleFactorTooSmall { axis });
+        }
+
+        for (axis, spacing) in self.spacing.iter().enumerate() {
+            if let Some(gap) = spacing.iter().flatten().position(|gap| usize::from(*gap) < 3) {
+                return Err(MazeError::DiagonalSpacingTooSmall { axis, gap });
+            }
+        }
+
+        return Ok(scale_factors)
+    }
+}
+
+// Construction from plain data
+impl <Buffer: MazeBuffer<BlockCellValue>> BoxSpaceBlockCellMazeCoordinator<Buffer, 2> {
+    /// Construct a maze from a grid of wall flags, such as a `&[Vec<bool>]`.
+    ///
+    /// `walls` is indexed as `walls[y][x]`. Every entry becomes exactly one cell --- `true` for a
+    /// wall and `false` for a passage. The resulting maze therefore has a scale factor of 1 and no
+    /// padding, so every cell is also a point.
+    ///
+    /// Any row type that can be viewed as a slice is accepted, so `Vec<bool>`, `[bool; N]`, and
+    /// row slices borrowed from some other matrix type all work.
+    ///
+    /// # Panics
+    ///
+    /// If `walls` is empty, its rows are empty, or its rows are not all the same length.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::restructure_inferred()`], to recover the scale factors and padding the grid was
+    /// drawn with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+    /// use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// use mazelib::interface::coordinate::MazeCoordinator;
+    ///
+    /// let walls = vec![
+    ///     vec![true,  true,  true],
+    ///     vec![false, false, true],
+    /// ];
+    ///
+    /// let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::from_walls(&walls);
+    ///
+    /// assert_eq!([3, 2], maze.coord_space().dimensions().map(usize::from));
+    /// assert_eq!(BlockCellPrimaryValue::WALL, maze.get([2, 1].into()).cell_type);
+    /// assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get([0, 1].into()).cell_type);
+    /// ```
+    #[must_use]
+    pub fn from_walls<Row: AsRef<[bool]>>(walls: &[Row]) -> Self {
+        let height = walls.len();
+        let width = walls.first().map_or(0, |row| row.as_ref().len());
+
+        assert!(walls.iter().all(|row| row.as_ref().len() == width), "All rows must be the same length");
+
+        let space = BoxCoordinateSpace::new_checked([width, height]);
+
+        let mut maze = Self::builder(space)
+            .scale_factors([NONZERO_USIZE_ONE; 2])
+            .padding([[0, 0]; 2])
+            .build();
+
+        for (y, row) in walls.iter().enumerate() {
+            for (x, is_wall) in row.as_ref().iter().enumerate() {
+                maze.set_cell_value_type([x, y].into(), if *is_wall { WALL } else { PASSAGE });
+            }
+        }
+
+        return maze
+    }
+}
+
+// Structure inference
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
+    /// Guess the scale factors and padding that produced this maze's cells.
+    ///
+    /// This is useful for mazes that were imported from raw cell grids (text, images, etc.), as
+    /// these usually come in with a scale factor of 1 and no padding, regardless of how they were
+    /// originally drawn.
+    ///
+    /// Each axis is analysed independently. A scale factor and offset are accepted if they describe
+    /// a well-formed maze along that axis:
+    /// * The cells between two points are all identical along the axis, aside from unvisited cells.
+    /// * The cells between two points are only passages where both points are passages.
+    /// * At least one cell between points is a wall or boundary.
+    ///
+    /// The largest acceptable scale factor is chosen. Slices of cells on the edge of the maze that
+    /// don't contain any passages become padding. If no scale factor above 1 is acceptable, the
+    /// axis is given a scale factor of 1.
+    ///
+    /// The inferred full dimensions are always the same as the maze's current full dimensions, so
+    /// the result can be passed straight to [`Self::restructure()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    ///
+    /// let (o, x) = (false, true);
+    ///
+    /// let walls = [
+    ///     [x, x, x, x, x],
+    ///     [x, o, o, o, x],
+    ///     [x, o, x, x, x],
+    ///     [x, o, o, o, x],
+    ///     [x, x, x, x, x],
+    /// ];
+    ///
+    /// let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::from_walls(&walls);
+    ///
+    /// let (scale_factors, padding) = maze.infer_scale_factors_and_padding();
+    ///
+    /// assert_eq!([2, 2], scale_factors.map(usize::from));
+    /// assert_eq!([[1, 1], [1, 1]], padding);
+    /// ```
+    #[must_use]
+    pub fn infer_scale_factors_and_padding(&self) -> ([NonZeroUsize; DIMENSION], [[usize; 2]; DIMENSION]) {
+        let mut scale_factors = [NONZERO_USIZE_ONE; DIMENSION];
+        let mut padding = [[0, 0]; DIMENSION];
+
+        for axis in 0..DIMENSION {
+            (scale_factors[axis], padding[axis]) = self.infer_axis_structure(axis);
+        }
+
+        return (scale_factors, padding)
+    }
+
+    /// Construct a copy of this maze with different scale factors and padding.
+    ///
+    /// The cells are copied over unchanged, so the new scale factors and padding must produce the
+    /// same full dimensions as the current ones. The coordinate space is derived from them.
+    ///
+    /// # Panics
+    ///
+    /// If the scale factors and padding do not produce the same full dimensions.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::infer_scale_factors_and_padding()`], which produces suitable parameters.
+    #[must_use]
+    pub fn restructure(&self, scale_factors: [NonZeroUsize; DIMENSION], padding: [[usize; 2]; DIMENSION]) -> Self {
+        let dimensions = self.full_dimensions.zip_map(&scale_factors, |full_dim, scale_factor| (usize::from(*full_dim), usize::from(*scale_factor)))
+            .zip_map(&padding, |(full_dim, scale_factor), [padding_before, padding_after]| {
+                full_dim.checked_sub(padding_before + padding_after)
+                    .filter(|scaled_dim| *scaled_dim > 0 && (scaled_dim - 1) % scale_factor == 0)
+                    .map(|scaled_dim| (scaled_dim - 1) / scale_factor + 1)
+                    .expect("The scale factors and padding do not match the maze's full dimensions")
+            });
+
+        let mut maze = Self::builder(BoxCoordinateSpace::new_checked(dimensions))
+            .scale_factors(scale_factors)
+            .padding(padding)
+            .build();
+
+        for cell_loc in BoxCoordinateSpace::new(self.full_dimensions).iter() {
+            *maze.get_cell_value_mut(BlockCellLocation(cell_loc)) = self.get_cell_value(BlockCellLocation(cell_loc));
+        }
+
+        return maze
+    }
+
+    /// Sugar for
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+    /// #
+    /// let (scale_factors, padding) = maze.infer_scale_factors_and_padding();
+    /// maze.restructure(scale_factors, padding)
+    /// # ;
+    /// ```
+    ///
+    /// Please see [`Self::infer_scale_factors_and_padding()`] for details.
+    #[must_use]
+    pub fn restructure_inferred(&self) -> Self {
+        let (scale_factors, padding) = self.infer_scale_factors_and_padding();
+
+        self.restructure(scale_factors, padding)
+    }
+
+    /// Infer the scale factor and padding of a single axis.
+    fn infer_axis_structure(&self, axis: usize) -> (NonZeroUsize, [usize; 2]) {
+        let length = usize::from(self.full_dimensions[axis]);
+
+        let slice_has_passage: Vec<bool> = (0..length).map(|index| {
+            self.slice_cell_locs(axis, index).any(|cell_loc| self.get_cell_value(cell_loc).cell_type == PASSAGE)
+        }).collect();
+
+        for scale_factor in (2..length).rev() {
+            for offset in 0..scale_factor {
+                let mut points = (offset..length).step_by(scale_factor).filter(|index| slice_has_passage[*index]);
+
+                let (Some(first), Some(last)) = (points.next(), points.next_back()) else {
+                    continue
+                };
+
+                if self.is_axis_structure_plausible(axis, scale_factor, first, last) {
+                    let scale_factor = NonZeroUsize::new(scale_factor).expect("[Bug] Scale factor was zero");
+
+                    return (scale_factor, [first, length - 1 - last])
+                }
+            }
+        }
+
+        // Fall back to every cell being a point, but still strip any solid edges
+        return match (slice_has_passage.iter().position(|has_passage| *has_passage), slice_has_passage.iter().rposition(|has_passage| *has_passage)) {
+            (Some(first), Some(last)) => (NONZERO_USIZE_ONE, [first, length - 1 - last]),
+            _ => (NONZERO_USIZE_ONE, [0, 0]),
+        }
+    }
+
+    /// Check whether the cells along `axis` between `first` and `last` are consistent with points
+    /// lying every `scale_factor` cells.
+    fn is_axis_structure_plausible(&self, axis: usize, scale_factor: usize, first: usize, last: usize) -> bool {
+        let mut has_wall = false;
+
+        for index in (first..last).filter(|index| (index - first) % scale_factor != 0) {
+            let point_before = index - (index - first) % scale_factor;
+            let point_after = point_before + scale_factor;
+
+            for cell_loc in self.slice_cell_locs(axis, index) {
+                let cell_type = self.get_cell_value(cell_loc).cell_type;
+
+                let at_index = |index: usize| {
+                    let mut loc: [usize; DIMENSION] = cell_loc.0.into();
+                    loc[axis] = index;
+
+                    self.get_cell_value(BlockCellLocation(loc.into())).cell_type
+                };
+
+                // All cells between two points must be the same. Unvisited cells could be anything.
+                if index > point_before + 1 {
+                    let previous_cell_type = at_index(index - 1);
+
+                    if cell_type != UNVISITED && previous_cell_type != UNVISITED && cell_type != previous_cell_type {
+                        return false
+                    }
+                }
+
+                // A passage between two points connects them, so they must be passages too
+                if cell_type == PASSAGE && (at_index(point_before) != PASSAGE || at_index(point_after) != PASSAGE) {
+                    return false
+                }
+
+                has_wall |= cell_type == WALL || cell_type == BOUNDARY;
+            }
+        }
+
+        return has_wall
+    }
+
+    /// Iterate over the locations of all cells whose position along `axis` is `index`.
+    fn slice_cell_locs(&self, axis: usize, index: usize) -> impl Iterator<Item = <Self as MazeCoordinator>::CellLoc> {
+        let mut slice_dimensions = self.full_dimensions;
+        slice_dimensions[axis] = NONZERO_USIZE_ONE;
+
+        BoxCoordinateSpace::new(slice_dimensions).iter().map(move |cell_loc| {
+            let mut cell_loc: [usize; DIMENSION] = cell_loc.into();
+            cell_loc[axis] = index;
+
+            BlockCellLocation(cell_loc.into())
+        })
     }
 }
 
@@ -558,9 +1410,26 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxSpaceBlockC
         writeln!(f, "\tbuffer: {:?}", self.buffer)?;
         writeln!(f, "\tspace: {:?}", self.space)?;
         writeln!(f, "\tscale_factors: {:?}", self.scale_factors)?;
+
+        if self.is_uniformly_spaced() == false {
+            writeln!(f, "\tspacing: {:?}", std::array::from_fn::<_, DIMENSION, _>(|axis| self.spacing(axis)))?;
+        }
+
         writeln!(f, "\tpadding: {:?}", self.padding)?;
         writeln!(f, "\tfull_dimensions: {:?}", self.full_dimensions)?;
 
+        if self.marks.named_points.is_empty() == false {
+            writeln!(f, "\tnamed_points: {:?}", self.marks.named_points)?;
+        }
+
+        if self.marks.annotations.is_empty() == false {
+            writeln!(f, "\tannotations: {:?}", self.marks.annotations)?;
+        }
+
+        if self.marks.portals.is_empty() == false {
+            writeln!(f, "\tportals: {:?}", self.marks.portals)?;
+        }
+
         return Ok(())
     }
 }
@@ -580,13 +1449,7 @@ impl <Buffer: MazeBuffer<BlockCellValue>> Debug for BoxSpaceBlockCellMazeCoordin
 
         writeln!(f)?;
 
-        let mut text_export = Vec::<u8>::new();
-
-        if let Err(err) = BoxSpaceBlockCellTextMazeExporter::default().export(self, &mut text_export) {
-            panic!("{}", err)
-        }
-
-        let text_export = std::str::from_utf8(&text_export).expect("BoxSpaceTextMazeExporter did not produce valid UTF-8");
+        let text_export = BoxSpaceBlockCellTextMazeExporter::default().export_to_string(self).unwrap_or_else(|err| panic!("{}", err));
 
         for line in text_export.lines() {
             writeln!(f, "\t{}", line)?;
@@ -596,4 +1459,15 @@ impl <Buffer: MazeBuffer<BlockCellValue>> Debug for BoxSpaceBlockCellMazeCoordin
 
         return Ok(())
     }
+}
+
+/// Render the maze as text, as per [`BoxSpaceBlockCellTextMazeExporter`] with its default settings.
+///
+/// Unlike the [`Debug`] output, this is just the maze itself, without a trailing line break.
+impl <Buffer: MazeBuffer<BlockCellValue>> Display for BoxSpaceBlockCellMazeCoordinator<Buffer, 2> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text_export = BoxSpaceBlockCellTextMazeExporter::default().export_to_string(self).unwrap_or_else(|err| panic!("{}", err));
+
+        return f.write_str(text_export.trim_end_matches(['\r', '\n']))
+    }
 }
\ No newline at end of file
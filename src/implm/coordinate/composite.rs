@@ -0,0 +1,200 @@
+//! A coordinator that stitches several mazes together into a
+//! [composite coordinate space][crate::implm::point::composite].
+
+use crate::implm::point::composite::{CompositeCoordinateSpace, CompositeLayout, CompositePoint};
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::mark::Marks;
+use crate::pt;
+
+/// A maze coordinator that joins one maze for each region of a [`CompositeLayout`] into a single
+/// maze.
+///
+/// Every point is stored in the maze for its region, and operations between points in the same
+/// region are passed straight through to it. The connections across junctions are stored by this
+/// coordinator. Carving a passage across a junction also [makes passages][MazeCoordinator::make_passage]
+/// of the points at either end, so they are visited.
+///
+/// Since exporters are written for specific coordinators, you will usually want to export each
+/// region's maze separately. You can get them back with [`maze()`][Self::maze] or
+/// [`into_mazes()`][Self::into_mazes].
+///
+/// # Examples
+///
+/// Two wings joined by a bridge:
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// use mazelib::implm::coordinate::composite::CompositeMazeCoordinator;
+/// use mazelib::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::point::composite::{CompositeLayout, CompositePoint};
+/// use mazelib::interface::cell::CellValue;
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::interface::point::CoordinateSpace;
+///
+/// type Wing = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<2>>, 2>;
+///
+/// let west = CompositePoint::new(0, [4, 2].into());
+/// let east = CompositePoint::new(1, [0, 2].into());
+///
+/// let layout = CompositeLayout::new(vec![BoxCoordinateSpace::new_checked([5, 5]); 2]).join(west, east);
+///
+/// let mut maze = CompositeMazeCoordinator::new(&layout, |space| BoxSpaceInlineCellMazeCoordinatorBuilder::<_, 2>::new(space).build());
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// // The bridge is the only way between the wings
+/// assert!(maze.is_passage_between(west, east));
+/// assert!(maze.coord_space().iter().all(|pt| maze.get(pt).is_fully_visited()));
+///
+/// let wings: Vec<Wing> = maze.into_mazes();
+/// ```
+#[derive(Debug)]
+pub struct CompositeMazeCoordinator<'layout, Maze: MazeCoordinator> {
+    mazes: Vec<Maze>,
+    space: CompositeCoordinateSpace<'layout, Maze::CoordSpace>,
+    /// The connection across each junction, in the same order as the layout's junctions.
+    junctions: Vec<ConnectionType>,
+    /// The maze's named points, annotations, and portals.
+    marks: Marks<CompositePoint<<Maze::CoordSpace as CoordinateSpace>::PtType>>,
+}
+
+/// A point within a single region's maze.
+type RegionPt<Maze> = <<Maze as MazeCoordinator>::CoordSpace as CoordinateSpace>::PtType;
+
+impl <'layout, Maze: MazeCoordinator> CompositeMazeCoordinator<'layout, Maze> {
+    /// Construct a new maze over `layout`.
+    ///
+    /// `build_region` is called with each region of the layout in turn, and must return a new maze
+    /// for it. Every junction starts off [unvisited][ConnectionType::UNVISITED].
+    ///
+    /// # Panics
+    ///
+    /// If a maze returned by `build_region` has a different number of points than its region.
+    #[must_use]
+    pub fn new(layout: &'layout CompositeLayout<Maze::CoordSpace>, build_region: impl FnMut(Maze::CoordSpace) -> Maze) -> Self {
+        let mazes: Vec<Maze> = layout.regions().iter().copied().map(build_region).collect();
+
+        assert!(mazes.iter().zip(layout.regions()).all(|(maze, region)| maze.coord_space().logical_size() == region.logical_size()), "Each maze must have the same coordinate space as its region");
+
+        Self { mazes, space: CompositeCoordinateSpace::new(layout), junctions: vec![ConnectionType::UNVISITED; layout.junctions().len()], marks: Marks::new() }
+    }
+
+    /// Return the maze for region `region`.
+    ///
+    /// # Panics
+    ///
+    /// If the region does not exist.
+    #[must_use]
+    pub fn maze(&self, region: usize) -> &Maze {
+        self.mazes.get(region).expect("The region does not exist")
+    }
+
+    /// Return the maze for region `region`, mutably.
+    ///
+    /// # Panics
+    ///
+    /// If the region does not exist.
+    #[must_use]
+    pub fn maze_mut(&mut self, region: usize) -> &mut Maze {
+        self.mazes.get_mut(region).expect("The region does not exist")
+    }
+
+    /// Unwrap this coordinator, returning the maze for each region.
+    #[must_use]
+    pub fn into_mazes(self) -> Vec<Maze> {
+        self.mazes
+    }
+
+    /// Return the junction between `from` and `to`, or `None` if they are adjacent within a
+    /// region instead.
+    ///
+    /// # Panics
+    ///
+    /// If the points are not adjacent.
+    #[must_use]
+    fn get_junction(&self, from: pt!(), to: pt!()) -> Option<usize> {
+        let layout = self.space.layout();
+
+        if layout.are_adjacent_within_region(from, to) {
+            return None
+        }
+
+        return Some(layout.junction_between(from, to).expect("from and to are not adjacent"))
+    }
+
+    /// Set the connection across the junction between `from` and `to`, if there is one. Otherwise,
+    /// pass the change to the maze of their region with `make_between`.
+    fn make_between(&mut self, from: pt!(), to: pt!(), connection: ConnectionType, make_between: fn(&mut Maze, RegionPt<Maze>, RegionPt<Maze>)) {
+        match self.get_junction(from, to) {
+            Some(junction) => self.junctions[junction] = connection,
+            None => make_between(&mut self.mazes[from.region], from.pt, to.pt),
+        }
+    }
+}
+
+impl <'layout, Maze: MazeCoordinator> MazeCoordinator for CompositeMazeCoordinator<'layout, Maze> {
+    type CoordSpace = CompositeCoordinateSpace<'layout, Maze::CoordSpace>;
+    type CellLoc = Maze::CellLoc;
+    type CellVal = Maze::CellVal;
+
+    fn coord_space(&self) -> &Self::CoordSpace {
+        &self.space
+    }
+
+    fn marks(&self) -> &Marks<pt!()> {
+        &self.marks
+    }
+
+    fn marks_mut(&mut self) -> &mut Marks<pt!()> {
+        &mut self.marks
+    }
+
+    fn get(&self, pt: pt!()) -> Self::CellVal {
+        self.mazes[pt.region].get(pt.pt)
+    }
+
+    /// The connection across a junction is whatever it was last set to, regardless of the points
+    /// at either end.
+    fn get_adjacent_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        match self.get_junction(from, to) {
+            Some(junction) => self.junctions[junction],
+            None => self.mazes[from.region].get_adjacent_connection(from.pt, to.pt),
+        }
+    }
+
+    fn make_passage(&mut self, pt: pt!()) {
+        self.mazes[pt.region].make_passage(pt.pt)
+    }
+
+    /// If the points are joined by a junction, the points at either end are made into passages
+    /// too.
+    fn make_passage_between(&mut self, from: pt!(), to: pt!()) {
+        if self.get_junction(from, to).is_some() {
+            self.make_passage(from);
+            self.make_passage(to);
+        }
+
+        self.make_between(from, to, ConnectionType::PASSAGE, Maze::make_passage_between)
+    }
+
+    fn make_wall(&mut self, pt: pt!()) {
+        self.mazes[pt.region].make_wall(pt.pt)
+    }
+
+    fn make_wall_between(&mut self, from: pt!(), to: pt!()) {
+        self.make_between(from, to, ConnectionType::WALL, Maze::make_wall_between)
+    }
+
+    fn make_boundary(&mut self, pt: pt!()) {
+        self.mazes[pt.region].make_boundary(pt.pt)
+    }
+
+    fn make_boundary_between(&mut self, from: pt!(), to: pt!()) {
+        self.make_between(from, to, ConnectionType::BOUNDARY, Maze::make_boundary_between)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,266 @@
+//! A coordinator that stops generators from carving through edges that must stay walls.
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::{CoordinateSpace, Point};
+use crate::mark::Marks;
+use crate::pt;
+
+/// Edges that a maze must have as passages, and edges it must have as walls, for hand-tuning a
+/// level before it is generated.
+///
+/// Constraints are given as pairs of adjacent points. The order of the points doesn't matter.
+///
+/// To generate a maze that meets its constraints, use a
+/// [`ConstrainedGenerator`][crate::implm::generate::ConstrainedGenerator].
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::implm::coordinate::constrained::EdgeConstraints;
+/// use mazelib::implm::point::boxy::CoordinateTuplet;
+///
+/// let mut constraints = EdgeConstraints::<CoordinateTuplet<2>>::new();
+///
+/// constraints.require_passage([0, 0].into(), [1, 0].into());
+/// constraints.require_wall([1, 0].into(), [1, 1].into());
+///
+/// assert!(constraints.is_passage_required([1, 0].into(), [0, 0].into()));
+/// assert!(constraints.is_wall_required([1, 1].into(), [1, 0].into()));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EdgeConstraints<Pt: Point> {
+    /// The edges that must be passages, in the order they were added.
+    passages: Vec<(Pt, Pt)>,
+    /// The edges that must be walls, in the order they were added.
+    walls: Vec<(Pt, Pt)>,
+    /// Every edge in `passages`, in both directions.
+    passage_lookup: HashSet<(Pt, Pt)>,
+    /// Every edge in `walls`, in both directions.
+    wall_lookup: HashSet<(Pt, Pt)>,
+}
+
+impl <Pt: Point> EdgeConstraints<Pt> {
+    /// Construct a new, empty set of constraints.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { passages: Vec::new(), walls: Vec::new(), passage_lookup: HashSet::new(), wall_lookup: HashSet::new() }
+    }
+
+    /// Require the edge between `a` and `b` to be a passage.
+    ///
+    /// # Panics
+    ///
+    /// If the edge is already required to be a wall.
+    pub fn require_passage(&mut self, a: Pt, b: Pt) {
+        assert!(self.is_wall_required(a, b) == false, "The edge between {:?} and {:?} is already required to be a wall", a, b);
+
+        if self.passage_lookup.insert((a, b)) {
+            self.passage_lookup.insert((b, a));
+            self.passages.push((a, b));
+        }
+    }
+
+    /// Require the edge between `a` and `b` to be a wall.
+    ///
+    /// # Panics
+    ///
+    /// If the edge is already required to be a passage.
+    pub fn require_wall(&mut self, a: Pt, b: Pt) {
+        assert!(self.is_passage_required(a, b) == false, "The edge between {:?} and {:?} is already required to be a passage", a, b);
+
+        if self.wall_lookup.insert((a, b)) {
+            self.wall_lookup.insert((b, a));
+            self.walls.push((a, b));
+        }
+    }
+
+    /// Return whether the edge between `a` and `b` is required to be a passage.
+    #[must_use]
+    pub fn is_passage_required(&self, a: Pt, b: Pt) -> bool {
+        self.passage_lookup.contains(&(a, b))
+    }
+
+    /// Return whether the edge between `a` and `b` is required to be a wall.
+    #[must_use]
+    pub fn is_wall_required(&self, a: Pt, b: Pt) -> bool {
+        self.wall_lookup.contains(&(a, b))
+    }
+
+    /// Return every edge required to be a passage, in the order they were added.
+    #[must_use]
+    pub fn passages(&self) -> &[(Pt, Pt)] {
+        &self.passages
+    }
+
+    /// Return every edge required to be a wall, in the order they were added.
+    #[must_use]
+    pub fn walls(&self) -> &[(Pt, Pt)] {
+        &self.walls
+    }
+
+    /// Return whether there are no constraints.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.passages.is_empty() && self.walls.is_empty()
+    }
+
+    /// Check that `maze` meets every constraint, returning the first one it doesn't.
+    ///
+    /// Edges required to be passages are checked first, in the order they were added, followed by
+    /// edges required to be walls. An edge required to be a wall may be a boundary.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first constraint that `maze` doesn't meet.
+    pub fn check<M: MazeCoordinator>(&self, maze: &M) -> Result<(), ConstraintViolation<Pt>> where M::CoordSpace: CoordinateSpace<PtType = Pt> {
+        if let Some((a, b)) = self.passages.iter().find(|(a, b)| maze.is_passage_between(*a, *b) == false) {
+            return Err(ConstraintViolation::MissingPassage(*a, *b))
+        }
+
+        if let Some((a, b)) = self.walls.iter().find(|(a, b)| maze.is_passage_between(*a, *b)) {
+            return Err(ConstraintViolation::UnwantedPassage(*a, *b))
+        }
+
+        return Ok(())
+    }
+}
+
+impl <Pt: Point> Default for EdgeConstraints<Pt> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A constraint that a maze doesn't meet.
+///
+/// *See [`EdgeConstraints::check()`].*
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ConstraintViolation<Pt: Point> {
+    /// The edge between the points is required to be a passage, but isn't.
+    MissingPassage(Pt, Pt),
+    /// The edge between the points is required to be a wall, but is a passage.
+    UnwantedPassage(Pt, Pt),
+}
+
+impl <Pt: Point> Display for ConstraintViolation<Pt> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingPassage(a, b) => write!(f, "The edge between {:?} and {:?} is required to be a passage, but isn't", a, b),
+            Self::UnwantedPassage(a, b) => write!(f, "The edge between {:?} and {:?} is required to be a wall, but is a passage", a, b),
+        }
+    }
+}
+
+impl <Pt: Point> Error for ConstraintViolation<Pt> {}
+
+/// A maze coordinator that wraps another maze and stops passages being made through the edges
+/// that [constraints][EdgeConstraints] require to be walls.
+///
+/// The wrapped maze stores all the cells, and almost every operation is passed straight through to
+/// it. The exception is edges required to be walls, which this coordinator reports as boundaries.
+/// As generators never carve through boundaries, they leave these edges alone. Making a passage
+/// through one of them only makes a passage at the point it starts from.
+///
+/// Edges required to be passages aren't enforced by this coordinator. A
+/// [`ConstrainedGenerator`][crate::implm::generate::ConstrainedGenerator] carves them in after
+/// generation.
+#[derive(Debug)]
+pub struct ConstrainedMazeCoordinator<'maze, Maze: MazeCoordinator> {
+    maze: &'maze mut Maze,
+    constraints: &'maze EdgeConstraints<<Maze::CoordSpace as CoordinateSpace>::PtType>,
+}
+
+impl <'maze, Maze: MazeCoordinator> ConstrainedMazeCoordinator<'maze, Maze> {
+    /// Constrain `maze` by `constraints`.
+    #[must_use]
+    pub fn new(maze: &'maze mut Maze, constraints: &'maze EdgeConstraints<<Maze::CoordSpace as CoordinateSpace>::PtType>) -> Self {
+        Self { maze, constraints }
+    }
+
+    /// Return the wrapped maze.
+    #[must_use]
+    pub fn maze(&self) -> &Maze {
+        self.maze
+    }
+
+    /// Return the wrapped maze, mutably.
+    ///
+    /// Changes made through this are not constrained.
+    #[must_use]
+    pub fn maze_mut(&mut self) -> &mut Maze {
+        self.maze
+    }
+
+    /// Return the constraints.
+    #[must_use]
+    pub fn constraints(&self) -> &EdgeConstraints<<Maze::CoordSpace as CoordinateSpace>::PtType> {
+        self.constraints
+    }
+}
+
+impl <'maze, Maze: MazeCoordinator> MazeCoordinator for ConstrainedMazeCoordinator<'maze, Maze> {
+    type CoordSpace = Maze::CoordSpace;
+    type CellLoc = Maze::CellLoc;
+    type CellVal = Maze::CellVal;
+
+    fn coord_space(&self) -> &Self::CoordSpace {
+        self.maze.coord_space()
+    }
+
+    fn marks(&self) -> &Marks<pt!()> {
+        self.maze.marks()
+    }
+
+    fn marks_mut(&mut self) -> &mut Marks<pt!()> {
+        self.maze.marks_mut()
+    }
+
+    fn get(&self, pt: pt!()) -> Self::CellVal {
+        self.maze.get(pt)
+    }
+
+    /// Return [`ConnectionType::BOUNDARY`] for edges required to be walls, and the wrapped maze's
+    /// connection otherwise.
+    fn get_adjacent_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        if self.constraints.is_wall_required(from, to) {
+            ConnectionType::BOUNDARY
+        } else {
+            self.maze.get_adjacent_connection(from, to)
+        }
+    }
+
+    fn make_passage(&mut self, pt: pt!()) {
+        self.maze.make_passage(pt)
+    }
+
+    /// Make a passage from `from` to `to`, unless the edge between them is required to be a wall,
+    /// in which case only `from` is made a passage.
+    fn make_passage_between(&mut self, from: pt!(), to: pt!()) {
+        if self.constraints.is_wall_required(from, to) {
+            self.maze.make_passage(from)
+        } else {
+            self.maze.make_passage_between(from, to)
+        }
+    }
+
+    fn make_wall(&mut self, pt: pt!()) {
+        self.maze.make_wall(pt)
+    }
+
+    fn make_wall_between(&mut self, from: pt!(), to: pt!()) {
+        self.maze.make_wall_between(from, to)
+    }
+
+    fn make_boundary(&mut self, pt: pt!()) {
+        self.maze.make_boundary(pt)
+    }
+
+    fn make_boundary_between(&mut self, from: pt!(), to: pt!()) {
+        self.maze.make_boundary_between(from, to)
+    }
+}
\ No newline at end of file
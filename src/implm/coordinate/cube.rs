@@ -0,0 +1,60 @@
+//! Coordinators for [cube cells][crate::implm::cell::cube].
+
+use crate::implm::cell::cube::CubeCellValue;
+use crate::implm::coordinate::edge::{EdgeCellMazeCoordinator, EdgeCellMazeCoordinatorBuilder, EdgeCellSpace};
+use crate::implm::export::text::{CubeSpaceCubeCellTextMazeExporter, ExportToString};
+use crate::implm::point::cube::{CubeCoordinate, CubeCoordinateSpace, CubeEdge};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+
+/// A maze coordinator for mazes on the surface of a cube.
+///
+/// Every point is mapped to exactly one [cube cell][crate::implm::cell::cube], which stores the
+/// state of each of its four edges.
+///
+/// As the surface of a cube has no boundary, every edge of every cell can be carved by generators.
+/// Passages may freely cross from one face to another.
+///
+/// *See [`EdgeCellMazeCoordinator`] for the methods and the builder.*
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::cube::CubeCellValue;
+/// use mazelib::implm::coordinate::cube::CubeSpaceCubeCellMazeCoordinator;
+/// use mazelib::implm::export::text::CubeSpaceCubeCellTextMazeExporter;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::cube::CubeCoordinateSpace;
+/// use mazelib::interface::export::DefaultMazeExporter;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// let mut maze = CubeSpaceCubeCellMazeCoordinator::<VecBuffer<CubeCellValue>>::builder(CubeCoordinateSpace::new_checked(6)).build();
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// CubeSpaceCubeCellTextMazeExporter::export(&maze, &mut std::io::stdout()).unwrap();
+/// ```
+pub type CubeSpaceCubeCellMazeCoordinator<Buffer> = EdgeCellMazeCoordinator<CubeCoordinateSpace, Buffer>;
+
+/// A builder for a [`CubeSpaceCubeCellMazeCoordinator`].
+pub type CubeSpaceCubeCellMazeCoordinatorBuilder<Buffer> = EdgeCellMazeCoordinatorBuilder<CubeCoordinateSpace, Buffer>;
+
+impl EdgeCellSpace for CubeCoordinateSpace {
+    type CellVal = CubeCellValue;
+
+    fn cell_id(&self, pt: CubeCoordinate) -> CellID {
+        let face_size = usize::from(self.face_size());
+
+        CellID(pt.x + (pt.y + pt.face as usize * face_size) * face_size)
+    }
+
+    /// The edges are not always opposites, as faces are rotated relative to each other.
+    fn edges_between(&self, from: CubeCoordinate, to: CubeCoordinate) -> Option<[CubeEdge; 2]> {
+        Some([self.edge_between(from, to)?, self.edge_between(to, from)?])
+    }
+
+    fn debug_text<Buffer: MazeBuffer<CubeCellValue>>(maze: &CubeSpaceCubeCellMazeCoordinator<Buffer>) -> Option<String> {
+        Some(CubeSpaceCubeCellTextMazeExporter::default().export_to_string(maze).unwrap_or_else(|err| panic!("{}", err)))
+    }
+}
\ No newline at end of file
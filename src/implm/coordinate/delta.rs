@@ -0,0 +1,56 @@
+//! Coordinators for [delta cells][crate::implm::cell::delta].
+
+use crate::implm::cell::delta::DeltaCellValue;
+use crate::implm::coordinate::edge::{EdgeCellMazeCoordinator, EdgeCellMazeCoordinatorBuilder, EdgeCellSpace};
+use crate::implm::export::text::{DeltaSpaceDeltaCellTextMazeExporter, ExportToString};
+use crate::implm::point::delta::{DeltaCoordinate, DeltaCoordinateSpace, DeltaEdge};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+
+/// A maze coordinator for delta (triangular) mazes.
+///
+/// Every point is mapped to exactly one [delta cell][crate::implm::cell::delta], which stores the
+/// state of each of its three edges.
+///
+/// *See [`EdgeCellMazeCoordinator`] for the methods and the builder.*
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::delta::DeltaCellValue;
+/// use mazelib::implm::coordinate::delta::DeltaSpaceDeltaCellMazeCoordinator;
+/// use mazelib::implm::export::text::DeltaSpaceDeltaCellTextMazeExporter;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::delta::DeltaCoordinateSpace;
+/// use mazelib::interface::export::DefaultMazeExporter;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// let mut maze = DeltaSpaceDeltaCellMazeCoordinator::<VecBuffer<DeltaCellValue>>::builder(DeltaCoordinateSpace::new_checked(15, 8)).build();
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// DeltaSpaceDeltaCellTextMazeExporter::export(&maze, &mut std::io::stdout()).unwrap();
+/// ```
+pub type DeltaSpaceDeltaCellMazeCoordinator<Buffer> = EdgeCellMazeCoordinator<DeltaCoordinateSpace, Buffer>;
+
+/// A builder for a [`DeltaSpaceDeltaCellMazeCoordinator`].
+pub type DeltaSpaceDeltaCellMazeCoordinatorBuilder<Buffer> = EdgeCellMazeCoordinatorBuilder<DeltaCoordinateSpace, Buffer>;
+
+impl EdgeCellSpace for DeltaCoordinateSpace {
+    type CellVal = DeltaCellValue;
+
+    fn cell_id(&self, pt: DeltaCoordinate) -> CellID {
+        CellID(pt.column + pt.row * usize::from(self.columns()))
+    }
+
+    fn edges_between(&self, from: DeltaCoordinate, to: DeltaCoordinate) -> Option<[DeltaEdge; 2]> {
+        let edge = self.edge_between(from, to)?;
+
+        return Some([edge, edge.opposite()])
+    }
+
+    fn debug_text<Buffer: MazeBuffer<DeltaCellValue>>(maze: &DeltaSpaceDeltaCellMazeCoordinator<Buffer>) -> Option<String> {
+        Some(DeltaSpaceDeltaCellTextMazeExporter::default().export_to_string(maze).unwrap_or_else(|err| panic!("{}", err)))
+    }
+}
\ No newline at end of file
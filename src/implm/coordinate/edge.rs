@@ -0,0 +1,295 @@
+//! Coordinators for [edge cells][crate::implm::cell::edge].
+//!
+//! A single coordinator, [`EdgeCellMazeCoordinator`], serves every coordinate space whose points
+//! each map to one cell with an edge per neighbour. All that differs between the spaces is how
+//! their points are laid out in the buffer and which edges face each other, which each space
+//! provides by implementing [`EdgeCellSpace`].
+
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use crate::implm::cell::edge::{EdgeCell, EdgeCellLocation};
+use crate::implm::cell::inline::InlineCellValueEdge;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::mark::Marks;
+use crate::pt;
+
+/// A coordinate space that can be used with an [`EdgeCellMazeCoordinator`].
+pub trait EdgeCellSpace: CoordinateSpace {
+    /// The type of cell each point is mapped to.
+    type CellVal: EdgeCell;
+
+    /// Return the ID of the cell of `pt`.
+    ///
+    /// Every point must have a different ID, and all IDs must be less than the space's
+    /// [logical size][CoordinateSpace::logical_size].
+    #[must_use]
+    fn cell_id(&self, pt: Self::PtType) -> CellID;
+
+    /// Return the edge of `from` that `to` lies across, and the edge of `to` that `from` lies
+    /// across, or `None` if the points aren't adjacent.
+    #[must_use]
+    fn edges_between(&self, from: Self::PtType, to: Self::PtType) -> Option<[<Self::CellVal as EdgeCell>::Edge; 2]>;
+
+    /// Return the value the cell of `pt` has in a new maze.
+    ///
+    /// Edges that the cell doesn't have should be [`InlineCellValueEdge::BOUNDARY`]s. They are
+    /// left alone by [`MazeCoordinator::make_wall()`].
+    ///
+    /// Defaults to every edge being [`InlineCellValueEdge::UNVISITED`].
+    #[must_use]
+    fn new_cell(&self, _pt: Self::PtType) -> Self::CellVal {
+        Self::CellVal::default()
+    }
+
+    /// Render `maze` as text, to be included in its [`Debug`] output.
+    ///
+    /// Defaults to `None`, for spaces without a text exporter.
+    #[must_use]
+    fn debug_text<Buffer: MazeBuffer<Self::CellVal>>(_maze: &EdgeCellMazeCoordinator<Self, Buffer>) -> Option<String> {
+        None
+    }
+}
+
+/// A maze coordinator for [edge cells][crate::implm::cell::edge], in any [`EdgeCellSpace`].
+///
+/// Every point is mapped to exactly one cell, which stores the state of each of its edges. A
+/// connection is made by setting the edges on both sides of it.
+///
+/// Edges on the outside of the maze are never carved by generators, as there is no point on the
+/// other side of them. They become walls once their cell is visited, just like in a
+/// [`BoxSpaceInlineCellMazeCoordinator`][crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator].
+///
+/// You will usually use this through one of its aliases, such as
+/// [`HexSpaceHexCellMazeCoordinator`][crate::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator].
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct EdgeCellMazeCoordinator<Space: EdgeCellSpace, Buffer: MazeBuffer<Space::CellVal>> {
+    buffer: Buffer,
+    space: Space,
+    /// The maze's named points, annotations, and portals.
+    marks: Marks<Space::PtType>,
+}
+
+// Constructor (private - use the builder)
+impl <Space: EdgeCellSpace, Buffer: MazeBuffer<Space::CellVal>> EdgeCellMazeCoordinator<Space, Buffer> {
+    /// Construct a new maze from a given coordinate space.
+    /// A [`MazeBuffer`] will be created from the value of type parameter `Buffer`.
+    #[must_use]
+    fn new(space: Space) -> Self {
+        let mut maze = Self { buffer: Buffer::new(space.logical_size()), space, marks: Marks::new() };
+
+        for pt in space.iter() {
+            let cell = space.new_cell(pt);
+
+            if cell != Space::CellVal::default() {
+                maze.set(pt, cell);
+            }
+        }
+
+        return maze
+    }
+}
+
+// Public functions
+impl <Space: EdgeCellSpace, Buffer: MazeBuffer<Space::CellVal>> EdgeCellMazeCoordinator<Space, Buffer> {
+    /// Return the maze's buffer.
+    #[must_use]
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Return the maze's buffer mutably. *See [Buffers][MazeCoordinator#buffers].*
+    #[must_use]
+    pub fn buffer_mut(&mut self) -> &mut Buffer {
+        &mut self.buffer
+    }
+
+    /// Set the value of a cell.
+    ///
+    /// In most cases you should use the methods on [`MazeCoordinator`] instead of this.
+    pub fn set(&mut self, pt: pt!(), value: <Self as MazeCoordinator>::CellVal) {
+        self.buffer.set(self.space.cell_id(pt), value)
+    }
+}
+
+// Internal functions
+impl <Space: EdgeCellSpace, Buffer: MazeBuffer<Space::CellVal>> EdgeCellMazeCoordinator<Space, Buffer> {
+    #[must_use]
+    fn get_mut(&mut self, pt: pt!()) -> &mut <Self as MazeCoordinator>::CellVal {
+        self.buffer.get_mut(self.space.cell_id(pt))
+    }
+
+    #[must_use]
+    fn get_edges(&self, from: pt!(), to: pt!()) -> [<Space::CellVal as EdgeCell>::Edge; 2] {
+        self.space.edges_between(from, to).expect("from and to are not adjacent")
+    }
+
+    fn set_unvisited_edges_to_wall(cell: &mut Space::CellVal) {
+        for edge in cell.edges_mut() {
+            if *edge == InlineCellValueEdge::UNVISITED {
+                *edge = InlineCellValueEdge::WALL;
+            }
+        }
+    }
+
+    /// Set the edge between the two cells to `edge_type`, for both cells.
+    ///
+    /// All [`InlineCellValueEdge::UNVISITED`] edges will be replaced with
+    /// [`InlineCellValueEdge::WALL`].
+    fn make_between(&mut self, from: pt!(), to: pt!(), edge_type: InlineCellValueEdge) {
+        let [from_edge, to_edge] = self.get_edges(from, to);
+
+        let from_existing = self.get_mut(from);
+        from_existing.set_edge(from_edge, edge_type);
+        Self::set_unvisited_edges_to_wall(from_existing);
+
+        let to_existing = self.get_mut(to);
+        to_existing.set_edge(to_edge, edge_type);
+        Self::set_unvisited_edges_to_wall(to_existing);
+    }
+}
+
+impl <Space: EdgeCellSpace, Buffer: MazeBuffer<Space::CellVal>> MazeCoordinator for EdgeCellMazeCoordinator<Space, Buffer> {
+    type CoordSpace = Space;
+    type CellLoc = EdgeCellLocation<Space::PtType>;
+    type CellVal = Space::CellVal;
+
+    fn coord_space(&self) -> &Self::CoordSpace {
+        &self.space
+    }
+
+    fn marks(&self) -> &Marks<pt!()> {
+        &self.marks
+    }
+
+    fn marks_mut(&mut self) -> &mut Marks<pt!()> {
+        &mut self.marks
+    }
+
+    fn get(&self, pt: pt!()) -> Self::CellVal {
+        self.buffer.get(self.space.cell_id(pt))
+    }
+
+    fn get_adjacent_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        let [from_edge, to_edge] = self.get_edges(from, to);
+
+        let from_wall = self.get(from).get_edge(from_edge);
+        let to_wall = self.get(to).get_edge(to_edge);
+
+        return match [from_wall, to_wall] {
+            [InlineCellValueEdge::BOUNDARY,  _] | [_, InlineCellValueEdge::BOUNDARY ] => ConnectionType::BOUNDARY,
+            [InlineCellValueEdge::UNVISITED, _] | [_, InlineCellValueEdge::UNVISITED] => ConnectionType::UNVISITED,
+            [InlineCellValueEdge::WALL,      _] | [_, InlineCellValueEdge::WALL     ] => ConnectionType::WALL,
+            [InlineCellValueEdge::PASSAGE, InlineCellValueEdge::PASSAGE]              => ConnectionType::PASSAGE,
+        };
+    }
+
+    /// Replace all edges of `pt` that are [`InlineCellValueEdge::UNVISITED`] with
+    /// [`InlineCellValueEdge::WALL`].
+    fn make_passage(&mut self, pt: pt!()) {
+        Self::set_unvisited_edges_to_wall(self.get_mut(pt));
+    }
+
+    /// Set the edge between the two cells to [`InlineCellValueEdge::PASSAGE`], for both cells.
+    ///
+    /// All [`InlineCellValueEdge::UNVISITED`] edges will be replaced with
+    /// [`InlineCellValueEdge::WALL`].
+    fn make_passage_between(&mut self, from: pt!(), to: pt!()) {
+        self.make_between(from, to, InlineCellValueEdge::PASSAGE)
+    }
+
+    /// Set all edges of `pt` to [`InlineCellValueEdge::WALL`].
+    ///
+    /// Edges that the cell doesn't have (see [`EdgeCellSpace::new_cell()`]) are left untouched.
+    fn make_wall(&mut self, pt: pt!()) {
+        let template = self.space.new_cell(pt);
+
+        for (edge, template_edge) in self.get_mut(pt).edges_mut().zip(template.edges()) {
+            if template_edge != InlineCellValueEdge::BOUNDARY {
+                *edge = InlineCellValueEdge::WALL;
+            }
+        }
+    }
+
+    /// Set the edge between the two cells to [`InlineCellValueEdge::WALL`], for both cells.
+    ///
+    /// All [`InlineCellValueEdge::UNVISITED`] edges will be replaced with
+    /// [`InlineCellValueEdge::WALL`].
+    fn make_wall_between(&mut self, from: pt!(), to: pt!()) {
+        self.make_between(from, to, InlineCellValueEdge::WALL)
+    }
+
+    /// Set all edges of `pt` to [`InlineCellValueEdge::BOUNDARY`].
+    fn make_boundary(&mut self, pt: pt!()) {
+        for edge in self.get_mut(pt).edges_mut() {
+            *edge = InlineCellValueEdge::BOUNDARY;
+        }
+    }
+
+    /// Set the edge between the two cells to [`InlineCellValueEdge::BOUNDARY`], for both cells.
+    ///
+    /// All [`InlineCellValueEdge::UNVISITED`] edges will be replaced with
+    /// [`InlineCellValueEdge::WALL`].
+    fn make_boundary_between(&mut self, from: pt!(), to: pt!()) {
+        self.make_between(from, to, InlineCellValueEdge::BOUNDARY)
+    }
+}
+
+// Builder
+impl <Space: EdgeCellSpace, Buffer: MazeBuffer<Space::CellVal>> EdgeCellMazeCoordinator<Space, Buffer> {
+    /// Construct a new builder for an `EdgeCellMazeCoordinator`.
+    pub fn builder(space: Space) -> EdgeCellMazeCoordinatorBuilder<Space, Buffer> {
+        EdgeCellMazeCoordinatorBuilder::new(space)
+    }
+}
+
+/// A builder for an [`EdgeCellMazeCoordinator`].
+#[must_use]
+pub struct EdgeCellMazeCoordinatorBuilder<Space: EdgeCellSpace, Buffer: MazeBuffer<Space::CellVal>> {
+    _buffer: PhantomData<Buffer>,  // We're not actually interested in constructing a buffer yet
+    /// The maze's coordinate space.
+    space: Space,
+}
+
+impl <Space: EdgeCellSpace, Buffer: MazeBuffer<Space::CellVal>> EdgeCellMazeCoordinatorBuilder<Space, Buffer> {
+    /// Construct a new builder for an `EdgeCellMazeCoordinator`.
+    ///
+    /// # Parameters
+    ///
+    /// `space` --- the coordinate space to use for the maze.
+    fn new(space: Space) -> Self {
+        Self {
+            _buffer: PhantomData,
+            space,
+        }
+    }
+
+    /// Finalise the [`EdgeCellMazeCoordinator`].
+    #[must_use]
+    pub fn build(&self) -> EdgeCellMazeCoordinator<Space, Buffer> {
+        EdgeCellMazeCoordinator::new(self.space)
+    }
+}
+
+impl <Space: EdgeCellSpace, Buffer: MazeBuffer<Space::CellVal>> Debug for EdgeCellMazeCoordinator<Space, Buffer> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "EdgeCellMazeCoordinator {{")?;
+        writeln!(f, "\tbuffer: {:?}", self.buffer)?;
+        writeln!(f, "\tspace: {:?}", self.space)?;
+
+        if let Some(text) = Space::debug_text(self) {
+            writeln!(f)?;
+
+            for line in text.lines() {
+                writeln!(f, "\t{}", line)?;
+            };
+        }
+
+        writeln!(f, "}}")?;
+
+        return Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,257 @@
+//! Coordinators for [graph cells][crate::implm::cell::graph].
+
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::graph::GraphCellLocation;
+use crate::implm::point::graph::{GraphCoordinateSpace, GraphPoint};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::mark::Marks;
+use crate::pt;
+
+/// A maze coordinator for mazes shaped like arbitrary graphs.
+///
+/// Every point (node) is mapped to one [graph cell][crate::implm::cell::graph], and so is every
+/// edge between two points. Two points are connected by a passage if both of them and the edge
+/// between them are passages.
+///
+/// As the coordinate space borrows its graph, so does the coordinator.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// use mazelib::implm::coordinate::graph::GraphSpaceGraphCellMazeCoordinator;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::graph::{Graph, GraphCoordinateSpace};
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// // A wheel: a hub joined to every node of a rim
+/// let graph = Graph::from_edges(7, (1..7).flat_map(|node| [[0, node], [node, node % 6 + 1]]));
+///
+/// let mut maze = GraphSpaceGraphCellMazeCoordinator::<VecBuffer<BlockCellValue>>::builder(GraphCoordinateSpace::new(&graph)).build();
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// println!("{}", maze.is_passage_between(0.into(), 1.into()));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GraphSpaceGraphCellMazeCoordinator<'graph, Buffer: MazeBuffer<BlockCellValue>> {
+    buffer: Buffer,
+    space: GraphCoordinateSpace<'graph>,
+    /// The maze's named points, annotations, and portals.
+    marks: Marks<GraphPoint>,
+}
+
+// Constructor (private - use the builder)
+impl <'graph, Buffer: MazeBuffer<BlockCellValue>> GraphSpaceGraphCellMazeCoordinator<'graph, Buffer> {
+    /// Construct a new maze from a given coordinate space.
+    /// A [`MazeBuffer`] will be created from the value of type parameter `Buffer`.
+    #[must_use]
+    fn new(space: GraphCoordinateSpace<'graph>) -> Self {
+        let cells_required = space.logical_size().checked_add(space.graph().edge_count()).expect("The number of cells in the maze does not fit within a usize");
+
+        Self { buffer: Buffer::new(cells_required), space, marks: Marks::new() }
+    }
+}
+
+// Public functions
+impl <'graph, Buffer: MazeBuffer<BlockCellValue>> GraphSpaceGraphCellMazeCoordinator<'graph, Buffer> {
+    /// Return the maze's buffer.
+    #[must_use]
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Return the maze's buffer mutably. *See [Buffers][MazeCoordinator#buffers].*
+    #[must_use]
+    pub fn buffer_mut(&mut self) -> &mut Buffer {
+        &mut self.buffer
+    }
+
+    /// Get the value of any cell, including the cells of edges.
+    ///
+    /// In most cases you should use the methods on [`MazeCoordinator`] instead of this.
+    #[must_use]
+    pub fn get_cell_value(&self, loc: <Self as MazeCoordinator>::CellLoc) -> BlockCellValue {
+        self.buffer.get(self.cell_loc_to_id(loc))
+    }
+
+    /// Set the value of any cell, including the cells of edges.
+    ///
+    /// In most cases you should use the methods on [`MazeCoordinator`] instead of this.
+    pub fn set_cell_value(&mut self, loc: <Self as MazeCoordinator>::CellLoc, value: BlockCellValue) {
+        self.buffer.set(self.cell_loc_to_id(loc), value)
+    }
+}
+
+// Internal functions
+impl <'graph, Buffer: MazeBuffer<BlockCellValue>> GraphSpaceGraphCellMazeCoordinator<'graph, Buffer> {
+    /// Convert a [`GraphCellLocation`] to a [`CellID`].
+    ///
+    /// Nodes come first, followed by edges.
+    #[must_use]
+    fn cell_loc_to_id(&self, loc: <Self as MazeCoordinator>::CellLoc) -> CellID {
+        match loc {
+            GraphCellLocation::NODE(node) => CellID(node),
+            GraphCellLocation::EDGE(edge) => CellID(usize::from(self.space.logical_size()) + edge),
+        }
+    }
+
+    /// Return the location of the cell of the edge between `from` and `to`.
+    #[must_use]
+    fn get_edge(&self, from: pt!(), to: pt!()) -> <Self as MazeCoordinator>::CellLoc {
+        GraphCellLocation::EDGE(self.space.graph().edge_between(from.0, to.0).expect("from and to are not adjacent"))
+    }
+
+    fn set_cell_value_type(&mut self, loc: <Self as MazeCoordinator>::CellLoc, cell_type: BlockCellPrimaryValue) {
+        self.buffer.get_mut(self.cell_loc_to_id(loc)).cell_type = cell_type;
+    }
+
+    /// Set every unvisited edge of `pt` to [`BlockCellPrimaryValue::WALL`].
+    fn set_unvisited_edges_to_wall(&mut self, pt: pt!()) {
+        let graph = self.space.graph();
+
+        for edge in graph.incident_edges(pt.0) {
+            let cell = self.buffer.get_mut(self.cell_loc_to_id(GraphCellLocation::EDGE(*edge)));
+
+            if cell.cell_type == BlockCellPrimaryValue::UNVISITED {
+                cell.cell_type = BlockCellPrimaryValue::WALL;
+            }
+        }
+    }
+}
+
+impl <'graph, Buffer: MazeBuffer<BlockCellValue>> MazeCoordinator for GraphSpaceGraphCellMazeCoordinator<'graph, Buffer> {
+    type CoordSpace = GraphCoordinateSpace<'graph>;
+    type CellLoc = GraphCellLocation;
+    type CellVal = BlockCellValue;
+
+    fn coord_space(&self) -> &Self::CoordSpace {
+        &self.space
+    }
+
+    fn marks(&self) -> &Marks<pt!()> {
+        &self.marks
+    }
+
+    fn marks_mut(&mut self) -> &mut Marks<pt!()> {
+        &mut self.marks
+    }
+
+    fn get(&self, pt: pt!()) -> Self::CellVal {
+        self.get_cell_value(pt.into())
+    }
+
+    /// The connection is determined by both points and the edge between them.
+    fn get_adjacent_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        let cell_types = [from.into(), self.get_edge(from, to), to.into()].map(|loc| self.get_cell_value(loc).cell_type);
+
+        cell_types.into_iter().fold(ConnectionType::PASSAGE, |connection, cell_type| match (connection, cell_type) {
+            (ConnectionType::BOUNDARY,  _) | (_, BlockCellPrimaryValue::BOUNDARY ) => ConnectionType::BOUNDARY,
+            (ConnectionType::UNVISITED, _) | (_, BlockCellPrimaryValue::UNVISITED) => ConnectionType::UNVISITED,
+            (ConnectionType::WALL,      _) | (_, BlockCellPrimaryValue::WALL     ) => ConnectionType::WALL,
+            (ConnectionType::PASSAGE,            BlockCellPrimaryValue::PASSAGE  ) => ConnectionType::PASSAGE,
+        })
+    }
+
+    /// Set `pt` to [`BlockCellPrimaryValue::PASSAGE`]. All of its unvisited edges are set to
+    /// [`BlockCellPrimaryValue::WALL`].
+    fn make_passage(&mut self, pt: pt!()) {
+        self.set_cell_value_type(pt.into(), BlockCellPrimaryValue::PASSAGE);
+        self.set_unvisited_edges_to_wall(pt);
+    }
+
+    /// Set `from`, `to`, and the edge between them to [`BlockCellPrimaryValue::PASSAGE`].
+    ///
+    /// All unvisited edges of `from` are set to [`BlockCellPrimaryValue::WALL`]. Note that this
+    /// excludes `to`, so that maze carvers will be able to progress. If you wish for `to`'s edges
+    /// to also become walls, simply call [`Self::make_passage()`] on `to` as well.
+    fn make_passage_between(&mut self, from: pt!(), to: pt!()) {
+        let edge = self.get_edge(from, to);
+
+        self.make_passage(from);
+        self.set_cell_value_type(edge, BlockCellPrimaryValue::PASSAGE);
+        self.set_cell_value_type(to.into(), BlockCellPrimaryValue::PASSAGE);
+    }
+
+    /// Set `pt` to [`BlockCellPrimaryValue::WALL`].
+    fn make_wall(&mut self, pt: pt!()) {
+        self.set_cell_value_type(pt.into(), BlockCellPrimaryValue::WALL);
+    }
+
+    /// Set the edge between `from` and `to` to [`BlockCellPrimaryValue::WALL`]. The points
+    /// themselves are left untouched.
+    fn make_wall_between(&mut self, from: pt!(), to: pt!()) {
+        let edge = self.get_edge(from, to);
+
+        self.set_cell_value_type(edge, BlockCellPrimaryValue::WALL);
+    }
+
+    /// Set `pt` to [`BlockCellPrimaryValue::BOUNDARY`].
+    fn make_boundary(&mut self, pt: pt!()) {
+        self.set_cell_value_type(pt.into(), BlockCellPrimaryValue::BOUNDARY);
+    }
+
+    /// Set the edge between `from` and `to` to [`BlockCellPrimaryValue::BOUNDARY`]. The points
+    /// themselves are left untouched.
+    fn make_boundary_between(&mut self, from: pt!(), to: pt!()) {
+        let edge = self.get_edge(from, to);
+
+        self.set_cell_value_type(edge, BlockCellPrimaryValue::BOUNDARY);
+    }
+}
+
+// Builder
+impl <'graph, Buffer: MazeBuffer<BlockCellValue>> GraphSpaceGraphCellMazeCoordinator<'graph, Buffer> {
+    /// Construct a new builder for a `GraphSpaceGraphCellMazeCoordinator`.
+    pub fn builder(space: GraphCoordinateSpace<'graph>) -> GraphSpaceGraphCellMazeCoordinatorBuilder<'graph, Buffer> {
+        GraphSpaceGraphCellMazeCoordinatorBuilder::new(space)
+    }
+}
+
+/// A builder for a [`GraphSpaceGraphCellMazeCoordinator`].
+#[must_use]
+pub struct GraphSpaceGraphCellMazeCoordinatorBuilder<'graph, Buffer: MazeBuffer<BlockCellValue>> {
+    _buffer: PhantomData<Buffer>,  // We're not actually interested in constructing a buffer yet
+    /// The maze's coordinate space.
+    space: GraphCoordinateSpace<'graph>,
+}
+
+impl <'graph, Buffer: MazeBuffer<BlockCellValue>> GraphSpaceGraphCellMazeCoordinatorBuilder<'graph, Buffer> {
+    /// Construct a new builder for a `GraphSpaceGraphCellMazeCoordinator`.
+    ///
+    /// # Parameters
+    ///
+    /// `space` --- the coordinate space to use for the maze.
+    fn new(space: GraphCoordinateSpace<'graph>) -> Self {
+        Self {
+            _buffer: PhantomData,
+            space,
+        }
+    }
+
+    /// Finalise the [`GraphSpaceGraphCellMazeCoordinator`].
+    #[must_use]
+    pub fn build(&self) -> GraphSpaceGraphCellMazeCoordinator<'graph, Buffer> {
+        GraphSpaceGraphCellMazeCoordinator::new(self.space)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>> Debug for GraphSpaceGraphCellMazeCoordinator<'_, Buffer> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "GraphSpaceGraphCellMazeCoordinator {{")?;
+        writeln!(f, "\tbuffer: {:?}", self.buffer)?;
+        writeln!(f, "\tspace: {:?}", self.space)?;
+
+        writeln!(f, "}}")?;
+
+        return Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+//! Coordinators for [hex cells][crate::implm::cell::hex].
+
+use crate::implm::cell::hex::HexCellValue;
+use crate::implm::coordinate::edge::{EdgeCellMazeCoordinator, EdgeCellMazeCoordinatorBuilder, EdgeCellSpace};
+use crate::implm::export::text::{HexSpaceHexCellTextMazeExporter, ExportToString};
+use crate::implm::point::hex::{HexCoordinate, HexCoordinateSpace, HexDirection};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+
+/// A maze coordinator for sigma (hexagonal) mazes.
+///
+/// Every point is mapped to exactly one [hex cell][crate::implm::cell::hex], which stores the
+/// state of each of its six edges.
+///
+/// *See [`EdgeCellMazeCoordinator`] for the methods and the builder.*
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::hex::HexCellValue;
+/// use mazelib::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+/// use mazelib::implm::export::text::HexSpaceHexCellTextMazeExporter;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::hex::HexCoordinateSpace;
+/// use mazelib::interface::export::DefaultMazeExporter;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// let mut maze = HexSpaceHexCellMazeCoordinator::<VecBuffer<HexCellValue>>::builder(HexCoordinateSpace::new_checked(8, 6)).build();
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// HexSpaceHexCellTextMazeExporter::export(&maze, &mut std::io::stdout()).unwrap();
+/// ```
+pub type HexSpaceHexCellMazeCoordinator<Buffer> = EdgeCellMazeCoordinator<HexCoordinateSpace, Buffer>;
+
+/// A builder for a [`HexSpaceHexCellMazeCoordinator`].
+pub type HexSpaceHexCellMazeCoordinatorBuilder<Buffer> = EdgeCellMazeCoordinatorBuilder<HexCoordinateSpace, Buffer>;
+
+impl EdgeCellSpace for HexCoordinateSpace {
+    type CellVal = HexCellValue;
+
+    fn cell_id(&self, pt: HexCoordinate) -> CellID {
+        CellID(pt.column + pt.row * usize::from(self.columns()))
+    }
+
+    fn edges_between(&self, from: HexCoordinate, to: HexCoordinate) -> Option<[HexDirection; 2]> {
+        let direction = self.direction_between(from, to)?;
+
+        return Some([direction, direction.opposite()])
+    }
+
+    fn debug_text<Buffer: MazeBuffer<HexCellValue>>(maze: &HexSpaceHexCellMazeCoordinator<Buffer>) -> Option<String> {
+        Some(HexSpaceHexCellTextMazeExporter::default().export_to_string(maze).unwrap_or_else(|err| panic!("{}", err)))
+    }
+}
\ No newline at end of file
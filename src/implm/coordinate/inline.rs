@@ -9,40 +9,57 @@
 //!
 //! TODO
 
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
+use std::num::NonZeroUsize;
 
+use crate::error::MazeError;
 use crate::implm::cell::block::BlockCellLocation;
 use crate::implm::cell::inline::InlineCellValue;
 use crate::implm::cell::inline::InlineCellValueEdge;
-use crate::implm::export::text::BoxSpaceInlineCellTextMazeExporter;
-use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::export::text::{BoxSpaceInlineCellTextMazeExporter, ExportToString};
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
 use crate::interface::buffer::MazeBuffer;
 use crate::interface::cell::{CellID, ConnectionType};
 use crate::interface::coordinate::MazeCoordinator;
-use crate::interface::export::MazeExporter;
 use crate::interface::point::CoordinateSpace;
 use crate::internal::array_util::Product;
+use crate::mark::Marks;
 use crate::pt;
 
 /// TODO
 /// 
+/// Coordinate spaces with [diagonal adjacency][BoxCoordinateSpace#diagonal-adjacency] are not
+/// supported.
+/// 
 /// As this coordinator implements a one-to-one mapping between points and cells, there is
 /// no separate [`CellLocation`][crate::interface::cell::CellLocation] struct.
 /// [`CoordinateTuplet`][crate::implm::point::boxy::CoordinateTuplet]s are converted directly
 /// into [`CellID`]s.
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct BoxSpaceInlineCellMazeCoordinator<Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> {
     buffer: Buffer,
     space: BoxCoordinateSpace<DIMENSION>,
+    /// The maze's named points, annotations, and portals.
+    marks: Marks<CoordinateTuplet<DIMENSION>>,
 }
 
 // Constructor (private - use the builder)
 impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION> {
     /// Construct a new maze from a given coordinate space.
     /// A [`MazeBuffer`] will be created from the value of type parameter `Buffer`.
-    #[must_use]
-    fn new(space: BoxCoordinateSpace<DIMENSION>) -> Self {
-        Self { buffer: Buffer::new(space.dimensions().product()), space }
+    ///
+    /// # Errors
+    ///
+    /// [`MazeError::DiagonalsUnsupported`] if the coordinate space has
+    /// [diagonal adjacency][BoxCoordinateSpace#diagonal-adjacency], which inline cells can't
+    /// represent.
+    fn try_new(space: BoxCoordinateSpace<DIMENSION>, make_buffer: impl FnOnce(NonZeroUsize) -> Buffer) -> Result<Self, MazeError> {
+        if space.diagonal() {
+            return Err(MazeError::DiagonalsUnsupported);
+        }
+
+        return Ok(Self { buffer: make_buffer(space.dimensions().product()), space, marks: Marks::new() })
     }
 }
 
@@ -53,6 +70,12 @@ impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> Bo
         &self.buffer
     }
 
+    /// Return the maze's buffer mutably. *See [Buffers][MazeCoordinator#buffers].*
+    #[must_use]
+    pub fn buffer_mut(&mut self) -> &mut Buffer {
+        &mut self.buffer
+    }
+
     /// Set the value of any cell, including ones not mapped by the coordinate space
     ///
     /// Since with a [InlineCellValue] it is impossible to get the maze into an
@@ -62,36 +85,274 @@ impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> Bo
     pub fn set(&mut self, cell: pt!(), value: <Self as MazeCoordinator>::CellVal) {
         self.buffer.set(self.pt_to_cell_id(cell), value)
     }
+
+    /// Set every edge of `pt` to `edge`.
+    ///
+    /// The facing edge of each neighbour of `pt` is set too, so the connection between `pt` and
+    /// every neighbour is consistent. Edges on the side of a non-wrapping axis only exist on
+    /// `pt`. No other edges are changed (unlike
+    /// [`make_passage_between()`][MazeCoordinator::make_passage_between], unvisited edges are left
+    /// as they are).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+    /// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// use mazelib::interface::coordinate::MazeCoordinator;
+    ///
+    /// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+    ///
+    /// maze.set_all_edges((1, 1).into(), InlineCellValueEdge::PASSAGE);
+    ///
+    /// assert!(maze.is_passage_between((1, 1).into(), (1, 0).into()));
+    /// assert!(maze.is_passage_between((2, 1).into(), (1, 1).into()));
+    /// assert!(maze.is_unvisited_between((0, 0).into(), (1, 0).into()));
+    /// ```
+    pub fn set_all_edges(&mut self, pt: pt!(), edge: InlineCellValueEdge) {
+        self.get_mut(pt).edges = [[edge; 2]; DIMENSION];
+
+        for neighbour in self.space.neighbours_of_pt(pt) {
+            let (axis, neighbour_before_pt) = self.get_adjacency(neighbour, pt);
+
+            self.get_mut(neighbour).edges[axis][usize::from(neighbour_before_pt)] = edge;
+        }
+    }
+
+    /// Set the outward-facing edge of every point on one side of the maze to
+    /// [`InlineCellValueEdge::BOUNDARY`].
+    ///
+    /// The side is the one at the end of axis `axis` (numbered as for
+    /// [`CoordinateTuplet`][crate::implm::point::boxy::CoordinateTuplet]s). If `positive` is true,
+    /// it is the side where the points are last along the axis, otherwise it is the side where
+    /// they are first. All other edges are left unchanged.
+    ///
+    /// If the axis [wraps around][BoxCoordinateSpace#wrapping-adjacency], this cuts the
+    /// connections between the two sides.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+    /// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// use mazelib::interface::coordinate::MazeCoordinator;
+    ///
+    /// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+    ///
+    /// // Close the bottom (y = 1) side
+    /// maze.close_side(1, true);
+    ///
+    /// assert_eq!([InlineCellValueEdge::UNVISITED, InlineCellValueEdge::BOUNDARY], maze.get((2, 1).into()).edges[1]);
+    /// assert_eq!([InlineCellValueEdge::UNVISITED; 2], maze.get((2, 0).into()).edges[1]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is not less than `DIMENSION`.
+    pub fn close_side(&mut self, axis: usize, positive: bool) {
+        assert!(axis < DIMENSION, "The axis must be less than the dimension");
+
+        let position = if positive { usize::from(self.space.dimensions()[axis]) - 1 } else { 0 };
+
+        for pt in self.space.iter().filter(|pt| pt[axis] == position) {
+            self.get_mut(pt).edges[axis][usize::from(positive)] = InlineCellValueEdge::BOUNDARY;
+        }
+    }
+
+    /// Return whether a tunnel could be carved from `from` to `to`.
+    ///
+    /// A tunnel passes beneath the point between `from` and `to`, joining them without entering
+    /// it. This is how *weave* mazes are made. For a tunnel to be possible:
+    /// * `from` and `to` must be two apart along a single axis,
+    /// * the point between them must be a straight passage running across that axis (it has
+    ///   passages on both sides along exactly one other axis, and walls everywhere else),
+    /// * there must not already be a tunnel beneath that point, and,
+    /// * neither `from` nor `to` may have a boundary on the side facing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::inline::InlineCellValue;
+    /// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// use mazelib::interface::coordinate::MazeCoordinator;
+    ///
+    /// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+    ///
+    /// assert!(maze.can_tunnel_between((0, 1).into(), (2, 1).into()) == false);
+    ///
+    /// // A vertical passage through the centre
+    /// maze.make_passage_between((1, 0).into(), (1, 1).into());
+    /// maze.make_passage_between((1, 1).into(), (1, 2).into());
+    ///
+    /// assert!(maze.can_tunnel_between((0, 1).into(), (2, 1).into()));
+    ///
+    /// maze.make_tunnel_between((0, 1).into(), (2, 1).into());
+    ///
+    /// assert!(maze.is_tunnel_between((2, 1).into(), (0, 1).into()));
+    /// assert!(maze.is_passage_between((0, 1).into(), (1, 1).into()) == false);
+    /// ```
+    #[must_use]
+    pub fn can_tunnel_between(&self, from: pt!(), to: pt!()) -> bool {
+        let (under, axis) = match self.get_tunnel_midpoint(from, to) {
+            Some(midpoint) => midpoint,
+            None => return false,
+        };
+
+        let under_value = self.get(under);
+
+        if under_value.tunnel.is_some() {
+            return false
+        }
+
+        // The point must be a straight passage across the tunnel
+        let mut passage_axes = under_value.edges.iter().enumerate().filter(|(_, edges)| **edges != [InlineCellValueEdge::WALL; 2]);
+
+        let is_straight = match (passage_axes.next(), passage_axes.next()) {
+            (Some((passage_axis, edges)), None) => passage_axis != axis && *edges == [InlineCellValueEdge::PASSAGE; 2],
+            _ => false,
+        };
+
+        if is_straight == false {
+            return false
+        }
+
+        return self.get_edge_towards(from, under) != InlineCellValueEdge::BOUNDARY && self.get_edge_towards(to, under) != InlineCellValueEdge::BOUNDARY
+    }
+
+    /// Carve a tunnel from `from` to `to`, beneath the point between them.
+    ///
+    /// The edges of `from` and `to` that face the point between them are set to
+    /// [`InlineCellValueEdge::PASSAGE`], and the tunnel is recorded in that point's
+    /// [`tunnel`][InlineCellValue::tunnel]. The point itself is unchanged, so it can't be entered
+    /// from the tunnel.
+    ///
+    /// All [`InlineCellValueEdge::UNVISITED`] edges of `from` and `to` will be replaced with
+    /// [`InlineCellValueEdge::WALL`], as with [`MazeCoordinator::make_passage_between()`].
+    ///
+    /// # Panics
+    ///
+    /// If a tunnel [cannot be carved][Self::can_tunnel_between] between `from` and `to`.
+    pub fn make_tunnel_between(&mut self, from: pt!(), to: pt!()) {
+        assert!(self.can_tunnel_between(from, to), "A tunnel cannot be carved between {:?} and {:?}", from, to);
+
+        let (under, axis) = self.get_tunnel_midpoint(from, to).expect("Checked by can_tunnel_between()");
+
+        for end in [from, to] {
+            let (_, end_before_under) = self.get_adjacency(end, under);
+
+            let end_value = self.get_mut(end);
+            end_value.edges[axis][usize::from(end_before_under)] = InlineCellValueEdge::PASSAGE;
+            Self::set_unvisited_edges_to_wall(&mut end_value.edges);
+        }
+
+        self.get_mut(under).tunnel = Some(axis);
+    }
+
+    /// Return whether `from` and `to` are joined by a tunnel.
+    ///
+    /// *See [`make_tunnel_between()`][Self::make_tunnel_between].*
+    #[must_use]
+    pub fn is_tunnel_between(&self, from: pt!(), to: pt!()) -> bool {
+        let (under, axis) = match self.get_tunnel_midpoint(from, to) {
+            Some(midpoint) => midpoint,
+            None => return false,
+        };
+
+        return self.get(under).tunnel == Some(axis)
+            && self.get_edge_towards(from, under) == InlineCellValueEdge::PASSAGE
+            && self.get_edge_towards(to, under) == InlineCellValueEdge::PASSAGE
+    }
+
+    /// Return every point that `pt` is joined to by a tunnel.
+    ///
+    /// These are in addition to the points `pt` is joined to by
+    /// [passages][MazeCoordinator::is_passage_between].
+    #[must_use]
+    pub fn tunnel_neighbours_of_pt(&self, pt: pt!()) -> Vec<pt!()> {
+        self.get_tunnel_candidates(pt).into_iter().filter(|candidate| self.is_tunnel_between(pt, *candidate)).collect()
+    }
+
+    /// Return every point that is two apart from `pt` along a single axis, and so could be joined
+    /// to it by a tunnel.
+    #[must_use]
+    pub(crate) fn get_tunnel_candidates(&self, pt: pt!()) -> Vec<pt!()> {
+        let mut candidates = Vec::new();
+
+        for under in self.space.neighbours_of_pt(pt) {
+            let axis = self.space.axis_of_adjacency(pt, under).expect("Neighbours are always adjacent");
+
+            for beyond in self.space.neighbours_of_pt(under) {
+                if beyond != pt && self.space.axis_of_adjacency(under, beyond) == Some(axis) && candidates.contains(&beyond) == false {
+                    candidates.push(beyond);
+                }
+            }
+        }
+
+        return candidates
+    }
 }
 
 // Internal functions
 impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION> {
-    //noinspection RsSelfConvention
-    /// Get the axis in which two points are adjacent.
+    /// Get the axis in which two points are adjacent, and whether `from` comes before `to` along
+    /// it.
     ///
-    /// The number represents the order of the axis (e.g.
-    /// x is 0, y is 1, etc.). This is the same as in the index
-    /// of the axis for the point (pt[index] gives the position of
-    /// the point along that axis).
+    /// The axis is numbered the same as the index of the axis for the point (`pt[index]` gives the
+    /// position of the point along that axis). `from` comes before `to` if `to` is across its
+    /// positive edge --- which is the case when `from` is the last point along an axis that
+    /// [wraps around][BoxCoordinateSpace#wrapping-adjacency] and `to` is the first.
     ///
-    /// Returns None if the points are identical or not adjacent.
+    /// # Panics
+    ///
+    /// If the points are not adjacent.
     #[must_use]
-    fn get_axis_of_adjacency(pt1: pt!(), pt2: pt!()) -> Option<usize> {
-        for i in 0..DIMENSION {
-            if pt1[i].abs_diff(pt2[i]) == 1 {
-                return Some(i)
+    pub(crate) fn get_adjacency(&self, from: pt!(), to: pt!()) -> (usize, bool) {
+        let axis_of_adjacency = self.space.axis_of_adjacency(from, to).expect("from and to are not adjacent");
+
+        let from_before_to = (from[axis_of_adjacency] < to[axis_of_adjacency]) != self.space.is_wrapped_between(from, to);
+
+        return (axis_of_adjacency, from_before_to)
+    }
+
+    /// Get the point a tunnel from `from` to `to` would pass beneath, and the axis it would run
+    /// along.
+    ///
+    /// Returns `None` if the points are not two apart along a single axis.
+    #[must_use]
+    fn get_tunnel_midpoint(&self, from: pt!(), to: pt!()) -> Option<(pt!(), usize)> {
+        for under in self.space.neighbours_of_pt(from) {
+            let axis = self.space.axis_of_adjacency(from, under).expect("Neighbours are always adjacent");
+
+            if to != from && self.space.axis_of_adjacency(under, to) == Some(axis) {
+                return Some((under, axis))
             }
         }
 
         return None
     }
 
+    /// Get the edge of `from` that faces `to`.
+    #[must_use]
+    fn get_edge_towards(&self, from: pt!(), to: pt!()) -> InlineCellValueEdge {
+        let (axis_of_adjacency, from_before_to) = self.get_adjacency(from, to);
+
+        return self.get(from).edges[axis_of_adjacency][usize::from(from_before_to)]
+    }
+
     #[must_use]
     fn pt_to_cell_id(&self, pt: pt!()) -> CellID {
-        let mut offset = pt[0];
+        let mut offset = 0;
+        let mut stride = 1;
 
-        for i in 1..DIMENSION {
-            offset += pt[i] * usize::from(self.coord_space().dimensions()[i - 1]);
+        for i in 0..DIMENSION {
+            offset += pt[i] * stride;
+            stride *= usize::from(self.coord_space().dimensions()[i]);
         }
 
         CellID(offset)
@@ -121,12 +382,7 @@ impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> Bo
     /// All [InlineCellValueEdge::UNVISITED] edges will be replaced with
     /// [InlineCellValueEdge::WALL].
     fn make_between(&mut self, from: pt!(), to: pt!(), edge_type: InlineCellValueEdge) {
-        let axis_of_adjacency = Self::get_axis_of_adjacency(from, to).expect("from and to are not adjacent");
-
-        let from_pos = from[axis_of_adjacency];
-        let to_pos = to[axis_of_adjacency];
-
-        let from_before_to = from_pos < to_pos;
+        let (axis_of_adjacency, from_before_to) = self.get_adjacency(from, to);
 
         let from_existing = self.get_mut(from);
 
@@ -159,14 +415,22 @@ impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> Ma
         &self.space
     }
 
+    fn marks(&self) -> &Marks<pt!()> {
+        &self.marks
+    }
+
+    fn marks_mut(&mut self) -> &mut Marks<pt!()> {
+        &mut self.marks
+    }
+
     fn get(&self, pt: pt!()) -> Self::CellVal {
         self.buffer.get(self.pt_to_cell_id(pt))
     }
 
-    fn get_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
-        let axis_of_adjacency = Self::get_axis_of_adjacency(from, to).expect("from and to are not adjacent");
+    fn get_adjacent_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        let (axis_of_adjacency, from_before_to) = self.get_adjacency(from, to);
 
-        let (from_wall_side, to_wall_side) = if from[axis_of_adjacency] < to[axis_of_adjacency] {
+        let (from_wall_side, to_wall_side) = if from_before_to {
             (1, 0)
         } else {
             (0, 1)
@@ -246,17 +510,61 @@ impl<Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> Box
         }
     }
 
+    /// Finalise the [`BoxSpaceInlineCellMazeCoordinator`].
+    ///
+    /// # Panics
+    ///
+    /// If the coordinate space has [diagonal adjacency][BoxCoordinateSpace#diagonal-adjacency],
+    /// which inline cells can't represent.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::try_build()`], which returns an error instead of panicking.
     #[must_use]
     pub fn build(&self) -> BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION> {
-        BoxSpaceInlineCellMazeCoordinator::new(self.space)
+        self.try_build().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Finalise the [`BoxSpaceInlineCellMazeCoordinator`], returning an error if the coordinate
+    /// space is unsupported.
+    ///
+    /// # Errors
+    ///
+    /// [`MazeError::DiagonalsUnsupported`] if the coordinate space has
+    /// [diagonal adjacency][BoxCoordinateSpace#diagonal-adjacency].
+    pub fn try_build(&self) -> Result<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, MazeError> {
+        BoxSpaceInlineCellMazeCoordinator::try_new(self.space, Buffer::new)
+    }
+
+    /// Finalise the [`BoxSpaceInlineCellMazeCoordinator`], storing it in an existing buffer instead
+    /// of constructing a new one. The buffer's contents are kept.
+    ///
+    /// `buffer` must hold at least as many cells as the coordinate space has points.
+    ///
+    /// # Panics
+    ///
+    /// See [`Self::build()`].
+    #[must_use]
+    pub fn build_with_buffer(&self, buffer: Buffer) -> BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION> {
+        self.try_build_with_buffer(buffer).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Finalise the [`BoxSpaceInlineCellMazeCoordinator`] in an existing buffer, returning an
+    /// error if the coordinate space is unsupported. See [`Self::build_with_buffer()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::try_build()`].
+    pub fn try_build_with_buffer(&self, buffer: Buffer) -> Result<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, MazeError> {
+        BoxSpaceInlineCellMazeCoordinator::try_new(self.space, |_| buffer)
     }
 }
 
 /*
- * We want to show the state of the maze in the debug output for 2D mazes.
+ * We want to show the state of the maze in the debug output for 2D and 3D mazes.
  *
  * To this end, we must manually implement Debug for BoxSpaceInlineCellMazeCoordinator,
- * then provide a specialisation where DIMENSION = 2.
+ * then provide specialisations where DIMENSION = 2 and DIMENSION = 3.
  *
  * The reason we must manually implement Debug is because #[derive(Debug)] does
  * not mark its implementation as `default`, which we need in order to specialise.
@@ -268,6 +576,18 @@ impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> Bo
         writeln!(f, "\tbuffer: {:?}", self.buffer)?;
         writeln!(f, "\tspace: {:?}", self.space)?;
 
+        if self.marks.named_points.is_empty() == false {
+            writeln!(f, "\tnamed_points: {:?}", self.marks.named_points)?;
+        }
+
+        if self.marks.annotations.is_empty() == false {
+            writeln!(f, "\tannotations: {:?}", self.marks.annotations)?;
+        }
+
+        if self.marks.portals.is_empty() == false {
+            writeln!(f, "\tportals: {:?}", self.marks.portals)?;
+        }
+
         return Ok(())
     }
 }
@@ -287,13 +607,25 @@ impl <Buffer: MazeBuffer<InlineCellValue<2>>> Debug for BoxSpaceInlineCellMazeCo
 
         writeln!(f)?;
 
-        let mut text_export = Vec::<u8>::new();
+        let text_export = BoxSpaceInlineCellTextMazeExporter::default().export_to_string(self).unwrap_or_else(|err| panic!("{}", err));
 
-        if let Err(err) = BoxSpaceInlineCellTextMazeExporter::default().export(self, &mut text_export) {
-            panic!("{}", err)
-        }
+        for line in text_export.lines() {
+            writeln!(f, "\t{}", line)?;
+        };
+
+        writeln!(f, "}}")?;
+
+        return Ok(())
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<3>>> Debug for BoxSpaceInlineCellMazeCoordinator<Buffer, 3> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_main_dbg_fmt(f)?;
 
-        let text_export = std::str::from_utf8(&text_export).expect("BoxSpaceTextMazeExporter did not produce valid UTF-8");
+        writeln!(f)?;
+
+        let text_export = BoxSpaceInlineCellTextMazeExporter::default().export_to_string(self).unwrap_or_else(|err| panic!("{}", err));
 
         for line in text_export.lines() {
             writeln!(f, "\t{}", line)?;
@@ -303,4 +635,34 @@ impl <Buffer: MazeBuffer<InlineCellValue<2>>> Debug for BoxSpaceInlineCellMazeCo
 
         return Ok(())
     }
+}
+
+/// Render the maze as text, as per [`BoxSpaceInlineCellTextMazeExporter`] with its default settings.
+///
+/// Unlike the [`Debug`] output, this is just the maze itself, without a trailing line break.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// # use mazelib::util::apply_solid_border;
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([2, 1])).build();
+/// apply_solid_border(&mut maze);
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+///
+/// assert_eq!("\
+/// ┏━━━━━━━┓
+/// ┃       ┃
+/// ┗━━━━━━━┛", maze.to_string().replace("\r\n", "\n"));
+/// ```
+impl <Buffer: MazeBuffer<InlineCellValue<2>>> Display for BoxSpaceInlineCellMazeCoordinator<Buffer, 2> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text_export = BoxSpaceInlineCellTextMazeExporter::default().export_to_string(self).unwrap_or_else(|err| panic!("{}", err));
+
+        return f.write_str(text_export.trim_end_matches(['\r', '\n']))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,143 @@
+//! A coordinator that restricts another maze to a [mask][crate::implm::point::masked].
+
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::point::masked::{BoxPointMask, MaskedBoxCoordinateSpace};
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::mark::Marks;
+use crate::pt;
+
+/// A maze coordinator that wraps a box-space maze and restricts it to the points in a
+/// [`BoxPointMask`].
+///
+/// The wrapped maze stores all the cells, and every operation is passed straight through to it.
+/// The only difference is the coordinate space --- as this coordinator exposes a
+/// [`MaskedBoxCoordinateSpace`], generators and other algorithms will only ever visit the points
+/// in the mask. Points outside the mask are left untouched (so usually unvisited).
+///
+/// Since exporters are written for specific coordinators, you will usually want to export the
+/// wrapped maze. You can get it back with [`maze()`][Self::maze] or
+/// [`into_inner()`][Self::into_inner].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// use mazelib::implm::coordinate::masked::MaskedMazeCoordinator;
+/// use mazelib::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::point::masked::BoxPointMask;
+/// use mazelib::interface::export::DefaultMazeExporter;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// // A heart
+/// let space = BoxCoordinateSpace::new_checked([15, 13]);
+///
+/// let mask = BoxPointMask::from_fn(space, |pt| {
+///     let x = (pt[0] as f64 - 7.0) / 6.0;
+///     let y = (6.0 - pt[1] as f64) / 6.0;
+///
+///     (x * x + y * y - 1.0).powi(3) - x * x * y.powi(3) <= 0.0
+/// });
+///
+/// let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).build();
+///
+/// let mut maze = MaskedMazeCoordinator::new(maze, &mask);
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// <BoxSpaceBlockCellTextMazeExporter as DefaultMazeExporter<_, _>>::export(maze.maze(), &mut std::io::stdout()).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MaskedMazeCoordinator<'mask, Maze: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> {
+    maze: Maze,
+    space: MaskedBoxCoordinateSpace<'mask, DIMENSION>,
+}
+
+impl <'mask, Maze: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> MaskedMazeCoordinator<'mask, Maze, DIMENSION> {
+    /// Restrict `maze` to the points in `mask`.
+    ///
+    /// # Panics
+    ///
+    /// If the mask does not have the same dimensions as the maze's coordinate space.
+    #[must_use]
+    pub fn new(maze: Maze, mask: &'mask BoxPointMask<DIMENSION>) -> Self {
+        assert!(maze.coord_space().dimensions() == mask.space().dimensions(), "The mask must have the same dimensions as the maze");
+
+        Self { maze, space: MaskedBoxCoordinateSpace::new(mask) }
+    }
+
+    /// Return the wrapped maze.
+    #[must_use]
+    pub fn maze(&self) -> &Maze {
+        &self.maze
+    }
+
+    /// Return the wrapped maze, mutably.
+    ///
+    /// Changes made through this are not restricted to the mask.
+    #[must_use]
+    pub fn maze_mut(&mut self) -> &mut Maze {
+        &mut self.maze
+    }
+
+    /// Unwrap this coordinator, returning the wrapped maze.
+    #[must_use]
+    pub fn into_inner(self) -> Maze {
+        self.maze
+    }
+}
+
+impl <'mask, Maze: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> MazeCoordinator for MaskedMazeCoordinator<'mask, Maze, DIMENSION> {
+    type CoordSpace = MaskedBoxCoordinateSpace<'mask, DIMENSION>;
+    type CellLoc = Maze::CellLoc;
+    type CellVal = Maze::CellVal;
+
+    fn coord_space(&self) -> &Self::CoordSpace {
+        &self.space
+    }
+
+    fn marks(&self) -> &Marks<pt!()> {
+        self.maze.marks()
+    }
+
+    fn marks_mut(&mut self) -> &mut Marks<pt!()> {
+        self.maze.marks_mut()
+    }
+
+    fn get(&self, pt: pt!()) -> Self::CellVal {
+        self.maze.get(pt)
+    }
+
+    fn get_adjacent_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        self.maze.get_adjacent_connection(from, to)
+    }
+
+    fn make_passage(&mut self, pt: pt!()) {
+        self.maze.make_passage(pt)
+    }
+
+    fn make_passage_between(&mut self, from: pt!(), to: pt!()) {
+        self.maze.make_passage_between(from, to)
+    }
+
+    fn make_wall(&mut self, pt: pt!()) {
+        self.maze.make_wall(pt)
+    }
+
+    fn make_wall_between(&mut self, from: pt!(), to: pt!()) {
+        self.maze.make_wall_between(from, to)
+    }
+
+    fn make_boundary(&mut self, pt: pt!()) {
+        self.maze.make_boundary(pt)
+    }
+
+    fn make_boundary_between(&mut self, from: pt!(), to: pt!()) {
+        self.maze.make_boundary_between(from, to)
+    }
+}
\ No newline at end of file
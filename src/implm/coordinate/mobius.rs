@@ -0,0 +1,62 @@
+//! Coordinators for mazes on a [Möbius strip][crate::implm::point::mobius].
+
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::edge::{EdgeCellMazeCoordinator, EdgeCellMazeCoordinatorBuilder, EdgeCellSpace};
+use crate::implm::export::text::{MobiusSpaceInlineCellTextMazeExporter, ExportToString};
+use crate::implm::point::boxy::{BoxDirection, CoordinateTuplet};
+use crate::implm::point::mobius::MobiusCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+
+/// A maze coordinator for mazes on a Möbius strip, using [inline cells][crate::implm::cell::inline].
+///
+/// Every point is mapped to exactly one inline cell, just like in a
+/// [`BoxSpaceInlineCellMazeCoordinator`][crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator].
+/// A passage across the join between the ends of the strip is stored on the right edge of the
+/// point at the right end and the left edge of the point at the left end, even though they're in
+/// mirrored rows.
+///
+/// *See [`EdgeCellMazeCoordinator`] for the methods and the builder.*
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// use mazelib::implm::coordinate::mobius::MobiusSpaceInlineCellMazeCoordinator;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::mobius::MobiusCoordinateSpace;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// let mut maze = MobiusSpaceInlineCellMazeCoordinator::<VecBuffer<InlineCellValue<2>>>::builder(MobiusCoordinateSpace::new_checked([20, 5])).build();
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// println!("{:?}", maze);
+/// ```
+pub type MobiusSpaceInlineCellMazeCoordinator<Buffer> = EdgeCellMazeCoordinator<MobiusCoordinateSpace, Buffer>;
+
+/// A builder for a [`MobiusSpaceInlineCellMazeCoordinator`].
+pub type MobiusSpaceInlineCellMazeCoordinatorBuilder<Buffer> = EdgeCellMazeCoordinatorBuilder<MobiusCoordinateSpace, Buffer>;
+
+impl EdgeCellSpace for MobiusCoordinateSpace {
+    type CellVal = InlineCellValue<2>;
+
+    fn cell_id(&self, pt: CoordinateTuplet<2>) -> CellID {
+        CellID(pt[0] + pt[1] * usize::from(self.dimensions()[0]))
+    }
+
+    /// `to` is across the positive edge of `from` when it's further along the axis, or when
+    /// `from` is at the right end of the strip and `to` is across the join at the left end.
+    fn edges_between(&self, from: CoordinateTuplet<2>, to: CoordinateTuplet<2>) -> Option<[BoxDirection<2>; 2]> {
+        let axis_of_adjacency = self.axis_of_adjacency(from, to)?;
+
+        let from_before_to = (from[axis_of_adjacency] < to[axis_of_adjacency]) != self.is_joined_between(from, to);
+
+        return Some([BoxDirection::new(axis_of_adjacency, from_before_to), BoxDirection::new(axis_of_adjacency, from_before_to == false)])
+    }
+
+    fn debug_text<Buffer: MazeBuffer<InlineCellValue<2>>>(maze: &MobiusSpaceInlineCellMazeCoordinator<Buffer>) -> Option<String> {
+        Some(MobiusSpaceInlineCellTextMazeExporter::default().export_to_string(maze).unwrap_or_else(|err| panic!("{}", err)))
+    }
+}
\ No newline at end of file
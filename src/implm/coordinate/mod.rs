@@ -18,10 +18,50 @@
 //! 
 //! TODO
 //!
+//! [`HexSpaceHexCellMazeCoordinator`][self::hex::HexSpaceHexCellMazeCoordinator]:
+//!
+//! TODO
+//!
+//! [`DeltaSpaceDeltaCellMazeCoordinator`][self::delta::DeltaSpaceDeltaCellMazeCoordinator]:
+//!
+//! TODO
+//!
+//! [`PolarSpaceInlineCellMazeCoordinator`][self::polar::PolarSpaceInlineCellMazeCoordinator]:
+//!
+//! TODO
+//!
+//! [`CubeSpaceCubeCellMazeCoordinator`][self::cube::CubeSpaceCubeCellMazeCoordinator]:
+//!
+//! TODO
+//!
+//! [`UpsilonSpaceUpsilonCellMazeCoordinator`][self::upsilon::UpsilonSpaceUpsilonCellMazeCoordinator]:
+//!
+//! TODO
+//!
+//! [`MobiusSpaceInlineCellMazeCoordinator`][self::mobius::MobiusSpaceInlineCellMazeCoordinator]:
+//!
+//! TODO
+//!
+//! [`GraphSpaceGraphCellMazeCoordinator`][self::graph::GraphSpaceGraphCellMazeCoordinator]:
+//!
+//! TODO
+//!
 //! # See Also
 //!
 //! * [`MazeCoordinator`][crate::interface::coordinate::MazeCoordinator] --- the interface trait
 #![doc = embed_doc_image::embed_image!("box-space-block-cell-coordinator-example", "src/doc/img/coordinate/box-space-block-cell/example-large.png")]
 
 pub mod block;
+pub mod composite;
+pub mod constrained;
+pub mod cube;
+pub mod delta;
+pub mod edge;
+pub mod graph;
+pub mod hex;
 pub mod inline;
+pub mod masked;
+pub mod mobius;
+pub mod polar;
+pub mod region;
+pub mod upsilon;
@@ -0,0 +1,53 @@
+//! Coordinators for [polar cells][crate::implm::cell::polar].
+
+use crate::implm::cell::polar::PolarCellValue;
+use crate::implm::coordinate::edge::{EdgeCellMazeCoordinator, EdgeCellMazeCoordinatorBuilder, EdgeCellSpace};
+use crate::implm::point::polar::{PolarCoordinate, PolarCoordinateSpace, PolarEdge};
+use crate::interface::cell::CellID;
+
+/// A maze coordinator for polar (theta) mazes.
+///
+/// Every point is mapped to exactly one [polar cell][crate::implm::cell::polar], which stores the
+/// state of each of its edges.
+///
+/// If the coordinate space is [subdivided][PolarCoordinateSpace::new_subdivided], a cell may have
+/// two outward neighbours. Each of them is connected through its own edge of the cell.
+///
+/// *See [`EdgeCellMazeCoordinator`] for the methods and the builder.*
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::polar::PolarCellValue;
+/// use mazelib::implm::coordinate::polar::PolarSpaceInlineCellMazeCoordinator;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::polar::PolarCoordinateSpace;
+/// use mazelib::interface::cell::CellValue;
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::interface::point::CoordinateSpace;
+///
+/// let mut maze = PolarSpaceInlineCellMazeCoordinator::<VecBuffer<PolarCellValue>>::builder(PolarCoordinateSpace::new_subdivided_checked(8, 6)).build();
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// assert!(maze.coord_space().iter().all(|pt| maze.get(pt).is_fully_visited()));
+/// ```
+pub type PolarSpaceInlineCellMazeCoordinator<Buffer> = EdgeCellMazeCoordinator<PolarCoordinateSpace, Buffer>;
+
+/// A builder for a [`PolarSpaceInlineCellMazeCoordinator`].
+pub type PolarSpaceInlineCellMazeCoordinatorBuilder<Buffer> = EdgeCellMazeCoordinatorBuilder<PolarCoordinateSpace, Buffer>;
+
+impl EdgeCellSpace for PolarCoordinateSpace {
+    type CellVal = PolarCellValue;
+
+    fn cell_id(&self, pt: PolarCoordinate) -> CellID {
+        CellID(self.ring_offset(pt.ring) + pt.sector)
+    }
+
+    /// The edges are not always opposites, as a cell may have two outward neighbours.
+    fn edges_between(&self, from: PolarCoordinate, to: PolarCoordinate) -> Option<[PolarEdge; 2]> {
+        Some([self.edge_between(from, to)?, self.edge_between(to, from)?])
+    }
+}
\ No newline at end of file
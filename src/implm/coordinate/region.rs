@@ -0,0 +1,164 @@
+//! A coordinator that views a rectangular [sub-region][crate::implm::point::boxy::BoxSubRegion] of
+//! another maze.
+
+use std::num::NonZeroUsize;
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxSubRegion, CoordinateTuplet};
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::mark::Marks;
+use crate::pt;
+
+/// A maze coordinator that views a rectangular sub-region of a box-space maze as its own, smaller
+/// maze.
+///
+/// The view has its own [`BoxCoordinateSpace`], with its origin at the sub-region's origin. Every
+/// operation is mapped to the corresponding point in the viewed maze and passed straight through
+/// to it, so all changes are written directly to the viewed maze's buffer.
+///
+/// Generators and other algorithms will only ever visit points in the sub-region, so you can use
+/// this to generate different parts of a maze in different ways. The sub-region never wraps
+/// around, so nothing is carved between it and the rest of the maze. Join the parts up afterwards
+/// with [`MazeCoordinator::make_passage_between()`] on the viewed maze.
+///
+/// # Examples
+///
+/// Generate the left half of a maze with one algorithm and the right half with another:
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// use mazelib::implm::coordinate::region::SubRegionMazeCoordinator;
+/// use mazelib::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([10, 5])).build();
+///
+/// RecursiveBacktrackerGenerator::generate(&mut SubRegionMazeCoordinator::new_checked(&mut maze, [0, 0].into(), [5, 5]));
+/// HuntAndKillGenerator::generate(&mut SubRegionMazeCoordinator::new_checked(&mut maze, [5, 0].into(), [5, 5]));
+///
+/// // Join the halves
+/// maze.make_passage_between([4, 2].into(), [5, 2].into());
+///
+/// println!("{:?}", maze);
+/// ```
+#[derive(Debug)]
+pub struct SubRegionMazeCoordinator<'maze, Maze: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> {
+    maze: &'maze mut Maze,
+    region: BoxSubRegion<DIMENSION>,
+    /// The maze's named points, annotations, and portals.
+    marks: Marks<CoordinateTuplet<DIMENSION>>,
+}
+
+impl <'maze, Maze: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> SubRegionMazeCoordinator<'maze, Maze, DIMENSION> {
+    /// View a sub-region of `maze`.
+    ///
+    /// # Parameters
+    ///
+    /// `maze`       --- the maze to view.  
+    /// `origin`     --- the point in `maze` that will be the origin of the view.  
+    /// `dimensions` --- the width, height, depth, etc. of the view.
+    ///
+    /// # Panics
+    ///
+    /// If the sub-region does not fit within the maze.
+    ///
+    /// # See Also
+    ///
+    /// [`new_checked()`][Self::new_checked]
+    #[must_use]
+    pub fn new(maze: &'maze mut Maze, origin: CoordinateTuplet<DIMENSION>, dimensions: [NonZeroUsize; DIMENSION]) -> Self {
+        let region = maze.coord_space().sub_region(origin, dimensions);
+
+        Self { maze, region, marks: Marks::new() }
+    }
+
+    /// View a sub-region of `maze`.
+    ///
+    /// *See [`new()`][Self::new].*
+    ///
+    /// # Panics
+    ///
+    /// If any dimension is zero, or if the sub-region does not fit within the maze.
+    #[must_use]
+    pub fn new_checked(maze: &'maze mut Maze, origin: CoordinateTuplet<DIMENSION>, dimensions: [usize; DIMENSION]) -> Self {
+        let region = maze.coord_space().sub_region_checked(origin, dimensions);
+
+        Self { maze, region, marks: Marks::new() }
+    }
+
+    /// Return the sub-region being viewed.
+    #[must_use]
+    pub fn region(&self) -> &BoxSubRegion<DIMENSION> {
+        &self.region
+    }
+
+    /// Return the viewed maze.
+    #[must_use]
+    pub fn maze(&self) -> &Maze {
+        self.maze
+    }
+
+    /// Return the viewed maze, mutably.
+    ///
+    /// Changes made through this are not restricted to the sub-region, and use the viewed maze's
+    /// coordinates.
+    #[must_use]
+    pub fn maze_mut(&mut self) -> &mut Maze {
+        self.maze
+    }
+}
+
+impl <'maze, Maze: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> MazeCoordinator for SubRegionMazeCoordinator<'maze, Maze, DIMENSION> {
+    type CoordSpace = BoxCoordinateSpace<DIMENSION>;
+    type CellLoc = Maze::CellLoc;
+    type CellVal = Maze::CellVal;
+
+    fn coord_space(&self) -> &Self::CoordSpace {
+        self.region.space()
+    }
+
+    fn marks(&self) -> &Marks<pt!()> {
+        &self.marks
+    }
+
+    fn marks_mut(&mut self) -> &mut Marks<pt!()> {
+        &mut self.marks
+    }
+
+    fn get(&self, pt: pt!()) -> Self::CellVal {
+        self.maze.get(self.region.to_parent(pt))
+    }
+
+    fn get_adjacent_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        self.maze.get_adjacent_connection(self.region.to_parent(from), self.region.to_parent(to))
+    }
+
+    fn make_passage(&mut self, pt: pt!()) {
+        self.maze.make_passage(self.region.to_parent(pt))
+    }
+
+    fn make_passage_between(&mut self, from: pt!(), to: pt!()) {
+        self.maze.make_passage_between(self.region.to_parent(from), self.region.to_parent(to))
+    }
+
+    fn make_wall(&mut self, pt: pt!()) {
+        self.maze.make_wall(self.region.to_parent(pt))
+    }
+
+    fn make_wall_between(&mut self, from: pt!(), to: pt!()) {
+        self.maze.make_wall_between(self.region.to_parent(from), self.region.to_parent(to))
+    }
+
+    fn make_boundary(&mut self, pt: pt!()) {
+        self.maze.make_boundary(self.region.to_parent(pt))
+    }
+
+    fn make_boundary_between(&mut self, from: pt!(), to: pt!()) {
+        self.maze.make_boundary_between(self.region.to_parent(from), self.region.to_parent(to))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+//! Coordinators for [upsilon cells][crate::implm::cell::upsilon].
+
+use crate::implm::cell::inline::InlineCellValueEdge;
+use crate::implm::cell::upsilon::UpsilonCellValue;
+use crate::implm::coordinate::edge::{EdgeCellMazeCoordinator, EdgeCellMazeCoordinatorBuilder, EdgeCellSpace};
+use crate::implm::point::upsilon::{UpsilonCoordinate, UpsilonCoordinateSpace, UpsilonEdge};
+use crate::interface::cell::CellID;
+
+/// A maze coordinator for upsilon (octagon and square) mazes.
+///
+/// Every point is mapped to exactly one [upsilon cell][crate::implm::cell::upsilon], which stores
+/// the state of each of its edges. Octagons have eight edges, and squares have four.
+///
+/// *See [`EdgeCellMazeCoordinator`] for the methods and the builder.*
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::upsilon::UpsilonCellValue;
+/// use mazelib::implm::coordinate::upsilon::UpsilonSpaceUpsilonCellMazeCoordinator;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::upsilon::{UpsilonCoordinate, UpsilonCoordinateSpace};
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// let mut maze = UpsilonSpaceUpsilonCellMazeCoordinator::<VecBuffer<UpsilonCellValue>>::builder(UpsilonCoordinateSpace::new_checked(9, 9)).build();
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// // Octagons can lead diagonally to other octagons
+/// let octagon = UpsilonCoordinate { column: 4, row: 4 };
+/// let diagonal = UpsilonCoordinate { column: 5, row: 5 };
+///
+/// println!("{}", maze.is_passage_between(octagon, diagonal));
+/// ```
+pub type UpsilonSpaceUpsilonCellMazeCoordinator<Buffer> = EdgeCellMazeCoordinator<UpsilonCoordinateSpace, Buffer>;
+
+/// A builder for an [`UpsilonSpaceUpsilonCellMazeCoordinator`].
+pub type UpsilonSpaceUpsilonCellMazeCoordinatorBuilder<Buffer> = EdgeCellMazeCoordinatorBuilder<UpsilonCoordinateSpace, Buffer>;
+
+impl EdgeCellSpace for UpsilonCoordinateSpace {
+    type CellVal = UpsilonCellValue;
+
+    fn cell_id(&self, pt: UpsilonCoordinate) -> CellID {
+        CellID(pt.column + pt.row * usize::from(self.columns()))
+    }
+
+    fn edges_between(&self, from: UpsilonCoordinate, to: UpsilonCoordinate) -> Option<[UpsilonEdge; 2]> {
+        let edge = self.edge_between(from, to)?;
+
+        return Some([edge, edge.opposite()])
+    }
+
+    /// Squares don't have diagonal edges, so they start (and stay) as boundaries.
+    fn new_cell(&self, pt: UpsilonCoordinate) -> UpsilonCellValue {
+        let mut cell = UpsilonCellValue::default();
+
+        for edge in UpsilonEdge::ALL.into_iter().filter(|edge| pt.has_edge(*edge) == false) {
+            cell.set_edge(edge, InlineCellValueEdge::BOUNDARY);
+        }
+
+        return cell
+    }
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use std::io::{Error, ErrorKind, Result, Write};
+
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::binary::{BinaryMazeExporter, BoxSpaceBinaryMazeExporter};
+use crate::implm::export::binary::format::{BLOCK_CELL_CLASS, encode_block_cell};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::point::CoordinateSpace;
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, Output> for BoxSpaceBinaryMazeExporter {
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, output: &mut Output) -> Result<()> {
+        if maze.is_uniformly_spaced() == false {
+            return Err(Error::new(ErrorKind::InvalidInput, "The binary format does not support mazes with variable spacing"))
+        }
+
+        let mut bytes = Vec::new();
+
+        Self::write_header(&mut bytes, BLOCK_CELL_CLASS, DIMENSION);
+
+        for dim in maze.coord_space().dimensions() {
+            Self::write_usize(&mut bytes, usize::from(dim));
+        }
+
+        for scale_factor in maze.scale_factors() {
+            Self::write_usize(&mut bytes, usize::from(scale_factor));
+        }
+
+        for [negative, positive] in maze.padding() {
+            Self::write_usize(&mut bytes, negative);
+            Self::write_usize(&mut bytes, positive);
+        }
+
+        for cell_loc in BoxCoordinateSpace::new(maze.get_full_dimensions()).iter() {
+            encode_block_cell(maze.get_cell_value(BlockCellLocation(cell_loc)), &mut bytes);
+        }
+
+        return Self::finish(bytes, output)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, Output: Write> BinaryMazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, Output> for BoxSpaceBinaryMazeExporter {}
\ No newline at end of file
@@ -0,0 +1,131 @@
+//! Shared definitions for the binary maze format.
+
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue, VerticalConnector};
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::interface::cell::Terrain;
+
+/// The bytes every file starts with.
+pub(crate) const MAGIC: [u8; 4] = *b"MZLB";
+
+/// The version of the format written by the exporter.
+pub(crate) const CURRENT_VERSION: u8 = 1;
+
+/// Every version of the format the importer can read.
+pub(crate) const SUPPORTED_VERSIONS: [u8; 1] = [1];
+
+/// The cell class tag for block cells.
+pub(crate) const BLOCK_CELL_CLASS: u8 = 0;
+
+/// The cell class tag for inline cells.
+pub(crate) const INLINE_CELL_CLASS: u8 = 1;
+
+/// The length of the header shared by all cell classes (magic, version, class, dimension).
+pub(crate) const HEADER_LENGTH: usize = 7;
+
+/// The length of the trailing checksum.
+pub(crate) const CHECKSUM_LENGTH: usize = 4;
+
+/// The number of bytes each block cell takes up.
+pub(crate) const BLOCK_CELL_LENGTH: usize = 2;
+
+/// Return the number of bytes each inline cell takes up.
+pub(crate) const fn inline_cell_length<const DIMENSION: usize>() -> usize {
+    2 + DIMENSION
+}
+
+/// Block cells are encoded as one byte for the cell type, mark, and vertical connector, then one
+/// byte for the terrain.
+pub(crate) fn encode_block_cell(value: BlockCellValue, output: &mut Vec<u8>) {
+    let cell_type = match value.cell_type {
+        BlockCellPrimaryValue::UNVISITED => 0,
+        BlockCellPrimaryValue::PASSAGE   => 1,
+        BlockCellPrimaryValue::WALL      => 2,
+        BlockCellPrimaryValue::BOUNDARY  => 3,
+    };
+
+    output.push(cell_type | (u8::from(value.marked) << 2) | (u8::from(value.connector.goes_up()) << 3) | (u8::from(value.connector.goes_down()) << 4));
+    output.push(value.terrain.0);
+}
+
+/// Decode a block cell from its [`BLOCK_CELL_LENGTH`] bytes.
+pub(crate) fn decode_block_cell(bytes: &[u8]) -> Option<BlockCellValue> {
+    let byte = bytes[0];
+
+    if byte >> 5 != 0 {
+        return None
+    }
+
+    let cell_type = match byte & 0b11 {
+        0 => BlockCellPrimaryValue::UNVISITED,
+        1 => BlockCellPrimaryValue::PASSAGE,
+        2 => BlockCellPrimaryValue::WALL,
+        _ => BlockCellPrimaryValue::BOUNDARY,
+    };
+
+    let connector = VerticalConnector::from_directions(byte & 0b1000 != 0, byte & 0b10000 != 0);
+
+    return Some(BlockCellValue { cell_type, marked: byte & 0b100 != 0, connector, terrain: Terrain(bytes[1]) })
+}
+
+fn encode_edge(edge: InlineCellValueEdge) -> u8 {
+    match edge {
+        InlineCellValueEdge::UNVISITED => 0,
+        InlineCellValueEdge::PASSAGE   => 1,
+        InlineCellValueEdge::WALL      => 2,
+        InlineCellValueEdge::BOUNDARY  => 3,
+    }
+}
+
+fn decode_edge(bits: u8) -> InlineCellValueEdge {
+    match bits & 0b11 {
+        0 => InlineCellValueEdge::UNVISITED,
+        1 => InlineCellValueEdge::PASSAGE,
+        2 => InlineCellValueEdge::WALL,
+        _ => InlineCellValueEdge::BOUNDARY,
+    }
+}
+
+/// Inline cells are encoded as one byte for the mark and tunnel, then one byte per axis holding the
+/// negative edge in the lower two bits and the positive edge in the next two, then one byte for
+/// the terrain.
+///
+/// The mark is the lowest bit of the first byte. The rest of the byte holds one more than the axis
+/// of the tunnel beneath the cell, or zero if there isn't one.
+pub(crate) fn encode_inline_cell<const DIMENSION: usize>(value: InlineCellValue<DIMENSION>, output: &mut Vec<u8>) {
+    let tunnel = value.tunnel.map_or(0, |axis| axis as u8 + 1);
+
+    output.push(u8::from(value.marked) | (tunnel << 1));
+
+    for [negative, positive] in value.edges {
+        output.push(encode_edge(negative) | (encode_edge(positive) << 2));
+    }
+
+    output.push(value.terrain.0);
+}
+
+pub(crate) fn decode_inline_cell<const DIMENSION: usize>(bytes: &[u8]) -> Option<InlineCellValue<DIMENSION>> {
+    let tunnel = usize::from(bytes[0] >> 1);
+
+    if tunnel > DIMENSION || bytes[1..=DIMENSION].iter().any(|byte| byte >> 4 != 0) {
+        return None
+    }
+
+    let edges = std::array::from_fn(|axis| [decode_edge(bytes[axis + 1]), decode_edge(bytes[axis + 1] >> 2)]);
+
+    return Some(InlineCellValue { edges, marked: bytes[0] & 1 == 1, tunnel: tunnel.checked_sub(1), terrain: Terrain(bytes[DIMENSION + 1]) })
+}
+
+/// CRC-32 (IEEE 802.3), as used by zip, PNG, etc.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+
+    return !crc
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use std::io::{Result, Write};
+
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
+use crate::implm::export::binary::{BinaryMazeExporter, BoxSpaceBinaryMazeExporter};
+use crate::implm::export::binary::format::{encode_inline_cell, INLINE_CELL_CLASS};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize, Output: Write> MazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, Output> for BoxSpaceBinaryMazeExporter {
+    fn export(&self, maze: &BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, output: &mut Output) -> Result<()> {
+        let mut bytes = Vec::new();
+
+        Self::write_header(&mut bytes, INLINE_CELL_CLASS, DIMENSION);
+
+        for dim in maze.coord_space().dimensions() {
+            Self::write_usize(&mut bytes, usize::from(dim));
+        }
+
+        for (_, value) in maze.iter() {
+            encode_inline_cell(value, &mut bytes);
+        }
+
+        return Self::finish(bytes, output)
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize, Output: Write> BinaryMazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, Output> for BoxSpaceBinaryMazeExporter {}
\ No newline at end of file
@@ -0,0 +1,99 @@
+//! A compact binary format.
+//!
+//! Unlike the other export formats, the binary format is lossless and versioned, so it is suitable
+//! for saving mazes and loading them again later with
+//! [`BoxSpaceBinaryMazeImporter`][crate::implm::import::binary::BoxSpaceBinaryMazeImporter].
+//!
+//! # Format
+//!
+//! All integers are little-endian.
+//!
+//! | Field          | Type        | Notes                                                   |
+//! |----------------|-------------|---------------------------------------------------------|
+//! | Magic          | 4 bytes     | `MZLB`                                                  |
+//! | Version        | `u8`        | Currently 1                                             |
+//! | Cell class     | `u8`        | 0 for block cells, 1 for inline cells                   |
+//! | Dimension      | `u8`        |                                                         |
+//! | Dimensions     | `u64` × D   | The coordinate space's dimensions                       |
+//! | Scale factors  | `u64` × D   | Block cells only                                        |
+//! | Padding        | `u64` × 2D  | Block cells only, negative then positive for each axis  |
+//! | Cells          |             | See below                                               |
+//! | Checksum       | `u32`       | CRC-32 (IEEE) of everything before it                   |
+//!
+//! Cells are written in ascending order of location, with the most minor axis varying fastest.
+//!
+//! Each block cell is two bytes. In the first byte, the lower two bits are the cell type
+//! (0 = unvisited, 1 = passage, 2 = wall, 3 = boundary), the third bit is the mark, and the fourth
+//! and fifth bits are whether the cell's
+//! [vertical connector][crate::implm::cell::block::VerticalConnector] goes up and down
+//! respectively. The second byte is the cell's [terrain][crate::interface::cell::Terrain].
+//!
+//! Each inline cell is 2 + D bytes. The lowest bit of the first byte is the mark, and the rest of
+//! it is one more than the axis of the tunnel beneath the cell (or zero if there isn't one). Each
+//! following byte up to the last is one axis, with the negative edge in the lower two bits and the
+//! positive edge in the next two (using the same encoding as block cell types). The last byte is the
+//! cell's terrain.
+//!
+//! The format only records scale factors, so block cell mazes with
+//! [variable spacing][crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinatorBuilder::spacing]
+//! can't be exported. Attempting to do so returns an error with the kind
+//! [`InvalidInput`][std::io::ErrorKind::InvalidInput].
+
+use std::io::Write;
+
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+
+mod block;
+mod inline;
+pub(crate) mod format;
+
+/// Export a maze into a compact binary format.
+pub trait BinaryMazeExporter<M: MazeCoordinator, O: Write> : MazeExporter<M, O> {}
+
+/// A [`BinaryMazeExporter`] for mazes that
+/// use [`BoxCoordinateSpace`][crate::implm::point::boxy::BoxCoordinateSpace]s, of any dimension
+/// and either cell class.
+///
+/// See the [module documentation][self] for the format.
+pub struct BoxSpaceBinaryMazeExporter {
+    _private: ()
+}
+
+impl BoxSpaceBinaryMazeExporter {
+    /// Construct a new instance.
+    ///
+    /// Optional, see [`DefaultMazeExporter`][crate::interface::export::DefaultMazeExporter].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Write the header shared by all cell classes.
+    fn write_header(bytes: &mut Vec<u8>, cell_class: u8, dimension: usize) {
+        bytes.extend_from_slice(&format::MAGIC);
+        bytes.push(format::CURRENT_VERSION);
+        bytes.push(cell_class);
+        bytes.push(u8::try_from(dimension).expect("Cannot export mazes with more than 255 dimensions"));
+    }
+
+    /// Append a `usize` as a `u64`.
+    fn write_usize(bytes: &mut Vec<u8>, value: usize) {
+        bytes.extend_from_slice(&(value as u64).to_le_bytes());
+    }
+
+    /// Append the checksum and write everything to `output`.
+    fn finish<O: Write>(mut bytes: Vec<u8>, output: &mut O) -> std::io::Result<()> {
+        let checksum = format::crc32(&bytes);
+
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+
+        return output.write_all(&bytes)
+    }
+}
+
+impl Default for BoxSpaceBinaryMazeExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
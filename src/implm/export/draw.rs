@@ -0,0 +1,364 @@
+//! Drawing mazes with immediate-mode graphics libraries, such as [macroquad](https://macroquad.rs)
+//! or [ggez](https://ggez.rs).
+//!
+//! Rather than depend on any one library, a [`MazeDrawer`] works out the geometry of a maze and
+//! hands each shape to a [`Canvas`], which draws it however it likes. Any closure taking a
+//! [`Shape`] and a [`MazeElement`] is a canvas, so hooking a maze up to a render loop is a matter
+//! of forwarding each shape to the library's drawing functions, picking a colour for each element.
+//!
+//! Requires the `draw` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+//! # use mazelib::implm::presets::Inline2DMazeBuilder;
+//! # use mazelib::interface::coordinate::MazeCoordinator;
+//! use mazelib::implm::export::draw::{BoxSpaceMazeDrawer, MazeDrawer, MazeElement, Shape};
+//!
+//! let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([2, 1])).build();
+//! maze.make_passage_between([0, 0].into(), [1, 0].into());
+//!
+//! let drawer = BoxSpaceMazeDrawer::builder().cell_size(32.0).build();
+//!
+//! let mut lines = 0;
+//!
+//! drawer.draw(&maze, &mut |shape: Shape, _: MazeElement| {
+//!     // In a render loop, this would forward the shape to the library, e.g.
+//!     //     draw_line(from[0], from[1], to[0], to[1], thickness, colour_of(element))
+//!     if let Shape::Line { .. } = shape {
+//!         lines += 1;
+//!     }
+//! });
+//!
+//! // The outline of the two cells, but not the passage between them
+//! assert_eq!(6, lines);
+//! ```
+
+use std::fmt::{Debug, Formatter};
+
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
+use crate::implm::point::boxy::{BoxDirection, CoordinateTuplet};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::path::Path;
+
+/// A shape to be drawn on a [`Canvas`].
+///
+/// Positions are in screen units, with the y axis pointing down.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Shape {
+    /// A filled axis-aligned rectangle.
+    Rect {
+        /// The top-left corner.
+        position: [f32; 2],
+        /// The width and height.
+        size: [f32; 2],
+    },
+    /// A straight line.
+    Line {
+        /// One end of the line.
+        from: [f32; 2],
+        /// The other end of the line.
+        to: [f32; 2],
+        /// The thickness of the line.
+        thickness: f32,
+    },
+}
+
+/// The part of a maze a [`Shape`] depicts.
+///
+/// Passages are never drawn, so they show whatever the canvas was cleared to.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum MazeElement {
+    /// A wall.
+    Wall,
+    /// A boundary.
+    Boundary,
+    /// Part of the maze that has not been generated yet.
+    Unvisited,
+    /// A step of a path through the maze, such as its solution.
+    Path,
+}
+
+impl MazeElement {
+    /// Return the element that depicts a connection of the given type, or `None` for passages.
+    fn of(connection: ConnectionType) -> Option<Self> {
+        match connection {
+            ConnectionType::PASSAGE => None,
+            ConnectionType::WALL => Some(Self::Wall),
+            ConnectionType::BOUNDARY => Some(Self::Boundary),
+            ConnectionType::UNVISITED => Some(Self::Unvisited),
+        }
+    }
+}
+
+/// Something [shapes][Shape] can be drawn on, such as the screen of a render loop.
+///
+/// This is implemented for all closures that take the same parameters as [`draw()`][Self::draw].
+pub trait Canvas {
+    /// Draw `shape`, which depicts `element`.
+    fn draw(&mut self, shape: Shape, element: MazeElement);
+}
+
+impl <F: FnMut(Shape, MazeElement)> Canvas for F {
+    fn draw(&mut self, shape: Shape, element: MazeElement) {
+        self(shape, element)
+    }
+}
+
+/// Draws mazes of type `M` on a [`Canvas`].
+pub trait MazeDrawer<M: MazeCoordinator> {
+    /// Draw `maze` on `canvas`.
+    fn draw(&self, maze: &M, canvas: &mut impl Canvas);
+
+    /// Draw `path` (such as the solution of `maze`) on `canvas`, lined up with where `maze` would
+    /// be drawn.
+    ///
+    /// The path is drawn as a [line][Shape::Line] through the centre of each of its points, in
+    /// order.
+    fn draw_path(&self, maze: &M, path: &Path<<M::CoordSpace as CoordinateSpace>::PtType>, canvas: &mut impl Canvas);
+
+    /// [Draw][Self::draw] `maze` on `canvas`, then [draw][Self::draw_path] `path` on top of it.
+    fn draw_with_path(&self, maze: &M, path: &Path<<M::CoordSpace as CoordinateSpace>::PtType>, canvas: &mut impl Canvas) {
+        self.draw(maze, canvas);
+        self.draw_path(maze, path, canvas);
+    }
+}
+
+/// A [`MazeDrawer`] for 2D [`BoxSpaceBlockCellMazeCoordinator`]s and
+/// [`BoxSpaceInlineCellMazeCoordinator`]s.
+///
+/// Block cell mazes are drawn as a [rectangle][Shape::Rect] for every cell that isn't a passage.
+/// Inline cell mazes are drawn as a [line][Shape::Line] for every edge that isn't a passage. Either
+/// way, each cell is a square [`cell_size()`][Self::cell_size] units wide, and the top-left corner
+/// of the maze is at [`origin()`][Self::origin].
+pub struct BoxSpaceMazeDrawer {
+    /// The position of the top-left corner of the maze.
+    origin: [f32; 2],
+    /// The width and height of each cell.
+    cell_size: f32,
+    /// The thickness of walls between inline cells.
+    wall_thickness: f32,
+    /// The thickness of paths.
+    path_thickness: f32,
+}
+
+impl BoxSpaceMazeDrawer {
+    /// Construct a new builder for a `BoxSpaceMazeDrawer`.
+    pub fn builder() -> BoxSpaceMazeDrawerBuilder {
+        BoxSpaceMazeDrawerBuilder::new()
+    }
+
+    /// Return the position of the top-left corner of the maze.
+    #[must_use]
+    pub fn origin(&self) -> [f32; 2] {
+        self.origin
+    }
+
+    /// Return the width and height of each cell.
+    #[must_use]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Return the thickness of walls between inline cells.
+    #[must_use]
+    pub fn wall_thickness(&self) -> f32 {
+        self.wall_thickness
+    }
+
+    /// Return the thickness of paths.
+    #[must_use]
+    pub fn path_thickness(&self) -> f32 {
+        self.path_thickness
+    }
+
+    /// Return the position of a corner of the cell grid.
+    fn corner(&self, [x, y]: [usize; 2]) -> [f32; 2] {
+        [self.origin[0] + x as f32 * self.cell_size, self.origin[1] + y as f32 * self.cell_size]
+    }
+
+    /// Return the position of the centre of the cell at `[x, y]`.
+    fn centre(&self, [x, y]: [usize; 2]) -> [f32; 2] {
+        [self.origin[0] + (x as f32 + 0.5) * self.cell_size, self.origin[1] + (y as f32 + 0.5) * self.cell_size]
+    }
+
+    /// Draw the edge running one cell along `axis` from the corner `start`, if it isn't a passage.
+    fn draw_edge(&self, start: [usize; 2], axis: usize, connection: ConnectionType, canvas: &mut impl Canvas) {
+        let Some(element) = MazeElement::of(connection) else { return };
+
+        let mut end = start;
+        end[axis] += 1;
+
+        canvas.draw(Shape::Line { from: self.corner(start), to: self.corner(end), thickness: self.wall_thickness }, element);
+    }
+
+    /// Draw a line through the centre of each cell in turn.
+    fn draw_line_through(&self, cells: impl Iterator<Item = [usize; 2]>, canvas: &mut impl Canvas) {
+        let centres: Vec<[f32; 2]> = cells.map(|cell| self.centre(cell)).collect();
+
+        for step in centres.windows(2) {
+            canvas.draw(Shape::Line { from: step[0], to: step[1], thickness: self.path_thickness }, MazeElement::Path);
+        }
+    }
+}
+
+impl Debug for BoxSpaceMazeDrawer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BoxSpaceMazeDrawer(origin = {:?}, cell_size = {}, wall_thickness = {}, path_thickness = {})", self.origin, self.cell_size, self.wall_thickness, self.path_thickness)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>> MazeDrawer<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>> for BoxSpaceMazeDrawer {
+    fn draw(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, canvas: &mut impl Canvas) {
+        let [width, height] = maze.get_full_dimensions().map(usize::from);
+
+        for y in 0..height {
+            for x in 0..width {
+                let element = match maze.buffer().get(CellID(x + y * width)).cell_type {
+                    BlockCellPrimaryValue::PASSAGE => continue,
+                    BlockCellPrimaryValue::WALL => MazeElement::Wall,
+                    BlockCellPrimaryValue::BOUNDARY => MazeElement::Boundary,
+                    BlockCellPrimaryValue::UNVISITED => MazeElement::Unvisited,
+                };
+
+                canvas.draw(Shape::Rect { position: self.corner([x, y]), size: [self.cell_size; 2] }, element);
+            }
+        }
+    }
+
+    fn draw_path(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, path: &Path<CoordinateTuplet<2>>, canvas: &mut impl Canvas) {
+        self.draw_line_through(path[..].iter().map(|pt| maze.map_pt_to_cell_loc(*pt).0.0), canvas);
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<2>>> MazeDrawer<BoxSpaceInlineCellMazeCoordinator<Buffer, 2>> for BoxSpaceMazeDrawer {
+    fn draw(&self, maze: &BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, canvas: &mut impl Canvas) {
+        let space = maze.coord_space();
+
+        for pt in space.iter() {
+            for axis in 0..2 {
+                let across = 1 - axis;
+
+                // The edge on the negative side of the point. If it is shared with a neighbour, it
+                // is drawn from this side only.
+                let connection = match space.neighbour(pt, BoxDirection::new(axis, false)) {
+                    Some(neighbour) => maze.get_connection(pt, neighbour),
+                    None => edge_connection(maze.get(pt).edges[axis][0]),
+                };
+
+                self.draw_edge(pt.0, across, connection, canvas);
+
+                // The edge on the positive side, if it is on the outside of the maze
+                if space.neighbour(pt, BoxDirection::new(axis, true)).is_none() {
+                    let mut corner = pt.0;
+                    corner[axis] += 1;
+
+                    self.draw_edge(corner, across, edge_connection(maze.get(pt).edges[axis][1]), canvas);
+                }
+            }
+        }
+    }
+
+    fn draw_path(&self, _: &BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, path: &Path<CoordinateTuplet<2>>, canvas: &mut impl Canvas) {
+        self.draw_line_through(path[..].iter().map(|pt| pt.0), canvas);
+    }
+}
+
+/// Return the type of connection an edge on the outside of the maze makes.
+fn edge_connection(edge: InlineCellValueEdge) -> ConnectionType {
+    match edge {
+        InlineCellValueEdge::PASSAGE => ConnectionType::PASSAGE,
+        InlineCellValueEdge::WALL => ConnectionType::WALL,
+        InlineCellValueEdge::BOUNDARY => ConnectionType::BOUNDARY,
+        InlineCellValueEdge::UNVISITED => ConnectionType::UNVISITED,
+    }
+}
+
+/// A builder for a [`BoxSpaceMazeDrawer`].
+#[must_use]
+pub struct BoxSpaceMazeDrawerBuilder {
+    /// The position of the top-left corner of the maze.
+    origin: [f32; 2],
+    /// The width and height of each cell.
+    cell_size: f32,
+    /// The thickness of walls between inline cells.
+    wall_thickness: f32,
+    /// The thickness of paths.
+    path_thickness: f32,
+}
+
+impl BoxSpaceMazeDrawerBuilder {
+    /// Create a new builder for a [`BoxSpaceMazeDrawer`].
+    fn new() -> Self {
+        Self {
+            origin: [0.0, 0.0],
+            cell_size: 16.0,
+            wall_thickness: 2.0,
+            path_thickness: 4.0,
+        }
+    }
+
+    /// Set the position of the top-left corner of the maze. Defaults to `[0, 0]`.
+    pub fn origin(mut self, origin: [f32; 2]) -> Self {
+        self.origin = origin;
+
+        return self
+    }
+
+    /// Set the width and height of each cell. Defaults to 16.
+    ///
+    /// # Panics
+    ///
+    /// If `cell_size` is not positive.
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        assert!(cell_size > 0.0, "The cell size must be positive");
+
+        self.cell_size = cell_size;
+
+        return self
+    }
+
+    /// Set the thickness of walls between inline cells. Defaults to 2.
+    ///
+    /// # Panics
+    ///
+    /// If `wall_thickness` is not positive.
+    pub fn wall_thickness(mut self, wall_thickness: f32) -> Self {
+        assert!(wall_thickness > 0.0, "The wall thickness must be positive");
+
+        self.wall_thickness = wall_thickness;
+
+        return self
+    }
+
+    /// Set the thickness of paths. Defaults to 4.
+    ///
+    /// # Panics
+    ///
+    /// If `path_thickness` is not positive.
+    pub fn path_thickness(mut self, path_thickness: f32) -> Self {
+        assert!(path_thickness > 0.0, "The path thickness must be positive");
+
+        self.path_thickness = path_thickness;
+
+        return self
+    }
+
+    /// Finalise the [`BoxSpaceMazeDrawer`].
+    #[must_use]
+    pub fn build(self) -> BoxSpaceMazeDrawer {
+        BoxSpaceMazeDrawer {
+            origin: self.origin,
+            cell_size: self.cell_size,
+            wall_thickness: self.wall_thickness,
+            path_thickness: self.path_thickness,
+        }
+    }
+}
\ No newline at end of file
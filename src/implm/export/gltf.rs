@@ -0,0 +1,367 @@
+//! Exporting mazes as [glTF 2.0](https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html) 3D
+//! models.
+
+use std::fmt::Write as _;
+use std::io::{Result, Write};
+
+use crate::implm::cell::block::{BlockCellLocation, BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::export::MazeExporter;
+
+/// An exporter that builds 3D models of [block cell mazes][BoxSpaceBlockCellMazeCoordinator], in
+/// the binary glTF format (`.glb`).
+///
+/// Every wall and boundary cell becomes a cube, and the cubes are merged into a single mesh. Faces
+/// where two cubes touch are left out, so the mesh is only the visible surface of the walls.
+/// Walls and boundaries are separate primitives with their own [materials][Self::builder], so they
+/// can be told apart and restyled. Passages and unvisited cells are left empty.
+///
+/// Two-dimensional mazes are extruded upwards by the [wall height][GltfMazeExporterBuilder::wall_height].
+/// In three-dimensional mazes each layer is stacked on top of the last.
+///
+/// The model is Y-up, as glTF requires. The maze's first axis runs along X, its second along Z,
+/// and its third (if it has one) up along Y. The file is self-contained, so it can be opened by
+/// any glTF viewer or engine.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::generate::HuntAndKillGenerator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::export::MazeExporter;
+/// # use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::implm::export::gltf::GltfMazeExporter;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([8, 8])).build();
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// let mut glb = Vec::new();
+/// GltfMazeExporter::builder().wall_height(2.0).build().export(&maze, &mut glb).unwrap();
+///
+/// assert!(glb.starts_with(b"glTF"));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GltfMazeExporter {
+    /// The width and depth of each cell, in metres.
+    cell_size: f32,
+    /// The height of the walls of two-dimensional mazes, in metres.
+    wall_height: f32,
+    /// The base colour of walls, as linear RGBA.
+    wall_colour: [f32; 4],
+    /// The base colour of boundaries, as linear RGBA.
+    boundary_colour: [f32; 4],
+}
+
+impl GltfMazeExporter {
+    /// Construct a new builder for a `GltfMazeExporter`.
+    pub fn builder() -> GltfMazeExporterBuilder {
+        GltfMazeExporterBuilder::new()
+    }
+
+    /// Return the width and depth of each cell, in metres.
+    #[must_use]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Return the height of the walls of two-dimensional mazes, in metres.
+    #[must_use]
+    pub fn wall_height(&self) -> f32 {
+        self.wall_height
+    }
+
+    /// Return the base colour of walls, as linear RGBA.
+    #[must_use]
+    pub fn wall_colour(&self) -> [f32; 4] {
+        self.wall_colour
+    }
+
+    /// Return the base colour of boundaries, as linear RGBA.
+    #[must_use]
+    pub fn boundary_colour(&self) -> [f32; 4] {
+        self.boundary_colour
+    }
+
+    /// Write the model of a grid of cells, where `material_of` gives the material of every solid
+    /// cell (`0` for walls and `1` for boundaries) and `None` for empty ones.
+    ///
+    /// The grid is indexed in glTF's axes, with Y up. `sizes` are the dimensions of each cell.
+    fn export_grid(&self, grid: [usize; 3], sizes: [f32; 3], material_of: impl Fn([usize; 3]) -> Option<usize>, output: &mut impl Write) -> Result<()> {
+        let mut positions: Vec<[f32; 3]> = Vec::new();
+        let mut normals: Vec<[f32; 3]> = Vec::new();
+        let mut indices: [Vec<u32>; 2] = [Vec::new(), Vec::new()];
+
+        for x in 0..grid[0] {
+            for y in 0..grid[1] {
+                for z in 0..grid[2] {
+                    let cell = [x, y, z];
+
+                    let Some(material) = material_of(cell) else { continue };
+
+                    for axis in 0..3 {
+                        for positive in [false, true] {
+                            let neighbour = if positive { cell[axis].checked_add(1).filter(|i| *i < grid[axis]) } else { cell[axis].checked_sub(1) };
+
+                            // Faces against other solid cells can never be seen
+                            if let Some(i) = neighbour {
+                                let mut other = cell;
+                                other[axis] = i;
+
+                                if material_of(other).is_some() {
+                                    continue
+                                }
+                            }
+
+                            add_face(&mut positions, &mut normals, &mut indices[material], cell, sizes, axis, positive);
+                        }
+                    }
+                }
+            }
+        }
+
+        return self.write_glb(&positions, &normals, &indices, output)
+    }
+
+    /// Write a binary glTF file holding a single mesh, with a primitive for each material that has
+    /// any faces.
+    fn write_glb(&self, positions: &[[f32; 3]], normals: &[[f32; 3]], indices: &[Vec<u32>; 2], output: &mut impl Write) -> Result<()> {
+        let mut bin = Vec::new();
+
+        for vector in positions.iter().chain(normals) {
+            bin.extend(vector.iter().flat_map(|component| component.to_le_bytes()));
+        }
+
+        let indices_offset = bin.len();
+
+        for index in indices.iter().flatten() {
+            bin.extend(index.to_le_bytes());
+        }
+
+        let [min, max] = [f32::min, f32::max].map(|pick| {
+            positions.iter().copied().reduce(|a, b| [0, 1, 2].map(|axis| pick(a[axis], b[axis]))).unwrap_or_default()
+        });
+
+        let vertices_length = positions.len() * 12;
+
+        let mut json = String::new();
+
+        // Writing to a string can't fail
+        let _ = write!(json, r#"{{"asset":{{"version":"2.0","generator":"mazelib"}},"scene":0,"scenes":[{{"nodes":[0]}}],"#);
+
+        if positions.is_empty() {
+            let _ = write!(json, r#""nodes":[{{"name":"Maze"}}]}}"#);
+        } else {
+            let _ = write!(json, r#""nodes":[{{"name":"Maze","mesh":0}}],"#);
+
+            let mut primitives = Vec::new();
+            let mut accessors = vec![
+                format!(r#"{{"bufferView":0,"componentType":5126,"count":{},"type":"VEC3","min":[{},{},{}],"max":[{},{},{}]}}"#, positions.len(), min[0], min[1], min[2], max[0], max[1], max[2]),
+                format!(r#"{{"bufferView":1,"componentType":5126,"count":{},"type":"VEC3"}}"#, normals.len()),
+            ];
+
+            let mut offset = 0;
+
+            for (material, indices) in indices.iter().enumerate() {
+                if indices.is_empty() == false {
+                    primitives.push(format!(r#"{{"attributes":{{"POSITION":0,"NORMAL":1}},"indices":{},"material":{}}}"#, accessors.len(), material));
+                    accessors.push(format!(r#"{{"bufferView":2,"byteOffset":{},"componentType":5125,"count":{},"type":"SCALAR"}}"#, offset * 4, indices.len()));
+                }
+
+                offset += indices.len();
+            }
+
+            let materials = [("Wall", self.wall_colour), ("Boundary", self.boundary_colour)].map(|(name, [r, g, b, a])| {
+                format!(r#"{{"name":"{}","pbrMetallicRoughness":{{"baseColorFactor":[{},{},{},{}],"metallicFactor":0,"roughnessFactor":1}}}}"#, name, r, g, b, a)
+            });
+
+            let _ = write!(json, r#""meshes":[{{"name":"Maze","primitives":[{}]}}],"#, primitives.join(","));
+            let _ = write!(json, r#""materials":[{}],"#, materials.join(","));
+            let _ = write!(json, r#""accessors":[{}],"#, accessors.join(","));
+            let _ = write!(json, r#""bufferViews":[{{"buffer":0,"byteOffset":0,"byteLength":{},"target":34962}},{{"buffer":0,"byteOffset":{},"byteLength":{},"target":34962}},{{"buffer":0,"byteOffset":{},"byteLength":{},"target":34963}}],"#,
+                           vertices_length, vertices_length, vertices_length, indices_offset, bin.len() - indices_offset);
+            let _ = write!(json, r#""buffers":[{{"byteLength":{}}}]}}"#, bin.len());
+        }
+
+        // Chunks must be aligned to four bytes. JSON is padded with spaces, and binary with zeros.
+        let mut json = json.into_bytes();
+        json.resize(padded_length(json.len()), b' ');
+        bin.resize(padded_length(bin.len()), 0);
+
+        let has_bin = bin.is_empty() == false;
+
+        let length = 12 + 8 + json.len() + if has_bin { 8 + bin.len() } else { 0 };
+
+        output.write_all(b"glTF")?;
+        output.write_all(&2u32.to_le_bytes())?;
+        output.write_all(&chunk_length(length).to_le_bytes())?;
+
+        output.write_all(&chunk_length(json.len()).to_le_bytes())?;
+        output.write_all(b"JSON")?;
+        output.write_all(&json)?;
+
+        if has_bin {
+            output.write_all(&chunk_length(bin.len()).to_le_bytes())?;
+            output.write_all(b"BIN\0")?;
+            output.write_all(&bin)?;
+        }
+
+        return Ok(())
+    }
+}
+
+impl Default for GltfMazeExporter {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// A builder for a [`GltfMazeExporter`].
+#[must_use]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GltfMazeExporterBuilder {
+    /// The width and depth of each cell, in metres.
+    cell_size: f32,
+    /// The height of the walls of two-dimensional mazes, in metres.
+    wall_height: f32,
+    /// The base colour of walls, as linear RGBA.
+    wall_colour: [f32; 4],
+    /// The base colour of boundaries, as linear RGBA.
+    boundary_colour: [f32; 4],
+}
+
+impl GltfMazeExporterBuilder {
+    /// Create a new builder for a [`GltfMazeExporter`].
+    fn new() -> Self {
+        Self {
+            cell_size: 1.0,
+            wall_height: 1.0,
+            wall_colour: [0.8, 0.8, 0.8, 1.0],
+            boundary_colour: [0.2, 0.2, 0.2, 1.0],
+        }
+    }
+
+    /// Set the width and depth of each cell, in metres. In three-dimensional mazes this is also
+    /// the height of each layer. Defaults to 1.
+    ///
+    /// # Panics
+    ///
+    /// If `cell_size` is not positive.
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        assert!(cell_size > 0.0, "The cell size must be positive");
+
+        self.cell_size = cell_size;
+
+        return self
+    }
+
+    /// Set the height of the walls of two-dimensional mazes, in metres. Defaults to 1.
+    ///
+    /// # Panics
+    ///
+    /// If `wall_height` is not positive.
+    pub fn wall_height(mut self, wall_height: f32) -> Self {
+        assert!(wall_height > 0.0, "The wall height must be positive");
+
+        self.wall_height = wall_height;
+
+        return self
+    }
+
+    /// Set the base colour of walls, as linear RGBA. Defaults to light grey.
+    pub fn wall_colour(mut self, wall_colour: [f32; 4]) -> Self {
+        self.wall_colour = wall_colour;
+
+        return self
+    }
+
+    /// Set the base colour of boundaries, as linear RGBA. Defaults to dark grey.
+    pub fn boundary_colour(mut self, boundary_colour: [f32; 4]) -> Self {
+        self.boundary_colour = boundary_colour;
+
+        return self
+    }
+
+    /// Finalise the [`GltfMazeExporter`].
+    #[must_use]
+    pub fn build(self) -> GltfMazeExporter {
+        GltfMazeExporter {
+            cell_size: self.cell_size,
+            wall_height: self.wall_height,
+            wall_colour: self.wall_colour,
+            boundary_colour: self.boundary_colour,
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for GltfMazeExporter {
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, output: &mut Output) -> Result<()> {
+        let [width, depth] = maze.get_full_dimensions().map(usize::from);
+
+        let material_of = |[x, _, z]: [usize; 3]| material(maze.get_cell_value(BlockCellLocation([x, z].into())));
+
+        return self.export_grid([width, 1, depth], [self.cell_size, self.wall_height, self.cell_size], material_of, output)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, Output> for GltfMazeExporter {
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, output: &mut Output) -> Result<()> {
+        let [width, depth, height] = maze.get_full_dimensions().map(usize::from);
+
+        let material_of = |[x, y, z]: [usize; 3]| material(maze.get_cell_value(BlockCellLocation([x, z, y].into())));
+
+        return self.export_grid([width, height, depth], [self.cell_size; 3], material_of, output)
+    }
+}
+
+/// Return the material of a cell, or `None` if it is empty.
+fn material(value: BlockCellValue) -> Option<usize> {
+    match value.cell_type {
+        BlockCellPrimaryValue::WALL => Some(0),
+        BlockCellPrimaryValue::BOUNDARY => Some(1),
+        BlockCellPrimaryValue::PASSAGE | BlockCellPrimaryValue::UNVISITED => None,
+    }
+}
+
+/// Add the face of `cell` on the `positive` or negative side of `axis`, as two triangles wound
+/// anticlockwise when seen from outside.
+fn add_face(positions: &mut Vec<[f32; 3]>, normals: &mut Vec<[f32; 3]>, indices: &mut Vec<u32>, cell: [usize; 3], sizes: [f32; 3], axis: usize, positive: bool) {
+    // The other two axes, in the order that makes a right-handed basis with `axis`
+    let [u, v] = [(axis + 1) % 3, (axis + 2) % 3];
+
+    let mut corners = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+
+    if positive == false {
+        corners.reverse();
+    }
+
+    let first = u32::try_from(positions.len()).expect("The maze is too large to export");
+
+    let mut normal = [0.0; 3];
+    normal[axis] = if positive { 1.0 } else { -1.0 };
+
+    for [du, dv] in corners {
+        let mut offset = [0.0; 3];
+        offset[axis] = if positive { 1.0 } else { 0.0 };
+        offset[u] = du;
+        offset[v] = dv;
+
+        positions.push([0, 1, 2].map(|i| (cell[i] as f32 + offset[i]) * sizes[i]));
+        normals.push(normal);
+    }
+
+    indices.extend([0, 1, 2, 0, 2, 3].map(|i| first + i));
+}
+
+/// Convert the length of a glTF file or chunk to the `u32` it's stored as.
+fn chunk_length(length: usize) -> u32 {
+    u32::try_from(length).expect("The maze is too large to export")
+}
+
+/// Return `length` rounded up to a multiple of four.
+fn padded_length(length: usize) -> usize {
+    length.div_ceil(4) * 4
+}
\ No newline at end of file
@@ -0,0 +1,83 @@
+//! The geometry shared by the exporters that draw [hexagonal mazes][HexSpaceHexCellMazeCoordinator]
+//! as images.
+//!
+//! None of the rectilinear exporters can draw hexagons, so these work out the corners of every
+//! hexagon and which of their edges are walls. The exporters themselves are
+//! [`HexSvgMazeExporter`][crate::implm::export::svg::HexSvgMazeExporter] and
+//! [`HexSpaceImageMazeExporter`][crate::implm::export::img::HexSpaceImageMazeExporter].
+
+use crate::implm::cell::hex::HexCellValue;
+use crate::implm::cell::inline::InlineCellValueEdge;
+use crate::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+use crate::implm::point::hex::HexDirection;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Which way up hexagons are drawn.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum HexOrientation {
+    /// Hexagons have a flat edge at the top. Columns of the maze run down the image, as in the
+    /// [layout of hexagonal coordinates][crate::implm::point::hex::HexCoordinate].
+    #[default]
+    FlatTop,
+    /// Hexagons have a corner at the top. The maze is drawn transposed, so columns of the maze run
+    /// across the image instead, with every odd one shifted right by half a hexagon.
+    PointyTop,
+}
+
+/// The ratio of a hexagon's width across its flat sides to its width across its corners.
+const FLAT_TO_CORNER: f64 = 0.866_025_403_784_438_6;  // sqrt(3) / 2
+
+/// Return the size of the drawing of `maze`, and the line segments of all of its walls, where every
+/// hexagon is `radius` units from its centre to its corners.
+///
+/// Positions have the y axis pointing down, with the origin at the top-left corner of the drawing.
+/// Every wall is given once, even though it is shared by two hexagons. Edges on the outside of the
+/// maze are walls unless they are passages.
+pub(crate) fn hex_walls<Buffer: MazeBuffer<HexCellValue>>(maze: &HexSpaceHexCellMazeCoordinator<Buffer>, orientation: HexOrientation, radius: f64) -> ([f64; 2], Vec<[[f64; 2]; 2]>) {
+    let columns = usize::from(maze.coord_space().columns()) as f64;
+    let rows = usize::from(maze.coord_space().rows()) as f64;
+
+    let half_height = FLAT_TO_CORNER * radius;
+
+    // Odd columns hang half a hexagon below the even ones, if there are any
+    let width = radius * (1.5 * columns + 0.5);
+    let height = 2.0 * half_height * (rows + if columns > 1.0 { 0.5 } else { 0.0 });
+
+    let mut walls = Vec::new();
+
+    for pt in maze.coord_space().iter() {
+        let centre = [
+            radius * (1.0 + 1.5 * pt.column as f64),
+            half_height * (1.0 + 2.0 * pt.row as f64 + (pt.column % 2) as f64),
+        ];
+
+        for direction in HexDirection::ALL {
+            let is_wall = match maze.coord_space().neighbour(pt, direction) {
+                // Only draw shared edges from one side
+                Some(_) if (direction as usize) >= 3 => false,
+                Some(neighbour) => maze.get_connection(pt, neighbour) != ConnectionType::PASSAGE,
+                None => maze.get(pt).get_edge(direction) != InlineCellValueEdge::PASSAGE,
+            };
+
+            if is_wall {
+                // The edge facing north runs between the corners at 240° and 300°, and the rest
+                // follow clockwise
+                let corners = [240.0, 300.0].map(|angle: f64| {
+                    let angle = (angle + 60.0 * direction as usize as f64).to_radians();
+
+                    [centre[0] + radius * angle.cos(), centre[1] + radius * angle.sin()]
+                });
+
+                walls.push(corners);
+            }
+        }
+    }
+
+    return match orientation {
+        HexOrientation::FlatTop => ([width, height], walls),
+        HexOrientation::PointyTop => ([height, width], walls.into_iter().map(|wall| wall.map(|[x, y]| [y, x])).collect()),
+    }
+}
\ No newline at end of file
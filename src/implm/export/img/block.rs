@@ -1,10 +1,10 @@
 use std::io::{Result, Seek, Write};
 
-use image::{ImageError, Rgba, RgbaImage};
+use image::{Rgba, RgbaImage};
 
-use crate::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue};
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue, BlockCellPrimaryValue};
 use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
-use crate::implm::export::img::{BoxSpaceImageMazeExporter, ImageMazeExporter};
+use crate::implm::export::img::{write_image, BoxSpaceImageMazeExporter, ImageMazeExporter};
 use crate::interface::buffer::MazeBuffer;
 use crate::interface::export::MazeExporter;
 use crate::internal::util::nonzero_usize_array_to_usize_array;
@@ -17,26 +17,33 @@ impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write + Seek> MazeExporter<Box
 
         for y in 0..height {
             for x in 0..width {
-                let pixel = match maze.get_cell_value([x, y].into()).cell_type {
-                    BlockCellPrimaryValue::PASSAGE => Rgba::from([255, 255, 255, 255]),
-                    BlockCellPrimaryValue::WALL | BlockCellPrimaryValue::BOUNDARY => Rgba::from([0, 0, 0, 255]),
-                    BlockCellPrimaryValue::UNVISITED => Rgba::from([0, 0, 0, 0]),
+                let loc = BlockCellLocation([x as usize, y as usize].into());
+                let value = maze.get_cell_value(loc);
+
+                let pixel = if let Some(colour_fn) = &self.colour_fn {
+                    colour_fn(loc, &value)
+                } else {
+                    self.default_colour(&value)
                 };
 
                 img.put_pixel(x as u32, y as u32, pixel);
             }
         }
 
-        return match img.write_to(output, self.format) {
-            Ok(_) => Ok(()),
-            Err(err) => match err {
-                ImageError::Decoding(_) => unreachable!("Decoding error encountered during encoding???"),
-                ImageError::Encoding(err) => panic!("[Bug] Failed to write image: {}", err),
-                ImageError::Parameter(err) => panic!("[Bug] Failed to write image: {}", err),
-                ImageError::Limits(err) => panic!("{}", err),
-                ImageError::Unsupported(err) => panic!("{}", err),
-                ImageError::IoError(err) => Err(err),
-            }
+        return write_image(&img, self.format, &self.metadata, output)
+    }
+}
+
+impl BoxSpaceImageMazeExporter {
+    /// Return the colour of a cell when there is no colour function.
+    pub(super) fn default_colour(&self, value: &BlockCellValue) -> Rgba<u8> {
+        match value.cell_type {
+            BlockCellPrimaryValue::PASSAGE => match self.marked_colour {
+                Some(colour) if value.marked => colour,
+                _ => Rgba::from([255, 255, 255, 255]),
+            },
+            BlockCellPrimaryValue::WALL | BlockCellPrimaryValue::BOUNDARY => Rgba::from([0, 0, 0, 255]),
+            BlockCellPrimaryValue::UNVISITED => Rgba::from([0, 0, 0, 0]),
         }
     }
 }
@@ -0,0 +1,218 @@
+use std::io::{Result, Seek, Write};
+
+use image::{ImageFormat, Rgba, RgbaImage};
+
+use crate::implm::cell::hex::HexCellValue;
+use crate::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+use crate::implm::export::hex::{hex_walls, HexOrientation};
+use crate::implm::export::img::{write_image, ImageMazeExporter};
+use crate::implm::export::metadata::MazeMetadata;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::export::MazeExporter;
+
+/// An [`ImageMazeExporter`] for [hexagonal mazes][HexSpaceHexCellMazeCoordinator].
+///
+/// Every hexagon edge that isn't a passage is drawn as a wall, including the outside of the maze,
+/// on a plain background. Hexagons can be drawn [flat- or pointy-topped][HexOrientation].
+///
+/// Each instance only exports to a given image format, specified on construction. PNG images can
+/// also carry [metadata][Self::with_metadata] describing the maze.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::Cursor;
+/// # use image::ImageFormat;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::hex::HexCellValue;
+/// # use mazelib::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+/// # use mazelib::implm::generate::HuntAndKillGenerator;
+/// # use mazelib::implm::point::hex::HexCoordinateSpace;
+/// # use mazelib::interface::export::MazeExporter;
+/// # use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::implm::export::hex::HexOrientation;
+/// use mazelib::implm::export::img::HexSpaceImageMazeExporter;
+///
+/// let mut maze = HexSpaceHexCellMazeCoordinator::<VecBuffer<HexCellValue>>::builder(HexCoordinateSpace::new_checked(8, 6)).build();
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// let exporter = HexSpaceImageMazeExporter::new(ImageFormat::Png).with_orientation(HexOrientation::PointyTop);
+///
+/// exporter.export(&maze, &mut Cursor::new(Vec::new())).unwrap();
+/// ```
+pub struct HexSpaceImageMazeExporter {
+    format: ImageFormat,
+    /// Which way up the hexagons are drawn.
+    orientation: HexOrientation,
+    /// The distance from the centre of each hexagon to its corners, in pixels.
+    cell_size: f64,
+    /// The width of the walls, in pixels.
+    wall_thickness: f64,
+    /// The colour of the walls.
+    wall_colour: Rgba<u8>,
+    /// The colour of everything else.
+    background_colour: Rgba<u8>,
+    /// The metadata to embed in PNG images.
+    metadata: MazeMetadata,
+}
+
+impl HexSpaceImageMazeExporter {
+    /// Construct a new instance, drawing black walls on white.
+    ///
+    /// # Parameters
+    ///
+    /// `format` --- the image format that mazes will be exported as. [`image`] must support
+    ///              encoding in it ([`ImageFormat::can_write`] must return true).
+    #[must_use]
+    pub fn new(format: ImageFormat) -> Self {
+        Self {
+            format,
+            orientation: HexOrientation::default(),
+            cell_size: 10.0,
+            wall_thickness: 2.0,
+            wall_colour: Rgba([0, 0, 0, 255]),
+            background_colour: Rgba([255, 255, 255, 255]),
+            metadata: MazeMetadata::default(),
+        }
+    }
+
+    /// Draw the hexagons `orientation` way up. Defaults to [`HexOrientation::FlatTop`].
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: HexOrientation) -> Self {
+        self.orientation = orientation;
+
+        return self
+    }
+
+    /// Return which way up the hexagons are drawn.
+    #[must_use]
+    pub fn orientation(&self) -> HexOrientation {
+        self.orientation
+    }
+
+    /// Draw every hexagon `cell_size` pixels from its centre to its corners. Defaults to 10.
+    ///
+    /// # Panics
+    ///
+    /// If `cell_size` is not positive.
+    #[must_use]
+    pub fn with_cell_size(mut self, cell_size: f64) -> Self {
+        assert!(cell_size > 0.0, "The cell size must be positive");
+
+        self.cell_size = cell_size;
+
+        return self
+    }
+
+    /// Return the distance from the centre of each hexagon to its corners, in pixels.
+    #[must_use]
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    /// Draw the walls `wall_thickness` pixels wide. Defaults to 2.
+    ///
+    /// # Panics
+    ///
+    /// If `wall_thickness` is not positive.
+    #[must_use]
+    pub fn with_wall_thickness(mut self, wall_thickness: f64) -> Self {
+        assert!(wall_thickness > 0.0, "The wall thickness must be positive");
+
+        self.wall_thickness = wall_thickness;
+
+        return self
+    }
+
+    /// Return the width of the walls, in pixels.
+    #[must_use]
+    pub fn wall_thickness(&self) -> f64 {
+        self.wall_thickness
+    }
+
+    /// Draw the walls in `wall_colour` on `background_colour`.
+    #[must_use]
+    pub fn with_colours(mut self, wall_colour: Rgba<u8>, background_colour: Rgba<u8>) -> Self {
+        self.wall_colour = wall_colour;
+        self.background_colour = background_colour;
+
+        return self
+    }
+
+    /// Return the colour of the walls.
+    #[must_use]
+    pub fn wall_colour(&self) -> Rgba<u8> {
+        self.wall_colour
+    }
+
+    /// Return the colour of everything but the walls.
+    #[must_use]
+    pub fn background_colour(&self) -> Rgba<u8> {
+        self.background_colour
+    }
+
+    /// Embed `metadata` in exported images, as `tEXt` chunks. Only PNG images support metadata,
+    /// so it is ignored for other formats.
+    ///
+    /// *See [`MazeMetadata`].*
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: MazeMetadata) -> Self {
+        self.metadata = metadata;
+
+        return self
+    }
+
+    /// Return the metadata embedded in exported PNG images.
+    #[must_use]
+    pub fn metadata(&self) -> &MazeMetadata {
+        &self.metadata
+    }
+}
+
+impl <Buffer: MazeBuffer<HexCellValue>, Output: Write + Seek> MazeExporter<HexSpaceHexCellMazeCoordinator<Buffer>, Output> for HexSpaceImageMazeExporter {
+    fn export(&self, maze: &HexSpaceHexCellMazeCoordinator<Buffer>, output: &mut Output) -> Result<()> {
+        let ([width, height], walls) = hex_walls(maze, self.orientation, self.cell_size);
+
+        // Leave room for the walls around the outside
+        let margin = self.wall_thickness / 2.0;
+
+        let [width, height] = [width, height].map(|length| TryInto::<u32>::try_into((length + 2.0 * margin).ceil() as u64).expect("Cannot export mazes with dimensions larger than u32"));
+
+        let mut img = RgbaImage::from_pixel(width, height, self.background_colour);
+
+        for [from, to] in walls {
+            let [from, to] = [from, to].map(|[x, y]| [x + margin, y + margin]);
+
+            // Only look at the pixels near the wall
+            let min = [0, 1].map(|axis| (from[axis].min(to[axis]) - margin).floor().max(0.0) as u32);
+            let max = [(width, 0), (height, 1)].map(|(length, axis)| ((from[axis].max(to[axis]) + margin).ceil() as u32).min(length));
+
+            for y in min[1]..max[1] {
+                for x in min[0]..max[0] {
+                    let centre = [f64::from(x) + 0.5, f64::from(y) + 0.5];
+
+                    if distance_to_segment(centre, from, to) <= margin {
+                        img.put_pixel(x, y, self.wall_colour);
+                    }
+                }
+            }
+        }
+
+        return write_image(&img, self.format, &self.metadata, output)
+    }
+}
+
+impl <Buffer: MazeBuffer<HexCellValue>, Output: Write + Seek> ImageMazeExporter<HexSpaceHexCellMazeCoordinator<Buffer>, Output> for HexSpaceImageMazeExporter {}
+
+/// Return the distance from `pt` to the nearest point on the line segment from `from` to `to`.
+fn distance_to_segment(pt: [f64; 2], from: [f64; 2], to: [f64; 2]) -> f64 {
+    let segment = [to[0] - from[0], to[1] - from[1]];
+    let offset = [pt[0] - from[0], pt[1] - from[1]];
+
+    let length_squared = segment[0] * segment[0] + segment[1] * segment[1];
+
+    // How far along the segment the nearest point is, from 0 (at `from`) to 1 (at `to`)
+    let t = if length_squared == 0.0 { 0.0 } else { ((offset[0] * segment[0] + offset[1] * segment[1]) / length_squared).clamp(0.0, 1.0) };
+
+    return (offset[0] - t * segment[0]).hypot(offset[1] - t * segment[1])
+}
\ No newline at end of file
@@ -1,15 +1,24 @@
 //! Image-related export formats.
 #![cfg(any(feature = "img", doc))]
 
-use std::io::Write;
+use std::io::{Cursor, Result, Seek, Write};
 
 use image;
-use image::ImageFormat;
+use image::{ImageError, ImageFormat, Rgba, RgbaImage};
 
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::export::img::png::insert_text_chunks;
+use crate::implm::export::metadata::MazeMetadata;
 use crate::interface::coordinate::MazeCoordinator;
 use crate::interface::export::MazeExporter;
 
+pub use self::hex::HexSpaceImageMazeExporter;
+pub use self::wavefront::BoxSpaceWavefrontImageExporter;
+
 mod block;
+mod hex;
+mod png;
+mod wavefront;
 
 /// Export a 2D maze into an image.
 ///
@@ -20,10 +29,31 @@ pub trait ImageMazeExporter<M: MazeCoordinator, O: Write> : MazeExporter<M, O> {
 /// use [`BoxCoordinateSpace`][crate::implm::point::boxy::BoxCoordinateSpace]s.
 ///
 /// Each instance only exports to a given image format, specified on construction.
+///
+/// [Marked][crate::interface::cell::CellValue::is_marked] passages can be drawn in a
+/// [distinct colour][Self::with_marked_colour], which makes the markings left by solvers and
+/// analyses visible.
+///
+/// For full control over the colours, give it a [colour function][Self::with_colour_fn] that
+/// picks the colour of every cell. This can draw any colouring scheme, such as zones,
+/// territories, or heatmaps.
+///
+/// PNG images can also carry [metadata][Self::with_metadata] describing the maze.
 pub struct BoxSpaceImageMazeExporter {
     format: ImageFormat,
+    /// The colour to draw marked passage cells, if they are drawn differently.
+    marked_colour: Option<Rgba<u8>>,
+    /// The metadata to embed in PNG images.
+    metadata: MazeMetadata,
+    /// The function picking the colour of every cell, if it isn't picked by its type.
+    colour_fn: Option<Box<ColourFn>>,
 }
 
+/// A function picking the colour of a cell, given its location and value.
+///
+/// *See [`BoxSpaceImageMazeExporter::with_colour_fn()`].*
+pub type ColourFn = dyn Fn(BlockCellLocation<2>, &BlockCellValue) -> Rgba<u8> + Send + Sync;
+
 impl BoxSpaceImageMazeExporter {
     /// Construct a new instance.
     ///
@@ -33,6 +63,125 @@ impl BoxSpaceImageMazeExporter {
     ///              encoding in it ([`ImageFormat::can_write`] must return true).
     #[must_use]
     pub fn new(format: ImageFormat) -> Self {
-        Self { format }
+        Self { format, marked_colour: None, metadata: MazeMetadata::default(), colour_fn: None }
     }
+
+    /// Draw [marked][crate::interface::cell::CellValue::is_marked] passage cells in `colour`,
+    /// such as those on a path found by a solver. By default marked passages are drawn like any
+    /// other passage.
+    #[must_use]
+    pub fn with_marked_colour(mut self, colour: Rgba<u8>) -> Self {
+        self.marked_colour = Some(colour);
+
+        return self
+    }
+
+    /// Return the colour that marked passage cells are drawn in, or `None` if they are drawn like
+    /// any other passage.
+    #[must_use]
+    pub fn marked_colour(&self) -> Option<Rgba<u8>> {
+        self.marked_colour
+    }
+
+    /// Colour every cell with `colour_fn`, which is given the cell's location and value and
+    /// returns its colour. This replaces the default colours, including the
+    /// [marked colour][Self::with_marked_colour].
+    ///
+    /// # Examples
+    ///
+    /// Shade the passages from left to right:
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// # use image::{ImageError, ImageFormat, Rgba, RgbaImage};
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::generate::HuntAndKillGenerator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::export::MazeExporter;
+    /// # use mazelib::interface::generate::DefaultMazeGenerator;
+    /// use mazelib::implm::export::img::BoxSpaceImageMazeExporter;
+    ///
+    /// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([8, 8])).build();
+    /// HuntAndKillGenerator::generate(&mut maze);
+    ///
+    /// let width = maze.get_full_dimensions()[0].get();
+    ///
+    /// let exporter = BoxSpaceImageMazeExporter::new(ImageFormat::Png).with_colour_fn(move |loc, value| {
+    ///     match value.cell_type {
+    ///         BlockCellPrimaryValue::PASSAGE => Rgba([(loc[0] * 255 / width) as u8, 0, 128, 255]),
+    ///         _ => Rgba([0, 0, 0, 255]),
+    ///     }
+    /// });
+    ///
+    /// exporter.export(&maze, &mut Cursor::new(Vec::new())).unwrap();
+    /// ```
+    #[must_use]
+    pub fn with_colour_fn(mut self, colour_fn: impl Fn(BlockCellLocation<2>, &BlockCellValue) -> Rgba<u8> + Send + Sync + 'static) -> Self {
+        self.colour_fn = Some(Box::new(colour_fn));
+
+        return self
+    }
+
+    /// Return the function that picks the colour of every cell, or `None` if cells are coloured
+    /// by their type.
+    #[must_use]
+    pub fn colour_fn(&self) -> Option<&ColourFn> {
+        self.colour_fn.as_deref()
+    }
+
+    /// Embed `metadata` in exported images, as `tEXt` chunks. Only PNG images support metadata,
+    /// so it is ignored for other formats.
+    ///
+    /// *See [`MazeMetadata`].*
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: MazeMetadata) -> Self {
+        self.metadata = metadata;
+
+        return self
+    }
+
+    /// Return the metadata embedded in exported PNG images.
+    #[must_use]
+    pub fn metadata(&self) -> &MazeMetadata {
+        &self.metadata
+    }
+}
+
+/// Encode `img` in `format` and write it to `output`, embedding `metadata` if it's a PNG.
+///
+/// # Panics
+///
+/// If `image` can't encode the image in `format`.
+fn write_image(img: &RgbaImage, format: ImageFormat, metadata: &MazeMetadata, output: &mut (impl Write + Seek)) -> Result<()> {
+    // Text chunks can't be added through `image`, so PNGs with metadata are encoded in memory
+    // first and have them spliced in afterwards
+    let written = if format == ImageFormat::Png && metadata.is_empty() == false {
+        let mut png = Cursor::new(Vec::new());
+
+        img.write_to(&mut png, format).map(|()| Some(png.into_inner()))
+    } else {
+        img.write_to(output, format).map(|()| None)
+    };
+
+    let png = match written {
+        Ok(png) => png,
+        Err(err) => return match err {
+            ImageError::Decoding(_) => unreachable!("Decoding error encountered during encoding???"),
+            ImageError::Encoding(err) => panic!("[Bug] Failed to write image: {}", err),
+            ImageError::Parameter(err) => panic!("[Bug] Failed to write image: {}", err),
+            ImageError::Limits(err) => panic!("{}", err),
+            ImageError::Unsupported(err) => panic!("{}", err),
+            ImageError::IoError(err) => Err(err),
+        }
+    };
+
+    if let Some(mut png) = png {
+        insert_text_chunks(&mut png, metadata);
+
+        output.write_all(&png)?;
+    }
+
+    return Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+use crate::implm::export::metadata::MazeMetadata;
+
+/// The length of the PNG signature and the `IHDR` chunk that follows it, which must come first.
+const HEADER_LENGTH: usize = 8 + 4 + 4 + 13 + 4;
+
+/// Insert a text chunk for each field of `metadata` into an encoded PNG image, straight after its
+/// header.
+///
+/// Text that can be encoded in Latin-1 is stored in a `tEXt` chunk, as most readers expect.
+/// Anything else is stored in an `iTXt` chunk, which holds UTF-8.
+pub(super) fn insert_text_chunks(png: &mut Vec<u8>, metadata: &MazeMetadata) {
+    let mut chunks = Vec::new();
+
+    for (keyword, text) in metadata.png_entries() {
+        let mut data = keyword.as_bytes().to_vec();
+        data.push(0);
+
+        let chunk_type = if text.chars().all(|char| u32::from(char) <= 0xFF) {
+            data.extend(text.chars().map(|char| char as u8));
+
+            b"tEXt"
+        } else {
+            // No compression, and no language tag or translated keyword
+            data.extend([0, 0, 0, 0]);
+            data.extend(text.as_bytes());
+
+            b"iTXt"
+        };
+
+        write_chunk(&mut chunks, chunk_type, &data);
+    }
+
+    png.splice(HEADER_LENGTH..HEADER_LENGTH, chunks);
+}
+
+/// Append a chunk with the given type and data to `output`.
+fn write_chunk(output: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    let length = u32::try_from(data.len()).expect("PNG chunks can't be larger than u32");
+
+    output.extend(length.to_be_bytes());
+    output.extend(chunk_type);
+    output.extend(data);
+    output.extend(crc32(chunk_type.iter().chain(data)).to_be_bytes());
+}
+
+/// Return the CRC-32 of `bytes`, as used by PNG.
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+
+    return crc ^ 0xFFFF_FFFF
+}
\ No newline at end of file
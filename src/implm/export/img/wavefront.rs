@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::io::{Result, Seek, Write};
+
+use image::{ImageFormat, Rgba};
+
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::img::{BoxSpaceImageMazeExporter, ImageMazeExporter};
+use crate::implm::point::boxy::CoordinateTuplet;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::point::CoordinateSpace;
+use crate::util::wavefronts;
+
+/// An [`ImageMazeExporter`] that draws how a flood from a point spreads through a maze, for
+/// demonstrating breadth-first search and flood-fill solving.
+///
+/// Every [wavefront][wavefronts] of the flood is drawn as a band of colour, cycling through a
+/// [palette][Self::with_palette]. Cells the flood doesn't reach are drawn as usual.
+///
+/// The flood can also be drawn [as it spreads][Self::export_frames], one frame per wavefront.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::Cursor;
+/// # use image::ImageFormat;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::generate::HuntAndKillGenerator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::export::MazeExporter;
+/// # use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::implm::export::img::BoxSpaceWavefrontImageExporter;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([8, 8])).build();
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// let exporter = BoxSpaceWavefrontImageExporter::new(ImageFormat::Png, [0, 0].into());
+///
+/// exporter.export(&maze, &mut Cursor::new(Vec::new())).unwrap();
+/// ```
+///
+/// Export an image per step of the flood:
+///
+/// ```no_run
+/// # use std::fs::File;
+/// # use image::ImageFormat;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::generate::HuntAndKillGenerator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::implm::export::img::BoxSpaceWavefrontImageExporter;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([8, 8])).build();
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// let exporter = BoxSpaceWavefrontImageExporter::new(ImageFormat::Png, [0, 0].into());
+///
+/// exporter.export_frames(&maze, |frame| File::create(format!("flood_{:03}.png", frame)))?;
+/// #
+/// # return Ok(());
+/// # }
+/// ```
+pub struct BoxSpaceWavefrontImageExporter {
+    format: ImageFormat,
+    /// The point the flood starts from.
+    origin: CoordinateTuplet<2>,
+    /// The colours of the bands, in order.
+    palette: Vec<Rgba<u8>>,
+}
+
+impl BoxSpaceWavefrontImageExporter {
+    /// The palette used when none is given: eight hues around the colour wheel.
+    pub const DEFAULT_PALETTE: [Rgba<u8>; 8] = [
+        Rgba([230,  25,  75, 255]),
+        Rgba([245, 130,  48, 255]),
+        Rgba([255, 225,  25, 255]),
+        Rgba([ 60, 180,  75, 255]),
+        Rgba([ 70, 240, 240, 255]),
+        Rgba([  0, 130, 200, 255]),
+        Rgba([145,  30, 180, 255]),
+        Rgba([240,  50, 230, 255]),
+    ];
+
+    /// Construct a new instance.
+    ///
+    /// # Parameters
+    ///
+    /// `format` --- the image format that mazes will be exported as. [`image`] must support
+    ///              encoding in it ([`ImageFormat::can_write`] must return true).  
+    /// `origin` --- the point the flood starts from.
+    #[must_use]
+    pub fn new(format: ImageFormat, origin: CoordinateTuplet<2>) -> Self {
+        Self { format, origin, palette: Self::DEFAULT_PALETTE.to_vec() }
+    }
+
+    /// Colour the bands with `palette`. Wavefront `n` is drawn in `palette[n % palette.len()]`.
+    ///
+    /// A single colour draws every reached cell the same, which shows the whole area the flood
+    /// covers.
+    ///
+    /// # Panics
+    ///
+    /// If `palette` is empty.
+    #[must_use]
+    pub fn with_palette(mut self, palette: Vec<Rgba<u8>>) -> Self {
+        assert!(palette.is_empty() == false, "The palette must have at least one colour");
+
+        self.palette = palette;
+
+        return self
+    }
+
+    /// Return the point the flood starts from.
+    #[must_use]
+    pub fn origin(&self) -> CoordinateTuplet<2> {
+        self.origin
+    }
+
+    /// Return the colours of the bands, in order.
+    #[must_use]
+    pub fn palette(&self) -> &[Rgba<u8>] {
+        &self.palette
+    }
+
+    /// Return the number of frames [`export_frames()`][Self::export_frames] would export for
+    /// `maze`, which is the number of wavefronts.
+    ///
+    /// # Panics
+    ///
+    /// If the origin does not lie within `maze`.
+    #[must_use]
+    pub fn frame_count<Buffer: MazeBuffer<BlockCellValue>>(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>) -> usize {
+        self.check_origin(maze);
+
+        return wavefronts(maze, self.origin).len()
+    }
+
+    /// Export one image per wavefront, showing the flood as it spreads. Frame `n` draws the
+    /// first `n + 1` wavefronts, so the last frame is the same as [`export()`][MazeExporter::export].
+    ///
+    /// # Parameters
+    ///
+    /// `maze`   --- the maze to export.  
+    /// `output` --- called with the number of each frame, in order, to get the writer to export
+    ///              it to.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by `output`, or encountered while writing to the writers it
+    /// returns. No more frames are exported after an error.
+    ///
+    /// # Panics
+    ///
+    /// If the origin does not lie within `maze`.
+    pub fn export_frames<Buffer: MazeBuffer<BlockCellValue>, Output: Write + Seek>(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, mut output: impl FnMut(usize) -> Result<Output>) -> Result<()> {
+        self.check_origin(maze);
+
+        let bands = self.cell_bands(maze);
+        let frame_count = bands.values().max().map_or(0, |band| band + 1);
+
+        for frame in 0..frame_count {
+            self.export_bands(maze, &bands, frame, &mut output(frame)?)?;
+        }
+
+        return Ok(())
+    }
+
+    /// Assert that the origin lies within `maze`.
+    fn check_origin<Buffer: MazeBuffer<BlockCellValue>>(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>) {
+        assert!((0..2).all(|axis| self.origin[axis] < usize::from(maze.coord_space()[axis])), "The origin {:?} does not lie within the maze", self.origin);
+    }
+
+    /// Return the wavefront that reaches each cell. A cell on the line between two points is
+    /// reached by the later of their wavefronts.
+    fn cell_bands<Buffer: MazeBuffer<BlockCellValue>>(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>) -> HashMap<BlockCellLocation<2>, usize> {
+        let distances: HashMap<_, _> = wavefronts(maze, self.origin).into_iter()
+            .enumerate()
+            .flat_map(|(distance, front)| front.into_iter().map(move |pt| (pt, distance)))
+            .collect();
+
+        let mut bands = HashMap::new();
+
+        for (pt, distance) in &distances {
+            bands.insert(maze.map_pt_to_cell_loc(*pt), *distance);
+
+            for neighbour in maze.coord_space().neighbours_of_pt(*pt) {
+                let Some(neighbour_distance) = distances.get(&neighbour) else { continue };
+
+                if maze.is_passage_between(*pt, neighbour) && neighbour_distance <= distance {
+                    for loc in maze.get_cells_between(*pt, neighbour) {
+                        bands.entry(loc).or_insert(*distance);
+                    }
+                }
+            }
+        }
+
+        return bands
+    }
+
+    /// Export the maze with every cell reached by wavefront `last` or earlier coloured by its band.
+    fn export_bands<Buffer: MazeBuffer<BlockCellValue>, Output: Write + Seek>(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, bands: &HashMap<BlockCellLocation<2>, usize>, last: usize, output: &mut Output) -> Result<()> {
+        let colours: HashMap<_, _> = bands.iter()
+            .filter(|(_, band)| **band <= last)
+            .map(|(loc, band)| (*loc, self.palette[band % self.palette.len()]))
+            .collect();
+
+        let plain = BoxSpaceImageMazeExporter::new(self.format);
+
+        let exporter = BoxSpaceImageMazeExporter::new(self.format).with_colour_fn(move |loc, value| {
+            colours.get(&loc).copied().unwrap_or_else(|| plain.default_colour(value))
+        });
+
+        return exporter.export(maze, output)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write + Seek> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceWavefrontImageExporter {
+    /// # Panics
+    ///
+    /// If the origin does not lie within `maze`.
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, output: &mut Output) -> Result<()> {
+        self.check_origin(maze);
+
+        return self.export_bands(maze, &self.cell_bands(maze), usize::MAX, output)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write + Seek> ImageMazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceWavefrontImageExporter {}
\ No newline at end of file
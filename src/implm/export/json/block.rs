@@ -0,0 +1,82 @@
+extern crate serde_json;
+
+use std::io::{Result, Write};
+
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::json::{BoxSpaceBlockCellJsonMazeExporter, JsonMazeExporter};
+use crate::implm::export::json::schema::{MetadataJson, BOX_SPACE_BLOCK_CELL_FORMAT, BOX_SPACE_BLOCK_CELL_VERSION, BoxSpaceBlockCellJson, cell_type_to_char};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::util::nonzero_usize_array_to_usize_array;
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, Output> for BoxSpaceBlockCellJsonMazeExporter {
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, output: &mut Output) -> Result<()> {
+        let full_dimensions = maze.get_full_dimensions();
+        let row_length = usize::from(full_dimensions[0]);
+
+        let mut cells = Vec::new();
+        let mut row = String::with_capacity(row_length);
+        let mut marked = Vec::new();
+        let mut stairs_up = Vec::new();
+        let mut stairs_down = Vec::new();
+
+        // The cell space is itself a box space, so we can borrow its iterator to visit every cell
+        for cell_loc in BoxCoordinateSpace::new(full_dimensions).iter() {
+            let value = maze.get_cell_value(BlockCellLocation(cell_loc));
+
+            row.push(cell_type_to_char(value.cell_type));
+
+            if value.marked {
+                marked.push(cell_loc.0.to_vec());
+            }
+
+            if value.connector.goes_up() {
+                stairs_up.push(cell_loc.0.to_vec());
+            }
+
+            if value.connector.goes_down() {
+                stairs_down.push(cell_loc.0.to_vec());
+            }
+
+            if row.len() == row_length {
+                cells.push(std::mem::replace(&mut row, String::with_capacity(row_length)));
+            }
+        }
+
+        let spacing = if maze.is_uniformly_spaced() {
+            Vec::new()
+        } else {
+            (0..DIMENSION).map(|axis| {
+                let spacing = maze.spacing(axis);
+
+                if spacing.iter().all(|gap| *gap == maze.scale_factors()[axis]) {
+                    None
+                } else {
+                    Some(spacing.into_iter().map(usize::from).collect())
+                }
+            }).collect()
+        };
+
+        let json = BoxSpaceBlockCellJson {
+            format: BOX_SPACE_BLOCK_CELL_FORMAT.to_string(),
+            version: BOX_SPACE_BLOCK_CELL_VERSION,
+            dimensions: nonzero_usize_array_to_usize_array(maze.coord_space().dimensions()).to_vec(),
+            scale_factors: nonzero_usize_array_to_usize_array(maze.scale_factors()).to_vec(),
+            spacing,
+            padding: maze.padding().to_vec(),
+            cells,
+            marked,
+            stairs_up,
+            stairs_down,
+            metadata: MetadataJson::from_metadata(&self.metadata),
+        };
+
+        return serde_json::to_writer(output, &json).map_err(|err| err.into())
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, Output: Write> JsonMazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, Output> for BoxSpaceBlockCellJsonMazeExporter {}
\ No newline at end of file
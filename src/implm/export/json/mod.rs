@@ -0,0 +1,237 @@
+//! JSON export formats.
+//!
+//! The schema is documented on each exporter. Mazes exported with
+//! [`BoxSpaceBlockCellJsonMazeExporter`] can be read back in with the matching importer in
+//! [`crate::implm::import::json`]. [`BoxSpaceInlineCellUnityJsonMazeExporter`] is meant for game
+//! engines instead, and can't be imported.
+#![cfg(any(feature = "json", doc))]
+
+use std::io::Write;
+
+use crate::implm::export::metadata::MazeMetadata;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+
+mod block;
+mod unity;
+pub(crate) mod schema;
+
+/// Export a maze into JSON.
+pub trait JsonMazeExporter<M: MazeCoordinator, O: Write> : MazeExporter<M, O> {}
+
+/// A [`JsonMazeExporter`] for [`BoxSpaceBlockCellMazeCoordinator`][crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator]s
+/// of any dimension.
+///
+/// The output is a single JSON object:
+///
+/// ```json
+/// {
+///   "format": "mazelib/box-space-block-cell",
+///   "version": 1,
+///   "dimensions": [3, 2],
+///   "scale_factors": [2, 2],
+///   "padding": [[1, 1], [1, 1]],
+///   "cells": [
+///     "#######",
+///     "#   # #",
+///     "# # # #",
+///     "# #   #",
+///     "#######"
+///   ],
+///   "marked": [[1, 1], [2, 1]]
+/// }
+/// ```
+///
+/// * `dimensions`, `scale_factors`, and `padding` are the coordinator's configuration, ordered
+///   from most minor axis to most major.
+/// * `spacing` lists the distance between each pair of consecutive points, for each axis with
+///   [variable spacing][crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinatorBuilder::spacing].
+///   Other axes are `null`. It's omitted when the maze is uniformly spaced.
+/// * `cells` holds every cell of the maze. Each string is one row along the first (x) axis. Rows
+///   are ordered by y, then z, and so on. Each character is one cell: `' '` is a passage, `'#'` is
+///   a wall, `'@'` is a boundary, and `'.'` is unvisited.
+/// * `marked` lists the [locations][crate::implm::cell::block::BlockCellLocation] of every marked
+///   cell.
+/// * `stairs_up` and `stairs_down` list the locations of every cell whose
+///   [vertical connector][crate::implm::cell::block::VerticalConnector] goes up or down
+///   respectively. They're omitted when empty, as they always are for mazes of fewer than three
+///   dimensions.
+/// * `metadata` holds the exporter's [metadata][Self::with_metadata]. It's omitted when there is
+///   none.
+///
+/// Unlike most exporters, this format is stable. Future versions will increment `version`.
+pub struct BoxSpaceBlockCellJsonMazeExporter {
+    /// The metadata to embed in the output.
+    metadata: MazeMetadata,
+}
+
+impl BoxSpaceBlockCellJsonMazeExporter {
+    /// Construct a new instance.
+    ///
+    /// Optional, see [`DefaultMazeExporter`][crate::interface::export::DefaultMazeExporter].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { metadata: MazeMetadata::default() }
+    }
+
+    /// Embed `metadata` in the output, as a `metadata` object with `title`, `seed`, `algorithm`,
+    /// `timestamp`, and `author` fields. Fields that aren't set are omitted.
+    ///
+    /// *See [`MazeMetadata`].*
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: MazeMetadata) -> Self {
+        self.metadata = metadata;
+
+        return self
+    }
+
+    /// Return the metadata embedded in the output.
+    #[must_use]
+    pub fn metadata(&self) -> &MazeMetadata {
+        &self.metadata
+    }
+}
+
+impl Default for BoxSpaceBlockCellJsonMazeExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`JsonMazeExporter`] for 2D [`BoxSpaceInlineCellMazeCoordinator`][crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator]s
+/// that produces levels for the [Unity](https://unity.com) game engine.
+///
+/// Rather than describe the maze cell by cell, the output lists the wall segments to build and
+/// where to place the player and the goal, so a level can be built from it directly:
+///
+/// ```json
+/// {
+///   "format": "mazelib/unity-level",
+///   "version": 1,
+///   "width": 2,
+///   "height": 1,
+///   "walls": [
+///     { "start": { "x": 0.0, "y": 0.0 }, "end": { "x": 2.0, "y": 0.0 }, "kind": "boundary" },
+///     { "start": { "x": 0.0, "y": 1.0 }, "end": { "x": 2.0, "y": 1.0 }, "kind": "boundary" },
+///     { "start": { "x": 0.0, "y": 0.0 }, "end": { "x": 0.0, "y": 1.0 }, "kind": "boundary" },
+///     { "start": { "x": 2.0, "y": 0.0 }, "end": { "x": 2.0, "y": 1.0 }, "kind": "boundary" }
+///   ],
+///   "markers": [
+///     { "kind": "spawn", "name": "start", "position": { "x": 0.5, "y": 0.5 } },
+///     { "kind": "goal", "name": "goal", "position": { "x": 1.5, "y": 0.5 } }
+///   ]
+/// }
+/// ```
+///
+/// * `width` and `height` are the dimensions of the maze, in points.
+/// * Positions are in units of one point. `(0, 0)` is the outer corner of point `[0, 0]`, `x`
+///   increases along the first axis of the maze, and `y` along the second.
+/// * `walls` lists every edge that isn't a passage, as a straight line from `start` to `end`.
+///   Consecutive edges of the same `kind` along the same line are merged into one segment.
+///   Horizontal segments come first, ordered by `y` then `x`, followed by vertical segments,
+///   ordered by `x` then `y`. `kind` is one of `"wall"`, `"boundary"`, or `"unvisited"`.
+/// * `markers` lists the maze's [named points][crate::mark::NamedPoints], placed at the centre of
+///   their point. The [start][crate::mark::NamedPoints::start] has the `kind` `"spawn"`, the
+///   [goal][crate::mark::NamedPoints::goal] has the `kind` `"goal"`, and all others have the `kind`
+///   `"point"`. They're ordered spawn, goal, then the rest by `name`.
+/// * `metadata` holds the exporter's [metadata][Self::with_metadata]. It's omitted when there is
+///   none.
+///
+/// Unlike most exporters, this format is stable. Future versions will increment `version`.
+///
+/// # Importing into Unity
+///
+/// The schema is laid out so that Unity's `JsonUtility` can read it without any extra packages.
+/// Declare serialisable classes whose fields match the keys above, with positions as `Vector2`s:
+///
+/// ```csharp
+/// [System.Serializable]
+/// public class MazeLevel {
+///     public string format;
+///     public int version;
+///     public int width;
+///     public int height;
+///     public MazeWall[] walls;
+///     public MazeMarker[] markers;
+/// }
+///
+/// [System.Serializable]
+/// public class MazeWall {
+///     public Vector2 start;
+///     public Vector2 end;
+///     public string kind;
+/// }
+///
+/// [System.Serializable]
+/// public class MazeMarker {
+///     public string kind;
+///     public string name;
+///     public Vector2 position;
+/// }
+///
+/// MazeLevel level = JsonUtility.FromJson<MazeLevel>(json);
+/// ```
+///
+/// An importer should check that `format` is `"mazelib/unity-level"` and that it understands
+/// `version`. Unity's y axis points up, so a maze is usually laid on the ground by mapping each
+/// position `(x, y)` to `new Vector3(x, 0, -y) * cellSize`, then stretching a wall prefab between
+/// the ends of each segment and placing the player at the spawn marker.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// use mazelib::implm::export::json::BoxSpaceInlineCellUnityJsonMazeExporter;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::export::DefaultMazeExporter;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::util::set_longest_path_endpoints;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([8, 8])).build();
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+/// set_longest_path_endpoints(&mut maze);
+///
+/// let mut json = Vec::new();
+/// BoxSpaceInlineCellUnityJsonMazeExporter::export(&maze, &mut json).unwrap();
+/// ```
+pub struct BoxSpaceInlineCellUnityJsonMazeExporter {
+    /// The metadata to embed in the output.
+    metadata: MazeMetadata,
+}
+
+impl BoxSpaceInlineCellUnityJsonMazeExporter {
+    /// Construct a new instance.
+    ///
+    /// Optional, see [`DefaultMazeExporter`][crate::interface::export::DefaultMazeExporter].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { metadata: MazeMetadata::default() }
+    }
+
+    /// Embed `metadata` in the output, as a `metadata` object with `title`, `seed`, `algorithm`,
+    /// `timestamp`, and `author` fields. Fields that aren't set are omitted.
+    ///
+    /// *See [`MazeMetadata`].*
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: MazeMetadata) -> Self {
+        self.metadata = metadata;
+
+        return self
+    }
+
+    /// Return the metadata embedded in the output.
+    #[must_use]
+    pub fn metadata(&self) -> &MazeMetadata {
+        &self.metadata
+    }
+}
+
+impl Default for BoxSpaceInlineCellUnityJsonMazeExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
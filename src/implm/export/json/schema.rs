@@ -0,0 +1,143 @@
+extern crate serde;
+
+use serde::{Deserialize, Serialize};
+
+use crate::implm::cell::block::BlockCellPrimaryValue;
+use crate::implm::export::metadata::{format_timestamp, MazeMetadata};
+
+/// The value of `format` for [`BoxSpaceBlockCellJson`].
+pub(crate) const BOX_SPACE_BLOCK_CELL_FORMAT: &str = "mazelib/box-space-block-cell";
+
+/// The current version of the [`BoxSpaceBlockCellJson`] schema.
+pub(crate) const BOX_SPACE_BLOCK_CELL_VERSION: u32 = 1;
+
+/// *See [`BoxSpaceBlockCellJsonMazeExporter`][super::BoxSpaceBlockCellJsonMazeExporter].*
+#[derive(Serialize, Deserialize)]
+pub(crate) struct BoxSpaceBlockCellJson {
+    pub(crate) format: String,
+    pub(crate) version: u32,
+    pub(crate) dimensions: Vec<usize>,
+    pub(crate) scale_factors: Vec<usize>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) spacing: Vec<Option<Vec<usize>>>,
+    pub(crate) padding: Vec<[usize; 2]>,
+    pub(crate) cells: Vec<String>,
+    pub(crate) marked: Vec<Vec<usize>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) stairs_up: Vec<Vec<usize>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) stairs_down: Vec<Vec<usize>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<MetadataJson>,
+}
+
+/// The value of `format` for [`RecursiveBacktrackerProgressJson`].
+pub(crate) const RECURSIVE_BACKTRACKER_PROGRESS_FORMAT: &str = "mazelib/recursive-backtracker-progress";
+
+/// The current version of the [`RecursiveBacktrackerProgressJson`] schema.
+pub(crate) const RECURSIVE_BACKTRACKER_PROGRESS_VERSION: u32 = 1;
+
+/// *See [`RecursiveBacktrackerProgress::save()`][crate::implm::generate::RecursiveBacktrackerProgress::save].*
+#[derive(Serialize, Deserialize)]
+pub(crate) struct RecursiveBacktrackerProgressJson {
+    pub(crate) format: String,
+    pub(crate) version: u32,
+    pub(crate) started: bool,
+    pub(crate) stack: Vec<Vec<usize>>,
+    pub(crate) rng: [u64; 4],
+}
+
+/// The value of `format` for [`UnityLevelJson`].
+pub(crate) const UNITY_LEVEL_FORMAT: &str = "mazelib/unity-level";
+
+/// The current version of the [`UnityLevelJson`] schema.
+pub(crate) const UNITY_LEVEL_VERSION: u32 = 1;
+
+/// *See [`BoxSpaceInlineCellUnityJsonMazeExporter`][super::BoxSpaceInlineCellUnityJsonMazeExporter].*
+#[derive(Serialize)]
+pub(crate) struct UnityLevelJson {
+    pub(crate) format: String,
+    pub(crate) version: u32,
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) walls: Vec<UnityWallJson>,
+    pub(crate) markers: Vec<UnityMarkerJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) metadata: Option<MetadataJson>,
+}
+
+/// A wall segment of a [`UnityLevelJson`].
+#[derive(Serialize)]
+pub(crate) struct UnityWallJson {
+    pub(crate) start: UnityVector2Json,
+    pub(crate) end: UnityVector2Json,
+    pub(crate) kind: &'static str,
+}
+
+/// A marker of a [`UnityLevelJson`].
+#[derive(Serialize)]
+pub(crate) struct UnityMarkerJson {
+    pub(crate) kind: &'static str,
+    pub(crate) name: String,
+    pub(crate) position: UnityVector2Json,
+}
+
+/// A position in a [`UnityLevelJson`], laid out like Unity's `Vector2`.
+#[derive(Serialize)]
+pub(crate) struct UnityVector2Json {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+}
+
+/// The `metadata` object shared by every schema.
+///
+/// *See [`MazeMetadata`].*
+#[derive(Serialize, Deserialize)]
+pub(crate) struct MetadataJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) algorithm: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) timestamp: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) author: Option<String>,
+}
+
+impl MetadataJson {
+    /// Convert `metadata` to its JSON form, or `None` if it's empty.
+    pub(crate) fn from_metadata(metadata: &MazeMetadata) -> Option<Self> {
+        if metadata.is_empty() {
+            return None
+        }
+
+        return Some(Self {
+            title: metadata.title.clone(),
+            seed: metadata.seed,
+            algorithm: metadata.algorithm.clone(),
+            timestamp: metadata.timestamp.map(format_timestamp),
+            author: metadata.author.clone(),
+        })
+    }
+}
+
+pub(crate) fn cell_type_to_char(cell_type: BlockCellPrimaryValue) -> char {
+    match cell_type {
+        BlockCellPrimaryValue::PASSAGE   => ' ',
+        BlockCellPrimaryValue::WALL      => '#',
+        BlockCellPrimaryValue::BOUNDARY  => '@',
+        BlockCellPrimaryValue::UNVISITED => '.',
+    }
+}
+
+pub(crate) fn char_to_cell_type(char: char) -> Option<BlockCellPrimaryValue> {
+    match char {
+        ' ' => Some(BlockCellPrimaryValue::PASSAGE),
+        '#' => Some(BlockCellPrimaryValue::WALL),
+        '@' => Some(BlockCellPrimaryValue::BOUNDARY),
+        '.' => Some(BlockCellPrimaryValue::UNVISITED),
+        _   => None,
+    }
+}
\ No newline at end of file
@@ -0,0 +1,126 @@
+extern crate serde_json;
+
+use std::io::{Result, Write};
+
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
+use crate::implm::export::json::{BoxSpaceInlineCellUnityJsonMazeExporter, JsonMazeExporter};
+use crate::implm::export::json::schema::{MetadataJson, UNITY_LEVEL_FORMAT, UNITY_LEVEL_VERSION, UnityLevelJson, UnityMarkerJson, UnityVector2Json, UnityWallJson};
+use crate::implm::point::boxy::CoordinateTuplet;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::mark::NamedPoints;
+
+impl <Buffer: MazeBuffer<InlineCellValue<2>>, Output: Write> MazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceInlineCellUnityJsonMazeExporter {
+    fn export(&self, maze: &BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, output: &mut Output) -> Result<()> {
+        let dimensions = maze.coord_space().dimensions().map(usize::from);
+
+        let mut walls = Vec::new();
+
+        // Edges running along the x-axis (axis 0), then along the y-axis. Each line of edges runs
+        // across `axis`, at position `line` along it.
+        for axis in [1, 0] {
+            let along = 1 - axis;
+
+            for line in 0..=dimensions[axis] {
+                // The segment being built up, as its kind and the position it started at
+                let mut segment: Option<(&'static str, usize)> = None;
+
+                for position in 0..=dimensions[along] {
+                    let kind = if position < dimensions[along] {
+                        edge_kind(maze, axis, along, line, position)
+                    } else {
+                        None
+                    };
+
+                    if let Some((segment_kind, start)) = segment {
+                        if kind == Some(segment_kind) {
+                            continue
+                        }
+
+                        walls.push(UnityWallJson {
+                            start: vector(axis, line, start),
+                            end: vector(axis, line, position),
+                            kind: segment_kind,
+                        });
+                    }
+
+                    segment = kind.map(|kind| (kind, position));
+                }
+            }
+        }
+
+        let named_points = maze.named_points();
+        let mut others: Vec<(&str, CoordinateTuplet<2>)> = named_points.iter().filter(|(name, _)| *name != NamedPoints::<CoordinateTuplet<2>>::START && *name != NamedPoints::<CoordinateTuplet<2>>::GOAL).collect();
+        others.sort_unstable_by_key(|(name, _)| *name);
+
+        let markers = named_points.start().map(|pt| ("spawn", NamedPoints::<CoordinateTuplet<2>>::START, pt)).into_iter()
+            .chain(named_points.goal().map(|pt| ("goal", NamedPoints::<CoordinateTuplet<2>>::GOAL, pt)))
+            .chain(others.into_iter().map(|(name, pt)| ("point", name, pt)))
+            .map(|(kind, name, pt)| UnityMarkerJson {
+                kind,
+                name: name.to_string(),
+                position: UnityVector2Json { x: pt[0] as f32 + 0.5, y: pt[1] as f32 + 0.5 },
+            })
+            .collect();
+
+        let json = UnityLevelJson {
+            format: UNITY_LEVEL_FORMAT.to_string(),
+            version: UNITY_LEVEL_VERSION,
+            width: dimensions[0],
+            height: dimensions[1],
+            walls,
+            markers,
+            metadata: MetadataJson::from_metadata(&self.metadata),
+        };
+
+        return serde_json::to_writer(output, &json).map_err(|err| err.into())
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<2>>, Output: Write> JsonMazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceInlineCellUnityJsonMazeExporter {}
+
+/// Return the kind of the edge across `axis` at position `line`, beside the point at `position`
+/// along the other axis (`along`), or `None` if it's a passage.
+fn edge_kind<Buffer: MazeBuffer<InlineCellValue<2>>>(maze: &BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, axis: usize, along: usize, line: usize, position: usize) -> Option<&'static str> {
+    let dimension = usize::from(maze.coord_space().dimensions()[axis]);
+
+    let mut pt = CoordinateTuplet([0, 0]);
+    pt[along] = position;
+
+    let connection = if line == 0 || line == dimension {
+        // On the outside of the maze, so there's only one point to ask
+        pt[axis] = if line == 0 { 0 } else { dimension - 1 };
+
+        match maze.get(pt).edges[axis][usize::from(line != 0)] {
+            InlineCellValueEdge::PASSAGE   => ConnectionType::PASSAGE,
+            InlineCellValueEdge::WALL      => ConnectionType::WALL,
+            InlineCellValueEdge::BOUNDARY  => ConnectionType::BOUNDARY,
+            InlineCellValueEdge::UNVISITED => ConnectionType::UNVISITED,
+        }
+    } else {
+        let mut before = pt;
+        before[axis] = line - 1;
+        pt[axis] = line;
+
+        maze.get_connection(before, pt)
+    };
+
+    return match connection {
+        ConnectionType::PASSAGE   => None,
+        ConnectionType::WALL      => Some("wall"),
+        ConnectionType::BOUNDARY  => Some("boundary"),
+        ConnectionType::UNVISITED => Some("unvisited"),
+    }
+}
+
+/// Return the position `position` along a line of edges across `axis` at `line`.
+fn vector(axis: usize, line: usize, position: usize) -> UnityVector2Json {
+    let mut coords = [0.0; 2];
+    coords[axis] = line as f32;
+    coords[1 - axis] = position as f32;
+
+    return UnityVector2Json { x: coords[0], y: coords[1] }
+}
\ No newline at end of file
@@ -0,0 +1,123 @@
+//! Describing exported mazes.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Information about a maze to embed in its exported file, so that the file describes itself.
+///
+/// Every field is optional, and only the fields that are set are written. Exporters that support
+/// metadata take it with a `metadata` or `with_metadata` method, and store it in whatever way is
+/// natural for their format:
+///
+/// * [PNG images][crate::implm::export::img::BoxSpaceImageMazeExporter::with_metadata] ---
+///   `tEXt` chunks (or `iTXt` chunks for text that isn't Latin-1), using the standard `Title`,
+///   `Author`, and `Creation Time` keywords, along with `Seed` and `Algorithm`.
+/// * [SVG images][crate::implm::export::svg::VoronoiSvgMazeExporterBuilder::metadata] --- a
+///   `<metadata>` element holding [Dublin Core](https://www.dublincore.org/specifications/dublin-core/dces/)
+///   properties, which most vector editors understand.
+/// * [JSON][crate::implm::export::json] --- a `metadata` object with `title`, `seed`,
+///   `algorithm`, `timestamp`, and `author` fields.
+/// * [Minecraft schematics][crate::implm::export::minecraft::BoxSpaceSchematicMazeExporter::with_metadata]
+///   --- the schematic's `Metadata` compound, whose `Name`, `Author`, and `Date` are defined by
+///   the specification, along with `Seed` and `Algorithm`.
+///
+/// Timestamps are written in [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) format in UTC
+/// (e.g. `2024-05-01T12:30:00Z`), except in schematics, which use milliseconds since the Unix
+/// epoch.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::SystemTime;
+/// use mazelib::implm::export::metadata::MazeMetadata;
+///
+/// let metadata = MazeMetadata {
+///     title: Some("The Labyrinth".to_string()),
+///     seed: Some(42),
+///     algorithm: Some("Hunt-and-kill".to_string()),
+///     timestamp: Some(SystemTime::now()),
+///     ..MazeMetadata::default()
+/// };
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct MazeMetadata {
+    /// The name of the maze.
+    pub title: Option<String>,
+    /// The seed the maze was generated from.
+    pub seed: Option<u64>,
+    /// The name of the algorithm the maze was generated with.
+    pub algorithm: Option<String>,
+    /// When the maze was generated.
+    pub timestamp: Option<SystemTime>,
+    /// Who made the maze.
+    pub author: Option<String>,
+}
+
+impl MazeMetadata {
+    /// Return true if no fields are set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Return the fields that are set as `(keyword, text)` pairs, using the keywords defined for
+    /// PNG text chunks.
+    #[cfg(any(feature = "img", doc))]
+    pub(crate) fn png_entries(&self) -> Vec<(&'static str, String)> {
+        let mut entries = Vec::new();
+
+        if let Some(title) = &self.title {
+            entries.push(("Title", title.clone()));
+        }
+
+        if let Some(author) = &self.author {
+            entries.push(("Author", author.clone()));
+        }
+
+        if let Some(timestamp) = self.timestamp {
+            entries.push(("Creation Time", format_timestamp(timestamp)));
+        }
+
+        if let Some(seed) = self.seed {
+            entries.push(("Seed", seed.to_string()));
+        }
+
+        if let Some(algorithm) = &self.algorithm {
+            entries.push(("Algorithm", algorithm.clone()));
+        }
+
+        return entries
+    }
+}
+
+/// Return `timestamp` in RFC 3339 format in UTC, to the second.
+pub(crate) fn format_timestamp(timestamp: SystemTime) -> String {
+    let seconds = match timestamp.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(err) => -(err.duration().as_secs() as i64),
+    };
+
+    let days = seconds.div_euclid(86_400);
+    let time = seconds.rem_euclid(86_400);
+
+    // Howard Hinnant's civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    return format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, time / 3600, time % 3600 / 60, time % 60)
+}
+
+/// Return `timestamp` as the number of milliseconds since the Unix epoch.
+#[cfg(any(feature = "minecraft", doc))]
+pub(crate) fn timestamp_millis(timestamp: SystemTime) -> i64 {
+    match timestamp.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_millis() as i64,
+        Err(err) => -(err.duration().as_millis() as i64),
+    }
+}
\ No newline at end of file
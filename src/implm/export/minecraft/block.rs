@@ -4,50 +4,89 @@ use std::time::SystemTime;
 
 use crate::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue};
 use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::metadata::timestamp_millis;
 use crate::implm::export::minecraft::{BoxSpaceSchematicMazeExporter, SchematicMazeExporter};
 use crate::implm::export::minecraft::schem::{SpongeSchematicV3, SpongeSchematicV3BlockContainer, SpongeSchematicV3MetadataObject, SpongeSchematicV3SchematicObject};
 use crate::interface::buffer::MazeBuffer;
 use crate::interface::export::MazeExporter;
 use crate::internal::util::nonzero_usize_array_to_usize_array;
 
+/// The height of each layer of cells, in blocks. One for the floor, and three for the space
+/// above it.
+const STOREY_HEIGHT: usize = 4;
+
 impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceSchematicMazeExporter {
     fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, output: &mut Output) -> Result<()> {
+        let [width, length] = nonzero_usize_array_to_usize_array(maze.get_full_dimensions());
+
+        self.export_layers([width, length, 1], |[x, z, _]| maze.get_cell_value([x, z].into()), output)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, Output> for BoxSpaceSchematicMazeExporter {
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, output: &mut Output) -> Result<()> {
+        let [width, length, layers] = nonzero_usize_array_to_usize_array(maze.get_full_dimensions());
+
+        self.export_layers([width, length, layers], |[x, z, layer]| maze.get_cell_value([x, z, layer].into()), output)
+    }
+}
+
+impl BoxSpaceSchematicMazeExporter {
+    /// Export `layers` layers of cells, each `width` by `length` cells, stacked on top of one
+    /// another. Each layer is [`STOREY_HEIGHT`] blocks tall.
+    fn export_layers<Output: Write>(&self, [width, length, layers]: [usize; 3], get_cell_value: impl Fn([usize; 3]) -> BlockCellValue, output: &mut Output) -> Result<()> {
         // Spec: https://github.com/SpongePowered/Schematic-Specification/blob/master/versions/schematic-3.md
 
-        let [width, length] = nonzero_usize_array_to_usize_array(maze.get_full_dimensions()).map(|dim| TryInto::<u16>::try_into(dim).expect("Cannot export mazes with dimensions larger than u16"));
+        let [width, length] = [width, length].map(|dim| TryInto::<u16>::try_into(dim).expect("Cannot export mazes with dimensions larger than u16"));
+        let height = layers.checked_mul(STOREY_HEIGHT).and_then(|height| u16::try_from(height).ok()).expect("Cannot export mazes with dimensions larger than u16");
 
         let mut palette: HashMap<String, i32> = HashMap::new();
 
         const AIR_BLOCK: i8 = 0;
         const WALL_BLOCK: i8 = 1;
         const FLOOR_BLOCK: i8 = 2;
+        const STAIRS_BLOCK: i8 = 3;
 
         // Any tool that lets you place schematics usually also lets you change the blocks
         // used, so our choices here don't matter
         palette.insert("minecraft:air".to_string(), AIR_BLOCK as i32);
         palette.insert("minecraft:deepslate_bricks".to_string(), WALL_BLOCK as i32);
         palette.insert("minecraft:snow_block".to_string(), FLOOR_BLOCK as i32);
+        palette.insert("minecraft:scaffolding".to_string(), STAIRS_BLOCK as i32);
 
-        // Technically this is supposed to be an array of varints, but we only three blocks
+        // Technically this is supposed to be an array of varints, but we only have four blocks
         // so we can just pretend it's an array of bytes
-        let mut data = vec![AIR_BLOCK; (width as usize) * (length as usize) * 4usize];
-
         let width_usize = width as usize;
         let length_usize = length as usize;
 
-        for z in 0..length_usize {
-            for x in 0..width_usize {
-                match maze.get_cell_value([x, z].into()).cell_type {
-                    BlockCellPrimaryValue::PASSAGE => {
-                        // y = 0
-                        data[x + z * width_usize] = FLOOR_BLOCK;
-                    },
-                    BlockCellPrimaryValue::WALL | BlockCellPrimaryValue::BOUNDARY => {
-                        for y in 0..4 {
-                            data[x + z * width_usize + y * width_usize * length_usize] = WALL_BLOCK;
-                        }
-                    },
-                    BlockCellPrimaryValue::UNVISITED => {},
+        let mut data = vec![AIR_BLOCK; width_usize * length_usize * (height as usize)];
+
+        for layer in 0..layers {
+            for z in 0..length_usize {
+                for x in 0..width_usize {
+                    let index_at = |y: usize| x + z * width_usize + (layer * STOREY_HEIGHT + y) * width_usize * length_usize;
+
+                    let value = get_cell_value([x, z, layer]);
+
+                    match value.cell_type {
+                        BlockCellPrimaryValue::PASSAGE => {
+                            // Stairs going down replace the floor, and stairs going up rise to the
+                            // top of the storey, to meet the stairs coming down from above
+                            data[index_at(0)] = if value.connector.goes_down() { STAIRS_BLOCK } else { FLOOR_BLOCK };
+
+                            if value.connector.goes_up() {
+                                for y in 0..STOREY_HEIGHT {
+                                    data[index_at(y)] = STAIRS_BLOCK;
+                                }
+                            }
+                        },
+                        BlockCellPrimaryValue::WALL | BlockCellPrimaryValue::BOUNDARY => {
+                            for y in 0..STOREY_HEIGHT {
+                                data[index_at(y)] = WALL_BLOCK;
+                            }
+                        },
+                        BlockCellPrimaryValue::UNVISITED => {},
+                    }
                 }
             }
         }
@@ -57,13 +96,16 @@ impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBl
                 Version: 3,
                 DataVersion: 2975, // 1.18.2
                 Metadata: Some(SpongeSchematicV3MetadataObject {
-                    Name: None,
-                    Author: None,
-                    Date: Some(SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis() as i64),
+                    Name: self.metadata.title.clone(),
+                    Author: self.metadata.author.clone(),
+                    Date: Some(timestamp_millis(self.metadata.timestamp.unwrap_or_else(SystemTime::now))),
                     RequiredMods: None,
+                    // NBT has no unsigned integers, so large seeds wrap around to negative numbers
+                    Seed: self.metadata.seed.map(|seed| seed as i64),
+                    Algorithm: self.metadata.algorithm.clone(),
                 }),
                 Width: width as i16,
-                Height: 4,
+                Height: height as i16,
                 Length: length as i16,
                 Offset: None,
                 Blocks: Some(SpongeSchematicV3BlockContainer {
@@ -86,4 +128,6 @@ impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBl
     }
 }
 
-impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> SchematicMazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceSchematicMazeExporter {}
\ No newline at end of file
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> SchematicMazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceSchematicMazeExporter {}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> SchematicMazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, Output> for BoxSpaceSchematicMazeExporter {}
\ No newline at end of file
@@ -3,13 +3,14 @@
 
 use std::io::Write;
 
+use crate::implm::export::metadata::MazeMetadata;
 use crate::interface::coordinate::MazeCoordinator;
 use crate::interface::export::MazeExporter;
 
 mod block;
 mod schem;
 
-/// Export a 2D maze into a Minecraft schematic.
+/// Export a 2D or 3D maze into a Minecraft schematic.
 ///
 /// Specifically, it exports the maze to a
 /// [Sponge Schematic (v3)](https://github.com/SpongePowered/Schematic-Specification/blob/master/versions/schematic-3.md),
@@ -20,12 +21,19 @@ mod schem;
 /// The blocks used cannot be changed, however most programs that let you import schematics
 /// also let you replace blocks of one type with another. The vanilla game also supports this
 /// on small areas with [`/fill <from> <to> replace <block>`](https://minecraft.fandom.com/wiki/Commands/fill).
+///
+/// Three-dimensional mazes are built as a stack of storeys, one for each layer of cells along the
+/// third axis, with Minecraft's y axis pointing up. Their
+/// [vertical connectors][crate::implm::cell::block::VerticalConnector] are built as climbable
+/// columns of scaffolding. A full staircase needs more room than the single block a cell is
+/// exported as, so swap the scaffolding for ladders or stairs after importing if you prefer.
 pub trait SchematicMazeExporter<M: MazeCoordinator, O: Write> : MazeExporter<M, O> {}
 
 /// A [`SchematicMazeExporter`] for mazes that
 /// use [`BoxCoordinateSpace`][crate::implm::point::boxy::BoxCoordinateSpace]s.
 pub struct BoxSpaceSchematicMazeExporter {
-    _private: ()
+    /// The metadata to embed in the schematic.
+    metadata: MazeMetadata,
 }
 
 impl BoxSpaceSchematicMazeExporter {
@@ -34,6 +42,24 @@ impl BoxSpaceSchematicMazeExporter {
     /// Optional, see [`DefaultMazeExporter`][crate::interface::export::DefaultMazeExporter].
     #[must_use]
     pub fn new() -> Self {
-        Self { _private: () }
+        Self { metadata: MazeMetadata::default() }
+    }
+
+    /// Embed `metadata` in the schematic's `Metadata` compound. The title is stored as its
+    /// `Name`, the author as its `Author`, and the timestamp as its `Date`, which defaults to the
+    /// time of export. The seed and algorithm are stored as `Seed` and `Algorithm`.
+    ///
+    /// *See [`MazeMetadata`].*
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: MazeMetadata) -> Self {
+        self.metadata = metadata;
+
+        return self
+    }
+
+    /// Return the metadata embedded in the schematic.
+    #[must_use]
+    pub fn metadata(&self) -> &MazeMetadata {
+        &self.metadata
     }
 }
\ No newline at end of file
@@ -33,6 +33,9 @@ pub(super) struct SpongeSchematicV3MetadataObject {
     pub(super) Author: Option<String>,
     pub(super) Date: Option<i64>,
     pub(super) RequiredMods: Option<Vec<String>>,
+    // Not in the spec, which allows any other fields
+    pub(super) Seed: Option<i64>,
+    pub(super) Algorithm: Option<String>,
 }
 
 #[allow(non_snake_case)] // using the spec's names
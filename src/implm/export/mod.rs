@@ -6,4 +6,16 @@
 
 pub mod text;
 pub mod img;
-pub mod minecraft;
\ No newline at end of file
+pub mod minecraft;
+pub mod tiled;
+pub mod json;
+pub mod ros;
+pub mod slice;
+pub mod binary;
+pub mod svg;
+pub mod gltf;
+pub mod vox;
+pub mod hex;
+pub mod metadata;
+#[cfg(any(feature = "draw", doc))]
+pub mod draw;
\ No newline at end of file
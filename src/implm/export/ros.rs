@@ -0,0 +1,205 @@
+//! Occupancy grid maps for the [Robot Operating System](https://www.ros.org) (ROS).
+//!
+//! ROS's [`map_server`](https://wiki.ros.org/map_server) loads maps as a pair of files: an image
+//! where each pixel is one square of floor, and a YAML file describing how big the squares are and
+//! where the map sits in the world. A [`BoxSpaceBlockCellRosMapExporter`] writes both, so generated
+//! mazes can be used as test environments for navigation stacks.
+
+use std::fmt::{Debug, Formatter};
+use std::io::{Result, Write};
+
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::export::MazeExporter;
+use crate::internal::util::nonzero_usize_array_to_usize_array;
+
+/// The value of occupied pixels (walls and boundaries).
+const OCCUPIED: u8 = 0;
+
+/// The value of free pixels (passages).
+const FREE: u8 = 254;
+
+/// The value of unknown pixels (unvisited cells).
+const UNKNOWN: u8 = 205;
+
+/// An exporter that writes 2D [`BoxSpaceBlockCellMazeCoordinator`]s as ROS occupancy grid maps.
+///
+/// [`export()`][MazeExporter::export] writes the map image, as a binary
+/// [PGM](https://netpbm.sourceforge.net/doc/pgm.html) with one pixel per cell. Walls and
+/// boundaries are occupied (black), passages are free (white), and unvisited cells are unknown
+/// (grey). The first row of the image is the row of cells with a y of 0.
+///
+/// [`export_metadata()`][Self::export_metadata] writes the YAML file that goes with it, which
+/// names the image file and records the [resolution][Self::resolution] and
+/// [origin][Self::origin]. It uses `trinary` mode with the standard thresholds of `0.65` and
+/// `0.196`, which read the image back exactly as it was written.
+///
+/// Each cell is one pixel, so to give corridors some room, build the maze with larger
+/// [scale factors][crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinatorBuilder::scale_factors].
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::export::ros::BoxSpaceBlockCellRosMapExporter;
+/// use mazelib::interface::export::MazeExporter;
+/// #
+/// # fn example() -> std::io::Result<()> {
+/// # let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([20, 20])).build();
+///
+/// let exporter = BoxSpaceBlockCellRosMapExporter::builder()
+///     .resolution(0.1)
+///     .origin([-2.0, -2.0, 0.0])
+///     .build();
+///
+/// exporter.export(&maze, &mut File::create("maze.pgm")?)?;
+/// exporter.export_metadata("maze.pgm", &mut File::create("maze.yaml")?)?;
+/// #
+/// # return Ok(());
+/// # }
+/// ```
+pub struct BoxSpaceBlockCellRosMapExporter {
+    /// The width and height of each cell, in metres.
+    resolution: f64,
+    /// The pose of the bottom-left pixel of the map, as x, y, and yaw.
+    origin: [f64; 3],
+}
+
+impl BoxSpaceBlockCellRosMapExporter {
+    /// Construct a new builder for a `BoxSpaceBlockCellRosMapExporter`.
+    pub fn builder() -> BoxSpaceBlockCellRosMapExporterBuilder {
+        BoxSpaceBlockCellRosMapExporterBuilder::new()
+    }
+
+    /// Construct a new instance with the default configuration.
+    ///
+    /// Optional, see [`DefaultMazeExporter`][crate::interface::export::DefaultMazeExporter].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::builder().build()
+    }
+
+    /// Return the width and height of each cell, in metres.
+    #[must_use]
+    pub fn resolution(&self) -> f64 {
+        self.resolution
+    }
+
+    /// Return the pose of the bottom-left pixel of the map in the world, as x, y, and yaw.
+    #[must_use]
+    pub fn origin(&self) -> [f64; 3] {
+        self.origin
+    }
+
+    /// Write the YAML metadata for a map written by this exporter.
+    ///
+    /// # Parameters
+    ///
+    /// * `image`  --- the path of the map image written by [`export()`][MazeExporter::export],
+    ///   relative to the YAML file.
+    /// * `output` --- where to write the metadata.
+    ///
+    /// # Errors
+    ///
+    /// If writing to `output` fails.
+    pub fn export_metadata(&self, image: &str, output: &mut impl Write) -> Result<()> {
+        writeln!(output, "image: {}", image)?;
+        writeln!(output, "mode: trinary")?;
+        writeln!(output, "resolution: {:?}", self.resolution)?;
+        writeln!(output, "origin: [{:?}, {:?}, {:?}]", self.origin[0], self.origin[1], self.origin[2])?;
+        writeln!(output, "negate: 0")?;
+        writeln!(output, "occupied_thresh: 0.65")?;
+        writeln!(output, "free_thresh: 0.196")?;
+
+        return Ok(())
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceBlockCellRosMapExporter {
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, output: &mut Output) -> Result<()> {
+        let [width, height] = nonzero_usize_array_to_usize_array(maze.get_full_dimensions());
+
+        write!(output, "P5\n{} {}\n255\n", width, height)?;
+
+        let mut row = Vec::with_capacity(width);
+
+        for y in 0..height {
+            row.clear();
+            row.extend((0..width).map(|x| match maze.get_cell_value([x, y].into()).cell_type {
+                BlockCellPrimaryValue::PASSAGE => FREE,
+                BlockCellPrimaryValue::WALL | BlockCellPrimaryValue::BOUNDARY => OCCUPIED,
+                BlockCellPrimaryValue::UNVISITED => UNKNOWN,
+            }));
+
+            output.write_all(&row)?;
+        }
+
+        return Ok(())
+    }
+}
+
+impl Default for BoxSpaceBlockCellRosMapExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for BoxSpaceBlockCellRosMapExporter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BoxSpaceBlockCellRosMapExporter(resolution = {}, origin = {:?})", self.resolution, self.origin)
+    }
+}
+
+/// A builder for a [`BoxSpaceBlockCellRosMapExporter`].
+#[must_use]
+pub struct BoxSpaceBlockCellRosMapExporterBuilder {
+    /// The width and height of each cell, in metres.
+    resolution: f64,
+    /// The pose of the bottom-left pixel of the map, as x, y, and yaw.
+    origin: [f64; 3],
+}
+
+impl BoxSpaceBlockCellRosMapExporterBuilder {
+    /// Create a new builder for a [`BoxSpaceBlockCellRosMapExporter`].
+    fn new() -> Self {
+        Self {
+            resolution: 0.05,
+            origin: [0.0, 0.0, 0.0],
+        }
+    }
+
+    /// Set the width and height of each cell, in metres. Defaults to 0.05 (5 cm).
+    ///
+    /// # Panics
+    ///
+    /// If `resolution` is not positive.
+    pub fn resolution(mut self, resolution: f64) -> Self {
+        assert!(resolution > 0.0, "The resolution must be positive");
+
+        self.resolution = resolution;
+
+        return self
+    }
+
+    /// Set the pose of the bottom-left pixel of the map in the world, as x and y in metres and yaw
+    /// in radians. Defaults to `[0, 0, 0]`.
+    pub fn origin(mut self, origin: [f64; 3]) -> Self {
+        self.origin = origin;
+
+        return self
+    }
+
+    /// Finalise the [`BoxSpaceBlockCellRosMapExporter`].
+    #[must_use]
+    pub fn build(self) -> BoxSpaceBlockCellRosMapExporter {
+        BoxSpaceBlockCellRosMapExporter {
+            resolution: self.resolution,
+            origin: self.origin,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+//! Exporting lower-dimensional slices of higher-dimensional mazes.
+//!
+//! Box mazes can have any number of dimensions, but exporters can only draw two or three. A
+//! [`BoxSliceMazeExporter`] takes a 2D or 3D slice through a maze and hands it to any exporter
+//! that can draw it, so that 4D mazes and beyond can be inspected one slice at a time.
+
+use std::io::{Result, Write};
+
+use crate::implm::point::boxy::CoordinateTuplet;
+use crate::interface::export::MazeExporter;
+use crate::util::{slice, BoxSlice};
+
+/// An exporter that exports a lower-dimensional slice of a box maze with another exporter.
+///
+/// The slice is spanned by [`axes()`][Self::axes] and passes through [`at()`][Self::at], as for
+/// [`slice()`]. Every other axis is fixed at `at`'s coordinate along it. For instance, the 3D
+/// slice of a 4D maze at `w = 2` has axes `[0, 1, 2]` and is taken at `[0, 0, 0, 2]`.
+///
+/// The slice is copied out of the maze (see [`slice()`]) and the copy is exported, so passages
+/// along the fixed axes aren't drawn.
+///
+/// # Examples
+///
+/// Draw every `z`--`w` plane of a 4D maze:
+///
+/// ```
+/// # use rand::SeedableRng;
+/// # use rand_chacha::ChaCha8Rng;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// use mazelib::implm::export::slice::BoxSliceMazeExporter;
+/// use mazelib::implm::export::text::BoxSpaceInlineCellTextMazeExporter;
+/// use mazelib::implm::generate::HuntAndKillGenerator;
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::export::MazeExporter;
+/// use mazelib::interface::generate::MazeGenerator;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<4>>, 4>::new(BoxCoordinateSpace::new_checked([4, 4, 3, 2])).build();
+/// HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(1));
+///
+/// for z in 0..3 {
+///     for w in 0..2 {
+///         let exporter = BoxSliceMazeExporter::new(BoxSpaceInlineCellTextMazeExporter::default(), [0, 1], [0, 0, z, w].into());
+///
+///         exporter.export(&maze, &mut std::io::stdout()).unwrap();
+///     }
+/// }
+/// ```
+pub struct BoxSliceMazeExporter<Exporter, const DIMENSION: usize, const SLICE_DIMENSION: usize> {
+    /// The exporter the slice is exported with.
+    exporter: Exporter,
+    /// The axes that span the slice.
+    axes: [usize; SLICE_DIMENSION],
+    /// A point the slice passes through.
+    at: CoordinateTuplet<DIMENSION>,
+}
+
+impl <Exporter, const DIMENSION: usize, const SLICE_DIMENSION: usize> BoxSliceMazeExporter<Exporter, DIMENSION, SLICE_DIMENSION> {
+    /// Construct a new instance of this exporter.
+    ///
+    /// # Parameters
+    ///
+    /// * `exporter` --- the exporter to export the slice with.
+    /// * `axes` --- the axes that span the slice, in the order the slice's axes should be in.
+    /// * `at` --- a point the slice passes through. Its coordinates along `axes` are ignored.
+    ///
+    /// # Panics
+    ///
+    /// If `axes` has any repeated axes or axes a `DIMENSION`-dimensional maze doesn't have.
+    #[must_use]
+    pub fn new(exporter: Exporter, axes: [usize; SLICE_DIMENSION], at: CoordinateTuplet<DIMENSION>) -> Self {
+        assert!(axes.iter().all(|&axis| axis < DIMENSION), "Every axis must be an axis of the maze");
+        assert!((0..SLICE_DIMENSION).all(|i| axes[..i].contains(&axes[i]) == false), "The axes must be distinct");
+
+        Self { exporter, axes, at }
+    }
+
+    /// Return the exporter the slice is exported with.
+    #[must_use]
+    pub fn exporter(&self) -> &Exporter {
+        &self.exporter
+    }
+
+    /// Return the axes that span the slice.
+    #[must_use]
+    pub fn axes(&self) -> [usize; SLICE_DIMENSION] {
+        self.axes
+    }
+
+    /// Return a point the slice passes through.
+    #[must_use]
+    pub fn at(&self) -> CoordinateTuplet<DIMENSION> {
+        self.at
+    }
+}
+
+/// # Panics
+///
+/// If the slice doesn't pass through the maze.
+impl <M: BoxSlice<DIMENSION, SLICE_DIMENSION>, Exporter: MazeExporter<M::Slice, Output>, Output: Write, const DIMENSION: usize, const SLICE_DIMENSION: usize> MazeExporter<M, Output> for BoxSliceMazeExporter<Exporter, DIMENSION, SLICE_DIMENSION> {
+    fn export(&self, maze: &M, output: &mut Output) -> Result<()> {
+        self.exporter.export(&slice(maze, self.axes, self.at), output)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,484 @@
+//! Exporting mazes as [SVG](https://developer.mozilla.org/en-US/docs/Web/SVG) vector images.
+//!
+//! * [`VoronoiSvgMazeExporter`] --- for mazes shaped like Voronoi diagrams.
+//! * [`HexSvgMazeExporter`] --- for hexagonal (sigma) mazes.
+
+use std::fmt::{Debug, Formatter};
+use std::io::{Result, Write};
+
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::cell::hex::HexCellValue;
+use crate::implm::coordinate::graph::GraphSpaceGraphCellMazeCoordinator;
+use crate::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+use crate::implm::export::hex::{hex_walls, HexOrientation};
+use crate::implm::export::metadata::{format_timestamp, MazeMetadata};
+use crate::implm::point::graph::{GraphPoint, VoronoiDiagram};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+
+/// An exporter that draws mazes shaped like [Voronoi diagrams][VoronoiDiagram] as SVG images.
+///
+/// Every cell edge that isn't a passage is drawn as a line, including the outside of the
+/// rectangle. The walls are drawn as a single `<path>`, so the image is easy to restyle.
+///
+/// The exporter needs the diagram the maze was built from, as the maze itself only knows the
+/// adjacency of the cells and not their shapes.
+///
+/// [Metadata][VoronoiSvgMazeExporterBuilder::metadata] describing the maze can be embedded in the
+/// image's `<metadata>` element.
+///
+/// # Examples
+///
+/// ```
+/// # use rand::thread_rng;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// use mazelib::implm::coordinate::graph::GraphSpaceGraphCellMazeCoordinator;
+/// use mazelib::implm::export::svg::VoronoiSvgMazeExporter;
+/// use mazelib::implm::generate::RecursiveBacktrackerGenerator;
+/// use mazelib::implm::point::graph::VoronoiDiagram;
+/// use mazelib::interface::export::MazeExporter;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// let diagram = VoronoiDiagram::random_checked([40.0, 30.0], 200, &mut thread_rng());
+///
+/// let mut maze = GraphSpaceGraphCellMazeCoordinator::<VecBuffer<BlockCellValue>>::builder(diagram.space()).build();
+///
+/// RecursiveBacktrackerGenerator::generate(&mut maze);
+///
+/// let mut svg = Vec::new();
+/// VoronoiSvgMazeExporter::builder(&diagram).scale(20.0).build().export(&maze, &mut svg).unwrap();
+///
+/// assert!(svg.starts_with(b"<svg"));
+/// ```
+pub struct VoronoiSvgMazeExporter<'diagram> {
+    /// The diagram that the mazes are shaped like.
+    diagram: &'diagram VoronoiDiagram,
+    /// The number of SVG units per unit of the diagram.
+    scale: f64,
+    /// The width of the walls, in SVG units.
+    stroke_width: f64,
+    /// The metadata to embed in the image.
+    metadata: MazeMetadata,
+}
+
+impl <'diagram> VoronoiSvgMazeExporter<'diagram> {
+    /// Construct a new builder for a `VoronoiSvgMazeExporter`.
+    ///
+    /// # Parameters
+    ///
+    /// `diagram` --- the diagram that the mazes to be exported are shaped like.
+    pub fn builder(diagram: &'diagram VoronoiDiagram) -> VoronoiSvgMazeExporterBuilder<'diagram> {
+        VoronoiSvgMazeExporterBuilder::new(diagram)
+    }
+
+    /// Return the diagram that the mazes are shaped like.
+    #[must_use]
+    pub fn diagram(&self) -> &'diagram VoronoiDiagram {
+        self.diagram
+    }
+
+    /// Return the number of SVG units per unit of the diagram.
+    #[must_use]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Return the width of the walls, in SVG units.
+    #[must_use]
+    pub fn stroke_width(&self) -> f64 {
+        self.stroke_width
+    }
+
+    /// Return the metadata embedded in the image.
+    #[must_use]
+    pub fn metadata(&self) -> &MazeMetadata {
+        &self.metadata
+    }
+
+    /// Return whether the edge between `site` and `other` should be drawn.
+    fn is_wall<Buffer: MazeBuffer<BlockCellValue>>(&self, maze: &GraphSpaceGraphCellMazeCoordinator<'_, Buffer>, site: usize, other: Option<usize>) -> bool {
+        match other {
+            None => true,
+            Some(other) => {
+                if maze.coord_space().graph().edge_between(site, other).is_none() {
+                    return true
+                }
+
+                // Both sides of the edge share it, so only draw it once
+                site < other && maze.get_connection(GraphPoint(site), GraphPoint(other)) != ConnectionType::PASSAGE
+            }
+        }
+    }
+
+    /// Convert a position in the diagram to SVG coordinates. The y axis is flipped so that the
+    /// diagram's y axis points up.
+    fn to_svg(&self, [x, y]: [f64; 2]) -> [f64; 2] {
+        [x * self.scale, (self.diagram.size()[1] - y) * self.scale]
+    }
+}
+
+impl Debug for VoronoiSvgMazeExporter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VoronoiSvgMazeExporter({:?}, scale = {}, stroke_width = {}, metadata = {:?})", self.diagram, self.scale, self.stroke_width, self.metadata)
+    }
+}
+
+/// A builder for a [`VoronoiSvgMazeExporter`].
+#[must_use]
+pub struct VoronoiSvgMazeExporterBuilder<'diagram> {
+    /// The diagram that the mazes are shaped like.
+    diagram: &'diagram VoronoiDiagram,
+    /// The number of SVG units per unit of the diagram.
+    scale: f64,
+    /// The width of the walls, in SVG units.
+    stroke_width: f64,
+    /// The metadata to embed in the image.
+    metadata: MazeMetadata,
+}
+
+impl <'diagram> VoronoiSvgMazeExporterBuilder<'diagram> {
+    /// Create a new builder for a [`VoronoiSvgMazeExporter`].
+    fn new(diagram: &'diagram VoronoiDiagram) -> Self {
+        Self {
+            diagram,
+            scale: 10.0,
+            stroke_width: 1.0,
+            metadata: MazeMetadata::default(),
+        }
+    }
+
+    /// Set the number of SVG units per unit of the diagram. Defaults to 10.
+    ///
+    /// # Panics
+    ///
+    /// If `scale` is not positive.
+    pub fn scale(mut self, scale: f64) -> Self {
+        assert!(scale > 0.0, "The scale must be positive");
+
+        self.scale = scale;
+
+        return self
+    }
+
+    /// Set the width of the walls, in SVG units. Defaults to 1.
+    ///
+    /// # Panics
+    ///
+    /// If `stroke_width` is not positive.
+    pub fn stroke_width(mut self, stroke_width: f64) -> Self {
+        assert!(stroke_width > 0.0, "The stroke width must be positive");
+
+        self.stroke_width = stroke_width;
+
+        return self
+    }
+
+    /// Set the metadata to embed in the image's `<metadata>` element, as
+    /// [Dublin Core](https://www.dublincore.org/specifications/dublin-core/dces/) properties.
+    /// The title is also used as the image's `<title>`. Defaults to no metadata.
+    ///
+    /// *See [`MazeMetadata`].*
+    pub fn metadata(mut self, metadata: MazeMetadata) -> Self {
+        self.metadata = metadata;
+
+        return self
+    }
+
+    /// Finalise the [`VoronoiSvgMazeExporter`].
+    #[must_use]
+    pub fn build(self) -> VoronoiSvgMazeExporter<'diagram> {
+        VoronoiSvgMazeExporter {
+            diagram: self.diagram,
+            scale: self.scale,
+            stroke_width: self.stroke_width,
+            metadata: self.metadata,
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<GraphSpaceGraphCellMazeCoordinator<'_, Buffer>, Output> for VoronoiSvgMazeExporter<'_> {
+    /// # Panics
+    ///
+    /// If the maze was not built from this exporter's diagram.
+    fn export(&self, maze: &GraphSpaceGraphCellMazeCoordinator<'_, Buffer>, output: &mut Output) -> Result<()> {
+        assert!(std::ptr::eq(maze.coord_space().graph(), self.diagram.graph()), "The maze was not built from this exporter's diagram");
+
+        let [width, height] = self.diagram.size().map(|length| length * self.scale);
+        let margin = self.stroke_width;
+
+        writeln!(output, r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}">"#, -margin, -margin, width + 2.0 * margin, height + 2.0 * margin, width + 2.0 * margin, height + 2.0 * margin)?;
+        write_metadata(&self.metadata, output)?;
+
+        write!(output, r#"<path fill="none" stroke="black" stroke-width="{}" stroke-linecap="round" d=""#, self.stroke_width)?;
+
+        let mut first = true;
+
+        for site in 0..self.diagram.sites().len() {
+            for (start, end, other) in self.diagram.cell_edges(site) {
+                if self.is_wall(maze, site, other) {
+                    let [[x1, y1], [x2, y2]] = [start, end].map(|vertex| self.to_svg(vertex));
+
+                    if first == false {
+                        write!(output, " ")?;
+                    }
+
+                    write!(output, "M{:.3} {:.3}L{:.3} {:.3}", x1, y1, x2, y2)?;
+
+                    first = false;
+                }
+            }
+        }
+
+        writeln!(output, r#""/>"#)?;
+        writeln!(output, "</svg>")?;
+
+        return Ok(())
+    }
+}
+
+/// An exporter that draws [hexagonal mazes][HexSpaceHexCellMazeCoordinator] as SVG images.
+///
+/// Every hexagon edge that isn't a passage is drawn as a line, including the outside of the maze.
+/// The walls are drawn as a single `<path>`, so the image is easy to restyle. Hexagons can be
+/// drawn [flat- or pointy-topped][HexOrientation].
+///
+/// [Metadata][HexSvgMazeExporterBuilder::metadata] describing the maze can be embedded in the
+/// image's `<metadata>` element.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::hex::HexCellValue;
+/// # use mazelib::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+/// # use mazelib::implm::generate::HuntAndKillGenerator;
+/// # use mazelib::implm::point::hex::HexCoordinateSpace;
+/// # use mazelib::interface::export::MazeExporter;
+/// # use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::implm::export::hex::HexOrientation;
+/// use mazelib::implm::export::svg::HexSvgMazeExporter;
+///
+/// let mut maze = HexSpaceHexCellMazeCoordinator::<VecBuffer<HexCellValue>>::builder(HexCoordinateSpace::new_checked(8, 6)).build();
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// let mut svg = Vec::new();
+/// HexSvgMazeExporter::builder().orientation(HexOrientation::PointyTop).scale(20.0).build().export(&maze, &mut svg).unwrap();
+///
+/// assert!(svg.starts_with(b"<svg"));
+/// ```
+pub struct HexSvgMazeExporter {
+    /// Which way up the hexagons are drawn.
+    orientation: HexOrientation,
+    /// The distance from the centre of each hexagon to its corners, in SVG units.
+    scale: f64,
+    /// The width of the walls, in SVG units.
+    stroke_width: f64,
+    /// The metadata to embed in the image.
+    metadata: MazeMetadata,
+}
+
+impl HexSvgMazeExporter {
+    /// Construct a new builder for a `HexSvgMazeExporter`.
+    pub fn builder() -> HexSvgMazeExporterBuilder {
+        HexSvgMazeExporterBuilder::new()
+    }
+
+    /// Return which way up the hexagons are drawn.
+    #[must_use]
+    pub fn orientation(&self) -> HexOrientation {
+        self.orientation
+    }
+
+    /// Return the distance from the centre of each hexagon to its corners, in SVG units.
+    #[must_use]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Return the width of the walls, in SVG units.
+    #[must_use]
+    pub fn stroke_width(&self) -> f64 {
+        self.stroke_width
+    }
+
+    /// Return the metadata embedded in the image.
+    #[must_use]
+    pub fn metadata(&self) -> &MazeMetadata {
+        &self.metadata
+    }
+}
+
+impl Default for HexSvgMazeExporter {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl Debug for HexSvgMazeExporter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HexSvgMazeExporter({:?}, scale = {}, stroke_width = {}, metadata = {:?})", self.orientation, self.scale, self.stroke_width, self.metadata)
+    }
+}
+
+/// A builder for a [`HexSvgMazeExporter`].
+#[must_use]
+pub struct HexSvgMazeExporterBuilder {
+    /// Which way up the hexagons are drawn.
+    orientation: HexOrientation,
+    /// The distance from the centre of each hexagon to its corners, in SVG units.
+    scale: f64,
+    /// The width of the walls, in SVG units.
+    stroke_width: f64,
+    /// The metadata to embed in the image.
+    metadata: MazeMetadata,
+}
+
+impl HexSvgMazeExporterBuilder {
+    /// Create a new builder for a [`HexSvgMazeExporter`].
+    fn new() -> Self {
+        Self {
+            orientation: HexOrientation::default(),
+            scale: 10.0,
+            stroke_width: 1.0,
+            metadata: MazeMetadata::default(),
+        }
+    }
+
+    /// Set which way up the hexagons are drawn. Defaults to [`HexOrientation::FlatTop`].
+    pub fn orientation(mut self, orientation: HexOrientation) -> Self {
+        self.orientation = orientation;
+
+        return self
+    }
+
+    /// Set the distance from the centre of each hexagon to its corners, in SVG units. Defaults to
+    /// 10.
+    ///
+    /// # Panics
+    ///
+    /// If `scale` is not positive.
+    pub fn scale(mut self, scale: f64) -> Self {
+        assert!(scale > 0.0, "The scale must be positive");
+
+        self.scale = scale;
+
+        return self
+    }
+
+    /// Set the width of the walls, in SVG units. Defaults to 1.
+    ///
+    /// # Panics
+    ///
+    /// If `stroke_width` is not positive.
+    pub fn stroke_width(mut self, stroke_width: f64) -> Self {
+        assert!(stroke_width > 0.0, "The stroke width must be positive");
+
+        self.stroke_width = stroke_width;
+
+        return self
+    }
+
+    /// Set the metadata to embed in the image's `<metadata>` element, as
+    /// [Dublin Core](https://www.dublincore.org/specifications/dublin-core/dces/) properties.
+    /// The title is also used as the image's `<title>`. Defaults to no metadata.
+    ///
+    /// *See [`MazeMetadata`].*
+    pub fn metadata(mut self, metadata: MazeMetadata) -> Self {
+        self.metadata = metadata;
+
+        return self
+    }
+
+    /// Finalise the [`HexSvgMazeExporter`].
+    #[must_use]
+    pub fn build(self) -> HexSvgMazeExporter {
+        HexSvgMazeExporter {
+            orientation: self.orientation,
+            scale: self.scale,
+            stroke_width: self.stroke_width,
+            metadata: self.metadata,
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<HexCellValue>, Output: Write> MazeExporter<HexSpaceHexCellMazeCoordinator<Buffer>, Output> for HexSvgMazeExporter {
+    fn export(&self, maze: &HexSpaceHexCellMazeCoordinator<Buffer>, output: &mut Output) -> Result<()> {
+        let ([width, height], walls) = hex_walls(maze, self.orientation, self.scale);
+        let margin = self.stroke_width;
+
+        writeln!(output, r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}">"#, -margin, -margin, width + 2.0 * margin, height + 2.0 * margin, width + 2.0 * margin, height + 2.0 * margin)?;
+        write_metadata(&self.metadata, output)?;
+
+        write!(output, r#"<path fill="none" stroke="black" stroke-width="{}" stroke-linecap="round" d=""#, self.stroke_width)?;
+
+        for (i, [[x1, y1], [x2, y2]]) in walls.into_iter().enumerate() {
+            if i > 0 {
+                write!(output, " ")?;
+            }
+
+            write!(output, "M{:.3} {:.3}L{:.3} {:.3}", x1, y1, x2, y2)?;
+        }
+
+        writeln!(output, r#""/>"#)?;
+        writeln!(output, "</svg>")?;
+
+        return Ok(())
+    }
+}
+
+/// Write the `<title>` and `<metadata>` elements, if there is any metadata.
+fn write_metadata(metadata: &MazeMetadata, output: &mut impl Write) -> Result<()> {
+    if metadata.is_empty() {
+        return Ok(())
+    }
+
+    if let Some(title) = &metadata.title {
+        writeln!(output, "<title>{}</title>", escape_xml(title))?;
+    }
+
+    writeln!(output, "<metadata>")?;
+    writeln!(output, r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:mazelib="https://github.com/Alphaesia/mazelib#">"#)?;
+    writeln!(output, "<rdf:Description>")?;
+
+    let fields = [
+        ("dc:title", metadata.title.clone()),
+        ("dc:creator", metadata.author.clone()),
+        ("dc:date", metadata.timestamp.map(format_timestamp)),
+        ("mazelib:seed", metadata.seed.map(|seed| seed.to_string())),
+        ("mazelib:algorithm", metadata.algorithm.clone()),
+    ];
+
+    for (element, value) in fields {
+        if let Some(value) = value {
+            writeln!(output, "<{}>{}</{}>", element, escape_xml(&value), element)?;
+        }
+    }
+
+    writeln!(output, "</rdf:Description>")?;
+    writeln!(output, "</rdf:RDF>")?;
+    writeln!(output, "</metadata>")?;
+
+    return Ok(())
+}
+
+/// Escape the characters in `text` that have special meaning in XML.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            '&'  => escaped.push_str("&amp;"),
+            '<'  => escaped.push_str("&lt;"),
+            '>'  => escaped.push_str("&gt;"),
+            '"'  => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            char => escaped.push(char),
+        }
+    }
+
+    return escaped
+}
\ No newline at end of file
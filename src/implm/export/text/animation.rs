@@ -0,0 +1,232 @@
+#![cfg(any(feature = "animate", doc))]
+
+use std::fmt::{Debug, Formatter};
+use std::io::{Result, Write};
+use std::time::Duration;
+
+use crate::implm::buffer::{CellObserver, ObservedBuffer};
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+use crate::internal::util::nonzero_usize_array_to_usize_array;
+
+/// Draws a 2D [`BoxSpaceBlockCellMazeCoordinator`] in a terminal, and redraws it in place every
+/// time one of its cells changes.
+///
+/// Attach it to a maze whose buffer is an [`ObservedBuffer`] and then generate, solve, or edit the
+/// maze as usual. Every change the maze's buffer sees is drawn as it happens, followed by a pause
+/// of the [frame delay][TerminalAnimationBuilder::frame_delay], so any generator becomes a live
+/// demonstration of how it works. [Marking][crate::interface::cell::CellValue::set_marked] cells
+/// is a change too, so solvers and analyses that mark their progress are animated as well.
+///
+/// The maze is drawn the first time one of its cells changes, starting on the line the terminal's
+/// cursor is on. Cells are redrawn by moving the cursor with
+/// [ANSI escape codes](https://en.wikipedia.org/wiki/ANSI_escape_code#CSI_(Control_Sequence_Introducer)_sequences),
+/// which every common terminal (including Windows Terminal) understands. Once the maze has been
+/// drawn the cursor is always left on the line below it, so anything printed afterwards appears
+/// underneath. The maze must fit in the terminal, or the lines that scroll off the top can't be
+/// redrawn.
+///
+/// Cells are drawn with the characters of a [`BoxSpaceBlockCellTextMazeExporter`], so the two
+/// look the same. The exporter's start, goal, and annotations aren't drawn, as the buffer doesn't
+/// know about them, and neither are its line break and maximum width.
+///
+/// Errors writing to the terminal are ignored, as there's no way to report them in the middle of
+/// generating a maze.
+///
+/// Requires the `animate` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use mazelib::implm::buffer::ObservedBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::generate::HuntAndKillGenerator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::implm::export::text::TerminalAnimation;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<ObservedBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([20, 10])).build();
+///
+/// TerminalAnimation::builder().frame_delay(Duration::from_millis(5)).attach(&mut maze);
+///
+/// HuntAndKillGenerator::generate(&mut maze);
+/// ```
+pub struct TerminalAnimation {
+    /// Where the maze is drawn.
+    output: Box<dyn Write + Send>,
+    /// How long to pause after drawing each change.
+    frame_delay: Duration,
+    /// The exporter whose characters are used to draw the cells.
+    exporter: BoxSpaceBlockCellTextMazeExporter,
+    /// The number of cells along each axis of the maze, padding included.
+    full_dimensions: [usize; 2],
+    /// The value of every cell as last drawn.
+    cells: Vec<BlockCellValue>,
+    /// Whether the whole maze has been drawn yet.
+    drawn: bool,
+}
+
+impl TerminalAnimation {
+    /// Construct a new builder for a `TerminalAnimation`.
+    pub fn builder() -> TerminalAnimationBuilder {
+        TerminalAnimationBuilder::new()
+    }
+
+    /// Return how long the animation pauses after drawing each change.
+    #[must_use]
+    pub fn frame_delay(&self) -> Duration {
+        self.frame_delay
+    }
+
+    /// Return the exporter whose characters are used to draw the cells.
+    #[must_use]
+    pub fn exporter(&self) -> &BoxSpaceBlockCellTextMazeExporter {
+        &self.exporter
+    }
+
+    /// Draw the whole maze, leaving the cursor on the line below it.
+    fn draw_all(&mut self) -> Result<()> {
+        let [width, height] = self.full_dimensions;
+
+        let mut frame = String::new();
+
+        for y in 0..height {
+            let mut line = String::new();
+
+            for value in &self.cells[y * width..(y + 1) * width] {
+                let char = self.exporter.cell_char(*value);
+
+                line.extend(std::iter::repeat(char).take(usize::from(self.exporter.chars_per_cell_horizontally())));
+            }
+
+            for _ in 0..usize::from(self.exporter.chars_per_cell_vertically()) {
+                frame.push_str(&line);
+                frame.push('\n');
+            }
+        }
+
+        self.output.write_all(frame.as_bytes())
+    }
+
+    /// Redraw `cell`, returning the cursor to the line below the maze.
+    fn draw_cell(&mut self, cell: CellID) -> Result<()> {
+        let [width, height] = self.full_dimensions;
+        let [chars_horizontally, chars_vertically] = [self.exporter.chars_per_cell_horizontally(), self.exporter.chars_per_cell_vertically()].map(usize::from);
+
+        let [x, y] = [cell.0 % width, cell.0 / width];
+
+        let chars: String = std::iter::repeat(self.exporter.cell_char(self.cells[cell.0])).take(chars_horizontally).collect();
+
+        for line in y * chars_vertically..(y + 1) * chars_vertically {
+            let lines_up = height * chars_vertically - line;
+
+            // Up to the line, along to the column, and back down again
+            write!(self.output, "\x1b[{}A\r", lines_up)?;
+
+            if x > 0 {
+                write!(self.output, "\x1b[{}C", x * chars_horizontally)?;
+            }
+
+            write!(self.output, "{}\x1b[{}B\r", chars, lines_up)?;
+        }
+
+        return Ok(())
+    }
+}
+
+impl CellObserver<BlockCellValue> for TerminalAnimation {
+    fn on_cell_changed(&mut self, cell: CellID, _: BlockCellValue, new: BlockCellValue) {
+        self.cells[cell.0] = new;
+
+        let result = if self.drawn { self.draw_cell(cell) } else { self.draw_all() };
+
+        self.drawn = true;
+
+        let _ = result.and_then(|()| self.output.flush());
+
+        if self.frame_delay.is_zero() == false {
+            std::thread::sleep(self.frame_delay);
+        }
+    }
+}
+
+impl Debug for TerminalAnimation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TerminalAnimation({}x{}, frame_delay = {:?})", self.full_dimensions[0], self.full_dimensions[1], self.frame_delay)
+    }
+}
+
+/// A builder for a [`TerminalAnimation`].
+#[must_use]
+pub struct TerminalAnimationBuilder {
+    /// Where the maze is drawn.
+    output: Box<dyn Write + Send>,
+    /// How long to pause after drawing each change.
+    frame_delay: Duration,
+    /// The exporter whose characters are used to draw the cells.
+    exporter: BoxSpaceBlockCellTextMazeExporter,
+}
+
+impl TerminalAnimationBuilder {
+    /// Create a new builder for a [`TerminalAnimation`].
+    fn new() -> Self {
+        Self {
+            output: Box::new(std::io::stdout()),
+            frame_delay: Duration::from_millis(10),
+            exporter: BoxSpaceBlockCellTextMazeExporter::default(),
+        }
+    }
+
+    /// Set where the maze is drawn. Defaults to [standard output][std::io::stdout].
+    pub fn output(mut self, output: impl Write + Send + 'static) -> Self {
+        self.output = Box::new(output);
+
+        return self
+    }
+
+    /// Set how long to pause after drawing each change. Defaults to 10 milliseconds.
+    pub fn frame_delay(mut self, frame_delay: Duration) -> Self {
+        self.frame_delay = frame_delay;
+
+        return self
+    }
+
+    /// Set the exporter whose characters are used to draw the cells. Defaults to
+    /// [the default exporter][BoxSpaceBlockCellTextMazeExporter::default].
+    pub fn exporter(mut self, exporter: BoxSpaceBlockCellTextMazeExporter) -> Self {
+        self.exporter = exporter;
+
+        return self
+    }
+
+    /// Finalise the [`TerminalAnimation`] for `maze`, without attaching it.
+    ///
+    /// Use this to attach the animation yourself, e.g. to combine it with another observer.
+    /// Otherwise use [`attach()`][Self::attach].
+    #[must_use]
+    pub fn build<Buffer: MazeBuffer<BlockCellValue>>(self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>) -> TerminalAnimation {
+        let full_dimensions = nonzero_usize_array_to_usize_array(maze.get_full_dimensions());
+
+        TerminalAnimation {
+            output: self.output,
+            frame_delay: self.frame_delay,
+            exporter: self.exporter,
+            full_dimensions,
+            cells: (0..full_dimensions[0] * full_dimensions[1]).map(|cell| maze.buffer().get(CellID(cell))).collect(),
+            drawn: false,
+        }
+    }
+
+    /// Finalise the [`TerminalAnimation`] and attach it to `maze`, replacing its buffer's
+    /// observer. Returns whether there was one already.
+    pub fn attach<Buffer: MazeBuffer<BlockCellValue>>(self, maze: &mut BoxSpaceBlockCellMazeCoordinator<ObservedBuffer<BlockCellValue, Buffer>, 2>) -> bool {
+        let animation = self.build(maze);
+
+        maze.buffer_mut().set_observer(animation)
+    }
+}
\ No newline at end of file
@@ -1,17 +1,38 @@
 use std::cmp::max;
-use std::io::{Result, Write};
+use std::collections::HashMap;
+use std::io::{BufWriter, Result, Write};
 use std::num::NonZeroUsize;
 
-use crate::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue};
+use crate::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue, VerticalConnector};
 use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
 use crate::implm::export::text::TextMazeExporter;
+use crate::implm::export::text::layout::{LayoutWriter, LineBreak, Overflow};
 use crate::interface::buffer::MazeBuffer;
 use crate::interface::cell::CellID;
+use crate::interface::coordinate::MazeCoordinator;
 use crate::interface::export::MazeExporter;
 use crate::internal::line_break::WriteLineBreak;
 use crate::internal::util::{nonzero_usize_array_to_usize_array, NONZERO_USIZE_ONE, NONZERO_USIZE_TWO};
 
 /// An exporter that renders [`BoxSpaceBlockCellMazeCoordinator`]s to text.
+///
+/// Three-dimensional mazes are drawn one layer of cells at a time, from the lowest to the highest
+/// along the third axis, with a blank line between each. Passage cells with
+/// [vertical connectors][crate::implm::cell::block::VerticalConnector] are drawn as stairs, which
+/// by default are `▲` for up, `▼` for down, and `◆` for both.
+///
+/// The maze's [start][crate::interface::coordinate::MazeCoordinator::start] and
+/// [goal][crate::interface::coordinate::MazeCoordinator::goal], if it has them, are drawn as `S`
+/// and `G` respectively. [Annotations][crate::interface::coordinate::MazeCoordinator::annotations]
+/// with a [glyph][crate::mark::Annotation::glyph] are drawn as it, unless
+/// [turned off][BoxSpaceBlockCellTextMazeExporterBuilder::draw_annotations].
+///
+/// The characters for each type of cell can be set individually, or all at once with a
+/// [theme][BoxSpaceBlockCellTextMazeExporterBuilder::theme].
+///
+/// [Marked][crate::interface::cell::CellValue::is_marked] passages can be drawn with a
+/// [distinct character][BoxSpaceBlockCellTextMazeExporterBuilder::marked_char], which makes the
+/// markings left by solvers and analyses visible.
 pub struct BoxSpaceBlockCellTextMazeExporter {
     /// The character to emit for passage cells.
     passage_char:   char,
@@ -21,11 +42,33 @@ pub struct BoxSpaceBlockCellTextMazeExporter {
     boundary_char:  char,
     /// The character to emit for unvisited cells.
     unvisited_char: char,
+
+    /// The character to emit for passage cells with stairs going up.
+    stairs_up_char:          char,
+    /// The character to emit for passage cells with stairs going down.
+    stairs_down_char:        char,
+    /// The character to emit for passage cells with stairs going both up and down.
+    stairs_up_and_down_char: char,
+
+    /// The character to emit for marked passage cells, if they are drawn differently.
+    marked_char: Option<char>,
+
+    /// The character to emit for the maze's start point.
+    start_char: char,
+    /// The character to emit for the maze's goal point.
+    goal_char:  char,
+    /// Whether to draw the glyphs of the maze's annotations.
+    draw_annotations: bool,
     
     /// The number of the characters to emit horizontally per cell.
     chars_per_cell_horizontally: NonZeroUsize,
     /// The number of the characters to emit vertically per cell.
     chars_per_cell_vertically:   NonZeroUsize,
+
+    /// The characters to end each line with.
+    line_break: LineBreak,
+    /// The maximum width of a line in characters, and what to do with lines longer than it.
+    max_width: Option<(usize, Overflow)>,
 }
 
 impl BoxSpaceBlockCellTextMazeExporter {
@@ -60,6 +103,49 @@ impl BoxSpaceBlockCellTextMazeExporter {
         self.unvisited_char
     }
 
+    /// Return the character that is emitted for passage cells with stairs going up.
+    #[must_use]
+    pub fn stairs_up_char(&self) -> char {
+        self.stairs_up_char
+    }
+
+    /// Return the character that is emitted for passage cells with stairs going down.
+    #[must_use]
+    pub fn stairs_down_char(&self) -> char {
+        self.stairs_down_char
+    }
+
+    /// Return the character that is emitted for passage cells with stairs going both up and down.
+    #[must_use]
+    pub fn stairs_up_and_down_char(&self) -> char {
+        self.stairs_up_and_down_char
+    }
+
+    /// Return the character that is emitted for [marked][crate::interface::cell::CellValue::is_marked]
+    /// passage cells, or `None` if they are drawn like any other passage.
+    #[must_use]
+    pub fn marked_char(&self) -> Option<char> {
+        self.marked_char
+    }
+
+    /// Return the character that is emitted for the maze's start point.
+    #[must_use]
+    pub fn start_char(&self) -> char {
+        self.start_char
+    }
+
+    /// Return the character that is emitted for the maze's goal point.
+    #[must_use]
+    pub fn goal_char(&self) -> char {
+        self.goal_char
+    }
+
+    /// Return whether the glyphs of the maze's annotations are drawn.
+    #[must_use]
+    pub fn draw_annotations(&self) -> bool {
+        self.draw_annotations
+    }
+
     /// Return the number of the characters that is emitted horizontally per cell.
     #[must_use]
     pub fn chars_per_cell_horizontally(&self) -> NonZeroUsize {
@@ -71,6 +157,19 @@ impl BoxSpaceBlockCellTextMazeExporter {
     pub fn chars_per_cell_vertically(&self) -> NonZeroUsize {
         self.chars_per_cell_vertically
     }
+
+    /// Return the characters that each line is ended with.
+    #[must_use]
+    pub fn line_break(&self) -> LineBreak {
+        self.line_break
+    }
+
+    /// Return the maximum width of a line in characters, and what is done with lines longer than
+    /// it, if there is a maximum.
+    #[must_use]
+    pub fn max_width(&self) -> Option<(usize, Overflow)> {
+        self.max_width
+    }
 }
 
 impl Default for BoxSpaceBlockCellTextMazeExporter {
@@ -91,10 +190,32 @@ pub struct BoxSpaceBlockCellTextMazeExporterBuilder {
     /// The character to emit for unvisited cells.
     unvisited_char: char,
 
+    /// The character to emit for passage cells with stairs going up.
+    stairs_up_char:          char,
+    /// The character to emit for passage cells with stairs going down.
+    stairs_down_char:        char,
+    /// The character to emit for passage cells with stairs going both up and down.
+    stairs_up_and_down_char: char,
+
+    /// The character to emit for marked passage cells, if they are drawn differently.
+    marked_char: Option<char>,
+
+    /// The character to emit for the maze's start point.
+    start_char: char,
+    /// The character to emit for the maze's goal point.
+    goal_char:  char,
+    /// Whether to draw the glyphs of the maze's annotations.
+    draw_annotations: bool,
+
     /// The number of the characters to emit horizontally per cell.
     chars_per_cell_horizontally: NonZeroUsize,
     /// The number of the characters to emit vertically per cell.
     chars_per_cell_vertically:   NonZeroUsize,
+
+    /// The characters to end each line with.
+    line_break: LineBreak,
+    /// The maximum width of a line in characters, and what to do with lines longer than it.
+    max_width: Option<(usize, Overflow)>,
 }
 
 impl BoxSpaceBlockCellTextMazeExporterBuilder {
@@ -105,12 +226,50 @@ impl BoxSpaceBlockCellTextMazeExporterBuilder {
             wall_char:      '█',
             boundary_char:  '█',
             unvisited_char: '.',
+
+            stairs_up_char:          '▲',
+            stairs_down_char:        '▼',
+            stairs_up_and_down_char: '◆',
+
+            marked_char: None,
+
+            start_char: 'S',
+            goal_char:  'G',
+            draw_annotations: true,
             
             chars_per_cell_horizontally: NONZERO_USIZE_TWO,
             chars_per_cell_vertically:   NONZERO_USIZE_ONE,
+
+            line_break: LineBreak::Native,
+            max_width:  None,
         }
     }
 
+    /// Set the characters to emit for passage, wall, boundary, and unvisited cells, and for
+    /// stairs, to those of `theme`.
+    ///
+    /// Any of them can be customised afterwards with their individual setters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::export::text::{BlockCellTextTheme, BoxSpaceBlockCellTextMazeExporter};
+    /// #
+    /// let exporter = BoxSpaceBlockCellTextMazeExporter::builder()
+    ///     .theme(BlockCellTextTheme::Ascii)
+    ///     .boundary_char('@')
+    ///     .build();
+    ///
+    /// assert_eq!('#', exporter.wall_char());
+    /// assert_eq!('@', exporter.boundary_char());
+    /// ```
+    pub fn theme(mut self, theme: BlockCellTextTheme) -> Self {
+        [self.passage_char, self.wall_char, self.boundary_char, self.unvisited_char] = theme.cell_chars();
+        [self.stairs_up_char, self.stairs_down_char, self.stairs_up_and_down_char] = theme.stairs_chars();
+
+        return self
+    }
+
     /// Set the character to emit for passage cells.
     pub fn passage_char(mut self, char: char) -> Self {
         self.passage_char = char;
@@ -139,6 +298,60 @@ impl BoxSpaceBlockCellTextMazeExporterBuilder {
         return self
     }
 
+    /// Set the character to emit for passage cells with stairs going up.
+    pub fn stairs_up_char(mut self, char: char) -> Self {
+        self.stairs_up_char = char;
+
+        return self
+    }
+
+    /// Set the character to emit for passage cells with stairs going down.
+    pub fn stairs_down_char(mut self, char: char) -> Self {
+        self.stairs_down_char = char;
+
+        return self
+    }
+
+    /// Set the character to emit for passage cells with stairs going both up and down.
+    pub fn stairs_up_and_down_char(mut self, char: char) -> Self {
+        self.stairs_up_and_down_char = char;
+
+        return self
+    }
+
+    /// Set the character to emit for [marked][crate::interface::cell::CellValue::is_marked]
+    /// passage cells, such as those on a path found by a solver. By default marked passages are
+    /// drawn like any other passage.
+    ///
+    /// Stairs are drawn as this too if they are marked.
+    pub fn marked_char(mut self, char: char) -> Self {
+        self.marked_char = Some(char);
+
+        return self
+    }
+
+    /// Set the character to emit for the maze's start point.
+    pub fn start_char(mut self, char: char) -> Self {
+        self.start_char = char;
+
+        return self
+    }
+
+    /// Set the character to emit for the maze's goal point.
+    pub fn goal_char(mut self, char: char) -> Self {
+        self.goal_char = char;
+
+        return self
+    }
+
+    /// Set whether to draw the [glyphs][crate::mark::Annotation::glyph] of the maze's
+    /// annotations. Defaults to `true`.
+    pub fn draw_annotations(mut self, draw: bool) -> Self {
+        self.draw_annotations = draw;
+
+        return self
+    }
+
     /// Set the number of the characters to emit horizontally per cell.
     /// 
     /// # See Also
@@ -193,6 +406,28 @@ impl BoxSpaceBlockCellTextMazeExporterBuilder {
         self.chars_per_cell_vertically(NonZeroUsize::new(count).expect("count was zero"))
     }
 
+    /// Set the characters to end each line with. Defaults to [`LineBreak::Native`].
+    pub fn line_break(mut self, line_break: LineBreak) -> Self {
+        self.line_break = line_break;
+
+        return self
+    }
+
+    /// Limit lines to `width` characters, [wrapping or truncating][Overflow] any that are longer.
+    /// By default lines are as long as the maze is wide.
+    ///
+    /// `width` must be at least 2, to leave room for the overflow indicator.
+    ///
+    /// # Panics
+    ///
+    /// If `width` is less than 2.
+    pub fn max_width(mut self, width: usize, overflow: Overflow) -> Self {
+        assert!(width >= 2, "The maximum width must be at least 2");
+
+        self.max_width = Some((width, overflow));
+
+        return self
+    }
 
     /// Finalise the [`BoxSpaceBlockCellTextMazeExporter`].
     #[must_use]
@@ -203,15 +438,115 @@ impl BoxSpaceBlockCellTextMazeExporterBuilder {
             boundary_char:  self.boundary_char,
             unvisited_char: self.unvisited_char,
 
+            stairs_up_char:          self.stairs_up_char,
+            stairs_down_char:        self.stairs_down_char,
+            stairs_up_and_down_char: self.stairs_up_and_down_char,
+
+            marked_char: self.marked_char,
+
+            start_char: self.start_char,
+            goal_char:  self.goal_char,
+            draw_annotations: self.draw_annotations,
+
             chars_per_cell_horizontally: self.chars_per_cell_horizontally,
             chars_per_cell_vertically:   self.chars_per_cell_vertically,
+
+            line_break: self.line_break,
+            max_width:  self.max_width,
+        }
+    }
+}
+
+/// A set of characters for a [`BoxSpaceBlockCellTextMazeExporter`] to draw with.
+///
+/// Use a theme other than the default if the output will be shown somewhere that doesn't render
+/// block characters well.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum BlockCellTextTheme {
+    /// Solid blocks for walls and boundaries, `.` for unvisited cells, and `▲`, `▼`, and `◆` for
+    /// stairs.
+    #[default]
+    Solid,
+    /// Dark shaded blocks for walls, solid blocks for boundaries, light shaded blocks for
+    /// unvisited cells, and `▲`, `▼`, and `◆` for stairs.
+    Shaded,
+    /// Only ASCII characters: `#` for walls and boundaries, `.` for unvisited cells, and `^`,
+    /// `v`, and `X` for stairs.
+    Ascii,
+}
+
+impl BlockCellTextTheme {
+    /// Return the characters for passage, wall, boundary, and unvisited cells, in that order.
+    fn cell_chars(self) -> [char; 4] {
+        match self {
+            Self::Solid  => [' ', '█', '█', '.'],
+            Self::Shaded => [' ', '▓', '█', '░'],
+            Self::Ascii  => [' ', '#', '#', '.'],
+        }
+    }
+
+    /// Return the characters for stairs going up, down, and both, in that order.
+    fn stairs_chars(self) -> [char; 3] {
+        match self {
+            Self::Solid | Self::Shaded => ['▲', '▼', '◆'],
+            Self::Ascii                => ['^', 'v', 'X'],
         }
     }
 }
 
 impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceBlockCellTextMazeExporter {
     fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, output: &mut Output) -> Result<()> {
-        let [width, height] = nonzero_usize_array_to_usize_array(maze.get_full_dimensions());
+        let mut output = BufWriter::new(LayoutWriter::new(output, self.line_break, self.max_width));
+
+        self.export_layer(maze, 0, &mut output)?;
+
+        return output.flush()
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, Output> for BoxSpaceBlockCellTextMazeExporter {
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, output: &mut Output) -> Result<()> {
+        let mut output = BufWriter::new(LayoutWriter::new(output, self.line_break, self.max_width));
+
+        for z in 0..usize::from(maze.get_full_dimensions()[2]) {
+            if z > 0 {
+                output.write_line_break()?;
+            }
+
+            self.export_layer(maze, z, &mut output)?;
+        }
+
+        return output.flush()
+    }
+}
+
+impl BoxSpaceBlockCellTextMazeExporter {
+    /// Return the character a cell with the value `value` is drawn as, ignoring the start, goal,
+    /// and annotations.
+    #[cfg(any(feature = "animate", doc))]
+    pub(super) fn cell_char(&self, value: BlockCellValue) -> char {
+        if let (BlockCellPrimaryValue::PASSAGE, true, Some(marked_char)) = (value.cell_type, value.marked, self.marked_char) {
+            return marked_char
+        }
+
+        match (value.cell_type, value.connector) {
+            (BlockCellPrimaryValue::PASSAGE, VerticalConnector::NONE)        => self.passage_char,
+            (BlockCellPrimaryValue::PASSAGE, VerticalConnector::UP)          => self.stairs_up_char,
+            (BlockCellPrimaryValue::PASSAGE, VerticalConnector::DOWN)        => self.stairs_down_char,
+            (BlockCellPrimaryValue::PASSAGE, VerticalConnector::UP_AND_DOWN) => self.stairs_up_and_down_char,
+            (BlockCellPrimaryValue::WALL,      _) => self.wall_char,
+            (BlockCellPrimaryValue::BOUNDARY,  _) => self.boundary_char,
+            (BlockCellPrimaryValue::UNVISITED, _) => self.unvisited_char,
+        }
+    }
+
+    /// Export the `layer`th layer of cells of `maze` spanned by the first two axes.
+    fn export_layer<Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, Output: Write>(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, layer: usize, output: &mut Output) -> Result<()> {
+        let full_dimensions = nonzero_usize_array_to_usize_array(maze.get_full_dimensions());
+        let [width, height] = [full_dimensions[0], full_dimensions[1]];
+
+        // Layers are contiguous in the buffer
+        let layer_offset = layer * width * height;
 
         let mut passage_char_utf8_buffer = [0u8; 4];
         let mut wall_char_utf8_buffer = [0u8; 4];
@@ -224,66 +559,90 @@ impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBl
         let boundary_char = self.boundary_char.encode_utf8(&mut boundary_char_utf8_buffer).as_bytes();
         let unvisited_char = self.unvisited_char.encode_utf8(&mut unvisited_char_utf8_buffer).as_bytes();
 
-        if self.chars_per_cell_vertically == NONZERO_USIZE_ONE {
-            // Optimised for n = 1
-            // Avoid storing the line buffer and write to the output directly
-            // Makes memory usage O(1)
-            // If they're not using a BufWriter and we end up making a bunch of
-            // syscalls this'll probably perform worse but that's on them.
-            
-            for y in 0..height {
-                for x in 0..width {
-                    let pt = CellID(x + y * width);
-
-                    let char = match maze.buffer().get(pt).cell_type {
-                        BlockCellPrimaryValue::PASSAGE   => passage_char,
-                        BlockCellPrimaryValue::WALL      => wall_char,
-                        BlockCellPrimaryValue::BOUNDARY  => boundary_char,
-                        BlockCellPrimaryValue::UNVISITED => unvisited_char
-                    };
-
-                    for _ in 0..usize::from(self.chars_per_cell_horizontally) {
-                        output.write_all(char)?;
-                    }
-                };
+        let mut stairs_up_char_utf8_buffer = [0u8; 4];
+        let mut stairs_down_char_utf8_buffer = [0u8; 4];
+        let mut stairs_up_and_down_char_utf8_buffer = [0u8; 4];
 
-                output.write_line_break()?;
-            }
+        let stairs_up_char = self.stairs_up_char.encode_utf8(&mut stairs_up_char_utf8_buffer).as_bytes();
+        let stairs_down_char = self.stairs_down_char.encode_utf8(&mut stairs_down_char_utf8_buffer).as_bytes();
+        let stairs_up_and_down_char = self.stairs_up_and_down_char.encode_utf8(&mut stairs_up_and_down_char_utf8_buffer).as_bytes();
+
+        let mut marked_char_utf8_buffer = [0u8; 4];
+
+        let marked_char = self.marked_char.map(|char| char.encode_utf8(&mut marked_char_utf8_buffer).as_bytes());
+
+        let mut start_char_utf8_buffer = [0u8; 4];
+        let mut goal_char_utf8_buffer = [0u8; 4];
+
+        let start_char = self.start_char.encode_utf8(&mut start_char_utf8_buffer).as_bytes();
+        let goal_char = self.goal_char.encode_utf8(&mut goal_char_utf8_buffer).as_bytes();
+
+        // The cells of the start and goal points, which are drawn over whatever is there
+        let endpoints = [(maze.start(), start_char), (maze.goal(), goal_char)].map(|(pt, char)| (pt.map(|pt| maze.map_pt_to_cell_id(pt)), char));
+
+        // The cells of annotated points, which are drawn over whatever is there (but under the
+        // start and goal)
+        let glyphs: HashMap<CellID, char> = if self.draw_annotations {
+            maze.annotations().glyphs().map(|(pt, glyph)| (maze.map_pt_to_cell_id(pt), glyph)).collect()
         } else {
-            // The maze *probably* has a thick solid border of wall or border cells
-            // and the passage cell's byte-encoded length is *probably* less than or
-            // equal to the wall/border cell's lengths. If either of those assumptions
-            // are false it will self-correct by increasing the capacity size as necessary.
-            let mut line_buffer = Vec::with_capacity(width * max(wall_char.len(), boundary_char.len()));
-
-            for y in 0..height {
-                for x in 0..width {
-                    let pt = CellID(x + y * width);
-
-                    let char = match maze.buffer().get(pt).cell_type {
-                        BlockCellPrimaryValue::PASSAGE   => passage_char,
-                        BlockCellPrimaryValue::WALL      => wall_char,
-                        BlockCellPrimaryValue::BOUNDARY  => boundary_char,
-                        BlockCellPrimaryValue::UNVISITED => unvisited_char
-                    };
-
-                    for _ in 0..usize::from(self.chars_per_cell_horizontally) {
-                        line_buffer.extend_from_slice(char);
-                    }
+            HashMap::new()
+        };
+
+        let mut glyph_utf8_buffer = [0u8; 4];
+
+        let get_char = |value: &BlockCellValue| {
+            if let (BlockCellPrimaryValue::PASSAGE, true, Some(marked_char)) = (value.cell_type, value.marked, marked_char) {
+                return marked_char
+            }
+
+            match (value.cell_type, value.connector) {
+                (BlockCellPrimaryValue::PASSAGE, VerticalConnector::NONE)        => passage_char,
+                (BlockCellPrimaryValue::PASSAGE, VerticalConnector::UP)          => stairs_up_char,
+                (BlockCellPrimaryValue::PASSAGE, VerticalConnector::DOWN)        => stairs_down_char,
+                (BlockCellPrimaryValue::PASSAGE, VerticalConnector::UP_AND_DOWN) => stairs_up_and_down_char,
+                (BlockCellPrimaryValue::WALL,      _) => wall_char,
+                (BlockCellPrimaryValue::BOUNDARY,  _) => boundary_char,
+                (BlockCellPrimaryValue::UNVISITED, _) => unvisited_char,
+            }
+        };
+
+        // The maze *probably* has a thick solid border of wall or border cells
+        // and the passage cell's byte-encoded length is *probably* less than or
+        // equal to the wall/border cell's lengths. If either of those assumptions
+        // are false it will self-correct by increasing the capacity size as necessary.
+        // The line buffer is reused for every row to avoid reallocating it needlessly.
+        let mut line_buffer = Vec::with_capacity(width * usize::from(self.chars_per_cell_horizontally) * max(wall_char.len(), boundary_char.len()));
+
+        for y in 0..height {
+            let row_offset = layer_offset + y * width;
+
+            // Rows are contiguous in the buffer too
+            for (x, value) in maze.buffer().get_slice(CellID(row_offset)..CellID(row_offset + width)).iter().enumerate() {
+                let char = match endpoints.iter().find(|(cell, _)| *cell == Some(CellID(row_offset + x))) {
+                    Some((_, char)) => char,
+                    None => match glyphs.get(&CellID(row_offset + x)) {
+                        Some(glyph) => glyph.encode_utf8(&mut glyph_utf8_buffer).as_bytes(),
+                        None => get_char(value),
+                    },
                 };
 
-                for _ in 0..usize::from(self.chars_per_cell_vertically) {
-                    output.write_all(&line_buffer)?;
-                    output.write_line_break()?;
+                for _ in 0..usize::from(self.chars_per_cell_horizontally) {
+                    line_buffer.extend_from_slice(char);
                 }
-                
-                // Line buffer is hoisted outside the loop to avoid reallocating it needlessly
-                line_buffer.clear();
             }
+
+            for _ in 0..usize::from(self.chars_per_cell_vertically) {
+                output.write_all(&line_buffer)?;
+                output.write_line_break()?;
+            }
+
+            line_buffer.clear();
         }
 
         return Ok(())
     }
 }
 
-impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> TextMazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceBlockCellTextMazeExporter {}
\ No newline at end of file
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> TextMazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceBlockCellTextMazeExporter {}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> TextMazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, Output> for BoxSpaceBlockCellTextMazeExporter {}
\ No newline at end of file
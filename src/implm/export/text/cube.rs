@@ -0,0 +1,158 @@
+use std::io::{Result, Write};
+
+use crate::implm::cell::cube::CubeCellValue;
+use crate::implm::coordinate::cube::CubeSpaceCubeCellMazeCoordinator;
+use crate::implm::export::text::TextMazeExporter;
+use crate::implm::point::cube::{CubeCoordinate, CubeEdge, CubeFace};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::line_break::WriteLineBreak;
+
+/// An exporter that renders [`CubeSpaceCubeCellMazeCoordinator`]s to ASCII art, as an unfolded
+/// net of the cube.
+///
+/// The faces are laid out in a cross, with the top and bottom faces above and below the front
+/// face:
+///
+/// ```text
+///        TOP
+/// LEFT  FRONT  RIGHT  BACK
+///       BOTTOM
+/// ```
+///
+/// Every cell is drawn as a box, with `+` at its corners, `-` for its horizontal edges, and `|`
+/// for its vertical edges. Passages are left blank. Walls, boundaries, and unvisited edges are all
+/// drawn. Edges of the cube that are split apart in the net (such as the top of the left face and
+/// the left of the top face) are drawn on both sides of the split, so a passage across one shows up
+/// as a gap in both places.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::cube::CubeCellValue;
+/// # use mazelib::implm::coordinate::cube::CubeSpaceCubeCellMazeCoordinator;
+/// use mazelib::implm::export::text::CubeSpaceCubeCellTextMazeExporter;
+/// use mazelib::implm::point::cube::{CubeCoordinate, CubeCoordinateSpace, CubeFace};
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::export::DefaultMazeExporter;
+///
+/// let mut maze = CubeSpaceCubeCellMazeCoordinator::<VecBuffer<CubeCellValue>>::builder(CubeCoordinateSpace::new_checked(1)).build();
+///
+/// maze.make_passage_between(CubeCoordinate { face: CubeFace::FRONT, x: 0, y: 0 }, CubeCoordinate { face: CubeFace::RIGHT, x: 0, y: 0 });
+/// maze.make_passage_between(CubeCoordinate { face: CubeFace::LEFT, x: 0, y: 0 }, CubeCoordinate { face: CubeFace::TOP, x: 0, y: 0 });
+///
+/// let mut text = Vec::new();
+/// CubeSpaceCubeCellTextMazeExporter::export(&maze, &mut text).unwrap();
+///
+/// assert_eq!("   +--+
+///       |
+/// +  +--+--+--+
+/// |  |     |  |
+/// +--+--+--+--+
+///    |  |
+///    +--+
+/// ", String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+/// ```
+pub struct CubeSpaceCubeCellTextMazeExporter {
+    _private: ()
+}
+
+impl CubeSpaceCubeCellTextMazeExporter {
+    /// Construct a new instance of this exporter.
+    ///
+    /// This doesn't take any parameters, so if you're just immediately going to call
+    /// [`export()`][crate::interface::export::MazeExporter::export], you may wish to use
+    /// [`DefaultMazeExporter::export()`][crate::interface::export::DefaultMazeExporter::export]
+    /// instead.
+    ///
+    /// Equivalent to [`Self::default()`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Return the position of `face` in the net, in faces.
+    #[must_use]
+    fn net_position(face: CubeFace) -> [usize; 2] {
+        match face {
+            CubeFace::LEFT   => [0, 1],
+            CubeFace::FRONT  => [1, 1],
+            CubeFace::RIGHT  => [2, 1],
+            CubeFace::BACK   => [3, 1],
+            CubeFace::TOP    => [1, 0],
+            CubeFace::BOTTOM => [1, 2],
+        }
+    }
+
+    /// Return the character that represents an edge of the given type, given the character that
+    /// would be drawn if it were a wall.
+    #[must_use]
+    fn edge_char(connection: ConnectionType, wall_char: char) -> char {
+        match connection {
+            ConnectionType::PASSAGE => ' ',
+            ConnectionType::WALL | ConnectionType::BOUNDARY | ConnectionType::UNVISITED => wall_char,
+        }
+    }
+
+    /// Return the type of the edge of `pt` on side `edge`.
+    #[must_use]
+    fn get_connection<Buffer: MazeBuffer<CubeCellValue>>(maze: &CubeSpaceCubeCellMazeCoordinator<Buffer>, pt: CubeCoordinate, edge: CubeEdge) -> ConnectionType {
+        maze.get_connection(pt, maze.coord_space().neighbour(pt, edge))
+    }
+}
+
+impl Default for CubeSpaceCubeCellTextMazeExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <Buffer: MazeBuffer<CubeCellValue>, Output: Write> MazeExporter<CubeSpaceCubeCellMazeCoordinator<Buffer>, Output> for CubeSpaceCubeCellTextMazeExporter {
+    fn export(&self, maze: &CubeSpaceCubeCellMazeCoordinator<Buffer>, output: &mut Output) -> Result<()> {
+        let face_size = usize::from(maze.coord_space().face_size());
+
+        // The net is four faces wide and three faces tall. Each cell is 3 characters wide and 2
+        // lines tall, plus one more of each for the far walls.
+        let width = 3 * 4 * face_size + 1;
+        let height = 2 * 3 * face_size + 1;
+
+        let mut canvas = vec![vec![' '; width]; height];
+
+        for pt in maze.coord_space().iter() {
+            let [face_x, face_y] = Self::net_position(pt.face);
+
+            let x = 3 * (face_x * face_size + pt.x);
+            let y = 2 * (face_y * face_size + pt.y);
+
+            for [corner_x, corner_y] in [[x, y], [x + 3, y], [x, y + 2], [x + 3, y + 2]] {
+                canvas[corner_y][corner_x] = '+';
+            }
+
+            let up = Self::edge_char(Self::get_connection(maze, pt, CubeEdge::UP), '-');
+            let down = Self::edge_char(Self::get_connection(maze, pt, CubeEdge::DOWN), '-');
+
+            // Shared edges are drawn twice, once by each neighbour, but they always agree
+            canvas[y][x + 1]     = up;
+            canvas[y][x + 2]     = up;
+            canvas[y + 2][x + 1] = down;
+            canvas[y + 2][x + 2] = down;
+            canvas[y + 1][x]     = Self::edge_char(Self::get_connection(maze, pt, CubeEdge::LEFT), '|');
+            canvas[y + 1][x + 3] = Self::edge_char(Self::get_connection(maze, pt, CubeEdge::RIGHT), '|');
+        }
+
+        for line in canvas {
+            let line: String = line.into_iter().collect();
+
+            output.write_all(line.trim_end().as_bytes())?;
+            output.write_line_break()?;
+        }
+
+        return Ok(())
+    }
+}
+
+impl <Buffer: MazeBuffer<CubeCellValue>, Output: Write> TextMazeExporter<CubeSpaceCubeCellMazeCoordinator<Buffer>, Output> for CubeSpaceCubeCellTextMazeExporter {}
\ No newline at end of file
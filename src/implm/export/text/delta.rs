@@ -0,0 +1,146 @@
+use std::io::{Result, Write};
+
+use crate::implm::cell::delta::DeltaCellValue;
+use crate::implm::cell::inline::InlineCellValueEdge;
+use crate::implm::coordinate::delta::DeltaSpaceDeltaCellMazeCoordinator;
+use crate::implm::export::text::TextMazeExporter;
+use crate::implm::point::delta::{DeltaCoordinate, DeltaEdge};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::line_break::WriteLineBreak;
+
+/// An exporter that renders [`DeltaSpaceDeltaCellMazeCoordinator`]s to ASCII art.
+///
+/// Every triangle is drawn with underscores for its flat edge, and slashes for its sloped edges.
+/// Neighbouring triangles share their edges. Passages are left blank. Walls, boundaries, and
+/// unvisited edges are all drawn.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::delta::DeltaCellValue;
+/// # use mazelib::implm::coordinate::delta::DeltaSpaceDeltaCellMazeCoordinator;
+/// use mazelib::implm::export::text::DeltaSpaceDeltaCellTextMazeExporter;
+/// use mazelib::implm::point::delta::{DeltaCoordinate, DeltaCoordinateSpace};
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::export::DefaultMazeExporter;
+///
+/// let mut maze = DeltaSpaceDeltaCellMazeCoordinator::<VecBuffer<DeltaCellValue>>::builder(DeltaCoordinateSpace::new_checked(4, 2)).build();
+///
+/// maze.make_passage_between(DeltaCoordinate { column: 0, row: 0 }, DeltaCoordinate { column: 1, row: 0 });
+/// maze.make_passage_between(DeltaCoordinate { column: 2, row: 0 }, DeltaCoordinate { column: 2, row: 1 });
+///
+/// let mut text = Vec::new();
+/// DeltaSpaceDeltaCellTextMazeExporter::export(&maze, &mut text).unwrap();
+///
+/// assert_eq!("   __  __
+///  /   /\\  /
+/// /__ /  \\/
+/// \\  /\\  /\\
+///  \\/__\\/__\\
+/// ", String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+/// ```
+pub struct DeltaSpaceDeltaCellTextMazeExporter {
+    _private: ()
+}
+
+impl DeltaSpaceDeltaCellTextMazeExporter {
+    /// Construct a new instance of this exporter.
+    ///
+    /// This doesn't take any parameters, so if you're just immediately going to call
+    /// [`export()`][crate::interface::export::MazeExporter::export], you may wish to use
+    /// [`DefaultMazeExporter::export()`][crate::interface::export::DefaultMazeExporter::export]
+    /// instead.
+    ///
+    /// Equivalent to [`Self::default()`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Return the character that represents an edge of the given type, given the character that
+    /// would be drawn if it were a wall.
+    #[must_use]
+    fn edge_char(connection: ConnectionType, wall_char: char) -> char {
+        match connection {
+            ConnectionType::PASSAGE => ' ',
+            ConnectionType::WALL | ConnectionType::BOUNDARY | ConnectionType::UNVISITED => wall_char,
+        }
+    }
+
+    /// Return the type of the edge of `pt` on side `edge`, taking into account the neighbouring
+    /// cell if there is one.
+    #[must_use]
+    fn get_connection<Buffer: MazeBuffer<DeltaCellValue>>(maze: &DeltaSpaceDeltaCellMazeCoordinator<Buffer>, pt: DeltaCoordinate, edge: DeltaEdge) -> ConnectionType {
+        match maze.coord_space().neighbour(pt, edge) {
+            Some(neighbour) => maze.get_connection(pt, neighbour),
+            None => match maze.get(pt).get_edge(edge) {
+                InlineCellValueEdge::PASSAGE => ConnectionType::PASSAGE,
+                InlineCellValueEdge::WALL => ConnectionType::WALL,
+                InlineCellValueEdge::BOUNDARY => ConnectionType::BOUNDARY,
+                InlineCellValueEdge::UNVISITED => ConnectionType::UNVISITED,
+            },
+        }
+    }
+}
+
+impl Default for DeltaSpaceDeltaCellTextMazeExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <Buffer: MazeBuffer<DeltaCellValue>, Output: Write> MazeExporter<DeltaSpaceDeltaCellMazeCoordinator<Buffer>, Output> for DeltaSpaceDeltaCellTextMazeExporter {
+    fn export(&self, maze: &DeltaSpaceDeltaCellMazeCoordinator<Buffer>, output: &mut Output) -> Result<()> {
+        let columns = usize::from(maze.coord_space().columns());
+        let rows = usize::from(maze.coord_space().rows());
+
+        // Each triangle is 4 characters wide and 2 lines tall (plus a line above for the flat edges
+        // of the first row), but overlaps its horizontal neighbours by half
+        let width = 2 * columns + 2;
+        let height = 2 * rows + 1;
+
+        let mut canvas = vec![vec![' '; width]; height];
+
+        for pt in maze.coord_space().iter() {
+            let x = 2 * pt.column;
+            let y = 2 * pt.row + 1;
+
+            let left = Self::get_connection(maze, pt, DeltaEdge::LEFT);
+            let right = Self::get_connection(maze, pt, DeltaEdge::RIGHT);
+            let base = Self::edge_char(Self::get_connection(maze, pt, DeltaEdge::BASE), '_');
+
+            // Shared edges are drawn twice, once by each neighbour, but they always agree
+            if pt.points_up() {
+                canvas[y][x + 1]     = Self::edge_char(left, '/');
+                canvas[y + 1][x]     = Self::edge_char(left, '/');
+                canvas[y][x + 2]     = Self::edge_char(right, '\\');
+                canvas[y + 1][x + 3] = Self::edge_char(right, '\\');
+                canvas[y + 1][x + 1] = base;
+                canvas[y + 1][x + 2] = base;
+            } else {
+                canvas[y][x]         = Self::edge_char(left, '\\');
+                canvas[y + 1][x + 1] = Self::edge_char(left, '\\');
+                canvas[y][x + 3]     = Self::edge_char(right, '/');
+                canvas[y + 1][x + 2] = Self::edge_char(right, '/');
+                canvas[y - 1][x + 1] = base;
+                canvas[y - 1][x + 2] = base;
+            }
+        }
+
+        for line in canvas {
+            let line: String = line.into_iter().collect();
+
+            output.write_all(line.trim_end().as_bytes())?;
+            output.write_line_break()?;
+        }
+
+        return Ok(())
+    }
+}
+
+impl <Buffer: MazeBuffer<DeltaCellValue>, Output: Write> TextMazeExporter<DeltaSpaceDeltaCellMazeCoordinator<Buffer>, Output> for DeltaSpaceDeltaCellTextMazeExporter {}
\ No newline at end of file
@@ -0,0 +1,140 @@
+use std::io::{Result, Write};
+
+use crate::implm::cell::hex::HexCellValue;
+use crate::implm::cell::inline::InlineCellValueEdge;
+use crate::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+use crate::implm::export::text::TextMazeExporter;
+use crate::implm::point::hex::{HexCoordinate, HexDirection};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::line_break::WriteLineBreak;
+
+/// An exporter that renders [`HexSpaceHexCellMazeCoordinator`]s to ASCII art.
+///
+/// Every hexagon is drawn with underscores for its top and bottom edges, and slashes for its
+/// diagonal edges. Neighbouring hexagons share their edges. Passages are left blank. Walls,
+/// boundaries, and unvisited edges are all drawn.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::hex::HexCellValue;
+/// # use mazelib::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+/// use mazelib::implm::export::text::HexSpaceHexCellTextMazeExporter;
+/// use mazelib::implm::point::hex::{HexCoordinate, HexCoordinateSpace};
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::export::DefaultMazeExporter;
+///
+/// let mut maze = HexSpaceHexCellMazeCoordinator::<VecBuffer<HexCellValue>>::builder(HexCoordinateSpace::new_checked(3, 2)).build();
+///
+/// maze.make_passage_between(HexCoordinate { column: 0, row: 0 }, HexCoordinate { column: 1, row: 0 });
+/// maze.make_passage_between(HexCoordinate { column: 1, row: 0 }, HexCoordinate { column: 1, row: 1 });
+///
+/// let mut text = Vec::new();
+/// HexSpaceHexCellTextMazeExporter::export(&maze, &mut text).unwrap();
+///
+/// assert_eq!(" __    __
+/// /  \\__/  \\
+/// \\__   \\__/
+/// /  \\  /  \\
+/// \\__/  \\__/
+///    \\__/
+/// ", String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+/// ```
+pub struct HexSpaceHexCellTextMazeExporter {
+    _private: ()
+}
+
+impl HexSpaceHexCellTextMazeExporter {
+    /// Construct a new instance of this exporter.
+    ///
+    /// This doesn't take any parameters, so if you're just immediately going to call
+    /// [`export()`][crate::interface::export::MazeExporter::export], you may wish to use
+    /// [`DefaultMazeExporter::export()`][crate::interface::export::DefaultMazeExporter::export]
+    /// instead.
+    ///
+    /// Equivalent to [`Self::default()`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Return the character that represents an edge of the given type, given the character that
+    /// would be drawn if it were a wall.
+    #[must_use]
+    fn edge_char(connection: ConnectionType, wall_char: char) -> char {
+        match connection {
+            ConnectionType::PASSAGE => ' ',
+            ConnectionType::WALL | ConnectionType::BOUNDARY | ConnectionType::UNVISITED => wall_char,
+        }
+    }
+
+    /// Return the type of the edge of `pt` in `direction`, taking into account the neighbouring
+    /// cell if there is one.
+    #[must_use]
+    fn get_connection<Buffer: MazeBuffer<HexCellValue>>(maze: &HexSpaceHexCellMazeCoordinator<Buffer>, pt: HexCoordinate, direction: HexDirection) -> ConnectionType {
+        match maze.coord_space().neighbour(pt, direction) {
+            Some(neighbour) => maze.get_connection(pt, neighbour),
+            None => match maze.get(pt).get_edge(direction) {
+                InlineCellValueEdge::PASSAGE => ConnectionType::PASSAGE,
+                InlineCellValueEdge::WALL => ConnectionType::WALL,
+                InlineCellValueEdge::BOUNDARY => ConnectionType::BOUNDARY,
+                InlineCellValueEdge::UNVISITED => ConnectionType::UNVISITED,
+            },
+        }
+    }
+}
+
+impl Default for HexSpaceHexCellTextMazeExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <Buffer: MazeBuffer<HexCellValue>, Output: Write> MazeExporter<HexSpaceHexCellMazeCoordinator<Buffer>, Output> for HexSpaceHexCellTextMazeExporter {
+    fn export(&self, maze: &HexSpaceHexCellMazeCoordinator<Buffer>, output: &mut Output) -> Result<()> {
+        let columns = usize::from(maze.coord_space().columns());
+        let rows = usize::from(maze.coord_space().rows());
+
+        // Each hexagon is 4 characters wide and 3 lines tall, but shares its sides and its top and
+        // bottom lines with its neighbours
+        let width = 3 * columns + 1;
+        let height = 2 * rows + 1 + usize::from(columns > 1);
+
+        let mut canvas = vec![vec![' '; width]; height];
+
+        for pt in maze.coord_space().iter() {
+            let x = 3 * pt.column;
+            let y = 2 * pt.row + pt.column % 2;
+
+            let top = Self::edge_char(Self::get_connection(maze, pt, HexDirection::NORTH), '_');
+            let bottom = Self::edge_char(Self::get_connection(maze, pt, HexDirection::SOUTH), '_');
+
+            // Shared edges are drawn twice, once by each neighbour, but they always agree
+            canvas[y][x + 1]     = top;
+            canvas[y][x + 2]     = top;
+            canvas[y + 2][x + 1] = bottom;
+            canvas[y + 2][x + 2] = bottom;
+
+            canvas[y + 1][x]     = Self::edge_char(Self::get_connection(maze, pt, HexDirection::NORTH_WEST), '/');
+            canvas[y + 1][x + 3] = Self::edge_char(Self::get_connection(maze, pt, HexDirection::NORTH_EAST), '\\');
+            canvas[y + 2][x]     = Self::edge_char(Self::get_connection(maze, pt, HexDirection::SOUTH_WEST), '\\');
+            canvas[y + 2][x + 3] = Self::edge_char(Self::get_connection(maze, pt, HexDirection::SOUTH_EAST), '/');
+        }
+
+        for line in canvas {
+            let line: String = line.into_iter().collect();
+
+            output.write_all(line.trim_end().as_bytes())?;
+            output.write_line_break()?;
+        }
+
+        return Ok(())
+    }
+}
+
+impl <Buffer: MazeBuffer<HexCellValue>, Output: Write> TextMazeExporter<HexSpaceHexCellMazeCoordinator<Buffer>, Output> for HexSpaceHexCellTextMazeExporter {}
\ No newline at end of file
@@ -1,22 +1,43 @@
-use std::io::{Result, Write};
+use std::io::{BufWriter, Result, Write};
 use std::num::NonZeroUsize;
 
 use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge as EdgeType};
 use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
 use crate::implm::export::text::TextMazeExporter;
+use crate::implm::export::text::layout::{LayoutWriter, LineBreak, Overflow};
+use crate::implm::point::boxy::CoordinateTuplet;
 use crate::interface::buffer::MazeBuffer;
-use crate::interface::cell::CellID;
 use crate::interface::coordinate::MazeCoordinator;
 use crate::interface::export::MazeExporter;
 use crate::internal::line_break::WriteLineBreak;
 use crate::internal::util::{nonzero_usize_array_to_usize_array, NONZERO_USIZE_ONE, NONZERO_USIZE_THREE};
 
 /// An exporter that renders [`BoxSpaceInlineCellMazeCoordinator`]s to text.
+///
+/// [Tunnels][BoxSpaceInlineCellMazeCoordinator::make_tunnel_between] are drawn by dashing the
+/// walls of the cell they pass beneath, on the sides the tunnel enters and leaves it.
+///
+/// Three-dimensional mazes are drawn one layer at a time, from the lowest to the highest along the
+/// third axis, with a blank line between each. Passages to the layer above are marked with `▲`, to
+/// the layer below with `▼`, and in both directions with `◆`.
+///
+/// Passages that [wrap around][crate::implm::point::boxy::BoxCoordinateSpace#wrapping-adjacency]
+/// the maze are drawn as gaps in the outer wall, on both of the opposite sides they join.
+///
+/// The characters used are chosen by the exporter's [theme][InlineCellTextTheme]. By default walls
+/// are drawn with light box-drawing characters and boundaries with heavy ones.
 pub struct BoxSpaceInlineCellTextMazeExporter {
+    /// The set of characters to draw with.
+    theme: InlineCellTextTheme,
     /// The number of the characters to emit horizontally per cell, excluding walls.
     chars_per_cell_horizontally: NonZeroUsize,
     /// The number of the characters to emit vertically per cell, excluding walls.
     chars_per_cell_vertically:   NonZeroUsize,
+
+    /// The characters to end each line with.
+    line_break: LineBreak,
+    /// The maximum width of a line in characters, and what to do with lines longer than it.
+    max_width: Option<(usize, Overflow)>,
 }
 
 impl BoxSpaceInlineCellTextMazeExporter {
@@ -27,6 +48,12 @@ impl BoxSpaceInlineCellTextMazeExporter {
         BoxSpaceInlineCellTextMazeExporterBuilder::new()
     }
 
+    /// Return the set of characters that is drawn with.
+    #[must_use]
+    pub fn theme(&self) -> InlineCellTextTheme {
+        self.theme
+    }
+
     /// Return the number of the characters that is emitted horizontally per cell, excluding walls.
     #[must_use]
     pub fn chars_per_cell_horizontally(&self) -> NonZeroUsize {
@@ -38,6 +65,19 @@ impl BoxSpaceInlineCellTextMazeExporter {
     pub fn chars_per_cell_vertically(&self) -> NonZeroUsize {
         self.chars_per_cell_vertically
     }
+
+    /// Return the characters that each line is ended with.
+    #[must_use]
+    pub fn line_break(&self) -> LineBreak {
+        self.line_break
+    }
+
+    /// Return the maximum width of a line in characters, and what is done with lines longer than
+    /// it, if there is a maximum.
+    #[must_use]
+    pub fn max_width(&self) -> Option<(usize, Overflow)> {
+        self.max_width
+    }
 }
 
 impl Default for BoxSpaceInlineCellTextMazeExporter {
@@ -49,21 +89,40 @@ impl Default for BoxSpaceInlineCellTextMazeExporter {
 /// A builder for a [`BoxSpaceInlineCellTextMazeExporter`].
 #[must_use]
 pub struct BoxSpaceInlineCellTextMazeExporterBuilder {
+    /// The set of characters to draw with.
+    theme: InlineCellTextTheme,
     /// The number of the characters to emit horizontally per cell.
     chars_per_cell_horizontally: NonZeroUsize,
     /// The number of the characters to emit vertically per cell.
     chars_per_cell_vertically:   NonZeroUsize,
+
+    /// The characters to end each line with.
+    line_break: LineBreak,
+    /// The maximum width of a line in characters, and what to do with lines longer than it.
+    max_width: Option<(usize, Overflow)>,
 }
 
 impl BoxSpaceInlineCellTextMazeExporterBuilder {
     /// Create a new builder for a [`BoxSpaceInlineCellTextMazeExporter`].
     fn new() -> Self {
         Self {
+            theme: InlineCellTextTheme::Light,
+
             chars_per_cell_horizontally: NONZERO_USIZE_THREE,
             chars_per_cell_vertically:   NONZERO_USIZE_ONE,
+
+            line_break: LineBreak::Native,
+            max_width:  None,
         }
     }
 
+    /// Set the set of characters to draw with. Defaults to [`InlineCellTextTheme::Light`].
+    pub fn theme(mut self, theme: InlineCellTextTheme) -> Self {
+        self.theme = theme;
+
+        return self
+    }
+
     /// Set the number of the characters to emit horizontally per cell, excluding walls.
     ///
     /// # See Also
@@ -118,44 +177,118 @@ impl BoxSpaceInlineCellTextMazeExporterBuilder {
         self.chars_per_cell_vertically(NonZeroUsize::new(count).expect("count was zero"))
     }
 
+    /// Set the characters to end each line with. Defaults to [`LineBreak::Native`].
+    pub fn line_break(mut self, line_break: LineBreak) -> Self {
+        self.line_break = line_break;
+
+        return self
+    }
+
+    /// Limit lines to `width` characters, [wrapping or truncating][Overflow] any that are longer.
+    /// By default lines are as long as the maze is wide.
+    ///
+    /// `width` must be at least 2, to leave room for the overflow indicator.
+    ///
+    /// # Panics
+    ///
+    /// If `width` is less than 2.
+    pub fn max_width(mut self, width: usize, overflow: Overflow) -> Self {
+        assert!(width >= 2, "The maximum width must be at least 2");
+
+        self.max_width = Some((width, overflow));
+
+        return self
+    }
 
     /// Finalise the [`BoxSpaceInlineCellTextMazeExporter`].
     #[must_use]
     pub fn build(self) -> BoxSpaceInlineCellTextMazeExporter {
         BoxSpaceInlineCellTextMazeExporter {
+            theme: self.theme,
+
             chars_per_cell_horizontally: self.chars_per_cell_horizontally,
             chars_per_cell_vertically:   self.chars_per_cell_vertically,
+
+            line_break: self.line_break,
+            max_width:  self.max_width,
         }
     }
 }
 
 impl <Buffer: MazeBuffer<InlineCellValue<2>>, Output: Write> MazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceInlineCellTextMazeExporter {
     fn export(&self, maze: &BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, output: &mut Output) -> Result<()> {
-        let [width, height] = nonzero_usize_array_to_usize_array(maze.coord_space().dimensions());
+        let mut output = BufWriter::new(LayoutWriter::new(output, self.line_break, self.max_width));
 
-        // Below +1's: cause we're looking at walls not cells
+        self.export_layer(maze, [0, 0].into(), &mut output)?;
 
-        // Track where the walls are above the current row, so we know where to print horizontal walls
-        let mut top_walls = vec![EdgeType::PASSAGE; height + 1];
+        return output.flush()
+    }
+}
 
-        // Track relevant connections for wall intersections, so we know how to print them
-        // [has_left_connection, has_top_connection]
-        // (bottom and right can be worked out at print time)
-        let mut wall_connections = vec![[EdgeType::PASSAGE; 2]; height + 1];
+impl <Buffer: MazeBuffer<InlineCellValue<3>>, Output: Write> MazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, 3>, Output> for BoxSpaceInlineCellTextMazeExporter {
+    fn export(&self, maze: &BoxSpaceInlineCellMazeCoordinator<Buffer, 3>, output: &mut Output) -> Result<()> {
+        let mut output = BufWriter::new(LayoutWriter::new(output, self.line_break, self.max_width));
 
-        for y in 0..height {
-            // TODO can we get rid of these and just write to the output directly?
-            // We export two lines simultaneously
-            let mut line_top_walls: String = String::with_capacity(width * 2 + 1);
-            let mut line_side_walls: String = String::with_capacity(width * 2 + 1);
+        let depth = usize::from(maze.coord_space().dimensions()[2]);
 
+        for z in 0..depth {
+            if z > 0 {
+                output.write_line_break()?;
+            }
+
+            self.export_layer(maze, [0, 0, z].into(), &mut output)?;
+        }
+
+        return output.flush()
+    }
+}
+
+/// What the exporter remembers about each column of wall intersections from the row above.
+#[derive(Copy, Clone)]
+struct Column {
+    /// The wall below the cell above, so we know where to print horizontal walls.
+    wall_above: EdgeType,
+    /// The walls to the left of and above the intersection, so we know how to print it. (Bottom
+    /// and right can be worked out at print time.)
+    connections: [EdgeType; 2],
+    /// The tunnel beneath the cell above, if any, so we know which horizontal walls to dash.
+    tunnel_above: Option<usize>,
+}
+
+impl BoxSpaceInlineCellTextMazeExporter {
+    /// Export the layer of `maze` spanned by the first two axes that contains `layer`.
+    ///
+    /// Passages across any further axes are drawn inside the cells they leave.
+    fn export_layer<Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize, Output: Write>(&self, maze: &BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, layer: CoordinateTuplet<DIMENSION>, output: &mut Output) -> Result<()> {
+        let dimensions = nonzero_usize_array_to_usize_array(maze.coord_space().dimensions());
+
+        self.export_grid([dimensions[0], dimensions[1]], |x, y| maze.get(layer.at(0, x).at(1, y)), output)
+    }
+
+    /// Export a `width` by `height` grid of cells, where `get_cell(x, y)` returns the value of the
+    /// cell in column `x` and row `y`.
+    ///
+    /// Passages across any further axes are drawn inside the cells they leave.
+    ///
+    /// Each row is drawn as two lines at once, which are built up in byte buffers that are reused
+    /// for every row. Nothing else is allocated per row or per cell.
+    pub(super) fn export_grid<const DIMENSION: usize, Output: Write>(&self, [width, height]: [usize; 2], get_cell: impl Fn(usize, usize) -> InlineCellValue<DIMENSION>, output: &mut Output) -> Result<()> {
+        let chars_per_cell_horizontally = usize::from(self.chars_per_cell_horizontally);
+
+        // +1 cause we're looking at walls not cells
+        let mut columns = vec![Column { wall_above: EdgeType::PASSAGE, connections: [EdgeType::PASSAGE; 2], tunnel_above: None }; width + 1];
+
+        // Box-drawing characters are three bytes each
+        let mut line_top_walls = Vec::with_capacity((width * (chars_per_cell_horizontally + 1) + 1) * 3);
+        let mut line_side_walls = Vec::with_capacity((width * (chars_per_cell_horizontally + 1) + 1) * 3);
+
+        for y in 0..height {
             // Track if there was a wall just before this one, so we know how to print joiners
             let mut wall_previously = EdgeType::PASSAGE;
+            let mut tunnel_previously = None;
 
             for x in 0..width {
-                let pt = CellID(x + y * width);
-
-                let cell_value = maze.buffer().get(pt);
+                let cell_value = get_cell(x, y);
 
                 let walls = cell_value.edges;
 
@@ -166,199 +299,301 @@ impl <Buffer: MazeBuffer<InlineCellValue<2>>, Output: Write> MazeExporter<BoxSpa
                     (EdgeType::PASSAGE, EdgeType::PASSAGE) => EdgeType::PASSAGE,
                 };
 
-                let top_wall_actual = match (walls[1][0], top_walls[x]) {
+                let top_wall_actual = match (walls[1][0], columns[x].wall_above) {
                     (EdgeType::BOUNDARY, _) | (_, EdgeType::BOUNDARY) => EdgeType::BOUNDARY,
                     (EdgeType::WALL, _) | (_, EdgeType::WALL) => EdgeType::WALL,
                     (EdgeType::UNVISITED, _) | (_, EdgeType::UNVISITED) => EdgeType::UNVISITED,
                     (EdgeType::PASSAGE, EdgeType::PASSAGE) => EdgeType::PASSAGE,
                 };
 
-                line_top_walls.push(Self::get_box_char(wall_connections[x][0], wall_connections[x][1], top_wall_actual, left_wall_actual));
+                push_char(&mut line_top_walls, self.theme.junction_char(columns[x].connections[0], columns[x].connections[1], top_wall_actual, left_wall_actual));
 
-                line_top_walls.push_str(&(match top_wall_actual {
-                    EdgeType::WALL => "─",
-                    EdgeType::BOUNDARY => "━",
-                    EdgeType::PASSAGE => " ",
-                    EdgeType::UNVISITED => "┄",
-                }).repeat(self.chars_per_cell_horizontally.into()));
+                let is_top_tunnelled = cell_value.tunnel == Some(1) || columns[x].tunnel_above == Some(1);
+                let is_left_tunnelled = cell_value.tunnel == Some(0) || tunnel_previously == Some(0);
 
-                line_side_walls.push(match left_wall_actual {
-                    EdgeType::WALL => '│',
-                    EdgeType::BOUNDARY => '┃',
-                    EdgeType::PASSAGE => ' ',
-                    EdgeType::UNVISITED => '┆',
-                });
+                let top_wall_char = self.theme.horizontal_char(top_wall_actual, is_top_tunnelled);
 
-                line_side_walls.push_str(&" ".repeat(self.chars_per_cell_horizontally.into()));
+                for _ in 0..chars_per_cell_horizontally {
+                    push_char(&mut line_top_walls, top_wall_char);
+                }
+
+                push_char(&mut line_side_walls, self.theme.vertical_char(left_wall_actual, is_left_tunnelled));
+
+                self.push_cell_interior(&mut line_side_walls, &cell_value);
 
                 wall_previously = walls[0][1];
-                top_walls[x] = walls[1][1];
+                tunnel_previously = cell_value.tunnel;
 
-                wall_connections[x][1] = left_wall_actual;
-                wall_connections[x + 1][0] = top_wall_actual;
+                columns[x].wall_above = walls[1][1];
+                columns[x].tunnel_above = cell_value.tunnel;
+                columns[x].connections[1] = left_wall_actual;
+                columns[x + 1].connections[0] = top_wall_actual;
             };
 
             // Draw the right side
 
-            line_top_walls.push(Self::get_box_char(wall_connections[width][0], wall_connections[width][1], EdgeType::PASSAGE, wall_previously));
-
-            if let EdgeType::WALL = wall_previously {
-                line_side_walls.push('│');
-                wall_connections[width][1] = wall_previously;
-            } else if let EdgeType::BOUNDARY = wall_previously {
-                line_side_walls.push('┃');
-                wall_connections[width][1] = wall_previously;
-            } else if let EdgeType::UNVISITED = wall_previously {
-                line_side_walls.push('┆');
-                wall_connections[width][1] = wall_previously;
+            push_char(&mut line_top_walls, self.theme.junction_char(columns[width].connections[0], columns[width].connections[1], EdgeType::PASSAGE, wall_previously));
+
+            if wall_previously != EdgeType::PASSAGE {
+                push_char(&mut line_side_walls, self.theme.vertical_char(wall_previously, false));
+                columns[width].connections[1] = wall_previously;
             }
 
-            output.write_all(line_top_walls.as_bytes())?;
+            output.write_all(&line_top_walls)?;
             output.write_line_break()?;
             
             for _ in 0..(self.chars_per_cell_vertically.into()) {
-                output.write_all(line_side_walls.as_bytes())?;
+                output.write_all(&line_side_walls)?;
                 output.write_line_break()?;
             }
+
+            line_top_walls.clear();
+            line_side_walls.clear();
         }
 
-        // Draw the bottom side
+        // Draw the bottom side, reusing the top wall buffer
         {
-            let mut line: String = String::with_capacity(width * 2 + 1);
+            let line = &mut line_top_walls;
 
             for x in 0..width {
-                let pt = CellID(x + (height - 1) * width);  // We're iterating over the last row
-
-                let cell_value = maze.buffer().get(pt);
+                let cell_value = get_cell(x, height - 1);  // We're iterating over the last row
 
                 let walls = cell_value.edges;
 
-                line.push(Self::get_box_char(wall_connections[x][0], walls[0][0], walls[1][1], EdgeType::PASSAGE));
+                push_char(line, self.theme.junction_char(columns[x].connections[0], walls[0][0], walls[1][1], EdgeType::PASSAGE));
+
+                let bottom_wall_char = self.theme.horizontal_char(walls[1][1], false);
 
-                line.push_str(&(match walls[1][1] {
-                    EdgeType::WALL => "─",
-                    EdgeType::BOUNDARY => "━",
-                    EdgeType::PASSAGE => " ",
-                    EdgeType::UNVISITED => "┄",
-                }).repeat(self.chars_per_cell_horizontally.into()));
+                for _ in 0..chars_per_cell_horizontally {
+                    push_char(line, bottom_wall_char);
+                }
 
-                wall_connections[x + 1][0] = walls[1][1];
+                columns[x + 1].connections[0] = walls[1][1];
             }
 
             // Bottom-right corner
-            line.push(Self::get_box_char(wall_connections[width][0], wall_connections[width][1], EdgeType::PASSAGE, EdgeType::PASSAGE));
+            push_char(line, self.theme.junction_char(columns[width].connections[0], columns[width].connections[1], EdgeType::PASSAGE, EdgeType::PASSAGE));
 
-            output.write_all(line.as_bytes())?;
+            output.write_all(line)?;
             output.write_line_break()?;
         }
 
         return Ok(())
     }
+
+    /// Append the characters to draw inside a cell, between its walls, to `line`.
+    ///
+    /// For mazes of three or more dimensions, passages along the third axis are marked in the
+    /// middle of the cell.
+    fn push_cell_interior<const DIMENSION: usize>(&self, line: &mut Vec<u8>, cell_value: &InlineCellValue<DIMENSION>) {
+        let marker = if DIMENSION >= 3 {
+            match (cell_value.edges[2][0], cell_value.edges[2][1]) {
+                (EdgeType::PASSAGE, EdgeType::PASSAGE) => Some(self.theme.stairs_chars()[2]),
+                (EdgeType::PASSAGE, _) => Some(self.theme.stairs_chars()[1]),
+                (_, EdgeType::PASSAGE) => Some(self.theme.stairs_chars()[0]),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        let chars_per_cell_horizontally = usize::from(self.chars_per_cell_horizontally);
+
+        for i in 0..chars_per_cell_horizontally {
+            match marker {
+                Some(marker) if i == chars_per_cell_horizontally / 2 => push_char(line, marker),
+                _ => line.push(b' '),
+            }
+        }
+    }
 }
 
-impl BoxSpaceInlineCellTextMazeExporter {
-    // not recommended reading
-    #[must_use]
-    fn get_box_char(left_wall: EdgeType, top_wall: EdgeType, right_wall: EdgeType, bottom_wall: EdgeType) -> char {
-        match (left_wall, top_wall, right_wall, bottom_wall) {
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┼',
-            (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┽',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '╀',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┾',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '╁',
-            (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '╃',
-            (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┿',
-            (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '╅',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '╄',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '╂',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '╆',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '╊',
-            (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '╈',
-            (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '╉',
-            (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '╇',
-            (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '╋',
-
-            (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '├',
-            (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┞',
-            (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┝',
-            (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┟',
-            (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┡',
-            (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┠',
-            (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┢',
-            (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┣',
-
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┬',
-            (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┭',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┮',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┰',
-            (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┯',
-            (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┱',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┲',
-            (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┳',
-
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┤',
-            (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┥',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┦',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┧',
-            (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┩',
-            (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┪',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┨',
-            (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┫',
-
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┴',
-            (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┵',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┸',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┶',
-            (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┹',
-            (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┷',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┺',
-            (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┻',
-
-            (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '│',
-            (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '╿',
-            (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '╽',
-            (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┃',
-
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '─',
-            (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '╾',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '╼',
-            (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '━',
-
-            (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┌',
-            (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┍',
-            (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┎',
-            (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┏',
-
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┐',
-            (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┑',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┒',
-            (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┓',
-
-            (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '└',
-            (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┖',
-            (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┕',
-            (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┗',
-
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '┘',
-            (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '┙',
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '┚',
-            (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '┛',
-
-            (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '╴',
-            (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '╸',
-
-            (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '╵',
-            (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '╹',
-
-            (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '╶',
-            (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '╺',
-
-            (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '╷',
-            (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '╻',
-
-            (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE)                     => ' ',
+/// A set of characters for a [`BoxSpaceInlineCellTextMazeExporter`] to draw with.
+///
+/// Use a theme other than the default if the output will be shown somewhere that doesn't render
+/// box-drawing characters well.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum InlineCellTextTheme {
+    /// Light box-drawing characters for walls, and heavy ones for boundaries (`─│┼`, `━┃╋`).
+    #[default]
+    Light,
+    /// Heavy box-drawing characters for both walls and boundaries (`━┃╋`).
+    Heavy,
+    /// Only ASCII characters. Walls and boundaries are drawn alike (`-|+`), tunnels are dashed
+    /// with `~` and `:`, unvisited walls are dotted with `.`, and passages between layers are
+    /// drawn as `^`, `v`, and `X`.
+    Ascii,
+}
+
+impl InlineCellTextTheme {
+    /// Return the character for a horizontal wall of type `edge`, dashed if `is_tunnelled`.
+    fn horizontal_char(self, edge: EdgeType, is_tunnelled: bool) -> char {
+        match (self, edge) {
+            (_, EdgeType::PASSAGE) => ' ',
+            (Self::Light, EdgeType::WALL) if is_tunnelled => '╌',
+            (Self::Light, EdgeType::WALL) => '─',
+            (Self::Light, EdgeType::UNVISITED) => '┄',
+            (Self::Light | Self::Heavy, EdgeType::BOUNDARY) => '━',
+            (Self::Heavy, EdgeType::WALL) if is_tunnelled => '╍',
+            (Self::Heavy, EdgeType::WALL) => '━',
+            (Self::Heavy, EdgeType::UNVISITED) => '┅',
+            (Self::Ascii, EdgeType::WALL) if is_tunnelled => '~',
+            (Self::Ascii, EdgeType::WALL | EdgeType::BOUNDARY) => '-',
+            (Self::Ascii, EdgeType::UNVISITED) => '.',
+        }
+    }
+
+    /// Return the character for a vertical wall of type `edge`, dashed if `is_tunnelled`.
+    fn vertical_char(self, edge: EdgeType, is_tunnelled: bool) -> char {
+        match (self, edge) {
+            (_, EdgeType::PASSAGE) => ' ',
+            (Self::Light, EdgeType::WALL) if is_tunnelled => '╎',
+            (Self::Light, EdgeType::WALL) => '│',
+            (Self::Light, EdgeType::UNVISITED) => '┆',
+            (Self::Light | Self::Heavy, EdgeType::BOUNDARY) => '┃',
+            (Self::Heavy, EdgeType::WALL) if is_tunnelled => '╏',
+            (Self::Heavy, EdgeType::WALL) => '┃',
+            (Self::Heavy, EdgeType::UNVISITED) => '┇',
+            (Self::Ascii, EdgeType::WALL) if is_tunnelled => ':',
+            (Self::Ascii, EdgeType::WALL | EdgeType::BOUNDARY) => '|',
+            (Self::Ascii, EdgeType::UNVISITED) => '.',
+        }
+    }
+
+    /// Return the characters for passages to the layer above, below, and both, in that order.
+    fn stairs_chars(self) -> [char; 3] {
+        match self {
+            Self::Light | Self::Heavy => ['▲', '▼', '◆'],
+            Self::Ascii => ['^', 'v', 'X'],
+        }
+    }
+
+    /// Return the character for the intersection of walls of the given types.
+    fn junction_char(self, left_wall: EdgeType, top_wall: EdgeType, right_wall: EdgeType, bottom_wall: EdgeType) -> char {
+        let heavy = |edge| match edge {
+            EdgeType::WALL | EdgeType::UNVISITED => EdgeType::BOUNDARY,
+            edge => edge,
+        };
+
+        match self {
+            Self::Light => get_box_char(left_wall, top_wall, right_wall, bottom_wall),
+            Self::Heavy => get_box_char(heavy(left_wall), heavy(top_wall), heavy(right_wall), heavy(bottom_wall)),
+            Self::Ascii if [left_wall, top_wall, right_wall, bottom_wall] == [EdgeType::PASSAGE; 4] => ' ',
+            Self::Ascii => '+',
         }
     }
 }
 
-impl <Buffer: MazeBuffer<InlineCellValue<2>>, Output: Write> TextMazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceInlineCellTextMazeExporter {}
\ No newline at end of file
+/// Return the box-drawing character for the intersection of walls of the given types, with light
+/// lines for walls and heavy lines for boundaries.
+// not recommended reading
+#[must_use]
+fn get_box_char(left_wall: EdgeType, top_wall: EdgeType, right_wall: EdgeType, bottom_wall: EdgeType) -> char {
+    match (left_wall, top_wall, right_wall, bottom_wall) {
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┼',
+        (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┽',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '╀',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┾',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '╁',
+        (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '╃',
+        (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┿',
+        (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '╅',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '╄',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '╂',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '╆',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '╊',
+        (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '╈',
+        (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '╉',
+        (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '╇',
+        (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '╋',
+
+        (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '├',
+        (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┞',
+        (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┝',
+        (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┟',
+        (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┡',
+        (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┠',
+        (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┢',
+        (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┣',
+
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┬',
+        (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┭',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┮',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┰',
+        (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┯',
+        (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┱',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┲',
+        (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┳',
+
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┤',
+        (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┥',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┦',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┧',
+        (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┩',
+        (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┪',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┨',
+        (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┫',
+
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┴',
+        (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┵',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┸',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┶',
+        (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┹',
+        (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┷',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┺',
+        (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┻',
+
+        (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '│',
+        (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '╿',
+        (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '╽',
+        (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┃',
+
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '─',
+        (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '╾',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '╼',
+        (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '━',
+
+        (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED) => '┌',
+        (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED) => '┍',
+        (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY)                   => '┎',
+        (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY)                   => '┏',
+
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┐',
+        (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '┑',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┒',
+        (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '┓',
+
+        (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '└',
+        (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '┖',
+        (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┕',
+        (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '┗',
+
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '┘',
+        (EdgeType::BOUNDARY,                    EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '┙',
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '┚',
+        (EdgeType::BOUNDARY,                    EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '┛',
+
+        (EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '╴',
+        (EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '╸',
+
+        (EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '╵',
+        (EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE,                     EdgeType::PASSAGE)                    => '╹',
+
+        (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED,  EdgeType::PASSAGE)                    => '╶',
+        (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY,                    EdgeType::PASSAGE)                    => '╺',
+
+        (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::WALL | EdgeType::UNVISITED) => '╷',
+        (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::BOUNDARY)                   => '╻',
+
+        (EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE,                     EdgeType::PASSAGE)                     => ' ',
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<2>>, Output: Write> TextMazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, 2>, Output> for BoxSpaceInlineCellTextMazeExporter {}
+
+impl <Buffer: MazeBuffer<InlineCellValue<3>>, Output: Write> TextMazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, 3>, Output> for BoxSpaceInlineCellTextMazeExporter {}
+
+/// Append the UTF-8 encoding of `char` to `line`.
+fn push_char(line: &mut Vec<u8>, char: char) {
+    line.extend_from_slice(char.encode_utf8(&mut [0u8; 4]).as_bytes());
+}
\ No newline at end of file
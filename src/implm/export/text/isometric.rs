@@ -0,0 +1,155 @@
+use std::io::{BufWriter, Result, Write};
+
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
+use crate::implm::export::text::TextMazeExporter;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::line_break::WriteLineBreak;
+
+/// An exporter that renders three-dimensional [`BoxSpaceInlineCellMazeCoordinator`]s to ASCII art
+/// in an isometric-style projection.
+///
+/// Each layer (floor) along the third axis is drawn as a parallelogram, slanted as though seen
+/// from above and in front, so that the maze reads as a stack of floors rather than a list of
+/// flat plans. The floors are stacked with the highest at the top, with a blank line between each.
+///
+/// Every cell is drawn with `+` at its corners, `-` for its edges along the first axis, and `/`
+/// for its edges along the second axis. Passages are left blank. Walls, boundaries, and unvisited
+/// edges are all drawn. Passages to the floor above are marked with `^`, to the floor below with
+/// `v`, and in both directions with `X`.
+///
+/// This is best suited to small mazes. The output is plain ASCII, so it works in any terminal.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// use mazelib::implm::export::text::BoxSpaceInlineCellIsometricTextMazeExporter;
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::export::DefaultMazeExporter;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<3>>, 3>::new(BoxCoordinateSpace::new_checked([2, 1, 2])).build();
+///
+/// maze.make_passage_between([0, 0, 0].into(), [1, 0, 0].into());
+/// maze.make_passage_between([1, 0, 0].into(), [1, 0, 1].into());
+/// maze.make_passage_between([1, 0, 1].into(), [0, 0, 1].into());
+///
+/// let mut text = Vec::new();
+/// BoxSpaceInlineCellIsometricTextMazeExporter::export(&maze, &mut text).unwrap();
+///
+/// assert_eq!("  +--+--+
+///  /    v/
+/// +--+--+
+///
+///   +--+--+
+///  /    ^/
+/// +--+--+
+/// ", String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+/// ```
+pub struct BoxSpaceInlineCellIsometricTextMazeExporter {
+    _private: ()
+}
+
+impl BoxSpaceInlineCellIsometricTextMazeExporter {
+    /// Construct a new instance of this exporter.
+    ///
+    /// This doesn't take any parameters, so if you're just immediately going to call
+    /// [`export()`][crate::interface::export::MazeExporter::export], you may wish to use
+    /// [`DefaultMazeExporter::export()`][crate::interface::export::DefaultMazeExporter::export]
+    /// instead.
+    ///
+    /// Equivalent to [`Self::default()`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Return the character that represents an edge, given the character that would be drawn if it
+    /// were a wall.
+    #[must_use]
+    fn edge_char(edge: InlineCellValueEdge, wall_char: char) -> char {
+        match edge {
+            InlineCellValueEdge::PASSAGE => ' ',
+            InlineCellValueEdge::WALL | InlineCellValueEdge::BOUNDARY | InlineCellValueEdge::UNVISITED => wall_char,
+        }
+    }
+
+    /// Return the character that marks the passages of `value` to the floors above and below, if
+    /// it has any.
+    #[must_use]
+    fn connector_char(value: InlineCellValue<3>) -> char {
+        match (value.edges[2][1] == InlineCellValueEdge::PASSAGE, value.edges[2][0] == InlineCellValueEdge::PASSAGE) {
+            (true, true)   => 'X',
+            (true, false)  => '^',
+            (false, true)  => 'v',
+            (false, false) => ' ',
+        }
+    }
+}
+
+impl Default for BoxSpaceInlineCellIsometricTextMazeExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<3>>, Output: Write> MazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, 3>, Output> for BoxSpaceInlineCellIsometricTextMazeExporter {
+    fn export(&self, maze: &BoxSpaceInlineCellMazeCoordinator<Buffer, 3>, output: &mut Output) -> Result<()> {
+        let mut output = BufWriter::new(output);
+
+        let [width, height, depth] = maze.coord_space().dimensions().map(usize::from);
+
+        // Each cell is 3 characters wide and 2 lines tall, plus one more of each for the far walls
+        let floor_width = 3 * width + 1;
+        let floor_height = 2 * height + 1;
+
+        for z in (0..depth).rev() {
+            if z < depth - 1 {
+                output.write_line_break()?;
+            }
+
+            let mut canvas = vec![vec![' '; floor_width]; floor_height];
+
+            for pt in maze.coord_space().iter().filter(|pt| pt[2] == z) {
+                let value = maze.get(pt);
+
+                let x = 3 * pt[0];
+                let y = 2 * pt[1];
+
+                for [corner_x, corner_y] in [[x, y], [x + 3, y], [x, y + 2], [x + 3, y + 2]] {
+                    canvas[corner_y][corner_x] = '+';
+                }
+
+                let top = Self::edge_char(value.edges[1][0], '-');
+                let bottom = Self::edge_char(value.edges[1][1], '-');
+
+                // Shared edges are drawn twice, once by each neighbour, but they always agree
+                canvas[y][x + 1]     = top;
+                canvas[y][x + 2]     = top;
+                canvas[y + 2][x + 1] = bottom;
+                canvas[y + 2][x + 2] = bottom;
+                canvas[y + 1][x]     = Self::edge_char(value.edges[0][0], '/');
+                canvas[y + 1][x + 3] = Self::edge_char(value.edges[0][1], '/');
+                canvas[y + 1][x + 2] = Self::connector_char(value);
+            }
+
+            // Slant the floor by shifting each line one character further right than the line below
+            for (line_index, line) in canvas.into_iter().enumerate() {
+                let line = " ".repeat(floor_height - 1 - line_index) + &line.into_iter().collect::<String>();
+
+                output.write_all(line.trim_end().as_bytes())?;
+                output.write_line_break()?;
+            }
+        }
+
+        return output.flush()
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<3>>, Output: Write> TextMazeExporter<BoxSpaceInlineCellMazeCoordinator<Buffer, 3>, Output> for BoxSpaceInlineCellIsometricTextMazeExporter {}
\ No newline at end of file
@@ -0,0 +1,143 @@
+use std::io::{Result, Write};
+
+use crate::internal::util::get_line_sep;
+
+/// The characters that text exporters end each line with.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum LineBreak {
+    /// The platform's native line break: `\r\n` on Windows and `\n` everywhere else.
+    #[default]
+    Native,
+    /// `\n`.
+    Lf,
+    /// `\r\n`.
+    CrLf,
+    /// Nothing. Lines are joined together.
+    None,
+}
+
+impl LineBreak {
+    /// Return the characters that end each line.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Native => get_line_sep(),
+            Self::Lf     => "\n",
+            Self::CrLf   => "\r\n",
+            Self::None   => "",
+        }
+    }
+}
+
+/// What text exporters do with lines that are longer than their maximum width.
+///
+/// Either way the indicator takes the last column of each line that was cut short, so no line is
+/// ever longer than the maximum width.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Overflow {
+    /// Continue the line on the next one, ending each cut line with the indicator.
+    Wrap(char),
+    /// Drop the rest of the line, ending it with the indicator.
+    Truncate(char),
+}
+
+/// A writer that lays out the lines written to it, ending each with a [`LineBreak`] and making
+/// sure it isn't longer than a maximum width.
+///
+/// Lines are separated by `\n`, optionally preceded by `\r`. Any line left unfinished is written
+/// without a line break when the writer is flushed.
+pub(super) struct LayoutWriter<W: Write> {
+    /// The writer to write the laid out lines to.
+    inner: W,
+    /// The characters to end each line with.
+    line_break: LineBreak,
+    /// The maximum width of a line in characters, and what to do with lines longer than it.
+    max_width: Option<(usize, Overflow)>,
+    /// The line being written.
+    line: Vec<u8>,
+}
+
+impl <W: Write> LayoutWriter<W> {
+    pub(super) fn new(inner: W, line_break: LineBreak, max_width: Option<(usize, Overflow)>) -> Self {
+        Self { inner, line_break, max_width, line: Vec::new() }
+    }
+
+    /// Write out the current line, followed by a line break if `is_finished`.
+    fn write_line(&mut self, is_finished: bool) -> Result<()> {
+        if self.line.last() == Some(&b'\r') {
+            self.line.pop();
+        }
+
+        let line_break = if is_finished { self.line_break.as_str() } else { "" };
+
+        let (width, overflow) = match self.max_width {
+            Some((width, overflow)) if String::from_utf8_lossy(&self.line).chars().count() > width => (width, overflow),
+            _ => {
+                self.inner.write_all(&self.line)?;
+                self.inner.write_all(line_break.as_bytes())?;
+
+                self.line.clear();
+
+                return Ok(())
+            }
+        };
+
+        let line = String::from_utf8_lossy(&self.line).into_owned();
+        let mut chars = line.chars();
+
+        let mut buffer = String::new();
+
+        loop {
+            buffer.clear();
+            buffer.extend(chars.by_ref().take(width - 1));
+
+            let rest = chars.as_str();
+
+            match overflow {
+                _ if rest.chars().count() <= 1 => {
+                    buffer.push_str(rest);
+                    self.inner.write_all(buffer.as_bytes())?;
+                    self.inner.write_all(line_break.as_bytes())?;
+                    break
+                },
+                Overflow::Wrap(indicator) => {
+                    buffer.push(indicator);
+                    self.inner.write_all(buffer.as_bytes())?;
+                    self.inner.write_all(self.line_break.as_str().as_bytes())?;
+                },
+                Overflow::Truncate(indicator) => {
+                    buffer.push(indicator);
+                    self.inner.write_all(buffer.as_bytes())?;
+                    self.inner.write_all(line_break.as_bytes())?;
+                    break
+                },
+            }
+        }
+
+        self.line.clear();
+
+        return Ok(())
+    }
+}
+
+impl <W: Write> Write for LayoutWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        for (i, line) in buf.split(|byte| *byte == b'\n').enumerate() {
+            if i > 0 {
+                self.write_line(true)?;
+            }
+
+            self.line.extend_from_slice(line);
+        }
+
+        return Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if self.line.is_empty() == false {
+            self.write_line(false)?;
+        }
+
+        return self.inner.flush()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+use std::io::{BufWriter, Result, Write};
+
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::mobius::MobiusSpaceInlineCellMazeCoordinator;
+use crate::implm::export::text::{BoxSpaceInlineCellTextMazeExporter, TextMazeExporter};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+
+/// An exporter that renders [`MobiusSpaceInlineCellMazeCoordinator`]s to text.
+///
+/// The strip is cut along its join and unrolled into a rectangle, which is then drawn the same way
+/// as a [`BoxSpaceInlineCellTextMazeExporter`] would draw it. Passages across the join are drawn
+/// as gaps in the left and right walls. Because of the half-twist, the gaps on the left side are in
+/// the mirrored rows of the gaps on the right side (i.e. a gap in the top row on the right
+/// corresponds to a gap in the bottom row on the left).
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::mobius::MobiusSpaceInlineCellMazeCoordinator;
+/// use mazelib::implm::export::text::MobiusSpaceInlineCellTextMazeExporter;
+/// use mazelib::implm::point::mobius::MobiusCoordinateSpace;
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::export::DefaultMazeExporter;
+///
+/// let mut maze = MobiusSpaceInlineCellMazeCoordinator::<VecBuffer<InlineCellValue<2>>>::builder(MobiusCoordinateSpace::new_checked([3, 2])).build();
+///
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+/// maze.make_passage_between([1, 0].into(), [2, 0].into());
+/// maze.make_passage_between([2, 0].into(), [0, 1].into());  // Across the join
+/// maze.make_passage_between([0, 1].into(), [1, 1].into());
+/// maze.make_passage_between([1, 1].into(), [2, 1].into());
+///
+/// let mut text = Vec::new();
+/// MobiusSpaceInlineCellTextMazeExporter::export(&maze, &mut text).unwrap();
+///
+/// assert_eq!("┌───────────╴
+/// │           
+/// └───────────┐
+///             │
+/// ╶───────────┘
+/// ", String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+/// ```
+pub struct MobiusSpaceInlineCellTextMazeExporter {
+    /// The exporter used to draw the unrolled strip.
+    unrolled_exporter: BoxSpaceInlineCellTextMazeExporter,
+}
+
+impl MobiusSpaceInlineCellTextMazeExporter {
+    /// Construct a new instance of this exporter.
+    ///
+    /// # Parameters
+    ///
+    /// `unrolled_exporter` --- the exporter to draw the unrolled strip with. Its settings (such as
+    ///                         the number of characters per cell) are used as-is.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::default()`], which uses the default [`BoxSpaceInlineCellTextMazeExporter`].
+    #[must_use]
+    pub fn new(unrolled_exporter: BoxSpaceInlineCellTextMazeExporter) -> Self {
+        Self { unrolled_exporter }
+    }
+
+    /// Return the exporter used to draw the unrolled strip.
+    #[must_use]
+    pub fn unrolled_exporter(&self) -> &BoxSpaceInlineCellTextMazeExporter {
+        &self.unrolled_exporter
+    }
+}
+
+impl Default for MobiusSpaceInlineCellTextMazeExporter {
+    fn default() -> Self {
+        Self::new(BoxSpaceInlineCellTextMazeExporter::default())
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<2>>, Output: Write> MazeExporter<MobiusSpaceInlineCellMazeCoordinator<Buffer>, Output> for MobiusSpaceInlineCellTextMazeExporter {
+    fn export(&self, maze: &MobiusSpaceInlineCellMazeCoordinator<Buffer>, output: &mut Output) -> Result<()> {
+        let dimensions = maze.coord_space().dimensions().map(usize::from);
+
+        let mut output = BufWriter::new(output);
+
+        self.unrolled_exporter.export_grid(dimensions, |x, y| maze.get([x, y].into()), &mut output)?;
+
+        return output.flush()
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<2>>, Output: Write> TextMazeExporter<MobiusSpaceInlineCellMazeCoordinator<Buffer>, Output> for MobiusSpaceInlineCellTextMazeExporter {}
\ No newline at end of file
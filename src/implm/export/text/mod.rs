@@ -6,16 +6,75 @@ use crate::implm::point::boxy::BoxCoordinateSpace;
 use crate::interface::coordinate::MazeCoordinator;
 use crate::interface::export::MazeExporter;
 
-pub use self::block::{BoxSpaceBlockCellTextMazeExporter, BoxSpaceBlockCellTextMazeExporterBuilder};
-pub use self::inline::{BoxSpaceInlineCellTextMazeExporter, BoxSpaceInlineCellTextMazeExporterBuilder};
+#[cfg(any(feature = "animate", doc))]
+pub use self::animation::{TerminalAnimation, TerminalAnimationBuilder};
+pub use self::block::{BlockCellTextTheme, BoxSpaceBlockCellTextMazeExporter, BoxSpaceBlockCellTextMazeExporterBuilder};
+pub use self::cube::CubeSpaceCubeCellTextMazeExporter;
+pub use self::delta::DeltaSpaceDeltaCellTextMazeExporter;
+pub use self::hex::HexSpaceHexCellTextMazeExporter;
+pub use self::inline::{BoxSpaceInlineCellTextMazeExporter, BoxSpaceInlineCellTextMazeExporterBuilder, InlineCellTextTheme};
+pub use self::isometric::BoxSpaceInlineCellIsometricTextMazeExporter;
+pub use self::layout::{LineBreak, Overflow};
+pub use self::mobius::MobiusSpaceInlineCellTextMazeExporter;
 
+mod animation;
 mod block;
+mod cube;
+mod delta;
+mod hex;
 mod inline;
+mod isometric;
+mod layout;
+mod mobius;
 
 /// Export a 2D maze to text.
 ///
 /// The output may contain any UTF-8 codepoint, not just ASCII.
 pub trait TextMazeExporter<M: MazeCoordinator, O: Write> : MazeExporter<M, O> {}
 
+/// Simple sugar for exporting a maze to a [`String`].
+///
+/// Implemented for every [`TextMazeExporter`]. Saves setting up the output yourself, and checking
+/// that it is valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::export::text::{BoxSpaceBlockCellTextMazeExporter, ExportToString, LineBreak};
+///
+/// let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([1, 1])).build();
+///
+/// let text = BoxSpaceBlockCellTextMazeExporter::builder().line_break(LineBreak::Lf).build().export_to_string(&maze).unwrap();
+///
+/// assert_eq!("......\n......\n......\n", text);
+/// ```
+pub trait ExportToString<M: MazeCoordinator> {
+    /// Export the maze `maze` to a new `String`.
+    ///
+    /// Returns an error if the exporter encountered one. Nothing can go wrong writing to memory,
+    /// but exporters may still report errors of their own.
+    ///
+    /// *See [`MazeExporter::export()`].*
+    ///
+    /// # Panics
+    ///
+    /// If the exporter doesn't produce valid UTF-8, which is a bug in the exporter.
+    fn export_to_string(&self, maze: &M) -> std::io::Result<String>;
+}
+
+impl <M: MazeCoordinator, T: TextMazeExporter<M, Vec<u8>> + ?Sized> ExportToString<M> for T {
+    fn export_to_string(&self, maze: &M) -> std::io::Result<String> {
+        let mut output = Vec::new();
+
+        self.export(maze, &mut output)?;
+
+        return Ok(String::from_utf8(output).expect("[Bug] Text exporter did not produce valid UTF-8"))
+    }
+}
+
 /// A [`TextMazeExporter`] for 2D mazes that use [`BoxCoordinateSpace`]s.
 pub trait BoxSpaceTextMazeExporter<M: MazeCoordinator<CoordSpace=BoxCoordinateSpace<2>>, O: Write> : TextMazeExporter<M, O> {}
\ No newline at end of file
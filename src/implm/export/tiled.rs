@@ -0,0 +1,429 @@
+//! Splitting large mazes over multiple pages.
+//!
+//! Wall-sized mazes are too large to print on any one sheet of paper. A
+//! [`BoxSpaceBlockCellTiledMazeExporter`] cuts a maze up into a grid of smaller *tiles* (pages),
+//! and then exports each tile separately using any other exporter.
+//!
+//! Tiles are labelled like spreadsheet cells: the row is a letter and the column is a number,
+//! starting from `A1` in the top-left corner. Adjacent tiles may share a strip of overlapping
+//! cells to make lining up the printed pages easier.
+//!
+//! The page exporter only sees the tile's cells, so it can't draw the label itself. Each label is
+//! given to the function that opens the page's output instead, which can use it to name the file
+//! or write the label onto the page (see
+//! [`export_tiles()`][BoxSpaceBlockCellTiledMazeExporter::export_tiles]).
+
+use std::fmt::{Debug, Display, Formatter};
+use std::io::{Error, ErrorKind, Result, Write};
+use std::num::NonZeroUsize;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::export::MazeExporter;
+use crate::internal::util::{nonzero_usize_array_to_usize_array, NONZERO_USIZE_ONE};
+
+/// The maze type that each tile is extracted into before being handed to the page exporter.
+///
+/// Tiles are taken from the *cell* space of the original maze, so each cell of the original maze
+/// becomes one point of the tile (with a scale factor of 1 and no padding).
+pub type BoxSpaceBlockCellTile = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+/// An exporter that splits a 2D [`BoxSpaceBlockCellMazeCoordinator`] into an R×C grid of pages,
+/// exporting each one with another exporter.
+///
+/// # Examples
+///
+/// Printing a giant maze over 3×4 sheets of paper:
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::BufWriter;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+/// use mazelib::implm::export::tiled::BoxSpaceBlockCellTiledMazeExporter;
+/// #
+/// # fn example() -> std::io::Result<()> {
+/// # let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([200, 200])).build();
+///
+/// let exporter = BoxSpaceBlockCellTiledMazeExporter::builder(BoxSpaceBlockCellTextMazeExporter::default())
+///     .rows_checked(3)
+///     .columns_checked(4)
+///     .overlap(2)
+///     .build();
+///
+/// // Writes maze-A1.txt, maze-A2.txt, ..., maze-C4.txt
+/// exporter.export_tiles(&maze, |tile| Ok(BufWriter::new(File::create(format!("maze-{}.txt", tile.label()))?)))?;
+/// #
+/// # return Ok(());
+/// # }
+/// ```
+pub struct BoxSpaceBlockCellTiledMazeExporter<Exporter> {
+    /// The exporter used for each individual page.
+    exporter: Exporter,
+    /// The number of rows of pages.
+    rows: NonZeroUsize,
+    /// The number of columns of pages.
+    columns: NonZeroUsize,
+    /// The number of cells each page shares with the page after it (to the right or below).
+    overlap: usize,
+}
+
+impl <Exporter> BoxSpaceBlockCellTiledMazeExporter<Exporter> {
+    /// Construct a new builder for a `BoxSpaceBlockCellTiledMazeExporter`.
+    ///
+    /// # Parameters
+    ///
+    /// `exporter` --- the exporter to use for each individual page.
+    pub fn builder(exporter: Exporter) -> BoxSpaceBlockCellTiledMazeExporterBuilder<Exporter> {
+        BoxSpaceBlockCellTiledMazeExporterBuilder::new(exporter)
+    }
+
+    /// Return the exporter used for each individual page.
+    #[must_use]
+    pub fn exporter(&self) -> &Exporter {
+        &self.exporter
+    }
+
+    /// Return the number of rows of pages.
+    #[must_use]
+    pub fn rows(&self) -> NonZeroUsize {
+        self.rows
+    }
+
+    /// Return the number of columns of pages.
+    #[must_use]
+    pub fn columns(&self) -> NonZeroUsize {
+        self.columns
+    }
+
+    /// Return the number of cells each page shares with the page after it.
+    #[must_use]
+    pub fn overlap(&self) -> usize {
+        self.overlap
+    }
+
+    /// Compute the layout of the pages for a given maze, without exporting anything.
+    ///
+    /// Tiles are returned in reading order (`A1`, `A2`, ..., `B1`, ...).
+    ///
+    /// # Errors
+    ///
+    /// If the maze has fewer cells along an axis than there are pages along that axis, an error
+    /// with the kind [`InvalidInput`][ErrorKind::InvalidInput] is returned.
+    pub fn tiles<Buffer: MazeBuffer<BlockCellValue>>(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>) -> Result<Vec<Tile>> {
+        let [width, height] = nonzero_usize_array_to_usize_array(maze.get_full_dimensions());
+
+        let column_spans = Self::split_axis(width, usize::from(self.columns), self.overlap)?;
+        let row_spans = Self::split_axis(height, usize::from(self.rows), self.overlap)?;
+
+        let mut tiles = Vec::with_capacity(column_spans.len() * row_spans.len());
+
+        for (row, &(y, height)) in row_spans.iter().enumerate() {
+            for (column, &(x, width)) in column_spans.iter().enumerate() {
+                tiles.push(Tile {
+                    label: TileLabel { row, column },
+                    origin: [x, y].into(),
+                    dimensions: [width, height],
+                });
+            }
+        }
+
+        return Ok(tiles)
+    }
+
+    /// Copy the cells covered by `tile` out of `maze` into a new, standalone maze.
+    ///
+    /// The resulting maze has a scale factor of 1 and no padding, so its points are exactly the
+    /// cells of the tile.
+    #[must_use]
+    pub fn extract_tile<Buffer: MazeBuffer<BlockCellValue>>(maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, tile: &Tile) -> BoxSpaceBlockCellTile {
+        let mut page = BoxSpaceBlockCellTile::builder(BoxCoordinateSpace::new(tile.dimensions))
+            .scale_factors([NONZERO_USIZE_ONE; 2])
+            .padding([[0, 0]; 2])
+            .build();
+
+        let [width, height] = nonzero_usize_array_to_usize_array(tile.dimensions);
+
+        for y in 0..height {
+            for x in 0..width {
+                let value = maze.get_cell_value([tile.origin[0] + x, tile.origin[1] + y].into());
+
+                *page.get_cell_value_mut([x, y].into()) = value;
+            }
+        }
+
+        return page
+    }
+
+    /// Export every page of the maze.
+    ///
+    /// # Parameters
+    ///
+    /// `maze`        --- the maze to split up and export.
+    /// `open_output` --- called once per page (in reading order) to obtain the writer that page
+    ///                   should be exported to. Typically this would create a file named after
+    ///                   the tile's [label][Tile::label].
+    ///
+    /// The page exporter doesn't draw the tile's label. To print it on the page, write it to the
+    /// output in `open_output`, before the page is exported to it.
+    ///
+    /// # Errors
+    ///
+    /// If the maze can't be split into the requested number of pages (see [`Self::tiles()`]).
+    /// Otherwise, returns the first IO error encountered, either from `open_output` or from the
+    /// page exporter. Pages before the error will have already been written.
+    ///
+    /// # Examples
+    ///
+    /// Labelling each page of a text export in its first line:
+    /// ```
+    /// use std::io::Write;
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// use mazelib::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+    /// use mazelib::implm::export::tiled::BoxSpaceBlockCellTiledMazeExporter;
+    /// #
+    /// # let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([10, 10])).build();
+    ///
+    /// let exporter = BoxSpaceBlockCellTiledMazeExporter::builder(BoxSpaceBlockCellTextMazeExporter::default())
+    ///     .columns_checked(2)
+    ///     .build();
+    ///
+    /// let mut pages = vec![Vec::new(); 2];
+    /// let mut outputs = pages.iter_mut();
+    ///
+    /// exporter.export_tiles(&maze, |tile| {
+    ///     let page = outputs.next().unwrap();
+    ///     writeln!(page, "Page {}", tile.label())?;
+    ///
+    ///     Ok(page)
+    /// }).unwrap();
+    ///
+    /// assert!(pages[1].starts_with(b"Page A2"));
+    /// ```
+    pub fn export_tiles<Buffer, Output, F>(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, mut open_output: F) -> Result<()>
+        where Buffer: MazeBuffer<BlockCellValue>,
+              Output: Write,
+              Exporter: MazeExporter<BoxSpaceBlockCellTile, Output>,
+              F: FnMut(&Tile) -> Result<Output> {
+        for tile in self.tiles(maze)? {
+            let page = Self::extract_tile(maze, &tile);
+
+            let mut output = open_output(&tile)?;
+
+            self.exporter.export(&page, &mut output)?;
+            output.flush()?;
+        }
+
+        return Ok(())
+    }
+
+    /// Divide an axis of length `length` into `count` near-equal spans, each extended by `overlap`
+    /// (except the last).
+    ///
+    /// Returns `(start, length)` pairs.
+    fn split_axis(length: usize, count: usize, overlap: usize) -> Result<Vec<(usize, NonZeroUsize)>> {
+        if count > length {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("Cannot split {} cells into {} pages", length, count)));
+        }
+
+        return Ok((0..count).map(|i| {
+            let start = i * length / count;
+            let end = if i + 1 == count { length } else { ((i + 1) * length / count + overlap).min(length) };
+
+            (start, NonZeroUsize::new(end - start).expect("[Bug] Empty tile"))
+        }).collect())
+    }
+}
+
+/// A builder for a [`BoxSpaceBlockCellTiledMazeExporter`].
+#[must_use]
+pub struct BoxSpaceBlockCellTiledMazeExporterBuilder<Exporter> {
+    /// The exporter used for each individual page.
+    exporter: Exporter,
+    /// The number of rows of pages.
+    rows: NonZeroUsize,
+    /// The number of columns of pages.
+    columns: NonZeroUsize,
+    /// The number of cells each page shares with the page after it (to the right or below).
+    overlap: usize,
+}
+
+impl <Exporter> BoxSpaceBlockCellTiledMazeExporterBuilder<Exporter> {
+    /// Create a new builder for a [`BoxSpaceBlockCellTiledMazeExporter`].
+    ///
+    /// Defaults to a single page with no overlap.
+    fn new(exporter: Exporter) -> Self {
+        Self {
+            exporter,
+            rows: NONZERO_USIZE_ONE,
+            columns: NONZERO_USIZE_ONE,
+            overlap: 0,
+        }
+    }
+
+    /// Set the number of rows of pages.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::rows_checked()`]. If you're using integer literals, you may wish to use this
+    /// instead.
+    pub fn rows(mut self, rows: NonZeroUsize) -> Self {
+        self.rows = rows;
+
+        return self
+    }
+
+    /// Set the number of rows of pages.
+    ///
+    /// `rows` must be non-zero.
+    ///
+    /// # Panics
+    ///
+    /// If `rows` is zero.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::rows()`], which takes a `NonZeroUsize`.
+    pub fn rows_checked(self, rows: usize) -> Self {
+        self.rows(NonZeroUsize::new(rows).expect("rows was zero"))
+    }
+
+    /// Set the number of columns of pages.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::columns_checked()`]. If you're using integer literals, you may wish to use this
+    /// instead.
+    pub fn columns(mut self, columns: NonZeroUsize) -> Self {
+        self.columns = columns;
+
+        return self
+    }
+
+    /// Set the number of columns of pages.
+    ///
+    /// `columns` must be non-zero.
+    ///
+    /// # Panics
+    ///
+    /// If `columns` is zero.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::columns()`], which takes a `NonZeroUsize`.
+    pub fn columns_checked(self, columns: usize) -> Self {
+        self.columns(NonZeroUsize::new(columns).expect("columns was zero"))
+    }
+
+    /// Set the number of cells each page shares with the page after it (to the right or below).
+    ///
+    /// Overlapping cells are printed on both pages, which makes it easier to line them up.
+    pub fn overlap(mut self, overlap: usize) -> Self {
+        self.overlap = overlap;
+
+        return self
+    }
+
+    /// Finalise the [`BoxSpaceBlockCellTiledMazeExporter`].
+    #[must_use]
+    pub fn build(self) -> BoxSpaceBlockCellTiledMazeExporter<Exporter> {
+        BoxSpaceBlockCellTiledMazeExporter {
+            exporter: self.exporter,
+            rows: self.rows,
+            columns: self.columns,
+            overlap: self.overlap,
+        }
+    }
+}
+
+/// One page of a tiled export.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Tile {
+    /// The page's label.
+    label: TileLabel,
+    /// The location of the top-left cell of this tile in the original maze.
+    origin: BlockCellLocation<2>,
+    /// The width and height of the tile, in cells.
+    dimensions: [NonZeroUsize; 2],
+}
+
+impl Tile {
+    /// The page's label, e.g. `B3`.
+    #[must_use]
+    pub fn label(&self) -> TileLabel {
+        self.label
+    }
+
+    /// The location of the top-left cell of this tile in the original maze.
+    #[must_use]
+    pub fn origin(&self) -> BlockCellLocation<2> {
+        self.origin
+    }
+
+    /// The width and height of the tile, in cells.
+    #[must_use]
+    pub fn dimensions(&self) -> [NonZeroUsize; 2] {
+        self.dimensions
+    }
+}
+
+/// The label of a page in a tiled export.
+///
+/// Displayed like a spreadsheet cell reference: rows are lettered (`A`, `B`, ..., `Z`, `AA`, ...)
+/// and columns are numbered from 1. So the top-left page is `A1`, and the page to its right is
+/// `A2`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TileLabel {
+    /// The zero-based row of the page.
+    row: usize,
+    /// The zero-based column of the page.
+    column: usize,
+}
+
+impl TileLabel {
+    /// The zero-based row of the page.
+    #[must_use]
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// The zero-based column of the page.
+    #[must_use]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for TileLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Bijective base-26, like spreadsheet columns
+        let mut letters = Vec::new();
+        let mut remaining = self.row + 1;
+
+        while remaining > 0 {
+            remaining -= 1;
+            letters.push(char::from(b'A' + (remaining % 26) as u8));
+            remaining /= 26;
+        }
+
+        for letter in letters.iter().rev() {
+            write!(f, "{}", letter)?;
+        }
+
+        return write!(f, "{}", self.column + 1)
+    }
+}
+
+impl Debug for TileLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TileLabel({})", self)
+    }
+}
@@ -0,0 +1,266 @@
+//! Exporting mazes as [MagicaVoxel](https://ephtracy.github.io) `.vox` models.
+
+use std::io::{Result, Write};
+
+use crate::implm::cell::block::{BlockCellLocation, BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::export::MazeExporter;
+
+/// The largest number of voxels a `.vox` model can have along each axis.
+const MAX_SIZE: usize = 256;
+
+/// The palette index of wall voxels.
+const WALL: u8 = 1;
+/// The palette index of boundary voxels.
+const BOUNDARY: u8 = 2;
+/// The palette index of floor voxels.
+const FLOOR: u8 = 3;
+
+/// An exporter that writes [block cell mazes][BoxSpaceBlockCellMazeCoordinator] as MagicaVoxel
+/// `.vox` models, for decorating by hand.
+///
+/// Every cell becomes a voxel. Walls and boundaries are solid, and passages and unvisited cells are
+/// empty. Two-dimensional mazes are extruded upwards by the [wall height][VoxMazeExporterBuilder::wall_height],
+/// and can be given a [floor][VoxMazeExporterBuilder::floor] under their passages. In
+/// three-dimensional mazes each layer is stacked on top of the last.
+///
+/// The palette is small: walls, boundaries, and floors each have their own colour (palette indices
+/// 1, 2, and 3), so they are easy to select and recolour in MagicaVoxel.
+///
+/// The model is Z-up, as in MagicaVoxel. The maze's first axis runs along X, its second along -Y
+/// (so that the maze isn't mirrored when seen from above), and its third (if it has one) up along
+/// Z.
+///
+/// # Panics
+///
+/// Exporting panics if the model would be larger than 256 voxels along any axis, as that is the
+/// most a `.vox` model can hold.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::generate::HuntAndKillGenerator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::export::MazeExporter;
+/// # use mazelib::interface::generate::DefaultMazeGenerator;
+/// use mazelib::implm::export::vox::VoxMazeExporter;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([8, 8])).build();
+/// HuntAndKillGenerator::generate(&mut maze);
+///
+/// let mut vox = Vec::new();
+/// VoxMazeExporter::builder().wall_height(3).floor(true).build().export(&maze, &mut vox).unwrap();
+///
+/// assert!(vox.starts_with(b"VOX "));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct VoxMazeExporter {
+    /// The height of the walls of two-dimensional mazes, in voxels.
+    wall_height: usize,
+    /// Whether two-dimensional mazes have a floor under their passages.
+    floor: bool,
+    /// The colours of walls, boundaries, and floors, as RGBA.
+    palette: [[u8; 4]; 3],
+}
+
+impl VoxMazeExporter {
+    /// Construct a new builder for a `VoxMazeExporter`.
+    pub fn builder() -> VoxMazeExporterBuilder {
+        VoxMazeExporterBuilder::new()
+    }
+
+    /// Return the height of the walls of two-dimensional mazes, in voxels.
+    #[must_use]
+    pub fn wall_height(&self) -> usize {
+        self.wall_height
+    }
+
+    /// Return whether two-dimensional mazes have a floor under their passages.
+    #[must_use]
+    pub fn floor(&self) -> bool {
+        self.floor
+    }
+
+    /// Return the colours of walls, boundaries, and floors, in that order, as RGBA.
+    #[must_use]
+    pub fn palette(&self) -> [[u8; 4]; 3] {
+        self.palette
+    }
+
+    /// Write a model of the given size, made of the given voxels (`[x, y, z, palette index]`).
+    fn write_vox(&self, size: [usize; 3], voxels: &[[u8; 4]], output: &mut impl Write) -> Result<()> {
+        assert!(size.iter().all(|length| *length <= MAX_SIZE), "Cannot export mazes larger than {} voxels along any axis", MAX_SIZE);
+
+        let mut size_chunk = Vec::new();
+
+        for length in size {
+            size_chunk.extend(chunk_length(length).to_le_bytes());
+        }
+
+        let mut xyzi_chunk = chunk_length(voxels.len()).to_le_bytes().to_vec();
+        xyzi_chunk.extend(voxels.iter().flatten());
+
+        // Palette entry `i` is colour index `i + 1`. The rest of the palette is left black.
+        let mut rgba_chunk = vec![0; 256 * 4];
+
+        for (i, colour) in self.palette.iter().enumerate() {
+            rgba_chunk[i * 4..i * 4 + 4].copy_from_slice(colour);
+        }
+
+        let mut children = Vec::new();
+
+        for (id, content) in [(b"SIZE", size_chunk), (b"XYZI", xyzi_chunk), (b"RGBA", rgba_chunk)] {
+            children.extend(id);
+            children.extend(chunk_length(content.len()).to_le_bytes());
+            children.extend(0u32.to_le_bytes());
+            children.extend(content);
+        }
+
+        output.write_all(b"VOX ")?;
+        output.write_all(&150u32.to_le_bytes())?;
+
+        output.write_all(b"MAIN")?;
+        output.write_all(&0u32.to_le_bytes())?;
+        output.write_all(&chunk_length(children.len()).to_le_bytes())?;
+        output.write_all(&children)?;
+
+        return Ok(())
+    }
+}
+
+impl Default for VoxMazeExporter {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// A builder for a [`VoxMazeExporter`].
+#[must_use]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct VoxMazeExporterBuilder {
+    /// The height of the walls of two-dimensional mazes, in voxels.
+    wall_height: usize,
+    /// Whether two-dimensional mazes have a floor under their passages.
+    floor: bool,
+    /// The colours of walls, boundaries, and floors, as RGBA.
+    palette: [[u8; 4]; 3],
+}
+
+impl VoxMazeExporterBuilder {
+    /// Create a new builder for a [`VoxMazeExporter`].
+    fn new() -> Self {
+        Self {
+            wall_height: 1,
+            floor: false,
+            palette: [[200, 200, 200, 255], [60, 60, 60, 255], [120, 100, 80, 255]],
+        }
+    }
+
+    /// Set the height of the walls of two-dimensional mazes, in voxels. Defaults to 1.
+    ///
+    /// # Panics
+    ///
+    /// If `wall_height` is zero.
+    pub fn wall_height(mut self, wall_height: usize) -> Self {
+        assert!(wall_height > 0, "The wall height must be non-zero");
+
+        self.wall_height = wall_height;
+
+        return self
+    }
+
+    /// Set whether two-dimensional mazes have a floor under their passages. The floor is a single
+    /// layer of voxels at the bottom of the model, level with the bottom of the walls. Defaults to
+    /// `false`.
+    pub fn floor(mut self, floor: bool) -> Self {
+        self.floor = floor;
+
+        return self
+    }
+
+    /// Set the colours of walls, boundaries, and floors, as RGBA. Defaults to light grey, dark grey,
+    /// and brown.
+    pub fn palette(mut self, wall_colour: [u8; 4], boundary_colour: [u8; 4], floor_colour: [u8; 4]) -> Self {
+        self.palette = [wall_colour, boundary_colour, floor_colour];
+
+        return self
+    }
+
+    /// Finalise the [`VoxMazeExporter`].
+    #[must_use]
+    pub fn build(self) -> VoxMazeExporter {
+        VoxMazeExporter {
+            wall_height: self.wall_height,
+            floor: self.floor,
+            palette: self.palette,
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Output> for VoxMazeExporter {
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, output: &mut Output) -> Result<()> {
+        let [width, depth] = maze.get_full_dimensions().map(usize::from);
+        let size = [width, depth, self.wall_height];
+
+        let mut voxels = Vec::new();
+
+        if size.iter().all(|length| *length <= MAX_SIZE) {
+            for y in 0..depth {
+                for x in 0..width {
+                    let position = [x, depth - 1 - y];
+
+                    match palette_index(maze.get_cell_value(BlockCellLocation([x, y].into()))) {
+                        Some(index) => voxels.extend((0..self.wall_height).map(|z| [position[0] as u8, position[1] as u8, z as u8, index])),
+                        None if self.floor && maze.get_cell_value(BlockCellLocation([x, y].into())).cell_type == BlockCellPrimaryValue::PASSAGE => {
+                            voxels.push([position[0] as u8, position[1] as u8, 0, FLOOR]);
+                        },
+                        None => {},
+                    }
+                }
+            }
+        }
+
+        return self.write_vox(size, &voxels, output)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Output: Write> MazeExporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, Output> for VoxMazeExporter {
+    fn export(&self, maze: &BoxSpaceBlockCellMazeCoordinator<Buffer, 3>, output: &mut Output) -> Result<()> {
+        let [width, depth, height] = maze.get_full_dimensions().map(usize::from);
+        let size = [width, depth, height];
+
+        let mut voxels = Vec::new();
+
+        if size.iter().all(|length| *length <= MAX_SIZE) {
+            for z in 0..height {
+                for y in 0..depth {
+                    for x in 0..width {
+                        if let Some(index) = palette_index(maze.get_cell_value(BlockCellLocation([x, y, z].into()))) {
+                            voxels.push([x as u8, (depth - 1 - y) as u8, z as u8, index]);
+                        }
+                    }
+                }
+            }
+        }
+
+        return self.write_vox(size, &voxels, output)
+    }
+}
+
+/// Return the palette index of a cell's voxel, or `None` if it is empty.
+fn palette_index(value: BlockCellValue) -> Option<u8> {
+    match value.cell_type {
+        BlockCellPrimaryValue::WALL => Some(WALL),
+        BlockCellPrimaryValue::BOUNDARY => Some(BOUNDARY),
+        BlockCellPrimaryValue::PASSAGE | BlockCellPrimaryValue::UNVISITED => None,
+    }
+}
+
+/// Convert a length to the `u32` it's stored as.
+fn chunk_length(length: usize) -> u32 {
+    u32::try_from(length).expect("The maze is too large to export")
+}
\ No newline at end of file
@@ -0,0 +1,242 @@
+use std::collections::{HashMap, VecDeque};
+
+use rand::Rng;
+
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::connect_components;
+
+/// The **Cave** algorithm is a cellular automaton that produces organic, open caverns instead of
+/// corridors.
+///
+/// Every point starts out open or closed at random, according to the [fill][Self::fill]. The
+/// layout is then smoothed a number of times. Each time, every point looks at the points within
+/// two steps of it, and becomes open if most of them are open, or closed if most of them are
+/// closed (a tie leaves it as it is). This clumps the points together into rounded caverns.
+/// Caverns smaller than the [minimum region size][Self::min_region_size] are then filled in (the
+/// largest is always kept), and the rest are joined together with
+/// [`connect_components()`][crate::util::connect_components()].
+///
+/// Open points become passages, with passages between every pair of adjacent open points. Closed
+/// points become walls. Boundaries are never carved through. In block cell mazes, cells that
+/// aren't next to any passage (such as the padding around closed points) are left unvisited;
+/// use [`convert_unvisited_cells_to_walls()`][crate::util::convert_unvisited_cells_to_walls()] to
+/// fill them in.
+///
+/// Unlike the other generators, the mazes it produces are not perfect: caverns are full of
+/// loops, and not every point can be reached. It is best suited to
+/// [block cell mazes][crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator] with a
+/// scale factor of 1, where open points form solid areas of floor. To mix caves with corridors in
+/// the same maze, generate each part through a
+/// [`SubRegionMazeCoordinator`][crate::implm::coordinate::region::SubRegionMazeCoordinator].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// use mazelib::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+/// use mazelib::implm::generate::CaveGenerator;
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::export::DefaultMazeExporter;
+/// use mazelib::interface::generate::MazeGenerator;
+/// use mazelib::util::convert_unvisited_cells_to_walls;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([40, 20]))
+///     .scale_factors_checked([1, 1])
+///     .build();
+///
+/// CaveGenerator::new().smoothing_iterations(5).generate(&mut maze);
+/// convert_unvisited_cells_to_walls(&mut maze);
+///
+/// BoxSpaceBlockCellTextMazeExporter::export(&maze, &mut std::io::stdout()).unwrap();
+/// ```
+pub struct CaveGenerator {
+    /// The probability of each point starting out open.
+    fill: f64,
+    /// The number of times the layout is smoothed.
+    smoothing_iterations: usize,
+    /// The fewest points a cavern can have without being filled in.
+    min_region_size: usize,
+}
+
+impl CaveGenerator {
+    /// The probability of each point starting out open, unless [changed][Self::fill].
+    pub const DEFAULT_FILL: f64 = 0.5;
+
+    /// The number of times the layout is smoothed, unless [changed][Self::smoothing_iterations].
+    pub const DEFAULT_SMOOTHING_ITERATIONS: usize = 4;
+
+    /// The fewest points a cavern can have without being filled in, unless
+    /// [changed][Self::min_region_size].
+    pub const DEFAULT_MIN_REGION_SIZE: usize = 8;
+
+    /// Construct a new generator instance, with the default parameters.
+    ///
+    /// Equivalent to [`Self::default()`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            fill: Self::DEFAULT_FILL,
+            smoothing_iterations: Self::DEFAULT_SMOOTHING_ITERATIONS,
+            min_region_size: Self::DEFAULT_MIN_REGION_SIZE,
+        }
+    }
+
+    /// Set the probability of each point starting out open. Higher fills give larger caverns.
+    ///
+    /// Defaults to [`DEFAULT_FILL`][Self::DEFAULT_FILL].
+    ///
+    /// # Panics
+    ///
+    /// If `fill` is not between 0 and 1 (inclusive).
+    #[must_use]
+    pub fn fill(mut self, fill: f64) -> Self {
+        assert!((0.0..=1.0).contains(&fill), "The fill must be between 0 and 1");
+
+        self.fill = fill;
+
+        return self
+    }
+
+    /// Set the number of times the layout is smoothed. More iterations give smoother caverns, but
+    /// there is little change after the first few.
+    ///
+    /// Defaults to [`DEFAULT_SMOOTHING_ITERATIONS`][Self::DEFAULT_SMOOTHING_ITERATIONS].
+    #[must_use]
+    pub fn smoothing_iterations(mut self, iterations: usize) -> Self {
+        self.smoothing_iterations = iterations;
+
+        return self
+    }
+
+    /// Set the fewest points a cavern can have without being filled in.
+    ///
+    /// Defaults to [`DEFAULT_MIN_REGION_SIZE`][Self::DEFAULT_MIN_REGION_SIZE].
+    #[must_use]
+    pub fn min_region_size(mut self, size: usize) -> Self {
+        self.min_region_size = size;
+
+        return self
+    }
+
+    /// Return the regions of open points, as indices into the adjacency list.
+    fn find_regions(open: &[bool], adjacent: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; open.len()];
+        let mut regions = Vec::new();
+
+        for origin in 0..open.len() {
+            if open[origin] == false || seen[origin] {
+                continue
+            }
+
+            seen[origin] = true;
+
+            let mut region = vec![origin];
+            let mut queue = VecDeque::from([origin]);
+
+            while let Some(i) = queue.pop_front() {
+                for &neighbour in &adjacent[i] {
+                    if open[neighbour] && seen[neighbour] == false {
+                        seen[neighbour] = true;
+                        region.push(neighbour);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+
+            regions.push(region);
+        }
+
+        return regions
+    }
+}
+
+impl Default for CaveGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <M: MazeCoordinator> MazeGenerator<M> for CaveGenerator {
+    fn generate_with_rng(&mut self, maze: &mut M, rng: &mut (impl Rng + ?Sized)) {
+        let points: Vec<_> = maze.coord_space().iter().collect();
+        let index_of: HashMap<_, _> = points.iter().enumerate().map(|(i, pt)| (*pt, i)).collect();
+
+        // The points adjacent to each point that it could have a passage to
+        let adjacent: Vec<Vec<usize>> = points.iter().map(|&pt| {
+            maze.coord_space().neighbours_of_pt(pt).into_iter()
+                .filter(|&neighbour| maze.is_boundary_between(pt, neighbour) == false)
+                .map(|neighbour| index_of[&neighbour])
+                .collect()
+        }).collect();
+
+        // The points within two steps of each point, not including itself
+        let nearby: Vec<Vec<usize>> = (0..points.len()).map(|i| {
+            let mut nearby: Vec<usize> = adjacent[i].iter().flat_map(|&neighbour| std::iter::once(neighbour).chain(adjacent[neighbour].iter().copied())).filter(|&j| j != i).collect();
+            nearby.sort_unstable();
+            nearby.dedup();
+            nearby
+        }).collect();
+
+        // Random fill
+        let mut open: Vec<bool> = points.iter().map(|_| rng.gen_bool(self.fill)).collect();
+
+        // Smoothing
+        for _ in 0..self.smoothing_iterations {
+            open = (0..points.len()).map(|i| {
+                let open_count = nearby[i].iter().filter(|&&j| open[j]).count();
+
+                match (open_count * 2).cmp(&nearby[i].len()) {
+                    std::cmp::Ordering::Greater => true,
+                    std::cmp::Ordering::Less => false,
+                    std::cmp::Ordering::Equal => open[i],
+                }
+            }).collect();
+        }
+
+        // Fill in the caverns that are too small, but always keep at least one
+        let regions = Self::find_regions(&open, &adjacent);
+
+        if let Some(largest) = regions.iter().map(Vec::len).max() {
+            let mut kept_largest = false;
+
+            for region in &regions {
+                if region.len() == largest && kept_largest == false {
+                    kept_largest = true;
+                } else if region.len() < self.min_region_size {
+                    for &i in region {
+                        open[i] = false;
+                    }
+                }
+            }
+        } else {
+            open[rng.gen_range(0..points.len())] = true;
+        }
+
+        // Carve the layout into the maze, walls first so that they don't overwrite any passages
+        for (i, &pt) in points.iter().enumerate() {
+            if open[i] == false {
+                maze.make_wall(pt);
+
+                for &neighbour in adjacent[i].iter().filter(|&&j| j > i && open[j] == false) {
+                    maze.make_wall_between(pt, points[neighbour]);
+                }
+            }
+        }
+
+        for (i, &pt) in points.iter().enumerate() {
+            if open[i] {
+                maze.make_passage(pt);
+
+                for &neighbour in adjacent[i].iter().filter(|&&j| j > i && open[j]) {
+                    maze.make_passage_between(pt, points[neighbour]);
+                }
+            }
+        }
+
+        connect_components(maze, rng);
+    }
+}
\ No newline at end of file
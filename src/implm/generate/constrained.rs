@@ -0,0 +1,137 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::implm::coordinate::constrained::{ConstrainedMazeCoordinator, EdgeConstraints};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::{CoordinateSpace, Point};
+use crate::internal::util::shortest_path;
+use crate::util::connect_components;
+
+/// Runs another generator while honouring [edge constraints][EdgeConstraints]: edges that must be
+/// passages, and edges that must stay walls.
+///
+/// Edges that must stay walls are hidden from the generator behind a
+/// [`ConstrainedMazeCoordinator`], which reports them as boundaries, so the generator never carves
+/// through them. Any parts of the maze this cuts off are then
+/// [joined back up][connect_components] through other edges. Edges that must be passages are
+/// carved in last. Where one would close a loop, a random unconstrained passage on the loop is
+/// walled up instead, so a perfect generator still gives a perfect maze (unless the required
+/// passages form a loop themselves).
+///
+/// Once generation is done, the maze is checked against the constraints.
+///
+/// # Panics
+///
+/// Generating panics if any constrained pair of points are not adjacent, if an edge required to be
+/// a passage is a boundary, or if the maze doesn't meet the constraints once generated.
+///
+/// # Examples
+///
+/// ```
+/// # use rand::SeedableRng;
+/// # use rand_chacha::ChaCha8Rng;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// use mazelib::implm::coordinate::constrained::EdgeConstraints;
+/// use mazelib::implm::generate::{ConstrainedGenerator, HuntAndKillGenerator};
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::generate::MazeGenerator;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([8, 8])).build();
+///
+/// // A straight corridor along the top, closed off from the room below it
+/// let mut constraints = EdgeConstraints::new();
+///
+/// for x in 0..7 {
+///     constraints.require_passage([x, 0].into(), [x + 1, 0].into());
+/// }
+///
+/// for x in 0..8 {
+///     constraints.require_wall([x, 0].into(), [x, 1].into());
+/// }
+///
+/// # let mut rng = ChaCha8Rng::seed_from_u64(0);
+/// ConstrainedGenerator::new(HuntAndKillGenerator::new(), constraints).generate_with_rng(&mut maze, &mut rng);
+///
+/// assert!(maze.is_passage_between([3, 0].into(), [4, 0].into()));
+/// assert!(maze.is_passage_between([3, 0].into(), [3, 1].into()) == false);
+/// ```
+pub struct ConstrainedGenerator<Generator, Pt: Point> {
+    /// The generator run on the constrained maze.
+    generator: Generator,
+    /// The constraints the maze must meet.
+    constraints: EdgeConstraints<Pt>,
+}
+
+impl <Generator, Pt: Point> ConstrainedGenerator<Generator, Pt> {
+    /// Construct a new generator instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `generator`   --- the generator to run on the constrained maze.
+    /// * `constraints` --- the constraints the maze must meet.
+    #[must_use]
+    pub fn new(generator: Generator, constraints: EdgeConstraints<Pt>) -> Self {
+        Self { generator, constraints }
+    }
+
+    /// Return the generator run on the constrained maze.
+    #[must_use]
+    pub fn generator(&self) -> &Generator {
+        &self.generator
+    }
+
+    /// Return the constraints the maze must meet.
+    #[must_use]
+    pub fn constraints(&self) -> &EdgeConstraints<Pt> {
+        &self.constraints
+    }
+}
+
+impl <M: MazeCoordinator, Generator: for<'maze> MazeGenerator<ConstrainedMazeCoordinator<'maze, M>>> MazeGenerator<M> for ConstrainedGenerator<Generator, <M::CoordSpace as CoordinateSpace>::PtType> {
+    fn generate_with_rng(&mut self, maze: &mut M, rng: &mut (impl Rng + ?Sized)) {
+        for (a, b) in self.constraints.passages().iter().chain(self.constraints.walls()) {
+            assert!(maze.coord_space().are_adjacent(*a, *b), "Constrained points {:?} and {:?} are not adjacent", a, b);
+        }
+
+        let mut constrained = ConstrainedMazeCoordinator::new(maze, &self.constraints);
+
+        self.generator.generate_with_rng(&mut constrained, rng);
+
+        // The walls may have split the maze up
+        connect_components(&mut constrained, rng);
+
+        for (from, to) in self.constraints.passages() {
+            if constrained.is_passage_between(*from, *to) {
+                continue
+            }
+
+            assert!(constrained.is_boundary_between(*from, *to) == false, "The edge between {:?} and {:?} is required to be a passage, but is a boundary", from, to);
+
+            // Break the loop this passage would close, if it can be broken
+            if let Some(path) = shortest_path(&constrained, *from, *to) {
+                let breakable: Vec<_> = path.windows(2)
+                    .map(|step| (step[0], step[1]))
+                    .filter(|(a, b)| self.constraints.is_passage_required(*a, *b) == false && constrained.portals().contains(*a, *b) == false)
+                    .collect();
+
+                if let Some((a, b)) = breakable.choose(rng) {
+                    constrained.make_wall_between(*a, *b);
+
+                    // Walling up an edge may wall up its ends too
+                    constrained.make_passage(*a);
+                    constrained.make_passage(*b);
+                }
+            }
+
+            constrained.make_passage_between(*from, *to);
+        }
+
+        if let Err(violation) = self.constraints.check(maze) {
+            panic!("{}", violation)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,132 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::implm::coordinate::region::SubRegionMazeCoordinator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+
+/// Generates three-dimensional mazes one floor at a time, then joins the floors with a controlled
+/// number of vertical connectors (stairwells).
+///
+/// Left to their own devices, generators treat the vertical axis like any other, so a 3D maze ends
+/// up with holes between its floors almost everywhere. That's rarely playable. This generator runs
+/// another generator on each floor on its own, so nothing is carved between floors, then places
+/// the connectors itself.
+///
+/// Floors are stacked along the third axis. Each floor is divided into regions of
+/// [`region_size()`][Self::region_size] points (smaller at the far edges if the floor doesn't
+/// divide evenly), and between every pair of neighbouring floors each region gets
+/// [`connectors_per_region()`][Self::connectors_per_region] connectors at random points within it
+/// (or as many as it has points to spare). Larger regions give fewer, more spread out connectors.
+///
+/// With one connector per region and a region covering the whole floor, a perfect generator gives
+/// a perfect maze. Any more connectors than that create loops between floors.
+///
+/// # Examples
+///
+/// At most one stairwell per 10x10 region:
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// use mazelib::implm::generate::{FloorsGenerator, HuntAndKillGenerator};
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::generate::MazeGenerator;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<3>>, 3>::new(BoxCoordinateSpace::new_checked([20, 20, 3])).build();
+///
+/// FloorsGenerator::new(HuntAndKillGenerator::new(), [10, 10], 1).generate(&mut maze);
+///
+/// let stairwells = maze.connections().filter(|(from, to, _)| from[2] < to[2] && maze.is_passage_between(*from, *to)).count();
+///
+/// // Four regions per floor, and two gaps between floors
+/// assert_eq!(8, stairwells);
+/// ```
+pub struct FloorsGenerator<Generator> {
+    /// The generator run on each floor.
+    generator: Generator,
+    /// The size of the regions along the first and second axes.
+    region_size: [usize; 2],
+    /// The number of connectors each region gets between each pair of floors.
+    connectors_per_region: usize,
+}
+
+impl <Generator> FloorsGenerator<Generator> {
+    /// Construct a new generator instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `generator` --- the generator to run on each floor.
+    /// * `region_size` --- the width and height of the regions each floor is divided into.
+    /// * `connectors_per_region` --- the number of connectors each region gets between each pair of floors.
+    ///
+    /// # Panics
+    ///
+    /// If either dimension of `region_size` is zero, or if `connectors_per_region` is zero.
+    #[must_use]
+    pub fn new(generator: Generator, region_size: [usize; 2], connectors_per_region: usize) -> Self {
+        assert!(region_size.iter().all(|&size| size > 0), "The region size must be non-zero");
+        assert!(connectors_per_region > 0, "There must be at least one connector per region");
+
+        Self { generator, region_size, connectors_per_region }
+    }
+
+    /// Return the generator run on each floor.
+    #[must_use]
+    pub fn generator(&self) -> &Generator {
+        &self.generator
+    }
+
+    /// Return the width and height of the regions each floor is divided into.
+    #[must_use]
+    pub fn region_size(&self) -> [usize; 2] {
+        self.region_size
+    }
+
+    /// Return the number of connectors each region gets between each pair of floors.
+    #[must_use]
+    pub fn connectors_per_region(&self) -> usize {
+        self.connectors_per_region
+    }
+}
+
+impl <M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<3>>, Generator: for<'maze> MazeGenerator<SubRegionMazeCoordinator<'maze, M, 3>>> MazeGenerator<M> for FloorsGenerator<Generator> {
+    fn generate_with_rng(&mut self, maze: &mut M, rng: &mut (impl Rng + ?Sized)) {
+        let [width, height, depth] = maze.coord_space().dimensions().map(usize::from);
+
+        for z in 0..depth {
+            self.generator.generate_with_rng(&mut SubRegionMazeCoordinator::new_checked(maze, [0, 0, z].into(), [width, height, 1]), rng);
+        }
+
+        // Reused for every region
+        let mut candidates = Vec::new();
+
+        for z in 1..depth {
+            for region_y in (0..height).step_by(self.region_size[1]) {
+                for region_x in (0..width).step_by(self.region_size[0]) {
+                    candidates.clear();
+
+                    for y in region_y..(region_y + self.region_size[1]).min(height) {
+                        for x in region_x..(region_x + self.region_size[0]).min(width) {
+                            let below = CoordinateTuplet([x, y, z - 1]);
+                            let above = CoordinateTuplet([x, y, z]);
+
+                            // Don't open up parts of the floors the generator left alone
+                            if maze.get(below).is_fully_visited() && maze.get(above).is_fully_visited() {
+                                candidates.push((below, above));
+                            }
+                        }
+                    }
+
+                    for (below, above) in candidates.choose_multiple(rng, self.connectors_per_region) {
+                        maze.make_passage_between(*below, *above);
+                    }
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -32,39 +32,70 @@ pub struct HuntAndKillGenerator {
 
 impl <M: MazeCoordinator> MazeGenerator<M> for HuntAndKillGenerator {
     fn generate_with_rng(&mut self, maze: &mut M, rng: &mut (impl Rng + ?Sized)) {
-        'hunt: for pt in maze.coord_space().iter() {
-            // Look for an unvisited point
+        // Whether the current tree of passages has been started
+        let mut started = false;
 
-            if maze.get(pt).is_fully_visited() == false {
-                // Connect the new kill path to a previous path
-                {
-                    // Get neighbouring visited points
+        // Reused for every neighbour lookup
+        let mut neighbours = Vec::new();
 
-                    let mut neighbours = maze.coord_space().neighbours_of_pt(pt).to_vec();
+        // Depending on the coordinate space, an unvisited point may not have any visited neighbours
+        // yet when the hunt reaches it, so we may need to hunt more than once
+        'pass: loop {
+            let mut skipped = false;
+            let mut progressed = false;
 
-                    neighbours.retain(|&neighbour| maze.get(neighbour).is_fully_visited());
+            'hunt: for pt in maze.coord_space().iter() {
+                // Look for an unvisited point
 
-                    if neighbours.is_empty() == false {
-                        // unwrap() is safe by virtue of the algorithm
-                        let selected_pt = *neighbours.choose(rng).unwrap();  // Already checked length
+                if maze.get(pt).is_fully_visited() == false {
+                    // Connect the new kill path to a previous path
+                    {
+                        // Get neighbouring visited points
 
-                        // The path is going from there to our hunt end position
-                        maze.make_passage_between(selected_pt, pt);
+                        neighbours.clear();
+                        maze.coord_space().neighbours_into(pt, &mut neighbours);
+
+                        neighbours.retain(|&neighbour| maze.get(neighbour).is_fully_visited() && maze.is_boundary_between(neighbour, pt) == false);
+
+                        if neighbours.is_empty() == false {
+                            // unwrap() is safe by virtue of the algorithm
+                            let selected_pt = *neighbours.choose(rng).unwrap();  // Already checked length
+
+                            // The path is going from there to our hunt end position
+                            maze.make_passage_between(selected_pt, pt);
+                        } else if started {
+                            // Come back to it once one of its neighbours has been visited
+                            skipped = true;
+
+                            continue 'hunt
+                        }
                     }
-                }
 
-                #[allow(unused_labels)]  // So we can label the kill phase
-                'kill: {
-                    let mut current_pt = pt;
+                    started = true;
+                    progressed = true;
 
-                    loop {
-                        match carve_to_unvisited_neighbour(maze, rng, current_pt) {
-                            Some(pt) => current_pt = pt,
-                            None => continue 'hunt  // If we're in a dead end, revert to the hunt phase
+                    #[allow(unused_labels)]  // So we can label the kill phase
+                    'kill: {
+                        let mut current_pt = pt;
+
+                        loop {
+                            match carve_to_unvisited_neighbour(maze, rng, current_pt, &mut neighbours) {
+                                Some(pt) => current_pt = pt,
+                                None => continue 'hunt  // If we're in a dead end, revert to the hunt phase
+                            }
                         }
                     }
                 }
             }
+
+            if skipped == false {
+                break 'pass
+            }
+
+            // The remaining points can't be reached from any visited point, so start a new tree
+            if progressed == false {
+                started = false;
+            }
         }
     }
 }
@@ -27,6 +27,13 @@
 //!
 //! ![A typical output of n-ary Tree.][example-nary-tree]
 //!
+//! For weave mazes, where passages cross over and under each other, see [*Weave*][WeaveGenerator].
+//! For mazes whose texture varies from region to region, see [*Noise-Biased*][NoiseBiasedGenerator].
+//! For open, organic caverns instead of corridors, see [*Cave*][CaveGenerator].
+//! For decorative mazes whose passages wind around the centre, see [*Spiral*][SpiralGenerator].
+//! For 3D mazes with a controlled number of connections between floors, see [`FloorsGenerator`].
+//! To keep hand-placed passages and walls while generating around them, see [`ConstrainedGenerator`].
+//!
 //! # See Also
 //!
 //! * [`MazeGenerator`][crate::interface::generate::MazeGenerator] --- the interface trait
@@ -34,12 +41,25 @@
 #![doc = ::embed_doc_image::embed_image!("example-recursive-backtracker", "src/doc/img/generate/recursive-backtracker/example.png")]
 #![doc = ::embed_doc_image::embed_image!("example-nary-tree", "src/doc/img/generate/nary-tree/example.png")]
 
+pub use self::cave::CaveGenerator;
+pub use self::constrained::ConstrainedGenerator;
+pub use self::floors::FloorsGenerator;
 pub use self::hunt_and_kill::HuntAndKillGenerator;
 pub use self::nary_tree::NAryTreeGenerator;
-pub use self::recursive_backtracker::RecursiveBacktrackerGenerator;
+#[cfg(any(feature = "noise", doc))]
+pub use self::noise::NoiseBiasedGenerator;
+pub use self::recursive_backtracker::{RecursiveBacktrackerGenerator, RecursiveBacktrackerProgress};
+pub use self::spiral::{Rotation, SpiralGenerator};
+pub use self::weave::WeaveGenerator;
 
+mod cave;
+mod constrained;
+mod floors;
 mod nary_tree;
 mod hunt_and_kill;
+mod noise;
 mod recursive_backtracker;
+mod spiral;
 mod util;
+mod weave;
 
@@ -0,0 +1,138 @@
+#![cfg(any(feature = "noise", doc))]
+
+use noise::NoiseFn;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+/// The **Noise-Biased** algorithm is a variant of the
+/// [*Recursive Backtracker* algorithm][crate::implm::generate::RecursiveBacktrackerGenerator] whose
+/// texture is steered by a [noise function][NoiseFn], such as Perlin noise from the
+/// [`noise`] crate.
+///
+/// At each step, the noise is sampled at the current point. The higher the noise, the more likely
+/// the generator is to keep going in the same direction rather than turning at random. As noise
+/// varies smoothly, so does the maze: where the noise is high it has long, straight corridors, and
+/// where it is low it has tight, twisty tangles. Noise of `1` or more always goes straight when it
+/// can, and noise of `-1` or less never prefers to.
+///
+/// Point `p` samples the noise at `p / scale`, so larger scales produce larger regions. 2D mazes
+/// need a `NoiseFn<f64, 2>` and 3D mazes a `NoiseFn<f64, 3>`.
+///
+/// Like Recursive Backtracker, it maintains a stack of the current path, which in the worst-case
+/// will be as long as the logical size of the coordinate space.
+///
+/// Requires the `noise` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// use mazelib::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+/// use mazelib::implm::generate::NoiseBiasedGenerator;
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::export::DefaultMazeExporter;
+/// use mazelib::interface::generate::MazeGenerator;
+/// use noise::Perlin;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([30, 15])).build();
+///
+/// NoiseBiasedGenerator::new(Perlin::new(7), 10.0).generate(&mut maze);
+///
+/// BoxSpaceBlockCellTextMazeExporter::export(&maze, &mut std::io::stdout()).unwrap();
+/// ```
+pub struct NoiseBiasedGenerator<Noise> {
+    /// The noise steering the generator.
+    noise: Noise,
+    /// The number of points per unit of noise.
+    scale: f64,
+}
+
+impl <Noise> NoiseBiasedGenerator<Noise> {
+    /// Construct a new generator instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `noise` --- the noise steering the generator. The higher it is, the straighter the passages.
+    /// * `scale` --- the number of points per unit of noise. Larger scales give larger regions.
+    ///
+    /// # Panics
+    ///
+    /// If `scale` is not positive.
+    #[must_use]
+    pub fn new(noise: Noise, scale: f64) -> Self {
+        assert!(scale > 0.0, "The scale must be positive");
+
+        Self { noise, scale }
+    }
+
+    /// Return the noise steering the generator.
+    #[must_use]
+    pub fn noise(&self) -> &Noise {
+        &self.noise
+    }
+
+    /// Return the number of points per unit of noise.
+    #[must_use]
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Return the probability of going straight at `pt`.
+    fn straightness<const DIMENSION: usize>(&self, pt: CoordinateTuplet<DIMENSION>) -> f64 where Noise: NoiseFn<f64, DIMENSION> {
+        let sample = self.noise.get(pt.0.map(|coord| coord as f64 / self.scale));
+
+        return ((sample + 1.0) / 2.0).clamp(0.0, 1.0)
+    }
+}
+
+impl <Noise: NoiseFn<f64, DIMENSION>, M: MazeCoordinator<CoordSpace=BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> MazeGenerator<M> for NoiseBiasedGenerator<Noise> {
+    fn generate_with_rng(&mut self, maze: &mut M, rng: &mut (impl Rng + ?Sized)) {
+        // Start at a random point
+        let start_pt = maze.coord_space().choose(rng);
+
+        maze.make_passage(start_pt);
+
+        // Each point on the current path, along with the point before it (so we know which way is
+        // straight ahead)
+        let mut stack = vec![(start_pt, None)];
+
+        // Reused for every step
+        let mut neighbours = Vec::new();
+
+        while let Some(&(current_pt, previous_pt)) = stack.last() {
+            // Get unvisited neighbours we're allowed to carve to
+            neighbours.clear();
+            maze.coord_space().neighbours_into(current_pt, &mut neighbours);
+            neighbours.retain(|&neighbour| maze.get(neighbour).is_fully_visited() == false && maze.is_boundary_between(current_pt, neighbour) == false);
+
+            // The neighbour directly ahead, if it's one of them
+            let ahead = previous_pt.and_then(|previous: CoordinateTuplet<DIMENSION>| {
+                neighbours.iter().copied().find(|neighbour| (0..DIMENSION).all(|axis| neighbour[axis] + previous[axis] == 2 * current_pt[axis]))
+            });
+
+            let next_pt = match ahead {
+                Some(ahead) if rng.gen_bool(self.straightness(current_pt)) => Some(ahead),
+                _ => neighbours.choose(rng).copied(),
+            };
+
+            match next_pt {
+                Some(pt) => {
+                    maze.make_passage_between(current_pt, pt);
+
+                    stack.push((pt, Some(current_pt)));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -1,10 +1,16 @@
+#[cfg(any(feature = "json", doc))]
+use std::io::{Read, Result, Write};
+
 use embed_doc_image::embed_doc_image;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 
 use crate::implm::generate::util::carve_to_unvisited_neighbour;
+#[cfg(any(feature = "json", doc))]
+use crate::implm::point::boxy::CoordinateTuplet;
 use crate::interface::coordinate::MazeCoordinator;
 use crate::interface::generate::MazeGenerator;
-use crate::interface::point::CoordinateSpace;
+use crate::interface::point::{CoordinateSpace, Point};
+use crate::rng::PortableRng;
 
 /// The **Recursive Backtracker** algorithm is a variant of depth-first search which selects
 /// the next child at random.
@@ -31,6 +37,9 @@ use crate::interface::point::CoordinateSpace;
 /// though. Also note how this description is exactly the same for
 /// [Hunt-and-Kill](crate::implm::generate::HuntAndKillGenerator#example).
 ///
+/// To generate a very large maze a little at a time, so that it can be checkpointed and resumed
+/// later, see [`RecursiveBacktrackerProgress`].
+///
 /// [^name]: While the name implies a recursive implementation, it is much more efficient to
 ///          implement it imperatively. A recursive implementation will likely overflow the
 ///          callstack on even medium-sized mazes (ignoring tail-call optimisation).
@@ -47,9 +56,10 @@ impl <M: MazeCoordinator> MazeGenerator<M> for RecursiveBacktrackerGenerator {
         maze.make_passage(current_pt);
 
         let mut stack = vec![current_pt];
+        let mut neighbours = Vec::new();
 
         while !stack.is_empty() {
-            match carve_to_unvisited_neighbour(maze, rng, current_pt) {
+            match carve_to_unvisited_neighbour(maze, rng, current_pt, &mut neighbours) {
                 Some(pt) => {
                     stack.push(pt);
                     current_pt = pt;
@@ -79,4 +89,213 @@ impl Default for RecursiveBacktrackerGenerator {
     fn default() -> Self {
         Self::new()
     }
+}
+
+/// A [*Recursive Backtracker*][RecursiveBacktrackerGenerator] generation in progress, which can be
+/// advanced a few steps at a time.
+///
+/// Generating an enormous maze can take a long time. This holds everything the generation needs
+/// between steps (the stack of the current path, and the random number generator), so it can be
+/// paused at any point and picked up again later. Along with the maze itself, it can be saved to
+/// disk and loaded again (see [`save()`][Self::save]), even on another machine, and the finished
+/// maze will be exactly the same as if it had never been interrupted.
+///
+/// Randomness is drawn from a [`PortableRng`] seeded when the generation starts, so that its state
+/// can be saved too. The mazes generated are not the same as those of
+/// [`RecursiveBacktrackerGenerator`] with the same seed.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::implm::presets::Standard2DMaze;
+/// # use mazelib::interface::cell::CellValue;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// # use mazelib::interface::point::CoordinateSpace;
+/// use mazelib::implm::generate::RecursiveBacktrackerProgress;
+///
+/// let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([20, 20])).build();
+/// let mut progress = RecursiveBacktrackerProgress::new(42);
+///
+/// // Do some of the work now...
+/// assert!(progress.run(&mut maze, 100) == false);
+///
+/// // ...and the rest later
+/// while progress.run(&mut maze, 100) == false {}
+///
+/// assert!(maze.coord_space().iter().all(|pt| maze.get(pt).is_fully_visited()));
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RecursiveBacktrackerProgress<Pt: Point> {
+    /// The points along the current path. The last is the point being carved from.
+    stack: Vec<Pt>,
+    /// Whether the starting point has been chosen yet.
+    started: bool,
+    /// The source of every random choice.
+    rng: PortableRng,
+}
+
+impl <Pt: Point> RecursiveBacktrackerProgress<Pt> {
+    /// Construct a new generation that hasn't taken any steps yet.
+    ///
+    /// # Parameters
+    ///
+    /// * `seed` --- the seed the generation's random number generator is seeded with.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { stack: Vec::new(), started: false, rng: PortableRng::seed_from_u64(seed) }
+    }
+
+    /// Return whether the generation has finished.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.started && self.stack.is_empty()
+    }
+
+    /// Return the points along the current path, from the starting point to the point being
+    /// carved from.
+    #[must_use]
+    pub fn path(&self) -> &[Pt] {
+        &self.stack
+    }
+
+    /// Take one step: either carve a passage to an unvisited neighbour, or backtrack along the
+    /// current path.
+    ///
+    /// The maze must be the same one given to every previous step. Returns whether the generation
+    /// has finished, in which case further steps do nothing.
+    pub fn step<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace<PtType = Pt>>(&mut self, maze: &mut M) -> bool {
+        if self.started == false {
+            // Start at a random point
+            let start = maze.coord_space().choose(&mut self.rng);
+
+            maze.make_passage(start);
+
+            self.stack.push(start);
+            self.started = true;
+
+            return false
+        }
+
+        let Some(&current_pt) = self.stack.last() else {
+            return true
+        };
+
+        match carve_to_unvisited_neighbour(maze, &mut self.rng, current_pt, &mut Vec::new()) {
+            Some(pt) => self.stack.push(pt),
+            None => { self.stack.pop(); },
+        }
+
+        return self.is_finished()
+    }
+
+    /// Take up to `steps` steps, stopping early if the generation finishes.
+    ///
+    /// Returns whether the generation has finished.
+    ///
+    /// *See [`step()`][Self::step].*
+    pub fn run<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace<PtType = Pt>>(&mut self, maze: &mut M, steps: usize) -> bool {
+        for _ in 0..steps {
+            if self.step(maze) {
+                break
+            }
+        }
+
+        return self.is_finished()
+    }
+}
+
+#[cfg(any(feature = "json", doc))]
+impl <const DIMENSION: usize> RecursiveBacktrackerProgress<CoordinateTuplet<DIMENSION>> {
+    /// Write the state of the generation to `output` as JSON, so that it can be
+    /// [loaded][Self::load] and resumed later.
+    ///
+    /// The maze isn't included, so save it alongside (e.g. with a
+    /// [`BoxSpaceBlockCellJsonMazeExporter`][crate::implm::export::json::BoxSpaceBlockCellJsonMazeExporter]).
+    ///
+    /// Requires the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// If writing to `output` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::export::MazeExporter;
+    /// # use mazelib::interface::import::MazeImporter;
+    /// use mazelib::implm::export::json::BoxSpaceBlockCellJsonMazeExporter;
+    /// use mazelib::implm::generate::RecursiveBacktrackerProgress;
+    /// use mazelib::implm::import::json::BoxSpaceBlockCellJsonMazeImporter;
+    ///
+    /// type Maze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+    ///
+    /// let mut maze = Maze::builder(BoxCoordinateSpace::new_checked([30, 30])).build();
+    /// let mut progress = RecursiveBacktrackerProgress::new(7);
+    /// progress.run(&mut maze, 500);
+    ///
+    /// // Checkpoint
+    /// let mut saved_maze = Vec::new();
+    /// let mut saved_progress = Vec::new();
+    /// BoxSpaceBlockCellJsonMazeExporter::new().export(&maze, &mut saved_maze).unwrap();
+    /// progress.save(&mut saved_progress).unwrap();
+    ///
+    /// // Resume
+    /// let mut maze: Maze = BoxSpaceBlockCellJsonMazeImporter::new().import(&mut saved_maze.as_slice()).unwrap();
+    /// let mut progress = RecursiveBacktrackerProgress::load(saved_progress.as_slice()).unwrap();
+    /// progress.run(&mut maze, usize::MAX);
+    ///
+    /// assert!(progress.is_finished());
+    /// ```
+    pub fn save(&self, output: impl Write) -> Result<()> {
+        use crate::implm::export::json::schema::{RECURSIVE_BACKTRACKER_PROGRESS_FORMAT, RECURSIVE_BACKTRACKER_PROGRESS_VERSION, RecursiveBacktrackerProgressJson};
+
+        let json = RecursiveBacktrackerProgressJson {
+            format: RECURSIVE_BACKTRACKER_PROGRESS_FORMAT.to_string(),
+            version: RECURSIVE_BACKTRACKER_PROGRESS_VERSION,
+            started: self.started,
+            stack: self.stack.iter().map(|pt| pt.0.to_vec()).collect(),
+            rng: self.rng.state(),
+        };
+
+        return serde_json::to_writer(output, &json).map_err(|err| err.into())
+    }
+
+    /// Read the state of a generation written by [`save()`][Self::save].
+    ///
+    /// Requires the `json` feature.
+    ///
+    /// # Errors
+    ///
+    /// If reading from `input` fails, or what it contains isn't a saved generation for this
+    /// dimension.
+    pub fn load(input: impl Read) -> Result<Self> {
+        use crate::implm::export::json::schema::{RECURSIVE_BACKTRACKER_PROGRESS_FORMAT, RECURSIVE_BACKTRACKER_PROGRESS_VERSION, RecursiveBacktrackerProgressJson};
+        use crate::internal::util::invalid_data;
+
+        let json: RecursiveBacktrackerProgressJson = serde_json::from_reader(input)?;
+
+        if json.format != RECURSIVE_BACKTRACKER_PROGRESS_FORMAT {
+            return Err(invalid_data(format!("Expected format {:?}, found {:?}", RECURSIVE_BACKTRACKER_PROGRESS_FORMAT, json.format)));
+        }
+
+        if json.version > RECURSIVE_BACKTRACKER_PROGRESS_VERSION {
+            return Err(invalid_data(format!("Unsupported version {} (the latest supported version is {})", json.version, RECURSIVE_BACKTRACKER_PROGRESS_VERSION)));
+        }
+
+        if json.rng == [0; 4] {
+            return Err(invalid_data("The random number generator's state can't be all zeroes"));
+        }
+
+        let stack = json.stack.into_iter()
+            .map(|pt| <[usize; DIMENSION]>::try_from(pt).map(CoordinateTuplet))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|pt| invalid_data(format!("Expected a point with {} coordinates, found {:?}", DIMENSION, pt)))?;
+
+        return Ok(Self { stack, started: json.started, rng: PortableRng::from_state(json.rng) })
+    }
 }
\ No newline at end of file
@@ -0,0 +1,166 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+/// The **Spiral** algorithm is a variant of the
+/// [*Recursive Backtracker* algorithm][crate::implm::generate::RecursiveBacktrackerGenerator] that
+/// carves passages which wind around the centre of the maze.
+///
+/// At each step, the generator prefers the unvisited neighbour that goes furthest around the
+/// centre in the [direction of rotation][Rotation]. It takes that neighbour with probability
+/// `bias`, and otherwise picks one at random. Passages follow rings around the centre until they
+/// run into one another, and then step inwards or outwards to the next ring, so the maze has a
+/// striking concentric texture. This is mostly for decorative and printed mazes, as the rings are
+/// easy to follow by eye.
+///
+/// The resulting mazes are still perfect. This generator only supports 2D box spaces. Like
+/// Recursive Backtracker, it maintains a stack of the current path, which in the worst-case will
+/// be as long as the logical size of the coordinate space.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// use mazelib::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+/// use mazelib::implm::generate::{Rotation, SpiralGenerator};
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::export::DefaultMazeExporter;
+/// use mazelib::interface::generate::MazeGenerator;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([21, 21])).build();
+///
+/// SpiralGenerator::new(0.9).rotation(Rotation::Anticlockwise).generate(&mut maze);
+///
+/// BoxSpaceBlockCellTextMazeExporter::export(&maze, &mut std::io::stdout()).unwrap();
+/// ```
+pub struct SpiralGenerator {
+    /// The probability of taking the neighbour that goes furthest around the centre.
+    bias: f64,
+    /// Which way around the centre passages wind.
+    rotation: Rotation,
+}
+
+/// Which way around the centre a [`SpiralGenerator`]'s passages wind.
+///
+/// Directions are as the maze is usually drawn, with the first axis running left to right and the
+/// second running top to bottom.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Rotation {
+    /// The way the hands of a clock turn.
+    #[default]
+    Clockwise,
+    /// Against the way the hands of a clock turn.
+    Anticlockwise,
+}
+
+impl SpiralGenerator {
+    /// Construct a new generator instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `bias` --- the probability of taking the neighbour that goes furthest around the centre at
+    ///   each step. `0` gives the same texture as Recursive Backtracker, and `1` gives the most
+    ///   regular rings.
+    ///
+    /// # Panics
+    ///
+    /// If `bias` is not between 0 and 1 (inclusive).
+    #[must_use]
+    pub fn new(bias: f64) -> Self {
+        assert!((0.0..=1.0).contains(&bias), "The bias must be between 0 and 1");
+
+        Self { bias, rotation: Rotation::default() }
+    }
+
+    /// Set which way around the centre passages wind.
+    ///
+    /// Defaults to [`Rotation::Clockwise`].
+    #[must_use]
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.rotation = rotation;
+
+        return self
+    }
+
+    /// Return the probability of taking the neighbour that goes furthest around the centre.
+    #[must_use]
+    pub fn bias(&self) -> f64 {
+        self.bias
+    }
+
+    /// Return how far moving from `from` to its neighbour `to` goes around `centre`, relative to
+    /// the other neighbours of `from`.
+    fn turn(&self, centre: [f64; 2], from: CoordinateTuplet<2>, to: CoordinateTuplet<2>) -> f64 {
+        // The step from one to the other, accounting for axes that wrap around
+        let step = [0, 1].map(|axis| match to[axis].cmp(&from[axis]) {
+            std::cmp::Ordering::Equal => 0.0,
+            std::cmp::Ordering::Greater if to[axis] - from[axis] == 1 => 1.0,
+            std::cmp::Ordering::Greater => -1.0,
+            std::cmp::Ordering::Less if from[axis] - to[axis] == 1 => -1.0,
+            std::cmp::Ordering::Less => 1.0,
+        });
+
+        let offset = [0, 1].map(|axis| from[axis] as f64 - centre[axis]);
+
+        // The first axis runs right and the second down, so this tangent points clockwise
+        let tangent = [-offset[1], offset[0]];
+
+        let turn = step[0] * tangent[0] + step[1] * tangent[1];
+
+        return match self.rotation {
+            Rotation::Clockwise     => turn,
+            Rotation::Anticlockwise => -turn,
+        }
+    }
+}
+
+impl <M: MazeCoordinator<CoordSpace=BoxCoordinateSpace<2>>> MazeGenerator<M> for SpiralGenerator {
+    fn generate_with_rng(&mut self, maze: &mut M, rng: &mut (impl Rng + ?Sized)) {
+        let dimensions = maze.coord_space().dimensions();
+        let centre = [0, 1].map(|axis| (usize::from(dimensions[axis]) - 1) as f64 / 2.0);
+
+        // Start at a random point
+        let start_pt = maze.coord_space().choose(rng);
+
+        maze.make_passage(start_pt);
+
+        let mut stack = vec![start_pt];
+
+        // Reused for every step
+        let mut neighbours = Vec::new();
+
+        while let Some(&current_pt) = stack.last() {
+            // Get unvisited neighbours we're allowed to carve to
+            neighbours.clear();
+            maze.coord_space().neighbours_into(current_pt, &mut neighbours);
+            neighbours.retain(|&neighbour| maze.get(neighbour).is_fully_visited() == false && maze.is_boundary_between(current_pt, neighbour) == false);
+
+            // Shuffle first so that ties are broken at random
+            neighbours.shuffle(rng);
+
+            let next_pt = if rng.gen_bool(self.bias) {
+                neighbours.iter().copied().max_by(|a, b| self.turn(centre, current_pt, *a).total_cmp(&self.turn(centre, current_pt, *b)))
+            } else {
+                neighbours.first().copied()
+            };
+
+            match next_pt {
+                Some(pt) => {
+                    maze.make_passage_between(current_pt, pt);
+
+                    stack.push(pt);
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -5,11 +5,16 @@ use crate::interface::cell::CellValue;
 use crate::interface::coordinate::MazeCoordinator;
 use crate::interface::point::CoordinateSpace;
 
-pub fn carve_to_unvisited_neighbour<M: MazeCoordinator>(maze: &mut M, rng: &mut (impl Rng + ?Sized), from_pt: <<M as MazeCoordinator>::CoordSpace as CoordinateSpace>::PtType) -> Option<<<M as MazeCoordinator>::CoordSpace as CoordinateSpace>::PtType>
+/// Carve a passage from `from_pt` to a random unvisited neighbour, returning the neighbour, or
+/// `None` if there are none.
+///
+/// `neighbours` is scratch space, so that callers can reuse the same buffer for every step.
+pub fn carve_to_unvisited_neighbour<M: MazeCoordinator>(maze: &mut M, rng: &mut (impl Rng + ?Sized), from_pt: <<M as MazeCoordinator>::CoordSpace as CoordinateSpace>::PtType, neighbours: &mut Vec<<<M as MazeCoordinator>::CoordSpace as CoordinateSpace>::PtType>) -> Option<<<M as MazeCoordinator>::CoordSpace as CoordinateSpace>::PtType>
 {
-    // Get unvisited neighbours
-    let mut neighbours = maze.coord_space().neighbours_of_pt(from_pt).to_vec();
-    neighbours.retain(|&neighbour| maze.get(neighbour).is_fully_visited() == false);
+    // Get unvisited neighbours we're allowed to carve to
+    neighbours.clear();
+    maze.coord_space().neighbours_into(from_pt, neighbours);
+    neighbours.retain(|&neighbour| maze.get(neighbour).is_fully_visited() == false && maze.is_boundary_between(from_pt, neighbour) == false);
 
     // Pick a random unvisited neighbouring point
     let next_pt = *neighbours.choose(rng)?;
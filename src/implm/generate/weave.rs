@@ -0,0 +1,115 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+/// The **Weave** algorithm is a variant of the
+/// [*Recursive Backtracker* algorithm][crate::implm::generate::RecursiveBacktrackerGenerator] that
+/// produces *weave* mazes, where passages cross over and under each other.
+///
+/// As well as carving to its unvisited neighbours, at each step it may also
+/// [tunnel][BoxSpaceInlineCellMazeCoordinator::make_tunnel_between] beneath a neighbour to reach an
+/// unvisited point on the other side. This is only possible when the neighbour is a straight
+/// passage running across the direction of travel. Both kinds of move are chosen from uniformly at
+/// random, so tunnels are most common in mazes with long straight corridors.
+///
+/// The resulting mazes are still perfect, but only when tunnels are counted as passages. Use
+/// [`BoxSpaceInlineCellMazeCoordinator::tunnel_neighbours_of_pt()`] to follow them.
+///
+/// This generator only supports [`BoxSpaceInlineCellMazeCoordinator`]s, as they are the only
+/// coordinator that can carve tunnels. Like Recursive Backtracker, it maintains a stack of the
+/// current path, which in the worst-case will be as long as the logical size of the coordinate
+/// space.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// use mazelib::implm::export::text::BoxSpaceInlineCellTextMazeExporter;
+/// use mazelib::implm::generate::WeaveGenerator;
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::interface::export::DefaultMazeExporter;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([12, 8])).build();
+///
+/// WeaveGenerator::generate(&mut maze);
+///
+/// BoxSpaceInlineCellTextMazeExporter::export(&maze, &mut std::io::stdout()).unwrap();
+/// ```
+pub struct WeaveGenerator {
+    _private: ()
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> MazeGenerator<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>> for WeaveGenerator {
+    fn generate_with_rng(&mut self, maze: &mut BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, rng: &mut (impl Rng + ?Sized)) {
+        // Start at a random point
+        let mut current_pt = maze.coord_space().choose(rng);
+
+        maze.make_passage(current_pt);
+
+        let mut stack = vec![current_pt];
+
+        // Reused for every step
+        let mut neighbours = Vec::new();
+        let mut candidates = Vec::new();
+
+        while !stack.is_empty() {
+            neighbours.clear();
+            maze.coord_space().neighbours_into(current_pt, &mut neighbours);
+
+            // Get unvisited neighbours, and unvisited points we could tunnel to
+            candidates.clear();
+            candidates.extend(neighbours.iter()
+                .filter(|neighbour| maze.get(**neighbour).is_fully_visited() == false)
+                .map(|neighbour| (*neighbour, false)));
+
+            candidates.extend(maze.get_tunnel_candidates(current_pt).into_iter()
+                .filter(|candidate| maze.get(*candidate).is_fully_visited() == false && maze.can_tunnel_between(current_pt, *candidate))
+                .map(|candidate| (candidate, true)));
+
+            match candidates.choose(rng) {
+                Some(&(pt, is_tunnel)) => {
+                    if is_tunnel {
+                        maze.make_tunnel_between(current_pt, pt);
+                    } else {
+                        maze.make_passage_between(current_pt, pt);
+                    }
+
+                    stack.push(pt);
+                    current_pt = pt;
+                }
+                None => current_pt = stack.pop().unwrap()
+            }
+        }
+    }
+}
+
+impl WeaveGenerator {
+    /// Construct a new generator instance.
+    ///
+    /// This doesn't take any parameters, so if you're just immediately going to call
+    /// [`generate()`][crate::interface::generate::MazeGenerator::generate], you may wish to use
+    /// [`DefaultMazeGenerator::generate()`][crate::interface::generate::DefaultMazeGenerator::generate]
+    /// instead.
+    ///
+    /// Equivalent to [`Self::default()`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl Default for WeaveGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use std::io::{Read, Result};
+
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::binary::format::{BLOCK_CELL_CLASS, BLOCK_CELL_LENGTH, decode_block_cell};
+use crate::implm::import::binary::{BinaryMazeImporter, BoxSpaceBinaryMazeImporter};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::import::MazeImporter;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::util::{invalid_data, try_usize_array_to_nonzero_usize_array};
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, Input: Read> MazeImporter<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, Input> for BoxSpaceBinaryMazeImporter {
+    fn import(&self, input: &mut Input) -> Result<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>> {
+        let mut reader = Self::read_verified(input, BLOCK_CELL_CLASS, DIMENSION)?;
+
+        let mut dimensions = [0; DIMENSION];
+        let mut scale_factors = [0; DIMENSION];
+        let mut padding = [[0; 2]; DIMENSION];
+
+        for dim in dimensions.iter_mut() {
+            *dim = reader.read_usize()?;
+        }
+
+        for scale_factor in scale_factors.iter_mut() {
+            *scale_factor = reader.read_usize()?;
+        }
+
+        for [negative, positive] in padding.iter_mut() {
+            *negative = reader.read_usize()?;
+            *positive = reader.read_usize()?;
+        }
+
+        let dimensions = try_usize_array_to_nonzero_usize_array(dimensions).map_err(|_| invalid_data("All dimensions must be non-zero"))?;
+        let scale_factors = try_usize_array_to_nonzero_usize_array(scale_factors).map_err(|_| invalid_data("All scale factors must be non-zero"))?;
+
+        let space = BoxCoordinateSpace::try_new(dimensions).map_err(|err| invalid_data(err.to_string()))?;
+
+        // Every point is a cell, so this bounds the size of the layout before it's computed
+        reader.check_remaining(usize::from(space.logical_size()), BLOCK_CELL_LENGTH)?;
+
+        let builder = BoxSpaceBlockCellMazeCoordinator::<Buffer, DIMENSION>::builder(space)
+            .scale_factors(scale_factors)
+            .padding(padding);
+
+        let cells_required = builder.try_cells_required().map_err(|err| invalid_data(err.to_string()))?;
+
+        reader.check_remaining(usize::from(cells_required), BLOCK_CELL_LENGTH)?;
+
+        let mut maze = builder.try_build().map_err(|err| invalid_data(err.to_string()))?;
+
+        for cell_loc in BoxCoordinateSpace::new(maze.get_full_dimensions()).iter() {
+            let bytes = reader.read_bytes(BLOCK_CELL_LENGTH)?;
+
+            *maze.get_cell_value_mut(BlockCellLocation(cell_loc)) = decode_block_cell(bytes).ok_or_else(|| invalid_data(format!("Invalid cell {:02x?}", bytes)))?;
+        }
+
+        reader.finish()?;
+
+        return Ok(maze)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, Input: Read> BinaryMazeImporter<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, Input> for BoxSpaceBinaryMazeImporter {}
\ No newline at end of file
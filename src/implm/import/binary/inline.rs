@@ -0,0 +1,48 @@
+use std::io::{Read, Result};
+
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::export::binary::format::{decode_inline_cell, INLINE_CELL_CLASS, inline_cell_length};
+use crate::implm::import::binary::{BinaryMazeImporter, BoxSpaceBinaryMazeImporter};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::import::MazeImporter;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::util::{invalid_data, try_usize_array_to_nonzero_usize_array};
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize, Input: Read> MazeImporter<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, Input> for BoxSpaceBinaryMazeImporter {
+    fn import(&self, input: &mut Input) -> Result<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>> {
+        let mut reader = Self::read_verified(input, INLINE_CELL_CLASS, DIMENSION)?;
+
+        let mut dimensions = [0; DIMENSION];
+
+        for dim in dimensions.iter_mut() {
+            *dim = reader.read_usize()?;
+        }
+
+        let dimensions = try_usize_array_to_nonzero_usize_array(dimensions).map_err(|_| invalid_data("All dimensions must be non-zero"))?;
+
+        let space = BoxCoordinateSpace::try_new(dimensions).map_err(|err| invalid_data(err.to_string()))?;
+
+        let cell_length = inline_cell_length::<DIMENSION>();
+
+        reader.check_remaining(usize::from(space.logical_size()), cell_length)?;
+
+        let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<Buffer, DIMENSION>::new(space).try_build().map_err(|err| invalid_data(err.to_string()))?;
+
+        for pt in maze.coord_space().iter() {
+            let bytes = reader.read_bytes(cell_length)?;
+
+            let value = decode_inline_cell(bytes).ok_or_else(|| invalid_data(format!("Invalid cell {:02x?}", bytes)))?;
+
+            maze.set(pt, value);
+        }
+
+        reader.finish()?;
+
+        return Ok(maze)
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize, Input: Read> BinaryMazeImporter<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, Input> for BoxSpaceBinaryMazeImporter {}
\ No newline at end of file
@@ -0,0 +1,150 @@
+//! The compact binary format.
+//!
+//! See [`crate::implm::export::binary`] for the format.
+
+use std::io::{Read, Result};
+
+use crate::implm::export::binary::format::{CHECKSUM_LENGTH, crc32, HEADER_LENGTH, MAGIC, SUPPORTED_VERSIONS};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::import::MazeImporter;
+use crate::internal::util::invalid_data;
+
+mod block;
+mod inline;
+
+/// Import a maze from the compact binary format.
+pub trait BinaryMazeImporter<M: MazeCoordinator, I: Read> : MazeImporter<M, I> {}
+
+/// A [`BinaryMazeImporter`] for mazes that
+/// use [`BoxCoordinateSpace`][crate::implm::point::boxy::BoxCoordinateSpace]s, of any dimension
+/// and either cell class.
+///
+/// The input is rejected if it is truncated, fails its checksum, was written by a newer version of
+/// the format than this importer understands, or describes a different cell class or dimension to
+/// the coordinator being constructed.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::export::binary::BoxSpaceBinaryMazeExporter;
+/// use mazelib::implm::import::binary::BoxSpaceBinaryMazeImporter;
+/// use mazelib::interface::export::MazeExporter;
+/// use mazelib::interface::import::MazeImporter;
+///
+/// type Maze = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<2>>, 2>;
+///
+/// let maze: Maze = BoxSpaceInlineCellMazeCoordinatorBuilder::new(BoxCoordinateSpace::new_checked([4, 4])).build();
+///
+/// let mut bytes = Vec::new();
+/// BoxSpaceBinaryMazeExporter::new().export(&maze, &mut bytes).unwrap();
+///
+/// let imported: Maze = BoxSpaceBinaryMazeImporter::new().import(&mut bytes.as_slice()).unwrap();
+/// ```
+pub struct BoxSpaceBinaryMazeImporter {
+    _private: ()
+}
+
+impl BoxSpaceBinaryMazeImporter {
+    /// Construct a new instance.
+    ///
+    /// Optional, see [`DefaultMazeImporter`][crate::interface::import::DefaultMazeImporter].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Return every version of the format this importer can read.
+    #[must_use]
+    pub fn supported_versions() -> &'static [u8] {
+        &SUPPORTED_VERSIONS
+    }
+
+    /// Read all of `input`, validate the header and checksum, and return a reader positioned at the
+    /// start of the class-specific data.
+    fn read_verified<I: Read>(input: &mut I, expected_cell_class: u8, expected_dimension: usize) -> Result<BinaryReader> {
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+
+        if bytes.len() < HEADER_LENGTH + CHECKSUM_LENGTH || bytes[0..4] != MAGIC {
+            return Err(invalid_data("The input is not a mazelib binary maze"));
+        }
+
+        let version = bytes[4];
+
+        if SUPPORTED_VERSIONS.contains(&version) == false {
+            return Err(invalid_data(format!("Unsupported version {} (supported versions are {:?})", version, SUPPORTED_VERSIONS)));
+        }
+
+        let (data, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LENGTH);
+        let checksum = u32::from_le_bytes(checksum.try_into().expect("[Bug] Checksum is four bytes"));
+
+        if crc32(data) != checksum {
+            return Err(invalid_data("Checksum mismatch (the input is corrupt)"));
+        }
+
+        if bytes[5] != expected_cell_class {
+            return Err(invalid_data(format!("Expected cell class {}, found {}", expected_cell_class, bytes[5])));
+        }
+
+        if usize::from(bytes[6]) != expected_dimension {
+            return Err(invalid_data(format!("Expected a {}-dimensional maze, found a {}-dimensional one", expected_dimension, bytes[6])));
+        }
+
+        bytes.truncate(bytes.len() - CHECKSUM_LENGTH);
+
+        return Ok(BinaryReader { bytes, position: HEADER_LENGTH })
+    }
+}
+
+impl Default for BoxSpaceBinaryMazeImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A cursor over a validated input.
+struct BinaryReader {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl BinaryReader {
+    /// Read the next `length` bytes.
+    fn read_bytes(&mut self, length: usize) -> Result<&[u8]> {
+        if self.bytes.len() - self.position < length {
+            return Err(invalid_data("The input is truncated"));
+        }
+
+        self.position += length;
+
+        return Ok(&self.bytes[self.position - length..self.position])
+    }
+
+    /// Check that at least `count` more values of `length` bytes each remain, without reading them.
+    fn check_remaining(&self, count: usize, length: usize) -> Result<()> {
+        match count.checked_mul(length) {
+            Some(total) if total <= self.bytes.len() - self.position => Ok(()),
+            _ => Err(invalid_data("The input is truncated")),
+        }
+    }
+
+    /// Read a `u64` and convert it to a `usize`.
+    fn read_usize(&mut self) -> Result<usize> {
+        let value = u64::from_le_bytes(self.read_bytes(8)?.try_into().expect("[Bug] Read eight bytes"));
+
+        return usize::try_from(value).map_err(|_| invalid_data("A size in the input does not fit within a usize"))
+    }
+
+    /// Check that every byte has been consumed.
+    fn finish(&self) -> Result<()> {
+        if self.position != self.bytes.len() {
+            return Err(invalid_data("The input has unexpected trailing data"));
+        }
+
+        return Ok(())
+    }
+}
\ No newline at end of file
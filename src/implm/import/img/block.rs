@@ -0,0 +1,302 @@
+use std::io::{Read, Result};
+use std::num::NonZeroUsize;
+
+use image::{ImageError, RgbaImage};
+
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::import::img::ImageMazeImporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::import::MazeImporter;
+use crate::internal::util::{invalid_data, NONZERO_USIZE_ONE};
+
+/// An importer that reads bitmaps into [`BoxSpaceBlockCellMazeCoordinator`]s.
+///
+/// Every pixel is classified by its brightness: pixels darker than the
+/// [threshold][BoxSpaceImageMazeImporterBuilder::threshold] become walls and all others become
+/// passages. Fully transparent pixels become unvisited cells. This is the inverse of
+/// [`BoxSpaceImageMazeExporter`][crate::implm::export::img::BoxSpaceImageMazeExporter].
+///
+/// Images are often drawn at more than one pixel per cell. The importer downsamples such images by
+/// reading the centre pixel of each cell. By default the cell size is detected automatically as the
+/// greatest common divisor of the lengths of all runs of same-coloured pixels along each axis. This
+/// works well for cleanly-scaled images, but noisy images (such as scans) will usually be detected
+/// as having a cell size of 1, so you should set the cell size explicitly for those.
+///
+/// By default the scale factors and padding of the maze are then
+/// [inferred][BoxSpaceBlockCellMazeCoordinator::infer_scale_factors_and_padding] from the layout of
+/// its walls. If this is disabled with
+/// [`infer_structure()`][BoxSpaceImageMazeImporterBuilder::infer_structure], the resulting maze has
+/// a scale factor of 1 and no padding, so every cell is a point.
+///
+/// # Examples
+///
+/// A checkerboard drawn with 2×2 pixel cells is detected as such:
+/// ```
+/// use std::io::Cursor;
+/// use image::{ImageFormat, Rgba, RgbaImage};
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// use mazelib::implm::import::img::BoxSpaceImageMazeImporter;
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::import::MazeImporter;
+///
+/// let img = RgbaImage::from_fn(6, 4, |x, y| {
+///     if (x / 2 + y / 2) % 2 == 0 { Rgba([0, 0, 0, 255]) } else { Rgba([255, 255, 255, 255]) }
+/// });
+///
+/// let mut png = Cursor::new(Vec::new());
+/// img.write_to(&mut png, ImageFormat::Png).unwrap();
+///
+/// let maze: BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2> = BoxSpaceImageMazeImporter::default().import(&mut png.get_ref().as_slice()).unwrap();
+///
+/// assert_eq!([3, 2], maze.coord_space().dimensions().map(usize::from));
+/// assert_eq!(BlockCellPrimaryValue::WALL, maze.get([0, 0].into()).cell_type);
+/// assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get([1, 0].into()).cell_type);
+/// ```
+pub struct BoxSpaceImageMazeImporter {
+    /// Pixels with a brightness below this are walls.
+    threshold: u8,
+    /// The width and height of each cell in pixels, or `None` to detect it.
+    cell_size: Option<[NonZeroUsize; 2]>,
+    /// Whether to infer the scale factors and padding of the maze.
+    infer_structure: bool,
+}
+
+impl BoxSpaceImageMazeImporter {
+    /// Construct a new builder for a `BoxSpaceImageMazeImporter`.
+    ///
+    /// Optional, see [`DefaultMazeImporter`][crate::interface::import::DefaultMazeImporter].
+    pub fn builder() -> BoxSpaceImageMazeImporterBuilder {
+        BoxSpaceImageMazeImporterBuilder::new()
+    }
+
+    /// Return the brightness below which pixels are considered walls.
+    #[must_use]
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Return the width and height of each cell in pixels, or `None` if it is detected
+    /// automatically.
+    #[must_use]
+    pub fn cell_size(&self) -> Option<[NonZeroUsize; 2]> {
+        self.cell_size
+    }
+
+    /// Return whether the scale factors and padding of the maze are inferred.
+    #[must_use]
+    pub fn infer_structure(&self) -> bool {
+        self.infer_structure
+    }
+
+    /// Classify every pixel of the image, before downsampling.
+    fn classify_pixels(&self, img: &RgbaImage) -> Vec<Vec<BlockCellPrimaryValue>> {
+        img.rows().map(|row| row.map(|pixel| {
+            let [r, g, b, a] = pixel.0;
+
+            // Rec. 601 luma
+            let brightness = (299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b)) / 1000;
+
+            if a == 0 {
+                BlockCellPrimaryValue::UNVISITED
+            } else if brightness < u32::from(self.threshold) {
+                BlockCellPrimaryValue::WALL
+            } else {
+                BlockCellPrimaryValue::PASSAGE
+            }
+        }).collect()).collect()
+    }
+
+    /// Detect the cell size as the GCD of the run lengths along each axis.
+    fn detect_cell_size(pixels: &[Vec<BlockCellPrimaryValue>]) -> [NonZeroUsize; 2] {
+        let width = pixels[0].len();
+        let height = pixels.len();
+
+        let mut horizontal = width;
+        let mut vertical = height;
+
+        for row in pixels {
+            let mut run = 1;
+
+            for x in 1..=width {
+                if x < width && row[x] == row[x - 1] {
+                    run += 1;
+                } else {
+                    horizontal = gcd(horizontal, run);
+                    run = 1;
+                }
+            }
+        }
+
+        for x in 0..width {
+            let mut run = 1;
+
+            for y in 1..=height {
+                if y < height && pixels[y][x] == pixels[y - 1][x] {
+                    run += 1;
+                } else {
+                    vertical = gcd(vertical, run);
+                    run = 1;
+                }
+            }
+        }
+
+        // Both are at least 1, as every run is at least 1 long
+        return [horizontal, vertical].map(|size| NonZeroUsize::new(size).unwrap_or(NONZERO_USIZE_ONE))
+    }
+}
+
+impl Default for BoxSpaceImageMazeImporter {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// A builder for a [`BoxSpaceImageMazeImporter`].
+#[must_use]
+pub struct BoxSpaceImageMazeImporterBuilder {
+    /// Pixels with a brightness below this are walls.
+    threshold: u8,
+    /// The width and height of each cell in pixels, or `None` to detect it.
+    cell_size: Option<[NonZeroUsize; 2]>,
+    /// Whether to infer the scale factors and padding of the maze.
+    infer_structure: bool,
+}
+
+impl BoxSpaceImageMazeImporterBuilder {
+    /// Create a new builder for a [`BoxSpaceImageMazeImporter`].
+    fn new() -> Self {
+        Self {
+            threshold: 128,
+            cell_size: None,
+            infer_structure: true,
+        }
+    }
+
+    /// Set the brightness (0--255) below which pixels are considered walls.
+    pub fn threshold(mut self, threshold: u8) -> Self {
+        self.threshold = threshold;
+
+        return self
+    }
+
+    /// Set the width and height of each cell in pixels, instead of detecting it.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::cell_size_checked()`]. If you're using integer literals, you may wish to use this
+    /// instead.
+    pub fn cell_size(mut self, cell_size: [NonZeroUsize; 2]) -> Self {
+        self.cell_size = Some(cell_size);
+
+        return self
+    }
+
+    /// Set the width and height of each cell in pixels, instead of detecting it.
+    ///
+    /// Both sizes must be non-zero.
+    ///
+    /// # Panics
+    ///
+    /// If either size is zero.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::cell_size()`], which takes `NonZeroUsize`s.
+    pub fn cell_size_checked(self, cell_size: [usize; 2]) -> Self {
+        self.cell_size(cell_size.map(|size| NonZeroUsize::new(size).expect("All cell sizes must be non-zero")))
+    }
+
+    /// Detect the cell size automatically from the image. This is the default.
+    pub fn detect_cell_size(mut self) -> Self {
+        self.cell_size = None;
+
+        return self
+    }
+
+    /// Set whether to infer the scale factors and padding of the maze from its walls. Enabled by
+    /// default.
+    ///
+    /// If disabled, the maze has a scale factor of 1 and no padding.
+    ///
+    /// # See Also
+    ///
+    /// [`BoxSpaceBlockCellMazeCoordinator::infer_scale_factors_and_padding()`] for how the
+    /// inference works.
+    pub fn infer_structure(mut self, infer_structure: bool) -> Self {
+        self.infer_structure = infer_structure;
+
+        return self
+    }
+
+    /// Finalise the [`BoxSpaceImageMazeImporter`].
+    #[must_use]
+    pub fn build(self) -> BoxSpaceImageMazeImporter {
+        BoxSpaceImageMazeImporter {
+            threshold: self.threshold,
+            cell_size: self.cell_size,
+            infer_structure: self.infer_structure,
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Input: Read> MazeImporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Input> for BoxSpaceImageMazeImporter {
+    fn import(&self, input: &mut Input) -> Result<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>> {
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+
+        let img = match image::load_from_memory(&bytes) {
+            Ok(img) => img.to_rgba8(),
+            Err(ImageError::IoError(err)) => return Err(err),
+            Err(err) => return Err(invalid_data(err.to_string())),
+        };
+
+        if img.width() == 0 || img.height() == 0 {
+            return Err(invalid_data("The image is empty"));
+        }
+
+        let pixels = self.classify_pixels(&img);
+
+        let [cell_width, cell_height] = self.cell_size.unwrap_or_else(|| Self::detect_cell_size(&pixels)).map(usize::from);
+
+        let width = pixels[0].len() / cell_width;
+        let height = pixels.len() / cell_height;
+
+        if width == 0 || height == 0 {
+            return Err(invalid_data("The image is smaller than a single cell"));
+        }
+
+        let mut maze = BoxSpaceBlockCellMazeCoordinator::<Buffer, 2>::builder(BoxCoordinateSpace::new_checked([width, height]))
+            .scale_factors([NONZERO_USIZE_ONE; 2])
+            .padding([[0, 0]; 2])
+            .build();
+
+        for y in 0..height {
+            for x in 0..width {
+                let cell_type = pixels[y * cell_height + cell_height / 2][x * cell_width + cell_width / 2];
+
+                maze.set_cell_value_type([x, y].into(), cell_type);
+            }
+        }
+
+        if self.infer_structure {
+            maze = maze.restructure_inferred();
+        }
+
+        return Ok(maze)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Input: Read> ImageMazeImporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Input> for BoxSpaceImageMazeImporter {}
+
+/// Greatest common divisor.
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    return a
+}
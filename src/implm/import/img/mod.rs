@@ -0,0 +1,16 @@
+//! Image-related import formats.
+#![cfg(any(feature = "img", doc))]
+
+use std::io::Read;
+
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::import::MazeImporter;
+
+pub use self::block::{BoxSpaceImageMazeImporter, BoxSpaceImageMazeImporterBuilder};
+
+mod block;
+
+/// Import a 2D maze from an image.
+///
+/// Image reading uses the [`image`] crate. Any format it supports this library supports.
+pub trait ImageMazeImporter<M: MazeCoordinator, I: Read> : MazeImporter<M, I> {}
\ No newline at end of file
@@ -0,0 +1,135 @@
+extern crate serde_json;
+
+use std::io::{Read, Result};
+use std::num::NonZeroUsize;
+
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue, VerticalConnector};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::json::schema::{BOX_SPACE_BLOCK_CELL_FORMAT, BOX_SPACE_BLOCK_CELL_VERSION, BoxSpaceBlockCellJson, char_to_cell_type};
+use crate::implm::import::json::{BoxSpaceBlockCellJsonMazeImporter, JsonMazeImporter};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::import::MazeImporter;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::util::{invalid_data, try_usize_array_to_nonzero_usize_array};
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, Input: Read> MazeImporter<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, Input> for BoxSpaceBlockCellJsonMazeImporter {
+    fn import(&self, input: &mut Input) -> Result<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>> {
+        let json: BoxSpaceBlockCellJson = serde_json::from_reader(input)?;
+
+        if json.format != BOX_SPACE_BLOCK_CELL_FORMAT {
+            return Err(invalid_data(format!("Expected format {:?}, found {:?}", BOX_SPACE_BLOCK_CELL_FORMAT, json.format)));
+        }
+
+        if json.version > BOX_SPACE_BLOCK_CELL_VERSION {
+            return Err(invalid_data(format!("Unsupported version {} (the latest supported version is {})", json.version, BOX_SPACE_BLOCK_CELL_VERSION)));
+        }
+
+        let dimensions: [usize; DIMENSION] = to_array(json.dimensions, "dimensions")?;
+        let scale_factors: [usize; DIMENSION] = to_array(json.scale_factors, "scale_factors")?;
+        let padding: [[usize; 2]; DIMENSION] = to_array(json.padding, "padding")?;
+
+        let dimensions = try_usize_array_to_nonzero_usize_array(dimensions).map_err(|_| invalid_data("All dimensions must be non-zero"))?;
+        let scale_factors = try_usize_array_to_nonzero_usize_array(scale_factors).map_err(|_| invalid_data("All scale factors must be non-zero"))?;
+
+        let space = BoxCoordinateSpace::try_new(dimensions).map_err(|err| invalid_data(err.to_string()))?;
+
+        let cell_count = json.cells.iter().map(|row| row.chars().count()).sum::<usize>();
+
+        // Every point is a cell, so this bounds the size of the layout before it's computed
+        if usize::from(space.logical_size()) > cell_count {
+            return Err(invalid_data(format!("Expected at least {} cells, found {}", space.logical_size(), cell_count)));
+        }
+
+        let mut builder = BoxSpaceBlockCellMazeCoordinator::<Buffer, DIMENSION>::builder(space)
+            .scale_factors(scale_factors)
+            .padding(padding);
+
+        if json.spacing.is_empty() == false {
+            let spacing: [Option<Vec<usize>>; DIMENSION] = to_array(json.spacing, "spacing")?;
+
+            for (axis, spacing) in spacing.into_iter().enumerate() {
+                let Some(spacing) = spacing else {
+                    continue
+                };
+
+                if spacing.len() + 1 != usize::from(dimensions[axis]) {
+                    return Err(invalid_data(format!("Expected the spacing of axis {} to have {} entries, found {}", axis, usize::from(dimensions[axis]) - 1, spacing.len())));
+                }
+
+                let spacing = spacing.into_iter().map(NonZeroUsize::new).collect::<Option<Vec<_>>>().ok_or_else(|| invalid_data("All spacings must be non-zero"))?;
+
+                builder = builder.spacing(axis, spacing);
+            }
+        }
+
+        let cells_required = builder.try_cells_required().map_err(|err| invalid_data(err.to_string()))?;
+
+        if usize::from(cells_required) > cell_count {
+            return Err(invalid_data(format!("Expected {} cells, found {}", cells_required, cell_count)));
+        }
+
+        let mut maze = builder.try_build().map_err(|err| invalid_data(err.to_string()))?;
+
+        let full_dimensions = maze.get_full_dimensions();
+        let row_length = usize::from(full_dimensions[0]);
+        let row_count = full_dimensions.iter().skip(1).map(|dim| usize::from(*dim)).product::<usize>();
+
+        if json.cells.len() != row_count {
+            return Err(invalid_data(format!("Expected {} rows of cells, found {}", row_count, json.cells.len())));
+        }
+
+        let mut rows = json.cells.iter();
+        let mut row = "".chars();
+
+        for cell_loc in BoxCoordinateSpace::new(full_dimensions).iter() {
+            if cell_loc[0] == 0 {
+                let next_row = rows.next().expect("[Bug] Row count was checked");
+
+                if next_row.chars().count() != row_length {
+                    return Err(invalid_data(format!("Expected rows of {} cells, found {:?}", row_length, next_row)));
+                }
+
+                row = next_row.chars();
+            }
+
+            let char = row.next().expect("[Bug] Row length was checked");
+
+            maze.get_cell_value_mut(BlockCellLocation(cell_loc)).cell_type = char_to_cell_type(char).ok_or_else(|| invalid_data(format!("Unrecognised cell {:?}", char)))?;
+        }
+
+        for marked in json.marked {
+            let cell_loc: [usize; DIMENSION] = to_array(marked, "marked")?;
+
+            if cell_loc.iter().zip(full_dimensions.iter()).any(|(pos, dim)| *pos >= usize::from(*dim)) {
+                return Err(invalid_data(format!("Marked cell {:?} is outside the maze", cell_loc)));
+            }
+
+            maze.get_cell_value_mut(cell_loc.into()).marked = true;
+        }
+
+        for (stairs, is_up) in [(json.stairs_up, true), (json.stairs_down, false)] {
+            for cell_loc in stairs {
+                let cell_loc: [usize; DIMENSION] = to_array(cell_loc, "stairs")?;
+
+                if cell_loc.iter().zip(full_dimensions.iter()).any(|(pos, dim)| *pos >= usize::from(*dim)) {
+                    return Err(invalid_data(format!("Stair cell {:?} is outside the maze", cell_loc)));
+                }
+
+                let cell = maze.get_cell_value_mut(cell_loc.into());
+                cell.connector = VerticalConnector::from_directions(cell.connector.goes_up() || is_up, cell.connector.goes_down() || is_up == false);
+            }
+        }
+
+        return Ok(maze)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, Input: Read> JsonMazeImporter<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, Input> for BoxSpaceBlockCellJsonMazeImporter {}
+
+/// Convert a JSON array to a fixed-size array, checking that it has one entry per axis.
+fn to_array<T, const DIMENSION: usize>(vec: Vec<T>, field: &str) -> Result<[T; DIMENSION]> {
+    let length = vec.len();
+
+    return vec.try_into().map_err(|_| invalid_data(format!("Expected {} to have {} entries, found {}", field, DIMENSION, length)))
+}
\ No newline at end of file
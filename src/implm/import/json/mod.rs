@@ -0,0 +1,71 @@
+//! JSON import formats.
+//!
+//! These read back the output of the exporters in [`crate::implm::export::json`].
+#![cfg(any(feature = "json", doc))]
+
+use std::io::Read;
+
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::import::MazeImporter;
+
+mod block;
+
+/// Import a maze from JSON.
+pub trait JsonMazeImporter<M: MazeCoordinator, I: Read> : MazeImporter<M, I> {}
+
+/// A [`JsonMazeImporter`] for [`BoxSpaceBlockCellMazeCoordinator`][crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator]s
+/// of any dimension.
+///
+/// Reads the schema written by
+/// [`BoxSpaceBlockCellJsonMazeExporter`][crate::implm::export::json::BoxSpaceBlockCellJsonMazeExporter].
+/// The coordinate space, scale factors, and padding are all restored, so an export-import round
+/// trip is lossless.
+///
+/// The dimension of the maze being imported must match the dimension of the coordinator type
+/// being constructed.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::export::json::BoxSpaceBlockCellJsonMazeExporter;
+/// use mazelib::implm::import::json::BoxSpaceBlockCellJsonMazeImporter;
+/// use mazelib::interface::export::MazeExporter;
+/// use mazelib::interface::import::MazeImporter;
+///
+/// type Maze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 3>;
+///
+/// let mut maze = Maze::builder(BoxCoordinateSpace::new_checked([4, 3, 2])).scale_factors_checked([3, 2, 1]).build();
+/// maze.get_cell_value_mut([1, 2, 1].into()).marked = true;
+///
+/// let mut json = Vec::new();
+/// BoxSpaceBlockCellJsonMazeExporter::new().export(&maze, &mut json).unwrap();
+///
+/// let imported: Maze = BoxSpaceBlockCellJsonMazeImporter::new().import(&mut json.as_slice()).unwrap();
+///
+/// assert_eq!(maze.scale_factors(), imported.scale_factors());
+/// assert_eq!(maze.padding(), imported.padding());
+/// assert!(imported.get_cell_value([1, 2, 1].into()).marked);
+/// ```
+pub struct BoxSpaceBlockCellJsonMazeImporter {
+    _private: ()
+}
+
+impl BoxSpaceBlockCellJsonMazeImporter {
+    /// Construct a new instance.
+    ///
+    /// Optional, see [`DefaultMazeImporter`][crate::interface::import::DefaultMazeImporter].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl Default for BoxSpaceBlockCellJsonMazeImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+//! Importers for common formats.
+//!
+//! # See Also
+//!
+//! * [`MazeImporter`][crate::interface::import::MazeImporter] --- the interface trait
+
+pub mod text;
+pub mod img;
+pub mod json;
+pub mod binary;
\ No newline at end of file
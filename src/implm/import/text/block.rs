@@ -0,0 +1,390 @@
+use std::io::{Read, Result};
+use std::num::NonZeroUsize;
+
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::import::text::TextMazeImporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::import::MazeImporter;
+use crate::internal::util::{invalid_data, NONZERO_USIZE_ONE, NONZERO_USIZE_TWO};
+
+/// An importer that parses text art into [`BoxSpaceBlockCellMazeCoordinator`]s.
+///
+/// Every character (or block of characters, see
+/// [`chars_per_cell_horizontally()`][BoxSpaceBlockCellTextMazeImporterBuilder::chars_per_cell_horizontally])
+/// is one cell. By default it understands the output of
+/// [`BoxSpaceBlockCellTextMazeExporter`][crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter]
+/// as well as common ASCII conventions:
+///
+/// | Cell type | Characters     |
+/// |-----------|----------------|
+/// | Passage   | space          |
+/// | Wall      | `█ # + - \|`   |
+/// | Unvisited | `.`            |
+///
+/// As the text exporter renders walls and boundaries identically, all walls are imported as
+/// [`WALL`][BlockCellPrimaryValue::WALL]s unless boundary characters are explicitly configured.
+///
+/// The dimensions of the maze are inferred from the input. The width is the length of the longest
+/// line. Lines shorter than that are padded with passages, as trailing whitespace is frequently
+/// stripped by editors.
+///
+/// By default the scale factors and padding of the maze are then
+/// [inferred][BoxSpaceBlockCellMazeCoordinator::infer_scale_factors_and_padding] from the layout of
+/// its walls, so its points line up with the ones the art was drawn from. If this is disabled with
+/// [`infer_structure()`][BoxSpaceBlockCellTextMazeImporterBuilder::infer_structure], the resulting
+/// maze has a scale factor of 1 and no padding, so every cell is a point.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// use mazelib::implm::import::text::BoxSpaceBlockCellTextMazeImporter;
+/// use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::interface::import::MazeImporter;
+///
+/// let text = "\
+/// +---+
+/// |   |
+/// | --+
+/// |   |
+/// +---+";
+///
+/// let importer = BoxSpaceBlockCellTextMazeImporter::builder().chars_per_cell_horizontally_checked(1).build();
+///
+/// let maze: BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2> = importer.import(&mut text.as_bytes()).unwrap();
+///
+/// assert_eq!([5, 5], maze.get_full_dimensions().map(usize::from));
+///
+/// // Every other cell is a point, inside a one-cell border
+/// assert_eq!([2, 2], maze.coord_space().dimensions().map(usize::from));
+/// assert_eq!([[1, 1], [1, 1]], maze.padding());
+/// assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get([1, 1].into()).cell_type);
+/// assert_eq!(BlockCellPrimaryValue::WALL, maze.get_cell_value([2, 2].into()).cell_type);
+/// ```
+pub struct BoxSpaceBlockCellTextMazeImporter {
+    /// The characters that are read as passage cells.
+    passage_chars:   Vec<char>,
+    /// The characters that are read as wall cells.
+    wall_chars:      Vec<char>,
+    /// The characters that are read as boundary cells.
+    boundary_chars:  Vec<char>,
+    /// The characters that are read as unvisited cells.
+    unvisited_chars: Vec<char>,
+
+    /// The number of the characters per cell horizontally.
+    chars_per_cell_horizontally: NonZeroUsize,
+    /// The number of the characters per cell vertically.
+    chars_per_cell_vertically:   NonZeroUsize,
+
+    /// Whether to infer the scale factors and padding of the maze.
+    infer_structure: bool,
+}
+
+impl BoxSpaceBlockCellTextMazeImporter {
+    /// Construct a new builder for a `BoxSpaceBlockCellTextMazeImporter`.
+    ///
+    /// Optional, see [`DefaultMazeImporter`][crate::interface::import::DefaultMazeImporter].
+    pub fn builder() -> BoxSpaceBlockCellTextMazeImporterBuilder {
+        BoxSpaceBlockCellTextMazeImporterBuilder::new()
+    }
+
+    /// Return the characters that are read as passage cells.
+    #[must_use]
+    pub fn passage_chars(&self) -> &[char] {
+        &self.passage_chars
+    }
+
+    /// Return the characters that are read as wall cells.
+    #[must_use]
+    pub fn wall_chars(&self) -> &[char] {
+        &self.wall_chars
+    }
+
+    /// Return the characters that are read as boundary cells.
+    #[must_use]
+    pub fn boundary_chars(&self) -> &[char] {
+        &self.boundary_chars
+    }
+
+    /// Return the characters that are read as unvisited cells.
+    #[must_use]
+    pub fn unvisited_chars(&self) -> &[char] {
+        &self.unvisited_chars
+    }
+
+    /// Return the number of the characters per cell horizontally.
+    #[must_use]
+    pub fn chars_per_cell_horizontally(&self) -> NonZeroUsize {
+        self.chars_per_cell_horizontally
+    }
+
+    /// Return the number of the characters per cell vertically.
+    #[must_use]
+    pub fn chars_per_cell_vertically(&self) -> NonZeroUsize {
+        self.chars_per_cell_vertically
+    }
+
+    /// Return whether the scale factors and padding of the maze are inferred.
+    #[must_use]
+    pub fn infer_structure(&self) -> bool {
+        self.infer_structure
+    }
+
+    /// Parse `text` into rows of cells, without constructing a maze.
+    ///
+    /// All rows in the result have the same length. Missing cells at the end of short lines are
+    /// filled with passages.
+    ///
+    /// Returns an error if the text is empty or contains a character that is not recognised.
+    pub fn parse_cells(&self, text: &str) -> Result<Vec<Vec<BlockCellPrimaryValue>>> {
+        let chars_per_cell_horizontally = usize::from(self.chars_per_cell_horizontally);
+        let chars_per_cell_vertically = usize::from(self.chars_per_cell_vertically);
+
+        let mut rows = Vec::new();
+
+        for (line_number, line) in text.lines().enumerate().step_by(chars_per_cell_vertically) {
+            let mut row = Vec::new();
+
+            for (column, char) in line.chars().enumerate().step_by(chars_per_cell_horizontally) {
+                row.push(self.classify(char).ok_or_else(|| invalid_data(format!("Unrecognised character {:?} at line {}, column {}", char, line_number + 1, column + 1)))?);
+            }
+
+            rows.push(row);
+        }
+
+        // Editors are fond of adding a trailing blank line
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        if width == 0 {
+            return Err(invalid_data("The input does not contain a maze"));
+        }
+
+        for row in rows.iter_mut() {
+            row.resize(width, BlockCellPrimaryValue::PASSAGE);
+        }
+
+        return Ok(rows)
+    }
+
+    /// Get the cell type a character represents, if any.
+    fn classify(&self, char: char) -> Option<BlockCellPrimaryValue> {
+        // Check boundaries first so they can be separated from the default wall set
+        if self.boundary_chars.contains(&char) {
+            Some(BlockCellPrimaryValue::BOUNDARY)
+        } else if self.wall_chars.contains(&char) {
+            Some(BlockCellPrimaryValue::WALL)
+        } else if self.passage_chars.contains(&char) {
+            Some(BlockCellPrimaryValue::PASSAGE)
+        } else if self.unvisited_chars.contains(&char) {
+            Some(BlockCellPrimaryValue::UNVISITED)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for BoxSpaceBlockCellTextMazeImporter {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// A builder for a [`BoxSpaceBlockCellTextMazeImporter`].
+#[must_use]
+pub struct BoxSpaceBlockCellTextMazeImporterBuilder {
+    /// The characters that are read as passage cells.
+    passage_chars:   Vec<char>,
+    /// The characters that are read as wall cells.
+    wall_chars:      Vec<char>,
+    /// The characters that are read as boundary cells.
+    boundary_chars:  Vec<char>,
+    /// The characters that are read as unvisited cells.
+    unvisited_chars: Vec<char>,
+
+    /// The number of the characters per cell horizontally.
+    chars_per_cell_horizontally: NonZeroUsize,
+    /// The number of the characters per cell vertically.
+    chars_per_cell_vertically:   NonZeroUsize,
+
+    /// Whether to infer the scale factors and padding of the maze.
+    infer_structure: bool,
+}
+
+impl BoxSpaceBlockCellTextMazeImporterBuilder {
+    /// Create a new builder for a [`BoxSpaceBlockCellTextMazeImporter`].
+    ///
+    /// The defaults match the defaults of
+    /// [`BoxSpaceBlockCellTextMazeExporter`][crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter].
+    fn new() -> Self {
+        Self {
+            passage_chars:   vec![' '],
+            wall_chars:      vec!['█', '#', '+', '-', '|'],
+            boundary_chars:  vec![],
+            unvisited_chars: vec!['.'],
+
+            chars_per_cell_horizontally: NONZERO_USIZE_TWO,
+            chars_per_cell_vertically:   NONZERO_USIZE_ONE,
+
+            infer_structure: true,
+        }
+    }
+
+    /// Set the characters that are read as passage cells.
+    pub fn passage_chars(mut self, chars: &str) -> Self {
+        self.passage_chars = chars.chars().collect();
+
+        return self
+    }
+
+    /// Set the characters that are read as wall cells.
+    pub fn wall_chars(mut self, chars: &str) -> Self {
+        self.wall_chars = chars.chars().collect();
+
+        return self
+    }
+
+    /// Set the characters that are read as boundary cells.
+    ///
+    /// Boundary characters take priority over wall characters, so you can move a character from
+    /// the default wall set into this one without also having to reset the wall set.
+    pub fn boundary_chars(mut self, chars: &str) -> Self {
+        self.boundary_chars = chars.chars().collect();
+
+        return self
+    }
+
+    /// Set the characters that are read as unvisited cells.
+    pub fn unvisited_chars(mut self, chars: &str) -> Self {
+        self.unvisited_chars = chars.chars().collect();
+
+        return self
+    }
+
+    /// Set the number of the characters per cell horizontally.
+    ///
+    /// Only the first character of each cell is inspected.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::chars_per_cell_horizontally_checked()`]. If you're using integer literals, you may
+    /// wish to use this instead.
+    pub fn chars_per_cell_horizontally(mut self, count: NonZeroUsize) -> Self {
+        self.chars_per_cell_horizontally = count;
+
+        return self
+    }
+
+    /// Set the number of the characters per cell horizontally.
+    ///
+    /// `count` must be non-zero.
+    ///
+    /// # Panics
+    ///
+    /// If `count` is zero.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::chars_per_cell_horizontally()`], which takes a `NonZeroUsize`.
+    pub fn chars_per_cell_horizontally_checked(self, count: usize) -> Self {
+        self.chars_per_cell_horizontally(NonZeroUsize::new(count).expect("count was zero"))
+    }
+
+    /// Set the number of the characters (lines) per cell vertically.
+    ///
+    /// Only the first line of each cell is inspected.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::chars_per_cell_vertically_checked()`]. If you're using integer literals, you may
+    /// wish to use this instead.
+    pub fn chars_per_cell_vertically(mut self, count: NonZeroUsize) -> Self {
+        self.chars_per_cell_vertically = count;
+
+        return self
+    }
+
+    /// Set the number of the characters (lines) per cell vertically.
+    ///
+    /// `count` must be non-zero.
+    ///
+    /// # Panics
+    ///
+    /// If `count` is zero.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::chars_per_cell_vertically()`], which takes a `NonZeroUsize`.
+    pub fn chars_per_cell_vertically_checked(self, count: usize) -> Self {
+        self.chars_per_cell_vertically(NonZeroUsize::new(count).expect("count was zero"))
+    }
+
+    /// Set whether to infer the scale factors and padding of the maze from its walls. Enabled by
+    /// default.
+    ///
+    /// If disabled, the maze has a scale factor of 1 and no padding.
+    ///
+    /// # See Also
+    ///
+    /// [`BoxSpaceBlockCellMazeCoordinator::infer_scale_factors_and_padding()`] for how the
+    /// inference works.
+    pub fn infer_structure(mut self, infer_structure: bool) -> Self {
+        self.infer_structure = infer_structure;
+
+        return self
+    }
+
+    /// Finalise the [`BoxSpaceBlockCellTextMazeImporter`].
+    #[must_use]
+    pub fn build(self) -> BoxSpaceBlockCellTextMazeImporter {
+        BoxSpaceBlockCellTextMazeImporter {
+            passage_chars:   self.passage_chars,
+            wall_chars:      self.wall_chars,
+            boundary_chars:  self.boundary_chars,
+            unvisited_chars: self.unvisited_chars,
+
+            chars_per_cell_horizontally: self.chars_per_cell_horizontally,
+            chars_per_cell_vertically:   self.chars_per_cell_vertically,
+
+            infer_structure: self.infer_structure,
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Input: Read> MazeImporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Input> for BoxSpaceBlockCellTextMazeImporter {
+    fn import(&self, input: &mut Input) -> Result<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>> {
+        let mut text = String::new();
+        input.read_to_string(&mut text)?;
+
+        let rows = self.parse_cells(&text)?;
+
+        let width = rows[0].len();
+        let height = rows.len();
+
+        let mut maze = BoxSpaceBlockCellMazeCoordinator::<Buffer, 2>::builder(BoxCoordinateSpace::new_checked([width, height]))
+            .scale_factors([NONZERO_USIZE_ONE; 2])
+            .padding([[0, 0]; 2])
+            .build();
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, cell_type) in row.iter().enumerate() {
+                maze.set_cell_value_type([x, y].into(), *cell_type);
+            }
+        }
+
+        if self.infer_structure {
+            maze = maze.restructure_inferred();
+        }
+
+        return Ok(maze)
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, Input: Read> TextMazeImporter<BoxSpaceBlockCellMazeCoordinator<Buffer, 2>, Input> for BoxSpaceBlockCellTextMazeImporter {}
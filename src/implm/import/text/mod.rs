@@ -0,0 +1,15 @@
+//! Text-based import formats.
+
+use std::io::Read;
+
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::import::MazeImporter;
+
+pub use self::block::{BoxSpaceBlockCellTextMazeImporter, BoxSpaceBlockCellTextMazeImporterBuilder};
+
+mod block;
+
+/// Import a 2D maze from text.
+///
+/// The input must be valid UTF-8.
+pub trait TextMazeImporter<M: MazeCoordinator, I: Read> : MazeImporter<M, I> {}
\ No newline at end of file
@@ -11,4 +11,7 @@ pub mod point;
 pub mod cell;
 pub mod coordinate;
 pub mod export;
-pub mod generate;
\ No newline at end of file
+pub mod import;
+pub mod generate;
+pub mod presets;
+pub mod template;
\ No newline at end of file
@@ -0,0 +1,122 @@
+use std::fmt::{Debug, Formatter};
+
+/// A direction one can move in from a point in a [`BoxCoordinateSpace`][super::BoxCoordinateSpace],
+/// towards a [directly adjacent][super::BoxCoordinateSpace#direct-adjacency] point.
+///
+/// Every [axis][super::BoxCoordinateSpace#coordinate-axes] has two directions, one towards
+/// increasing coordinates (positive) and one towards decreasing coordinates (negative). So there
+/// are `2 * DIMENSION` directions in total.
+///
+/// There are also named constants for the first three axes. Row zero is at the top (north), and
+/// layer zero is at the bottom. Using a constant for an axis the space doesn't have (e.g.
+/// [`UP`][Self::UP] in two dimensions) is a compile-time error.
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+///
+/// let space = BoxCoordinateSpace::new_checked([5, 4]);
+///
+/// assert_eq!(Some(CoordinateTuplet([2, 0])), space.neighbour([2, 1].into(), BoxDirection::NORTH));
+/// assert_eq!(None, space.neighbour([4, 1].into(), BoxDirection::EAST));
+///
+/// assert_eq!(4, BoxDirection::<2>::all().count());
+/// assert_eq!(BoxDirection::<2>::SOUTH, BoxDirection::NORTH.opposite());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BoxDirection<const DIMENSION: usize> {
+    /// The axis moved along.
+    axis: usize,
+    /// Whether the coordinate along the axis increases.
+    positive: bool,
+}
+
+impl <const DIMENSION: usize> BoxDirection<DIMENSION> {
+    /// Construct a new direction.
+    ///
+    /// # Parameters
+    ///
+    /// `axis`     --- the axis to move along.  
+    /// `positive` --- whether to move towards increasing coordinates.
+    ///
+    /// # Panics
+    ///
+    /// If `axis` is not less than `DIMENSION`.
+    #[must_use]
+    pub const fn new(axis: usize, positive: bool) -> Self {
+        assert!(axis < DIMENSION, "The axis must be less than the dimension");
+
+        Self { axis, positive }
+    }
+
+    /// Construct the direction towards increasing coordinates along `axis`.
+    ///
+    /// *See [`new()`][Self::new].*
+    #[must_use]
+    pub const fn positive(axis: usize) -> Self {
+        Self::new(axis, true)
+    }
+
+    /// Construct the direction towards decreasing coordinates along `axis`.
+    ///
+    /// *See [`new()`][Self::new].*
+    #[must_use]
+    pub const fn negative(axis: usize) -> Self {
+        Self::new(axis, false)
+    }
+
+    /// Return an iterator over every direction.
+    ///
+    /// The directions are ordered by axis, and the negative direction of each axis comes before
+    /// the positive direction.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..DIMENSION).flat_map(|axis| [Self::negative(axis), Self::positive(axis)])
+    }
+
+    /// Return the axis moved along.
+    #[must_use]
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+
+    /// Return whether the coordinate along the axis increases.
+    #[must_use]
+    pub fn is_positive(&self) -> bool {
+        self.positive
+    }
+
+    /// Return the change in the coordinate along the axis, either `1` or `-1`.
+    #[must_use]
+    pub fn delta(&self) -> isize {
+        if self.positive { 1 } else { -1 }
+    }
+
+    /// Return the direction facing the other way.
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        Self { axis: self.axis, positive: self.positive == false }
+    }
+}
+
+// Named directions
+impl <const DIMENSION: usize> BoxDirection<DIMENSION> {
+    /// Towards row zero (axis `1`).
+    pub const NORTH: Self = Self::negative(1);
+    /// Towards the last column (axis `0`).
+    pub const EAST: Self = Self::positive(0);
+    /// Away from row zero (axis `1`).
+    pub const SOUTH: Self = Self::positive(1);
+    /// Towards column zero (axis `0`).
+    pub const WEST: Self = Self::negative(0);
+    /// Away from layer zero (axis `2`).
+    pub const UP: Self = Self::positive(2);
+    /// Towards layer zero (axis `2`).
+    pub const DOWN: Self = Self::negative(2);
+}
+
+impl <const DIMENSION: usize> Debug for BoxDirection<DIMENSION> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BoxDirection({}{})", if self.positive { '+' } else { '-' }, self.axis)
+    }
+}
\ No newline at end of file
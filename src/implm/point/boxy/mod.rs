@@ -28,13 +28,20 @@
 //! You could also use [ThreeDimensionalBoxCoordinateSpace] in this example instead of
 //! [BoxCoordinateSpace] — they would be equivalent.
 
+pub use self::direction::BoxDirection;
 pub use self::iterator::BoxCoordinateSpaceIterator;
+#[cfg(any(feature = "rayon", doc))]
+pub use self::par_iter::BoxCoordinateSpaceParallelIterator;
 pub use self::point::CoordinateTuplet;
+pub use self::region::BoxSubRegion;
 pub use self::space::BoxCoordinateSpace;
 
+mod direction;
 mod space;
 mod point;
 mod iterator;
+mod par_iter;
+mod region;
 
 /// A flat rectangular coordinate space. The most common type of coordinate space for mazes.
 ///
@@ -0,0 +1,189 @@
+#![cfg(any(feature = "rayon", doc))]
+
+use std::ops::Range;
+
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::point::CoordinateSpace;
+
+impl <const DIMENSION: usize> BoxCoordinateSpace<DIMENSION> {
+    /// Iterate over all of the points in the space in parallel.
+    ///
+    /// Points are yielded in the same order as [`iter()`][CoordinateSpace::iter], for adapters
+    /// that preserve order (such as [`collect()`][ParallelIterator::collect]).
+    ///
+    /// This is a shorthand for [`into_par_iter()`][IntoParallelIterator::into_par_iter], so you
+    /// don't need to import rayon's prelude to use it.
+    ///
+    /// # Examples
+    ///
+    /// Counting the dead ends of a maze on every core:
+    /// ```
+    /// use rayon::prelude::*;
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::generate::HuntAndKillGenerator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::cell::ConnectionType;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// # use mazelib::interface::generate::DefaultMazeGenerator;
+    /// # use mazelib::interface::point::CoordinateSpace;
+    /// #
+    /// let space = BoxCoordinateSpace::new_checked([20, 20]);
+    ///
+    /// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).build();
+    /// HuntAndKillGenerator::generate(&mut maze);
+    ///
+    /// let dead_ends = space.par_iter().filter(|pt| {
+    ///     space.neighbours_of_pt(*pt).into_iter().filter(|neighbour| maze.get_connection(*pt, *neighbour) == ConnectionType::PASSAGE).count() == 1
+    /// }).count();
+    ///
+    /// assert_eq!(dead_ends, space.iter().filter(|pt| {
+    ///     space.neighbours_of_pt(*pt).into_iter().filter(|neighbour| maze.get_connection(*pt, *neighbour) == ConnectionType::PASSAGE).count() == 1
+    /// }).count());
+    /// ```
+    #[must_use]
+    pub fn par_iter(&self) -> BoxCoordinateSpaceParallelIterator<DIMENSION> {
+        self.into_par_iter()
+    }
+
+    /// Get the point at `index` in the order points are [iterated][CoordinateSpace::iter].
+    #[must_use]
+    fn pt_at_index(&self, mut index: usize) -> CoordinateTuplet<DIMENSION> {
+        let mut pt = [0; DIMENSION];
+
+        for axis in 0..DIMENSION {
+            let dimension = usize::from(self[axis]);
+
+            pt[axis] = index % dimension;
+            index /= dimension;
+        }
+
+        return pt.into()
+    }
+}
+
+impl <const DIMENSION: usize> IntoParallelIterator for BoxCoordinateSpace<DIMENSION> {
+    type Iter = BoxCoordinateSpaceParallelIterator<DIMENSION>;
+    type Item = CoordinateTuplet<DIMENSION>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        BoxCoordinateSpaceParallelIterator { space: self, indices: 0..usize::from(self.logical_size()) }
+    }
+}
+
+impl <const DIMENSION: usize> IntoParallelIterator for &BoxCoordinateSpace<DIMENSION> {
+    type Iter = BoxCoordinateSpaceParallelIterator<DIMENSION>;
+    type Item = CoordinateTuplet<DIMENSION>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        (*self).into_par_iter()
+    }
+}
+
+/// A parallel iterator over all of the points in a box coordinate space, for use with
+/// [rayon].
+///
+/// Can only be obtained by calling [`BoxCoordinateSpace::par_iter()`] or
+/// [`into_par_iter()`][IntoParallelIterator::into_par_iter].
+///
+/// This is an [indexed][IndexedParallelIterator] parallel iterator, so it supports adapters such as
+/// [`zip()`][IndexedParallelIterator::zip] and [`enumerate()`][IndexedParallelIterator::enumerate].
+/// The index of each point is its position in the order of [`iter()`][CoordinateSpace::iter].
+///
+/// # Examples
+///
+/// ```
+/// use rayon::prelude::*;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let space = BoxCoordinateSpace::new_checked([3, 4, 5]);
+///
+/// let points: Vec<_> = space.par_iter().collect();
+///
+/// assert_eq!(space.iter().collect::<Vec<_>>(), points);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BoxCoordinateSpaceParallelIterator<const DIMENSION: usize> {
+    space: BoxCoordinateSpace<DIMENSION>,
+    /// The indices of the points that remain to be yielded.
+    indices: Range<usize>,
+}
+
+impl <const DIMENSION: usize> ParallelIterator for BoxCoordinateSpaceParallelIterator<DIMENSION> {
+    type Item = CoordinateTuplet<DIMENSION>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.indices.len())
+    }
+}
+
+impl <const DIMENSION: usize> IndexedParallelIterator for BoxCoordinateSpaceParallelIterator<DIMENSION> {
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(PointProducer { space: self.space, indices: self.indices })
+    }
+}
+
+/// Splits the points of a space into contiguous ranges of indices for rayon's worker threads,
+/// each of which is then iterated sequentially.
+struct PointProducer<const DIMENSION: usize> {
+    space: BoxCoordinateSpace<DIMENSION>,
+    indices: Range<usize>,
+}
+
+impl <const DIMENSION: usize> Producer for PointProducer<DIMENSION> {
+    type Item = CoordinateTuplet<DIMENSION>;
+    type IntoIter = PointProducerIterator<DIMENSION>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PointProducerIterator { space: self.space, indices: self.indices }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.indices.start + index;
+
+        (Self { space: self.space, indices: self.indices.start..mid }, Self { space: self.space, indices: mid..self.indices.end })
+    }
+}
+
+/// The sequential iterator over one thread's share of the points.
+struct PointProducerIterator<const DIMENSION: usize> {
+    space: BoxCoordinateSpace<DIMENSION>,
+    indices: Range<usize>,
+}
+
+impl <const DIMENSION: usize> Iterator for PointProducerIterator<DIMENSION> {
+    type Item = CoordinateTuplet<DIMENSION>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().map(|index| self.space.pt_at_index(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl <const DIMENSION: usize> DoubleEndedIterator for PointProducerIterator<DIMENSION> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().map(|index| self.space.pt_at_index(index))
+    }
+}
+
+impl <const DIMENSION: usize> ExactSizeIterator for PointProducerIterator<DIMENSION> {}
\ No newline at end of file
@@ -2,6 +2,7 @@ use std::convert::TryInto;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Index, IndexMut};
 
+use crate::implm::point::boxy::BoxDirection;
 use crate::interface::point::Point;
 use crate::internal::util::offset_usize;
 
@@ -47,6 +48,28 @@ impl <const DIMENSION: usize> CoordinateTuplet<DIMENSION> {
         return new;
     }
 
+    /// Get the directly adjacent point in the given direction.
+    ///
+    /// Returns `None` if the neighbour would have a negative coordinate. The neighbour is not
+    /// checked against any coordinate space, so it may still be out-of-bounds. Use
+    /// [`BoxCoordinateSpace::neighbour()`][super::BoxCoordinateSpace::neighbour] for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mazelib::implm::point::boxy::{BoxDirection, CoordinateTuplet};
+    ///
+    /// assert_eq!(Some(CoordinateTuplet([2, 3, 2])), CoordinateTuplet([2, 2, 2]).neighbour(BoxDirection::SOUTH));
+    /// assert_eq!(Some(CoordinateTuplet([2, 2, 1])), CoordinateTuplet([2, 2, 2]).neighbour(BoxDirection::DOWN));
+    /// assert_eq!(None, CoordinateTuplet([0, 0]).neighbour(BoxDirection::WEST));
+    /// ```
+    #[must_use]
+    pub fn neighbour(&self, direction: BoxDirection<DIMENSION>) -> Option<Self> {
+        let axis = direction.axis();
+
+        return Some(self.at(axis, self[axis].checked_add_signed(direction.delta())?))
+    }
+
     /// Replace the position along the given [axis] with a new value.
     ///
     /// Useful when iterating along an axis.
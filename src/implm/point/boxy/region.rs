@@ -0,0 +1,100 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::point::CoordinateSpace;
+
+/// A rectangular sub-region of a [`BoxCoordinateSpace`].
+///
+/// A sub-region is its own (smaller) coordinate space, with its own origin. Every point in the
+/// sub-region corresponds to exactly one point in the parent space, offset by the sub-region's
+/// [origin][Self::origin].
+///
+/// The sub-region's space never [wraps around][BoxCoordinateSpace#wrapping-adjacency], even if the
+/// parent space does. It is [diagonal][BoxCoordinateSpace#diagonal-adjacency] if the parent space
+/// is.
+///
+/// You can construct one with [`BoxCoordinateSpace::sub_region()`].
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+///
+/// let space = BoxCoordinateSpace::new_checked([10, 8]);
+///
+/// let region = space.sub_region_checked([5, 4].into(), [5, 4]);
+///
+/// assert_eq!(CoordinateTuplet([7, 5]), region.to_parent([2, 1].into()));
+/// assert_eq!(Some([0, 0].into()), region.from_parent([5, 4].into()));
+/// assert_eq!(None, region.from_parent([4, 4].into()));
+/// ```
+#[derive(Copy, Clone)]
+pub struct BoxSubRegion<const DIMENSION: usize> {
+    /// The point in the parent space that is the origin of the sub-region.
+    origin: CoordinateTuplet<DIMENSION>,
+    /// The coordinate space of the sub-region.
+    space: BoxCoordinateSpace<DIMENSION>,
+}
+
+impl <const DIMENSION: usize> BoxSubRegion<DIMENSION> {
+    /// Construct a new sub-region.
+    ///
+    /// Validation is done by [`BoxCoordinateSpace::sub_region()`].
+    #[must_use]
+    pub(super) fn new(origin: CoordinateTuplet<DIMENSION>, space: BoxCoordinateSpace<DIMENSION>) -> Self {
+        Self { origin, space }
+    }
+
+    /// Return the point in the parent space that is the origin of the sub-region.
+    #[must_use]
+    pub fn origin(&self) -> CoordinateTuplet<DIMENSION> {
+        self.origin
+    }
+
+    /// Return the coordinate space of the sub-region.
+    #[must_use]
+    pub fn space(&self) -> &BoxCoordinateSpace<DIMENSION> {
+        &self.space
+    }
+
+    /// Map a point in the sub-region to the corresponding point in the parent space.
+    #[must_use]
+    pub fn to_parent(&self, pt: CoordinateTuplet<DIMENSION>) -> CoordinateTuplet<DIMENSION> {
+        CoordinateTuplet(std::array::from_fn(|axis| self.origin[axis] + pt[axis]))
+    }
+
+    /// Map a point in the parent space to the corresponding point in the sub-region.
+    ///
+    /// Returns `None` if the point is not in the sub-region.
+    #[must_use]
+    pub fn from_parent(&self, pt: CoordinateTuplet<DIMENSION>) -> Option<CoordinateTuplet<DIMENSION>> {
+        let mut local = [0; DIMENSION];
+
+        for axis in 0..DIMENSION {
+            local[axis] = pt[axis].checked_sub(self.origin[axis])?;
+
+            if local[axis] >= usize::from(self.space[axis]) {
+                return None
+            }
+        }
+
+        return Some(local.into())
+    }
+
+    /// Return whether the point in the parent space is in the sub-region.
+    #[must_use]
+    pub fn contains(&self, pt: CoordinateTuplet<DIMENSION>) -> bool {
+        self.from_parent(pt).is_some()
+    }
+
+    /// Return an iterator over the points of the sub-region, in parent space coordinates.
+    pub fn iter_parent(&self) -> impl Iterator<Item = CoordinateTuplet<DIMENSION>> + '_ {
+        self.space.iter().map(|pt| self.to_parent(pt))
+    }
+}
+
+impl <const DIMENSION: usize> Debug for BoxSubRegion<DIMENSION> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BoxSubRegion(origin = {:?}, space = {:?})", self.origin, self.space)
+    }
+}
\ No newline at end of file
@@ -4,11 +4,13 @@ use std::ops::Index;
 
 use rand::Rng;
 
-use crate::implm::point::boxy::BoxCoordinateSpaceIterator;
+use crate::error::MazeError;
+use crate::implm::point::boxy::{BoxCoordinateSpaceIterator, BoxDirection, BoxSubRegion};
 use crate::implm::point::boxy::CoordinateTuplet;
 use crate::interface::point::CoordinateSpace;
 use crate::internal::array_util::CheckedProduct;
 use crate::internal::util::try_usize_array_to_nonzero_usize_array;
+use crate::rng::gen_index;
 
 /// An `n`-dimensional coordinate space shaped like a box with box-like points.
 ///
@@ -39,11 +41,35 @@ use crate::internal::util::try_usize_array_to_nonzero_usize_array;
 /// `(1, 1, 1)` and (`1, 2, 2)` are not directly adjacent. `(1, 1, 1)` and `(1, 1, 1)` are
 /// also not considered directly adjacent.
 ///
+/// ## Wrapping Adjacency
+///
+/// Axes can be set to [wrap around][Self::new_wrapping]. Along a wrapping axis, the first and last
+/// positions are also directly adjacent, as if the space were rolled up into a tube. (Or a torus,
+/// if two axes wrap). Mazes generated in such a space tile seamlessly.
+///
+/// For example, in a 5x5 space where axis `0` wraps, `(0, 2)` and `(4, 2)` are directly adjacent.
+///
+/// Axes with fewer than three positions are never considered to wrap, since their first and last
+/// positions are either the same point or already adjacent.
+///
+/// ## Diagonal Adjacency
+///
+/// [Zeta][Self::new_zeta] spaces also consider points to be adjacent diagonally. Two points are
+/// diagonally adjacent if:
+/// * On exactly two coordinate axes, their respective coordinates differ by exactly 1, and,
+/// * On every other axis, their respective coordinates are the same.
+///
+/// For example, `(1, 1, 1)` and `(2, 2, 1)` are diagonally adjacent, but `(1, 1, 1)` and
+/// `(2, 2, 2)` are not. In two dimensions, every point that isn't on the edge has eight neighbours.
+///
+/// Zeta spaces never wrap.
+///
 /// ## Edge Adjacency
 ///
 /// A point is considered to be adjacent to the edge of the coordinate space if there is
-/// some axis where the point's respective coordinate is `0` or the `length of axis - 1`.
-#[derive(Copy, Clone)]
+/// some axis where the point's respective coordinate is `0` or the `length of axis - 1`. Axes that
+/// wrap have no edges.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct BoxCoordinateSpace<const DIMENSION: usize> {
     /// The (size) dimensions of the coordinate space.
     ///
@@ -56,6 +82,12 @@ pub struct BoxCoordinateSpace<const DIMENSION: usize> {
     /// (e.g. (x, y, z), (width, height, depth))
     dimensions: [NonZeroUsize; DIMENSION],
 
+    /// Whether each axis wraps around, so that its first and last positions are adjacent.
+    wrapping: [bool; DIMENSION],
+
+    /// Whether points are also adjacent diagonally.
+    diagonal: bool,
+
     /// The total number of possible points or positions in this coordinate space.
     ///
     /// This just serves as a cache of the result of
@@ -90,15 +122,35 @@ impl <const DIMENSION: usize> BoxCoordinateSpace<DIMENSION> {
     /// # See Also
     ///
     /// [`new_checked()`][Self::new_checked]
+    ///
+    /// [`try_new()`][Self::try_new], to handle dimensions that are too large.
     #[must_use]
     pub fn new(dimensions: [NonZeroUsize; DIMENSION]) -> Self {
+        Self::try_new(dimensions).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Construct a new `BoxCoordinateSpace` from the given (size) dimensions, returning an error
+    /// instead of panicking if they are invalid.
+    ///
+    /// # Errors
+    ///
+    /// [`MazeError::TooManyPoints`] if the product of all dimensions does not fit within a `usize`.
+    ///
+    /// # Panics
+    ///
+    /// If `DIMENSION` is zero.
+    ///
+    /// # See Also
+    ///
+    /// [`new()`][Self::new]
+    pub fn try_new(dimensions: [NonZeroUsize; DIMENSION]) -> Result<Self, MazeError> {
         if DIMENSION == 0 {
             panic!("DIMENSION must be >= 1")
         }
 
-        let size = dimensions.checked_product().expect("The dimensions specified are too large. The number of points in the space does not fit within a usize.");
+        let size = dimensions.checked_product().ok_or(MazeError::TooManyPoints)?;
 
-        Self { dimensions, size }
+        return Ok(Self { dimensions, wrapping: [false; DIMENSION], diagonal: false, size })
     }
 
     /// Construct a new `BoxCoordinateSpace` from the given (size) dimensions.
@@ -119,9 +171,176 @@ impl <const DIMENSION: usize> BoxCoordinateSpace<DIMENSION> {
     /// #
     /// let coord_space = BoxCoordinateSpace::new_checked([5, 7, 3]);
     /// ```
+    ///
+    /// # See Also
+    ///
+    /// [`try_new_checked()`][Self::try_new_checked], to handle invalid dimensions.
     #[must_use]
     pub fn new_checked(dimensions: [usize; DIMENSION]) -> Self {
-        Self::new(try_usize_array_to_nonzero_usize_array(dimensions).expect("All dimensions must be non-zero"))
+        Self::try_new_checked(dimensions).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Construct a new `BoxCoordinateSpace` from the given (size) dimensions, returning an error
+    /// instead of panicking if they are invalid.
+    ///
+    /// # Errors
+    ///
+    /// [`MazeError::ZeroDimension`] if any dimension is zero, or [`MazeError::TooManyPoints`] if the
+    /// product of all dimensions does not fit within a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::error::MazeError;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// #
+    /// assert!(BoxCoordinateSpace::try_new_checked([5, 7]).is_ok());
+    /// assert_eq!(MazeError::ZeroDimension, BoxCoordinateSpace::try_new_checked([5, 0]).unwrap_err());
+    /// ```
+    pub fn try_new_checked(dimensions: [usize; DIMENSION]) -> Result<Self, MazeError> {
+        Self::try_new(try_usize_array_to_nonzero_usize_array(dimensions).map_err(|_| MazeError::ZeroDimension)?)
+    }
+
+    /// Construct a new `BoxCoordinateSpace` from the given (size) dimensions, where some axes wrap
+    /// around.
+    ///
+    /// # Parameters
+    ///
+    /// `dimensions` --- the width, height, depth, etc. of the coordinate space.
+    ///                  *See [`new()`][Self::new].*  
+    /// `wrapping`   --- whether each axis [wraps around](#wrapping-adjacency).
+    ///
+    /// # See Also
+    ///
+    /// [`new_wrapping_checked()`][Self::new_wrapping_checked]
+    #[must_use]
+    pub fn new_wrapping(dimensions: [NonZeroUsize; DIMENSION], wrapping: [bool; DIMENSION]) -> Self {
+        Self { wrapping, ..Self::new(dimensions) }
+    }
+
+    /// Construct a new `BoxCoordinateSpace` from the given (size) dimensions, where some axes wrap
+    /// around.
+    ///
+    /// # Parameters
+    ///
+    /// `dimensions` --- the width, height, depth, etc. of the coordinate space.
+    ///                  *See [`new_checked()`][Self::new_checked].*  
+    /// `wrapping`   --- whether each axis [wraps around](#wrapping-adjacency).
+    ///
+    /// # Examples
+    ///
+    /// A torus:
+    ///
+    /// ```
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::point::CoordinateSpace;
+    /// #
+    /// let coord_space = BoxCoordinateSpace::new_wrapping_checked([5, 4], [true, true]);
+    ///
+    /// assert!(coord_space.are_adjacent((0, 1).into(), (4, 1).into()));
+    /// assert!(coord_space.are_adjacent((2, 0).into(), (2, 3).into()));
+    /// assert_eq!(4, coord_space.neighbours_of_pt((0, 0).into()).len());
+    /// ```
+    #[must_use]
+    pub fn new_wrapping_checked(dimensions: [usize; DIMENSION], wrapping: [bool; DIMENSION]) -> Self {
+        Self::new_wrapping(try_usize_array_to_nonzero_usize_array(dimensions).expect("All dimensions must be non-zero"), wrapping)
+    }
+
+    /// Construct a new `BoxCoordinateSpace` shaped like a cylinder, from the given (size)
+    /// dimensions.
+    ///
+    /// Only axis `0` [wraps around](#wrapping-adjacency), so the space is rolled up into a tube
+    /// whose circumference is its width. This is useful for mazes that will be wrapped around
+    /// something, like a can or a tower.
+    ///
+    /// # Parameters
+    ///
+    /// `dimensions` --- the circumference, height, depth, etc. of the coordinate space.
+    ///                  *See [`new()`][Self::new].*
+    ///
+    /// # See Also
+    ///
+    /// [`new_cylinder_checked()`][Self::new_cylinder_checked]
+    #[must_use]
+    pub fn new_cylinder(dimensions: [NonZeroUsize; DIMENSION]) -> Self {
+        let mut wrapping = [false; DIMENSION];
+        wrapping[0] = true;
+
+        Self::new_wrapping(dimensions, wrapping)
+    }
+
+    /// Construct a new `BoxCoordinateSpace` shaped like a cylinder, from the given (size)
+    /// dimensions.
+    ///
+    /// *See [`new_cylinder()`][Self::new_cylinder].*
+    ///
+    /// # Parameters
+    ///
+    /// `dimensions` --- the circumference, height, depth, etc. of the coordinate space.
+    ///                  *See [`new_checked()`][Self::new_checked].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::point::CoordinateSpace;
+    /// #
+    /// let coord_space = BoxCoordinateSpace::new_cylinder_checked([6, 4]);
+    ///
+    /// assert_eq!([true, false], coord_space.wrapping());
+    /// assert!(coord_space.are_adjacent((0, 1).into(), (5, 1).into()));
+    /// assert!(coord_space.are_adjacent((2, 0).into(), (2, 3).into()) == false);
+    /// ```
+    #[must_use]
+    pub fn new_cylinder_checked(dimensions: [usize; DIMENSION]) -> Self {
+        Self::new_cylinder(try_usize_array_to_nonzero_usize_array(dimensions).expect("All dimensions must be non-zero"))
+    }
+
+    /// Construct a new `BoxCoordinateSpace` from the given (size) dimensions, where points are
+    /// also [adjacent diagonally](#diagonal-adjacency).
+    ///
+    /// Mazes generated in such a space are often called *zeta* mazes, as their passages can run in
+    /// eight directions.
+    ///
+    /// # Parameters
+    ///
+    /// `dimensions` --- the width, height, depth, etc. of the coordinate space.
+    ///                  *See [`new()`][Self::new].*
+    ///
+    /// # See Also
+    ///
+    /// [`new_zeta_checked()`][Self::new_zeta_checked]
+    #[must_use]
+    pub fn new_zeta(dimensions: [NonZeroUsize; DIMENSION]) -> Self {
+        Self { diagonal: true, ..Self::new(dimensions) }
+    }
+
+    /// Construct a new `BoxCoordinateSpace` from the given (size) dimensions, where points are
+    /// also [adjacent diagonally](#diagonal-adjacency).
+    ///
+    /// *See [`new_zeta()`][Self::new_zeta].*
+    ///
+    /// # Parameters
+    ///
+    /// `dimensions` --- the width, height, depth, etc. of the coordinate space.
+    ///                  *See [`new_checked()`][Self::new_checked].*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::point::CoordinateSpace;
+    /// #
+    /// let coord_space = BoxCoordinateSpace::new_zeta_checked([5, 4]);
+    ///
+    /// assert!(coord_space.are_adjacent((1, 1).into(), (2, 2).into()));
+    /// assert!(coord_space.are_adjacent((1, 1).into(), (0, 2).into()));
+    /// assert_eq!(8, coord_space.neighbours_of_pt((1, 1).into()).len());
+    /// assert_eq!(3, coord_space.neighbours_of_pt((0, 0).into()).len());
+    /// ```
+    #[must_use]
+    pub fn new_zeta_checked(dimensions: [usize; DIMENSION]) -> Self {
+        Self::new_zeta(try_usize_array_to_nonzero_usize_array(dimensions).expect("All dimensions must be non-zero"))
     }
 
     /// Return the (size) dimensions of this coordinate space.
@@ -130,10 +349,215 @@ impl <const DIMENSION: usize> BoxCoordinateSpace<DIMENSION> {
         self.dimensions
     }
 
+    /// Return whether each axis [wraps around](#wrapping-adjacency).
+    #[must_use]
+    pub fn wrapping(&self) -> [bool; DIMENSION] {
+        self.wrapping
+    }
+
+    /// Return whether points are also [adjacent diagonally](#diagonal-adjacency).
+    #[must_use]
+    pub fn diagonal(&self) -> bool {
+        self.diagonal
+    }
+
+    /// Return the space spanned by `axes`, in the order given. Each axis keeps its size and
+    /// wrapping, and the new space is diagonal if this one is.
+    #[must_use]
+    pub(crate) fn project<const SLICE_DIMENSION: usize>(&self, axes: [usize; SLICE_DIMENSION]) -> BoxCoordinateSpace<SLICE_DIMENSION> {
+        let space = BoxCoordinateSpace::new_wrapping(axes.map(|axis| self.dimensions[axis]), axes.map(|axis| self.wrapping[axis]));
+
+        return BoxCoordinateSpace { diagonal: self.diagonal, ..space }
+    }
+
+    /// Return a space with the given dimensions, that wraps and is diagonal wherever this one is.
+    pub(crate) fn resize(&self, dimensions: [NonZeroUsize; DIMENSION]) -> Self {
+        Self { diagonal: self.diagonal, ..Self::new_wrapping(dimensions, self.wrapping) }
+    }
+
+    /// Return this space with its axes reordered, so that axis `i` of the new space is axis
+    /// `axes[i]` of this one.
+    pub(crate) fn permute_axes(&self, axes: [usize; DIMENSION]) -> Self {
+        Self { dimensions: axes.map(|axis| self.dimensions[axis]), wrapping: axes.map(|axis| self.wrapping[axis]), ..*self }
+    }
+
+    /// Return a rectangular [sub-region][BoxSubRegion] of this space.
+    ///
+    /// # Parameters
+    ///
+    /// `origin`     --- the point in this space that will be the origin of the sub-region.  
+    /// `dimensions` --- the width, height, depth, etc. of the sub-region.
+    ///
+    /// # Panics
+    ///
+    /// If the sub-region does not fit within this space.
+    ///
+    /// # See Also
+    ///
+    /// [`sub_region_checked()`][Self::sub_region_checked]
+    #[must_use]
+    pub fn sub_region(&self, origin: <Self as CoordinateSpace>::PtType, dimensions: [NonZeroUsize; DIMENSION]) -> BoxSubRegion<DIMENSION> {
+        for axis in 0..DIMENSION {
+            assert!(origin[axis].checked_add(usize::from(dimensions[axis])).is_some_and(|end| end <= usize::from(self[axis])), "The sub-region does not fit within the coordinate space");
+        }
+
+        let space = Self { diagonal: self.diagonal, ..Self::new(dimensions) };
+
+        return BoxSubRegion::new(origin, space)
+    }
+
+    /// Return a rectangular [sub-region][BoxSubRegion] of this space.
+    ///
+    /// *See [`sub_region()`][Self::sub_region].*
+    ///
+    /// # Parameters
+    ///
+    /// `origin`     --- the point in this space that will be the origin of the sub-region.  
+    /// `dimensions` --- the width, height, depth, etc. of the sub-region. All must be non-zero.
+    ///
+    /// # Panics
+    ///
+    /// If any dimension is zero, or if the sub-region does not fit within this space.
+    #[must_use]
+    pub fn sub_region_checked(&self, origin: <Self as CoordinateSpace>::PtType, dimensions: [usize; DIMENSION]) -> BoxSubRegion<DIMENSION> {
+        self.sub_region(origin, try_usize_array_to_nonzero_usize_array(dimensions).expect("All dimensions must be non-zero"))
+    }
+
+    /// Return the axis along which two points are directly adjacent.
+    ///
+    /// Returns `None` if the points are not directly adjacent.
+    #[must_use]
+    pub fn axis_of_adjacency(&self, pt1: <Self as CoordinateSpace>::PtType, pt2: <Self as CoordinateSpace>::PtType) -> Option<usize> {
+        let mut axis_of_adjacency = None;
+
+        for axis in 0..DIMENSION {
+            match pt1[axis].abs_diff(pt2[axis]) {
+                0 => {},
+                1 if axis_of_adjacency.is_none() => axis_of_adjacency = Some(axis),
+                diff if axis_of_adjacency.is_none() && self.does_axis_wrap(axis) && diff == usize::from(self[axis]) - 1 => axis_of_adjacency = Some(axis),
+                _ => return None,
+            }
+        }
+
+        return axis_of_adjacency
+    }
+
+    /// Return the point [directly adjacent](#direct-adjacency) to `pt` in the given direction.
+    ///
+    /// Returns `None` if there is no such point in this space. Along axes that
+    /// [wrap around](#wrapping-adjacency), stepping off one end brings you back at the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+    /// #
+    /// let coord_space = BoxCoordinateSpace::new_cylinder_checked([5, 4]);
+    ///
+    /// assert_eq!(Some(CoordinateTuplet([4, 2])), coord_space.neighbour((0, 2).into(), BoxDirection::WEST));
+    /// assert_eq!(None, coord_space.neighbour((0, 3).into(), BoxDirection::SOUTH));
+    /// ```
+    #[must_use]
+    pub fn neighbour(&self, pt: <Self as CoordinateSpace>::PtType, direction: BoxDirection<DIMENSION>) -> Option<<Self as CoordinateSpace>::PtType> {
+        let axis = direction.axis();
+        let last = usize::from(self[axis]) - 1;
+
+        return match (pt[axis], direction.is_positive()) {
+            (0, false) if self.does_axis_wrap(axis) => Some(pt.at(axis, last)),
+            (position, true) if position == last && self.does_axis_wrap(axis) => Some(pt.at(axis, 0)),
+            _ => pt.neighbour(direction).filter(|neighbour| neighbour[axis] <= last),
+        }
+    }
+
+    /// Return the direction one must travel in to get from `from` to `to`.
+    ///
+    /// Returns `None` if the points are not [directly adjacent](#direct-adjacency).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+    /// #
+    /// let coord_space = BoxCoordinateSpace::new_cylinder_checked([5, 4]);
+    ///
+    /// assert_eq!(Some(BoxDirection::NORTH), coord_space.direction_between((1, 1).into(), (1, 0).into()));
+    /// assert_eq!(Some(BoxDirection::EAST), coord_space.direction_between((4, 1).into(), (0, 1).into()));
+    /// ```
+    #[must_use]
+    pub fn direction_between(&self, from: <Self as CoordinateSpace>::PtType, to: <Self as CoordinateSpace>::PtType) -> Option<BoxDirection<DIMENSION>> {
+        let axis = self.axis_of_adjacency(from, to)?;
+
+        // Wrapping reverses the direction
+        let positive = (from[axis] < to[axis]) != self.is_wrapped_between(from, to);
+
+        return Some(BoxDirection::new(axis, positive))
+    }
+
+    /// Return the two axes along which two points are [diagonally adjacent](#diagonal-adjacency),
+    /// in ascending order.
+    ///
+    /// Returns `None` if the points are not diagonally adjacent, including if this space doesn't
+    /// have diagonal adjacency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// #
+    /// let coord_space = BoxCoordinateSpace::new_zeta_checked([5, 4, 3]);
+    ///
+    /// assert_eq!(Some([0, 2]), coord_space.axes_of_diagonal_adjacency((1, 1, 1).into(), (0, 1, 2).into()));
+    /// assert_eq!(None, coord_space.axes_of_diagonal_adjacency((1, 1, 1).into(), (1, 1, 2).into()));  // Directly adjacent
+    /// assert_eq!(None, coord_space.axes_of_diagonal_adjacency((1, 1, 1).into(), (2, 2, 2).into()));
+    /// ```
+    #[must_use]
+    pub fn axes_of_diagonal_adjacency(&self, pt1: <Self as CoordinateSpace>::PtType, pt2: <Self as CoordinateSpace>::PtType) -> Option<[usize; 2]> {
+        if self.diagonal == false {
+            return None
+        }
+
+        let mut axes = Vec::with_capacity(2);
+
+        for axis in 0..DIMENSION {
+            match pt1[axis].abs_diff(pt2[axis]) {
+                0 => {},
+                1 if axes.len() < 2 => axes.push(axis),
+                _ => return None,
+            }
+        }
+
+        return axes.try_into().ok()
+    }
+
+    /// Return whether two points are adjacent by [wrapping around](#wrapping-adjacency) an axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// #
+    /// let coord_space = BoxCoordinateSpace::new_wrapping_checked([5, 4], [true, false]);
+    ///
+    /// assert!(coord_space.is_wrapped_between((4, 1).into(), (0, 1).into()));
+    /// assert!(coord_space.is_wrapped_between((3, 1).into(), (4, 1).into()) == false);
+    /// assert!(coord_space.is_wrapped_between((2, 0).into(), (2, 3).into()) == false);  // Not adjacent
+    /// ```
+    #[must_use]
+    pub fn is_wrapped_between(&self, pt1: <Self as CoordinateSpace>::PtType, pt2: <Self as CoordinateSpace>::PtType) -> bool {
+        match self.axis_of_adjacency(pt1, pt2) {
+            Some(axis) => pt1[axis].abs_diff(pt2[axis]) > 1,
+            None => false,
+        }
+    }
+
     /// Return whether the point is [adjacent to the edge of this coordinate space](#edge-adjacency).
     #[must_use]
     pub fn is_adjacent_to_edge(&self, pt: <Self as CoordinateSpace>::PtType) -> bool {
         for i in 0..DIMENSION {
+            if self.does_axis_wrap(i) {
+                continue
+            }
+
             if pt[i] == 0 || pt[i] == usize::from(self[i]) - 1 {
                 return true
             }
@@ -141,6 +565,32 @@ impl <const DIMENSION: usize> BoxCoordinateSpace<DIMENSION> {
 
         return false
     }
+
+    /// Call `f` with every point that is adjacent to `pt`, in the order of
+    /// [`neighbours_of_pt()`][CoordinateSpace::neighbours_of_pt].
+    pub(crate) fn for_each_neighbour(&self, pt: <Self as CoordinateSpace>::PtType, mut f: impl FnMut(<Self as CoordinateSpace>::PtType)) {
+        BoxDirection::all().filter_map(|direction| self.neighbour(pt, direction)).for_each(&mut f);
+
+        if self.diagonal {
+            for axis1 in 0..DIMENSION {
+                for axis2 in (axis1 + 1)..DIMENSION {
+                    for (offset1, offset2) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+                        if let (Some(pos1), Some(pos2)) = (pt[axis1].checked_add_signed(offset1), pt[axis2].checked_add_signed(offset2)) {
+                            if pos1 < usize::from(self[axis1]) && pos2 < usize::from(self[axis2]) {
+                                f(pt.at(axis1, pos1).at(axis2, pos2))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Return whether the first and last positions along `axis` are adjacent.
+    #[must_use]
+    fn does_axis_wrap(&self, axis: usize) -> bool {
+        self.wrapping[axis] && usize::from(self[axis]) > 2
+    }
 }
 
 impl <const DIMENSION: usize> CoordinateSpace for BoxCoordinateSpace<DIMENSION> {
@@ -154,37 +604,17 @@ impl <const DIMENSION: usize> CoordinateSpace for BoxCoordinateSpace<DIMENSION>
     fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
         let mut neighbours = Vec::with_capacity(DIMENSION ^ 2);
 
-        for dim in 0..DIMENSION {
-            if pt[dim] > 0 {
-                neighbours.push(pt.offset(dim, -1))
-            }
-
-            if pt[dim] + 1 < usize::from(self.dimensions[dim]) {
-                neighbours.push(pt.offset(dim, 1))
-            }
-        }
+        self.neighbours_into(pt, &mut neighbours);
 
         return neighbours
     }
 
-    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
-        let mut found_axis_of_adjacency = false;
-
-        for dim in 0..DIMENSION {
-            match pt1[dim].abs_diff(pt2[dim]) {
-                0 => {},
-                1 => {
-                    if found_axis_of_adjacency {
-                        return false;
-                    } else {
-                        found_axis_of_adjacency = true;
-                    }
-                },
-                _ => return false,
-            }
-        }
+    fn neighbours_into(&self, pt: Self::PtType, neighbours: &mut impl Extend<Self::PtType>) {
+        self.for_each_neighbour(pt, |neighbour| neighbours.extend(Some(neighbour)));
+    }
 
-        return found_axis_of_adjacency;
+    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
+        self.axis_of_adjacency(pt1, pt2).is_some() || self.axes_of_diagonal_adjacency(pt1, pt2).is_some()
     }
 
     fn iter(&self) -> Self::Iter {
@@ -196,7 +626,7 @@ impl <const DIMENSION: usize> CoordinateSpace for BoxCoordinateSpace<DIMENSION>
     }
 
     fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
-        self.dimensions.map(|dim| rng.gen_range(0..usize::from(dim))).into()
+        self.dimensions.map(|dim| gen_index(rng, usize::from(dim))).into()
     }
 }
 
@@ -218,6 +648,14 @@ impl <const DIMENSION: usize> Debug for BoxCoordinateSpace<DIMENSION> {
 
         write!(f, "{}", self[DIMENSION - 1])?;
 
+        if self.wrapping.contains(&true) {
+            write!(f, ", wrapping = {:?}", self.wrapping)?;
+        }
+
+        if self.diagonal {
+            write!(f, ", diagonal")?;
+        }
+
         write!(f, ")")
     }
 }
\ No newline at end of file
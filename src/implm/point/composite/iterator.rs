@@ -0,0 +1,66 @@
+use std::iter::FusedIterator;
+
+use crate::implm::point::composite::{CompositeLayout, CompositePoint};
+use crate::interface::point::CoordinateSpace;
+
+/// An iterator for iterating over all of the points in a composite coordinate space.
+///
+/// Points are yielded one region at a time, in the order of the regions in the layout. Each
+/// region's points are yielded in the order of that region's own iterator. The first point of
+/// each region after the first is not necessarily adjacent to any point before it.
+///
+/// Can only be obtained by calling
+/// [`CompositeCoordinateSpace::iter()`][crate::interface::point::CoordinateSpace::iter] or
+/// [`CompositeCoordinateSpace::iter_from()`][crate::interface::point::CoordinateSpace::iter_from].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::implm::point::composite::{CompositeCoordinateSpace, CompositeLayout, CompositePoint};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let layout = CompositeLayout::new(vec![BoxCoordinateSpace::new_checked([2]), BoxCoordinateSpace::new_checked([1])]);
+/// let mut iter = CompositeCoordinateSpace::new(&layout).iter();
+///
+/// assert_eq!(Some(CompositePoint::new(0, [0].into())), iter.next());
+/// assert_eq!(Some(CompositePoint::new(0, [1].into())), iter.next());
+/// assert_eq!(Some(CompositePoint::new(1, [0].into())), iter.next());
+/// assert_eq!(None, iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+pub struct CompositeCoordinateSpaceIterator<'layout, Space: CoordinateSpace> {
+    layout: &'layout CompositeLayout<Space>,
+    region: usize,
+    points: Space::Iter,
+}
+
+// Constructor
+impl <'layout, Space: CoordinateSpace> CompositeCoordinateSpaceIterator<'layout, Space> {
+    #[must_use]
+    pub(crate) fn new(layout: &'layout CompositeLayout<Space>, region: usize, points: Space::Iter) -> Self {
+        Self { layout, region, points }
+    }
+}
+
+impl <'layout, Space: CoordinateSpace> Iterator for CompositeCoordinateSpaceIterator<'layout, Space> {
+    type Item = CompositePoint<Space::PtType>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pt) = self.points.next() {
+                return Some(CompositePoint::new(self.region, pt))
+            }
+
+            // Iterator is done
+            if self.region + 1 >= self.layout.regions().len() {
+                return None
+            }
+
+            self.region += 1;
+            self.points = self.layout.regions()[self.region].iter();
+        }
+    }
+}
+
+impl <'layout, Space: CoordinateSpace> FusedIterator for CompositeCoordinateSpaceIterator<'layout, Space> {}
\ No newline at end of file
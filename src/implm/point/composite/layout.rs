@@ -0,0 +1,100 @@
+use std::num::NonZeroUsize;
+
+use crate::implm::point::composite::CompositePoint;
+use crate::interface::point::CoordinateSpace;
+
+/// The regions of a [`CompositeCoordinateSpace`][super::CompositeCoordinateSpace], and the
+/// junctions between them.
+///
+/// Every region is a separate coordinate space of the same type. A junction makes two points
+/// adjacent that otherwise wouldn't be, usually in different regions.
+///
+/// # Examples
+///
+/// Two wings joined by a bridge:
+/// ```
+/// use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::point::composite::{CompositeLayout, CompositePoint};
+///
+/// let layout = CompositeLayout::new(vec![BoxCoordinateSpace::new_checked([3, 3]), BoxCoordinateSpace::new_checked([4, 2])])
+///     .join(CompositePoint::new(0, [2, 1].into()), CompositePoint::new(1, [0, 0].into()));
+///
+/// assert_eq!(2, layout.regions().len());
+/// assert_eq!(Some(0), layout.junction_between(CompositePoint::new(1, [0, 0].into()), CompositePoint::new(0, [2, 1].into())));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CompositeLayout<Space: CoordinateSpace> {
+    regions: Vec<Space>,
+    junctions: Vec<[CompositePoint<Space::PtType>; 2]>,
+}
+
+impl <Space: CoordinateSpace> CompositeLayout<Space> {
+    /// Construct a new layout out of `regions`, without any junctions.
+    ///
+    /// # Panics
+    ///
+    /// If `regions` is empty.
+    #[must_use]
+    pub fn new(regions: Vec<Space>) -> Self {
+        assert!(regions.is_empty() == false, "A composite layout must have at least one region");
+
+        Self { regions, junctions: Vec::new() }
+    }
+
+    /// Add a junction between `pt1` and `pt2`, making them adjacent.
+    ///
+    /// Junctions are numbered in the order they are added.
+    ///
+    /// # Panics
+    ///
+    /// If either point's region doesn't exist, if the points are the same, or if they are
+    /// already adjacent.
+    pub fn join(mut self, pt1: CompositePoint<Space::PtType>, pt2: CompositePoint<Space::PtType>) -> Self {
+        assert!(pt1.region < self.regions.len() && pt2.region < self.regions.len(), "The region does not exist");
+        assert!(pt1 != pt2, "A junction cannot join a point to itself");
+        assert!(self.are_adjacent(pt1, pt2) == false, "The points are already adjacent");
+
+        self.junctions.push([pt1, pt2]);
+
+        return self
+    }
+
+    /// Return the regions of this layout.
+    #[must_use]
+    pub fn regions(&self) -> &[Space] {
+        &self.regions
+    }
+
+    /// Return the junctions of this layout, in the order they were added.
+    #[must_use]
+    pub fn junctions(&self) -> &[[CompositePoint<Space::PtType>; 2]] {
+        &self.junctions
+    }
+
+    /// Return the index of the junction between `pt1` and `pt2`, in either order, if there is
+    /// one.
+    #[must_use]
+    pub fn junction_between(&self, pt1: CompositePoint<Space::PtType>, pt2: CompositePoint<Space::PtType>) -> Option<usize> {
+        self.junctions.iter().position(|junction| *junction == [pt1, pt2] || *junction == [pt2, pt1])
+    }
+
+    /// Return the total number of points in every region.
+    #[must_use]
+    pub fn logical_size(&self) -> NonZeroUsize {
+        self.regions.iter().map(|region| region.logical_size())
+            .reduce(|total, size| total.checked_add(usize::from(size)).expect("The number of points in the layout does not fit within a usize"))
+            .expect("[Bug] A composite layout must have at least one region")
+    }
+
+    /// Return whether two points are adjacent, either within a region or by a junction.
+    #[must_use]
+    pub(crate) fn are_adjacent(&self, pt1: CompositePoint<Space::PtType>, pt2: CompositePoint<Space::PtType>) -> bool {
+        self.are_adjacent_within_region(pt1, pt2) || self.junction_between(pt1, pt2).is_some()
+    }
+
+    /// Return whether two points are in the same region, and adjacent within it.
+    #[must_use]
+    pub(crate) fn are_adjacent_within_region(&self, pt1: CompositePoint<Space::PtType>, pt2: CompositePoint<Space::PtType>) -> bool {
+        pt1.region == pt2.region && pt1.region < self.regions.len() && self.regions[pt1.region].are_adjacent(pt1.pt, pt2.pt)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+//! Composite coordinate spaces, for mazes stitched together from several regions.
+//!
+//! A [`CompositeLayout`] holds a number of regions (each a coordinate space in its own right) and
+//! the junctions that join points in different regions together. A [`CompositeCoordinateSpace`]
+//! then presents the whole layout as a single coordinate space, so that generators and solvers
+//! can work across every region at once. For example, two rectangular wings joined by a bridge.
+//!
+//! To build a maze on a composite space, use a
+//! [`CompositeMazeCoordinator`][crate::implm::coordinate::composite::CompositeMazeCoordinator].
+
+pub use self::iterator::CompositeCoordinateSpaceIterator;
+pub use self::layout::CompositeLayout;
+pub use self::point::CompositePoint;
+pub use self::space::CompositeCoordinateSpace;
+
+mod iterator;
+mod layout;
+mod point;
+mod space;
\ No newline at end of file
@@ -0,0 +1,31 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::interface::point::Point;
+
+/// A point in a [composite coordinate space][super::CompositeCoordinateSpace].
+///
+/// It is the index of a region in the space's [layout][super::CompositeLayout], and a point within
+/// that region.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CompositePoint<Pt: Point> {
+    /// The index of the region this point is in.
+    pub region: usize,
+    /// The position of this point within its region.
+    pub pt: Pt,
+}
+
+impl <Pt: Point> CompositePoint<Pt> {
+    /// Construct a new point at `pt` within region `region`.
+    #[must_use]
+    pub fn new(region: usize, pt: Pt) -> Self {
+        Self { region, pt }
+    }
+}
+
+impl <Pt: Point> Point for CompositePoint<Pt> {}
+
+impl <Pt: Point> Debug for CompositePoint<Pt> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "r{}:{:?}", self.region, self.pt)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+use std::fmt::{Debug, Formatter};
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::composite::{CompositeCoordinateSpaceIterator, CompositeLayout, CompositePoint};
+use crate::interface::point::CoordinateSpace;
+use crate::rng::gen_index;
+
+/// A coordinate space made up of the regions of a [`CompositeLayout`].
+///
+/// Every point of every region is a point of this space. Points are adjacent if they're adjacent
+/// within their region, or if there is a junction between them.
+///
+/// As coordinate spaces must be cheap to copy, the space borrows its layout rather than owning
+/// it. The layout must therefore outlive any mazes built on it.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::implm::point::composite::{CompositeCoordinateSpace, CompositeLayout, CompositePoint};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let layout = CompositeLayout::new(vec![BoxCoordinateSpace::new_checked([3, 3]), BoxCoordinateSpace::new_checked([3, 3])])
+///     .join(CompositePoint::new(0, [2, 1].into()), CompositePoint::new(1, [0, 1].into()));
+///
+/// let space = CompositeCoordinateSpace::new(&layout);
+///
+/// assert_eq!(18, usize::from(space.logical_size()));
+/// assert_eq!(4, space.neighbours_of_pt(CompositePoint::new(0, [2, 1].into())).len());
+/// assert!(space.are_adjacent(CompositePoint::new(1, [0, 1].into()), CompositePoint::new(0, [2, 1].into())));
+/// assert!(space.are_adjacent(CompositePoint::new(1, [0, 0].into()), CompositePoint::new(0, [2, 0].into())) == false);
+/// ```
+#[derive(Copy, Clone)]
+pub struct CompositeCoordinateSpace<'layout, Space: CoordinateSpace> {
+    layout: &'layout CompositeLayout<Space>,
+}
+
+impl <'layout, Space: CoordinateSpace> CompositeCoordinateSpace<'layout, Space> {
+    /// Construct a new `CompositeCoordinateSpace` over `layout`.
+    #[must_use]
+    pub fn new(layout: &'layout CompositeLayout<Space>) -> Self {
+        Self { layout }
+    }
+
+    /// Return the layout of this space.
+    #[must_use]
+    pub fn layout(&self) -> &'layout CompositeLayout<Space> {
+        self.layout
+    }
+}
+
+impl <'layout, Space: CoordinateSpace> CoordinateSpace for CompositeCoordinateSpace<'layout, Space> {
+    type PtType = CompositePoint<Space::PtType>;
+    type Iter = CompositeCoordinateSpaceIterator<'layout, Space>;
+
+    fn logical_size(&self) -> NonZeroUsize {
+        self.layout.logical_size()
+    }
+
+    /// *See [`CoordinateSpace::neighbours_of_pt()`].*
+    ///
+    /// The neighbours within `pt`'s region come first, followed by the points it is joined to by
+    /// junctions.
+    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
+        let mut neighbours: Vec<Self::PtType> = self.layout.regions()[pt.region].neighbours_of_pt(pt.pt).into_iter()
+            .map(|neighbour| CompositePoint::new(pt.region, neighbour))
+            .collect();
+
+        for [pt1, pt2] in self.layout.junctions() {
+            if *pt1 == pt {
+                neighbours.push(*pt2);
+            } else if *pt2 == pt {
+                neighbours.push(*pt1);
+            }
+        }
+
+        return neighbours
+    }
+
+    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
+        self.layout.are_adjacent(pt1, pt2)
+    }
+
+    fn iter(&self) -> Self::Iter {
+        CompositeCoordinateSpaceIterator::new(self.layout, 0, self.layout.regions()[0].iter())
+    }
+
+    fn iter_from(&self, pt: Self::PtType) -> Self::Iter {
+        CompositeCoordinateSpaceIterator::new(self.layout, pt.region, self.layout.regions()[pt.region].iter_from(pt.pt))
+    }
+
+    fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
+        // Weight each region by its size, so every point is equally likely
+        let mut index = gen_index(rng, usize::from(self.logical_size()));
+
+        for (region, space) in self.layout.regions().iter().enumerate() {
+            let size = usize::from(space.logical_size());
+
+            if index < size {
+                return CompositePoint::new(region, space.choose(rng))
+            }
+
+            index -= size;
+        }
+
+        unreachable!("[Bug] The index was outside every region")
+    }
+}
+
+impl <Space: CoordinateSpace> Debug for CompositeCoordinateSpace<'_, Space> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CompositeCoordinateSpace({:?})", self.layout)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+use std::iter::FusedIterator;
+
+use crate::implm::point::cube::{CubeCoordinate, CubeCoordinateSpace, CubeFace};
+
+/// An iterator for iterating over all of the points in a cube-surface coordinate space.
+///
+/// Points are yielded face by face, in the order of [`CubeFace::ALL`]. Within each face they are
+/// yielded by `x`, then by `y`, in ascending order. The faces are ordered such that the first
+/// point of each face is adjacent to a face that has already been yielded.
+///
+/// Can only be obtained by calling
+/// [`CubeCoordinateSpace::iter()`][crate::interface::point::CoordinateSpace::iter] or
+/// [`CubeCoordinateSpace::iter_from()`][crate::interface::point::CoordinateSpace::iter_from].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::cube::{CubeCoordinate, CubeCoordinateSpace, CubeFace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let mut iter = CubeCoordinateSpace::new_checked(2).iter();
+///
+/// assert_eq!(Some(CubeCoordinate { face: CubeFace::FRONT, x: 0, y: 0 }), iter.next());
+/// assert_eq!(Some(CubeCoordinate { face: CubeFace::FRONT, x: 1, y: 0 }), iter.next());
+/// assert_eq!(Some(CubeCoordinate { face: CubeFace::FRONT, x: 0, y: 1 }), iter.next());
+/// assert_eq!(Some(CubeCoordinate { face: CubeFace::FRONT, x: 1, y: 1 }), iter.next());
+/// assert_eq!(Some(CubeCoordinate { face: CubeFace::RIGHT, x: 0, y: 0 }), iter.next());
+/// assert_eq!(19, iter.count());
+/// ```
+pub struct CubeCoordinateSpaceIterator {
+    space: CubeCoordinateSpace,
+    pos: Option<CubeCoordinate>,
+}
+
+// Constructor
+impl CubeCoordinateSpaceIterator {
+    #[must_use]
+    pub(crate) fn new(space: CubeCoordinateSpace, starting_pos: Option<CubeCoordinate>) -> Self {
+        Self { space, pos: starting_pos }
+    }
+}
+
+impl Iterator for CubeCoordinateSpaceIterator {
+    type Item = CubeCoordinate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let face_size = usize::from(self.space.face_size());
+
+        match self.pos {
+            None => self.pos = Some(CubeCoordinate { face: CubeFace::FRONT, x: 0, y: 0 }),
+            Some(pt) => {
+                let pt = if pt.x + 1 < face_size {
+                    CubeCoordinate { x: pt.x + 1, ..pt }
+                } else if pt.y + 1 < face_size {
+                    CubeCoordinate { x: 0, y: pt.y + 1, ..pt }
+                } else {
+                    // If this was the last face, the iterator is done
+                    let face = *CubeFace::ALL.get(pt.face as usize + 1)?;
+
+                    CubeCoordinate { face, x: 0, y: 0 }
+                };
+
+                self.pos = Some(pt);
+            }
+        }
+
+        return self.pos
+    }
+}
+
+impl FusedIterator for CubeCoordinateSpaceIterator {}
\ No newline at end of file
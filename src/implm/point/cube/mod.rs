@@ -0,0 +1,12 @@
+//! Cube-surface coordinate spaces, for mazes on the six faces of a cube.
+//!
+//! For more information on how the faces are laid out and stitched together, see
+//! [`CubeCoordinateSpace`].
+
+pub use self::iterator::CubeCoordinateSpaceIterator;
+pub use self::point::{CubeCoordinate, CubeEdge, CubeFace};
+pub use self::space::CubeCoordinateSpace;
+
+mod space;
+mod point;
+mod iterator;
\ No newline at end of file
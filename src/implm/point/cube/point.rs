@@ -0,0 +1,118 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::interface::point::Point;
+
+/// A coordinate on the surface of a cube.
+///
+/// A coordinate is a face of the cube, and the column (`x`) and row (`y`) of a square on that
+/// face. The acceptable ranges for `x` and `y` are `0 <= x, y < space.face_size()`.
+///
+/// Each face has its own `x` and `y` axes. They are oriented so that, when the cube is unfolded
+/// into the net below, `x` increases to the right and `y` increases downwards on every face.
+///
+/// ```text
+///        +------+
+///        | TOP  |
+/// +------+------+------+------+
+/// | LEFT |FRONT |RIGHT | BACK |
+/// +------+------+------+------+
+///        |BOTTOM|
+///        +------+
+/// ```
+///
+/// Cube coordinates are often written out as (`<face>`, `<x>`, `<y>`).
+///
+/// # Examples
+///
+/// It's just a regular struct with all fields public, so you can construct it directly.
+///
+/// ```
+/// # use mazelib::implm::point::cube::{CubeCoordinate, CubeFace};
+/// #
+/// CubeCoordinate { face: CubeFace::FRONT, x: 3, y: 2 };
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CubeCoordinate {
+    /// The face of the cube.
+    pub face: CubeFace,
+
+    /// The column on the face.
+    pub x: usize,
+
+    /// The row on the face.
+    pub y: usize,
+}
+
+impl Point for CubeCoordinate {}
+
+impl Debug for CubeCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {}, {})", self.face, self.x, self.y)
+    }
+}
+
+impl Display for CubeCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The six faces of a cube.
+///
+/// The faces are named as seen from the front of the cube. They are listed in the same order as
+/// [`CubeFace::ALL`], which is also their value when cast to `usize`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CubeFace {
+    /// The face facing the viewer.
+    FRONT,
+    /// The face to the right of the front face.
+    RIGHT,
+    /// The face opposite the front face.
+    BACK,
+    /// The face to the left of the front face.
+    LEFT,
+    /// The face above the front face.
+    TOP,
+    /// The face below the front face.
+    BOTTOM,
+}
+
+impl CubeFace {
+    /// Every face.
+    pub const ALL: [Self; 6] = [Self::FRONT, Self::RIGHT, Self::BACK, Self::LEFT, Self::TOP, Self::BOTTOM];
+}
+
+/// The four edges of a square on a face, and so the four directions one can move in from it.
+///
+/// The edges are relative to the face's own axes (see [`CubeCoordinate`]), not to the cube as a
+/// whole.
+///
+/// The edges are listed in the same order as [`CubeEdge::ALL`], which is also their value when
+/// cast to `usize`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CubeEdge {
+    /// The edge towards `x = 0`.
+    LEFT,
+    /// The edge away from `x = 0`.
+    RIGHT,
+    /// The edge towards `y = 0`.
+    UP,
+    /// The edge away from `y = 0`.
+    DOWN,
+}
+
+impl CubeEdge {
+    /// Every edge.
+    pub const ALL: [Self; 4] = [Self::LEFT, Self::RIGHT, Self::UP, Self::DOWN];
+
+    /// Return the offset of this edge along the face's `x` and `y` axes.
+    #[must_use]
+    pub(super) fn offset(&self) -> [isize; 2] {
+        match self {
+            Self::LEFT => [-1, 0],
+            Self::RIGHT => [1, 0],
+            Self::UP => [0, -1],
+            Self::DOWN => [0, 1],
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,257 @@
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::cube::{CubeCoordinate, CubeCoordinateSpaceIterator, CubeEdge, CubeFace};
+use crate::interface::point::CoordinateSpace;
+use crate::internal::array_util::ArrayZipMap;
+use crate::rng::gen_index;
+
+/// A coordinate space on the surface of a cube.
+///
+/// Each of the six faces of the cube is a square grid of `face_size` by `face_size` points. The
+/// edges of the faces are stitched together as they are on a real cube, so the space has no
+/// boundary. Mazes in this space are the standard approximation of mazes on a sphere.
+///
+/// For details on how each face's axes are oriented, see [`CubeCoordinate`].
+///
+/// The origin of a cube-surface coordinate space is at the point (`FRONT`, 0, 0).
+///
+/// # Adjacency
+///
+/// Two points are adjacent if their squares share an edge. Every point has exactly four
+/// neighbours. Within a face these are the same as in a
+/// [`BoxCoordinateSpace`][crate::implm::point::boxy::BoxCoordinateSpace]. At the edge of a face,
+/// the neighbour is on the face that shares that edge of the cube.
+///
+/// Note that the faces are rotated relative to each other, so which [`CubeEdge`] is shared
+/// depends on the faces involved. For example, the top of the left face is shared with the left of
+/// the top face.
+///
+/// ## Adjacency Example
+///
+/// ```
+/// # use mazelib::implm::point::cube::{CubeCoordinate, CubeCoordinateSpace, CubeEdge, CubeFace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let coord_space = CubeCoordinateSpace::new_checked(3);
+///
+/// let pt = CubeCoordinate { face: CubeFace::LEFT, x: 1, y: 0 };
+///
+/// assert_eq!(CubeCoordinate { face: CubeFace::TOP, x: 0, y: 1 }, coord_space.neighbour(pt, CubeEdge::UP));
+/// assert_eq!(Some(CubeEdge::LEFT), coord_space.edge_between(CubeCoordinate { face: CubeFace::TOP, x: 0, y: 1 }, pt));
+///
+/// assert!(coord_space.are_adjacent(CubeCoordinate { face: CubeFace::FRONT, x: 2, y: 1 }, CubeCoordinate { face: CubeFace::RIGHT, x: 0, y: 1 }));
+/// assert!(coord_space.are_adjacent(CubeCoordinate { face: CubeFace::BACK, x: 2, y: 1 }, CubeCoordinate { face: CubeFace::LEFT, x: 0, y: 1 }));
+/// assert!(coord_space.are_adjacent(pt, pt) == false);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CubeCoordinateSpace {
+    face_size: NonZeroUsize,
+    size: NonZeroUsize,
+}
+
+impl CubeCoordinateSpace {
+    /// Construct a new `CubeCoordinateSpace` with the given number of points along each edge of
+    /// the cube.
+    ///
+    /// `6 * face_size * face_size` must also fit within a `usize`. (Mazes this large won't fit in
+    /// memory anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `face_size` --- the width and height of each face.
+    ///
+    /// # See Also
+    ///
+    /// [`new_checked()`][Self::new_checked]
+    #[must_use]
+    pub fn new(face_size: NonZeroUsize) -> Self {
+        let size = face_size.checked_mul(face_size)
+            .and_then(|face_area| face_area.checked_mul(NonZeroUsize::new(CubeFace::ALL.len()).unwrap()))
+            .expect("The dimensions specified are too large. The number of points in the space does not fit within a usize.");
+
+        Self { face_size, size }
+    }
+
+    /// Construct a new `CubeCoordinateSpace` with the given number of points along each edge of
+    /// the cube.
+    ///
+    /// `6 * face_size * face_size` must also fit within a `usize`. (Mazes this large won't fit in
+    /// memory anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `face_size` --- the width and height of each face.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::cube::CubeCoordinateSpace;
+    /// # use mazelib::interface::point::CoordinateSpace;
+    /// #
+    /// let coord_space = CubeCoordinateSpace::new_checked(5);
+    ///
+    /// assert_eq!(150, usize::from(coord_space.logical_size()));
+    /// ```
+    #[must_use]
+    pub fn new_checked(face_size: usize) -> Self {
+        Self::new(NonZeroUsize::new(face_size).expect("face_size must be non-zero"))
+    }
+
+    /// Return the width and height of each face.
+    #[must_use]
+    pub fn face_size(&self) -> NonZeroUsize {
+        self.face_size
+    }
+
+    /// Return whether `pt` lies within this coordinate space.
+    #[must_use]
+    pub fn contains(&self, pt: CubeCoordinate) -> bool {
+        pt.x < usize::from(self.face_size) && pt.y < usize::from(self.face_size)
+    }
+
+    /// Return the neighbour of `pt` across the given edge.
+    ///
+    /// As the surface of a cube has no boundary, every edge has a neighbour. If the edge is on the
+    /// border of a face, the neighbour is on the adjoining face.
+    ///
+    /// # Panics
+    ///
+    /// If `pt` does not lie within this coordinate space.
+    #[must_use]
+    pub fn neighbour(&self, pt: CubeCoordinate, edge: CubeEdge) -> CubeCoordinate {
+        assert!(self.contains(pt), "pt {:?} does not lie within this coordinate space", pt);
+
+        let face_size = usize::from(self.face_size);
+
+        let [dx, dy] = edge.offset();
+
+        let x = pt.x.checked_add_signed(dx).filter(|x| *x < face_size);
+        let y = pt.y.checked_add_signed(dy).filter(|y| *y < face_size);
+
+        if let (Some(x), Some(y)) = (x, y) {
+            return CubeCoordinate { x, y, ..pt }
+        }
+
+        // Cross over the edge of the face, working in 3D. The cube spans from 0 to 2 * face_size on
+        // each axis, so the centre of every square has odd coordinates and every edge even ones.
+        let (origin, u, v) = self.frame(pt.face);
+
+        let direction = u.map(|i| i * dx).zip_map(&v.map(|i| i * dy), |a, b| a + b);
+
+        // Step from the centre of the square to the edge of the face, then down the adjoining face
+        let parts = [
+            origin,
+            u.map(|i| i * (2 * pt.x as isize + 1)),
+            v.map(|i| i * (2 * pt.y as isize + 1)),
+            direction,
+            Self::normal(pt.face).map(|i| -i),
+        ];
+
+        let neighbour_centre = parts.into_iter().reduce(|a, b| a.zip_map(&b, |a, b| a + b)).unwrap();
+
+        // The adjoining face is the one facing in the direction we stepped
+        let face = CubeFace::ALL.into_iter().find(|face| Self::normal(*face) == direction).expect("Every direction has a face");
+
+        let (origin, u, v) = self.frame(face);
+
+        let relative = neighbour_centre.zip_map(&origin, |a, b| a - b);
+
+        let [x, y] = [u, v].map(|axis| (relative.zip_map(&axis, |a, b| a * b).into_iter().sum::<isize>() - 1) / 2);
+
+        return CubeCoordinate { face, x: x as usize, y: y as usize }
+    }
+
+    /// Return the edge of `from` that is shared with `to`.
+    ///
+    /// Returns `None` if the points are not adjacent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::cube::{CubeCoordinate, CubeCoordinateSpace, CubeEdge, CubeFace};
+    /// #
+    /// let coord_space = CubeCoordinateSpace::new_checked(4);
+    ///
+    /// let from = CubeCoordinate { face: CubeFace::FRONT, x: 2, y: 0 };
+    /// let to = CubeCoordinate { face: CubeFace::TOP, x: 2, y: 3 };
+    ///
+    /// assert_eq!(Some(CubeEdge::UP), coord_space.edge_between(from, to));
+    /// assert_eq!(Some(CubeEdge::DOWN), coord_space.edge_between(to, from));
+    /// ```
+    #[must_use]
+    pub fn edge_between(&self, from: CubeCoordinate, to: CubeCoordinate) -> Option<CubeEdge> {
+        if self.contains(from) == false || self.contains(to) == false {
+            return None
+        }
+
+        CubeEdge::ALL.into_iter().find(|edge| self.neighbour(from, *edge) == to)
+    }
+
+    /// Return the corner of `face` at its origin, and the directions of its `x` and `y` axes.
+    ///
+    /// Positions are in 3D, with the cube spanning from 0 to `2 * face_size` on each axis. `x`
+    /// points right, `y` points down, and `z` points away from the viewer.
+    #[must_use]
+    fn frame(&self, face: CubeFace) -> ([isize; 3], [isize; 3], [isize; 3]) {
+        let n = 2 * usize::from(self.face_size) as isize;
+
+        return match face {
+            CubeFace::FRONT  => ([0, 0, 0], [1, 0, 0],  [0, 1, 0]),
+            CubeFace::RIGHT  => ([n, 0, 0], [0, 0, 1],  [0, 1, 0]),
+            CubeFace::BACK   => ([n, 0, n], [-1, 0, 0], [0, 1, 0]),
+            CubeFace::LEFT   => ([0, 0, n], [0, 0, -1], [0, 1, 0]),
+            CubeFace::TOP    => ([0, 0, n], [1, 0, 0],  [0, 0, -1]),
+            CubeFace::BOTTOM => ([0, n, 0], [1, 0, 0],  [0, 0, 1]),
+        }
+    }
+
+    /// Return the direction `face` faces, out of the cube.
+    #[must_use]
+    fn normal(face: CubeFace) -> [isize; 3] {
+        match face {
+            CubeFace::FRONT  => [0, 0, -1],
+            CubeFace::RIGHT  => [1, 0, 0],
+            CubeFace::BACK   => [0, 0, 1],
+            CubeFace::LEFT   => [-1, 0, 0],
+            CubeFace::TOP    => [0, -1, 0],
+            CubeFace::BOTTOM => [0, 1, 0],
+        }
+    }
+}
+
+impl CoordinateSpace for CubeCoordinateSpace {
+    type PtType = CubeCoordinate;
+    type Iter = CubeCoordinateSpaceIterator;
+
+    fn logical_size(&self) -> NonZeroUsize {
+        self.size
+    }
+
+    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
+        CubeEdge::ALL.into_iter().map(|edge| self.neighbour(pt, edge)).collect()
+    }
+
+    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
+        self.edge_between(pt1, pt2).is_some()
+    }
+
+    fn iter(&self) -> Self::Iter {
+        CubeCoordinateSpaceIterator::new(*self, None)
+    }
+
+    fn iter_from(&self, pt: Self::PtType) -> Self::Iter {
+        CubeCoordinateSpaceIterator::new(*self, Some(pt))
+    }
+
+    fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
+        let face = CubeFace::ALL[gen_index(rng, CubeFace::ALL.len())];
+
+        let x = gen_index(rng, self.face_size.into());
+
+        let y = gen_index(rng, self.face_size.into());
+
+        return Self::PtType { face, x, y }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+use std::iter::FusedIterator;
+
+use crate::implm::point::delta::{DeltaCoordinate, DeltaCoordinateSpace};
+
+/// An iterator for iterating over all of the points in a triangular coordinate space.
+///
+/// Points are yielded by column, then by row, in ascending order. The one exception is that when a
+/// row (other than the first) starts with a triangle that points up, its first two triangles are
+/// swapped. That triangle is not adjacent to the row above, so this ensures every point is adjacent
+/// to a point that has already been yielded.
+///
+/// Can only be obtained by calling
+/// [`DeltaCoordinateSpace::iter()`][crate::interface::point::CoordinateSpace::iter] or
+/// [`DeltaCoordinateSpace::iter_from()`][crate::interface::point::CoordinateSpace::iter_from].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::delta::{DeltaCoordinate, DeltaCoordinateSpace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let mut iter = DeltaCoordinateSpace::new_checked(2, 3).iter();
+///
+/// assert_eq!(Some(DeltaCoordinate { column: 0, row: 0 }), iter.next());
+/// assert_eq!(Some(DeltaCoordinate { column: 1, row: 0 }), iter.next());
+/// assert_eq!(Some(DeltaCoordinate { column: 0, row: 1 }), iter.next());
+/// assert_eq!(Some(DeltaCoordinate { column: 1, row: 1 }), iter.next());
+/// assert_eq!(Some(DeltaCoordinate { column: 1, row: 2 }), iter.next());  // Swapped
+/// assert_eq!(Some(DeltaCoordinate { column: 0, row: 2 }), iter.next());
+/// assert_eq!(None, iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+pub struct DeltaCoordinateSpaceIterator {
+    space: DeltaCoordinateSpace,
+    pos: Option<DeltaCoordinate>,
+}
+
+// Constructor
+impl DeltaCoordinateSpaceIterator {
+    #[must_use]
+    pub(crate) fn new(space: DeltaCoordinateSpace, starting_pos: Option<DeltaCoordinate>) -> Self {
+        Self { space, pos: starting_pos }
+    }
+}
+
+// Internal functions
+impl DeltaCoordinateSpaceIterator {
+    /// Return whether the first two triangles of the row are yielded in reverse order.
+    #[must_use]
+    fn is_row_swapped(&self, row: usize) -> bool {
+        row > 0 && DeltaCoordinate { column: 0, row }.points_up() && usize::from(self.space.columns()) > 1
+    }
+
+    /// Convert between a column and the order it is yielded in within its row.
+    ///
+    /// Swapping is its own inverse, so this works in both directions.
+    #[must_use]
+    fn reorder(&self, row: usize, column: usize) -> usize {
+        match column {
+            0 | 1 if self.is_row_swapped(row) => 1 - column,
+            _ => column,
+        }
+    }
+}
+
+impl Iterator for DeltaCoordinateSpaceIterator {
+    type Item = DeltaCoordinate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.pos {
+            None => self.pos = Some(DeltaCoordinate { column: 0, row: 0 }),
+            Some(pt) => {
+                let index = self.reorder(pt.row, pt.column);
+
+                let pt = if index + 1 == usize::from(self.space.columns()) {
+                    if pt.row + 1 == usize::from(self.space.rows()) {
+                        return None  // Iterator is done
+                    } else {
+                        DeltaCoordinate { column: self.reorder(pt.row + 1, 0), row: pt.row + 1 }
+                    }
+                } else {
+                    DeltaCoordinate { column: self.reorder(pt.row, index + 1), row: pt.row }
+                };
+
+                self.pos = Some(pt);
+            }
+        }
+
+        return self.pos
+    }
+}
+
+impl FusedIterator for DeltaCoordinateSpaceIterator {}
\ No newline at end of file
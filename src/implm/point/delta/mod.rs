@@ -0,0 +1,11 @@
+//! Triangular coordinate spaces, for delta mazes.
+//!
+//! For more information on how triangular coordinates are laid out, see [`DeltaCoordinate`].
+
+pub use self::iterator::DeltaCoordinateSpaceIterator;
+pub use self::point::{DeltaCoordinate, DeltaEdge};
+pub use self::space::DeltaCoordinateSpace;
+
+mod space;
+mod point;
+mod iterator;
\ No newline at end of file
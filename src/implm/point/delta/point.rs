@@ -0,0 +1,145 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::interface::point::Point;
+
+/// A triangular coordinate.
+///
+/// Triangular coordinate spaces are made of rows of triangles that alternate between pointing up
+/// and pointing down. A coordinate is the column and row of a triangle. The acceptable ranges for
+/// these coordinates are `0 <= column < space.columns()` and `0 <= row < space.rows()`.
+///
+/// The triangle at (0, 0) points up. From there, a triangle points up if the sum of its column and
+/// row is even, and down otherwise. This means each row starts with the opposite orientation to the
+/// row above it, so the flat edges of the triangles line up between rows.
+///
+/// Triangular coordinates are often written out as (`<column>`, `<row>`).
+///
+/// Here's an example of a triangular coordinate space with 4 columns and 2 rows:
+///
+/// ```text
+///    __  __
+///  /\  /\  /
+/// /__\/__\/
+/// \  /\  /\
+///  \/__\/__\
+/// ```
+///
+/// # Examples
+///
+/// It's just a regular struct with all fields public, so you can construct it directly.
+///
+/// ```
+/// # use mazelib::implm::point::delta::DeltaCoordinate;
+/// #
+/// DeltaCoordinate { column: 3, row: 2 };
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeltaCoordinate {
+    /// The column coordinate.
+    pub column: usize,
+
+    /// The row coordinate.
+    pub row: usize,
+}
+
+impl Point for DeltaCoordinate {}
+
+impl DeltaCoordinate {
+    /// Return whether the triangle at this coordinate points up.
+    ///
+    /// If it doesn't, it points down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::delta::DeltaCoordinate;
+    /// #
+    /// assert!(DeltaCoordinate { column: 0, row: 0 }.points_up());
+    /// assert!(DeltaCoordinate { column: 1, row: 0 }.points_up() == false);
+    /// assert!(DeltaCoordinate { column: 1, row: 1 }.points_up());
+    /// ```
+    #[must_use]
+    pub fn points_up(&self) -> bool {
+        (self.column + self.row) % 2 == 0
+    }
+
+    /// Return the coordinate of the triangle that shares the given edge with this one.
+    ///
+    /// Returns `None` if the neighbour would have a negative coordinate. The neighbour is not
+    /// checked against any coordinate space, so it may still be out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::delta::{DeltaCoordinate, DeltaEdge};
+    /// #
+    /// let up = DeltaCoordinate { column: 2, row: 0 };
+    /// let down = DeltaCoordinate { column: 2, row: 1 };
+    ///
+    /// assert_eq!(Some(down), up.neighbour(DeltaEdge::BASE));
+    /// assert_eq!(Some(up), down.neighbour(DeltaEdge::BASE));
+    /// assert_eq!(Some(DeltaCoordinate { column: 1, row: 0 }), up.neighbour(DeltaEdge::LEFT));
+    /// ```
+    #[must_use]
+    pub fn neighbour(&self, edge: DeltaEdge) -> Option<Self> {
+        return match edge {
+            DeltaEdge::LEFT => Some(Self { column: self.column.checked_sub(1)?, row: self.row }),
+            DeltaEdge::RIGHT => Some(Self { column: self.column + 1, row: self.row }),
+            DeltaEdge::BASE if self.points_up() => Some(Self { column: self.column, row: self.row + 1 }),
+            DeltaEdge::BASE => Some(Self { column: self.column, row: self.row.checked_sub(1)? }),
+        }
+    }
+}
+
+impl Debug for DeltaCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.column, self.row)
+    }
+}
+
+impl Display for DeltaCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The three edges of a triangle, and so the three directions one can move in from it.
+///
+/// The slanted edges are always on the left and right. The flat edge, the base, is at the bottom
+/// of triangles that point up and at the top of triangles that point down.
+///
+/// The edges are listed in the same order as [`DeltaEdge::ALL`], which is also their value when
+/// cast to `usize`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum DeltaEdge {
+    /// The slanted edge towards column zero.
+    LEFT,
+    /// The slanted edge away from column zero.
+    RIGHT,
+    /// The flat edge, shared with the triangle in the row above or below.
+    BASE,
+}
+
+impl DeltaEdge {
+    /// Every edge.
+    pub const ALL: [Self; 3] = [Self::LEFT, Self::RIGHT, Self::BASE];
+
+    /// Return the edge that the neighbour on the other side of this edge sees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::delta::DeltaEdge;
+    /// #
+    /// assert_eq!(DeltaEdge::RIGHT, DeltaEdge::LEFT.opposite());
+    /// assert_eq!(DeltaEdge::BASE, DeltaEdge::BASE.opposite());
+    /// ```
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::LEFT => Self::RIGHT,
+            Self::RIGHT => Self::LEFT,
+            Self::BASE => Self::BASE,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,174 @@
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::delta::{DeltaCoordinate, DeltaCoordinateSpaceIterator, DeltaEdge};
+use crate::interface::point::CoordinateSpace;
+use crate::rng::gen_index;
+
+/// A triangular coordinate space.
+///
+/// A triangular coordinate space is a grid of triangles with a set number of columns and rows.
+/// Triangles alternate between pointing up and pointing down. For more details on the layout see
+/// [`DeltaCoordinate`].
+///
+/// The origin of a triangular coordinate space is at the point (0, 0).
+///
+/// # Adjacency
+///
+/// Two points are adjacent if their triangles share an edge. Every triangle has up to three
+/// neighbours: the triangles to its left and right in the same row, and the triangle on the other
+/// side of its [base][DeltaEdge::BASE]. That is the triangle below it if it points up, and the
+/// triangle above it if it points down.
+///
+/// ## Adjacency Example
+///
+/// ```
+/// # use mazelib::implm::point::delta::{DeltaCoordinate, DeltaCoordinateSpace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let coord_space = DeltaCoordinateSpace::new_checked(4, 4);
+///
+/// let pt = DeltaCoordinate { column: 1, row: 1 };  // Points up
+///
+/// assert!(coord_space.are_adjacent(pt, DeltaCoordinate { column: 0, row: 1 }));
+/// assert!(coord_space.are_adjacent(pt, DeltaCoordinate { column: 2, row: 1 }));
+/// assert!(coord_space.are_adjacent(pt, DeltaCoordinate { column: 1, row: 2 }));
+///
+/// assert!(coord_space.are_adjacent(pt, DeltaCoordinate { column: 1, row: 0 }) == false);
+/// assert!(coord_space.are_adjacent(pt, pt) == false);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct DeltaCoordinateSpace {
+    columns: NonZeroUsize,
+    rows: NonZeroUsize,
+    size: NonZeroUsize,
+}
+
+impl DeltaCoordinateSpace {
+    /// Construct a new `DeltaCoordinateSpace` from the given dimensions.
+    ///
+    /// `columns * rows` must also fit within a `usize`. (Mazes this large won't fit in memory
+    /// anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `columns` --- the number of triangles in each row.  
+    /// `rows`    --- the number of rows of triangles.
+    ///
+    /// # See Also
+    ///
+    /// [`new_checked()`][Self::new_checked]
+    #[must_use]
+    pub fn new(columns: NonZeroUsize, rows: NonZeroUsize) -> Self {
+        let size = columns.checked_mul(rows).expect("The dimensions specified are too large. The number of points in the space does not fit within a usize.");
+
+        Self { columns, rows, size }
+    }
+
+    /// Construct a new `DeltaCoordinateSpace` from the given dimensions.
+    ///
+    /// `columns * rows` must also fit within a `usize`. (Mazes this large won't fit in memory
+    /// anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `columns` --- the number of triangles in each row.  
+    /// `rows`    --- the number of rows of triangles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::delta::DeltaCoordinateSpace;
+    /// #
+    /// let coord_space = DeltaCoordinateSpace::new_checked(15, 8);
+    /// ```
+    #[must_use]
+    pub fn new_checked(columns: usize, rows: usize) -> Self {
+        Self::new(NonZeroUsize::new(columns).expect("columns must be non-zero"), NonZeroUsize::new(rows).expect("rows must be non-zero"))
+    }
+
+    /// Return the number of columns in this coordinate space.
+    #[must_use]
+    pub fn columns(&self) -> NonZeroUsize {
+        self.columns
+    }
+
+    /// Return the number of rows in this coordinate space.
+    #[must_use]
+    pub fn rows(&self) -> NonZeroUsize {
+        self.rows
+    }
+
+    /// Return whether `pt` lies within this coordinate space.
+    #[must_use]
+    pub fn contains(&self, pt: DeltaCoordinate) -> bool {
+        pt.column < usize::from(self.columns) && pt.row < usize::from(self.rows)
+    }
+
+    /// Return the neighbour of `pt` across the given edge, if it lies within this coordinate
+    /// space.
+    #[must_use]
+    pub fn neighbour(&self, pt: DeltaCoordinate, edge: DeltaEdge) -> Option<DeltaCoordinate> {
+        pt.neighbour(edge).filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Return the edge of `from` that is shared with `to`.
+    ///
+    /// Returns `None` if the points are not adjacent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::delta::{DeltaCoordinate, DeltaCoordinateSpace, DeltaEdge};
+    /// #
+    /// let coord_space = DeltaCoordinateSpace::new_checked(4, 4);
+    ///
+    /// let from = DeltaCoordinate { column: 0, row: 0 };
+    /// let to = DeltaCoordinate { column: 1, row: 0 };
+    ///
+    /// assert_eq!(Some(DeltaEdge::RIGHT), coord_space.edge_between(from, to));
+    /// assert_eq!(Some(DeltaEdge::LEFT), coord_space.edge_between(to, from));
+    /// ```
+    #[must_use]
+    pub fn edge_between(&self, from: DeltaCoordinate, to: DeltaCoordinate) -> Option<DeltaEdge> {
+        if self.contains(from) == false || self.contains(to) == false {
+            return None
+        }
+
+        DeltaEdge::ALL.into_iter().find(|edge| from.neighbour(*edge) == Some(to))
+    }
+}
+
+impl CoordinateSpace for DeltaCoordinateSpace {
+    type PtType = DeltaCoordinate;
+    type Iter = DeltaCoordinateSpaceIterator;
+
+    fn logical_size(&self) -> NonZeroUsize {
+        self.size
+    }
+
+    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
+        DeltaEdge::ALL.into_iter().filter_map(|edge| self.neighbour(pt, edge)).collect()
+    }
+
+    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
+        self.edge_between(pt1, pt2).is_some()
+    }
+
+    fn iter(&self) -> Self::Iter {
+        DeltaCoordinateSpaceIterator::new(*self, None)
+    }
+
+    fn iter_from(&self, pt: Self::PtType) -> Self::Iter {
+        DeltaCoordinateSpaceIterator::new(*self, Some(pt))
+    }
+
+    fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
+        let column = gen_index(rng, self.columns.into());
+
+        let row = gen_index(rng, self.rows.into());
+
+        return Self::PtType { column, row }
+    }
+}
\ No newline at end of file
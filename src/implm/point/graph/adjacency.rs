@@ -0,0 +1,136 @@
+use std::fmt::{Debug, Formatter};
+use std::num::NonZeroUsize;
+
+/// An undirected graph, without self-loops or duplicate edges.
+///
+/// Nodes are identified by their index, from `0` to `node_count - 1`. Edges are also numbered, in
+/// the order they were first given. A graph must have at least one node.
+///
+/// Graphs are turned into coordinate spaces by [`GraphCoordinateSpace`][super::GraphCoordinateSpace],
+/// which borrows them.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::graph::Graph;
+/// #
+/// // A triangle with a tail
+/// let graph = Graph::from_edges(4, [[0, 1], [1, 2], [2, 0], [2, 3]]);
+///
+/// assert_eq!(4, graph.edge_count());
+/// assert_eq!(&[1, 0, 3], graph.neighbours(2));
+/// assert_eq!(Some(3), graph.edge_between(3, 2));
+/// assert_eq!(None, graph.edge_between(0, 3));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Graph {
+    /// The neighbours of each node, in the order their edges were given.
+    neighbours: Vec<Vec<usize>>,
+    /// The index of the edge to each of the corresponding neighbours in `neighbours`.
+    incident_edges: Vec<Vec<usize>>,
+    /// The two nodes joined by each edge.
+    edges: Vec<[usize; 2]>,
+}
+
+impl Graph {
+    /// Construct a new graph from its edges.
+    ///
+    /// # Parameters
+    ///
+    /// `node_count` --- the number of nodes in the graph. Must be non-zero.
+    /// `edges`      --- the pairs of nodes that are joined. Duplicate edges (in either direction)
+    ///                  are ignored.
+    ///
+    /// # Panics
+    ///
+    /// If `node_count` is zero, or any edge refers to a node that doesn't exist or joins a node to
+    /// itself.
+    #[must_use]
+    pub fn from_edges(node_count: usize, edges: impl IntoIterator<Item = [usize; 2]>) -> Self {
+        assert!(node_count > 0, "A graph must have at least one node");
+
+        let mut graph = Self {
+            neighbours: vec![Vec::new(); node_count],
+            incident_edges: vec![Vec::new(); node_count],
+            edges: Vec::new(),
+        };
+
+        for [a, b] in edges {
+            assert!(a < node_count && b < node_count, "The edge {:?} refers to a node that does not exist", [a, b]);
+            assert!(a != b, "The edge {:?} joins a node to itself", [a, b]);
+
+            if graph.edge_between(a, b).is_some() {
+                continue
+            }
+
+            let edge = graph.edges.len();
+
+            graph.edges.push([a, b]);
+            graph.neighbours[a].push(b);
+            graph.incident_edges[a].push(edge);
+            graph.neighbours[b].push(a);
+            graph.incident_edges[b].push(edge);
+        }
+
+        return graph
+    }
+
+    /// Return the number of nodes in the graph.
+    #[must_use]
+    pub fn node_count(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.neighbours.len()).expect("A graph always has at least one node")
+    }
+
+    /// Return the number of edges in the graph.
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Return the nodes joined to `node`, in the order their edges were given.
+    ///
+    /// # Panics
+    ///
+    /// If `node` does not exist.
+    #[must_use]
+    pub fn neighbours(&self, node: usize) -> &[usize] {
+        &self.neighbours[node]
+    }
+
+    /// Return the two nodes joined by edge `edge`.
+    ///
+    /// # Panics
+    ///
+    /// If `edge` does not exist.
+    #[must_use]
+    pub fn edge(&self, edge: usize) -> [usize; 2] {
+        self.edges[edge]
+    }
+
+    /// Return the index of the edge joining nodes `a` and `b`, or `None` if they aren't joined.
+    ///
+    /// # Panics
+    ///
+    /// If `a` does not exist.
+    #[must_use]
+    pub fn edge_between(&self, a: usize, b: usize) -> Option<usize> {
+        self.neighbours[a].iter().position(|neighbour| *neighbour == b).map(|i| self.incident_edges[a][i])
+    }
+
+    /// Return the indices of every edge joined to `node`, in the same order as
+    /// [`neighbours()`][Self::neighbours].
+    ///
+    /// # Panics
+    ///
+    /// If `node` does not exist.
+    #[must_use]
+    pub fn incident_edges(&self, node: usize) -> &[usize] {
+        &self.incident_edges[node]
+    }
+}
+
+impl Debug for Graph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Graph(nodes = {}, edges = {})", self.neighbours.len(), self.edges.len())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use std::iter::FusedIterator;
+use std::num::NonZeroUsize;
+
+use crate::implm::point::graph::GraphPoint;
+
+/// An iterator for iterating over all of the points in a graph coordinate space.
+///
+/// Points are yielded in ascending order of their node index.
+///
+/// Can only be obtained by calling
+/// [`GraphCoordinateSpace::iter()`][crate::interface::point::CoordinateSpace::iter] or
+/// [`GraphCoordinateSpace::iter_from()`][crate::interface::point::CoordinateSpace::iter_from].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::graph::{Graph, GraphCoordinateSpace, GraphPoint};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let graph = Graph::from_edges(2, [[0, 1]]);
+/// let mut iter = GraphCoordinateSpace::new(&graph).iter();
+///
+/// assert_eq!(Some(GraphPoint(0)), iter.next());
+/// assert_eq!(Some(GraphPoint(1)), iter.next());
+/// assert_eq!(None, iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+pub struct GraphCoordinateSpaceIterator {
+    node_count: NonZeroUsize,
+    pos: Option<GraphPoint>,
+}
+
+// Constructor
+impl GraphCoordinateSpaceIterator {
+    #[must_use]
+    pub(crate) fn new(node_count: NonZeroUsize, starting_pos: Option<GraphPoint>) -> Self {
+        Self { node_count, pos: starting_pos }
+    }
+}
+
+impl Iterator for GraphCoordinateSpaceIterator {
+    type Item = GraphPoint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.pos {
+            None => self.pos = Some(GraphPoint(0)),
+            Some(pt) if pt.0 + 1 == usize::from(self.node_count) => return None,  // Iterator is done
+            Some(pt) => self.pos = Some(GraphPoint(pt.0 + 1)),
+        }
+
+        return self.pos
+    }
+}
+
+impl FusedIterator for GraphCoordinateSpaceIterator {}
\ No newline at end of file
@@ -0,0 +1,20 @@
+//! Coordinate spaces for arbitrary graphs, for mazes with irregular layouts.
+//!
+//! Any undirected [`Graph`] can serve as the shape of a maze. Each node is a point and each edge of
+//! the graph is a place a passage could go. For organic-looking mazes built from randomly
+//! scattered points, see [`VoronoiDiagram`].
+//!
+//! With the `petgraph` feature, a [`Graph`] can also be converted from a `petgraph` graph.
+
+pub use self::adjacency::Graph;
+pub use self::iterator::GraphCoordinateSpaceIterator;
+pub use self::point::GraphPoint;
+pub use self::space::GraphCoordinateSpace;
+pub use self::voronoi::VoronoiDiagram;
+
+mod adjacency;
+mod space;
+mod point;
+mod iterator;
+mod petgraph;
+mod voronoi;
\ No newline at end of file
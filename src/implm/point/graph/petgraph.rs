@@ -0,0 +1,42 @@
+#![cfg(any(feature = "petgraph", doc))]
+
+use petgraph::graph::IndexType;
+use petgraph::EdgeType;
+
+use crate::implm::point::graph::Graph;
+
+/// Convert a [`petgraph`] graph into a [`Graph`], so it can be used as the shape of a maze.
+///
+/// Each node becomes the node with the same index. Edge weights and directions are ignored, as
+/// are self-loops. Parallel edges are merged.
+///
+/// Requires the `petgraph` feature.
+///
+/// # Panics
+///
+/// If the graph has no nodes.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::graph::{Graph, GraphCoordinateSpace};
+/// use petgraph::graph::DiGraph;
+///
+/// let petgraph = DiGraph::<(), ()>::from_edges([(0, 1), (1, 2), (2, 1), (2, 2)]);
+///
+/// let graph = Graph::from(&petgraph);
+///
+/// assert_eq!(3, graph.node_count().get());
+/// assert_eq!(2, graph.edge_count());
+///
+/// let space = GraphCoordinateSpace::new(&graph);
+/// ```
+impl <N, E, Ty: EdgeType, Ix: IndexType> From<&petgraph::Graph<N, E, Ty, Ix>> for Graph {
+    fn from(graph: &petgraph::Graph<N, E, Ty, Ix>) -> Self {
+        let edges = graph.raw_edges().iter()
+            .map(|edge| [edge.source().index(), edge.target().index()])
+            .filter(|[a, b]| a != b);
+
+        return Self::from_edges(graph.node_count(), edges)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::interface::point::Point;
+
+/// A point in a [graph coordinate space][super::GraphCoordinateSpace].
+///
+/// It is simply the index of a node in the underlying [graph][super::Graph].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GraphPoint(pub usize);
+
+impl Point for GraphPoint {}
+
+impl Debug for GraphPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "n{}", self.0)
+    }
+}
+
+impl From<usize> for GraphPoint {
+    fn from(node: usize) -> Self {
+        Self(node)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use std::fmt::{Debug, Formatter};
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::graph::{Graph, GraphCoordinateSpaceIterator, GraphPoint};
+use crate::interface::point::CoordinateSpace;
+use crate::rng::gen_index;
+
+/// A coordinate space shaped like an arbitrary [`Graph`].
+///
+/// Every node of the graph is a point, and two points are adjacent if there is an edge between
+/// them. This makes it possible to build mazes of any shape at all, at the cost of there being no
+/// inherent geometry to draw them with.
+///
+/// As coordinate spaces must be cheap to copy, the space borrows its graph rather than owning it.
+/// The graph must therefore outlive any mazes built on it.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::graph::{Graph, GraphCoordinateSpace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let graph = Graph::from_edges(4, [[0, 1], [1, 2], [2, 3], [3, 0]]);
+/// let space = GraphCoordinateSpace::new(&graph);
+///
+/// assert_eq!(4, usize::from(space.logical_size()));
+/// assert!(space.are_adjacent(0.into(), 3.into()));
+/// assert!(space.are_adjacent(0.into(), 2.into()) == false);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GraphCoordinateSpace<'graph> {
+    graph: &'graph Graph,
+}
+
+impl <'graph> GraphCoordinateSpace<'graph> {
+    /// Construct a new `GraphCoordinateSpace` over `graph`.
+    #[must_use]
+    pub fn new(graph: &'graph Graph) -> Self {
+        Self { graph }
+    }
+
+    /// Return the graph this space is shaped like.
+    #[must_use]
+    pub fn graph(&self) -> &'graph Graph {
+        self.graph
+    }
+
+    /// Return whether the point is in this space.
+    #[must_use]
+    pub fn contains(&self, pt: GraphPoint) -> bool {
+        pt.0 < usize::from(self.graph.node_count())
+    }
+}
+
+impl <'graph> CoordinateSpace for GraphCoordinateSpace<'graph> {
+    type PtType = GraphPoint;
+    type Iter = GraphCoordinateSpaceIterator;
+
+    fn logical_size(&self) -> NonZeroUsize {
+        self.graph.node_count()
+    }
+
+    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
+        self.graph.neighbours(pt.0).iter().map(|node| GraphPoint(*node)).collect()
+    }
+
+    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
+        self.contains(pt1) && self.contains(pt2) && self.graph.edge_between(pt1.0, pt2.0).is_some()
+    }
+
+    fn iter(&self) -> Self::Iter {
+        GraphCoordinateSpaceIterator::new(self.graph.node_count(), None)
+    }
+
+    fn iter_from(&self, pt: Self::PtType) -> Self::Iter {
+        GraphCoordinateSpaceIterator::new(self.graph.node_count(), Some(pt))
+    }
+
+    fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
+        GraphPoint(gen_index(rng, usize::from(self.graph.node_count())))
+    }
+}
+
+impl Debug for GraphCoordinateSpace<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GraphCoordinateSpace({:?})", self.graph)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,370 @@
+use std::fmt::{Debug, Formatter};
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::graph::{Graph, GraphCoordinateSpace};
+
+/// A Voronoi diagram of a rectangle, for irregular, organic-looking mazes.
+///
+/// The rectangle is split into one cell for every *site*, where each cell is the region that is
+/// closer to its site than to any other. Two sites are adjacent if their cells share an edge. This
+/// is the same as their [Delaunay] adjacency, except that cells which would only meet outside the
+/// rectangle aren't adjacent.
+///
+/// The diagram provides the [graph][Self::graph] that its mazes are shaped like. Mazes are built on
+/// its [coordinate space][Self::space] and generated like any other. They can then be drawn with
+/// [`VoronoiSvgMazeExporter`][crate::implm::export::svg::VoronoiSvgMazeExporter], which needs the
+/// diagram for its geometry.
+///
+/// The diagram is computed with the Bowyer-Watson algorithm, so construction takes quadratic time
+/// in the worst case. Mazes of a few thousand sites are fine.
+///
+/// [Delaunay]: https://en.wikipedia.org/wiki/Delaunay_triangulation
+///
+/// # Examples
+///
+/// ```
+/// # use rand::thread_rng;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// use mazelib::implm::coordinate::graph::GraphSpaceGraphCellMazeCoordinator;
+/// use mazelib::implm::generate::RecursiveBacktrackerGenerator;
+/// use mazelib::implm::point::graph::VoronoiDiagram;
+/// use mazelib::interface::generate::DefaultMazeGenerator;
+///
+/// let diagram = VoronoiDiagram::random_checked([40.0, 30.0], 200, &mut thread_rng());
+///
+/// let mut maze = GraphSpaceGraphCellMazeCoordinator::<VecBuffer<BlockCellValue>>::builder(diagram.space()).build();
+///
+/// RecursiveBacktrackerGenerator::generate(&mut maze);
+/// ```
+#[derive(Clone)]
+pub struct VoronoiDiagram {
+    /// The width and height of the rectangle.
+    size: [f64; 2],
+    /// The position of every site.
+    sites: Vec<[f64; 2]>,
+    /// The polygon of each site's cell, in anticlockwise order (with y pointing up). Each vertex
+    /// is paired with the site on the other side of the edge that starts at it, or `None` for
+    /// the rectangle's edges.
+    cells: Vec<Vec<([f64; 2], Option<usize>)>>,
+    /// The adjacency of the sites.
+    graph: Graph,
+}
+
+impl VoronoiDiagram {
+    /// Construct the Voronoi diagram of the given sites.
+    ///
+    /// # Parameters
+    ///
+    /// `size`  --- the width and height of the rectangle. Both must be positive.
+    /// `sites` --- the position of every site. There must be at least one, and they must all be
+    ///             distinct and lie within the rectangle.
+    ///
+    /// # Panics
+    ///
+    /// If any of the above conditions are not met.
+    ///
+    /// # Examples
+    ///
+    /// Three sites in a row:
+    /// ```
+    /// # use mazelib::implm::point::graph::VoronoiDiagram;
+    /// #
+    /// let diagram = VoronoiDiagram::new([3.0, 1.0], vec![[0.5, 0.5], [1.5, 0.5], [2.5, 0.5]]);
+    ///
+    /// assert_eq!(&[1], diagram.graph().neighbours(0));
+    /// assert_eq!(&[0, 2], diagram.graph().neighbours(1));
+    /// ```
+    #[must_use]
+    pub fn new(size: [f64; 2], sites: Vec<[f64; 2]>) -> Self {
+        assert!(size.iter().all(|length| *length > 0.0), "The size of the diagram must be positive");
+        assert!(sites.is_empty() == false, "A Voronoi diagram must have at least one site");
+        assert!(sites.iter().all(|[x, y]| (0.0..=size[0]).contains(x) && (0.0..=size[1]).contains(y)), "All sites must lie within the diagram");
+
+        let mut sorted_sites = sites.clone();
+        sorted_sites.sort_by(|a, b| a.partial_cmp(b).expect("Sites are never NaN"));
+        assert!(sorted_sites.windows(2).all(|pair| pair[0] != pair[1]), "All sites must be distinct");
+
+        let delaunay_neighbours = delaunay_neighbours(&sites);
+
+        let epsilon = size[0].max(size[1]) * 1e-9;
+
+        let cells: Vec<_> = (0..sites.len()).map(|site| {
+            let mut cell = clip_cell(size, &sites, site, &delaunay_neighbours[site]);
+
+            // Drop the edges that are only a point long, such as between diagonal neighbours in a
+            // grid, so that we don't leave passages through a single point
+            while let Some(i) = (0..cell.len()).find(|i| cell.len() > 3 && distance(cell[*i].0, cell[(i + 1) % cell.len()].0) <= epsilon) {
+                cell.remove(i);
+            }
+
+            cell
+        }).collect();
+
+        // Only keep adjacencies where both cells agree they share an edge, in case of floating
+        // point error
+        let shares_edge = |site: usize, other: usize| cells[site].iter().any(|(_, label)| *label == Some(other));
+
+        let edges = (0..sites.len()).flat_map(|site| delaunay_neighbours[site].iter().map(move |other| [site, *other]))
+            .filter(|[site, other]| site < other && shares_edge(*site, *other) && shares_edge(*other, *site))
+            .collect::<Vec<_>>();
+
+        let graph = Graph::from_edges(sites.len(), edges);
+
+        Self { size, sites, cells, graph }
+    }
+
+    /// Construct the Voronoi diagram of `site_count` sites scattered uniformly at random over a
+    /// rectangle.
+    ///
+    /// # Parameters
+    ///
+    /// `size`       --- the width and height of the rectangle. Both must be positive.
+    /// `site_count` --- the number of sites, and so the number of points in mazes built on it.
+    /// `rng`        --- the source of randomness for the positions of the sites.
+    ///
+    /// # Panics
+    ///
+    /// If either length of the rectangle is not positive.
+    ///
+    /// # See Also
+    ///
+    /// [`random_checked()`][Self::random_checked]
+    #[must_use]
+    pub fn random(size: [f64; 2], site_count: NonZeroUsize, rng: &mut (impl Rng + ?Sized)) -> Self {
+        assert!(size.iter().all(|length| *length > 0.0), "The size of the diagram must be positive");
+
+        let mut sites = Vec::with_capacity(site_count.into());
+
+        while sites.len() < usize::from(site_count) {
+            let site = [rng.gen_range(0.0..size[0]), rng.gen_range(0.0..size[1])];
+
+            // Vanishingly unlikely, but duplicates aren't allowed
+            if sites.contains(&site) == false {
+                sites.push(site);
+            }
+        }
+
+        Self::new(size, sites)
+    }
+
+    /// Construct the Voronoi diagram of `site_count` sites scattered uniformly at random over a
+    /// rectangle.
+    ///
+    /// *See [`random()`][Self::random].*
+    ///
+    /// # Panics
+    ///
+    /// If `site_count` is zero, or either length of the rectangle is not positive.
+    #[must_use]
+    pub fn random_checked(size: [f64; 2], site_count: usize, rng: &mut (impl Rng + ?Sized)) -> Self {
+        Self::random(size, NonZeroUsize::new(site_count).expect("site_count must be non-zero"), rng)
+    }
+
+    /// Return the width and height of the rectangle.
+    #[must_use]
+    pub fn size(&self) -> [f64; 2] {
+        self.size
+    }
+
+    /// Return the position of every site.
+    #[must_use]
+    pub fn sites(&self) -> &[[f64; 2]] {
+        &self.sites
+    }
+
+    /// Return the vertices of `site`'s cell, in anticlockwise order (with y pointing up).
+    ///
+    /// # Panics
+    ///
+    /// If `site` does not exist.
+    #[must_use]
+    pub fn cell(&self, site: usize) -> Vec<[f64; 2]> {
+        self.cells[site].iter().map(|(vertex, _)| *vertex).collect()
+    }
+
+    /// Return the edges of `site`'s cell, in anticlockwise order (with y pointing up).
+    ///
+    /// Each edge is given by its two ends, and the site on the other side of it. Edges on the
+    /// outside of the rectangle have no site on the other side.
+    ///
+    /// Note that the other site is not necessarily [adjacent][Self::graph], if the edge is too
+    /// short to pass through.
+    ///
+    /// # Panics
+    ///
+    /// If `site` does not exist.
+    #[must_use]
+    pub fn cell_edges(&self, site: usize) -> Vec<([f64; 2], [f64; 2], Option<usize>)> {
+        let cell = &self.cells[site];
+
+        (0..cell.len()).map(|i| (cell[i].0, cell[(i + 1) % cell.len()].0, cell[i].1)).collect()
+    }
+
+    /// Return the graph of which sites are adjacent. Node `i` is site `i`.
+    #[must_use]
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Return a coordinate space for mazes shaped like this diagram.
+    #[must_use]
+    pub fn space(&self) -> GraphCoordinateSpace<'_> {
+        GraphCoordinateSpace::new(&self.graph)
+    }
+}
+
+impl Debug for VoronoiDiagram {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VoronoiDiagram(size = {}x{}, sites = {})", self.size[0], self.size[1], self.sites.len())
+    }
+}
+
+/// A triangle of a Delaunay triangulation, with its circumcircle cached.
+struct Triangle {
+    vertices: [usize; 3],
+    circumcentre: [f64; 2],
+    circumradius_squared: f64,
+}
+
+impl Triangle {
+    fn new(vertices: [usize; 3], points: &[[f64; 2]]) -> Self {
+        let [[ax, ay], [bx, by], [cx, cy]] = vertices.map(|vertex| points[vertex]);
+
+        let d = 2.0 * (ax * (by - cy) + bx * (cy - ay) + cx * (ay - by));
+
+        // Collinear points have no circumcircle, so make sure the triangle is replaced as soon as possible
+        if d.abs() < f64::EPSILON {
+            return Self { vertices, circumcentre: [0.0, 0.0], circumradius_squared: f64::INFINITY }
+        }
+
+        let a2 = ax * ax + ay * ay;
+        let b2 = bx * bx + by * by;
+        let c2 = cx * cx + cy * cy;
+
+        let circumcentre = [
+            (a2 * (by - cy) + b2 * (cy - ay) + c2 * (ay - by)) / d,
+            (a2 * (cx - bx) + b2 * (ax - cx) + c2 * (bx - ax)) / d,
+        ];
+
+        let circumradius_squared = distance_squared(circumcentre, [ax, ay]);
+
+        Self { vertices, circumcentre, circumradius_squared }
+    }
+
+    fn edges(&self) -> [[usize; 2]; 3] {
+        let [a, b, c] = self.vertices;
+
+        [[a, b], [b, c], [c, a]]
+    }
+}
+
+/// Find the neighbours of every site in the Delaunay triangulation, using the Bowyer-Watson
+/// algorithm.
+fn delaunay_neighbours(sites: &[[f64; 2]]) -> Vec<Vec<usize>> {
+    let site_count = sites.len();
+
+    let mut neighbours = vec![Vec::new(); site_count];
+
+    if site_count < 2 {
+        return neighbours
+    }
+
+    // Start with a triangle big enough to contain every site
+    let (min_x, max_x) = sites.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), [x, _]| (min.min(*x), max.max(*x)));
+    let (min_y, max_y) = sites.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), [_, y]| (min.min(*y), max.max(*y)));
+
+    let extent = (max_x - min_x).max(max_y - min_y).max(1.0);
+    let [mid_x, mid_y] = [(min_x + max_x) / 2.0, (min_y + max_y) / 2.0];
+
+    let mut points = sites.to_vec();
+    points.push([mid_x - 20.0 * extent, mid_y - extent]);
+    points.push([mid_x, mid_y + 20.0 * extent]);
+    points.push([mid_x + 20.0 * extent, mid_y - extent]);
+
+    let mut triangles = vec![Triangle::new([site_count, site_count + 1, site_count + 2], &points)];
+
+    for site in 0..site_count {
+        let point = points[site];
+
+        let (bad, good): (Vec<_>, Vec<_>) = triangles.into_iter().partition(|triangle| distance_squared(point, triangle.circumcentre) < triangle.circumradius_squared);
+
+        triangles = good;
+
+        // The edges of the hole left by the bad triangles are the ones that only one of them has
+        let bad_edges: Vec<[usize; 2]> = bad.iter().flat_map(Triangle::edges).collect();
+
+        for [a, b] in &bad_edges {
+            if bad_edges.iter().filter(|[c, d]| (a, b) == (c, d) || (a, b) == (d, c)).count() == 1 {
+                triangles.push(Triangle::new([*a, *b, site], &points));
+            }
+        }
+    }
+
+    for triangle in &triangles {
+        for [a, b] in triangle.edges() {
+            if a < site_count && b < site_count && neighbours[a].contains(&b) == false {
+                neighbours[a].push(b);
+                neighbours[b].push(a);
+            }
+        }
+    }
+
+    for site_neighbours in &mut neighbours {
+        site_neighbours.sort_unstable();
+    }
+
+    return neighbours
+}
+
+/// Find the polygon of `site`'s cell, by clipping the rectangle to the half of the plane that is
+/// closer to `site` than each of its neighbours.
+fn clip_cell(size: [f64; 2], sites: &[[f64; 2]], site: usize, neighbours: &[usize]) -> Vec<([f64; 2], Option<usize>)> {
+    let [width, height] = size;
+
+    let mut cell = vec![([0.0, 0.0], None), ([width, 0.0], None), ([width, height], None), ([0.0, height], None)];
+
+    let [sx, sy] = sites[site];
+
+    for neighbour in neighbours {
+        let [nx, ny] = sites[*neighbour];
+
+        // Positive on the neighbour's side of the perpendicular bisector
+        let side = |[x, y]: [f64; 2]| (x - (sx + nx) / 2.0) * (nx - sx) + (y - (sy + ny) / 2.0) * (ny - sy);
+
+        let mut clipped = Vec::with_capacity(cell.len() + 1);
+
+        for i in 0..cell.len() {
+            let (current, label) = cell[i];
+            let (next, _) = cell[(i + 1) % cell.len()];
+
+            let (current_side, next_side) = (side(current), side(next));
+
+            if current_side <= 0.0 {
+                clipped.push((current, label));
+            }
+
+            if (current_side <= 0.0) != (next_side <= 0.0) {
+                let t = current_side / (current_side - next_side);
+                let intersection = [current[0] + t * (next[0] - current[0]), current[1] + t * (next[1] - current[1])];
+
+                // Leaving the cell means the next edge runs along the bisector
+                clipped.push((intersection, if current_side <= 0.0 { Some(*neighbour) } else { label }));
+            }
+        }
+
+        cell = clipped;
+    }
+
+    return cell
+}
+
+fn distance_squared([ax, ay]: [f64; 2], [bx, by]: [f64; 2]) -> f64 {
+    (ax - bx) * (ax - bx) + (ay - by) * (ay - by)
+}
+
+fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    distance_squared(a, b).sqrt()
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use std::iter::FusedIterator;
+
+use crate::implm::point::hex::{HexCoordinate, HexCoordinateSpace};
+
+/// An iterator for iterating over all of the points in a hexagonal coordinate space.
+///
+/// Points are yielded by column, then by row, in ascending order.
+///
+/// Can only be obtained by calling
+/// [`HexCoordinateSpace::iter()`][crate::interface::point::CoordinateSpace::iter] or
+/// [`HexCoordinateSpace::iter_from()`][crate::interface::point::CoordinateSpace::iter_from].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::hex::{HexCoordinate, HexCoordinateSpace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let mut iter = HexCoordinateSpace::new_checked(2, 2).iter();
+///
+/// assert_eq!(Some(HexCoordinate { column: 0, row: 0 }), iter.next());
+/// assert_eq!(Some(HexCoordinate { column: 1, row: 0 }), iter.next());
+/// assert_eq!(Some(HexCoordinate { column: 0, row: 1 }), iter.next());
+/// assert_eq!(Some(HexCoordinate { column: 1, row: 1 }), iter.next());
+/// assert_eq!(None, iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+pub struct HexCoordinateSpaceIterator {
+    space: HexCoordinateSpace,
+    pos: Option<HexCoordinate>,
+}
+
+// Constructor
+impl HexCoordinateSpaceIterator {
+    #[must_use]
+    pub(crate) fn new(space: HexCoordinateSpace, starting_pos: Option<HexCoordinate>) -> Self {
+        Self { space, pos: starting_pos }
+    }
+}
+
+impl Iterator for HexCoordinateSpaceIterator {
+    type Item = HexCoordinate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.pos {
+            None => self.pos = Some(HexCoordinate { column: 0, row: 0 }),
+            Some(mut pt) => {
+                if pt.column + 1 == usize::from(self.space.columns()) {
+                    if pt.row + 1 == usize::from(self.space.rows()) {
+                        return None  // Iterator is done
+                    } else {
+                        pt.column = 0;
+                        pt.row += 1;
+                    }
+                } else {
+                    pt.column += 1;
+                }
+
+                self.pos = Some(pt);
+            }
+        }
+
+        return self.pos
+    }
+}
+
+impl FusedIterator for HexCoordinateSpaceIterator {}
\ No newline at end of file
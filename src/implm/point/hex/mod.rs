@@ -0,0 +1,11 @@
+//! Hexagonal coordinate spaces, for sigma mazes.
+//!
+//! For more information on how hexagonal coordinates are laid out, see [`HexCoordinate`].
+
+pub use self::iterator::HexCoordinateSpaceIterator;
+pub use self::point::{HexCoordinate, HexDirection};
+pub use self::space::HexCoordinateSpace;
+
+mod space;
+mod point;
+mod iterator;
\ No newline at end of file
@@ -0,0 +1,158 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::interface::point::Point;
+
+/// A hexagonal coordinate.
+///
+/// Hexagonal coordinate spaces are made of flat-topped hexagons arranged in columns. Every odd
+/// column is shifted down by half a hexagon, so that the columns interlock. (This is known as the
+/// "odd-q" offset layout.) A coordinate is the column and row of a hexagon within this layout. The
+/// acceptable ranges for these coordinates are `0 <= column < space.columns()` and
+/// `0 <= row < space.rows()`.
+///
+/// Hexagonal coordinates are often written out as (`<column>`, `<row>`).
+///
+/// Here's an example of a hexagonal coordinate space with 3 columns and 2 rows. Each hexagon is
+/// labelled with its column then its row:
+///
+/// ```text
+///  __    __
+/// /00\__/20\
+/// \__/10\__/
+/// /01\__/21\
+/// \__/11\__/
+///    \__/
+/// ```
+///
+/// Unlike in box coordinate spaces, which hexagons are adjacent depends on whether the column is
+/// odd or even. [See `HexDirection` for the details](HexDirection).
+///
+/// # Examples
+///
+/// It's just a regular struct with all fields public, so you can construct it directly.
+///
+/// ```
+/// # use mazelib::implm::point::hex::HexCoordinate;
+/// #
+/// HexCoordinate { column: 3, row: 2 };
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexCoordinate {
+    /// The column coordinate.
+    pub column: usize,
+
+    /// The row coordinate.
+    pub row: usize,
+}
+
+impl Point for HexCoordinate {}
+
+impl HexCoordinate {
+    /// Return the coordinate of the hexagon that shares the given edge with this one.
+    ///
+    /// Returns `None` if the neighbour would have a negative coordinate. The neighbour is not
+    /// checked against any coordinate space, so it may still be out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::hex::{HexCoordinate, HexDirection};
+    /// #
+    /// let even = HexCoordinate { column: 2, row: 1 };
+    /// let odd = HexCoordinate { column: 3, row: 1 };
+    ///
+    /// assert_eq!(Some(HexCoordinate { column: 3, row: 0 }), even.neighbour(HexDirection::NORTH_EAST));
+    /// assert_eq!(Some(HexCoordinate { column: 4, row: 1 }), odd.neighbour(HexDirection::NORTH_EAST));
+    ///
+    /// assert_eq!(None, HexCoordinate { column: 0, row: 0 }.neighbour(HexDirection::NORTH));
+    /// ```
+    #[must_use]
+    pub fn neighbour(&self, direction: HexDirection) -> Option<Self> {
+        let [column_offset, row_offset] = direction.offset(self.column % 2 == 1);
+
+        let column = self.column.checked_add_signed(column_offset)?;
+        let row = self.row.checked_add_signed(row_offset)?;
+
+        return Some(Self { column, row })
+    }
+}
+
+impl Debug for HexCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.column, self.row)
+    }
+}
+
+impl Display for HexCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The six edges of a flat-topped hexagon, and so the six directions one can move in from it.
+///
+/// The directions are listed clockwise starting from north. This is also their order in
+/// [`HexDirection::ALL`] and their value when cast to `usize`.
+///
+/// Because odd columns are shifted down by half a hexagon, the diagonal neighbours of a hexagon
+/// are one row higher in even columns than they are in odd columns:
+///
+/// | Direction    | Even column offset | Odd column offset |
+/// |--------------|--------------------|-------------------|
+/// | `NORTH`      | (0, -1)            | (0, -1)           |
+/// | `NORTH_EAST` | (+1, -1)           | (+1, 0)           |
+/// | `SOUTH_EAST` | (+1, 0)            | (+1, +1)          |
+/// | `SOUTH`      | (0, +1)            | (0, +1)           |
+/// | `SOUTH_WEST` | (-1, 0)            | (-1, +1)          |
+/// | `NORTH_WEST` | (-1, -1)           | (-1, 0)           |
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum HexDirection {
+    /// Towards row zero.
+    NORTH,
+    /// Towards the next column, and upwards.
+    NORTH_EAST,
+    /// Towards the next column, and downwards.
+    SOUTH_EAST,
+    /// Away from row zero.
+    SOUTH,
+    /// Towards the previous column, and downwards.
+    SOUTH_WEST,
+    /// Towards the previous column, and upwards.
+    NORTH_WEST,
+}
+
+impl HexDirection {
+    /// Every direction, clockwise starting from north.
+    pub const ALL: [Self; 6] = [Self::NORTH, Self::NORTH_EAST, Self::SOUTH_EAST, Self::SOUTH, Self::SOUTH_WEST, Self::NORTH_WEST];
+
+    /// Return the direction facing the other way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::hex::HexDirection;
+    /// #
+    /// assert_eq!(HexDirection::SOUTH_WEST, HexDirection::NORTH_EAST.opposite());
+    /// ```
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 3) % 6]
+    }
+
+    /// Return the `[column, row]` offset of a step in this direction.
+    #[must_use]
+    fn offset(&self, odd_column: bool) -> [isize; 2] {
+        // Diagonals are half a row lower in odd columns
+        let shift = isize::from(odd_column);
+
+        return match self {
+            Self::NORTH      => [0, -1],
+            Self::NORTH_EAST => [1, -1 + shift],
+            Self::SOUTH_EAST => [1, shift],
+            Self::SOUTH      => [0, 1],
+            Self::SOUTH_WEST => [-1, shift],
+            Self::NORTH_WEST => [-1, -1 + shift],
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,172 @@
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::hex::{HexCoordinate, HexCoordinateSpaceIterator, HexDirection};
+use crate::interface::point::CoordinateSpace;
+use crate::rng::gen_index;
+
+/// A hexagonal coordinate space.
+///
+/// A hexagonal coordinate space is a grid of flat-topped hexagons with a set number of columns and
+/// rows. Every odd column is shifted down by half a hexagon. For more details on the layout see
+/// [`HexCoordinate`].
+///
+/// The origin of a hexagonal coordinate space is at the point (0, 0).
+///
+/// # Adjacency
+///
+/// Two points are adjacent if their hexagons share an edge. Every point has up to six neighbours
+/// (fewer on the edges of the space). [See `HexDirection` for the exact offsets](HexDirection).
+///
+/// ## Adjacency Example
+///
+/// ```
+/// # use mazelib::implm::point::hex::{HexCoordinate, HexCoordinateSpace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let coord_space = HexCoordinateSpace::new_checked(4, 4);
+///
+/// let pt = HexCoordinate { column: 1, row: 1 };  // An odd column
+///
+/// assert!(coord_space.are_adjacent(pt, HexCoordinate { column: 1, row: 0 }));
+/// assert!(coord_space.are_adjacent(pt, HexCoordinate { column: 2, row: 2 }));
+/// assert!(coord_space.are_adjacent(pt, HexCoordinate { column: 0, row: 1 }));
+///
+/// assert!(coord_space.are_adjacent(pt, HexCoordinate { column: 2, row: 0 }) == false);
+/// assert!(coord_space.are_adjacent(pt, pt) == false);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct HexCoordinateSpace {
+    columns: NonZeroUsize,
+    rows: NonZeroUsize,
+    size: NonZeroUsize,
+}
+
+impl HexCoordinateSpace {
+    /// Construct a new `HexCoordinateSpace` from the given dimensions.
+    ///
+    /// `columns * rows` must also fit within a `usize`. (Mazes this large won't fit in memory
+    /// anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `columns` --- the number of columns of hexagons.  
+    /// `rows`    --- the number of hexagons in each column.
+    ///
+    /// # See Also
+    ///
+    /// [`new_checked()`][Self::new_checked]
+    #[must_use]
+    pub fn new(columns: NonZeroUsize, rows: NonZeroUsize) -> Self {
+        let size = columns.checked_mul(rows).expect("The dimensions specified are too large. The number of points in the space does not fit within a usize.");
+
+        Self { columns, rows, size }
+    }
+
+    /// Construct a new `HexCoordinateSpace` from the given dimensions.
+    ///
+    /// `columns * rows` must also fit within a `usize`. (Mazes this large won't fit in memory
+    /// anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `columns` --- the number of columns of hexagons.  
+    /// `rows`    --- the number of hexagons in each column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::hex::HexCoordinateSpace;
+    /// #
+    /// let coord_space = HexCoordinateSpace::new_checked(8, 6);
+    /// ```
+    #[must_use]
+    pub fn new_checked(columns: usize, rows: usize) -> Self {
+        Self::new(NonZeroUsize::new(columns).expect("columns must be non-zero"), NonZeroUsize::new(rows).expect("rows must be non-zero"))
+    }
+
+    /// Return the number of columns in this coordinate space.
+    #[must_use]
+    pub fn columns(&self) -> NonZeroUsize {
+        self.columns
+    }
+
+    /// Return the number of rows in this coordinate space.
+    #[must_use]
+    pub fn rows(&self) -> NonZeroUsize {
+        self.rows
+    }
+
+    /// Return whether `pt` lies within this coordinate space.
+    #[must_use]
+    pub fn contains(&self, pt: HexCoordinate) -> bool {
+        pt.column < usize::from(self.columns) && pt.row < usize::from(self.rows)
+    }
+
+    /// Return the neighbour of `pt` in the given direction, if it lies within this coordinate
+    /// space.
+    #[must_use]
+    pub fn neighbour(&self, pt: HexCoordinate, direction: HexDirection) -> Option<HexCoordinate> {
+        pt.neighbour(direction).filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Return the direction one must travel in to get from `from` to `to`.
+    ///
+    /// Returns `None` if the points are not adjacent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::hex::{HexCoordinate, HexCoordinateSpace, HexDirection};
+    /// #
+    /// let coord_space = HexCoordinateSpace::new_checked(4, 4);
+    ///
+    /// let from = HexCoordinate { column: 0, row: 1 };
+    /// let to = HexCoordinate { column: 1, row: 0 };
+    ///
+    /// assert_eq!(Some(HexDirection::NORTH_EAST), coord_space.direction_between(from, to));
+    /// assert_eq!(Some(HexDirection::SOUTH_WEST), coord_space.direction_between(to, from));
+    /// ```
+    #[must_use]
+    pub fn direction_between(&self, from: HexCoordinate, to: HexCoordinate) -> Option<HexDirection> {
+        if self.contains(from) == false || self.contains(to) == false {
+            return None
+        }
+
+        HexDirection::ALL.into_iter().find(|direction| from.neighbour(*direction) == Some(to))
+    }
+}
+
+impl CoordinateSpace for HexCoordinateSpace {
+    type PtType = HexCoordinate;
+    type Iter = HexCoordinateSpaceIterator;
+
+    fn logical_size(&self) -> NonZeroUsize {
+        self.size
+    }
+
+    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
+        HexDirection::ALL.into_iter().filter_map(|direction| self.neighbour(pt, direction)).collect()
+    }
+
+    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
+        self.direction_between(pt1, pt2).is_some()
+    }
+
+    fn iter(&self) -> Self::Iter {
+        HexCoordinateSpaceIterator::new(*self, None)
+    }
+
+    fn iter_from(&self, pt: Self::PtType) -> Self::Iter {
+        HexCoordinateSpaceIterator::new(*self, Some(pt))
+    }
+
+    fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
+        let column = gen_index(rng, self.columns.into());
+
+        let row = gen_index(rng, self.rows.into());
+
+        return Self::PtType { column, row }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+use std::iter::FusedIterator;
+use std::slice::Iter;
+
+use crate::implm::point::boxy::CoordinateTuplet;
+
+/// An iterator for iterating over all of the points in a masked coordinate space.
+///
+/// Points are yielded one connected region of the mask at a time. The first point of each region
+/// is the first of its points in [box space order][crate::implm::point::boxy::BoxCoordinateSpaceIterator],
+/// and the rest of the region is yielded in breadth-first order from there.
+///
+/// Can only be obtained by calling
+/// [`MaskedBoxCoordinateSpace::iter()`][crate::interface::point::CoordinateSpace::iter] or
+/// [`MaskedBoxCoordinateSpace::iter_from()`][crate::interface::point::CoordinateSpace::iter_from].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::masked::{BoxPointMask, MaskedBoxCoordinateSpace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let mask = BoxPointMask::from_grid(&[
+///     [true,  false],
+///     [true,  true ],
+/// ]);
+///
+/// let mut iter = MaskedBoxCoordinateSpace::new(&mask).iter();
+///
+/// assert_eq!(Some([0, 0].into()), iter.next());
+/// assert_eq!(Some([0, 1].into()), iter.next());
+/// assert_eq!(Some([1, 1].into()), iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+pub struct MaskedBoxCoordinateSpaceIterator<'mask, const DIMENSION: usize> {
+    points: Iter<'mask, CoordinateTuplet<DIMENSION>>,
+}
+
+// Constructor
+impl <'mask, const DIMENSION: usize> MaskedBoxCoordinateSpaceIterator<'mask, DIMENSION> {
+    #[must_use]
+    pub(crate) fn new(points: &'mask [CoordinateTuplet<DIMENSION>]) -> Self {
+        Self { points: points.iter() }
+    }
+}
+
+impl <'mask, const DIMENSION: usize> Iterator for MaskedBoxCoordinateSpaceIterator<'mask, DIMENSION> {
+    type Item = CoordinateTuplet<DIMENSION>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.points.next().copied()
+    }
+}
+
+impl <'mask, const DIMENSION: usize> FusedIterator for MaskedBoxCoordinateSpaceIterator<'mask, DIMENSION> {}
\ No newline at end of file
@@ -0,0 +1,289 @@
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::num::NonZeroUsize;
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::point::CoordinateSpace;
+
+/// A selection of points from a [`BoxCoordinateSpace`].
+///
+/// Masks are used to construct [`MaskedBoxCoordinateSpace`][super::MaskedBoxCoordinateSpace]s.
+/// A mask must include at least one point.
+///
+/// # Examples
+///
+/// A ring-shaped mask:
+///
+/// ```
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::point::masked::BoxPointMask;
+///
+/// let mask = BoxPointMask::from_fn(BoxCoordinateSpace::new_checked([9, 9]), |pt| {
+///     let distance_squared = (pt[0] as isize - 4).pow(2) + (pt[1] as isize - 4).pow(2);
+///
+///     (4..=16).contains(&distance_squared)
+/// });
+///
+/// assert!(mask.contains([4, 1].into()));
+/// assert!(mask.contains([4, 4].into()) == false);
+/// ```
+#[derive(Clone)]
+pub struct BoxPointMask<const DIMENSION: usize> {
+    /// The coordinate space the mask selects points from.
+    space: BoxCoordinateSpace<DIMENSION>,
+
+    /// For every point in `space`, its position in `points` if it is included.
+    positions: Vec<Option<usize>>,
+
+    /// Every included point, in an order where every point is adjacent to an earlier one where
+    /// possible.
+    points: Vec<CoordinateTuplet<DIMENSION>>,
+}
+
+// Constructors
+impl <const DIMENSION: usize> BoxPointMask<DIMENSION> {
+    /// Construct a mask that includes every point of `space` that `is_included` returns true for.
+    ///
+    /// # Panics
+    ///
+    /// If no points are included.
+    #[must_use]
+    pub fn from_fn(space: BoxCoordinateSpace<DIMENSION>, mut is_included: impl FnMut(CoordinateTuplet<DIMENSION>) -> bool) -> Self {
+        let included: Vec<bool> = space.iter().map(&mut is_included).collect();
+
+        assert!(included.contains(&true), "The mask must include at least one point");
+
+        let mut mask = Self {
+            space,
+            positions: vec![None; included.len()],
+            points: Vec::with_capacity(included.iter().filter(|is_included| **is_included).count()),
+        };
+
+        // Walk each connected component breadth-first, so that the order points are stored in
+        // satisfies the iteration requirements of CoordinateSpace
+        for start in space.iter() {
+            if included[mask.index_of(start)] == false || mask.contains(start) {
+                continue
+            }
+
+            mask.push(start);
+
+            let mut queue = VecDeque::from([start]);
+
+            while let Some(pt) = queue.pop_front() {
+                for neighbour in space.neighbours_of_pt(pt) {
+                    if included[mask.index_of(neighbour)] && mask.contains(neighbour) == false {
+                        mask.push(neighbour);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+        }
+
+        return mask
+    }
+}
+
+impl BoxPointMask<2> {
+    /// Construct a mask from a grid of flags, such as a `&[Vec<bool>]`.
+    ///
+    /// `grid` is indexed as `grid[y][x]`. A point is included if its flag is `true`.
+    ///
+    /// # Panics
+    ///
+    /// If `grid` is empty, its rows are empty, its rows are not all the same length, or no points
+    /// are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mazelib::implm::point::masked::BoxPointMask;
+    ///
+    /// let mask = BoxPointMask::from_grid(&[
+    ///     [false, true, false],
+    ///     [true,  true, true ],
+    /// ]);
+    ///
+    /// assert_eq!(4, usize::from(mask.point_count()));
+    /// assert!(mask.contains([1, 0].into()));
+    /// ```
+    #[must_use]
+    pub fn from_grid<Row: AsRef<[bool]>>(grid: &[Row]) -> Self {
+        let height = grid.len();
+        let width = grid.first().map_or(0, |row| row.as_ref().len());
+
+        assert!(grid.iter().all(|row| row.as_ref().len() == width), "All rows must be the same length");
+
+        return Self::from_fn(BoxCoordinateSpace::new_checked([width, height]), |pt| grid[pt[1]].as_ref()[pt[0]])
+    }
+
+    /// Construct a mask from a picture drawn in text, with one point per character.
+    ///
+    /// Each line of `mask` is a row of points, from top to bottom. `.` is a point inside the mask,
+    /// and `#` is a point outside of it. Lines shorter than the longest are treated as if they
+    /// were padded with `#`. Blank lines at the start and end are ignored, as is whitespace at the
+    /// end of each line, so the mask can be written as an indented raw string.
+    ///
+    /// # Panics
+    ///
+    /// If `mask` contains any other characters (except for leading indentation shared by every
+    /// line), or no points are included.
+    ///
+    /// # Examples
+    ///
+    /// A maze shaped like a plus sign:
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::coordinate::masked::MaskedMazeCoordinator;
+    /// # use mazelib::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+    /// # use mazelib::implm::generate::HuntAndKillGenerator;
+    /// # use mazelib::interface::export::DefaultMazeExporter;
+    /// # use mazelib::interface::generate::DefaultMazeGenerator;
+    /// use mazelib::implm::point::masked::BoxPointMask;
+    ///
+    /// let mask = BoxPointMask::from_ascii("
+    ///     ###...###
+    ///     ###...###
+    ///     .........
+    ///     ###...###
+    ///     ###...###
+    /// ");
+    ///
+    /// assert_eq!(21, usize::from(mask.point_count()));
+    ///
+    /// let mut maze = MaskedMazeCoordinator::new(BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(mask.space()).build(), &mask);
+    /// HuntAndKillGenerator::generate(&mut maze);
+    /// <BoxSpaceBlockCellTextMazeExporter as DefaultMazeExporter<_, _>>::export(maze.maze(), &mut std::io::stdout()).unwrap();
+    /// ```
+    #[must_use]
+    pub fn from_ascii(mask: &str) -> Self {
+        let mut lines: Vec<&str> = mask.lines().map(str::trim_end).collect();
+
+        while lines.first().is_some_and(|line| line.is_empty()) {
+            lines.remove(0);
+        }
+
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+
+        let indent = lines.iter()
+            .filter(|line| line.is_empty() == false)
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+
+        let rows: Vec<Vec<bool>> = lines.iter().map(|line| {
+            line.get(indent..).unwrap_or("").chars().map(|char| match char {
+                '.' => true,
+                '#' => false,
+                _ => panic!("Unexpected character {:?} in the mask (expected '.' or '#')", char),
+            }).collect()
+        }).collect();
+
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        let grid: Vec<Vec<bool>> = rows.into_iter().map(|mut row| { row.resize(width, false); row }).collect();
+
+        return Self::from_grid(&grid)
+    }
+
+    /// Construct a mask from an image, with one point per pixel.
+    ///
+    /// A point is included if its pixel is dark (less than half brightness) and not fully
+    /// transparent. This way a black shape drawn on a white or transparent background becomes the
+    /// shape of the maze.
+    ///
+    /// # Panics
+    ///
+    /// If the image is empty or no points are included.
+    #[cfg(feature = "img")]
+    #[must_use]
+    pub fn from_image(img: &image::DynamicImage) -> Self {
+        let img = img.to_luma_alpha8();
+
+        let space = BoxCoordinateSpace::new_checked([img.width() as usize, img.height() as usize]);
+
+        return Self::from_fn(space, |pt| {
+            let [luma, alpha] = img.get_pixel(pt[0] as u32, pt[1] as u32).0;
+
+            alpha != 0 && luma < 128
+        })
+    }
+}
+
+// Public functions
+impl <const DIMENSION: usize> BoxPointMask<DIMENSION> {
+    /// Return the coordinate space the mask selects points from.
+    #[must_use]
+    pub fn space(&self) -> BoxCoordinateSpace<DIMENSION> {
+        self.space
+    }
+
+    /// Return the number of points included in the mask.
+    #[must_use]
+    pub fn point_count(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.points.len()).expect("masks always include at least one point")
+    }
+
+    /// Return whether `pt` is included in the mask.
+    ///
+    /// Points outside of the mask's coordinate space are never included.
+    #[must_use]
+    pub fn contains(&self, pt: CoordinateTuplet<DIMENSION>) -> bool {
+        self.position_of(pt).is_some()
+    }
+}
+
+// Internal functions
+impl <const DIMENSION: usize> BoxPointMask<DIMENSION> {
+    /// Return every included point, in iteration order.
+    #[must_use]
+    pub(super) fn points(&self) -> &[CoordinateTuplet<DIMENSION>] {
+        &self.points
+    }
+
+    /// Return the position of `pt` in iteration order, if it is included.
+    #[must_use]
+    pub(super) fn position_of(&self, pt: CoordinateTuplet<DIMENSION>) -> Option<usize> {
+        if (0..DIMENSION).any(|axis| pt[axis] >= usize::from(self.space[axis])) {
+            return None
+        }
+
+        return self.positions[self.index_of(pt)]
+    }
+
+    /// Return the index of `pt` amongst all the points of the space, in the same order
+    /// [`BoxCoordinateSpace::iter()`] yields them.
+    #[must_use]
+    fn index_of(&self, pt: CoordinateTuplet<DIMENSION>) -> usize {
+        let mut index = 0;
+        let mut stride = 1;
+
+        for axis in 0..DIMENSION {
+            index += pt[axis] * stride;
+            stride *= usize::from(self.space[axis]);
+        }
+
+        return index
+    }
+
+    fn push(&mut self, pt: CoordinateTuplet<DIMENSION>) {
+        let index = self.index_of(pt);
+
+        self.positions[index] = Some(self.points.len());
+        self.points.push(pt);
+    }
+}
+
+impl <const DIMENSION: usize> Debug for BoxPointMask<DIMENSION> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BoxPointMask")
+            .field("space", &self.space)
+            .field("point_count", &self.points.len())
+            .finish()
+    }
+}
\ No newline at end of file
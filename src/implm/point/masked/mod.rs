@@ -0,0 +1,19 @@
+//! Masked coordinate spaces, for mazes with arbitrary outlines.
+//!
+//! A [`BoxPointMask`] selects which points of a [`BoxCoordinateSpace`] are part of the maze. A
+//! [`MaskedBoxCoordinateSpace`] then behaves like the box space, except it only contains the
+//! points in the mask. This lets you generate mazes shaped like hearts, rings, letters, or
+//! anything else you can draw on a grid (or [in text][BoxPointMask::from_ascii]).
+//!
+//! To use a masked space with an existing maze, wrap it in a
+//! [`MaskedMazeCoordinator`][crate::implm::coordinate::masked::MaskedMazeCoordinator].
+//!
+//! [`BoxCoordinateSpace`]: crate::implm::point::boxy::BoxCoordinateSpace
+
+pub use self::iterator::MaskedBoxCoordinateSpaceIterator;
+pub use self::mask::BoxPointMask;
+pub use self::space::MaskedBoxCoordinateSpace;
+
+mod iterator;
+mod mask;
+mod space;
\ No newline at end of file
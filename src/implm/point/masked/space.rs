@@ -0,0 +1,126 @@
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::implm::point::masked::{BoxPointMask, MaskedBoxCoordinateSpaceIterator};
+use crate::interface::point::CoordinateSpace;
+use crate::rng::gen_index;
+
+/// A [`BoxCoordinateSpace`] restricted to the points in a [`BoxPointMask`].
+///
+/// Points keep the coordinates they have in the box space. Points outside the mask are not part of
+/// the space: they are never iterated over or chosen, and are never adjacent to anything.
+///
+/// The space borrows its mask, so that it can be copied around cheaply.
+///
+/// # Adjacency
+///
+/// Two points are adjacent if they are both in the mask and they are
+/// [adjacent in the box space][BoxCoordinateSpace#adjacency].
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::implm::point::masked::{BoxPointMask, MaskedBoxCoordinateSpace};
+/// use mazelib::interface::point::CoordinateSpace;
+///
+/// let mask = BoxPointMask::from_grid(&[
+///     [true, true,  true],
+///     [true, false, true],
+///     [true, true,  true],
+/// ]);
+///
+/// let space = MaskedBoxCoordinateSpace::new(&mask);
+///
+/// assert_eq!(8, usize::from(space.logical_size()));
+/// assert_eq!(2, space.neighbours_of_pt([1, 0].into()).len());
+/// assert!(space.are_adjacent([1, 0].into(), [1, 1].into()) == false);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct MaskedBoxCoordinateSpace<'mask, const DIMENSION: usize> {
+    mask: &'mask BoxPointMask<DIMENSION>,
+}
+
+impl <'mask, const DIMENSION: usize> MaskedBoxCoordinateSpace<'mask, DIMENSION> {
+    /// Construct a new `MaskedBoxCoordinateSpace` containing the points of `mask`.
+    #[must_use]
+    pub fn new(mask: &'mask BoxPointMask<DIMENSION>) -> Self {
+        Self { mask }
+    }
+
+    /// Return the mask of this coordinate space.
+    #[must_use]
+    pub fn mask(&self) -> &'mask BoxPointMask<DIMENSION> {
+        self.mask
+    }
+
+    /// Return the box coordinate space that this space is a subset of.
+    #[must_use]
+    pub fn unmasked(&self) -> BoxCoordinateSpace<DIMENSION> {
+        self.mask.space()
+    }
+
+    /// Return whether `pt` is in this coordinate space.
+    #[must_use]
+    pub fn contains(&self, pt: CoordinateTuplet<DIMENSION>) -> bool {
+        self.mask.contains(pt)
+    }
+}
+
+impl <'mask, const DIMENSION: usize> CoordinateSpace for MaskedBoxCoordinateSpace<'mask, DIMENSION> {
+    type PtType = CoordinateTuplet<DIMENSION>;
+    type Iter = MaskedBoxCoordinateSpaceIterator<'mask, DIMENSION>;
+
+    fn logical_size(&self) -> NonZeroUsize {
+        self.mask.point_count()
+    }
+
+    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
+        if self.contains(pt) == false {
+            return Vec::new()
+        }
+
+        let mut neighbours = self.unmasked().neighbours_of_pt(pt);
+
+        neighbours.retain(|neighbour| self.contains(*neighbour));
+
+        return neighbours
+    }
+
+    fn neighbours_into(&self, pt: Self::PtType, neighbours: &mut impl Extend<Self::PtType>) {
+        if self.contains(pt) == false {
+            return
+        }
+
+        self.unmasked().for_each_neighbour(pt, |neighbour| if self.contains(neighbour) {
+            neighbours.extend(Some(neighbour))
+        });
+    }
+
+    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
+        self.contains(pt1) && self.contains(pt2) && self.unmasked().are_adjacent(pt1, pt2)
+    }
+
+    fn iter(&self) -> Self::Iter {
+        MaskedBoxCoordinateSpaceIterator::new(self.mask.points())
+    }
+
+    /// *See [`CoordinateSpace::iter_from()`].*
+    ///
+    /// If `pt` is not in this space, the iterator is empty.
+    fn iter_from(&self, pt: Self::PtType) -> Self::Iter {
+        let points = self.mask.points();
+
+        return match self.mask.position_of(pt) {
+            Some(position) => MaskedBoxCoordinateSpaceIterator::new(&points[position + 1..]),
+            None => MaskedBoxCoordinateSpaceIterator::new(&[]),
+        }
+    }
+
+    fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
+        let points = self.mask.points();
+
+        return points[gen_index(rng, points.len())]
+    }
+}
\ No newline at end of file
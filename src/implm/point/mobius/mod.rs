@@ -0,0 +1,8 @@
+//! Möbius coordinate spaces, for mazes on a Möbius strip.
+//!
+//! A Möbius strip is a rectangle whose left and right edges are joined with a half-twist. For more
+//! information see [`MobiusCoordinateSpace`].
+
+pub use self::space::MobiusCoordinateSpace;
+
+mod space;
\ No newline at end of file
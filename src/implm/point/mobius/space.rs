@@ -0,0 +1,188 @@
+use std::fmt::{Debug, Formatter};
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxCoordinateSpaceIterator, CoordinateTuplet};
+use crate::interface::point::CoordinateSpace;
+use crate::internal::util::try_usize_array_to_nonzero_usize_array;
+
+/// A two-dimensional coordinate space on a [Möbius strip](https://en.wikipedia.org/wiki/M%C3%B6bius_strip).
+///
+/// The space is a rectangle like a [`BoxCoordinateSpace<2>`], with the same points, except that
+/// its left and right edges are joined with a half-twist. Walking off the right edge brings you back
+/// onto the left edge, upside down. A maze on a Möbius strip has only one side, so a path can
+/// visit every row from both directions.
+///
+/// As with a [wrapping box space][BoxCoordinateSpace#wrapping-adjacency], spaces less than three
+/// points wide are not joined, since their left and right edges are already (nearly) adjacent.
+///
+/// # Adjacency
+///
+/// Two points are adjacent if they are [adjacent in the box space][BoxCoordinateSpace#adjacency],
+/// or if they're on opposite ends of the strip in mirrored rows. That is, in a space `w` points
+/// wide and `h` points tall, `(w - 1, y)` and `(0, h - 1 - y)` are adjacent.
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::implm::point::mobius::MobiusCoordinateSpace;
+/// use mazelib::interface::point::CoordinateSpace;
+///
+/// let space = MobiusCoordinateSpace::new_checked([5, 3]);
+///
+/// assert!(space.are_adjacent([4, 0].into(), [0, 2].into()));
+/// assert!(space.are_adjacent([4, 1].into(), [0, 1].into()));
+/// assert!(space.are_adjacent([4, 0].into(), [0, 0].into()) == false);
+///
+/// assert_eq!(4, space.neighbours_of_pt([0, 1].into()).len());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MobiusCoordinateSpace {
+    /// The rectangle the strip is made from.
+    rectangle: BoxCoordinateSpace<2>,
+}
+
+impl MobiusCoordinateSpace {
+    /// Construct a new `MobiusCoordinateSpace` from the given dimensions.
+    ///
+    /// # Parameters
+    ///
+    /// `dimensions` --- the length (width) and height of the strip.
+    ///
+    /// # See Also
+    ///
+    /// [`new_checked()`][Self::new_checked]
+    #[must_use]
+    pub fn new(dimensions: [NonZeroUsize; 2]) -> Self {
+        Self { rectangle: BoxCoordinateSpace::new(dimensions) }
+    }
+
+    /// Construct a new `MobiusCoordinateSpace` from the given dimensions.
+    ///
+    /// # Parameters
+    ///
+    /// `dimensions` --- the length (width) and height of the strip. Both must be non-zero.
+    ///
+    /// # Panics
+    ///
+    /// If either dimension is zero.
+    ///
+    /// # See Also
+    ///
+    /// [`new()`][Self::new]
+    #[must_use]
+    pub fn new_checked(dimensions: [usize; 2]) -> Self {
+        Self::new(try_usize_array_to_nonzero_usize_array(dimensions).expect("All dimensions must be non-zero"))
+    }
+
+    /// Return the length (width) and height of the strip.
+    #[must_use]
+    pub fn dimensions(&self) -> [NonZeroUsize; 2] {
+        self.rectangle.dimensions()
+    }
+
+    /// Return the rectangle the strip is made from, without its ends joined.
+    #[must_use]
+    pub fn unrolled(&self) -> BoxCoordinateSpace<2> {
+        self.rectangle
+    }
+
+    /// Return whether the ends of the strip are joined. They are unless the strip is less than
+    /// three points long.
+    #[must_use]
+    pub fn is_joined(&self) -> bool {
+        usize::from(self.rectangle[0]) > 2
+    }
+
+    /// Return the point across the join from `pt`, if `pt` is at either end of the strip.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::mobius::MobiusCoordinateSpace;
+    /// #
+    /// let space = MobiusCoordinateSpace::new_checked([5, 3]);
+    ///
+    /// assert_eq!(Some([0, 1].into()), space.across_join([4, 1].into()));
+    /// assert_eq!(Some([4, 0].into()), space.across_join([0, 2].into()));
+    /// assert_eq!(None, space.across_join([2, 2].into()));
+    /// ```
+    #[must_use]
+    pub fn across_join(&self, pt: CoordinateTuplet<2>) -> Option<CoordinateTuplet<2>> {
+        let [width, height] = self.rectangle.dimensions().map(usize::from);
+
+        if self.is_joined() == false || pt[1] >= height {
+            return None
+        }
+
+        let mirrored_row = height - 1 - pt[1];
+
+        return match pt[0] {
+            0 => Some([width - 1, mirrored_row].into()),
+            x if x == width - 1 => Some([0, mirrored_row].into()),
+            _ => None,
+        }
+    }
+
+    /// Return whether two points are adjacent across the join between the ends of the strip.
+    #[must_use]
+    pub fn is_joined_between(&self, pt1: CoordinateTuplet<2>, pt2: CoordinateTuplet<2>) -> bool {
+        self.across_join(pt1) == Some(pt2)
+    }
+
+    /// Return the axis two points are adjacent along, or `None` if they're not adjacent.
+    ///
+    /// Points adjacent across the join are adjacent along axis `0`.
+    #[must_use]
+    pub fn axis_of_adjacency(&self, pt1: CoordinateTuplet<2>, pt2: CoordinateTuplet<2>) -> Option<usize> {
+        if self.is_joined_between(pt1, pt2) {
+            return Some(0)
+        }
+
+        return self.rectangle.axis_of_adjacency(pt1, pt2)
+    }
+}
+
+impl CoordinateSpace for MobiusCoordinateSpace {
+    type PtType = CoordinateTuplet<2>;
+    type Iter = BoxCoordinateSpaceIterator<2>;
+
+    fn logical_size(&self) -> NonZeroUsize {
+        self.rectangle.logical_size()
+    }
+
+    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
+        let mut neighbours = self.rectangle.neighbours_of_pt(pt);
+
+        if let Some(neighbour) = self.across_join(pt) {
+            neighbours.push(neighbour);
+        }
+
+        return neighbours
+    }
+
+    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
+        self.is_joined_between(pt1, pt2) || self.rectangle.are_adjacent(pt1, pt2)
+    }
+
+    fn iter(&self) -> Self::Iter {
+        self.rectangle.iter()
+    }
+
+    fn iter_from(&self, pt: Self::PtType) -> Self::Iter {
+        self.rectangle.iter_from(pt)
+    }
+
+    fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
+        self.rectangle.choose(rng)
+    }
+}
+
+impl Debug for MobiusCoordinateSpace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let [width, height] = self.rectangle.dimensions();
+
+        write!(f, "MobiusCoordinateSpace(dimensions = {}x{})", width, height)
+    }
+}
\ No newline at end of file
@@ -2,11 +2,24 @@
 //!
 //! If you are looking for [two-dimensional][self::boxy::TwoDimensionalBoxCoordinateSpace]
 //! or [three-dimensional][self::boxy::ThreeDimensionalBoxCoordinateSpace] coordinate spaces,
-//! check out [`boxy`].
+//! check out [`boxy`]. For sigma (hexagonal) mazes, see [`hex`], and for delta (triangular) mazes,
+//! see [`delta`]. For box mazes with arbitrary outlines, see [`masked`], and for mazes on the
+//! surface of a cube (or an approximate sphere), see [`cube`]. For upsilon mazes (octagons and
+//! squares), see [`upsilon`]. For mazes on a Möbius strip, see [`mobius`]. For mazes of any shape
+//! at all, including irregular Voronoi mazes, see [`graph`]. To stitch several spaces together into
+//! one, see [`composite`].
 //!
 //! # See Also
 //! * [`CoordinateSpace`][crate::interface::point::CoordinateSpace], and
 //! * [`Point`][crate::interface::point::Point] --- the interface traits.
 
 pub mod boxy;
-pub mod polar;
\ No newline at end of file
+pub mod composite;
+pub mod cube;
+pub mod delta;
+pub mod graph;
+pub mod hex;
+pub mod masked;
+pub mod mobius;
+pub mod polar;
+pub mod upsilon;
\ No newline at end of file
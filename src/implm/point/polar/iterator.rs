@@ -2,9 +2,10 @@ use std::iter::FusedIterator;
 
 use crate::implm::point::polar::{PolarCoordinate, PolarCoordinateSpace};
 
-/// An iterator for iterating over all of the points in a boxy coordinate space.
+/// An iterator for iterating over all of the points in a polar coordinate space.
 ///
-/// Points are yielded by sector, then by ring, in ascending order.
+/// Points are yielded by sector, then by ring, in ascending order. That is, every point in a ring
+/// is yielded before moving on to the next ring.
 ///
 /// Can only be obtained by calling
 /// [`PolarCoordinateSpace::iter()`][crate::interface::point::CoordinateSpace::iter] or
@@ -45,7 +46,7 @@ impl Iterator for PolarCoordinateSpaceIterator {
         match self.pos {
             None => self.pos = Some(PolarCoordinate { ring: 0, sector: 0 }),
             Some(mut pt) => {
-                if pt.sector + 1 == usize::from(self.space.sectors()) {
+                if pt.sector + 1 == usize::from(self.space.sectors_in_ring(pt.ring)) {
                     if pt.ring + 1 == usize::from(self.space.rings()) {
                         return None  // Iterator is done
                     } else {
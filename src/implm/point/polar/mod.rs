@@ -3,7 +3,7 @@
 //! For more information on what polar coordinates are, see [`PolarCoordinate`].
 
 pub use self::iterator::PolarCoordinateSpaceIterator;
-pub use self::point::PolarCoordinate;
+pub use self::point::{PolarCoordinate, PolarEdge};
 pub use self::space::PolarCoordinateSpace;
 
 mod space;
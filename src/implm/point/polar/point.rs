@@ -11,7 +11,7 @@ use crate::internal::util::offset_usize;
 /// coordinate (the angle). Since our [polar coordinate spaces][super::PolarCoordinateSpace] are
 /// discretised, here we refer to these coordinate components as the *ring* and *sector*
 /// respectively. The acceptable ranges for these coordinates are `0 <= ring < space.rings()` and
-/// `0 <= sector < space.sectors()`. Sector coordinates beyond the coordinate space's sector count
+/// `0 <= sector < space.sectors_in_ring(ring)`. Sector coordinates beyond the ring's sector count
 /// will not be automatically wrapped around and will be considered out-of-bounds.
 ///
 /// Polar coordinates are often written out as (`<ring>` ∠ `<sector>`).
@@ -86,4 +86,37 @@ impl Display for PolarCoordinate {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
     }
+}
+
+/// An edge of a cell in a [polar coordinate space][super::PolarCoordinateSpace].
+///
+/// Sectors are numbered clockwise, so the clockwise neighbour of a point has the next sector
+/// number, and the counter-clockwise neighbour has the previous sector number (both wrapping
+/// around).
+///
+/// When the next ring out is [subdivided][super::PolarCoordinateSpace::new_subdivided], each
+/// point has two neighbours in it. The one with the lower sector number is across
+/// [`OUTWARD`][Self::OUTWARD] and the other is across
+/// [`OUTWARD_CLOCKWISE`][Self::OUTWARD_CLOCKWISE]. Otherwise there is only one, across
+/// [`OUTWARD`][Self::OUTWARD], and the [`OUTWARD_CLOCKWISE`][Self::OUTWARD_CLOCKWISE] edge does
+/// not lead anywhere.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PolarEdge {
+    /// Towards the centre.
+    INWARD,
+    /// Away from the centre. If the next ring is subdivided, this is the counter-clockwise half.
+    OUTWARD,
+    /// Away from the centre, in the clockwise half. Only leads anywhere if the next ring is
+    /// subdivided.
+    OUTWARD_CLOCKWISE,
+    /// Towards the previous sector.
+    COUNTER_CLOCKWISE,
+    /// Towards the next sector.
+    CLOCKWISE,
+}
+
+impl PolarEdge {
+    /// Every edge.
+    pub const ALL: [Self; 5] = [Self::INWARD, Self::OUTWARD, Self::OUTWARD_CLOCKWISE, Self::COUNTER_CLOCKWISE, Self::CLOCKWISE];
 }
\ No newline at end of file
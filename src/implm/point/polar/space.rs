@@ -3,9 +3,9 @@ use std::num::NonZeroUsize;
 use embed_doc_image::embed_doc_image;
 use rand::Rng;
 
-use crate::implm::point::polar::point::PolarCoordinate;
-use crate::implm::point::polar::PolarCoordinateSpaceIterator;
+use crate::implm::point::polar::{PolarCoordinate, PolarCoordinateSpaceIterator, PolarEdge};
 use crate::interface::point::CoordinateSpace;
+use crate::rng::gen_index;
 
 /// A discretised polar (circular) coordinate space.
 ///
@@ -14,6 +14,12 @@ use crate::interface::point::CoordinateSpace;
 /// circle. A polar coordinate space has a set number of rings and sectors. All rings and all
 /// sectors are equally-sized.
 ///
+/// Since the rings get longer the further out they are, the cells in the outer rings of a large
+/// space get very wide. To counter this, a space can be
+/// [subdivided][PolarCoordinateSpace::new_subdivided], in which case the number of sectors in a
+/// ring doubles every time the radius of the ring doubles. Each sector is then split into two
+/// in the next ring out.
+///
 /// Here is an example of a polar coordinate space:
 ///
 /// ![A diagram visually explaining polar coordinates. There are five concentric circles divided into eight sectors, but the top-left corner of the circles is missing. The spaces between the circles are labelled rings 0 to 4, where 0 is the closest to the center. The spaces between the sector lines are labelled sectors 0 to 5, starting from the sector just to the right of the top of the diagram. Sectors 6 and 7 are omitted.][polar-coordinate-space-example]
@@ -25,13 +31,17 @@ use crate::interface::point::CoordinateSpace;
 ///
 /// # Adjacency
 ///
-/// Two rings are adjacent if their ring numbers differ by exactly one. Two sectors are adjacent
-/// if their sector numbers differ by exactly one *or* if one sector has a sector number of zero
-/// and the other has a sector number of `self.sectors() - 1` (that is to say, they are the first
-/// and last sectors).
+/// Two rings are adjacent if their ring numbers differ by exactly one. Two sectors in the same ring
+/// are adjacent if their sector numbers differ by exactly one *or* if one sector has a sector
+/// number of zero and the other has a sector number of `self.sectors_in_ring(ring) - 1` (that is
+/// to say, they are the first and last sectors).
 ///
 /// Two polar coordinates are adjacent if they are in the same ring and their sectors are adjacent,
-/// or if their rings are adjacent and they are in the same sector.
+/// or if their rings are adjacent and they overlap. Without subdivision, points overlap if they're
+/// in the same sector. With subdivision, the sectors `2 * n` and `2 * n + 1` of a subdivided ring
+/// both overlap sector `n` of the ring inside it.
+///
+/// The edges that points are adjacent across are given by [`PolarEdge`].
 /// 
 /// ## Adjacency Example
 /// 
@@ -63,10 +73,11 @@ use crate::interface::point::CoordinateSpace;
 /// ) == false);
 /// ```
 #[embed_doc_image("polar-coordinate-space-example", "src/doc/img/point/polar/polar-coordinate-space-example.png")]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct PolarCoordinateSpace {
     rings: NonZeroUsize,
     sectors: NonZeroUsize,
+    subdivided: bool,
     size: NonZeroUsize
 }
 
@@ -101,7 +112,7 @@ impl PolarCoordinateSpace {
     pub fn new(rings: NonZeroUsize, sectors: NonZeroUsize) -> Self {
         let size = rings.checked_mul(sectors).expect("The dimensions specified are too large. The number of points in the space does not fit within a usize.");
 
-        Self { rings, sectors, size }
+        Self { rings, sectors, subdivided: false, size }
     }
 
     /// Construct a new `PolarCoordinateSpace` from the given dimensions.
@@ -126,6 +137,67 @@ impl PolarCoordinateSpace {
         Self::new(NonZeroUsize::new(rings).expect("rings must be non-zero"), NonZeroUsize::new(sectors).expect("sectors must be non-zero"))
     }
 
+    /// Construct a new subdivided `PolarCoordinateSpace` from the given dimensions.
+    ///
+    /// The innermost ring has `sectors` sectors. Ring `n` has `sectors * 2^floor(log2(n + 1))`
+    /// sectors, so the number of sectors doubles at rings 1, 3, 7, 15, and so on.
+    ///
+    /// The total number of points must also fit within a `usize`. (Mazes this large won't fit in
+    /// memory anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `rings`   --- the number of rings the circle should be divided into.  
+    /// `sectors` --- the number of sectors the innermost ring should be divided into.
+    ///
+    /// # See Also
+    ///
+    /// [`new_subdivided_checked()`][Self::new_subdivided_checked]
+    #[must_use]
+    pub fn new_subdivided(rings: NonZeroUsize, sectors: NonZeroUsize) -> Self {
+        let mut space = Self { rings, sectors, subdivided: true, size: sectors };
+
+        let size = (1..usize::from(rings)).try_fold(sectors, |size, ring| size.checked_add(usize::from(space.checked_sectors_in_ring(ring)?)));
+
+        space.size = size.expect("The dimensions specified are too large. The number of points in the space does not fit within a usize.");
+
+        return space
+    }
+
+    /// Construct a new subdivided `PolarCoordinateSpace` from the given dimensions.
+    ///
+    /// The total number of points must also fit within a `usize`. (Mazes this large won't fit in
+    /// memory anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `rings`   --- the number of rings the circle should be divided into.  
+    /// `sectors` --- the number of sectors the innermost ring should be divided into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::polar::PolarCoordinateSpace;
+    /// # use mazelib::interface::point::CoordinateSpace;
+    /// #
+    /// let coord_space = PolarCoordinateSpace::new_subdivided_checked(4, 6);
+    ///
+    /// assert_eq!(6, usize::from(coord_space.sectors_in_ring(0)));
+    /// assert_eq!(12, usize::from(coord_space.sectors_in_ring(1)));
+    /// assert_eq!(12, usize::from(coord_space.sectors_in_ring(2)));
+    /// assert_eq!(24, usize::from(coord_space.sectors_in_ring(3)));
+    ///
+    /// assert_eq!(54, usize::from(coord_space.logical_size()));
+    /// ```
+    ///
+    /// # See Also
+    ///
+    /// [`new_subdivided()`][Self::new_subdivided]
+    #[must_use]
+    pub fn new_subdivided_checked(rings: usize, sectors: usize) -> Self {
+        Self::new_subdivided(NonZeroUsize::new(rings).expect("rings must be non-zero"), NonZeroUsize::new(sectors).expect("sectors must be non-zero"))
+    }
+
     /// Return the number of rings in this coordinate space.
     ///
     /// # Examples
@@ -136,7 +208,7 @@ impl PolarCoordinateSpace {
     /// let [rings, sectors] = [5, 7];
     /// let coord_space = PolarCoordinateSpace::new_checked(rings, sectors);
     /// 
-    /// assert_eq!(rings, coord_space.rings().into());
+    /// assert_eq!(rings, usize::from(coord_space.rings()));
     #[must_use]
     pub fn rings(&self) -> NonZeroUsize {
         self.rings
@@ -144,6 +216,9 @@ impl PolarCoordinateSpace {
 
     /// Return the number of sectors in this coordinate space.
     ///
+    /// If the space is [subdivided][Self::new_subdivided], this is the number of sectors in the
+    /// innermost ring.
+    ///
     /// # Examples
     ///
     /// ```
@@ -152,61 +227,150 @@ impl PolarCoordinateSpace {
     /// let [rings, sectors] = [5, 7];
     /// let coord_space = PolarCoordinateSpace::new_checked(rings, sectors);
     ///
-    /// assert_eq!(sectors, coord_space.sectors().into());
+    /// assert_eq!(sectors, usize::from(coord_space.sectors()));
     #[must_use]
     pub fn sectors(&self) -> NonZeroUsize {
         self.sectors
     }
-}
 
-impl CoordinateSpace for PolarCoordinateSpace {
-    type PtType = PolarCoordinate;
-    type Iter = PolarCoordinateSpaceIterator;
+    /// Return whether the number of sectors increases as the rings grow.
+    ///
+    /// *See [`new_subdivided()`][Self::new_subdivided].*
+    #[must_use]
+    pub fn is_subdivided(&self) -> bool {
+        self.subdivided
+    }
 
-    fn logical_size(&self) -> NonZeroUsize {
-        self.size
+    /// Return the number of sectors in the given ring.
+    ///
+    /// This is always [`sectors()`][Self::sectors] unless the space is
+    /// [subdivided][Self::new_subdivided]. The ring does not need to be within the space.
+    #[must_use]
+    pub fn sectors_in_ring(&self, ring: usize) -> NonZeroUsize {
+        self.checked_sectors_in_ring(ring).expect("The number of sectors in the ring does not fit within a usize")
     }
 
-    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
-        let mut neighbours = Vec::with_capacity(4);
+    /// Return whether `pt` lies within this coordinate space.
+    #[must_use]
+    pub fn contains(&self, pt: PolarCoordinate) -> bool {
+        pt.ring < usize::from(self.rings) && pt.sector < usize::from(self.sectors_in_ring(pt.ring))
+    }
+
+    /// Return the neighbour of `pt` across the given edge, if there is one within this coordinate
+    /// space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::polar::{PolarCoordinate, PolarCoordinateSpace, PolarEdge};
+    /// #
+    /// let coord_space = PolarCoordinateSpace::new_subdivided_checked(3, 4);
+    ///
+    /// let pt = PolarCoordinate { ring: 0, sector: 3 };
+    ///
+    /// assert_eq!(Some(PolarCoordinate { ring: 1, sector: 6 }), coord_space.neighbour(pt, PolarEdge::OUTWARD));
+    /// assert_eq!(Some(PolarCoordinate { ring: 1, sector: 7 }), coord_space.neighbour(pt, PolarEdge::OUTWARD_CLOCKWISE));
+    /// assert_eq!(Some(PolarCoordinate { ring: 0, sector: 0 }), coord_space.neighbour(pt, PolarEdge::CLOCKWISE));
+    /// assert_eq!(None, coord_space.neighbour(pt, PolarEdge::INWARD));
+    /// ```
+    #[must_use]
+    pub fn neighbour(&self, pt: PolarCoordinate, edge: PolarEdge) -> Option<PolarCoordinate> {
+        if self.contains(pt) == false {
+            return None
+        }
 
-        let sectors_minus_one = usize::from(self.sectors) - 1;
+        let sectors = usize::from(self.sectors_in_ring(pt.ring));
+        let has_outer_ring = pt.ring + 1 < usize::from(self.rings);
 
-        if pt.ring > 0 {
-            neighbours.push(pt.offset_ring(-1))
+        return match edge {
+            PolarEdge::INWARD if pt.ring > 0 => Some(PolarCoordinate { ring: pt.ring - 1, sector: pt.sector / self.subdivision_ratio(pt.ring) }),
+            PolarEdge::OUTWARD if has_outer_ring => Some(PolarCoordinate { ring: pt.ring + 1, sector: pt.sector * self.subdivision_ratio(pt.ring + 1) }),
+            PolarEdge::OUTWARD_CLOCKWISE if has_outer_ring && self.subdivision_ratio(pt.ring + 1) == 2 => Some(PolarCoordinate { ring: pt.ring + 1, sector: pt.sector * 2 + 1 }),
+            PolarEdge::CLOCKWISE if sectors > 1 => Some(pt.with_sector((pt.sector + 1) % sectors)),
+            // With only two sectors the clockwise and counter-clockwise neighbours are the same,
+            // so only count it once
+            PolarEdge::COUNTER_CLOCKWISE if sectors > 2 => Some(pt.with_sector((pt.sector + sectors - 1) % sectors)),
+            _ => None,
         }
+    }
 
-        if pt.ring < sectors_minus_one {
-            neighbours.push(pt.offset_ring(1))
+    /// Return the edge of `from` that `to` lies across.
+    ///
+    /// Returns `None` if the points are not adjacent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::polar::{PolarCoordinate, PolarCoordinateSpace, PolarEdge};
+    /// #
+    /// let coord_space = PolarCoordinateSpace::new_subdivided_checked(3, 4);
+    ///
+    /// let from = PolarCoordinate { ring: 0, sector: 1 };
+    /// let to = PolarCoordinate { ring: 1, sector: 3 };
+    ///
+    /// assert_eq!(Some(PolarEdge::OUTWARD_CLOCKWISE), coord_space.edge_between(from, to));
+    /// assert_eq!(Some(PolarEdge::INWARD), coord_space.edge_between(to, from));
+    /// ```
+    #[must_use]
+    pub fn edge_between(&self, from: PolarCoordinate, to: PolarCoordinate) -> Option<PolarEdge> {
+        PolarEdge::ALL.into_iter().find(|edge| self.neighbour(from, *edge) == Some(to))
+    }
+
+    /// Return the number of points in all rings inside the given ring.
+    #[must_use]
+    pub(crate) fn ring_offset(&self, ring: usize) -> usize {
+        if self.subdivided == false {
+            return ring * usize::from(self.sectors)
         }
 
-        if pt.sector == 0 {
-            neighbours.push(pt.with_sector(sectors_minus_one))
-        } else {
-            neighbours.push(pt.offset_sector(-1))
+        // Rings with the same number of sectors come in groups, with each group being twice as
+        // long as the one before it
+        let mut offset = 0;
+        let mut group_start = 0;
+        let mut group_len = 1;
+
+        while group_start < ring {
+            offset += (ring - group_start).min(group_len) * group_len * usize::from(self.sectors);
+
+            group_start += group_len;
+            group_len *= 2;
         }
 
-        if pt.sector == sectors_minus_one {
-            neighbours.push(pt.with_sector(0))
+        return offset
+    }
+
+    /// Return how many sectors of `ring` overlap each sector of the ring inside it.
+    ///
+    /// This is either 1 or 2.
+    #[must_use]
+    fn subdivision_ratio(&self, ring: usize) -> usize {
+        usize::from(self.sectors_in_ring(ring)) / usize::from(self.sectors_in_ring(ring - 1))
+    }
+
+    #[must_use]
+    fn checked_sectors_in_ring(&self, ring: usize) -> Option<NonZeroUsize> {
+        if self.subdivided {
+            self.sectors.checked_mul(NonZeroUsize::new(1usize.checked_shl((ring + 1).ilog2())?)?)
         } else {
-            neighbours.push(pt.offset_sector(1))
+            Some(self.sectors)
         }
+    }
+}
+
+impl CoordinateSpace for PolarCoordinateSpace {
+    type PtType = PolarCoordinate;
+    type Iter = PolarCoordinateSpaceIterator;
 
-        return neighbours
+    fn logical_size(&self) -> NonZeroUsize {
+        self.size
+    }
+
+    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
+        PolarEdge::ALL.into_iter().filter_map(|edge| self.neighbour(pt, edge)).collect()
     }
 
     fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
-        // Only sectors wrap - rings don't
-
-        return if pt1.ring == pt2.ring {
-            pt1.sector.abs_diff(pt2.sector) == 1
-                || pt1.sector == 0 && pt2.sector == usize::from(self.sectors) - 1
-                || pt1.sector == usize::from(self.sectors) - 1 && pt2.sector == 0
-        } else if pt1.sector == pt2.sector {
-            pt1.ring.abs_diff(pt2.ring) == 1
-        } else {
-            false
-        }
+        self.edge_between(pt1, pt2).is_some()
     }
 
     fn iter(&self) -> Self::Iter {
@@ -218,9 +382,26 @@ impl CoordinateSpace for PolarCoordinateSpace {
     }
 
     fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
-        let ring = rng.gen_range(0..self.rings.into());
+        if self.subdivided {
+            // Rings have different sizes, so pick a point uniformly by its position in the space
+            let mut index = gen_index(rng, self.size.into());
+
+            for ring in 0..usize::from(self.rings) {
+                let sectors = usize::from(self.sectors_in_ring(ring));
+
+                if index < sectors {
+                    return Self::PtType { ring, sector: index }
+                }
+
+                index -= sectors;
+            }
+
+            unreachable!("The size of the space is the sum of the sizes of its rings")
+        }
+
+        let ring = gen_index(rng, self.rings.into());
 
-        let sector = rng.gen_range(0..self.sectors.into());
+        let sector = gen_index(rng, self.sectors.into());
 
         return Self::PtType { ring, sector }
     }
@@ -0,0 +1,66 @@
+use std::iter::FusedIterator;
+
+use crate::implm::point::upsilon::{UpsilonCoordinate, UpsilonCoordinateSpace};
+
+/// An iterator for iterating over all of the points in an upsilon coordinate space.
+///
+/// Points are yielded by column, then by row, in ascending order.
+///
+/// Can only be obtained by calling
+/// [`UpsilonCoordinateSpace::iter()`][crate::interface::point::CoordinateSpace::iter] or
+/// [`UpsilonCoordinateSpace::iter_from()`][crate::interface::point::CoordinateSpace::iter_from].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::point::upsilon::{UpsilonCoordinate, UpsilonCoordinateSpace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let mut iter = UpsilonCoordinateSpace::new_checked(2, 2).iter();
+///
+/// assert_eq!(Some(UpsilonCoordinate { column: 0, row: 0 }), iter.next());
+/// assert_eq!(Some(UpsilonCoordinate { column: 1, row: 0 }), iter.next());
+/// assert_eq!(Some(UpsilonCoordinate { column: 0, row: 1 }), iter.next());
+/// assert_eq!(Some(UpsilonCoordinate { column: 1, row: 1 }), iter.next());
+/// assert_eq!(None, iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+pub struct UpsilonCoordinateSpaceIterator {
+    space: UpsilonCoordinateSpace,
+    pos: Option<UpsilonCoordinate>,
+}
+
+// Constructor
+impl UpsilonCoordinateSpaceIterator {
+    #[must_use]
+    pub(crate) fn new(space: UpsilonCoordinateSpace, starting_pos: Option<UpsilonCoordinate>) -> Self {
+        Self { space, pos: starting_pos }
+    }
+}
+
+impl Iterator for UpsilonCoordinateSpaceIterator {
+    type Item = UpsilonCoordinate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.pos {
+            None => self.pos = Some(UpsilonCoordinate { column: 0, row: 0 }),
+            Some(pt) => {
+                let pt = if pt.column + 1 == usize::from(self.space.columns()) {
+                    if pt.row + 1 == usize::from(self.space.rows()) {
+                        return None  // Iterator is done
+                    } else {
+                        UpsilonCoordinate { column: 0, row: pt.row + 1 }
+                    }
+                } else {
+                    UpsilonCoordinate { column: pt.column + 1, row: pt.row }
+                };
+
+                self.pos = Some(pt);
+            }
+        }
+
+        return self.pos
+    }
+}
+
+impl FusedIterator for UpsilonCoordinateSpaceIterator {}
\ No newline at end of file
@@ -0,0 +1,11 @@
+//! Upsilon coordinate spaces, for mazes of octagons and squares.
+//!
+//! For more information on how upsilon coordinates are laid out, see [`UpsilonCoordinate`].
+
+pub use self::iterator::UpsilonCoordinateSpaceIterator;
+pub use self::point::{UpsilonCoordinate, UpsilonEdge};
+pub use self::space::UpsilonCoordinateSpace;
+
+mod space;
+mod point;
+mod iterator;
\ No newline at end of file
@@ -0,0 +1,198 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::interface::point::Point;
+
+/// An upsilon coordinate.
+///
+/// Upsilon coordinate spaces are a grid of octagons, with a square in each of the gaps between
+/// them (a truncated square tiling). A coordinate is the column and row of a shape in this grid.
+/// The acceptable ranges for these coordinates are `0 <= column < space.columns()` and
+/// `0 <= row < space.rows()`.
+///
+/// Octagons and squares alternate like the squares of a checkerboard. The shape at (0, 0) is an
+/// octagon. From there, a shape is an octagon if the sum of its column and row is even, and a
+/// square otherwise.
+///
+/// Upsilon coordinates are often written out as (`<column>`, `<row>`).
+///
+/// Here's which shapes are where in an upsilon coordinate space with 4 columns and 3 rows, where
+/// `O` is an octagon and `S` is a square:
+///
+/// ```text
+/// O S O S
+/// S O S O
+/// O S O S
+/// ```
+///
+/// # Examples
+///
+/// It's just a regular struct with all fields public, so you can construct it directly.
+///
+/// ```
+/// # use mazelib::implm::point::upsilon::UpsilonCoordinate;
+/// #
+/// UpsilonCoordinate { column: 3, row: 2 };
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UpsilonCoordinate {
+    /// The column coordinate.
+    pub column: usize,
+
+    /// The row coordinate.
+    pub row: usize,
+}
+
+impl Point for UpsilonCoordinate {}
+
+impl UpsilonCoordinate {
+    /// Return whether the shape at this coordinate is an octagon.
+    ///
+    /// If it isn't, it's a square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::upsilon::UpsilonCoordinate;
+    /// #
+    /// assert!(UpsilonCoordinate { column: 0, row: 0 }.is_octagon());
+    /// assert!(UpsilonCoordinate { column: 1, row: 0 }.is_octagon() == false);
+    /// assert!(UpsilonCoordinate { column: 1, row: 1 }.is_octagon());
+    /// ```
+    #[must_use]
+    pub fn is_octagon(&self) -> bool {
+        (self.column + self.row) % 2 == 0
+    }
+
+    /// Return whether the shape at this coordinate has the given edge.
+    ///
+    /// Octagons have every edge. Squares only have the [straight][UpsilonEdge::STRAIGHT] ones.
+    #[must_use]
+    pub fn has_edge(&self, edge: UpsilonEdge) -> bool {
+        self.is_octagon() || edge.is_straight()
+    }
+
+    /// Return the coordinate of the shape that shares the given edge with this one.
+    ///
+    /// Returns `None` if this shape doesn't have that edge, or if the neighbour would have a
+    /// negative coordinate. The neighbour is not checked against any coordinate space, so it may
+    /// still be out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::upsilon::{UpsilonCoordinate, UpsilonEdge};
+    /// #
+    /// let octagon = UpsilonCoordinate { column: 1, row: 1 };
+    /// let square = UpsilonCoordinate { column: 1, row: 2 };
+    ///
+    /// assert_eq!(Some(square), octagon.neighbour(UpsilonEdge::DOWN));
+    /// assert_eq!(Some(UpsilonCoordinate { column: 2, row: 2 }), octagon.neighbour(UpsilonEdge::DOWN_RIGHT));
+    /// assert_eq!(None, square.neighbour(UpsilonEdge::DOWN_RIGHT));
+    /// ```
+    #[must_use]
+    pub fn neighbour(&self, edge: UpsilonEdge) -> Option<Self> {
+        if self.has_edge(edge) == false {
+            return None
+        }
+
+        let [dx, dy] = edge.offset();
+
+        return Some(Self {
+            column: self.column.checked_add_signed(dx)?,
+            row: self.row.checked_add_signed(dy)?,
+        })
+    }
+}
+
+impl Debug for UpsilonCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.column, self.row)
+    }
+}
+
+impl Display for UpsilonCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The eight edges of an octagon, and so the eight directions one can move in from it.
+///
+/// Squares only have the four [straight][Self::STRAIGHT] edges, which they share with octagons.
+/// Octagons share their diagonal edges with other octagons.
+///
+/// The edges are listed in the same order as [`UpsilonEdge::ALL`], which is also their value when
+/// cast to `usize`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[allow(non_camel_case_types)]
+pub enum UpsilonEdge {
+    /// The edge towards column zero.
+    LEFT,
+    /// The edge away from column zero.
+    RIGHT,
+    /// The edge towards row zero.
+    UP,
+    /// The edge away from row zero.
+    DOWN,
+    /// The diagonal edge towards column zero and row zero.
+    UP_LEFT,
+    /// The diagonal edge away from column zero and towards row zero.
+    UP_RIGHT,
+    /// The diagonal edge towards column zero and away from row zero.
+    DOWN_LEFT,
+    /// The diagonal edge away from column zero and row zero.
+    DOWN_RIGHT,
+}
+
+impl UpsilonEdge {
+    /// Every edge.
+    pub const ALL: [Self; 8] = [Self::LEFT, Self::RIGHT, Self::UP, Self::DOWN, Self::UP_LEFT, Self::UP_RIGHT, Self::DOWN_LEFT, Self::DOWN_RIGHT];
+
+    /// The edges that squares have.
+    pub const STRAIGHT: [Self; 4] = [Self::LEFT, Self::RIGHT, Self::UP, Self::DOWN];
+
+    /// Return whether this is one of the [straight][Self::STRAIGHT] edges that squares have.
+    #[must_use]
+    pub fn is_straight(&self) -> bool {
+        Self::STRAIGHT.contains(self)
+    }
+
+    /// Return the edge that the neighbour on the other side of this edge sees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::upsilon::UpsilonEdge;
+    /// #
+    /// assert_eq!(UpsilonEdge::RIGHT, UpsilonEdge::LEFT.opposite());
+    /// assert_eq!(UpsilonEdge::DOWN_LEFT, UpsilonEdge::UP_RIGHT.opposite());
+    /// ```
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::LEFT => Self::RIGHT,
+            Self::RIGHT => Self::LEFT,
+            Self::UP => Self::DOWN,
+            Self::DOWN => Self::UP,
+            Self::UP_LEFT => Self::DOWN_RIGHT,
+            Self::UP_RIGHT => Self::DOWN_LEFT,
+            Self::DOWN_LEFT => Self::UP_RIGHT,
+            Self::DOWN_RIGHT => Self::UP_LEFT,
+        }
+    }
+
+    /// Return the offset of this edge in columns and rows.
+    #[must_use]
+    fn offset(&self) -> [isize; 2] {
+        match self {
+            Self::LEFT => [-1, 0],
+            Self::RIGHT => [1, 0],
+            Self::UP => [0, -1],
+            Self::DOWN => [0, 1],
+            Self::UP_LEFT => [-1, -1],
+            Self::UP_RIGHT => [1, -1],
+            Self::DOWN_LEFT => [-1, 1],
+            Self::DOWN_RIGHT => [1, 1],
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,176 @@
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::upsilon::{UpsilonCoordinate, UpsilonCoordinateSpaceIterator, UpsilonEdge};
+use crate::interface::point::CoordinateSpace;
+use crate::rng::gen_index;
+
+/// An upsilon coordinate space.
+///
+/// An upsilon coordinate space is a grid of octagons and squares with a set number of columns and
+/// rows. Octagons and squares alternate like a checkerboard. For more details on the layout see
+/// [`UpsilonCoordinate`].
+///
+/// The origin of an upsilon coordinate space is at the point (0, 0).
+///
+/// # Adjacency
+///
+/// Two points are adjacent if their shapes share an edge. Octagons have up to eight neighbours:
+/// the squares directly to their left, right, above and below, and the octagons diagonally
+/// adjacent to them. Squares have up to four neighbours, the octagons directly to their left,
+/// right, above and below.
+///
+/// ## Adjacency Example
+///
+/// ```
+/// # use mazelib::implm::point::upsilon::{UpsilonCoordinate, UpsilonCoordinateSpace};
+/// # use mazelib::interface::point::CoordinateSpace;
+/// #
+/// let coord_space = UpsilonCoordinateSpace::new_checked(4, 4);
+///
+/// let octagon = UpsilonCoordinate { column: 1, row: 1 };
+/// let square = UpsilonCoordinate { column: 2, row: 1 };
+///
+/// assert_eq!(8, coord_space.neighbours_of_pt(octagon).len());
+/// assert_eq!(4, coord_space.neighbours_of_pt(square).len());
+///
+/// assert!(coord_space.are_adjacent(octagon, square));
+/// assert!(coord_space.are_adjacent(octagon, UpsilonCoordinate { column: 2, row: 2 }));
+/// assert!(coord_space.are_adjacent(square, UpsilonCoordinate { column: 3, row: 2 }) == false);
+/// assert!(coord_space.are_adjacent(octagon, octagon) == false);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct UpsilonCoordinateSpace {
+    columns: NonZeroUsize,
+    rows: NonZeroUsize,
+    size: NonZeroUsize,
+}
+
+impl UpsilonCoordinateSpace {
+    /// Construct a new `UpsilonCoordinateSpace` from the given dimensions.
+    ///
+    /// `columns * rows` must also fit within a `usize`. (Mazes this large won't fit in memory
+    /// anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `columns` --- the number of shapes in each row.  
+    /// `rows`    --- the number of rows of shapes.
+    ///
+    /// # See Also
+    ///
+    /// [`new_checked()`][Self::new_checked]
+    #[must_use]
+    pub fn new(columns: NonZeroUsize, rows: NonZeroUsize) -> Self {
+        let size = columns.checked_mul(rows).expect("The dimensions specified are too large. The number of points in the space does not fit within a usize.");
+
+        Self { columns, rows, size }
+    }
+
+    /// Construct a new `UpsilonCoordinateSpace` from the given dimensions.
+    ///
+    /// `columns * rows` must also fit within a `usize`. (Mazes this large won't fit in memory
+    /// anyway).
+    ///
+    /// # Parameters
+    ///
+    /// `columns` --- the number of shapes in each row.  
+    /// `rows`    --- the number of rows of shapes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::upsilon::UpsilonCoordinateSpace;
+    /// #
+    /// let coord_space = UpsilonCoordinateSpace::new_checked(15, 8);
+    /// ```
+    #[must_use]
+    pub fn new_checked(columns: usize, rows: usize) -> Self {
+        Self::new(NonZeroUsize::new(columns).expect("columns must be non-zero"), NonZeroUsize::new(rows).expect("rows must be non-zero"))
+    }
+
+    /// Return the number of columns in this coordinate space.
+    #[must_use]
+    pub fn columns(&self) -> NonZeroUsize {
+        self.columns
+    }
+
+    /// Return the number of rows in this coordinate space.
+    #[must_use]
+    pub fn rows(&self) -> NonZeroUsize {
+        self.rows
+    }
+
+    /// Return whether `pt` lies within this coordinate space.
+    #[must_use]
+    pub fn contains(&self, pt: UpsilonCoordinate) -> bool {
+        pt.column < usize::from(self.columns) && pt.row < usize::from(self.rows)
+    }
+
+    /// Return the neighbour of `pt` across the given edge, if it lies within this coordinate
+    /// space.
+    #[must_use]
+    pub fn neighbour(&self, pt: UpsilonCoordinate, edge: UpsilonEdge) -> Option<UpsilonCoordinate> {
+        pt.neighbour(edge).filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Return the edge of `from` that is shared with `to`.
+    ///
+    /// Returns `None` if the points are not adjacent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::upsilon::{UpsilonCoordinate, UpsilonCoordinateSpace, UpsilonEdge};
+    /// #
+    /// let coord_space = UpsilonCoordinateSpace::new_checked(4, 4);
+    ///
+    /// let from = UpsilonCoordinate { column: 0, row: 0 };
+    /// let to = UpsilonCoordinate { column: 1, row: 1 };
+    ///
+    /// assert_eq!(Some(UpsilonEdge::DOWN_RIGHT), coord_space.edge_between(from, to));
+    /// assert_eq!(Some(UpsilonEdge::UP_LEFT), coord_space.edge_between(to, from));
+    /// ```
+    #[must_use]
+    pub fn edge_between(&self, from: UpsilonCoordinate, to: UpsilonCoordinate) -> Option<UpsilonEdge> {
+        if self.contains(from) == false || self.contains(to) == false {
+            return None
+        }
+
+        UpsilonEdge::ALL.into_iter().find(|edge| from.neighbour(*edge) == Some(to))
+    }
+}
+
+impl CoordinateSpace for UpsilonCoordinateSpace {
+    type PtType = UpsilonCoordinate;
+    type Iter = UpsilonCoordinateSpaceIterator;
+
+    fn logical_size(&self) -> NonZeroUsize {
+        self.size
+    }
+
+    fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType> {
+        UpsilonEdge::ALL.into_iter().filter_map(|edge| self.neighbour(pt, edge)).collect()
+    }
+
+    fn are_adjacent(&self, pt1: Self::PtType, pt2: Self::PtType) -> bool {
+        self.edge_between(pt1, pt2).is_some()
+    }
+
+    fn iter(&self) -> Self::Iter {
+        UpsilonCoordinateSpaceIterator::new(*self, None)
+    }
+
+    fn iter_from(&self, pt: Self::PtType) -> Self::Iter {
+        UpsilonCoordinateSpaceIterator::new(*self, Some(pt))
+    }
+
+    fn choose(&self, rng: &mut (impl Rng + ?Sized)) -> Self::PtType {
+        let column = gen_index(rng, self.columns.into());
+
+        let row = gen_index(rng, self.rows.into());
+
+        return Self::PtType { column, row }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+//! Shorthands for the most common maze setups.
+//!
+//! Spelling out a coordinator's buffer, cell value, and dimension every time gets long. These
+//! aliases name the usual combinations, all stored in a [`VecBuffer`].
+//!
+//! # Examples
+//!
+//! ```
+//! use mazelib::implm::point::boxy::BoxCoordinateSpace;
+//! use mazelib::implm::presets::{Inline2DMazeBuilder, Standard2DMaze};
+//!
+//! let block_maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([10, 10])).build();
+//! let inline_maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([10, 10])).build();
+//! ```
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::{BoxSpaceBlockCellMazeCoordinator, BoxSpaceBlockCellMazeCoordinatorBuilder};
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+
+/// A 2D maze where walls take up whole cells. The most common type of maze.
+pub type Standard2DMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+/// The builder for a [`Standard2DMaze`].
+pub type Standard2DMazeBuilder = BoxSpaceBlockCellMazeCoordinatorBuilder<VecBuffer<BlockCellValue>, 2>;
+
+/// Like [`Standard2DMaze`], but 3D.
+pub type Standard3DMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 3>;
+
+/// The builder for a [`Standard3DMaze`].
+pub type Standard3DMazeBuilder = BoxSpaceBlockCellMazeCoordinatorBuilder<VecBuffer<BlockCellValue>, 3>;
+
+/// A 2D maze where walls sit between cells.
+pub type Inline2DMaze = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<2>>, 2>;
+
+/// The builder for an [`Inline2DMaze`].
+pub type Inline2DMazeBuilder = BoxSpaceInlineCellMazeCoordinatorBuilder<VecBuffer<InlineCellValue<2>>, 2>;
+
+/// Like [`Inline2DMaze`], but 3D.
+pub type Inline3DMaze = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<3>>, 3>;
+
+/// The builder for an [`Inline3DMaze`].
+pub type Inline3DMazeBuilder = BoxSpaceInlineCellMazeCoordinatorBuilder<VecBuffer<InlineCellValue<3>>, 3>;
\ No newline at end of file
@@ -0,0 +1,109 @@
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::implm::template::{MazeTemplate, TemplateCanvas};
+use crate::interface::coordinate::MazeCoordinator;
+
+/// A junction of four straight passages.
+///
+/// The template is a square `2 * arm_length + 1` points wide. An arm runs from the centre to the
+/// middle of each side, with walls down either side of it, and leads out of the template. Any arm
+/// can be [closed][Self::close] at the end, making T-junctions, corners, and dead ends. The
+/// corners of the template are left untouched, for the generator to fill in.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::implm::template::{CrossroadsTemplate, MazeTemplate, Orientation};
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([7, 7])).build();
+///
+/// // A T-junction, turned so that the closed arm faces west
+/// CrossroadsTemplate::new(2)
+///     .close(BoxDirection::NORTH)
+///     .apply(&mut maze, [1, 1].into(), Orientation::new(3, false));
+///
+/// assert!(maze.is_passage_between([3, 1].into(), [3, 0].into()));
+/// assert!(maze.is_wall_between([1, 3].into(), [0, 3].into()));
+/// assert!(maze.is_wall_between([2, 3].into(), [2, 2].into()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CrossroadsTemplate {
+    /// The number of points in each arm, not counting the centre.
+    arm_length: usize,
+    /// The arms that are closed at the end.
+    closed: Vec<BoxDirection<2>>,
+}
+
+impl CrossroadsTemplate {
+    /// Construct a new crossroads with every arm open.
+    ///
+    /// # Parameters
+    ///
+    /// `arm_length` --- the number of points in each arm, not counting the centre. May be zero,
+    ///                  in which case the template is a single point.
+    #[must_use]
+    pub fn new(arm_length: usize) -> Self {
+        Self { arm_length, closed: Vec::new() }
+    }
+
+    /// Close the end of the arm in the given direction.
+    #[must_use]
+    pub fn close(mut self, direction: BoxDirection<2>) -> Self {
+        if self.closed.contains(&direction) == false {
+            self.closed.push(direction);
+        }
+
+        self
+    }
+
+    /// Return the number of points in each arm, not counting the centre.
+    #[must_use]
+    pub fn arm_length(&self) -> usize {
+        self.arm_length
+    }
+
+    /// Return whether the arm in the given direction leads out of the template.
+    #[must_use]
+    pub fn is_open(&self, direction: BoxDirection<2>) -> bool {
+        self.closed.contains(&direction) == false
+    }
+}
+
+impl MazeTemplate for CrossroadsTemplate {
+    fn dimensions(&self) -> [usize; 2] {
+        [self.arm_length * 2 + 1; 2]
+    }
+
+    fn draw<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(&self, canvas: &mut TemplateCanvas<'_, M, DIMENSION>) {
+        let centre: CoordinateTuplet<2> = [self.arm_length; 2].into();
+
+        canvas.make_passage(centre);
+
+        for direction in BoxDirection::<2>::all() {
+            let mut pt = centre;
+
+            for _ in 0..self.arm_length {
+                let next = pt.neighbour(direction).expect("arms stay within the template");
+
+                canvas.make_passage_between(pt, next);
+                pt = next;
+
+                // Wall off both sides of the arm
+                let side = BoxDirection::new(1 - direction.axis(), true);
+
+                canvas.make_wall_towards(pt, side);
+                canvas.make_wall_towards(pt, side.opposite());
+            }
+
+            if self.is_open(direction) {
+                canvas.make_passage_towards(pt, direction);
+            } else {
+                canvas.make_wall_towards(pt, direction);
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,118 @@
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::implm::template::{MazeTemplate, TemplateCanvas};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::util::BorderKind;
+
+/// An enclosure with gates in it.
+///
+/// The outside of the template is closed off, except for passages through the gates. The inside
+/// is left untouched, for the generator to fill in. This is handy for dividing a maze into
+/// districts that only connect in a few places.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::implm::template::{GatedBorderTemplate, MazeTemplate, Orientation};
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([6, 6])).build();
+///
+/// GatedBorderTemplate::new([4, 4])
+///     .gate([3, 1].into(), BoxDirection::EAST)
+///     .apply(&mut maze, [1, 1].into(), Orientation::IDENTITY);
+///
+/// assert!(maze.is_boundary_between([1, 1].into(), [0, 1].into()));
+/// assert!(maze.is_passage_between([4, 2].into(), [5, 2].into()));
+/// assert!(maze.is_unvisited_between([1, 1].into(), [2, 1].into()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GatedBorderTemplate {
+    /// The size of the enclosure.
+    dimensions: [usize; 2],
+    /// What the enclosure is made of.
+    kind: BorderKind,
+    /// The points along the outside with a gate, and the side the gate is on.
+    gates: Vec<(CoordinateTuplet<2>, BoxDirection<2>)>,
+}
+
+impl GatedBorderTemplate {
+    /// Construct a new enclosure made of boundaries, without any gates.
+    ///
+    /// # Panics
+    ///
+    /// If either dimension is zero.
+    #[must_use]
+    pub fn new(dimensions: [usize; 2]) -> Self {
+        assert!(dimensions.contains(&0) == false, "Templates must have non-zero dimensions");
+
+        Self { dimensions, kind: BorderKind::Boundary, gates: Vec::new() }
+    }
+
+    /// Set what the enclosure is made of.
+    #[must_use]
+    pub fn kind(mut self, kind: BorderKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Add a gate leading out of `pt` in the given direction.
+    ///
+    /// # Panics
+    ///
+    /// If `pt` is not along the outside of the enclosure, or `direction` does not face out of it.
+    #[must_use]
+    pub fn gate(mut self, pt: CoordinateTuplet<2>, direction: BoxDirection<2>) -> Self {
+        assert!(faces_out(self.dimensions, pt, direction), "Gates must face out of the template");
+
+        self.gates.push((pt, direction));
+        self
+    }
+
+    /// Return what the enclosure is made of.
+    #[must_use]
+    pub fn get_kind(&self) -> BorderKind {
+        self.kind
+    }
+
+    /// Return the gates, as the point each leads out of and the direction it faces.
+    #[must_use]
+    pub fn gates(&self) -> &[(CoordinateTuplet<2>, BoxDirection<2>)] {
+        &self.gates
+    }
+}
+
+impl MazeTemplate for GatedBorderTemplate {
+    fn dimensions(&self) -> [usize; 2] {
+        self.dimensions
+    }
+
+    fn draw<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(&self, canvas: &mut TemplateCanvas<'_, M, DIMENSION>) {
+        let perimeter: Vec<_> = canvas.perimeter().collect();
+
+        for (pt, direction) in perimeter {
+            if self.gates.contains(&(pt, direction)) {
+                canvas.make_passage_towards(pt, direction);
+            } else {
+                match self.kind {
+                    BorderKind::Boundary => canvas.make_boundary_towards(pt, direction),
+                    BorderKind::Wall     => canvas.make_wall_towards(pt, direction),
+                }
+            }
+        }
+    }
+}
+
+/// Whether `direction` leads out of a template with the given dimensions from `pt`.
+pub(super) fn faces_out(dimensions: [usize; 2], pt: CoordinateTuplet<2>, direction: BoxDirection<2>) -> bool {
+    let axis = direction.axis();
+
+    if pt[0] >= dimensions[0] || pt[1] >= dimensions[1] {
+        return false
+    }
+
+    return if direction.is_positive() { pt[axis] == dimensions[axis] - 1 } else { pt[axis] == 0 }
+}
\ No newline at end of file
@@ -0,0 +1,418 @@
+//! Reusable, parameterised pieces of mazes.
+//!
+//! A template is a small, hand-designed feature --- a room, a crossroads, a spiral --- that can be
+//! stamped into a [box maze][BoxCoordinateSpace] before it is generated. The generator then works
+//! the template into the rest of the maze. Templates can be rotated and mirrored as they are
+//! applied, so a single template can be reused in any [orientation][Orientation].
+//!
+//! Templates are two-dimensional. In mazes with more than two dimensions, they are drawn in the
+//! plane of the first two axes, at the layer given by the offset.
+//!
+//! The built-in templates are:
+//! * [`RoomTemplate`] --- an open room with doors.
+//! * [`CrossroadsTemplate`] --- a junction of straight passages.
+//! * [`SpiralTemplate`] --- a passage spiralling into the centre.
+//! * [`GatedBorderTemplate`] --- an enclosure with gates, leaving the inside to the generator.
+//!
+//! To write your own, implement [`MazeTemplate`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use rand::SeedableRng;
+//! # use rand_chacha::ChaCha8Rng;
+//! # use mazelib::implm::buffer::VecBuffer;
+//! # use mazelib::implm::cell::block::BlockCellValue;
+//! # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+//! # use mazelib::implm::generate::HuntAndKillGenerator;
+//! # use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+//! # use mazelib::interface::coordinate::MazeCoordinator;
+//! # use mazelib::interface::generate::MazeGenerator;
+//! use mazelib::implm::template::{MazeTemplate, Orientation, RoomTemplate};
+//!
+//! let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([10, 10])).build();
+//!
+//! // A 3x2 room with a door on its left-hand side, turned so the door faces up
+//! let room = RoomTemplate::new([3, 2]).door([0, 0].into(), BoxDirection::WEST);
+//! room.apply(&mut maze, [4, 4].into(), Orientation::new(1, false));
+//!
+//! HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(1));
+//!
+//! assert!(maze.is_passage_between([5, 4].into(), [5, 3].into()));
+//! assert!(maze.is_boundary_between([4, 4].into(), [3, 4].into()));
+//! ```
+
+use rand::Rng;
+
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+
+pub use self::crossroads::CrossroadsTemplate;
+pub use self::gated_border::GatedBorderTemplate;
+pub use self::room::RoomTemplate;
+pub use self::spiral::SpiralTemplate;
+
+mod crossroads;
+mod gated_border;
+mod room;
+mod spiral;
+
+/// A reusable piece of a maze.
+///
+/// Templates are drawn in their own coordinates, from `[0, 0]` to just short of their
+/// [dimensions][Self::dimensions], through a [`TemplateCanvas`]. The canvas takes care of placing
+/// and orienting them in the maze.
+pub trait MazeTemplate {
+    /// Return the size of the template, before it is oriented.
+    #[must_use]
+    fn dimensions(&self) -> [usize; 2];
+
+    /// Draw the template onto `canvas`.
+    ///
+    /// Templates should only draw points within their dimensions, and connections between them
+    /// and their neighbours.
+    fn draw<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(&self, canvas: &mut TemplateCanvas<'_, M, DIMENSION>);
+
+    /// Draw the template into `maze`, oriented as per `orientation`, with the corner nearest the
+    /// origin at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// If the maze has fewer than two dimensions.
+    ///
+    /// If the template does not fit within the maze at `offset`.
+    fn apply<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(&self, maze: &mut M, offset: CoordinateTuplet<DIMENSION>, orientation: Orientation) {
+        assert!(DIMENSION >= 2, "Templates can only be applied to mazes with at least two dimensions");
+
+        let dimensions = self.dimensions();
+        let oriented_dimensions = orientation.oriented_dimensions(dimensions);
+
+        for axis in 0..DIMENSION {
+            let extent = if axis < 2 { oriented_dimensions[axis] } else { 1 };
+
+            assert!(offset[axis] + extent <= usize::from(maze.coord_space()[axis]), "The template does not fit within the maze");
+        }
+
+        self.draw(&mut TemplateCanvas { maze, offset, orientation, dimensions })
+    }
+}
+
+/// How a [template][MazeTemplate] is turned when it is applied.
+///
+/// Templates are first mirrored (if at all) along the x-axis, then rotated clockwise a number of
+/// quarter turns. As in the text exporters, the x-axis points right and the y-axis points down.
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::implm::template::Orientation;
+///
+/// let orientation = Orientation::new(1, false);
+///
+/// // A quarter turn clockwise moves the top-left corner to the top-right
+/// assert_eq!([2, 3], orientation.oriented_dimensions([3, 2]));
+/// assert_eq!([1, 0], orientation.orient_pt([0, 0].into(), [3, 2]).0);
+///
+/// assert_eq!(8, Orientation::all().count());
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct Orientation {
+    /// The number of clockwise quarter turns, from 0 to 3.
+    quarter_turns: u8,
+    /// Whether the template is mirrored before it is turned.
+    mirrored: bool,
+}
+
+impl Orientation {
+    /// The orientation that leaves templates as they are.
+    pub const IDENTITY: Self = Self { quarter_turns: 0, mirrored: false };
+
+    /// Construct a new orientation.
+    ///
+    /// # Parameters
+    ///
+    /// `quarter_turns` --- the number of clockwise quarter turns. Wraps around every four turns.  
+    /// `mirrored`      --- whether to mirror the template along the x-axis before turning it.
+    #[must_use]
+    pub fn new(quarter_turns: u8, mirrored: bool) -> Self {
+        Self { quarter_turns: quarter_turns % 4, mirrored }
+    }
+
+    /// Return an iterator over all eight orientations.
+    pub fn all() -> impl Iterator<Item = Self> {
+        [false, true].into_iter().flat_map(|mirrored| (0..4).map(move |quarter_turns| Self::new(quarter_turns, mirrored)))
+    }
+
+    /// Choose an orientation uniformly at random.
+    #[must_use]
+    pub fn random(rng: &mut (impl Rng + ?Sized)) -> Self {
+        Self::new(rng.gen_range(0..4), rng.gen())
+    }
+
+    /// Return the number of clockwise quarter turns, from 0 to 3.
+    #[must_use]
+    pub fn quarter_turns(&self) -> u8 {
+        self.quarter_turns
+    }
+
+    /// Return whether templates are mirrored before they are turned.
+    #[must_use]
+    pub fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    /// Return the size of a template with the given dimensions once it has been oriented.
+    #[must_use]
+    pub fn oriented_dimensions(&self, dimensions: [usize; 2]) -> [usize; 2] {
+        if self.quarter_turns % 2 == 0 { dimensions } else { [dimensions[1], dimensions[0]] }
+    }
+
+    /// Return where a point of a template with the given dimensions ends up once it has been
+    /// oriented.
+    #[must_use]
+    pub fn orient_pt(&self, pt: CoordinateTuplet<2>, dimensions: [usize; 2]) -> CoordinateTuplet<2> {
+        let [mut width, mut height] = dimensions;
+        let [mut x, mut y] = pt.0;
+
+        if self.mirrored {
+            x = width - 1 - x;
+        }
+
+        for _ in 0..self.quarter_turns {
+            [x, y] = [height - 1 - y, x];
+            [width, height] = [height, width];
+        }
+
+        return [x, y].into()
+    }
+
+    /// Return which way a direction within a template faces once it has been oriented.
+    #[must_use]
+    pub fn orient_direction(&self, direction: BoxDirection<2>) -> BoxDirection<2> {
+        let mut direction = direction;
+
+        if self.mirrored && direction.axis() == 0 {
+            direction = direction.opposite();
+        }
+
+        for _ in 0..self.quarter_turns {
+            // East becomes south, and south becomes west
+            direction = if direction.axis() == 0 {
+                BoxDirection::new(1, direction.is_positive())
+            } else {
+                BoxDirection::new(0, direction.is_positive() == false)
+            };
+        }
+
+        return direction
+    }
+}
+
+/// Draws a [template][MazeTemplate] into a maze.
+///
+/// Every method takes points in the template's own coordinates, and places them in the maze as
+/// per the template's offset and [orientation][Orientation].
+///
+/// # Panics
+///
+/// Every method panics if given a point outside the template's dimensions.
+pub struct TemplateCanvas<'a, M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> {
+    /// The maze being drawn into.
+    maze: &'a mut M,
+    /// Where the template's corner nearest the origin lies in the maze.
+    offset: CoordinateTuplet<DIMENSION>,
+    /// How the template is turned.
+    orientation: Orientation,
+    /// The size of the template, before it is oriented.
+    dimensions: [usize; 2],
+}
+
+impl <'a, M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> TemplateCanvas<'a, M, DIMENSION> {
+    /// Return the size of the template, before it is oriented.
+    #[must_use]
+    pub fn dimensions(&self) -> [usize; 2] {
+        self.dimensions
+    }
+
+    /// Return the maze being drawn into.
+    #[must_use]
+    pub fn maze(&self) -> &M {
+        self.maze
+    }
+
+    /// Return where a point of the template lies in the maze.
+    #[must_use]
+    pub fn to_maze_pt(&self, pt: CoordinateTuplet<2>) -> CoordinateTuplet<DIMENSION> {
+        assert!(pt[0] < self.dimensions[0] && pt[1] < self.dimensions[1], "The point lies outside the template");
+
+        let oriented = self.orientation.orient_pt(pt, self.dimensions);
+
+        return self.offset.offset(0, oriented[0] as isize).offset(1, oriented[1] as isize)
+    }
+
+    /// Return the neighbour of a point of the template in the maze, in the given direction.
+    ///
+    /// The neighbour may lie outside the template. Returns `None` if it lies outside the maze.
+    #[must_use]
+    pub fn maze_neighbour(&self, pt: CoordinateTuplet<2>, direction: BoxDirection<2>) -> Option<CoordinateTuplet<DIMENSION>> {
+        let direction = self.orientation.orient_direction(direction);
+
+        return self.maze.coord_space().neighbour(self.to_maze_pt(pt), BoxDirection::new(direction.axis(), direction.is_positive()))
+    }
+
+    /// Return an iterator over the outside edges of the template, as each point along the
+    /// outside and the direction facing out of the template.
+    ///
+    /// Corner points are yielded once for each outward direction.
+    pub fn perimeter(&self) -> impl Iterator<Item = (CoordinateTuplet<2>, BoxDirection<2>)> + use<'a, M, DIMENSION> {
+        let [width, height] = self.dimensions;
+
+        let horizontal = (0..width).flat_map(move |x| [([x, 0].into(), BoxDirection::NORTH), ([x, height - 1].into(), BoxDirection::SOUTH)]);
+        let vertical = (0..height).flat_map(move |y| [([0, y].into(), BoxDirection::WEST), ([width - 1, y].into(), BoxDirection::EAST)]);
+
+        horizontal.chain(vertical)
+    }
+
+    /// Make a passage at `pt`.
+    pub fn make_passage(&mut self, pt: CoordinateTuplet<2>) {
+        let pt = self.to_maze_pt(pt);
+
+        self.maze.make_passage(pt)
+    }
+
+    /// Make a wall at `pt`.
+    pub fn make_wall(&mut self, pt: CoordinateTuplet<2>) {
+        let pt = self.to_maze_pt(pt);
+
+        self.maze.make_wall(pt)
+    }
+
+    /// Make a boundary at `pt`.
+    pub fn make_boundary(&mut self, pt: CoordinateTuplet<2>) {
+        let pt = self.to_maze_pt(pt);
+
+        self.maze.make_boundary(pt)
+    }
+
+    /// Make a passage from `from` to `to`. The points must be adjacent.
+    pub fn make_passage_between(&mut self, from: CoordinateTuplet<2>, to: CoordinateTuplet<2>) {
+        let (from, to) = (self.to_maze_pt(from), self.to_maze_pt(to));
+
+        self.maze.make_passage_between(from, to)
+    }
+
+    /// Make a wall from `from` to `to`. The points must be adjacent.
+    pub fn make_wall_between(&mut self, from: CoordinateTuplet<2>, to: CoordinateTuplet<2>) {
+        let (from, to) = (self.to_maze_pt(from), self.to_maze_pt(to));
+
+        self.maze.make_wall_between(from, to)
+    }
+
+    /// Make a boundary from `from` to `to`. The points must be adjacent.
+    pub fn make_boundary_between(&mut self, from: CoordinateTuplet<2>, to: CoordinateTuplet<2>) {
+        let (from, to) = (self.to_maze_pt(from), self.to_maze_pt(to));
+
+        self.maze.make_boundary_between(from, to)
+    }
+
+    /// Make a passage from `pt` to its neighbour in the given direction, which may lie outside the
+    /// template. Does nothing if the neighbour lies outside the maze.
+    pub fn make_passage_towards(&mut self, pt: CoordinateTuplet<2>, direction: BoxDirection<2>) {
+        if let Some(neighbour) = self.maze_neighbour(pt, direction) {
+            let pt = self.to_maze_pt(pt);
+
+            self.maze.make_passage_between(pt, neighbour)
+        }
+    }
+
+    /// Make a wall from `pt` to its neighbour in the given direction, which may lie outside the
+    /// template. Does nothing if the neighbour lies outside the maze.
+    ///
+    /// Unlike [`make_wall_between()`][Self::make_wall_between], only the connection is closed.
+    /// Both points are left as they are.
+    pub fn make_wall_towards(&mut self, pt: CoordinateTuplet<2>, direction: BoxDirection<2>) {
+        self.close_towards(pt, direction, ConnectionType::WALL)
+    }
+
+    /// Make a boundary from `pt` to its neighbour in the given direction, which may lie outside
+    /// the template. Does nothing if the neighbour lies outside the maze.
+    ///
+    /// Unlike [`make_boundary_between()`][Self::make_boundary_between], only the connection is
+    /// closed. Both points are left as they are.
+    pub fn make_boundary_towards(&mut self, pt: CoordinateTuplet<2>, direction: BoxDirection<2>) {
+        self.close_towards(pt, direction, ConnectionType::BOUNDARY)
+    }
+
+    fn close_towards(&mut self, pt: CoordinateTuplet<2>, direction: BoxDirection<2>, connection: ConnectionType) {
+        if let Some(neighbour) = self.maze_neighbour(pt, direction) {
+            let pt = self.to_maze_pt(pt);
+
+            <FixSpecialisationPls as CloseConnection<M, DIMENSION>>::close(self.maze, pt, neighbour, connection)
+        }
+    }
+}
+
+// Same trick as for apply_solid_border(). Making a wall or boundary between block cell points
+// also fills in the points themselves, which would wall off the template's own passages, and doing
+// so between inline cell points walls off every other unvisited edge of both points.
+
+trait CloseConnection<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> {
+    fn close(maze: &mut M, from: CoordinateTuplet<DIMENSION>, to: CoordinateTuplet<DIMENSION>, connection: ConnectionType);
+}
+
+struct FixSpecialisationPls {}
+
+impl <M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> CloseConnection<M, DIMENSION> for FixSpecialisationPls {
+    default fn close(maze: &mut M, from: CoordinateTuplet<DIMENSION>, to: CoordinateTuplet<DIMENSION>, connection: ConnectionType) {
+        match connection {
+            ConnectionType::WALL     => maze.make_wall_between(from, to),
+            ConnectionType::BOUNDARY => maze.make_boundary_between(from, to),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> CloseConnection<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, DIMENSION> for FixSpecialisationPls {
+    fn close(maze: &mut BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, from: CoordinateTuplet<DIMENSION>, to: CoordinateTuplet<DIMENSION>, connection: ConnectionType) {
+        let cell_type = match connection {
+            ConnectionType::WALL     => BlockCellPrimaryValue::WALL,
+            ConnectionType::BOUNDARY => BlockCellPrimaryValue::BOUNDARY,
+            _ => unreachable!(),
+        };
+
+        // Only the cells strictly between the points. If there are none, the points are touching
+        // and can't be separated.
+        let cells = maze.get_cells_between(from, to);
+
+        for cell in &cells[1..cells.len() - 1] {
+            maze.set_cell_value_type(*cell, cell_type);
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> CloseConnection<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, DIMENSION> for FixSpecialisationPls {
+    fn close(maze: &mut BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, from: CoordinateTuplet<DIMENSION>, to: CoordinateTuplet<DIMENSION>, connection: ConnectionType) {
+        let edge = match connection {
+            ConnectionType::WALL     => InlineCellValueEdge::WALL,
+            ConnectionType::BOUNDARY => InlineCellValueEdge::BOUNDARY,
+            _ => unreachable!(),
+        };
+
+        let (axis, from_before_to) = maze.get_adjacency(from, to);
+        let from_side = usize::from(from_before_to);
+
+        let mut from_value = maze.get(from);
+        from_value.edges[axis][from_side] = edge;
+        maze.set(from, from_value);
+
+        let mut to_value = maze.get(to);
+        to_value.edges[axis][1 - from_side] = edge;
+        maze.set(to, to_value);
+    }
+}
\ No newline at end of file
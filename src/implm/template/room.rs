@@ -0,0 +1,108 @@
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::implm::template::{GatedBorderTemplate, MazeTemplate, TemplateCanvas};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::util::BorderKind;
+
+/// An open room with doors.
+///
+/// Every point in the room is connected to its neighbours. The room is enclosed by boundaries (or
+/// walls, see [`kind()`][Self::kind]) except at its doors.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::implm::template::{MazeTemplate, Orientation, RoomTemplate};
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([5, 5])).build();
+///
+/// RoomTemplate::new([3, 3])
+///     .door([1, 0].into(), BoxDirection::NORTH)
+///     .apply(&mut maze, [1, 1].into(), Orientation::IDENTITY);
+///
+/// assert!(maze.is_passage_between([1, 1].into(), [2, 1].into()));
+/// assert!(maze.is_passage_between([2, 1].into(), [2, 0].into()));
+/// assert!(maze.is_boundary_between([1, 1].into(), [1, 0].into()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RoomTemplate {
+    /// The walls and doors around the room.
+    border: GatedBorderTemplate,
+}
+
+impl RoomTemplate {
+    /// Construct a new room enclosed by boundaries, without any doors.
+    ///
+    /// # Panics
+    ///
+    /// If either dimension is zero.
+    #[must_use]
+    pub fn new(dimensions: [usize; 2]) -> Self {
+        Self { border: GatedBorderTemplate::new(dimensions) }
+    }
+
+    /// Set what the room is enclosed by. If it is enclosed by walls, generators may add more
+    /// doors.
+    #[must_use]
+    pub fn kind(mut self, kind: BorderKind) -> Self {
+        self.border = self.border.kind(kind);
+        self
+    }
+
+    /// Add a door leading out of `pt` in the given direction.
+    ///
+    /// # Panics
+    ///
+    /// If `pt` is not along the outside of the room, or `direction` does not face out of it.
+    #[must_use]
+    pub fn door(mut self, pt: CoordinateTuplet<2>, direction: BoxDirection<2>) -> Self {
+        self.border = self.border.gate(pt, direction);
+        self
+    }
+
+    /// Return what the room is enclosed by.
+    #[must_use]
+    pub fn get_kind(&self) -> BorderKind {
+        self.border.get_kind()
+    }
+
+    /// Return the doors, as the point each leads out of and the direction it faces.
+    #[must_use]
+    pub fn doors(&self) -> &[(CoordinateTuplet<2>, BoxDirection<2>)] {
+        self.border.gates()
+    }
+}
+
+impl MazeTemplate for RoomTemplate {
+    fn dimensions(&self) -> [usize; 2] {
+        self.border.dimensions()
+    }
+
+    fn draw<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(&self, canvas: &mut TemplateCanvas<'_, M, DIMENSION>) {
+        let [width, height] = canvas.dimensions();
+
+        for y in 0..height {
+            for x in 0..width {
+                canvas.make_passage([x, y].into());
+            }
+        }
+
+        for y in 0..height {
+            for x in 0..width {
+                if x + 1 < width {
+                    canvas.make_passage_between([x, y].into(), [x + 1, y].into());
+                }
+
+                if y + 1 < height {
+                    canvas.make_passage_between([x, y].into(), [x, y + 1].into());
+                }
+            }
+        }
+
+        self.border.draw(canvas)
+    }
+}
\ No newline at end of file
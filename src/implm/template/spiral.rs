@@ -0,0 +1,140 @@
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::implm::template::{MazeTemplate, TemplateCanvas};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::util::BorderKind;
+
+/// A single passage spiralling clockwise into the centre.
+///
+/// The passage enters at the top-left corner from the left, then winds around the template until
+/// it dead-ends in the middle. The spiral is enclosed by boundaries (or walls, see
+/// [`kind()`][Self::kind]) everywhere but the entrance. Mirror it for an anticlockwise spiral.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::implm::template::{MazeTemplate, Orientation, SpiralTemplate};
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+///
+/// SpiralTemplate::new([3, 3]).apply(&mut maze, [0, 0].into(), Orientation::IDENTITY);
+///
+/// assert!(maze.is_passage_between([2, 0].into(), [2, 1].into()));
+/// assert!(maze.is_passage_between([0, 1].into(), [1, 1].into()));
+/// assert!(maze.is_wall_between([1, 0].into(), [1, 1].into()));
+/// assert!(maze.is_wall_between([0, 0].into(), [0, 1].into()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpiralTemplate {
+    /// The size of the spiral.
+    dimensions: [usize; 2],
+    /// What the spiral is enclosed by.
+    kind: BorderKind,
+}
+
+impl SpiralTemplate {
+    /// Construct a new spiral enclosed by boundaries.
+    ///
+    /// # Panics
+    ///
+    /// If either dimension is zero.
+    #[must_use]
+    pub fn new(dimensions: [usize; 2]) -> Self {
+        assert!(dimensions.contains(&0) == false, "Templates must have non-zero dimensions");
+
+        Self { dimensions, kind: BorderKind::Boundary }
+    }
+
+    /// Set what the spiral is enclosed by. If it is enclosed by walls, generators may add more
+    /// entrances.
+    #[must_use]
+    pub fn kind(mut self, kind: BorderKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Return what the spiral is enclosed by.
+    #[must_use]
+    pub fn get_kind(&self) -> BorderKind {
+        self.kind
+    }
+
+    /// Return the points of the spiral in the order the passage visits them.
+    fn path(&self) -> Vec<CoordinateTuplet<2>> {
+        let [width, height] = self.dimensions;
+
+        let mut path = Vec::with_capacity(width * height);
+
+        // The bounds of the ring currently being walked, inclusive
+        let (mut left, mut top, mut right, mut bottom) = (0, 0, width - 1, height - 1);
+
+        loop {
+            path.extend((left..=right).map(|x| CoordinateTuplet([x, top])));
+            if top == bottom { break }
+            top += 1;
+
+            path.extend((top..=bottom).map(|y| CoordinateTuplet([right, y])));
+            if left == right { break }
+            right -= 1;
+
+            path.extend((left..=right).rev().map(|x| CoordinateTuplet([x, bottom])));
+            if top == bottom { break }
+            bottom -= 1;
+
+            path.extend((top..=bottom).rev().map(|y| CoordinateTuplet([left, y])));
+            if left == right { break }
+            left += 1;
+        }
+
+        return path
+    }
+}
+
+impl MazeTemplate for SpiralTemplate {
+    fn dimensions(&self) -> [usize; 2] {
+        self.dimensions
+    }
+
+    fn draw<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(&self, canvas: &mut TemplateCanvas<'_, M, DIMENSION>) {
+        let [width, height] = self.dimensions;
+        let path = self.path();
+
+        // Wall everything off first, then carve the passage through it
+        for y in 0..height {
+            for x in 0..width {
+                canvas.make_wall([x, y].into());
+
+                if x + 1 < width {
+                    canvas.make_wall_between([x, y].into(), [x + 1, y].into());
+                }
+
+                if y + 1 < height {
+                    canvas.make_wall_between([x, y].into(), [x, y + 1].into());
+                }
+            }
+        }
+
+        canvas.make_passage(path[0]);
+
+        for step in path.windows(2) {
+            canvas.make_passage_between(step[0], step[1]);
+        }
+
+        let perimeter: Vec<_> = canvas.perimeter().collect();
+
+        for (pt, direction) in perimeter {
+            if pt == path[0] && direction == BoxDirection::WEST {
+                canvas.make_passage_towards(pt, direction);
+            } else {
+                match self.kind {
+                    BorderKind::Boundary => canvas.make_boundary_towards(pt, direction),
+                    BorderKind::Wall     => canvas.make_wall_towards(pt, direction),
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
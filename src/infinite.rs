@@ -0,0 +1,278 @@
+//! Mazes without edges, generated a chunk at a time as they are explored.
+//!
+//! An [`InfiniteMaze`] is divided into fixed-size chunks, each of which is an ordinary
+//! [inline cell maze][crate::implm::presets::Inline2DMaze]. A chunk is only generated when
+//! something in it is first looked at, so the maze can be explored in any direction for as long as
+//! you like while only ever storing the chunks that have been visited.
+//!
+//! Every chunk is generated from the maze's seed and its own position alone, so the same seed
+//! always produces the same maze no matter which order its chunks are explored in, or which
+//! platform it's explored on (see [`PortableRng`][crate::rng::PortableRng]). Neighbouring
+//! chunks are joined by a passage through the border between them, which is also chosen from the
+//! seed and the border's position. As each chunk is connected within itself, every point in the
+//! maze can be reached from every other.
+//!
+//! # Examples
+//!
+//! ```
+//! use mazelib::implm::generate::HuntAndKillGenerator;
+//! use mazelib::infinite::InfiniteMaze;
+//!
+//! let mut maze = InfiniteMaze::new(HuntAndKillGenerator::new(), [8, 8], 42);
+//!
+//! // Look at a point a long way from the origin, in the negative direction
+//! let _ = maze.connected_neighbours([-1003, 37]);
+//!
+//! assert_eq!(1, maze.chunk_count());
+//! assert!(maze.is_generated(maze.chunk_of([-1003, 37])));
+//! ```
+
+use std::collections::HashMap;
+
+use rand::SeedableRng;
+
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::implm::presets::{Inline2DMaze, Inline2DMazeBuilder};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::rng::{gen_index, PortableRng};
+
+/// A point in an [`InfiniteMaze`], which may be anywhere (including at negative coordinates).
+pub type InfinitePt = [i64; 2];
+
+/// The position of a chunk of an [`InfiniteMaze`]. The chunk at `[0, 0]` contains the origin, the
+/// chunk at `[1, 0]` is the one after it along the first axis, and so on.
+pub type ChunkPos = [i64; 2];
+
+/// A two-dimensional maze that extends forever in every direction, generated a chunk at a time.
+///
+/// *See the [module-level docs][self].*
+///
+/// Looking at a point generates its chunk if it hasn't been already, so the query methods take
+/// `&mut self`. To use the rest of the library on the part of the maze that has been explored, copy
+/// it out with [`explored()`][Self::explored].
+pub struct InfiniteMaze<Generator: MazeGenerator<Inline2DMaze>> {
+    /// The generator used for each chunk.
+    generator: Generator,
+    /// The number of points along each axis of a chunk.
+    chunk_size: [usize; 2],
+    /// The seed every chunk and border is generated from.
+    seed: u64,
+    /// The chunks generated so far.
+    chunks: HashMap<ChunkPos, Inline2DMaze>,
+}
+
+impl <Generator: MazeGenerator<Inline2DMaze>> InfiniteMaze<Generator> {
+    /// Construct a new infinite maze. No chunks are generated until they are looked at.
+    ///
+    /// # Parameters
+    ///
+    /// * `generator`  --- the generator used to generate each chunk.
+    /// * `chunk_size` --- the number of points along each axis of a chunk.
+    /// * `seed`       --- the seed the whole maze is generated from.
+    ///
+    /// # Panics
+    ///
+    /// If either side of `chunk_size` is zero.
+    #[must_use]
+    pub fn new(generator: Generator, chunk_size: [usize; 2], seed: u64) -> Self {
+        assert!(chunk_size.iter().all(|side| *side > 0), "The chunk size must be non-zero");
+
+        Self { generator, chunk_size, seed, chunks: HashMap::new() }
+    }
+
+    /// Return the generator used for each chunk.
+    #[must_use]
+    pub fn generator(&self) -> &Generator {
+        &self.generator
+    }
+
+    /// Return the number of points along each axis of a chunk.
+    #[must_use]
+    pub fn chunk_size(&self) -> [usize; 2] {
+        self.chunk_size
+    }
+
+    /// Return the seed the whole maze is generated from.
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Return the chunk that contains `pt`.
+    #[must_use]
+    pub fn chunk_of(&self, pt: InfinitePt) -> ChunkPos {
+        [0, 1].map(|axis| pt[axis].div_euclid(self.chunk_size[axis] as i64))
+    }
+
+    /// Return whether `chunk` has been generated.
+    #[must_use]
+    pub fn is_generated(&self, chunk: ChunkPos) -> bool {
+        self.chunks.contains_key(&chunk)
+    }
+
+    /// Return the number of chunks that have been generated.
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Return an iterator over the positions of the chunks that have been generated, in no
+    /// particular order.
+    pub fn generated_chunks(&self) -> impl Iterator<Item = ChunkPos> + '_ {
+        self.chunks.keys().copied()
+    }
+
+    /// Return `chunk`, generating it if it hasn't been already.
+    ///
+    /// The chunk's point `[0, 0]` is the maze's point `chunk * chunk_size`.
+    pub fn chunk(&mut self, chunk: ChunkPos) -> &Inline2DMaze {
+        if self.chunks.contains_key(&chunk) == false {
+            let generated = self.generate_chunk(chunk);
+
+            self.chunks.insert(chunk, generated);
+        }
+
+        return &self.chunks[&chunk]
+    }
+
+    /// Return the value of the cell of `pt`, generating its chunk if necessary.
+    pub fn get(&mut self, pt: InfinitePt) -> InlineCellValue<2> {
+        let chunk = self.chunk_of(pt);
+        let local = self.local_pt(pt);
+
+        return self.chunk(chunk).get(local)
+    }
+
+    /// Return the four points adjacent to `pt`.
+    #[must_use]
+    pub fn neighbours_of_pt(&self, pt: InfinitePt) -> [InfinitePt; 4] {
+        let [x, y] = pt;
+
+        return [[x - 1, y], [x + 1, y], [x, y - 1], [x, y + 1]]
+    }
+
+    /// Return whether there is a passage between two adjacent points, generating their chunks if
+    /// necessary.
+    ///
+    /// # Panics
+    ///
+    /// If `from` and `to` are not adjacent.
+    pub fn is_passage_between(&mut self, from: InfinitePt, to: InfinitePt) -> bool {
+        self.edge_between(from, to) == InlineCellValueEdge::PASSAGE
+    }
+
+    /// Return whether there is a wall between two adjacent points, generating their chunks if
+    /// necessary.
+    ///
+    /// # Panics
+    ///
+    /// If `from` and `to` are not adjacent.
+    pub fn is_wall_between(&mut self, from: InfinitePt, to: InfinitePt) -> bool {
+        self.edge_between(from, to) == InlineCellValueEdge::WALL
+    }
+
+    /// Return the points adjacent to `pt` that there is a passage to, generating chunks as
+    /// necessary.
+    pub fn connected_neighbours(&mut self, pt: InfinitePt) -> Vec<InfinitePt> {
+        self.neighbours_of_pt(pt).into_iter().filter(|neighbour| self.is_passage_between(pt, *neighbour)).collect()
+    }
+
+    /// Copy the part of the maze that has been explored out into an ordinary maze, so that the
+    /// rest of the library can be used on it.
+    ///
+    /// The maze returned covers the smallest rectangle of chunks containing every generated
+    /// chunk. Chunks within it that haven't been generated are left unvisited. Returns the maze,
+    /// along with the point in this maze that its origin corresponds to, or `None` if no chunks
+    /// have been generated.
+    #[must_use]
+    pub fn explored(&self) -> Option<(Inline2DMaze, InfinitePt)> {
+        let first = *self.chunks.keys().next()?;
+
+        let (min, max) = self.chunks.keys().fold((first, first), |(min, max), chunk| {
+            ([0, 1].map(|axis| min[axis].min(chunk[axis])), [0, 1].map(|axis| max[axis].max(chunk[axis])))
+        });
+
+        let dimensions = [0, 1].map(|axis| (max[axis] - min[axis] + 1) as usize * self.chunk_size[axis]);
+
+        let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked(dimensions)).build();
+
+        for (chunk, chunk_maze) in &self.chunks {
+            let offset = [0, 1].map(|axis| (chunk[axis] - min[axis]) as usize * self.chunk_size[axis]);
+
+            for (pt, value) in chunk_maze.iter() {
+                maze.set([pt[0] + offset[0], pt[1] + offset[1]].into(), value);
+            }
+        }
+
+        let origin = [0, 1].map(|axis| min[axis] * self.chunk_size[axis] as i64);
+
+        return Some((maze, origin))
+    }
+
+    /// Return the position of `pt` within its chunk.
+    fn local_pt(&self, pt: InfinitePt) -> CoordinateTuplet<2> {
+        [0, 1].map(|axis| pt[axis].rem_euclid(self.chunk_size[axis] as i64) as usize).into()
+    }
+
+    /// Return the edge of `from`'s cell that faces `to`.
+    fn edge_between(&mut self, from: InfinitePt, to: InfinitePt) -> InlineCellValueEdge {
+        let axis = match [to[0] - from[0], to[1] - from[1]] {
+            [-1 | 1, 0] => 0,
+            [0, -1 | 1] => 1,
+            _ => panic!("{:?} and {:?} are not adjacent", from, to),
+        };
+
+        let side = usize::from(to[axis] > from[axis]);
+
+        return self.get(from).edges[axis][side]
+    }
+
+    /// Generate `chunk` from scratch, including the passages through its borders.
+    fn generate_chunk(&mut self, chunk: ChunkPos) -> Inline2DMaze {
+        let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked(self.chunk_size)).build();
+
+        self.generator.generate_with_rng(&mut maze, &mut PortableRng::seed_from_u64(mix(self.seed, chunk, 0)));
+
+        for axis in 0..2 {
+            let other_axis = 1 - axis;
+
+            // The border before this chunk is the border after the previous one
+            let mut previous = chunk;
+            previous[axis] -= 1;
+
+            for (border, side) in [(previous, 0), (chunk, 1)] {
+                let position = self.border_opening(border, axis);
+
+                let mut pt = [0; 2];
+                pt[axis] = if side == 0 { 0 } else { self.chunk_size[axis] - 1 };
+                pt[other_axis] = position;
+
+                let mut value = maze.get(pt.into());
+                value.edges[axis][side] = InlineCellValueEdge::PASSAGE;
+                maze.set(pt.into(), value);
+            }
+        }
+
+        return maze
+    }
+
+    /// Return where along the border after `chunk` on `axis` the passage through it is.
+    fn border_opening(&self, chunk: ChunkPos, axis: usize) -> usize {
+        gen_index(&mut PortableRng::seed_from_u64(mix(self.seed, chunk, 1 + axis as u64)), self.chunk_size[1 - axis])
+    }
+}
+
+/// Combine a seed, a chunk position, and a tag into a new seed, so that every chunk and border
+/// gets its own stream of random numbers.
+fn mix(seed: u64, chunk: ChunkPos, tag: u64) -> u64 {
+    // SplitMix64's finaliser, applied after folding in each value
+    let finalise = |mut z: u64| {
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+
+    [chunk[0] as u64, chunk[1] as u64, tag].into_iter().fold(finalise(seed), |hash, value| finalise(hash.wrapping_add(0x9E37_79B9_7F4A_7C15) ^ value))
+}
\ No newline at end of file
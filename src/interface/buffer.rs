@@ -6,9 +6,12 @@
 //!
 //! # Recommended Reading
 //! 1. [`MazeBuffer`] -- the buffer trait.
+//! 2. [`SyncMazeBuffer`] -- buffers that can be shared between threads.
 
+use std::borrow::Cow;
 use std::fmt::Debug;
 use std::num::NonZeroUsize;
+use std::ops::Range;
 
 use crate::interface::cell::{CellID, CellValue};
 
@@ -99,7 +102,7 @@ pub trait MazeBuffer<CellVal: CellValue> : Debug + Send {
     /// let mut buffer = VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(1).expect("If this fails the sky is falling"));
     ///
     /// let cell_id = CellID(0);
-    /// let cell = BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, marked: false };
+    /// let cell = BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, marked: false, ..Default::default() };
     ///
     /// buffer.set(cell_id, cell);
     ///
@@ -132,11 +135,146 @@ pub trait MazeBuffer<CellVal: CellValue> : Debug + Send {
     /// let mut buffer = VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(1).expect("If this fails the sky is falling"));
     ///
     /// let cell_id = CellID(0);
-    /// let cell = BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, marked: false };
+    /// let cell = BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, marked: false, ..Default::default() };
     ///
     /// buffer.set(cell_id, cell);
     ///
     /// assert_eq!(cell, buffer.get(cell_id));
     /// ```
     fn set(&mut self, cell: CellID, new_value: CellVal);
+
+    /// Return the number of cells this buffer stores, as given when it was
+    /// [constructed][Self::new].
+    #[must_use]
+    fn cell_count(&self) -> NonZeroUsize;
+
+    /// Get the values of a range of consecutive cells.
+    ///
+    /// Buffers that store their cells contiguously return them without copying. The default
+    /// implementation [gets][Self::get] each cell in turn.
+    ///
+    /// # Parameters
+    /// `cells` --- the IDs of the cells to retrieve. Must be inbounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue};
+    /// # use mazelib::interface::buffer::MazeBuffer;
+    /// # use mazelib::interface::cell::CellID;
+    /// #
+    /// let mut buffer = VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(10).expect("If this fails the sky is falling"));
+    ///
+    /// let wall = BlockCellValue { cell_type: BlockCellPrimaryValue::WALL, ..Default::default() };
+    ///
+    /// buffer.set_range(CellID(2)..CellID(5), wall);
+    ///
+    /// assert_eq!([BlockCellValue::default(), wall, wall, wall, BlockCellValue::default()], *buffer.get_slice(CellID(1)..CellID(6)));
+    /// ```
+    #[must_use]
+    fn get_slice(&self, cells: Range<CellID>) -> Cow<'_, [CellVal]> {
+        Cow::Owned((cells.start.0..cells.end.0).map(|cell| self.get(CellID(cell))).collect())
+    }
+
+    /// Return an iterator over every cell and its value, in order of cell ID.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::num::NonZeroUsize;
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue};
+    /// # use mazelib::interface::buffer::MazeBuffer;
+    /// # use mazelib::interface::cell::CellID;
+    /// #
+    /// let mut buffer = VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(10).expect("If this fails the sky is falling"));
+    ///
+    /// buffer.set_range(CellID(2)..CellID(5), BlockCellValue { cell_type: BlockCellPrimaryValue::WALL, ..Default::default() });
+    ///
+    /// let walls: Vec<CellID> = buffer.iter_cells()
+    ///                                .filter(|(_, value)| value.cell_type == BlockCellPrimaryValue::WALL)
+    ///                                .map(|(cell, _)| cell)
+    ///                                .collect();
+    ///
+    /// assert_eq!(vec![CellID(2), CellID(3), CellID(4)], walls);
+    /// ```
+    #[must_use]
+    fn iter_cells(&self) -> impl Iterator<Item = (CellID, CellVal)> + '_ {
+        (0..usize::from(self.cell_count())).map(move |cell| (CellID(cell), self.get(CellID(cell))))
+    }
+
+    /// Set every cell in a range of consecutive cells to the same value.
+    ///
+    /// The default implementation [sets][Self::set] each cell in turn.
+    ///
+    /// # Parameters
+    /// `cells` --- the IDs of the cells to modify. Must be inbounds.
+    ///
+    /// # See Also
+    ///
+    /// [`get_slice()`][Self::get_slice] for an example.
+    fn set_range(&mut self, cells: Range<CellID>, new_value: CellVal) {
+        for cell in cells.start.0..cells.end.0 {
+            self.set(CellID(cell), new_value);
+        }
+    }
+
+    /// Set every cell in the buffer to the same value.
+    ///
+    /// The default implementation [sets the range][Self::set_range] of every cell.
+    fn fill(&mut self, new_value: CellVal) {
+        self.set_range(CellID(0)..CellID(usize::from(self.cell_count())), new_value)
+    }
+}
+
+/// A [`MazeBuffer`] whose cells can be read and written through a shared reference, so that it
+/// can be used from several threads at once.
+///
+/// This is what allows a maze to be generated in parallel, with multiple workers carving disjoint
+/// regions of the same buffer concurrently. Each individual access is atomic, but there is no
+/// synchronisation between accesses to different cells. Workers must coordinate amongst
+/// themselves to avoid modifying the same cells.
+///
+/// # Examples
+/// ```
+/// # use std::num::NonZeroUsize;
+/// # use mazelib::implm::buffer::SyncBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue};
+/// # use mazelib::interface::buffer::{MazeBuffer, SyncMazeBuffer};
+/// # use mazelib::interface::cell::CellID;
+/// #
+/// let buffer = SyncBuffer::<BlockCellValue>::new(NonZeroUsize::new(100).expect("If this fails the sky is falling"));
+///
+/// std::thread::scope(|scope| {
+///     for half in [0..50, 50..100] {
+///         let buffer = &buffer;
+///
+///         scope.spawn(move || for cell in half {
+///             buffer.update_shared(CellID(cell), |value| value.cell_type = BlockCellPrimaryValue::PASSAGE);
+///         });
+///     }
+/// });
+///
+/// assert_eq!(BlockCellPrimaryValue::PASSAGE, buffer.get_shared(CellID(75)).cell_type);
+/// ```
+pub trait SyncMazeBuffer<CellVal: CellValue> : MazeBuffer<CellVal> + Sync {
+    /// Get the value of a given cell.
+    ///
+    /// *See [`MazeBuffer::get()`].*
+    #[must_use]
+    fn get_shared(&self, cell: CellID) -> CellVal;
+
+    /// Set the value of a given cell.
+    ///
+    /// *See [`MazeBuffer::set()`].*
+    fn set_shared(&self, cell: CellID, new_value: CellVal);
+
+    /// Modify the value of a given cell in place.
+    ///
+    /// No other access to the cell can happen while `update` is running, so this can be used to
+    /// modify part of a cell without losing concurrent changes to the rest of it. `update` must
+    /// not access the buffer itself, or it may deadlock.
+    ///
+    /// *See [`MazeBuffer::get_mut()`].*
+    fn update_shared(&self, cell: CellID, update: impl FnOnce(&mut CellVal));
 }
\ No newline at end of file
@@ -33,6 +33,41 @@ pub trait CellLocation: Sized + Clone + Copy + PartialEq + Eq + Hash + Send + Sy
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct CellID(pub usize);
 
+/// The kind of ground a cell is made of, such as mud, water, or road.
+///
+/// Terrain has no built-in interpretation. What each kind of terrain means (and so its traversal
+/// cost, or the colour it is drawn in) is entirely up to you. The only convention is that
+/// [`Terrain::DEFAULT`] is plain, ordinary ground, which is what all cells start as.
+///
+/// Coordinators never change the terrain of a cell themselves, so it is preserved through
+/// generation. Cell values that don't store a terrain are always [`Terrain::DEFAULT`].
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::interface::cell::Terrain;
+///
+/// const MUD: Terrain = Terrain(1);
+/// const ROAD: Terrain = Terrain(2);
+///
+/// fn traversal_cost(terrain: Terrain) -> u32 {
+///     match terrain {
+///         MUD => 5,
+///         ROAD => 1,
+///         _ => 2,
+///     }
+/// }
+///
+/// assert_eq!(2, traversal_cost(Terrain::DEFAULT));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Terrain(pub u8);
+
+impl Terrain {
+    /// Plain, ordinary ground.
+    pub const DEFAULT: Terrain = Terrain(0);
+}
+
 /// Value of a cell.
 ///
 /// Cells can be marked. Marks are a general-purpose signalling mechanism, and have
@@ -53,6 +88,24 @@ pub trait CellValue: Sized + Clone + Copy + PartialEq + Eq + Hash + Send + Sync
 
     /// Set the cell's mark flag.
     fn set_marked(&mut self, marked: bool);
+
+    /// The cell's [terrain][Terrain].
+    ///
+    /// Defaults to [`Terrain::DEFAULT`] for cell values that don't store a terrain.
+    #[must_use]
+    fn terrain(&self) -> Terrain {
+        Terrain::DEFAULT
+    }
+
+    /// The overall state of the cell, as counted by
+    /// [occupancy statistics][crate::implm::buffer::Occupancy].
+    ///
+    /// Defaults to [`ConnectionType::PASSAGE`] for fully-visited cells and
+    /// [`ConnectionType::UNVISITED`] for all others.
+    #[must_use]
+    fn state(&self) -> ConnectionType {
+        if self.is_fully_visited() { ConnectionType::PASSAGE } else { ConnectionType::UNVISITED }
+    }
 }
 
 /// Type of connection (graph theory: *edge*) (e.g. wall, passage) between two points.
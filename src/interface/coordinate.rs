@@ -9,10 +9,14 @@
 //! 1. [`MazeCoordinator`] --- the primary maze trait, responsible for coordinating all of a maze's
 //!                            components.
 
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
 
+use crate::error::MazeError;
 use crate::interface::cell::{CellLocation, CellValue, ConnectionType};
 use crate::interface::point::CoordinateSpace;
+use crate::mark::{Annotations, Marks, NamedPoints, Portals};
+use crate::path::Path;
 use crate::pt;
 
 /// Handles high-level queries on mazes.
@@ -28,6 +32,16 @@ use crate::pt;
 ///
 /// Each coordinator has different characteristics that dramatically affect the maze's structure
 /// so you should consult your chosen implementation carefully.
+///
+/// # Buffers
+///
+/// Coordinators that own a [buffer][crate::interface::buffer::MazeBuffer] expose it through
+/// `buffer()` and `buffer_mut()`. Mutable access is for buffers with controls of their own, such
+/// as [`UndoBuffer`][crate::implm::buffer::UndoBuffer]'s transactions.
+///
+/// Cells written directly through the buffer bypass the coordinator. Nothing checks that they
+/// still agree with each other --- for example, that both cells either side of an edge record the
+/// same connection --- so it's up to the caller to keep the maze consistent.
 pub trait MazeCoordinator: Debug {
     /*
      * Note: In the source, `<<Self as MazeCoordinator>::CoordSpace as CoordinateSpace>::PtType` is
@@ -47,10 +61,228 @@ pub trait MazeCoordinator: Debug {
     #[must_use]
     fn coord_space(&self) -> &Self::CoordSpace;
 
+    /// Return the maze's [marks][Marks]: its named points, annotations, and portals.
+    ///
+    /// The accessors for each part, such as [`named_points()`][Self::named_points], go through
+    /// this.
+    #[must_use]
+    fn marks(&self) -> &Marks<pt!()>;
+
+    /// Return the maze's [marks][Marks] mutably.
+    #[must_use]
+    fn marks_mut(&mut self) -> &mut Marks<pt!()>;
+
+    /// Return the maze's [named points][NamedPoints], such as its start and goal.
+    #[must_use]
+    fn named_points(&self) -> &NamedPoints<pt!()> {
+        &self.marks().named_points
+    }
+
+    /// Return the maze's [named points][NamedPoints] mutably.
+    #[must_use]
+    fn named_points_mut(&mut self) -> &mut NamedPoints<pt!()> {
+        &mut self.marks_mut().named_points
+    }
+
+    /// Return the maze's [annotations][Annotations], such as its doors and keys.
+    #[must_use]
+    fn annotations(&self) -> &Annotations<pt!()> {
+        &self.marks().annotations
+    }
+
+    /// Return the maze's [annotations][Annotations] mutably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// use mazelib::mark::Annotation;
+    ///
+    /// #[derive(Clone, Eq, PartialEq, Debug)]
+    /// struct Key { colour: &'static str }
+    ///
+    /// impl Annotation for Key {}
+    ///
+    /// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([5, 5])).build();
+    ///
+    /// maze.annotations_mut().insert([2, 3].into(), Key { colour: "red" });
+    ///
+    /// assert_eq!(Some(&Key { colour: "red" }), maze.annotations().get([2, 3].into()));
+    /// ```
+    #[must_use]
+    fn annotations_mut(&mut self) -> &mut Annotations<pt!()> {
+        &mut self.marks_mut().annotations
+    }
+
+    /// Return the maze's [portals][Portals], such as its teleporters.
+    #[must_use]
+    fn portals(&self) -> &Portals<pt!()> {
+        &self.marks().portals
+    }
+
+    /// Return the maze's [portals][Portals] mutably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::inline::InlineCellValue;
+    /// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+    /// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// #
+    /// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([5, 5])).build();
+    ///
+    /// maze.portals_mut().insert([0, 0].into(), [4, 4].into());
+    ///
+    /// assert!(maze.is_passage_between([0, 0].into(), [4, 4].into()));
+    /// assert_eq!(vec![CoordinateTuplet::from([1, 0]), [0, 1].into(), [4, 4].into()], maze.neighbours_of_pt([0, 0].into()));
+    /// ```
+    #[must_use]
+    fn portals_mut(&mut self) -> &mut Portals<pt!()> {
+        &mut self.marks_mut().portals
+    }
+
+    /// Return the maze's start point, if it has one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([5, 5])).build();
+    ///
+    /// assert_eq!(None, maze.start());
+    ///
+    /// maze.set_start([0, 0].into());
+    /// maze.set_goal([4, 4].into());
+    ///
+    /// assert_eq!(Some([0, 0].into()), maze.start());
+    /// assert_eq!(Some([4, 4].into()), maze.goal());
+    /// ```
+    #[must_use]
+    fn start(&self) -> Option<pt!()> {
+        self.named_points().start()
+    }
+
+    /// Set the maze's start point, returning the previous one.
+    fn set_start(&mut self, pt: pt!()) -> Option<pt!()> {
+        self.named_points_mut().set_start(pt)
+    }
+
+    /// Return the maze's goal point, if it has one.
+    #[must_use]
+    fn goal(&self) -> Option<pt!()> {
+        self.named_points().goal()
+    }
+
+    /// Set the maze's goal point, returning the previous one.
+    fn set_goal(&mut self, pt: pt!()) -> Option<pt!()> {
+        self.named_points_mut().set_goal(pt)
+    }
+
     /// Return the value of the point `pt`.
     #[must_use]
     fn get(&self, pt: pt!()) -> Self::CellVal;
 
+    /// Return an iterator over every point and its value.
+    ///
+    /// Points are visited in the order of [`CoordinateSpace::iter()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// #
+    /// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+    ///
+    /// maze.make_passage([1, 2].into());
+    ///
+    /// let passages: Vec<_> = maze.iter()
+    ///                            .filter(|(_, value)| value.cell_type == BlockCellPrimaryValue::PASSAGE)
+    ///                            .map(|(pt, _)| pt)
+    ///                            .collect();
+    ///
+    /// assert_eq!(vec![CoordinateTuplet::from([1, 2])], passages);
+    /// ```
+    #[must_use]
+    fn iter(&self) -> impl Iterator<Item = (pt!(), Self::CellVal)> + '_ {
+        self.coord_space().iter().map(move |pt| (pt, self.get(pt)))
+    }
+
+    /// Return an iterator over every point whose value satisfies `predicate`, along with its value.
+    ///
+    /// Points are visited in the order of [`CoordinateSpace::iter()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::inline::InlineCellValue;
+    /// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+    /// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+    /// # use mazelib::interface::cell::CellValue;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// #
+    /// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+    ///
+    /// maze.make_passage_between([0, 0].into(), [1, 0].into());
+    ///
+    /// let visited: Vec<_> = maze.iter_where(|value| value.is_fully_visited())
+    ///                           .map(|(pt, _)| pt)
+    ///                           .collect();
+    ///
+    /// assert_eq!(vec![CoordinateTuplet::from([0, 0]), CoordinateTuplet::from([1, 0])], visited);
+    /// ```
+    #[must_use]
+    fn iter_where<'a>(&'a self, mut predicate: impl FnMut(&Self::CellVal) -> bool + 'a) -> impl Iterator<Item = (pt!(), Self::CellVal)> + 'a {
+        self.iter().filter(move |(_, value)| predicate(value))
+    }
+
+    /// Return an iterator over every point whose value is [marked][CellValue::is_marked], along
+    /// with its value.
+    ///
+    /// This is for passes that follow a solver or analyser which flags the cells it's interested
+    /// in. Points are visited in the order of [`CoordinateSpace::iter()`].
+    ///
+    /// # See Also
+    ///
+    /// [`iter_where()`][Self::iter_where] --- for any other property of the cells.
+    #[must_use]
+    fn iter_marked(&self) -> impl Iterator<Item = (pt!(), Self::CellVal)> + '_ {
+        self.iter_where(CellValue::is_marked)
+    }
+
+    /// Return every point that can be reached from `pt` in a single step: its
+    /// [neighbours][CoordinateSpace::neighbours_of_pt] in the coordinate space, followed by the
+    /// destinations of its [portals][Self::portals].
+    ///
+    /// Use this rather than the coordinate space's neighbours when walking a maze, so that portals
+    /// are honoured.
+    #[must_use]
+    fn neighbours_of_pt(&self, pt: pt!()) -> Vec<pt!()> {
+        let mut neighbours = self.coord_space().neighbours_of_pt(pt);
+
+        for destination in self.portals().destinations(pt) {
+            if neighbours.contains(destination) == false {
+                neighbours.push(*destination);
+            }
+        }
+
+        return neighbours
+    }
+
     /// Return the type of connection (graph theory: *edge*) between two points.
     ///
     /// If you attempted to walk from `from` to `to` this is what you would encounter.
@@ -59,9 +291,30 @@ pub trait MazeCoordinator: Debug {
     /// the arguments may produce different results.
     ///
     /// This method follows the
+    /// [priority order outlined in `ConnectionType`][ConnectionType#priority], except that a
+    /// [portal][Self::portals] from `from` to `to` is always a [`ConnectionType::PASSAGE`].
+    ///
+    /// `to` must be one of [`self.neighbours_of_pt(from)`][Self::neighbours_of_pt]. That is, the
+    /// points must either be adjacent or have a portal between them.
+    #[must_use]
+    fn get_connection(&self, from: pt!(), to: pt!()) -> ConnectionType {
+        if self.portals().contains(from, to) {
+            return ConnectionType::PASSAGE
+        }
+
+        return self.get_adjacent_connection(from, to)
+    }
+
+    /// Return the type of connection between two adjacent points, ignoring
+    /// [portals][Self::portals].
+    ///
+    /// Implementors provide this, and [`get_connection()`][Self::get_connection] adds the portals
+    /// on top. Use [`get_connection()`][Self::get_connection] instead when querying a maze.
+    ///
+    /// This method follows the
     /// [priority order outlined in `ConnectionType`][ConnectionType#priority].
     #[must_use]
-    fn get_connection(&self, from: pt!(), to: pt!()) -> ConnectionType;
+    fn get_adjacent_connection(&self, from: pt!(), to: pt!()) -> ConnectionType;
 
     /// Return true when [`get_connection(from, to)`][Self::get_connection] returns
     /// [`ConnectionType::PASSAGE`].
@@ -91,6 +344,49 @@ pub trait MazeCoordinator: Debug {
         self.get_connection(from, to) == ConnectionType::UNVISITED
     }
 
+    /// Return an iterator over the connection between every pair of adjacent points.
+    ///
+    /// Each item is `(from, to, connection)`, where `connection` is
+    /// [`get_connection(from, to)`][Self::get_connection]. As connections are directional, every
+    /// pair of adjacent points is yielded twice, once in each direction. Points are visited in the
+    /// order of [`CoordinateSpace::iter()`], and their neighbours in the order of
+    /// [`CoordinateSpace::neighbours_of_pt()`].
+    ///
+    /// Each point's [portals][Self::portals] follow its neighbours, as
+    /// [`ConnectionType::PASSAGE`]s. A portal between two adjacent points makes their connection a
+    /// passage rather than being yielded as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::inline::InlineCellValue;
+    /// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::cell::ConnectionType;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// #
+    /// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([2, 2])).build();
+    ///
+    /// maze.make_passage_between([0, 0].into(), [1, 0].into());
+    ///
+    /// // A 2x2 maze has four edges, each seen from both sides
+    /// assert_eq!(8, maze.connections().count());
+    ///
+    /// let passages: Vec<_> = maze.connections()
+    ///                            .filter(|(_, _, connection)| *connection == ConnectionType::PASSAGE)
+    ///                            .map(|(from, to, _)| (from, to))
+    ///                            .collect();
+    ///
+    /// assert_eq!(vec![([0, 0].into(), [1, 0].into()), ([1, 0].into(), [0, 0].into())], passages);
+    /// ```
+    #[must_use]
+    fn connections(&self) -> impl Iterator<Item = (pt!(), pt!(), ConnectionType)> + '_ {
+        self.coord_space().iter().flat_map(move |from| {
+            self.neighbours_of_pt(from).into_iter().map(move |to| (from, to, self.get_connection(from, to)))
+        })
+    }
+
     /// Make a passage at `pt`.
     fn make_passage(&mut self, pt: pt!());
 
@@ -100,8 +396,85 @@ pub trait MazeCoordinator: Debug {
     ///
     /// The order of the arguments is important and has semantic meaning. Swapping
     /// the arguments may produce different results.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::try_make_passage_between()`], which checks that the points are adjacent.
     fn make_passage_between(&mut self, from: pt!(), to: pt!());
 
+    /// Make a passage from `from` to `to` if they are adjacent.
+    ///
+    /// # Errors
+    ///
+    /// [`MazeError::NotAdjacent`] if the points are not adjacent. The maze is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::error::MazeError;
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// #
+    /// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+    ///
+    /// assert_eq!(Ok(()), maze.try_make_passage_between([0, 0].into(), [1, 0].into()));
+    /// assert!(matches!(maze.try_make_passage_between([0, 0].into(), [2, 2].into()), Err(MazeError::NotAdjacent { .. })));
+    /// ```
+    fn try_make_passage_between(&mut self, from: pt!(), to: pt!()) -> Result<(), MazeError> {
+        check_adjacent(self.coord_space(), from, to)?;
+
+        self.make_passage_between(from, to);
+
+        return Ok(())
+    }
+
+    /// Carve a corridor of passages from `from` to `to`, which needn't be adjacent, returning the
+    /// route it takes.
+    ///
+    /// The route is the shortest one between the points that doesn't cross a boundary. It runs
+    /// straight through walls, unvisited territory, and existing passages alike, and every
+    /// connection along it is made a passage with
+    /// [`make_passage_between()`][Self::make_passage_between]. [Portals][Self::portals] aren't
+    /// taken, as there's nothing to carve through them. This is handy for joining up hand-placed
+    /// rooms, or regions that were generated separately.
+    ///
+    /// Returns `None` if `to` can't be reached from `from` without crossing a boundary, in which
+    /// case the maze is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::block::BlockCellValue;
+    /// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// #
+    /// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([5, 5])).build();
+    ///
+    /// let route = maze.carve_corridor([0, 0].into(), [3, 2].into()).unwrap();
+    ///
+    /// // Three steps across and two down
+    /// assert_eq!(5, route.len());
+    /// assert!(maze.is_passage_between([0, 0].into(), [1, 0].into()));
+    /// ```
+    fn carve_corridor(&mut self, from: pt!(), to: pt!()) -> Option<Path<pt!()>> {
+        let route = find_corridor(self, from, to)?;
+
+        if let [pt] = route.as_slice() {
+            self.make_passage(*pt);
+        }
+
+        for step in route.windows(2) {
+            self.make_passage_between(step[0], step[1]);
+        }
+
+        return Some(Path::from_vec(route))
+    }
+
     /// Make a wall at `pt`.
     fn make_wall(&mut self, pt: pt!());
 
@@ -111,8 +484,25 @@ pub trait MazeCoordinator: Debug {
     ///
     /// The order of the arguments is important and has semantic meaning. Swapping
     /// the arguments may produce different results.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::try_make_wall_between()`], which checks that the points are adjacent.
     fn make_wall_between(&mut self, from: pt!(), to: pt!());
 
+    /// Make a wall from `from` to `to` if they are adjacent.
+    ///
+    /// # Errors
+    ///
+    /// [`MazeError::NotAdjacent`] if the points are not adjacent. The maze is left untouched.
+    fn try_make_wall_between(&mut self, from: pt!(), to: pt!()) -> Result<(), MazeError> {
+        check_adjacent(self.coord_space(), from, to)?;
+
+        self.make_wall_between(from, to);
+
+        return Ok(())
+    }
+
     /// Make a boundary at `pt`.
     fn make_boundary(&mut self, pt: pt!());
 
@@ -122,5 +512,65 @@ pub trait MazeCoordinator: Debug {
     ///
     /// The order of the arguments is important and has semantic meaning. Swapping
     /// the arguments may produce different results.
+    ///
+    /// # See Also
+    ///
+    /// [`Self::try_make_boundary_between()`], which checks that the points are adjacent.
     fn make_boundary_between(&mut self, from: pt!(), to: pt!());
+
+    /// Make a boundary from `from` to `to` if they are adjacent.
+    ///
+    /// # Errors
+    ///
+    /// [`MazeError::NotAdjacent`] if the points are not adjacent. The maze is left untouched.
+    fn try_make_boundary_between(&mut self, from: pt!(), to: pt!()) -> Result<(), MazeError> {
+        check_adjacent(self.coord_space(), from, to)?;
+
+        self.make_boundary_between(from, to);
+
+        return Ok(())
+    }
+}
+
+/// Return the shortest route from `from` to `to` that doesn't cross a boundary, or `None` if there
+/// isn't one.
+fn find_corridor<M: MazeCoordinator + ?Sized>(maze: &M, from: <M::CoordSpace as CoordinateSpace>::PtType, to: <M::CoordSpace as CoordinateSpace>::PtType) -> Option<Vec<<M::CoordSpace as CoordinateSpace>::PtType>> {
+    // The point each point was first reached from
+    let mut previous = HashMap::from([(from, from)]);
+    let mut queue = VecDeque::from([from]);
+
+    while let Some(pt) = queue.pop_front() {
+        if pt == to {
+            let mut route = vec![to];
+            let mut pt = to;
+
+            // Walk back to the start
+            while pt != from {
+                pt = previous[&pt];
+                route.push(pt);
+            }
+
+            route.reverse();
+
+            return Some(route)
+        }
+
+        for neighbour in maze.coord_space().neighbours_of_pt(pt) {
+            if previous.contains_key(&neighbour) == false && maze.is_boundary_between(pt, neighbour) == false {
+                previous.insert(neighbour, pt);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    return None
+}
+
+/// Return [`MazeError::NotAdjacent`] if `from` and `to` are not adjacent in `space`.
+fn check_adjacent<Space: CoordinateSpace>(space: &Space, from: Space::PtType, to: Space::PtType) -> Result<(), MazeError> {
+    if space.are_adjacent(from, to) == false {
+        return Err(MazeError::NotAdjacent { from: format!("{:?}", from), to: format!("{:?}", to) });
+    }
+
+    return Ok(())
 }
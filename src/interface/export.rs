@@ -2,9 +2,10 @@
 //!
 //! # Recommended Reading
 //! 1. [`MazeExporter`] --- the exporter interface.
+//! 2. [`ExportToVec`] --- exporting into memory.
 
 use std::io;
-use std::io::Write;
+use std::io::{Cursor, Write};
 
 use crate::interface::coordinate::MazeCoordinator;
 
@@ -155,4 +156,46 @@ impl <M: MazeCoordinator, O: Write, T: MazeExporter<M, O> + Default> DefaultMaze
     fn export(maze: &M, output: &mut O) -> io::Result<()> {
         Self::default().export(maze, output)
     }
+}
+
+/// Simple sugar for exporting a maze into memory.
+///
+/// Implemented for every [`MazeExporter`] that can write to a [`Cursor<Vec<u8>>`], which is
+/// every exporter in this library. Saves setting up the output yourself. For text, see also
+/// [`ExportToString`][crate::implm::export::text::ExportToString].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// use mazelib::implm::export::binary::BoxSpaceBinaryMazeExporter;
+/// use mazelib::interface::export::ExportToVec;
+///
+/// let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+///
+/// let bytes = BoxSpaceBinaryMazeExporter::new().export_to_vec(&maze).unwrap();
+///
+/// assert!(bytes.is_empty() == false);
+/// ```
+pub trait ExportToVec<M: MazeCoordinator> {
+    /// Export the maze `maze` to a new `Vec`.
+    ///
+    /// Returns an error if the exporter encountered one. Nothing can go wrong writing to memory,
+    /// but exporters may still report errors of their own.
+    ///
+    /// *See [`MazeExporter::export()`].*
+    fn export_to_vec(&self, maze: &M) -> io::Result<Vec<u8>>;
+}
+
+impl <M: MazeCoordinator, T: MazeExporter<M, Cursor<Vec<u8>>> + ?Sized> ExportToVec<M> for T {
+    fn export_to_vec(&self, maze: &M) -> io::Result<Vec<u8>> {
+        let mut output = Cursor::new(Vec::new());
+
+        self.export(maze, &mut output)?;
+
+        return Ok(output.into_inner())
+    }
 }
\ No newline at end of file
@@ -8,6 +8,15 @@
 //!
 //! 1. [`MazeGenerator`] --- the generator interface.
 //! 2. [`crate::implm::generate`] --- a comparison of the built-in generators.
+//!
+//! # Sources of Randomness
+//!
+//! [`MazeGenerator::generate()`] draws from the crate's [default RNG][crate::rng::default_rng],
+//! which is seeded by the operating system. Some targets, like `wasm32-unknown-unknown`, have no
+//! such source. To build for them, disable the default `thread-rng` feature and use
+//! [`generate_with_rng()`][MazeGenerator::generate_with_rng] with an RNG you seed yourself.
+//! The [`wasm`][crate::wasm] facade is built this way. Any
+//! [`rand::RngCore`] will do. See [`crate::rng`].
 
 use rand::Rng;
 
@@ -35,8 +44,9 @@ pub trait MazeGenerator<M: MazeCoordinator> {
     ///              cells and work them into its generation. Any
     ///              <abbr title="A connection between points">edge</abbr> that is not
     ///              a boundary may be overwritten as part of the generation process.
+    #[cfg(feature = "thread-rng")]
     fn generate(&mut self, maze: &mut M) {
-        self.generate_with_rng(maze, &mut rand::thread_rng());
+        self.generate_with_rng(maze, &mut crate::rng::default_rng());
     }
 
     /// Generate a maze using a given random number generator.
@@ -86,6 +96,7 @@ pub trait MazeGenerator<M: MazeCoordinator> {
 /// ```
 pub trait DefaultMazeGenerator<M: MazeCoordinator>: MazeGenerator<M> {
     /// *See [`MazeGenerator::generate()`].*
+    #[cfg(feature = "thread-rng")]
     fn generate(maze: &mut M);
 
     /// *See [`MazeGenerator::generate_with_rng()`].*
@@ -93,6 +104,7 @@ pub trait DefaultMazeGenerator<M: MazeCoordinator>: MazeGenerator<M> {
 }
 
 impl <M: MazeCoordinator, T: MazeGenerator<M> + Default> DefaultMazeGenerator<M> for T {
+    #[cfg(feature = "thread-rng")]
     fn generate(maze: &mut M) {
         Self::default().generate(maze)
     }
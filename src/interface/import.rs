@@ -0,0 +1,57 @@
+//! Importing mazes from other formats.
+//!
+//! # Recommended Reading
+//! 1. [`MazeImporter`] --- the importer interface.
+//! 2. [`MazeExporter`][crate::interface::export::MazeExporter] --- the inverse.
+
+use std::io;
+use std::io::Read;
+
+use crate::interface::coordinate::MazeCoordinator;
+
+/// Import a maze from another, usually persistent, format.
+///
+/// This is the inverse of a [`MazeExporter`][crate::interface::export::MazeExporter]. Where an
+/// exporter takes a maze and writes it out, an importer reads a maze in and constructs a new
+/// [maze coordinator][crate::interface::coordinate::MazeCoordinator] for it.
+///
+/// As with exporters, what an importer accepts is completely up to it, so make sure to consult
+/// your chosen implementation's documentation. Not every format carries enough information to
+/// reconstruct a maze exactly. For example, a text rendering doesn't record the maze's scale
+/// factors. In such cases the importer will document what it infers and what it assumes.
+///
+/// # Errors
+///
+/// Importers report all failures as [`io::Error`]s. Errors from the underlying reader are passed
+/// through unchanged. Malformed input is reported with the kind
+/// [`InvalidData`][io::ErrorKind::InvalidData].
+pub trait MazeImporter<M: MazeCoordinator, I: Read> {
+    /// Import a maze from reader `input`.
+    ///
+    /// The format the maze is read from (and thus the type of data that is expected from
+    /// `input`) is defined by and dependent on the implementation.
+    ///
+    /// Returns an IO error if one was encountered while reading from `input`, or if the data read
+    /// does not describe a valid maze.
+    ///
+    /// # Usage Notes
+    ///
+    /// You may wish to wrap `input` in a [`BufReader`][std::io::BufReader] if it not already
+    /// buffered.
+    fn import(&self, input: &mut I) -> io::Result<M>;
+}
+
+/// Simple sugar for [`MazeImporter`]s.
+///
+/// Lets you elide constructing importers when they implement [`Default`].
+pub trait DefaultMazeImporter<M: MazeCoordinator, I: Read>: MazeImporter<M, I> {
+    /// *See [`MazeImporter::import()`].*
+    fn import(input: &mut I) -> io::Result<M>;
+}
+
+impl <M: MazeCoordinator, I: Read, T: MazeImporter<M, I> + Default> DefaultMazeImporter<M, I> for T {
+    fn import(input: &mut I) -> io::Result<M> {
+        Self::default().import(input)
+    }
+}
+
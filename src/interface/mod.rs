@@ -196,6 +196,13 @@
 //! exporters though are only capable with dealing with with a small number of maze coordinators
 //! due to restrictions inherent in the format. You're not exporting a 3D maze to a 2D image after
 //! all. Exporters are represented by the [`MazeExporter`][export::MazeExporter] trait.
+//!
+//! ## Importer
+//!
+//! Importers are the inverse of exporters. They read a maze back in from some format and construct
+//! a maze coordinator for it, so that mazes can be round-tripped, or so that mazes from elsewhere
+//! (say, drawn by hand) can be solved and analysed. Importers are represented by the
+//! [`MazeImporter`][import::MazeImporter] trait.
 #![doc = embed_doc_image::embed_image!("example-maze-unannotated", "src/doc/img/maze-model/example-maze-unannotated.png")]
 #![doc = embed_doc_image::embed_image!("example-maze-cell-outlines", "src/doc/img/maze-model/example-maze-cell-outlines.png")]
 #![doc = embed_doc_image::embed_image!("example-maze-cell-outlines-with-annotated-ids", "src/doc/img/maze-model/example-maze-cell-outlines-with-annotated-ids.png")]
@@ -207,4 +214,5 @@ pub mod cell;
 pub mod point;
 pub mod coordinate;
 pub mod export;
+pub mod import;
 pub mod generate;
\ No newline at end of file
@@ -116,6 +116,37 @@ pub trait CoordinateSpace : Sized + Clone + Copy + Send + Sync + Debug {
     #[must_use]
     fn neighbours_of_pt(&self, pt: Self::PtType) -> Vec<Self::PtType>;
 
+    /// Add every point that is adjacent to `pt` to `neighbours`, in the same order as
+    /// [`neighbours_of_pt()`][Self::neighbours_of_pt].
+    ///
+    /// Unlike `neighbours_of_pt()`, this doesn't allocate if the coordinate space supports it, so
+    /// it is better suited to hot loops such as generators. Clear and reuse the same buffer for
+    /// each point.
+    ///
+    /// The default implementation simply extends `neighbours` with the result of
+    /// `neighbours_of_pt()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, CoordinatePair};
+    /// # use mazelib::interface::point::CoordinateSpace;
+    /// #
+    /// let space = BoxCoordinateSpace::new_checked([3, 3]);
+    ///
+    /// let mut neighbours = Vec::new();
+    ///
+    /// for pt in space.iter() {
+    ///     neighbours.clear();
+    ///     space.neighbours_into(pt, &mut neighbours);
+    ///
+    ///     assert_eq!(space.neighbours_of_pt(pt), neighbours);
+    /// }
+    /// ```
+    fn neighbours_into(&self, pt: Self::PtType, neighbours: &mut impl Extend<Self::PtType>) {
+        neighbours.extend(self.neighbours_of_pt(pt))
+    }
+
     /// Return whether two points are adjacent in this coordinate space.
     ///
     /// A point is **not** adjacent to itself.
@@ -1,5 +1,9 @@
+use std::collections::{HashMap, VecDeque};
 use std::num::{NonZeroUsize, TryFromIntError};
 
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
 #[cfg(windows)]
 #[must_use]
 pub fn get_line_sep() -> &'static str {
@@ -38,3 +42,47 @@ pub fn offset_usize(mut value: usize, offset: isize) -> usize {
 
     return value
 }
+
+
+/// Construct the error importers return when their input is malformed.
+#[must_use]
+pub fn invalid_data(message: impl Into<String>) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.into())
+}
+
+/// Return the number of neighbours of `pt` it has a passage to.
+#[must_use]
+pub fn exit_count<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M, pt: Space::PtType) -> usize {
+    maze.neighbours_of_pt(pt).into_iter().filter(|neighbour| maze.is_passage_between(pt, *neighbour)).count()
+}
+
+/// Return the points along the shortest path from `start` to `goal` by passage, including both
+/// ends, or `None` if there is no such path.
+#[must_use]
+pub fn shortest_path<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M, start: Space::PtType, goal: Space::PtType) -> Option<Vec<Space::PtType>> {
+    let mut previous = HashMap::from([(start, start)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(pt) = queue.pop_front() {
+        if pt == goal {
+            let mut path = vec![goal];
+
+            while *path.last().expect("[Bug] The path is never empty") != start {
+                path.push(previous[path.last().expect("[Bug] The path is never empty")]);
+            }
+
+            path.reverse();
+
+            return Some(path)
+        }
+
+        for neighbour in maze.neighbours_of_pt(pt) {
+            if maze.is_passage_between(pt, neighbour) && previous.contains_key(&neighbour) == false {
+                previous.insert(neighbour, pt);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    return None
+}
\ No newline at end of file
@@ -0,0 +1,859 @@
+//! Annotating points in mazes.
+//!
+//! Every [cell value][crate::interface::cell::CellValue] has a single mark flag. This is handy for
+//! quick one-off signals, but since there is only one, two components that both use it will get
+//! in each other's way. Mark layers are kept outside the maze instead, so you can have as many as
+//! you like, and they can hold any data you want (such as distances, region IDs, or colours).
+//!
+//! Layers are keyed by point rather than by [cell][crate::interface::cell::CellID], as points are
+//! the same for every coordinator of a given coordinate space.
+//!
+//! # Recommended Reading
+//!
+//! * [`MarkLayer`] --- a single layer of marks.
+//! * [`MarkLayers`] --- a collection of named layers.
+//! * [`NamedPoints`] --- points of interest, such as the start and goal, stored on the maze itself.
+//! * [`Annotations`] --- gameplay objects, such as doors and keys, stored on the maze itself.
+//! * [`Portals`] --- connections between points that aren't adjacent, stored on the maze itself.
+//! * [`Marks`] --- a maze's named points, annotations, and portals together.
+
+use std::any::{Any, TypeId};
+use std::collections::hash_map::{Entry, HashMap};
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::{CoordinateSpace, Point};
+
+/// A single layer of marks, which attaches a value of type `T` to some of the points of a maze.
+///
+/// If you just want to flag points, use `()` for `T`.
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::mark::MarkLayer;
+/// use mazelib::implm::point::boxy::CoordinateTuplet;
+///
+/// let mut distances = MarkLayer::<CoordinateTuplet<2>, usize>::new();
+///
+/// distances.set([0, 0].into(), 0);
+/// distances.set([1, 0].into(), 1);
+///
+/// assert_eq!(Some(&1), distances.get([1, 0].into()));
+/// assert_eq!(None, distances.get([2, 0].into()));
+/// assert_eq!(2, distances.len());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct MarkLayer<Pt: Point, T> {
+    marks: HashMap<Pt, T>,
+}
+
+impl <Pt: Point, T> MarkLayer<Pt, T> {
+    /// Construct a new, empty layer.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { marks: HashMap::new() }
+    }
+
+    /// Return the value attached to `pt`, if it is marked.
+    #[must_use]
+    pub fn get(&self, pt: Pt) -> Option<&T> {
+        self.marks.get(&pt)
+    }
+
+    /// Return the value attached to `pt` mutably, if it is marked.
+    #[must_use]
+    pub fn get_mut(&mut self, pt: Pt) -> Option<&mut T> {
+        self.marks.get_mut(&pt)
+    }
+
+    /// Mark `pt` with `value`, returning the value it was previously marked with.
+    pub fn set(&mut self, pt: Pt, value: T) -> Option<T> {
+        self.marks.insert(pt, value)
+    }
+
+    /// Unmark `pt`, returning the value it was marked with.
+    pub fn remove(&mut self, pt: Pt) -> Option<T> {
+        self.marks.remove(&pt)
+    }
+
+    /// Return whether `pt` is marked.
+    #[must_use]
+    pub fn is_marked(&self, pt: Pt) -> bool {
+        self.marks.contains_key(&pt)
+    }
+
+    /// Return the number of marked points.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.marks.len()
+    }
+
+    /// Return whether no points are marked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.marks.is_empty()
+    }
+
+    /// Unmark every point.
+    pub fn clear(&mut self) {
+        self.marks.clear()
+    }
+
+    /// Return an iterator over every marked point and its value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pt, &T)> {
+        self.marks.iter().map(|(pt, value)| (*pt, value))
+    }
+}
+
+impl <Pt: Point> MarkLayer<Pt, ()> {
+    /// Mark `pt`.
+    pub fn mark(&mut self, pt: Pt) {
+        self.marks.insert(pt, ());
+    }
+
+    /// Construct a layer that marks every point of `maze` whose cell is
+    /// [marked][crate::interface::cell::CellValue::is_marked].
+    #[must_use]
+    pub fn from_marked_cells<M: MazeCoordinator>(maze: &M) -> Self where M::CoordSpace: CoordinateSpace<PtType = Pt> {
+        Self { marks: maze.iter_marked().map(|(pt, _)| (pt, ())).collect() }
+    }
+}
+
+impl <Pt: Point, T> Default for MarkLayer<Pt, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <Pt: Point, T> FromIterator<(Pt, T)> for MarkLayer<Pt, T> {
+    fn from_iter<I: IntoIterator<Item = (Pt, T)>>(iter: I) -> Self {
+        Self { marks: iter.into_iter().collect() }
+    }
+}
+
+impl <Pt: Point, T: Debug> Debug for MarkLayer<Pt, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.marks.iter()).finish()
+    }
+}
+
+/// A collection of named [mark layers][MarkLayer], all holding the same type of value.
+///
+/// Give each component that needs to annotate a maze its own layer (e.g. `"solution"`,
+/// `"dead ends"`) and they won't clobber each other's marks.
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::mark::MarkLayers;
+/// use mazelib::implm::point::boxy::CoordinateTuplet;
+///
+/// let mut layers = MarkLayers::<CoordinateTuplet<2>, ()>::new();
+///
+/// layers.layer_mut("solution").mark([0, 0].into());
+/// layers.layer_mut("dead ends").mark([0, 0].into());
+/// layers.layer_mut("dead ends").mark([3, 1].into());
+///
+/// assert_eq!(2, layers.layer("dead ends").unwrap().len());
+/// assert!(layers.layer("visited").is_none());
+///
+/// let mut names: Vec<&str> = layers.layers_marking([0, 0].into()).map(|(name, _)| name).collect();
+/// names.sort();
+/// assert_eq!(vec!["dead ends", "solution"], names);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct MarkLayers<Pt: Point, T> {
+    layers: HashMap<String, MarkLayer<Pt, T>>,
+}
+
+impl <Pt: Point, T> MarkLayers<Pt, T> {
+    /// Construct a new collection with no layers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { layers: HashMap::new() }
+    }
+
+    /// Return the layer called `name`, if it exists.
+    #[must_use]
+    pub fn layer(&self, name: &str) -> Option<&MarkLayer<Pt, T>> {
+        self.layers.get(name)
+    }
+
+    /// Return the layer called `name` mutably, creating an empty one if it doesn't exist.
+    #[must_use]
+    pub fn layer_mut(&mut self, name: &str) -> &mut MarkLayer<Pt, T> {
+        match self.layers.entry(name.to_owned()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(MarkLayer::new()),
+        }
+    }
+
+    /// Remove the layer called `name`, returning it if it existed.
+    pub fn remove_layer(&mut self, name: &str) -> Option<MarkLayer<Pt, T>> {
+        self.layers.remove(name)
+    }
+
+    /// Return an iterator over the names of every layer, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.layers.keys().map(String::as_str)
+    }
+
+    /// Return an iterator over the name of every layer that marks `pt`, along with the value it
+    /// marks it with, in no particular order.
+    pub fn layers_marking(&self, pt: Pt) -> impl Iterator<Item = (&str, &T)> {
+        self.layers.iter().filter_map(move |(name, layer)| layer.get(pt).map(|value| (name.as_str(), value)))
+    }
+}
+
+impl <Pt: Point, T> Default for MarkLayers<Pt, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <Pt: Point, T: Debug> Debug for MarkLayers<Pt, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.layers.iter()).finish()
+    }
+}
+
+/// A set of named points of interest in a maze, such as its start and goal.
+///
+/// Unlike [mark layers][MarkLayer], every [coordinator][MazeCoordinator] stores its own
+/// `NamedPoints` (see [`MazeCoordinator::named_points()`]), so anything holding the maze can find
+/// them. Generators and helpers may set them, and exporters may draw them.
+///
+/// The start and goal are stored under [`Self::START`] and [`Self::GOAL`], and have their own
+/// accessors for convenience. Any other names are free for you to use.
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::mark::NamedPoints;
+/// use mazelib::implm::point::boxy::CoordinateTuplet;
+///
+/// let mut points = NamedPoints::<CoordinateTuplet<2>>::new();
+///
+/// points.set_start([0, 0].into());
+/// points.set("key", [3, 1].into());
+///
+/// assert_eq!(Some([0, 0].into()), points.start());
+/// assert_eq!(None, points.goal());
+/// assert_eq!(Some([3, 1].into()), points.get("key"));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct NamedPoints<Pt: Point> {
+    points: HashMap<String, Pt>,
+}
+
+impl <Pt: Point> NamedPoints<Pt> {
+    /// The name the start point is stored under.
+    pub const START: &'static str = "start";
+
+    /// The name the goal point is stored under.
+    pub const GOAL: &'static str = "goal";
+
+    /// Construct a new, empty set of named points.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { points: HashMap::new() }
+    }
+
+    /// Return the point called `name`, if there is one.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Pt> {
+        self.points.get(name).copied()
+    }
+
+    /// Name `pt` `name`, returning the point that previously had that name.
+    pub fn set(&mut self, name: &str, pt: Pt) -> Option<Pt> {
+        self.points.insert(name.to_owned(), pt)
+    }
+
+    /// Remove the point called `name`, returning it if there was one.
+    pub fn remove(&mut self, name: &str) -> Option<Pt> {
+        self.points.remove(name)
+    }
+
+    /// Return the start point, if there is one.
+    #[must_use]
+    pub fn start(&self) -> Option<Pt> {
+        self.get(Self::START)
+    }
+
+    /// Set the start point, returning the previous one.
+    pub fn set_start(&mut self, pt: Pt) -> Option<Pt> {
+        self.set(Self::START, pt)
+    }
+
+    /// Return the goal point, if there is one.
+    #[must_use]
+    pub fn goal(&self) -> Option<Pt> {
+        self.get(Self::GOAL)
+    }
+
+    /// Set the goal point, returning the previous one.
+    pub fn set_goal(&mut self, pt: Pt) -> Option<Pt> {
+        self.set(Self::GOAL, pt)
+    }
+
+    /// Return the number of named points.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Return whether there are no named points.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Remove every named point.
+    pub fn clear(&mut self) {
+        self.points.clear()
+    }
+
+    /// Return an iterator over every name and its point, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Pt)> {
+        self.points.iter().map(|(name, pt)| (name.as_str(), *pt))
+    }
+
+    /// Return a copy with every point moved to `f(pt)`. Points that `f` maps to `None` are dropped.
+    ///
+    /// This is handy for carrying the named points over to a transformed copy of a maze.
+    #[must_use]
+    pub fn map_points(&self, f: impl Fn(Pt) -> Option<Pt>) -> Self {
+        Self { points: self.points.iter().filter_map(|(name, pt)| f(*pt).map(|pt| (name.clone(), pt))).collect() }
+    }
+}
+
+impl <Pt: Point> Default for NamedPoints<Pt> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <Pt: Point> Hash for NamedPoints<Pt> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash maps iterate in no particular order, so sort the points by name so that equal sets
+        // of points hash the same
+        let mut points: Vec<(&String, &Pt)> = self.points.iter().collect();
+        points.sort_unstable_by_key(|(name, _)| *name);
+
+        points.hash(state)
+    }
+}
+
+impl <Pt: Point> Debug for NamedPoints<Pt> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.points.iter()).finish()
+    }
+}
+
+/// A value that can be attached to the points of a maze as an [annotation][Annotations], such as a
+/// door, a key, or a monster spawner.
+///
+/// Implement this for your own types to attach them to mazes.
+pub trait Annotation: Any + Clone + Eq + Debug + Send + Sync + UnwindSafe + RefUnwindSafe {
+    /// Return the character that exporters which draw annotations (such as the
+    /// [text exporter][crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter]) should draw
+    /// this annotation as, or `None` if it shouldn't be drawn.
+    ///
+    /// Annotations aren't drawn by default.
+    #[must_use]
+    fn glyph(&self) -> Option<char> {
+        None
+    }
+}
+
+/// Typed annotations attached to the points of a maze, such as doors, keys, and treasure.
+///
+/// Every [coordinator][MazeCoordinator] stores its own `Annotations` (see
+/// [`MazeCoordinator::annotations()`]), so gameplay data lives alongside the maze rather than in a
+/// separate map keyed by point. They're carried over by [transformations][crate::util::rotate90]
+/// and [crops][crate::util::crop], and compared when mazes are.
+///
+/// Each type of [annotation][Annotation] is stored in its own [`MarkLayer`], so a point can have
+/// at most one annotation of each type, but any number of annotations of different types.
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::mark::{Annotation, Annotations};
+/// use mazelib::implm::point::boxy::CoordinateTuplet;
+///
+/// #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+/// struct Door { locked: bool }
+///
+/// impl Annotation for Door {
+///     fn glyph(&self) -> Option<char> {
+///         Some(if self.locked { 'D' } else { 'd' })
+///     }
+/// }
+///
+/// #[derive(Clone, Eq, PartialEq, Debug)]
+/// struct Treasure(u32);
+///
+/// impl Annotation for Treasure {}
+///
+/// let mut annotations = Annotations::<CoordinateTuplet<2>>::new();
+///
+/// annotations.insert([1, 0].into(), Door { locked: true });
+/// annotations.insert([1, 0].into(), Treasure(50));
+/// annotations.insert([3, 2].into(), Treasure(10));
+///
+/// assert_eq!(Some(&Door { locked: true }), annotations.get([1, 0].into()));
+/// assert_eq!(None, annotations.get::<Door>([3, 2].into()));
+/// assert_eq!(2, annotations.layer::<Treasure>().unwrap().len());
+/// assert_eq!(Some('D'), annotations.glyph([1, 0].into()));
+/// ```
+pub struct Annotations<Pt: Point> {
+    /// A layer for each type of annotation, in the order they were first used.
+    layers: Vec<Box<dyn AnnotationLayer<Pt>>>,
+}
+
+impl <Pt: Point> Annotations<Pt> {
+    /// Construct a new, empty set of annotations.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// Return the total number of annotations, of every type.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.layers.iter().map(|layer| layer.len()).sum()
+    }
+
+    /// Return whether there are no annotations.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove every annotation.
+    pub fn clear(&mut self) {
+        self.layers.clear()
+    }
+
+    /// Remove every annotation of `pt`, of every type.
+    pub fn remove_all(&mut self, pt: Pt) {
+        for layer in self.layers.iter_mut() {
+            layer.remove(pt);
+        }
+    }
+
+    /// Return the [glyph][Annotation::glyph] to draw `pt` as, if any of its annotations have one.
+    ///
+    /// If several do, the glyph of the type of annotation that was used first wins.
+    #[must_use]
+    pub fn glyph(&self, pt: Pt) -> Option<char> {
+        self.layers.iter().find_map(|layer| layer.glyph(pt))
+    }
+
+    /// Return an iterator over every point whose annotations have a [glyph][Annotation::glyph],
+    /// along with the glyph it should be drawn as (see [`glyph()`][Self::glyph]), in no
+    /// particular order.
+    pub fn glyphs(&self) -> impl Iterator<Item = (Pt, char)> {
+        let mut glyphs = HashMap::new();
+
+        // Earlier types of annotation win, so let them overwrite later ones
+        for layer in self.layers.iter().rev() {
+            glyphs.extend(layer.glyphs());
+        }
+
+        return glyphs.into_iter()
+    }
+
+    /// Return a copy with every annotation moved to `f(pt)`. Annotations of points that `f` maps
+    /// to `None` are dropped.
+    ///
+    /// This is handy for carrying the annotations over to a transformed copy of a maze.
+    #[must_use]
+    pub fn map_points(&self, f: impl Fn(Pt) -> Option<Pt>) -> Self {
+        Self { layers: self.layers.iter().map(|layer| layer.map_points(&f)).collect() }
+    }
+
+    /// Return the non-empty layers.
+    fn occupied_layers(&self) -> impl Iterator<Item = &dyn AnnotationLayer<Pt>> {
+        self.layers.iter().map(|layer| layer.as_ref()).filter(|layer| layer.len() > 0)
+    }
+}
+
+impl <Pt: Point + 'static> Annotations<Pt> {
+    /// Annotate `pt` with `value`, returning its previous annotation of the same type.
+    pub fn insert<T: Annotation>(&mut self, pt: Pt, value: T) -> Option<T> {
+        self.layer_mut::<T>().set(pt, value)
+    }
+
+    /// Return the annotation of type `T` of `pt`, if it has one.
+    #[must_use]
+    pub fn get<T: Annotation>(&self, pt: Pt) -> Option<&T> {
+        self.layer::<T>()?.get(pt)
+    }
+
+    /// Return the annotation of type `T` of `pt` mutably, if it has one.
+    #[must_use]
+    pub fn get_mut<T: Annotation>(&mut self, pt: Pt) -> Option<&mut T> {
+        self.layers.iter_mut().find_map(|layer| layer.as_any_mut().downcast_mut::<MarkLayer<Pt, T>>())?.get_mut(pt)
+    }
+
+    /// Remove the annotation of type `T` of `pt`, returning it if it had one.
+    pub fn remove<T: Annotation>(&mut self, pt: Pt) -> Option<T> {
+        self.layers.iter_mut().find_map(|layer| layer.as_any_mut().downcast_mut::<MarkLayer<Pt, T>>())?.remove(pt)
+    }
+
+    /// Return the layer of every annotation of type `T`, if any have been made.
+    #[must_use]
+    pub fn layer<T: Annotation>(&self) -> Option<&MarkLayer<Pt, T>> {
+        self.layers.iter().find_map(|layer| layer.as_any().downcast_ref::<MarkLayer<Pt, T>>())
+    }
+
+    /// Return the layer of every annotation of type `T` mutably, creating an empty one if none
+    /// have been made.
+    #[must_use]
+    pub fn layer_mut<T: Annotation>(&mut self) -> &mut MarkLayer<Pt, T> {
+        let index = match self.layers.iter().position(|layer| layer.as_any().is::<MarkLayer<Pt, T>>()) {
+            Some(index) => index,
+            None => {
+                self.layers.push(Box::new(MarkLayer::<Pt, T>::new()));
+
+                self.layers.len() - 1
+            }
+        };
+
+        return self.layers[index].as_any_mut().downcast_mut().expect("The layer was found by its type")
+    }
+}
+
+impl <Pt: Point> Default for Annotations<Pt> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl <Pt: Point> Clone for Annotations<Pt> {
+    fn clone(&self) -> Self {
+        Self { layers: self.layers.iter().map(|layer| layer.clone_box()).collect() }
+    }
+}
+
+/// Annotations are equal if they annotate the same points with the same values, regardless of
+/// the order their types were first used in.
+impl <Pt: Point> PartialEq for Annotations<Pt> {
+    fn eq(&self, other: &Self) -> bool {
+        self.occupied_layers().count() == other.occupied_layers().count()
+            && self.occupied_layers().all(|layer| other.occupied_layers().any(|other_layer| layer.eq_dyn(other_layer)))
+    }
+}
+
+impl <Pt: Point> Eq for Annotations<Pt> {}
+
+impl <Pt: Point> Hash for Annotations<Pt> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Annotations needn't be hashable, so just hash how many there are of each type. Layers
+        // are sorted by type so that equal annotations hash the same.
+        let mut sizes: Vec<(TypeId, usize)> = self.occupied_layers().map(|layer| (layer.as_any().type_id(), layer.len())).collect();
+        sizes.sort_unstable();
+
+        sizes.hash(state)
+    }
+}
+
+// Annotations are required to be unwind safe, and points are plain values, so the layers are too
+impl <Pt: Point> UnwindSafe for Annotations<Pt> {}
+impl <Pt: Point> RefUnwindSafe for Annotations<Pt> {}
+
+impl <Pt: Point> Debug for Annotations<Pt> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut map = f.debug_map();
+
+        for layer in self.occupied_layers() {
+            map.key(&layer.type_name());
+            layer.fmt_value(&mut map);
+        }
+
+        return map.finish()
+    }
+}
+
+/// A [`MarkLayer`] of [annotations][Annotation] of any type.
+trait AnnotationLayer<Pt: Point>: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn clone_box(&self) -> Box<dyn AnnotationLayer<Pt>>;
+    /// Return whether `other` is a layer of the same type with the same annotations.
+    fn eq_dyn(&self, other: &dyn AnnotationLayer<Pt>) -> bool;
+    fn len(&self) -> usize;
+    fn remove(&mut self, pt: Pt);
+    fn glyph(&self, pt: Pt) -> Option<char>;
+    fn glyphs(&self) -> Vec<(Pt, char)>;
+    fn map_points(&self, f: &dyn Fn(Pt) -> Option<Pt>) -> Box<dyn AnnotationLayer<Pt>>;
+    /// Return the name of the type of annotation.
+    fn type_name(&self) -> &'static str;
+    /// Write the layer as the value of a debug map entry.
+    fn fmt_value(&self, map: &mut std::fmt::DebugMap<'_, '_>);
+}
+
+impl <Pt: Point + 'static, T: Annotation> AnnotationLayer<Pt> for MarkLayer<Pt, T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn AnnotationLayer<Pt>> {
+        Box::new(self.clone())
+    }
+
+    fn eq_dyn(&self, other: &dyn AnnotationLayer<Pt>) -> bool {
+        other.as_any().downcast_ref::<Self>() == Some(self)
+    }
+
+    fn len(&self) -> usize {
+        MarkLayer::len(self)
+    }
+
+    fn remove(&mut self, pt: Pt) {
+        MarkLayer::remove(self, pt);
+    }
+
+    fn glyph(&self, pt: Pt) -> Option<char> {
+        self.get(pt)?.glyph()
+    }
+
+    fn glyphs(&self) -> Vec<(Pt, char)> {
+        self.iter().filter_map(|(pt, value)| value.glyph().map(|glyph| (pt, glyph))).collect()
+    }
+
+    fn map_points(&self, f: &dyn Fn(Pt) -> Option<Pt>) -> Box<dyn AnnotationLayer<Pt>> {
+        Box::new(self.iter().filter_map(|(pt, value)| f(pt).map(|pt| (pt, value.clone()))).collect::<Self>())
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn fmt_value(&self, map: &mut std::fmt::DebugMap<'_, '_>) {
+        map.value(self);
+    }
+}
+
+/// Extra connections between points of a maze that aren't adjacent, such as teleporters and wrap
+/// tunnels.
+///
+/// Every [coordinator][MazeCoordinator] stores its own `Portals` (see
+/// [`MazeCoordinator::portals()`]). A portal is always a passage, whatever the cells around its
+/// ends look like, so it's up to you to put its ends somewhere open. Portals are honoured by
+/// [`MazeCoordinator::neighbours_of_pt()`], [`MazeCoordinator::is_passage_between()`], and
+/// [`MazeCoordinator::connections()`], and so by everything built on them, such as
+/// [`set_longest_path_endpoints()`][crate::util::set_longest_path_endpoints].
+///
+/// Like connections, portals are directional. [`insert()`][Self::insert] links two points both
+/// ways, while [`insert_one_way()`][Self::insert_one_way] only links them from the first to the
+/// second.
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::mark::Portals;
+/// use mazelib::implm::point::boxy::CoordinateTuplet;
+///
+/// let mut portals = Portals::<CoordinateTuplet<2>>::new();
+///
+/// portals.insert([0, 0].into(), [4, 4].into());
+/// portals.insert_one_way([2, 0].into(), [0, 3].into());
+///
+/// assert!(portals.contains([4, 4].into(), [0, 0].into()));
+/// assert!(portals.contains([2, 0].into(), [0, 3].into()));
+/// assert!(portals.contains([0, 3].into(), [2, 0].into()) == false);
+/// assert_eq!(3, portals.len());
+/// ```
+#[derive(Clone)]
+pub struct Portals<Pt: Point> {
+    /// The destinations of each point's portals, in the order they were made.
+    links: HashMap<Pt, Vec<Pt>>,
+}
+
+impl <Pt: Point> Portals<Pt> {
+    /// Construct a new, empty set of portals.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { links: HashMap::new() }
+    }
+
+    /// Link `a` and `b` both ways, returning whether either direction is new.
+    pub fn insert(&mut self, a: Pt, b: Pt) -> bool {
+        let forwards = self.insert_one_way(a, b);
+        let backwards = self.insert_one_way(b, a);
+
+        return forwards || backwards
+    }
+
+    /// Link `from` to `to`, but not back again, returning whether the link is new.
+    pub fn insert_one_way(&mut self, from: Pt, to: Pt) -> bool {
+        let destinations = self.links.entry(from).or_default();
+
+        if destinations.contains(&to) {
+            return false
+        }
+
+        destinations.push(to);
+
+        return true
+    }
+
+    /// Unlink `a` and `b` in both directions, returning whether either direction was linked.
+    pub fn remove(&mut self, a: Pt, b: Pt) -> bool {
+        let forwards = self.remove_one_way(a, b);
+        let backwards = self.remove_one_way(b, a);
+
+        return forwards || backwards
+    }
+
+    /// Unlink `from` from `to`, leaving any link back again, returning whether it was linked.
+    pub fn remove_one_way(&mut self, from: Pt, to: Pt) -> bool {
+        let Some(destinations) = self.links.get_mut(&from) else { return false };
+        let Some(index) = destinations.iter().position(|destination| *destination == to) else { return false };
+
+        destinations.remove(index);
+
+        if destinations.is_empty() {
+            self.links.remove(&from);
+        }
+
+        return true
+    }
+
+    /// Remove every portal to or from `pt`.
+    pub fn remove_all(&mut self, pt: Pt) {
+        self.links.remove(&pt);
+
+        self.links.retain(|_, destinations| {
+            destinations.retain(|destination| *destination != pt);
+
+            destinations.is_empty() == false
+        });
+    }
+
+    /// Return whether there is a portal from `from` to `to`.
+    #[must_use]
+    pub fn contains(&self, from: Pt, to: Pt) -> bool {
+        // This is checked on every passage query, so skip hashing when there are no portals
+        if self.links.is_empty() {
+            return false
+        }
+
+        return self.destinations(from).contains(&to)
+    }
+
+    /// Return the points that `from`'s portals lead to, in the order they were made.
+    #[must_use]
+    pub fn destinations(&self, from: Pt) -> &[Pt] {
+        self.links.get(&from).map_or(&[], Vec::as_slice)
+    }
+
+    /// Return the number of portals. A portal made with [`insert()`][Self::insert] counts twice,
+    /// once in each direction.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.links.values().map(Vec::len).sum()
+    }
+
+    /// Return whether there are no portals.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty()
+    }
+
+    /// Remove every portal.
+    pub fn clear(&mut self) {
+        self.links.clear()
+    }
+
+    /// Return an iterator over every portal as `(from, to)`, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pt, Pt)> + '_ {
+        self.links.iter().flat_map(|(from, destinations)| destinations.iter().map(move |to| (*from, *to)))
+    }
+
+    /// Return a copy with both ends of every portal moved to `f(pt)`. Portals with an end that `f`
+    /// maps to `None` are dropped.
+    ///
+    /// This is handy for carrying the portals over to a transformed copy of a maze.
+    #[must_use]
+    pub fn map_points(&self, f: impl Fn(Pt) -> Option<Pt>) -> Self {
+        let mut portals = Self::new();
+
+        for (from, to) in self.iter() {
+            if let (Some(from), Some(to)) = (f(from), f(to)) {
+                portals.insert_one_way(from, to);
+            }
+        }
+
+        return portals
+    }
+}
+
+impl <Pt: Point> Default for Portals<Pt> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Portals are equal if they link the same points, regardless of the order they were made in.
+impl <Pt: Point> PartialEq for Portals<Pt> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(from, to)| other.contains(from, to))
+    }
+}
+
+impl <Pt: Point> Eq for Portals<Pt> {}
+
+impl <Pt: Point> Hash for Portals<Pt> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Points can't be sorted, so there's no order to hash the portals in that equal sets would
+        // share. Just hash how many there are.
+        self.len().hash(state)
+    }
+}
+
+impl <Pt: Point> Debug for Portals<Pt> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.links.iter()).finish()
+    }
+}
+
+/// Everything a maze stores about its points alongside its cells: its
+/// [named points][NamedPoints], [annotations][Annotations], and [portals][Portals].
+///
+/// Every [coordinator][MazeCoordinator] owns one `Marks` (see [`MazeCoordinator::marks()`]). The
+/// trait's accessors for each part, such as [`MazeCoordinator::portals()`], all go through it.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Marks<Pt: Point> {
+    /// The maze's points of interest, such as its start and goal.
+    pub named_points: NamedPoints<Pt>,
+    /// The maze's gameplay annotations, such as its doors and keys.
+    pub annotations: Annotations<Pt>,
+    /// The maze's portals between points that aren't adjacent.
+    pub portals: Portals<Pt>,
+}
+
+impl <Pt: Point> Marks<Pt> {
+    /// Construct a new set of marks, with no named points, annotations, or portals.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { named_points: NamedPoints::new(), annotations: Annotations::new(), portals: Portals::new() }
+    }
+}
+
+impl <Pt: Point> Default for Marks<Pt> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
@@ -8,6 +8,10 @@
 //! For those who want to jump straight into playing around. Do come back and read the docs
 //! though!
 //!
+//! If you just want a maze, [`quick`] has one-liners like
+//! `quick::generate_2d(width, height, Algorithm::HuntAndKill, seed)`. The rest of this section
+//! shows what they do under the hood.
+//!
 //! ```
 //! use mazelib::implm::buffer::VecBuffer;
 //! use mazelib::implm::cell::block::BlockCellValue;
@@ -171,6 +175,7 @@
 //! It discusses generating mazes, solving mazes, mazes on cubes, spheres, cylinders,
 //! and mobius strips, and more.
 //! <http://www.mazesforprogrammers.com>.
+//! Mobius strips are supported by this crate via [`implm::point::mobius`].
 //!
 //! # Glossary
 //!
@@ -192,9 +197,20 @@
 // Stylistic choices
 #![allow(clippy::needless_return, clippy::needless_range_loop, clippy::bool_comparison)]
 
+pub mod agent;
+pub mod error;
 pub mod interface;
 pub mod implm;
+pub mod infinite;
+pub mod mark;
+pub mod nested;
 pub mod path;
+pub mod prelude;
+pub mod quality;
+pub mod quick;
+pub mod rng;
+pub mod testing;
 pub mod util;
+pub mod wasm;
 pub(crate) mod internal;
 #[cfg(test)] mod test;
@@ -0,0 +1,269 @@
+//! Mazes within mazes, for exploring at more than one scale.
+//!
+//! A [`NestedMaze`] is a coarse "overworld" maze where every point expands into a sub-maze of its
+//! own. Wherever the overworld has a passage between two points, their sub-mazes are joined by a
+//! doorway: a point on the edge of one sub-maze that leads to the point facing it on the edge of
+//! the other. Every sub-maze has the same dimensions, so together they tile one large maze.
+//!
+//! Points are [`NestedPoint`]s, which name both the overworld point and the point within its
+//! sub-maze. They can be converted to and from coordinates in the large maze with
+//! [`to_global()`][NestedMaze::to_global] and [`from_global()`][NestedMaze::from_global], and
+//! [`resolve_path()`][NestedMaze::resolve_path] finds a path between any two of them through both
+//! levels.
+//!
+//! # Examples
+//!
+//! ```
+//! use mazelib::implm::generate::HuntAndKillGenerator;
+//! use mazelib::implm::point::boxy::BoxCoordinateSpace;
+//! use mazelib::implm::presets::Inline2DMazeBuilder;
+//! use mazelib::nested::{NestedMaze, NestedPoint};
+//! use mazelib::rng::{PortableRng, SeedableRng};
+//!
+//! let overworld = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+//!
+//! let mut maze = NestedMaze::new(overworld, |_| Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([5, 5])).build());
+//!
+//! maze.generate_with_rng(&mut HuntAndKillGenerator::new(), &mut HuntAndKillGenerator::new(), &mut PortableRng::seed_from_u64(4960));
+//!
+//! let start = NestedPoint::new([0, 0], [0, 0]);
+//! let goal = NestedPoint::new([2, 2], [4, 4]);
+//!
+//! let path = maze.resolve_path(start, goal).unwrap();
+//!
+//! assert_eq!(start, path[0]);
+//! assert_eq!(goal, *path.last().unwrap());
+//! assert_eq!([14, 14], maze.to_global(goal).0);
+//! ```
+
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+
+use rand::Rng;
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::util::shortest_path;
+use crate::rng::gen_index;
+
+/// A point in a [`NestedMaze`]: a point of the overworld, and a point within its sub-maze.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct NestedPoint<const DIMENSION: usize> {
+    /// The point of the overworld.
+    pub outer: CoordinateTuplet<DIMENSION>,
+    /// The point within the overworld point's sub-maze.
+    pub inner: CoordinateTuplet<DIMENSION>,
+}
+
+impl <const DIMENSION: usize> NestedPoint<DIMENSION> {
+    /// Construct a new point.
+    #[must_use]
+    pub fn new(outer: impl Into<CoordinateTuplet<DIMENSION>>, inner: impl Into<CoordinateTuplet<DIMENSION>>) -> Self {
+        Self { outer: outer.into(), inner: inner.into() }
+    }
+}
+
+/// A coarse "overworld" maze where every point expands into a sub-maze.
+///
+/// *See the [module-level docs][self].*
+///
+/// Doorways are only placed by [`place_doorways()`][Self::place_doorways] (or
+/// [`generate_with_rng()`][Self::generate_with_rng], which calls it), so call it again after
+/// changing the overworld's passages. The overworld's portals aren't followed.
+pub struct NestedMaze<Outer, Inner, const DIMENSION: usize>
+    where Outer: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>,
+          Inner: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>> {
+    /// The overworld.
+    outer: Outer,
+    /// The sub-maze of every point of the overworld.
+    inners: HashMap<CoordinateTuplet<DIMENSION>, Inner>,
+    /// The dimensions every sub-maze shares.
+    inner_dimensions: [NonZeroUsize; DIMENSION],
+    /// For every pair of adjacent overworld points with a passage between them, the point in the
+    /// first's sub-maze that leads into the second's.
+    doorways: HashMap<(CoordinateTuplet<DIMENSION>, CoordinateTuplet<DIMENSION>), CoordinateTuplet<DIMENSION>>,
+}
+
+impl <Outer, Inner, const DIMENSION: usize> NestedMaze<Outer, Inner, DIMENSION>
+    where Outer: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>,
+          Inner: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>> {
+    /// Construct a new nested maze, without any doorways.
+    ///
+    /// # Parameters
+    ///
+    /// * `outer`       --- the overworld.
+    /// * `build_inner` --- called with each point of the overworld in turn, and must return a new
+    ///   sub-maze for it.
+    ///
+    /// # Panics
+    ///
+    /// If the sub-mazes don't all have the same dimensions.
+    #[must_use]
+    pub fn new(outer: Outer, mut build_inner: impl FnMut(CoordinateTuplet<DIMENSION>) -> Inner) -> Self {
+        let inners: HashMap<_, _> = outer.coord_space().iter().map(|pt| (pt, build_inner(pt))).collect();
+
+        let first = outer.coord_space().iter().next().expect("Coordinate spaces are never empty");
+        let inner_dimensions = inners[&first].coord_space().dimensions();
+
+        assert!(inners.values().all(|inner| inner.coord_space().dimensions() == inner_dimensions), "Every sub-maze must have the same dimensions");
+
+        Self { outer, inners, inner_dimensions, doorways: HashMap::new() }
+    }
+
+    /// Return the overworld.
+    #[must_use]
+    pub fn outer(&self) -> &Outer {
+        &self.outer
+    }
+
+    /// Return the overworld, mutably.
+    #[must_use]
+    pub fn outer_mut(&mut self) -> &mut Outer {
+        &mut self.outer
+    }
+
+    /// Return the sub-maze of the overworld point `pt`.
+    ///
+    /// # Panics
+    ///
+    /// If `pt` is not a point of the overworld.
+    #[must_use]
+    pub fn inner(&self, pt: CoordinateTuplet<DIMENSION>) -> &Inner {
+        self.inners.get(&pt).unwrap_or_else(|| panic!("{:?} is not a point of the overworld", pt))
+    }
+
+    /// Return the sub-maze of the overworld point `pt`, mutably.
+    ///
+    /// # Panics
+    ///
+    /// If `pt` is not a point of the overworld.
+    #[must_use]
+    pub fn inner_mut(&mut self, pt: CoordinateTuplet<DIMENSION>) -> &mut Inner {
+        self.inners.get_mut(&pt).unwrap_or_else(|| panic!("{:?} is not a point of the overworld", pt))
+    }
+
+    /// Return the dimensions every sub-maze shares.
+    #[must_use]
+    pub fn inner_dimensions(&self) -> [NonZeroUsize; DIMENSION] {
+        self.inner_dimensions
+    }
+
+    /// Return the number of points along each axis of the whole maze, once every sub-maze has
+    /// been put in place.
+    #[must_use]
+    pub fn global_dimensions(&self) -> [usize; DIMENSION] {
+        let outer_dimensions = self.outer.coord_space().dimensions();
+
+        return std::array::from_fn(|axis| usize::from(outer_dimensions[axis]) * usize::from(self.inner_dimensions[axis]))
+    }
+
+    /// Return the coordinates of `pt` in the whole maze.
+    #[must_use]
+    pub fn to_global(&self, pt: NestedPoint<DIMENSION>) -> CoordinateTuplet<DIMENSION> {
+        CoordinateTuplet(std::array::from_fn(|axis| pt.outer[axis] * usize::from(self.inner_dimensions[axis]) + pt.inner[axis]))
+    }
+
+    /// Return the point at the coordinates `global` in the whole maze.
+    ///
+    /// *The inverse of [`to_global()`][Self::to_global].*
+    #[must_use]
+    pub fn from_global(&self, global: CoordinateTuplet<DIMENSION>) -> NestedPoint<DIMENSION> {
+        NestedPoint {
+            outer: CoordinateTuplet(std::array::from_fn(|axis| global[axis] / usize::from(self.inner_dimensions[axis]))),
+            inner: CoordinateTuplet(std::array::from_fn(|axis| global[axis] % usize::from(self.inner_dimensions[axis]))),
+        }
+    }
+
+    /// Return the doorway from the sub-maze of `from` to the sub-maze of `to`, as the point it
+    /// leaves from and the point it arrives at, or `None` if there isn't one.
+    #[must_use]
+    pub fn doorway(&self, from: CoordinateTuplet<DIMENSION>, to: CoordinateTuplet<DIMENSION>) -> Option<(NestedPoint<DIMENSION>, NestedPoint<DIMENSION>)> {
+        let exit = *self.doorways.get(&(from, to))?;
+        let entry = self.doorways[&(to, from)];
+
+        return Some((NestedPoint { outer: from, inner: exit }, NestedPoint { outer: to, inner: entry }))
+    }
+
+    /// Generate the overworld and every sub-maze, and then [place the doorways][Self::place_doorways]
+    /// between them.
+    ///
+    /// # Parameters
+    ///
+    /// * `outer_generator` --- the generator for the overworld.
+    /// * `inner_generator` --- the generator for every sub-maze.
+    /// * `rng`             --- the source of randomness for both generators and the doorways.
+    pub fn generate_with_rng(&mut self, outer_generator: &mut impl MazeGenerator<Outer>, inner_generator: &mut impl MazeGenerator<Inner>, rng: &mut (impl Rng + ?Sized)) {
+        outer_generator.generate_with_rng(&mut self.outer, rng);
+
+        // Go in a fixed order so that the same seed always gives the same maze
+        for pt in self.outer.coord_space().iter() {
+            inner_generator.generate_with_rng(self.inners.get_mut(&pt).expect("[Bug] Every point has a sub-maze"), rng);
+        }
+
+        self.place_doorways(rng);
+    }
+
+    /// Place a doorway between the sub-mazes of every pair of adjacent overworld points with a
+    /// passage between them, replacing any placed before.
+    ///
+    /// Each doorway is at a random position along the side the two sub-mazes share.
+    pub fn place_doorways(&mut self, rng: &mut (impl Rng + ?Sized)) {
+        self.doorways.clear();
+
+        let outer_space = *self.outer.coord_space();
+
+        for from in outer_space.iter() {
+            for to in outer_space.neighbours_of_pt(from) {
+                if self.doorways.contains_key(&(from, to)) || self.outer.is_passage_between(from, to) == false {
+                    continue
+                }
+
+                let axis = (0..DIMENSION).find(|axis| from[*axis] != to[*axis]).expect("[Bug] Adjacent points differ along one axis");
+                let is_positive = (from[axis] + 1) % usize::from(outer_space[axis]) == to[axis];
+
+                let mut exit = CoordinateTuplet(std::array::from_fn(|axis| gen_index(rng, usize::from(self.inner_dimensions[axis]))));
+                let mut entry = exit;
+
+                let last = usize::from(self.inner_dimensions[axis]) - 1;
+
+                exit[axis] = if is_positive { last } else { 0 };
+                entry[axis] = if is_positive { 0 } else { last };
+
+                self.doorways.insert((from, to), exit);
+                self.doorways.insert((to, from), entry);
+            }
+        }
+    }
+
+    /// Return a path from `from` to `to` through both levels, including both ends, or `None` if
+    /// there is no such path (including if either point is outside of the maze).
+    ///
+    /// The path takes the shortest route through the overworld, and the shortest path through
+    /// each sub-maze along it, from the doorway it enters by to the doorway it leaves by. Crossing
+    /// a doorway is a single step between two points in different sub-mazes.
+    #[must_use]
+    pub fn resolve_path(&self, from: NestedPoint<DIMENSION>, to: NestedPoint<DIMENSION>) -> Option<Vec<NestedPoint<DIMENSION>>> {
+        let outer_path = shortest_path(&self.outer, from.outer, to.outer)?;
+
+        let mut path = Vec::new();
+        let mut entry = from.inner;
+
+        for step in outer_path.windows(2) {
+            let (exit, next) = self.doorway(step[0], step[1])?;
+
+            let leg = shortest_path(self.inner(step[0]), entry, exit.inner)?;
+
+            path.extend(leg.into_iter().map(|inner| NestedPoint { outer: step[0], inner }));
+
+            entry = next.inner;
+        }
+
+        let leg = shortest_path(self.inner(to.outer), entry, to.inner)?;
+
+        path.extend(leg.into_iter().map(|inner| NestedPoint { outer: to.outer, inner }));
+
+        return Some(path)
+    }
+}
\ No newline at end of file
@@ -11,7 +11,7 @@ use std::hash::Hash;
 use std::ops::{Index, IndexMut, RangeInclusive};
 use std::slice::SliceIndex;
 
-use crate::interface::cell::CellValue;
+use crate::interface::cell::CellLocation;
 use crate::interface::point::CoordinateSpace;
 
 /// A series of movements from location to location.
@@ -57,7 +57,7 @@ pub struct PointPath<CoordSpace: CoordinateSpace>(pub Path<CoordSpace::PtType>);
 /// A path of cell locations.
 ///
 /// See [`Path`].
-pub struct CellPath<CellType: CellValue>(pub Path<CellType>);
+pub struct CellPath<CellLoc: CellLocation>(pub Path<CellLoc>);
 
 impl <T: Sized + Clone + Copy + PartialEq + Eq + Hash + Send + Sync + Debug> Path<T> {
     /// Create a path from the given locations.
@@ -0,0 +1,47 @@
+//! The most commonly-used traits and types, for glob importing.
+//!
+//! ```
+//! use mazelib::prelude::*;
+//! ```
+//!
+//! This brings the core [interfaces][crate::interface] into scope, so their methods can be called,
+//! along with the standard box-space implementations used by most mazes and their
+//! [presets][crate::implm::presets].
+//!
+//! Only the [`DefaultMazeGenerator`] and [`DefaultMazeExporter`] sugar traits are included. Their
+//! associated functions share names with those of [`MazeGenerator`][crate::interface::generate::MazeGenerator]
+//! and [`MazeExporter`][crate::interface::export::MazeExporter], so importing both would make calls
+//! like `HuntAndKillGenerator::generate(&mut maze)` ambiguous. Import the full traits yourself
+//! if you need to configure a generator or exporter before using it.
+//!
+//! # Examples
+//!
+//! ```
+//! use mazelib::prelude::*;
+//!
+//! let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([5, 5])).build();
+//!
+//! apply_solid_border(&mut maze);
+//! HuntAndKillGenerator::generate(&mut maze);
+//!
+//! # #[allow(unused_must_use)]
+//! BoxSpaceBlockCellTextMazeExporter::export(&maze, &mut std::io::stdout());
+//! ```
+
+pub use crate::error::MazeError;
+pub use crate::implm::buffer::VecBuffer;
+pub use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+pub use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+pub use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+pub use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+pub use crate::implm::export::text::{BoxSpaceBlockCellTextMazeExporter, BoxSpaceInlineCellTextMazeExporter};
+pub use crate::implm::generate::{HuntAndKillGenerator, NAryTreeGenerator, RecursiveBacktrackerGenerator};
+pub use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+pub use crate::implm::presets::{Inline2DMaze, Inline3DMaze, Standard2DMaze, Standard3DMaze};
+pub use crate::interface::buffer::MazeBuffer;
+pub use crate::interface::cell::CellValue;
+pub use crate::interface::coordinate::MazeCoordinator;
+pub use crate::interface::export::DefaultMazeExporter;
+pub use crate::interface::generate::DefaultMazeGenerator;
+pub use crate::interface::point::CoordinateSpace;
+pub use crate::util::apply_solid_border;
\ No newline at end of file
@@ -0,0 +1,273 @@
+//! Measuring mazes, and generating them until they measure up.
+//!
+//! Generators pick passages at random, so some mazes they produce are better than others for a
+//! given purpose: too easy, too short, or full of tiny dead ends. [`MazeStats`] measures a maze,
+//! and a [`QualityGate`] keeps generating mazes from fresh seeds until one passes every check you
+//! give it.
+//!
+//! # Examples
+//!
+//! ```
+//! use mazelib::quality::QualityGate;
+//! use mazelib::quick::{self, Algorithm};
+//!
+//! let (maze, seed) = QualityGate::new()
+//!     .min_solution_length(30)
+//!     .dead_end_ratio(0.05..=0.3)
+//!     .generate(7, |seed| quick::generate_2d(10, 10, Algorithm::RecursiveBacktracker, seed))
+//!     .expect("No maze met the requirements");
+//!
+//! // The same seed always produces the same maze
+//! assert!(maze == quick::generate_2d(10, 10, Algorithm::RecursiveBacktracker, seed));
+//! ```
+
+use std::ops::RangeInclusive;
+
+use rand::{RngCore, SeedableRng};
+
+use crate::interface::cell::{CellValue, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::util::{exit_count, shortest_path};
+use crate::rng::PortableRng;
+
+/// Measurements of the layout of a maze.
+///
+/// Only passage points (points whose [state][CellValue::state] is
+/// [`PASSAGE`][ConnectionType::PASSAGE]) are counted. A point's *exits* are the neighbouring points
+/// it has a passage to.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct MazeStats {
+    /// The number of passage points.
+    pub passages: usize,
+    /// The number of passage points with exactly one exit.
+    pub dead_ends: usize,
+    /// The number of passage points with three or more exits.
+    pub junctions: usize,
+    /// The number of steps along the shortest path from the maze's [start][MazeCoordinator::start]
+    /// to its [goal][MazeCoordinator::goal], or `None` if either isn't set or there is no such
+    /// path.
+    pub solution_length: Option<usize>,
+    /// The number of points along the shortest path from the start to the goal where someone
+    /// walking it has more than one way forward, or `None` if there is no such path.
+    ///
+    /// This is a rough measure of how difficult the maze is to solve by hand. Every one of these
+    /// points is a chance to take a wrong turn.
+    pub solution_decisions: Option<usize>,
+}
+
+impl MazeStats {
+    /// Measure `maze`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use mazelib::implm::buffer::VecBuffer;
+    /// # use mazelib::implm::cell::inline::InlineCellValue;
+    /// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+    /// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+    /// # use mazelib::interface::coordinate::MazeCoordinator;
+    /// use mazelib::quality::MazeStats;
+    ///
+    /// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+    ///
+    /// // A T-junction at [1, 0]
+    /// maze.make_passage_between([0, 0].into(), [1, 0].into());
+    /// maze.make_passage_between([1, 0].into(), [2, 0].into());
+    /// maze.make_passage_between([1, 0].into(), [1, 1].into());
+    ///
+    /// maze.set_start([0, 0].into());
+    /// maze.set_goal([2, 0].into());
+    ///
+    /// let stats = MazeStats::of(&maze);
+    ///
+    /// assert_eq!(3, stats.dead_ends);
+    /// assert_eq!(1, stats.junctions);
+    /// assert_eq!(Some(2), stats.solution_length);
+    /// assert_eq!(Some(1), stats.solution_decisions);
+    /// ```
+    #[must_use]
+    pub fn of<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M) -> Self {
+        let mut stats = Self { passages: 0, dead_ends: 0, junctions: 0, solution_length: None, solution_decisions: None };
+
+        for (pt, value) in maze.iter() {
+            if value.state() != ConnectionType::PASSAGE {
+                continue
+            }
+
+            stats.passages += 1;
+
+            match exit_count(maze, pt) {
+                1 => stats.dead_ends += 1,
+                3.. => stats.junctions += 1,
+                _ => {},
+            }
+        }
+
+        if let Some(solution) = maze.start().zip(maze.goal()).and_then(|(start, goal)| shortest_path(maze, start, goal)) {
+            // Every point but the goal is left by one of its exits
+            let walked = &solution[..solution.len() - 1];
+
+            stats.solution_length = Some(walked.len());
+            stats.solution_decisions = Some(walked.iter().enumerate().filter(|(i, pt)| {
+                // Every point but the start was entered through one of its exits too
+                exit_count(maze, **pt) - usize::from(*i > 0) > 1
+            }).count());
+        }
+
+        return stats
+    }
+
+    /// Return the fraction of passage points that are dead ends, from `0.0` to `1.0`.
+    ///
+    /// Returns `0.0` if there are no passage points.
+    #[must_use]
+    pub fn dead_end_ratio(&self) -> f64 {
+        if self.passages == 0 { 0.0 } else { self.dead_ends as f64 / self.passages as f64 }
+    }
+}
+
+/// Checks that a maze must pass, and a driver that keeps generating mazes until one does.
+///
+/// *See the [module-level docs][self].*
+///
+/// With no checks, every maze passes.
+pub struct QualityGate<M: MazeCoordinator> {
+    /// The fewest steps the solution may take.
+    min_solution_length: Option<usize>,
+    /// The range the fraction of dead ends must lie within.
+    dead_end_ratio: Option<RangeInclusive<f64>>,
+    /// The range the number of decisions along the solution must lie within.
+    difficulty: Option<RangeInclusive<usize>>,
+    /// Any other checks.
+    predicates: Vec<Box<Predicate<M>>>,
+    /// The most mazes to generate before giving up.
+    max_attempts: usize,
+}
+
+/// A check that a maze must pass.
+///
+/// *See [`QualityGate::require()`].*
+pub type Predicate<M> = dyn Fn(&M) -> bool;
+
+impl <M: MazeCoordinator> QualityGate<M> {
+    /// The most mazes [`generate()`][Self::generate] generates before giving up, unless
+    /// [changed][Self::max_attempts].
+    pub const DEFAULT_MAX_ATTEMPTS: usize = 1000;
+
+    /// Construct a new gate, with no checks.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { min_solution_length: None, dead_end_ratio: None, difficulty: None, predicates: Vec::new(), max_attempts: Self::DEFAULT_MAX_ATTEMPTS }
+    }
+
+    /// Require the [solution][MazeStats::solution_length] to take at least `length` steps.
+    ///
+    /// Mazes without a start and goal, or without a path between them, fail this check.
+    #[must_use]
+    pub fn min_solution_length(mut self, length: usize) -> Self {
+        self.min_solution_length = Some(length);
+
+        return self
+    }
+
+    /// Require the [fraction of passages that are dead ends][MazeStats::dead_end_ratio] to lie
+    /// within `range`.
+    #[must_use]
+    pub fn dead_end_ratio(mut self, range: RangeInclusive<f64>) -> Self {
+        self.dead_end_ratio = Some(range);
+
+        return self
+    }
+
+    /// Require the number of [decisions along the solution][MazeStats::solution_decisions] to lie
+    /// within `range`.
+    ///
+    /// Mazes without a start and goal, or without a path between them, fail this check.
+    #[must_use]
+    pub fn difficulty(mut self, range: RangeInclusive<usize>) -> Self {
+        self.difficulty = Some(range);
+
+        return self
+    }
+
+    /// Require `predicate` to return true for the maze.
+    ///
+    /// This can be called any number of times. Every predicate must be met.
+    #[must_use]
+    pub fn require(mut self, predicate: impl Fn(&M) -> bool + 'static) -> Self {
+        self.predicates.push(Box::new(predicate));
+
+        return self
+    }
+
+    /// Set the most mazes [`generate()`][Self::generate] generates before giving up.
+    ///
+    /// Defaults to [`DEFAULT_MAX_ATTEMPTS`][Self::DEFAULT_MAX_ATTEMPTS].
+    #[must_use]
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+
+        return self
+    }
+
+    /// Return true if `maze` passes every check.
+    #[must_use]
+    pub fn is_met_by(&self, maze: &M) -> bool {
+        let stats = MazeStats::of(maze);
+
+        if let Some(min_length) = self.min_solution_length {
+            if stats.solution_length.map_or(true, |length| length < min_length) {
+                return false
+            }
+        }
+
+        if let Some(range) = &self.dead_end_ratio {
+            if range.contains(&stats.dead_end_ratio()) == false {
+                return false
+            }
+        }
+
+        if let Some(range) = &self.difficulty {
+            if stats.solution_decisions.map_or(true, |decisions| range.contains(&decisions) == false) {
+                return false
+            }
+        }
+
+        return self.predicates.iter().all(|predicate| predicate(maze))
+    }
+
+    /// Generate mazes until one passes every check, returning it along with the seed it was
+    /// generated from.
+    ///
+    /// Each attempt calls `generate` with a fresh seed, which should produce the same maze every
+    /// time it is given the same seed (e.g. by seeding a [`PortableRng`] with it). The seeds are
+    /// drawn from a [`PortableRng`] seeded with `seed`, so the whole search is reproducible.
+    ///
+    /// Returns `None` if no maze passed within the [maximum number of attempts][Self::max_attempts].
+    ///
+    /// # Parameters
+    ///
+    /// * `seed`     --- the seed the seed of every attempt is drawn from.
+    /// * `generate` --- produces a finished maze from a seed.
+    pub fn generate(&self, seed: u64, mut generate: impl FnMut(u64) -> M) -> Option<(M, u64)> {
+        let mut seeds = PortableRng::seed_from_u64(seed);
+
+        for _ in 0..self.max_attempts {
+            let attempt_seed = seeds.next_u64();
+            let maze = generate(attempt_seed);
+
+            if self.is_met_by(&maze) {
+                return Some((maze, attempt_seed))
+            }
+        }
+
+        return None
+    }
+}
+
+impl <M: MazeCoordinator> Default for QualityGate<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+//! One-liners for generating common mazes without assembling them by hand.
+//!
+//! Building a maze normally means picking a coordinate space, cell type, buffer, and generator.
+//! That flexibility is the point of this library, but it's a lot to take in when you just want
+//! a maze. The functions here make sensible choices for you and hand back a finished maze.
+//!
+//! ```
+//! use mazelib::prelude::*;
+//! use mazelib::quick::{self, Algorithm};
+//!
+//! let maze = quick::generate_2d(10, 10, Algorithm::HuntAndKill, 42);
+//!
+//! # #[allow(unused_must_use)]
+//! BoxSpaceBlockCellTextMazeExporter::export(&maze, &mut std::io::stdout());
+//! ```
+//!
+//! The mazes returned are ordinary [maze coordinators][crate::interface::coordinate::MazeCoordinator],
+//! so everything else in the library works on them too. Glob-import the [prelude][crate::prelude]
+//! to bring their traits into scope.
+
+use rand::{Rng, SeedableRng};
+
+use crate::implm::generate::{HuntAndKillGenerator, NAryTreeGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::{Inline2DMaze, Inline2DMazeBuilder, Standard2DMaze};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::DefaultMazeGenerator;
+use crate::rng::PortableRng;
+use crate::util::{apply_solid_border, set_longest_path_endpoints};
+
+/// The 2D maze produced by [`generate_2d()`], where walls take up whole cells.
+pub type BlockMaze2D = Standard2DMaze;
+
+/// The 2D maze produced by [`generate_2d_inline()`], where walls sit between cells.
+pub type InlineMaze2D = Inline2DMaze;
+
+/// The generation algorithms available to the one-liners.
+///
+/// See [`crate::implm::generate`] for a comparison of their output.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen)]
+pub enum Algorithm {
+    /// See [`HuntAndKillGenerator`]. The recommended default.
+    #[default]
+    HuntAndKill,
+    /// See [`RecursiveBacktrackerGenerator`].
+    RecursiveBacktracker,
+    /// See [`NAryTreeGenerator`].
+    NAryTree,
+}
+
+impl Algorithm {
+    /// Generate `maze` in-place with this algorithm, drawing from the
+    /// [default RNG][crate::rng::default_rng].
+    ///
+    /// # Parameters
+    ///
+    /// * `maze` --- the maze to be filled in. See [`MazeGenerator::generate()`][crate::interface::generate::MazeGenerator::generate].
+    #[cfg(feature = "thread-rng")]
+    pub fn generate<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(self, maze: &mut M) {
+        self.generate_with_rng(maze, &mut crate::rng::default_rng())
+    }
+
+    /// Generate `maze` in-place with this algorithm, using a given random number generator.
+    ///
+    /// # Parameters
+    ///
+    /// * `maze` --- the maze to be filled in. See [`MazeGenerator::generate()`][crate::interface::generate::MazeGenerator::generate].
+    /// * `rng`  --- the sole source of randomness for the generator.
+    pub fn generate_with_rng<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(self, maze: &mut M, rng: &mut (impl Rng + ?Sized)) {
+        match self {
+            Self::HuntAndKill => HuntAndKillGenerator::generate_with_rng(maze, rng),
+            Self::RecursiveBacktracker => RecursiveBacktrackerGenerator::generate_with_rng(maze, rng),
+            Self::NAryTree => NAryTreeGenerator::generate_with_rng(maze, rng),
+        }
+    }
+}
+
+/// Generate a `width` by `height` maze with a solid border, where walls take up whole cells.
+///
+/// The same seed always produces the same maze for a given version of this library, on every
+/// platform (see [`PortableRng`]). The maze's start and goal are set to the ends of its longest
+/// path (see [`set_longest_path_endpoints()`]).
+///
+/// # Parameters
+///
+/// * `width`     --- the number of points along the x-axis.
+/// * `height`    --- the number of points along the y-axis.
+/// * `algorithm` --- which generator to use.
+/// * `seed`      --- the seed for the random number generator.
+///
+/// # Panics
+///
+/// If `width` or `height` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::prelude::*;
+/// use mazelib::quick::{self, Algorithm};
+///
+/// let maze = quick::generate_2d(5, 5, Algorithm::RecursiveBacktracker, 7);
+///
+/// assert_eq!([5, 5], maze.coord_space().dimensions().map(usize::from));
+/// ```
+#[must_use]
+pub fn generate_2d(width: usize, height: usize, algorithm: Algorithm, seed: u64) -> BlockMaze2D {
+    let mut maze = BlockMaze2D::builder(BoxCoordinateSpace::new_checked([width, height])).build();
+
+    apply_solid_border(&mut maze);
+
+    algorithm.generate_with_rng(&mut maze, &mut PortableRng::seed_from_u64(seed));
+    set_longest_path_endpoints(&mut maze);
+
+    return maze
+}
+
+/// Generate a `width` by `height` maze where walls sit between cells.
+///
+/// The same seed always produces the same maze for a given version of this library, on every
+/// platform (see [`PortableRng`]). The maze's start and goal are set to the ends of its longest
+/// path (see [`set_longest_path_endpoints()`]).
+///
+/// # Parameters
+///
+/// * `width`     --- the number of points along the x-axis.
+/// * `height`    --- the number of points along the y-axis.
+/// * `algorithm` --- which generator to use.
+/// * `seed`      --- the seed for the random number generator.
+///
+/// # Panics
+///
+/// If `width` or `height` is zero.
+#[must_use]
+pub fn generate_2d_inline(width: usize, height: usize, algorithm: Algorithm, seed: u64) -> InlineMaze2D {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([width, height])).build();
+
+    algorithm.generate_with_rng(&mut maze, &mut PortableRng::seed_from_u64(seed));
+    set_longest_path_endpoints(&mut maze);
+
+    return maze
+}
\ No newline at end of file
@@ -0,0 +1,198 @@
+//! Sources of randomness.
+//!
+//! Everything in this library that needs randomness takes it as a `&mut (impl Rng + ?Sized)`.
+//! [`Rng`] is implemented for every [`RngCore`], so any random number generator from the
+//! [`rand`] ecosystem can be used, including trait objects like `&mut dyn RngCore`. Given a
+//! [`SeedableRng`] with a fixed seed, the results are deterministic.
+//!
+//! The [`rand`] traits are re-exported here so that you needn't depend on the same version of
+//! `rand` yourself.
+//!
+//! Functions that don't take an RNG, such as [`MazeGenerator::generate()`][crate::interface::generate::MazeGenerator::generate],
+//! draw from [`default_rng()`] instead. This is seeded by the operating system, which some
+//! targets, like `wasm32-unknown-unknown`, don't have. To build for them, disable the default
+//! `thread-rng` feature and pass an RNG you seed yourself.
+//!
+//! # Reproducibility
+//!
+//! Functions that take a seed, such as [`quick::generate_2d()`][crate::quick::generate_2d], draw
+//! from a [`PortableRng`]. Given the same seed, algorithm, and version of this library, they
+//! produce identical mazes on every platform, regardless of its pointer width or endianness.
+//!
+//! You can get the same guarantee when calling generators yourself by passing them a
+//! [`PortableRng`]. Other RNGs carry their own guarantees. [`rand::rngs::StdRng`], for instance,
+//! may change its algorithm in any release of `rand`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use mazelib::implm::buffer::VecBuffer;
+//! # use mazelib::implm::cell::block::BlockCellValue;
+//! # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+//! # use mazelib::implm::generate::HuntAndKillGenerator;
+//! # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+//! # use mazelib::interface::generate::DefaultMazeGenerator;
+//! use mazelib::rng::{RngCore, SeedableRng};
+//! use rand_chacha::ChaCha8Rng;
+//!
+//! let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([5, 5])).build();
+//!
+//! // Pick a generator at runtime
+//! let mut rng: Box<dyn RngCore> = Box::new(ChaCha8Rng::seed_from_u64(1));
+//!
+//! HuntAndKillGenerator::generate_with_rng(&mut maze, &mut *rng);
+//! ```
+
+pub use rand::{Rng, RngCore, SeedableRng};
+
+/// The random number generator returned by [`default_rng()`].
+///
+/// Requires the `thread-rng` feature (enabled by default).
+#[cfg(any(feature = "thread-rng", doc))]
+pub type DefaultRng = rand::rngs::ThreadRng;
+
+/// Return the random number generator used when none is given.
+///
+/// This is currently [`rand::thread_rng()`], a cryptographically secure generator local to the
+/// current thread and seeded by the operating system.
+///
+/// Requires the `thread-rng` feature (enabled by default).
+#[cfg(any(feature = "thread-rng", doc))]
+#[must_use]
+pub fn default_rng() -> DefaultRng {
+    rand::thread_rng()
+}
+
+/// A small, fast random number generator whose output will never change.
+///
+/// This is the [xoshiro256\*\*](https://prng.di.unimi.it/) generator, implemented within this
+/// library so that its output doesn't depend on the version of any other crate. It is seeded with
+/// [SplitMix64](https://prng.di.unimi.it/splitmix64.c) when constructed with
+/// [`seed_from_u64()`][SeedableRng::seed_from_u64]. Its output is the same on every platform, and
+/// this will remain true in every future version of this library.
+///
+/// It is not cryptographically secure.
+///
+/// *See the [module-level docs][self#reproducibility].*
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::rng::{PortableRng, RngCore, SeedableRng};
+///
+/// let mut rng = PortableRng::seed_from_u64(0);
+///
+/// assert_eq!(11_091_344_671_253_066_420, rng.next_u64());
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PortableRng {
+    state: [u64; 4],
+}
+
+impl PortableRng {
+    /// Return the generator's full state, so that it can be saved and restored later with
+    /// [`from_state()`][Self::from_state].
+    #[cfg(any(feature = "json", doc))]
+    pub(crate) fn state(&self) -> [u64; 4] {
+        self.state
+    }
+
+    /// Construct a generator from a state returned by [`state()`][Self::state].
+    ///
+    /// The state must not be all zeroes.
+    #[cfg(any(feature = "json", doc))]
+    pub(crate) fn from_state(state: [u64; 4]) -> Self {
+        debug_assert!(state != [0; 4], "xoshiro256** can't have a state of all zeroes");
+
+        Self { state }
+    }
+}
+
+impl RngCore for PortableRng {
+    fn next_u32(&mut self) -> u32 {
+        // The upper bits are the strongest
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let [s0, s1, s2, s3] = &mut self.state;
+
+        let result = s1.wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        let t = *s1 << 17;
+
+        *s2 ^= *s0;
+        *s3 ^= *s1;
+        *s1 ^= *s2;
+        *s0 ^= *s3;
+
+        *s2 ^= t;
+        *s3 = s3.rotate_left(45);
+
+        return result
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+
+        return Ok(())
+    }
+}
+
+impl SeedableRng for PortableRng {
+    type Seed = [u8; 32];
+
+    /// Construct a generator from its full state, read as four little-endian `u64`s.
+    ///
+    /// xoshiro256\*\* can't have a state of all zeroes, so that seed is treated as
+    /// [`seed_from_u64(0)`][Self::seed_from_u64] instead.
+    fn from_seed(seed: Self::Seed) -> Self {
+        if seed == [0; 32] {
+            return Self::seed_from_u64(0)
+        }
+
+        let mut state = [0; 4];
+
+        for (word, bytes) in state.iter_mut().zip(seed.chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().expect("The chunks are eight bytes long"));
+        }
+
+        return Self { state }
+    }
+
+    fn seed_from_u64(mut seed: u64) -> Self {
+        let state = [(); 4].map(|()| {
+            seed = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        });
+
+        return Self { state }
+    }
+}
+
+/// Return a random index less than `bound`, drawing the same values on every platform.
+///
+/// [`Rng::gen_range()`] samples a `usize` from a `u64` on 64-bit platforms but from a `u32` on
+/// 32-bit ones, so it gives different results for the same RNG. This always uses a `u32` if
+/// `bound` fits in one, as [`rand::seq::SliceRandom::choose()`] does.
+///
+/// # Panics
+///
+/// If `bound` is zero.
+pub(crate) fn gen_index(rng: &mut (impl Rng + ?Sized), bound: usize) -> usize {
+    if let Ok(bound) = u32::try_from(bound) {
+        rng.gen_range(0..bound) as usize
+    } else {
+        rng.gen_range(0..bound)
+    }
+}
\ No newline at end of file
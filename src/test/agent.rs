@@ -0,0 +1,97 @@
+use crate::agent::{Agent, MoveResult};
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::implm::point::hex::{HexCoordinate, HexCoordinateSpace, HexDirection};
+use crate::implm::presets::{Inline2DMaze, Inline2DMazeBuilder};
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// A 3x3 maze with a wall between `[0, 0]` and `[0, 1]`, and passages everywhere else inside.
+fn open_maze() -> Inline2DMaze {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    let space = *maze.coord_space();
+
+    for pt in space.iter() {
+        for neighbour in space.neighbours_of_pt(pt) {
+            maze.make_passage_between(pt, neighbour);
+        }
+    }
+
+    maze.make_wall_between([0, 0].into(), [0, 1].into());
+
+    return maze
+}
+
+#[test]
+fn test_agent_moves_only_through_passages() {
+    let maze = open_maze();
+    let mut agent = Agent::new(CoordinateTuplet([0, 0]));
+
+    assert_eq!(MoveResult::Blocked(ConnectionType::WALL), agent.try_move(&maze, BoxDirection::SOUTH));
+    assert_eq!(MoveResult::OffMaze, agent.try_move(&maze, BoxDirection::NORTH));
+    assert_eq!(CoordinateTuplet([0, 0]), agent.position());
+
+    let result = agent.try_move(&maze, BoxDirection::EAST);
+
+    assert!(result.has_moved());
+    assert_eq!(MoveResult::Moved(CoordinateTuplet([1, 0])), result);
+    assert_eq!(CoordinateTuplet([1, 0]), agent.position());
+}
+
+#[test]
+fn test_agent_tracks_history() {
+    let maze = open_maze();
+    let mut agent = Agent::new(CoordinateTuplet([0, 0]));
+
+    for direction in [BoxDirection::EAST, BoxDirection::SOUTH, BoxDirection::NORTH, BoxDirection::WEST] {
+        assert!(agent.try_move(&maze, direction).has_moved());
+    }
+
+    assert_eq!(&[[0, 0], [1, 0], [1, 1], [1, 0], [0, 0]].map(CoordinateTuplet), agent.history());
+    assert_eq!(3, agent.visited_count());
+    assert!(agent.has_visited([1, 1].into()));
+    assert!(agent.has_visited([2, 2].into()) == false);
+}
+
+#[test]
+fn test_agent_reports_reaching_goal() {
+    let maze = open_maze();
+    let mut agent = Agent::new(CoordinateTuplet([0, 0])).with_goal(CoordinateTuplet([1, 1]));
+
+    assert!(agent.is_at_goal() == false);
+    assert_eq!(MoveResult::Moved(CoordinateTuplet([1, 0])), agent.try_move(&maze, BoxDirection::EAST));
+    assert_eq!(MoveResult::ReachedGoal(CoordinateTuplet([1, 1])), agent.try_move(&maze, BoxDirection::SOUTH));
+    assert!(agent.is_at_goal());
+}
+
+#[test]
+fn test_agent_try_move_to_takes_portals() {
+    let mut maze = open_maze();
+    maze.portals_mut().insert([0, 0].into(), [2, 2].into());
+
+    let mut agent = Agent::new(CoordinateTuplet([0, 0]));
+
+    assert_eq!(MoveResult::Blocked(ConnectionType::WALL), agent.try_move_to(&maze, [0, 1].into()));
+    assert_eq!(MoveResult::OffMaze, agent.try_move_to(&maze, [2, 0].into()));
+    assert_eq!(MoveResult::Moved(CoordinateTuplet([2, 2])), agent.try_move_to(&maze, [2, 2].into()));
+}
+
+#[test]
+fn test_agent_moves_in_hex_space() {
+    use crate::implm::buffer::VecBuffer;
+    use crate::implm::cell::hex::HexCellValue;
+    use crate::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+
+    let mut maze = HexSpaceHexCellMazeCoordinator::<VecBuffer<HexCellValue>>::builder(HexCoordinateSpace::new_checked(3, 3)).build();
+
+    let start = HexCoordinate { column: 0, row: 1 };
+    let goal = HexCoordinate { column: 1, row: 0 };
+
+    maze.make_passage_between(start, goal);
+
+    let mut agent = Agent::new(start).with_goal(goal);
+
+    assert_eq!(MoveResult::Blocked(ConnectionType::UNVISITED), agent.try_move(&maze, HexDirection::SOUTH));
+    assert_eq!(MoveResult::ReachedGoal(goal), agent.try_move(&maze, HexDirection::NORTH_EAST));
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+#![cfg(feature = "animate")]
+
+use std::io::{Result, Write};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::ObservedBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::text::{BlockCellTextTheme, BoxSpaceBlockCellTextMazeExporter, TerminalAnimation};
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::generate::DefaultMazeGenerator;
+
+type ObservedMaze = BoxSpaceBlockCellMazeCoordinator<ObservedBuffer<BlockCellValue>, 2>;
+
+/// A writer that can be read back after being handed off to an animation.
+#[derive(Clone, Default)]
+struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Play `output` back on a pretend terminal that only understands the escape codes the animation
+/// uses, and return what ends up on the screen.
+fn play_back(output: &str) -> String {
+    let mut screen: Vec<Vec<char>> = vec![Vec::new()];
+    let [mut x, mut y] = [0, 0];
+
+    let mut chars = output.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '\x1b' => {
+                assert_eq!(Some('['), chars.next());
+
+                let mut count = String::new();
+
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    count.push(digit);
+                }
+
+                let count: usize = count.parse().unwrap();
+
+                match chars.next() {
+                    Some('A') => y -= count,
+                    Some('B') => y += count,
+                    Some('C') => x += count,
+                    other => panic!("Unexpected escape code {:?}", other),
+                }
+            },
+            '\r' => x = 0,
+            '\n' => { x = 0; y += 1 },
+            char => {
+                while screen.len() <= y {
+                    screen.push(Vec::new());
+                }
+
+                let line = &mut screen[y];
+
+                if line.len() <= x {
+                    line.resize(x + 1, ' ');
+                }
+
+                line[x] = char;
+                x += 1;
+            },
+        }
+    }
+
+    return screen.iter().take(y).map(|line| line.iter().collect::<String>() + "\n").collect()
+}
+
+#[test]
+fn test_animation_ends_on_the_finished_maze() {
+    let output = SharedOutput::default();
+
+    let exporter = BoxSpaceBlockCellTextMazeExporter::builder().theme(BlockCellTextTheme::Ascii).build();
+
+    let mut maze = ObservedMaze::builder(BoxCoordinateSpace::new_checked([5, 4])).build();
+
+    let had_observer = TerminalAnimation::builder()
+        .output(output.clone())
+        .frame_delay(Duration::ZERO)
+        .exporter(exporter)
+        .attach(&mut maze);
+
+    assert!(had_observer == false);
+
+    HuntAndKillGenerator::generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4947));
+    maze.buffer().flush();
+
+    let mut expected = Vec::new();
+    BoxSpaceBlockCellTextMazeExporter::builder().theme(BlockCellTextTheme::Ascii).build().export(&maze, &mut expected).unwrap();
+
+    let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+
+    assert_eq!(String::from_utf8(expected).unwrap(), play_back(&output));
+}
+
+#[test]
+fn test_animation_draws_nothing_until_a_cell_changes() {
+    let output = SharedOutput::default();
+
+    let mut maze = ObservedMaze::builder(BoxCoordinateSpace::new_checked([2, 1])).build();
+
+    TerminalAnimation::builder().output(output.clone()).frame_delay(Duration::ZERO).attach(&mut maze);
+
+    assert!(output.0.lock().unwrap().is_empty());
+
+    maze.make_passage([0, 0].into());
+    maze.buffer().flush();
+
+    let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+
+    // The passage is drawn along with the rest of the maze, then the walls around it one by one
+    assert!(output.starts_with("..........\n..  ......\n..........\n\x1b["));
+    assert_eq!("..██......\n██  ██....\n..██......\n", play_back(&output));
+}
\ No newline at end of file
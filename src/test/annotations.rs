@@ -0,0 +1,120 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::implm::presets::Inline2DMazeBuilder;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::mark::{Annotation, Annotations};
+use crate::util::{crop, rotate90};
+
+type BlockMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct Door;
+
+impl Annotation for Door {
+    fn glyph(&self) -> Option<char> {
+        Some('D')
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct Loot(&'static str);
+
+impl Annotation for Loot {}
+
+fn hash_of(annotations: &Annotations<CoordinateTuplet<2>>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    annotations.hash(&mut hasher);
+
+    return hasher.finish()
+}
+
+#[test]
+fn test_annotations_are_typed() {
+    let mut annotations = Annotations::<CoordinateTuplet<2>>::new();
+
+    assert_eq!(None, annotations.insert([0, 0].into(), Loot("sword")));
+    assert_eq!(Some(Loot("sword")), annotations.insert([0, 0].into(), Loot("shield")));
+    annotations.insert([0, 0].into(), Door);
+
+    assert_eq!(Some(&Loot("shield")), annotations.get([0, 0].into()));
+    assert_eq!(Some(&Door), annotations.get([0, 0].into()));
+    assert_eq!(2, annotations.len());
+
+    annotations.get_mut::<Loot>([0, 0].into()).unwrap().0 = "bow";
+    assert_eq!(Some(Loot("bow")), annotations.remove([0, 0].into()));
+
+    annotations.remove_all([0, 0].into());
+    assert!(annotations.is_empty());
+}
+
+#[test]
+fn test_equality_ignores_insertion_order() {
+    let mut first = Annotations::<CoordinateTuplet<2>>::new();
+    first.insert([1, 1].into(), Door);
+    first.insert([2, 1].into(), Loot("gold"));
+
+    let mut second = Annotations::<CoordinateTuplet<2>>::new();
+    second.insert([2, 1].into(), Loot("gold"));
+    second.insert([1, 1].into(), Door);
+    second.insert([3, 3].into(), Door);
+    second.remove::<Door>([3, 3].into());
+
+    assert!(first == second);
+    assert_eq!(hash_of(&first), hash_of(&second));
+
+    second.insert([2, 1].into(), Loot("silver"));
+
+    assert!(first != second);
+}
+
+#[test]
+fn test_annotations_follow_transformations() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+
+    maze.annotations_mut().insert([0, 0].into(), Door);
+    maze.annotations_mut().insert([2, 1].into(), Loot("gem"));
+    maze.set_start([2, 0].into());
+
+    // A quarter turn clockwise moves the top-left corner to the top-right
+    let rotated = rotate90(&maze);
+
+    assert_eq!(Some(&Door), rotated.annotations().get([1, 0].into()));
+    assert_eq!(Some(&Loot("gem")), rotated.annotations().get([0, 2].into()));
+    assert_eq!(Some([1, 2].into()), rotated.start());
+
+    let cropped = crop(&maze, &maze.coord_space().sub_region_checked([1, 1].into(), [2, 1]));
+
+    assert_eq!(1, cropped.annotations().len());
+    assert_eq!(Some(&Loot("gem")), cropped.annotations().get([1, 0].into()));
+    assert_eq!(None, cropped.start());
+}
+
+#[test]
+fn test_text_export_draws_glyphs() {
+    let mut maze = BlockMaze::from_walls(&[
+        [true, true, true, true, true],
+        [true, false, false, false, true],
+        [true, true, true, true, true],
+    ]);
+
+    maze.set_start([1, 1].into());
+    maze.annotations_mut().insert([2, 1].into(), Door);
+    maze.annotations_mut().insert([3, 1].into(), Loot("key"));
+
+    let export = |draw_annotations| {
+        let mut text = Vec::new();
+        BoxSpaceBlockCellTextMazeExporter::builder().chars_per_cell_horizontally_checked(1).draw_annotations(draw_annotations).build().export(&maze, &mut text).unwrap();
+
+        String::from_utf8(text).unwrap().replace("\r\n", "\n")
+    };
+
+    assert_eq!("█████\n█SD █\n█████\n", export(true));
+    assert_eq!("█████\n█S  █\n█████\n", export(false));
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use fluent_asserter::prelude::*;
+
+use crate::implm::point::masked::BoxPointMask;
+use crate::interface::point::CoordinateSpace;
+
+#[test]
+fn test_ascii_mask() {
+    let mask = BoxPointMask::from_ascii("
+        #..#
+        ....
+        #.
+    ");
+
+    assert_eq!([4, 3], mask.space().dimensions().map(usize::from));
+    assert_eq!(7, usize::from(mask.point_count()));
+
+    assert!(mask.contains([0, 0].into()) == false);
+    assert!(mask.contains([1, 0].into()));
+    assert!(mask.contains([0, 1].into()));
+    assert!(mask.contains([1, 2].into()));
+
+    // Short lines are padded with outside points
+    assert!(mask.contains([2, 2].into()) == false);
+    assert!(mask.contains([3, 2].into()) == false);
+}
+
+#[test]
+fn test_ascii_mask_matches_grid() {
+    let from_ascii = BoxPointMask::from_ascii(".#.\r\n...\r\n");
+    let from_grid = BoxPointMask::from_grid(&[[true, false, true], [true, true, true]]);
+
+    assert_eq!(from_grid.space().dimensions(), from_ascii.space().dimensions());
+    assert!(from_grid.space().iter().all(|pt| from_grid.contains(pt) == from_ascii.contains(pt)));
+}
+
+#[test]
+fn test_ascii_mask_with_unexpected_character() {
+    assert_that_code!(|| BoxPointMask::from_ascii("..\n.x")).panics().with_having_message("Unexpected character 'x' in the mask (expected '.' or '#')");
+}
+
+#[test]
+fn test_empty_ascii_mask() {
+    assert_that_code!(|| BoxPointMask::from_ascii("##\n##")).panics().with_having_message("The mask must include at least one point");
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+#![cfg(feature = "testing")]
+
+use proptest::prelude::*;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::export::binary::BoxSpaceBinaryMazeExporter;
+use crate::implm::import::binary::BoxSpaceBinaryMazeImporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::import::MazeImporter;
+use crate::interface::point::CoordinateSpace;
+use crate::testing::box_coordinate_spaces;
+
+type BlockMaze<const DIMENSION: usize> = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, DIMENSION>;
+type InlineMaze<const DIMENSION: usize> = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<DIMENSION>>, DIMENSION>;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(50))]
+
+    #[test]
+    fn test_binary_block_cell_round_trip_2d(maze in block_cell_mazes::<2>()) {
+        assert_block_cell_round_trip(&maze)?;
+    }
+
+    #[test]
+    fn test_binary_block_cell_round_trip_3d(maze in block_cell_mazes::<3>()) {
+        assert_block_cell_round_trip(&maze)?;
+    }
+
+    #[test]
+    fn test_binary_inline_cell_round_trip_2d(maze in inline_cell_mazes::<2>()) {
+        assert_inline_cell_round_trip(&maze)?;
+    }
+
+    #[test]
+    fn test_binary_inline_cell_round_trip_3d(maze in inline_cell_mazes::<3>()) {
+        assert_inline_cell_round_trip(&maze)?;
+    }
+}
+
+fn assert_block_cell_round_trip<const DIMENSION: usize>(maze: &BlockMaze<DIMENSION>) -> Result<(), TestCaseError> {
+    let mut bytes = Vec::new();
+    BoxSpaceBinaryMazeExporter::new().export(maze, &mut bytes).unwrap();
+
+    let imported: BlockMaze<DIMENSION> = BoxSpaceBinaryMazeImporter::new().import(&mut bytes.as_slice()).unwrap();
+
+    prop_assert_eq!(maze.coord_space().dimensions(), imported.coord_space().dimensions());
+    prop_assert_eq!(maze.scale_factors(), imported.scale_factors());
+    prop_assert_eq!(maze.padding(), imported.padding());
+
+    for cell_loc in BoxCoordinateSpace::new(maze.get_full_dimensions()).iter() {
+        prop_assert_eq!(maze.get_cell_value(BlockCellLocation(cell_loc)), imported.get_cell_value(BlockCellLocation(cell_loc)));
+    }
+
+    return Ok(())
+}
+
+fn assert_inline_cell_round_trip<const DIMENSION: usize>(maze: &InlineMaze<DIMENSION>) -> Result<(), TestCaseError> {
+    let mut bytes = Vec::new();
+    BoxSpaceBinaryMazeExporter::new().export(maze, &mut bytes).unwrap();
+
+    let imported: InlineMaze<DIMENSION> = BoxSpaceBinaryMazeImporter::new().import(&mut bytes.as_slice()).unwrap();
+
+    prop_assert_eq!(maze.coord_space().dimensions(), imported.coord_space().dimensions());
+
+    for pt in maze.coord_space().iter() {
+        prop_assert_eq!(maze.get(pt), imported.get(pt));
+    }
+
+    return Ok(())
+}
+
+/// Block cell mazes with random scale factors, padding, and cell values (which needn't make a valid
+/// maze).
+fn block_cell_mazes<const DIMENSION: usize>() -> impl Strategy<Value = BlockMaze<DIMENSION>> {
+    (box_coordinate_spaces::<DIMENSION>(5), std::array::from_fn::<_, DIMENSION, _>(|_| 1..4usize), std::array::from_fn::<_, DIMENSION, _>(|_| [0..3usize, 0..3usize]))
+        .prop_flat_map(|(space, scale_factors, padding)| {
+            let cells = BlockMaze::<DIMENSION>::builder(space).scale_factors_checked(scale_factors).padding(padding).cells_required();
+
+            (Just(space), Just(scale_factors), Just(padding), proptest::collection::vec(any::<BlockCellValue>(), cells.get()))
+        })
+        .prop_map(|(space, scale_factors, padding, values)| {
+            let mut maze = BlockMaze::<DIMENSION>::builder(space).scale_factors_checked(scale_factors).padding(padding).build();
+
+            for (cell_loc, value) in BoxCoordinateSpace::new(maze.get_full_dimensions()).iter().zip(values) {
+                *maze.get_cell_value_mut(BlockCellLocation(cell_loc)) = value;
+            }
+
+            maze
+        })
+}
+
+/// Inline cell mazes with random cell values (which needn't make a valid maze).
+fn inline_cell_mazes<const DIMENSION: usize>() -> impl Strategy<Value = InlineMaze<DIMENSION>> {
+    box_coordinate_spaces::<DIMENSION>(5)
+        .prop_flat_map(|space| (Just(space), proptest::collection::vec(any::<InlineCellValue<DIMENSION>>(), usize::from(space.logical_size()))))
+        .prop_map(|(space, values)| {
+            let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<DIMENSION>>, DIMENSION>::new(space).build();
+
+            for (pt, value) in space.iter().zip(values) {
+                maze.set(pt, value);
+            }
+
+            maze
+        })
+}
\ No newline at end of file
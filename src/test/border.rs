@@ -0,0 +1,67 @@
+use fluent_asserter::prelude::*;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::util::{apply_border, apply_solid_border, Border, BorderKind};
+
+type BlockMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[test]
+fn test_border_matches_solid_border_by_default() {
+    let mut solid = BlockMaze::builder(BoxCoordinateSpace::new_checked([4, 3])).build();
+    let mut border = BlockMaze::builder(BoxCoordinateSpace::new_checked([4, 3])).build();
+
+    apply_solid_border(&mut solid);
+    Border::default().apply(&mut border);
+
+    assert_eq!(format!("{:?}", solid), format!("{:?}", border));
+}
+
+#[test]
+fn test_block_cell_border_thickness_and_gaps() {
+    let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([3, 3])).padding([[2, 2], [2, 2]]).build();
+
+    Border::builder()
+        .sides(&[BoxDirection::WEST, BoxDirection::EAST])
+        .thickness(2)
+        .kind(BorderKind::Wall)
+        .gap(BoxDirection::WEST, [0, 1].into())
+        .build()
+        .apply(&mut maze);
+
+    for y in 0..9 {
+        let expected = if y == 4 { BlockCellPrimaryValue::PASSAGE } else { BlockCellPrimaryValue::WALL };
+
+        assert_eq!(expected, maze.get_cell_value([0, y].into()).cell_type);
+        assert_eq!(expected, maze.get_cell_value([1, y].into()).cell_type);
+        assert_eq!(BlockCellPrimaryValue::WALL, maze.get_cell_value([8, y].into()).cell_type);
+        assert_eq!(BlockCellPrimaryValue::UNVISITED, maze.get_cell_value([4, y].into()).cell_type);
+    }
+
+    // The gap's point itself is left alone
+    assert_eq!(BlockCellPrimaryValue::UNVISITED, maze.get([0, 1].into()).cell_type);
+}
+
+#[test]
+fn test_inline_cell_border_gaps() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    Border::builder().gap(BoxDirection::SOUTH, [2, 2].into()).build().apply(&mut maze);
+
+    assert_eq!([InlineCellValueEdge::UNVISITED, InlineCellValueEdge::PASSAGE], maze.get([2, 2].into()).edges[1]);
+    assert_eq!([InlineCellValueEdge::UNVISITED, InlineCellValueEdge::BOUNDARY], maze.get([1, 2].into()).edges[1]);
+    assert_eq!([InlineCellValueEdge::BOUNDARY, InlineCellValueEdge::UNVISITED], maze.get([1, 0].into()).edges[1]);
+
+    apply_border(&mut maze, &[BoxDirection::NORTH], 1);
+
+    assert_that_code!(|| {
+        let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+        Border::builder().gap(BoxDirection::NORTH, [1, 1].into()).build().apply(&mut maze)
+    }).panics().with_message("The point (1, 1) is not on the side of the maze its gap is on");
+}
\ No newline at end of file
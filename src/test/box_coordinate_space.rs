@@ -57,4 +57,30 @@ fn test_adjacency() {
     assert!(!space.are_adjacent([0, 0, 0].into(), [8, 0, 0].into()));
     assert!(!space.are_adjacent([0, 0, 0].into(), [0, 8, 0].into()));
     assert!(!space.are_adjacent([0, 0, 0].into(), [0, 0, 8].into()));
+}
+
+#[test]
+fn test_wrapping_adjacency() {
+    let space = BoxCoordinateSpace::new_wrapping_checked([9, 9, 2], [true, false, true]);
+
+    assert!(space.are_adjacent([0, 0, 0].into(), [8, 0, 0].into()));
+    assert!(space.are_adjacent([8, 4, 1].into(), [0, 4, 1].into()));
+    assert!(space.is_wrapped_between([8, 4, 1].into(), [0, 4, 1].into()));
+    assert!(!space.is_wrapped_between([7, 4, 1].into(), [8, 4, 1].into()));
+
+    // Only wrapping axes wrap
+    assert!(!space.are_adjacent([0, 0, 0].into(), [0, 8, 0].into()));
+
+    // An axis of length 2 is already adjacent end-to-end, so it doesn't wrap
+    assert_eq!(Some(2), space.axis_of_adjacency([0, 0, 0].into(), [0, 0, 1].into()));
+    assert!(!space.is_wrapped_between([0, 0, 0].into(), [0, 0, 1].into()));
+    assert_eq!(5, space.neighbours_of_pt([0, 4, 0].into()).len());
+
+    assert!(space.is_adjacent_to_edge([4, 0, 0].into()));
+    assert!(space.is_adjacent_to_edge([0, 4, 0].into()));
+
+    let torus = BoxCoordinateSpace::new_wrapping_checked([9, 9], [true, true]);
+
+    assert!(torus.iter().all(|pt| torus.is_adjacent_to_edge(pt) == false));
+    assert!(torus.iter().all(|pt| torus.neighbours_of_pt(pt).len() == 4));
 }
\ No newline at end of file
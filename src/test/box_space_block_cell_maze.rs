@@ -1,12 +1,14 @@
 use fluent_asserter::prelude::*;
 
 use crate::implm::buffer::VecBuffer;
-use crate::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue};
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue, BlockCellPrimaryValue};
 use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
 use crate::implm::point::boxy::BoxCoordinateSpace;
 use crate::interface::buffer::MazeBuffer;
 use crate::interface::cell::CellID;
 use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::path::Path;
 
 // We test both at a coordinator level and a buffer level
 // (i.e. ignoring and taking into account the resolution)
@@ -1248,4 +1250,104 @@ fn test_passage_between_with_scaling_without_padding() {
             }
         }
     }
+}
+
+#[test]
+fn test_from_walls() {
+    let walls = [
+        [true, true, true, true],
+        [false, false, false, true],
+        [true, false, true, true],
+    ];
+
+    let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::from_walls(&walls);
+
+    assert_eq!([4, 3], maze.get_full_dimensions().map(usize::from));
+    assert_eq!([1, 1], maze.scale_factors().map(usize::from));
+    assert_eq!([[0, 0], [0, 0]], maze.padding());
+
+    for (y, row) in walls.iter().enumerate() {
+        for (x, is_wall) in row.iter().enumerate() {
+            let expected = if *is_wall { BlockCellPrimaryValue::WALL } else { BlockCellPrimaryValue::PASSAGE };
+
+            assert_eq!(expected, maze.get([x, y].into()).cell_type);
+        }
+    }
+}
+
+#[test]
+fn test_from_walls_ragged() {
+    let walls = vec![vec![true, true], vec![true]];
+
+    assert_that_code!(|| BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::from_walls(&walls)).panics().with_having_message("All rows must be the same length");
+}
+
+#[test]
+fn test_map_pt_to_cell_id() {
+    let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 3>::builder(BoxCoordinateSpace::new_checked([3, 4, 2]))
+        .scale_factors_checked([2, 3, 2])
+        .spacing_checked(1, vec![1, 4, 2])
+        .padding([[1, 0], [0, 2], [1, 1]])
+        .build();
+
+    let [width, length, _] = maze.get_full_dimensions().map(usize::from);
+
+    assert_eq!([1, width, width * length], maze.strides());
+
+    for pt in maze.coord_space().iter() {
+        let cell_loc = maze.map_pt_to_cell_loc(pt);
+
+        assert_eq!(CellID(cell_loc[0] + cell_loc[1] * width + cell_loc[2] * width * length), maze.map_pt_to_cell_id(pt));
+    }
+}
+
+#[test]
+fn test_map_path_to_cell_path() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_wrapping_checked([3, 2], [true, false]))
+        .scale_factors_checked([3, 2])
+        .padding([[1, 1], [0, 1]])
+        .build();
+
+    maze.portals_mut().insert([1, 0].into(), [2, 1].into());
+
+    // Around the wrap, down a row, then through the portal
+    let path = Path::from_vec(vec![[0, 0].into(), [2, 0].into(), [2, 1].into(), [1, 0].into()]);
+
+    let cells = maze.map_path_to_cell_path(&path).0;
+
+    let expected: Vec<BlockCellLocation<2>> = vec![
+        [1, 0].into(), [0, 0].into(), [8, 0].into(), [7, 0].into(),
+        [7, 1].into(), [7, 2].into(),
+        [4, 0].into(),
+    ];
+
+    assert_eq!(expected, cells[..]);
+
+    // Every step between points runs along cells that make_passage_between() carves
+    for step in path[..].windows(2).take(2) {
+        maze.make_passage_between(step[0], step[1]);
+    }
+
+    assert!(cells[..6].iter().all(|cell| maze.get_cell_value(*cell).cell_type == BlockCellPrimaryValue::PASSAGE));
+}
+
+#[test]
+fn test_map_path_to_cell_path_panics_on_a_jump() {
+    let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    let path = Path::from_vec(vec![[0, 0].into(), [2, 2].into()]);
+
+    assert_that_code!(|| maze.map_path_to_cell_path(&path)).panics();
+}
+
+#[test]
+fn test_display() {
+    let walls = [
+        [true, true, true],
+        [false, false, true],
+    ];
+
+    let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::from_walls(&walls);
+
+    assert_eq!("██████\n    ██", maze.to_string().replace("\r\n", "\n"));
 }
\ No newline at end of file
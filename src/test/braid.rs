@@ -0,0 +1,64 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::{Inline2DMaze, Inline2DMazeBuilder};
+use crate::interface::generate::MazeGenerator;
+use crate::util::{braid, dead_ends_by_depth, BraidPolicy};
+
+fn generate(rng: &mut ChaCha8Rng) -> Inline2DMaze {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([16, 16])).build();
+
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, rng);
+
+    return maze
+}
+
+#[test]
+fn test_braid_everything_removes_every_dead_end() {
+    let mut rng = ChaCha8Rng::seed_from_u64(4964);
+    let mut maze = generate(&mut rng);
+
+    let removed = braid(&mut maze, 1.0, BraidPolicy::Random, &mut rng);
+
+    assert!(removed > 0);
+    assert!(dead_ends_by_depth(&maze).is_empty());
+}
+
+#[test]
+fn test_braid_nothing_changes_nothing() {
+    let mut rng = ChaCha8Rng::seed_from_u64(4964);
+    let mut maze = generate(&mut rng);
+    let before = maze.clone();
+
+    assert_eq!(0, braid(&mut maze, 0.0, BraidPolicy::Spread, &mut rng));
+    assert_eq!(before, maze);
+}
+
+#[test]
+fn test_deeper_than_keeps_short_dead_ends() {
+    let mut rng = ChaCha8Rng::seed_from_u64(4964);
+    let mut maze = generate(&mut rng);
+
+    let short_before: Vec<_> = dead_ends_by_depth(&maze).into_iter().filter(|(_, depth)| *depth <= 2).collect();
+
+    braid(&mut maze, 1.0, BraidPolicy::DeeperThan(2), &mut rng);
+
+    let after = dead_ends_by_depth(&maze);
+
+    assert!(after.iter().all(|(_, depth)| *depth <= 2));
+    assert!(after.len() * 2 >= short_before.len(), "Most of the short dead ends should be kept");
+}
+
+#[test]
+fn test_deepest_first_removes_the_deepest() {
+    let mut rng = ChaCha8Rng::seed_from_u64(4964);
+    let mut maze = generate(&mut rng);
+
+    let deepest = dead_ends_by_depth(&maze)[0];
+
+    braid(&mut maze, 0.1, BraidPolicy::DeepestFirst, &mut rng);
+
+    assert!(dead_ends_by_depth(&maze).iter().all(|(pt, _)| *pt != deepest.0));
+}
\ No newline at end of file
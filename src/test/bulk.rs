@@ -0,0 +1,57 @@
+use std::num::NonZeroUsize;
+
+use crate::implm::buffer::{ArrayBuffer, ChunkedBuffer, RleBuffer, SyncBuffer, VecBuffer};
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+
+fn cell(cell_type: BlockCellPrimaryValue) -> BlockCellValue {
+    BlockCellValue { cell_type, ..Default::default() }
+}
+
+/// Apply the same sequence of bulk operations to a buffer, returning its final contents.
+fn apply_bulk_operations<Buffer: MazeBuffer<BlockCellValue>>() -> Vec<BlockCellValue> {
+    let mut buffer = Buffer::new(NonZeroUsize::new(20).unwrap());
+
+    buffer.fill(cell(BlockCellPrimaryValue::WALL));
+    buffer.set_range(CellID(3)..CellID(9), cell(BlockCellPrimaryValue::PASSAGE));
+    buffer.set(CellID(5), cell(BlockCellPrimaryValue::BOUNDARY));
+    buffer.set_range(CellID(8)..CellID(12), cell(BlockCellPrimaryValue::PASSAGE));
+    buffer.set_range(CellID(15)..CellID(20), BlockCellValue::default());
+    buffer.set_range(CellID(10)..CellID(10), cell(BlockCellPrimaryValue::BOUNDARY));
+
+    assert_eq!(usize::from(buffer.cell_count()), buffer.get_slice(CellID(0)..CellID(20)).len());
+
+    return buffer.get_slice(CellID(0)..CellID(20)).into_owned()
+}
+
+#[test]
+fn test_bulk_operations_agree() {
+    let expected: Vec<BlockCellValue> = (0..20).map(|i| match i {
+        5 => cell(BlockCellPrimaryValue::BOUNDARY),
+        3..=11 => cell(BlockCellPrimaryValue::PASSAGE),
+        15.. => BlockCellValue::default(),
+        _ => cell(BlockCellPrimaryValue::WALL),
+    }).collect();
+
+    assert_eq!(expected, apply_bulk_operations::<VecBuffer<BlockCellValue>>());
+    assert_eq!(expected, apply_bulk_operations::<ArrayBuffer<BlockCellValue, 32>>());
+    assert_eq!(expected, apply_bulk_operations::<ChunkedBuffer<BlockCellValue, 6>>());
+    assert_eq!(expected, apply_bulk_operations::<RleBuffer<BlockCellValue>>());
+    assert_eq!(expected, apply_bulk_operations::<SyncBuffer<BlockCellValue, 7>>());
+}
+
+#[test]
+fn test_rle_set_range_merges_runs() {
+    let mut buffer = RleBuffer::<BlockCellValue>::new(NonZeroUsize::new(20).unwrap());
+
+    buffer.set_range(CellID(2)..CellID(6), cell(BlockCellPrimaryValue::WALL));
+    buffer.set_range(CellID(10)..CellID(14), cell(BlockCellPrimaryValue::WALL));
+    assert_eq!(5, buffer.run_count());
+
+    buffer.set_range(CellID(4)..CellID(12), cell(BlockCellPrimaryValue::WALL));
+    assert_eq!(3, buffer.run_count());
+
+    buffer.fill(BlockCellValue::default());
+    assert_eq!(1, buffer.run_count());
+}
\ No newline at end of file
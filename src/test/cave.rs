@@ -0,0 +1,100 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::region::SubRegionMazeCoordinator;
+use crate::implm::generate::{CaveGenerator, HuntAndKillGenerator};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::cell::{CellValue, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::util::wavefronts;
+
+type Maze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+fn maze(dimensions: [usize; 2]) -> Maze {
+    Maze::builder(BoxCoordinateSpace::new_checked(dimensions)).scale_factors_checked([1, 1]).build()
+}
+
+fn passages(maze: &Maze) -> Vec<crate::implm::point::boxy::CoordinateTuplet<2>> {
+    maze.iter().filter(|(_, value)| value.state() == ConnectionType::PASSAGE).map(|(pt, _)| pt).collect()
+}
+
+#[test]
+fn test_caves_are_connected() {
+    for seed in 0..5 {
+        let mut maze = maze([30, 20]);
+
+        CaveGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+
+        // Every point is either floor or wall
+        assert!(maze.iter().all(|(_, value)| matches!(value.cell_type, BlockCellPrimaryValue::PASSAGE | BlockCellPrimaryValue::WALL)));
+
+        let passages = passages(&maze);
+        let reached: usize = wavefronts(&maze, passages[0]).iter().map(Vec::len).sum();
+
+        assert!(passages.len() > 1);
+        assert_eq!(passages.len(), reached);
+    }
+}
+
+#[test]
+fn test_caves_have_loops() {
+    let mut maze = maze([30, 20]);
+
+    CaveGenerator::new().fill(0.7).generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4957));
+
+    let passage_count = passages(&maze).len();
+    let connection_count = maze.connections().filter(|(_, _, connection)| *connection == ConnectionType::PASSAGE).count();
+
+    // A perfect maze would have exactly one fewer connection than points
+    assert!(connection_count >= passage_count);
+}
+
+#[test]
+fn test_extreme_fills() {
+    let mut full = maze([10, 10]);
+    CaveGenerator::new().fill(1.0).generate_with_rng(&mut full, &mut ChaCha8Rng::seed_from_u64(0));
+
+    assert_eq!(100, passages(&full).len());
+
+    // There is always at least one open point
+    let mut empty = maze([10, 10]);
+    CaveGenerator::new().fill(0.0).generate_with_rng(&mut empty, &mut ChaCha8Rng::seed_from_u64(0));
+
+    assert_eq!(1, passages(&empty).len());
+}
+
+#[test]
+fn test_small_caverns_are_filled_in() {
+    let mut maze = maze([30, 20]);
+
+    CaveGenerator::new().min_region_size(usize::MAX).smoothing_iterations(0).generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(1));
+
+    let passages = passages(&maze);
+    let reached: usize = wavefronts(&maze, passages[0]).iter().map(Vec::len).sum();
+
+    assert_eq!(passages.len(), reached);
+}
+
+#[test]
+fn test_caves_mix_with_corridors() {
+    let mut maze = maze([20, 10]);
+
+    CaveGenerator::new().generate_with_rng(&mut SubRegionMazeCoordinator::new_checked(&mut maze, [0, 0].into(), [10, 10]), &mut ChaCha8Rng::seed_from_u64(2));
+
+    // The other half is left alone, apart from the walls along the edge of the caves
+    assert!(maze.iter().filter(|(pt, _)| pt[0] > 10).all(|(_, value)| value.cell_type == BlockCellPrimaryValue::UNVISITED));
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut SubRegionMazeCoordinator::new_checked(&mut maze, [10, 0].into(), [10, 10]), &mut ChaCha8Rng::seed_from_u64(3));
+
+    assert!(maze.iter().all(|(_, value)| value.cell_type != BlockCellPrimaryValue::UNVISITED));
+}
+
+#[test]
+fn test_fill_out_of_range() {
+    assert_that_code!(|| CaveGenerator::new().fill(1.5)).panics().with_having_message("The fill must be between 0 and 1");
+}
\ No newline at end of file
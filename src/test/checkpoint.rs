@@ -0,0 +1,84 @@
+use crate::implm::generate::RecursiveBacktrackerProgress;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::Standard2DMaze;
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+fn maze() -> Standard2DMaze {
+    Standard2DMaze::builder(BoxCoordinateSpace::new_checked([12, 9])).build()
+}
+
+#[test]
+fn test_progress_visits_every_point() {
+    let mut maze = maze();
+    let mut progress = RecursiveBacktrackerProgress::new(4955);
+
+    assert!(progress.is_finished() == false);
+
+    while progress.step(&mut maze) == false {}
+
+    assert!(progress.is_finished());
+    assert!(progress.path().is_empty());
+    assert!(maze.coord_space().iter().all(|pt| maze.get(pt).is_fully_visited()));
+
+    // Further steps do nothing
+    assert!(progress.run(&mut maze, 10));
+}
+
+#[test]
+fn test_progress_is_independent_of_how_it_is_split() {
+    let mut all_at_once = maze();
+    RecursiveBacktrackerProgress::new(3).run(&mut all_at_once, usize::MAX);
+
+    let mut in_pieces = maze();
+    let mut progress = RecursiveBacktrackerProgress::new(3);
+
+    while progress.run(&mut in_pieces, 17) == false {}
+
+    assert_eq!(all_at_once, in_pieces);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_progress_resumes_from_checkpoint() {
+    use crate::implm::export::json::BoxSpaceBlockCellJsonMazeExporter;
+    use crate::implm::import::json::BoxSpaceBlockCellJsonMazeImporter;
+    use crate::interface::export::MazeExporter;
+    use crate::interface::import::MazeImporter;
+
+    let mut uninterrupted = maze();
+    RecursiveBacktrackerProgress::new(11).run(&mut uninterrupted, usize::MAX);
+
+    let mut maze = maze();
+    let mut progress = RecursiveBacktrackerProgress::new(11);
+    progress.run(&mut maze, 60);
+
+    let mut saved_maze = Vec::new();
+    let mut saved_progress = Vec::new();
+    BoxSpaceBlockCellJsonMazeExporter::new().export(&maze, &mut saved_maze).unwrap();
+    progress.save(&mut saved_progress).unwrap();
+
+    let mut resumed: Standard2DMaze = BoxSpaceBlockCellJsonMazeImporter::new().import(&mut saved_maze.as_slice()).unwrap();
+    let mut resumed_progress = RecursiveBacktrackerProgress::load(saved_progress.as_slice()).unwrap();
+
+    assert_eq!(progress, resumed_progress);
+
+    resumed_progress.run(&mut resumed, usize::MAX);
+
+    assert_eq!(uninterrupted, resumed);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_loading_rejects_mismatched_checkpoints() {
+    use crate::implm::point::boxy::CoordinateTuplet;
+
+    let mut saved = Vec::new();
+    let mut progress = RecursiveBacktrackerProgress::<CoordinateTuplet<2>>::new(0);
+    progress.run(&mut maze(), 5);
+    progress.save(&mut saved).unwrap();
+
+    assert!(RecursiveBacktrackerProgress::<CoordinateTuplet<3>>::load(saved.as_slice()).is_err());
+    assert!(RecursiveBacktrackerProgress::<CoordinateTuplet<2>>::load(&b"{\"format\": \"mazelib/box-space-block-cell\"}"[..]).is_err());
+}
\ No newline at end of file
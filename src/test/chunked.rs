@@ -0,0 +1,45 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::{ChunkedBuffer, VecBuffer};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::coordinate::masked::MaskedMazeCoordinator;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::point::masked::BoxPointMask;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+/// A small square in the corner of a much larger maze.
+fn get_corner_mask() -> BoxPointMask<2> {
+    BoxPointMask::from_fn(BoxCoordinateSpace::new_checked([64, 64]), |pt| pt[0] < 8 && pt[1] < 8)
+}
+
+#[test]
+fn test_masked_generation_allocates_few_chunks() {
+    let mask = get_corner_mask();
+
+    let maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<ChunkedBuffer<InlineCellValue<2>, 64>, 2>::new(BoxCoordinateSpace::new_checked([64, 64])).build();
+    let mut maze = MaskedMazeCoordinator::new(maze, &mask);
+
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(5));
+
+    // Each row of the maze is exactly one chunk, and the mask only covers the first 8 rows
+    assert_eq!(64, maze.maze().buffer().chunk_count());
+    assert_eq!(8, maze.maze().buffer().allocated_chunk_count());
+}
+
+#[test]
+fn test_matches_vec_buffer() {
+    let mut chunked = BoxSpaceInlineCellMazeCoordinatorBuilder::<ChunkedBuffer<InlineCellValue<2>, 7>, 2>::new(BoxCoordinateSpace::new_checked([9, 6])).build();
+    let mut vec = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([9, 6])).build();
+
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut chunked, &mut ChaCha8Rng::seed_from_u64(5));
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut vec, &mut ChaCha8Rng::seed_from_u64(5));
+
+    for pt in vec.coord_space().iter() {
+        assert_eq!(vec.get(pt), chunked.get(pt));
+    }
+}
\ No newline at end of file
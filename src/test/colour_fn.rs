@@ -0,0 +1,66 @@
+#![cfg(feature = "img")]
+
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use image::{ImageFormat, Rgba, RgbaImage};
+
+use crate::implm::cell::block::BlockCellPrimaryValue;
+use crate::implm::export::img::BoxSpaceImageMazeExporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::Standard2DMaze;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+
+fn export(exporter: &BoxSpaceImageMazeExporter, maze: &Standard2DMaze) -> RgbaImage {
+    let mut output = Cursor::new(Vec::new());
+    exporter.export(maze, &mut output).unwrap();
+
+    return image::load_from_memory_with_format(output.get_ref(), ImageFormat::Png).unwrap().to_rgba8()
+}
+
+#[test]
+fn test_colour_fn_sees_every_cell() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([3, 2])).build();
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+    // Colour each cell by its location, so the image can be checked against the maze
+    let exporter = BoxSpaceImageMazeExporter::new(ImageFormat::Png).with_colour_fn(|loc, value| {
+        let alpha = if value.cell_type == BlockCellPrimaryValue::PASSAGE { 255 } else { 128 };
+
+        Rgba([loc[0] as u8, loc[1] as u8, 0, alpha])
+    });
+
+    assert!(exporter.colour_fn().is_some());
+
+    let img = export(&exporter, &maze);
+
+    assert_eq!([img.width(), img.height()], maze.get_full_dimensions().map(|dim| dim.get() as u32));
+
+    for (x, y, pixel) in img.enumerate_pixels() {
+        let value = maze.get_cell_value([x as usize, y as usize].into());
+        let alpha = if value.cell_type == BlockCellPrimaryValue::PASSAGE { 255 } else { 128 };
+
+        assert_eq!(&Rgba([x as u8, y as u8, 0, alpha]), pixel);
+    }
+}
+
+#[test]
+fn test_colour_fn_replaces_default_colours() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([2, 1])).build();
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+    let loc = maze.map_pt_to_cell_loc([0, 0].into());
+    maze.get_cell_value_mut(loc).marked = true;
+
+    let zones = HashMap::from([(loc, Rgba([0, 255, 0, 255]))]);
+
+    let exporter = BoxSpaceImageMazeExporter::new(ImageFormat::Png)
+        .with_marked_colour(Rgba([255, 0, 0, 255]))
+        .with_colour_fn(move |loc, _| zones.get(&loc).copied().unwrap_or(Rgba([0, 0, 255, 255])));
+
+    let img = export(&exporter, &maze);
+
+    assert_eq!(&Rgba([0, 255, 0, 255]), img.get_pixel(loc[0] as u32, loc[1] as u32));
+    assert!(img.pixels().filter(|pixel| **pixel == Rgba([0, 0, 255, 255])).count() == img.pixels().count() - 1);
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::cell::block::BlockCellPrimaryValue;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::{Inline2DMazeBuilder, Standard2DMaze};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::DefaultMazeGenerator;
+use crate::util::{connect_components, convert_unvisited_cells_to_walls, convert_unvisited_points_to_walls, wavefronts};
+
+#[test]
+fn test_connect_components_joins_every_region() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([7, 7])).build();
+
+    // Three rooms in separate corners
+    for (from, to) in [([0, 0], [1, 0]), ([6, 0], [6, 1]), ([5, 6], [6, 6])] {
+        maze.make_passage_between(from.into(), to.into());
+    }
+
+    convert_unvisited_cells_to_walls(&mut maze);
+
+    assert_eq!(2, connect_components(&mut maze, &mut ChaCha8Rng::seed_from_u64(4952)));
+
+    let reached: usize = wavefronts(&maze, [0, 0].into()).iter().map(Vec::len).sum();
+    let passages = maze.iter().filter(|(_, value)| value.cell_type == BlockCellPrimaryValue::PASSAGE).count();
+
+    assert_eq!(passages, reached);
+}
+
+#[test]
+fn test_connect_components_carves_shortest_line() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([5, 3])).build();
+
+    maze.make_passage_between([0, 1].into(), [1, 1].into());
+    maze.make_passage_between([3, 1].into(), [4, 1].into());
+    convert_unvisited_points_to_walls(&mut maze);
+
+    assert_eq!(1, connect_components(&mut maze, &mut ChaCha8Rng::seed_from_u64(0)));
+
+    assert!(maze.is_passage_between([1, 1].into(), [2, 1].into()));
+    assert!(maze.is_passage_between([2, 1].into(), [3, 1].into()));
+
+    // Nothing else was carved
+    for pt in [[2, 0], [2, 2]] {
+        assert!(maze.is_wall_between([2, 1].into(), pt.into()));
+    }
+}
+
+#[test]
+fn test_connect_components_leaves_connected_maze_alone() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([6, 6])).build();
+    RecursiveBacktrackerGenerator::generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4952));
+
+    let before = maze.clone();
+
+    assert_eq!(0, connect_components(&mut maze, &mut ChaCha8Rng::seed_from_u64(0)));
+    assert!(before == maze);
+}
+
+#[test]
+fn test_connect_components_does_not_carve_boundaries() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([3, 1])).build();
+
+    maze.make_passage([0, 0].into());
+    maze.make_boundary([1, 0].into());
+    maze.make_passage([2, 0].into());
+
+    assert_eq!(0, connect_components(&mut maze, &mut ChaCha8Rng::seed_from_u64(0)));
+    assert!(maze.is_passage_between([0, 0].into(), [1, 0].into()) == false);
+}
\ No newline at end of file
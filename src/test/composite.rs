@@ -0,0 +1,80 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::composite::CompositeMazeCoordinator;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::point::composite::{CompositeCoordinateSpace, CompositeLayout, CompositePoint};
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+type Wing = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[test]
+fn test_generation_spans_every_region() {
+    let layout = CompositeLayout::new(vec![BoxCoordinateSpace::new_checked([4, 3]), BoxCoordinateSpace::new_checked([2, 2]), BoxCoordinateSpace::new_checked([3, 5])])
+        .join(CompositePoint::new(0, [3, 1].into()), CompositePoint::new(1, [0, 0].into()))
+        .join(CompositePoint::new(1, [1, 1].into()), CompositePoint::new(2, [1, 0].into()));
+
+    let mut maze = CompositeMazeCoordinator::new(&layout, |space| Wing::builder(space).build());
+
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(3));
+
+    assert!(maze.is_passage_between(CompositePoint::new(1, [0, 0].into()), CompositePoint::new(0, [3, 1].into())));
+    assert!(maze.is_passage_between(CompositePoint::new(1, [1, 1].into()), CompositePoint::new(2, [1, 0].into())));
+
+    // A perfect maze has exactly one fewer passage than points
+    let passages = maze.connections().filter(|(_, _, connection)| *connection == ConnectionType::PASSAGE).count();
+    assert_eq!(2 * (usize::from(maze.coord_space().logical_size()) - 1), passages);
+
+    for region in 0..3 {
+        let wing = maze.maze(region);
+
+        assert!(wing.coord_space().iter().all(|pt| wing.get(pt).cell_type == BlockCellPrimaryValue::PASSAGE));
+    }
+}
+
+#[test]
+fn test_junction_connections() {
+    let [west, east] = [CompositePoint::new(0, [0].into()), CompositePoint::new(1, [0].into())];
+
+    let layout = CompositeLayout::new(vec![BoxCoordinateSpace::new_checked([1]); 2]).join(west, east);
+
+    let mut maze = CompositeMazeCoordinator::new(&layout, |space| BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 1>::builder(space).build());
+
+    assert_eq!(ConnectionType::UNVISITED, maze.get_connection(west, east));
+
+    maze.make_wall_between(east, west);
+    assert_eq!(ConnectionType::WALL, maze.get_connection(west, east));
+    assert_eq!(BlockCellPrimaryValue::UNVISITED, maze.get(west).cell_type);
+
+    maze.make_passage_between(west, east);
+    assert_eq!(ConnectionType::PASSAGE, maze.get_connection(east, west));
+    assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get(east).cell_type);
+}
+
+#[test]
+fn test_composite_iteration() {
+    let layout = CompositeLayout::new(vec![BoxCoordinateSpace::new_checked([2, 1]), BoxCoordinateSpace::new_checked([1, 2])]);
+    let space = CompositeCoordinateSpace::new(&layout);
+
+    let expected = vec![CompositePoint::new(1, [0, 0].into()), CompositePoint::new(1, [0, 1].into())];
+
+    assert_eq!(expected, space.iter_from(CompositePoint::new(0, [0, 0].into())).skip(1).collect::<Vec<_>>());
+    assert_eq!(4, space.iter().count());
+}
+
+#[test]
+fn test_invalid_junctions() {
+    let layout = || CompositeLayout::new(vec![BoxCoordinateSpace::new_checked([2, 2])]);
+
+    assert_that_code!(|| layout().join(CompositePoint::new(0, [0, 0].into()), CompositePoint::new(0, [1, 0].into()))).panics().with_having_message("The points are already adjacent");
+    assert_that_code!(|| layout().join(CompositePoint::new(0, [0, 0].into()), CompositePoint::new(1, [0, 0].into()))).panics().with_having_message("The region does not exist");
+    assert_that_code!(|| CompositeLayout::<BoxCoordinateSpace<2>>::new(Vec::new())).panics().with_having_message("A composite layout must have at least one region");
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::coordinate::constrained::{ConstraintViolation, EdgeConstraints};
+use crate::implm::generate::{ConstrainedGenerator, HuntAndKillGenerator, NAryTreeGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::implm::presets::{Inline2DMazeBuilder, Standard2DMaze};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::util::wavefronts;
+use crate::test::assert_perfect;
+
+/// A corridor down the middle column that must be open, walled off from the column to its right.
+fn corridor_constraints() -> EdgeConstraints<CoordinateTuplet<2>> {
+    let mut constraints = EdgeConstraints::new();
+
+    for y in 0..6 {
+        constraints.require_passage([3, y].into(), [3, y + 1].into());
+        constraints.require_wall([3, y].into(), [4, y].into());
+    }
+
+    return constraints
+}
+
+
+#[test]
+fn test_constrained_generation_is_perfect_and_meets_constraints() {
+    for seed in 0..20 {
+        let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([7, 7])).build();
+        let mut generator = ConstrainedGenerator::new(HuntAndKillGenerator::new(), corridor_constraints());
+
+        generator.generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+
+        assert_eq!(Ok(()), corridor_constraints().check(&maze));
+        assert_perfect(&maze);
+
+        let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([7, 7])).build();
+        let mut generator = ConstrainedGenerator::new(RecursiveBacktrackerGenerator::new(), corridor_constraints());
+
+        generator.generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+
+        assert_eq!(Ok(()), corridor_constraints().check(&maze));
+        assert_perfect(&maze);
+    }
+}
+
+#[test]
+fn test_constrained_generation_rejoins_split_maze() {
+    // n-ary tree ignores boundaries, so it relies on the maze being joined back up
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([7, 7])).build();
+    let mut generator = ConstrainedGenerator::new(NAryTreeGenerator::new(), corridor_constraints());
+
+    generator.generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4954));
+
+    assert_eq!(Ok(()), corridor_constraints().check(&maze));
+    assert_perfect(&maze);
+}
+
+#[test]
+fn test_constrained_generation_on_block_maze() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([7, 7])).build();
+    let mut generator = ConstrainedGenerator::new(HuntAndKillGenerator::new(), corridor_constraints());
+
+    generator.generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4954));
+
+    assert_eq!(Ok(()), corridor_constraints().check(&maze));
+
+    let reached: usize = wavefronts(&maze, [0, 0].into()).iter().map(Vec::len).sum();
+
+    assert_eq!(49, reached);
+}
+
+#[test]
+fn test_constraint_check_reports_violations() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([2, 2])).build();
+    maze.make_passage_between([0, 0].into(), [0, 1].into());
+
+    let mut constraints = EdgeConstraints::new();
+    constraints.require_wall([0, 1].into(), [0, 0].into());
+
+    assert_eq!(Err(ConstraintViolation::UnwantedPassage(CoordinateTuplet([0, 1]), CoordinateTuplet([0, 0]))), constraints.check(&maze));
+
+    constraints.require_passage([0, 0].into(), [1, 0].into());
+
+    assert_eq!(Err(ConstraintViolation::MissingPassage(CoordinateTuplet([0, 0]), CoordinateTuplet([1, 0]))), constraints.check(&maze));
+}
+
+#[test]
+fn test_contradictory_constraints_panic() {
+    let mut constraints = EdgeConstraints::<CoordinateTuplet<2>>::new();
+    constraints.require_passage([0, 0].into(), [1, 0].into());
+
+    assert_that_code!(|| constraints.clone().require_wall([1, 0].into(), [0, 0].into())).panics().with_having_message("The edge between (1, 0) and (0, 0) is already required to be a passage");
+}
+
+#[test]
+fn test_non_adjacent_constraints_panic() {
+    let mut constraints = EdgeConstraints::<CoordinateTuplet<2>>::new();
+    constraints.require_wall([0, 0].into(), [2, 0].into());
+
+    assert_that_code!(|| {
+        let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+        ConstrainedGenerator::new(HuntAndKillGenerator::new(), constraints.clone()).generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+    }).panics().with_having_message("Constrained points (0, 0) and (2, 0) are not adjacent");
+}
\ No newline at end of file
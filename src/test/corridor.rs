@@ -0,0 +1,60 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::Inline2DMazeBuilder;
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::fill_unreachable_regions;
+
+type BlockMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[test]
+fn test_corridor_joins_rooms() {
+    let space = BoxCoordinateSpace::new_checked([7, 3]);
+    let mut maze = BlockMaze::builder(space).build();
+
+    for pt in space.iter() {
+        maze.make_wall(pt);
+    }
+
+    // Two rooms at opposite ends
+    maze.make_passage_between([0, 1].into(), [1, 1].into());
+    maze.make_passage_between([5, 1].into(), [6, 1].into());
+
+    let route = maze.carve_corridor([1, 1].into(), [5, 1].into()).unwrap();
+
+    assert_eq!(4, route.len());
+    assert!(route.contains([3, 1].into()));
+    assert!(maze.is_passage_between([3, 1].into(), [4, 1].into()));
+
+    assert_eq!(0, fill_unreachable_regions(&mut maze, [0, 1].into()));
+}
+
+#[test]
+fn test_corridor_goes_around_boundaries() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    maze.make_boundary_between([0, 0].into(), [1, 0].into());
+    maze.make_boundary_between([0, 1].into(), [1, 1].into());
+
+    let route = maze.carve_corridor([0, 0].into(), [1, 0].into()).unwrap();
+
+    assert_eq!(5, route.len());
+    assert!(maze.is_passage_between([0, 2].into(), [1, 2].into()));
+
+    maze.make_boundary_between([0, 2].into(), [1, 2].into());
+
+    assert!(maze.carve_corridor([0, 0].into(), [2, 2].into()).is_none());
+}
+
+#[test]
+fn test_corridor_to_itself() {
+    let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    let route = maze.carve_corridor([1, 1].into(), [1, 1].into()).unwrap();
+
+    assert_eq!(0, route.len());
+    assert!(maze.get([1, 1].into()).is_fully_visited());
+}
\ No newline at end of file
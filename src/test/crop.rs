@@ -0,0 +1,64 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::{apply_solid_border, crop};
+
+#[test]
+fn test_block_cell_crop() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([6, 5]))
+        .spacing_checked(0, vec![2, 3, 2, 4, 2])
+        .padding([[1, 2], [2, 1]])
+        .build();
+
+    apply_solid_border(&mut maze);
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(3));
+
+    let whole = crop(&maze, &maze.coord_space().sub_region_checked([0, 0].into(), [6, 5]));
+
+    assert_eq!(format!("{:?}", maze), format!("{:?}", whole));
+
+    let region = maze.coord_space().sub_region_checked([1, 0].into(), [3, 4]);
+    let cropped = crop(&maze, &region);
+
+    assert_eq!([[1, 3], [2, 1]], cropped.padding());
+    assert_eq!(vec![3, 2], cropped.spacing(0).into_iter().map(usize::from).collect::<Vec<_>>());
+
+    for pt in region.space().iter() {
+        assert_eq!(maze.get(region.to_parent(pt)), cropped.get(pt));
+
+        for neighbour in region.space().neighbours_of_pt(pt) {
+            assert_eq!(maze.get_connection(region.to_parent(pt), region.to_parent(neighbour)), cropped.get_connection(pt, neighbour));
+        }
+    }
+}
+
+#[test]
+fn test_inline_cell_crop() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([5, 5])).build();
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(8));
+
+    let region = maze.coord_space().sub_region_checked([2, 1].into(), [2, 3]);
+    let cropped = crop(&maze, &region);
+
+    for pt in region.space().iter() {
+        assert_eq!(maze.get(region.to_parent(pt)), cropped.get(pt));
+    }
+
+    assert_that_code!(|| {
+        let small = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([2, 2])).build();
+
+        let _ = crop(&small, &region);
+    }).panics().with_message("The region does not fit within the maze");
+}
\ No newline at end of file
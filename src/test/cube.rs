@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::cube::CubeCellValue;
+use crate::implm::coordinate::cube::CubeSpaceCubeCellMazeCoordinator;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::cube::{CubeCoordinate, CubeCoordinateSpace, CubeEdge, CubeFace};
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::test::assert_perfect;
+
+type CubeMaze = CubeSpaceCubeCellMazeCoordinator<VecBuffer<CubeCellValue>>;
+
+#[test]
+fn test_adjacency() {
+    for face_size in [1, 2, 5] {
+        let space = CubeCoordinateSpace::new_checked(face_size);
+
+        let mut edges_between_faces = 0;
+
+        for pt in space.iter() {
+            let neighbours = space.neighbours_of_pt(pt);
+
+            assert_eq!(4, neighbours.iter().collect::<HashSet<_>>().len(), "{:?} does not have four distinct neighbours", pt);
+
+            // Adjacency must be symmetric, and stepping back across the shared edge must return to
+            // the same point
+            for edge in CubeEdge::ALL {
+                let neighbour = space.neighbour(pt, edge);
+
+                assert!(space.contains(neighbour));
+                assert!(space.are_adjacent(neighbour, pt));
+
+                let back = space.edge_between(neighbour, pt).unwrap();
+                assert_eq!(pt, space.neighbour(neighbour, back));
+
+                if neighbour.face != pt.face {
+                    edges_between_faces += 1;
+                }
+            }
+        }
+
+        // A cube has 12 edges, each face_size squares long and seen from both sides
+        assert_eq!(12 * face_size * 2, edges_between_faces);
+    }
+
+    let space = CubeCoordinateSpace::new_checked(3);
+
+    assert_eq!(CubeCoordinate { face: CubeFace::TOP, x: 1, y: 2 }, space.neighbour(CubeCoordinate { face: CubeFace::FRONT, x: 1, y: 0 }, CubeEdge::UP));
+    assert_eq!(CubeCoordinate { face: CubeFace::BOTTOM, x: 1, y: 0 }, space.neighbour(CubeCoordinate { face: CubeFace::FRONT, x: 1, y: 2 }, CubeEdge::DOWN));
+    assert_eq!(CubeCoordinate { face: CubeFace::TOP, x: 1, y: 0 }, space.neighbour(CubeCoordinate { face: CubeFace::BACK, x: 1, y: 0 }, CubeEdge::UP));
+    assert_eq!(CubeCoordinate { face: CubeFace::TOP, x: 2, y: 1 }, space.neighbour(CubeCoordinate { face: CubeFace::RIGHT, x: 1, y: 0 }, CubeEdge::UP));
+    assert_eq!(CubeCoordinate { face: CubeFace::BOTTOM, x: 0, y: 1 }, space.neighbour(CubeCoordinate { face: CubeFace::LEFT, x: 1, y: 2 }, CubeEdge::DOWN));
+    assert_eq!(CubeCoordinate { face: CubeFace::LEFT, x: 0, y: 1 }, space.neighbour(CubeCoordinate { face: CubeFace::BACK, x: 2, y: 1 }, CubeEdge::RIGHT));
+}
+
+#[test]
+fn test_iteration_order() {
+    for face_size in [1, 2, 4] {
+        let space = CubeCoordinateSpace::new_checked(face_size);
+
+        let points: Vec<_> = space.iter().collect();
+
+        assert_eq!(usize::from(space.logical_size()), points.iter().collect::<HashSet<_>>().len());
+        assert_eq!(usize::from(space.logical_size()), points.len());
+
+        // Every point must be adjacent to a point already yielded
+        for (i, pt) in points.iter().enumerate().skip(1) {
+            assert!(points[..i].iter().any(|prev| space.are_adjacent(*prev, *pt)), "{:?} is not adjacent to any previous point", pt);
+        }
+
+        for (i, pt) in points.iter().enumerate() {
+            assert_eq!(points[i + 1..], space.iter_from(*pt).collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn test_generated_maze_is_perfect() {
+    let space = CubeCoordinateSpace::new_checked(5);
+
+    let mut maze = CubeMaze::builder(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+    assert_perfect(&maze);
+
+    let mut maze = CubeMaze::builder(space).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+    assert_perfect(&maze);
+}
\ No newline at end of file
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::delta::DeltaCellValue;
+use crate::implm::coordinate::delta::DeltaSpaceDeltaCellMazeCoordinator;
+use crate::implm::export::text::DeltaSpaceDeltaCellTextMazeExporter;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::delta::{DeltaCoordinate, DeltaCoordinateSpace, DeltaEdge};
+use crate::interface::export::DefaultMazeExporter;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::test::assert_perfect;
+
+type DeltaMaze = DeltaSpaceDeltaCellMazeCoordinator<VecBuffer<DeltaCellValue>>;
+
+#[test]
+fn test_adjacency() {
+    let space = DeltaCoordinateSpace::new_checked(5, 4);
+
+    assert_eq!(2, space.neighbours_of_pt(DeltaCoordinate { column: 0, row: 0 }).len());
+    assert_eq!(2, space.neighbours_of_pt(DeltaCoordinate { column: 1, row: 0 }).len());
+    assert_eq!(3, space.neighbours_of_pt(DeltaCoordinate { column: 1, row: 1 }).len());
+    assert_eq!(3, space.neighbours_of_pt(DeltaCoordinate { column: 2, row: 1 }).len());
+    assert_eq!(2, space.neighbours_of_pt(DeltaCoordinate { column: 4, row: 3 }).len());
+
+    // Adjacency must be symmetric, across opposite edges
+    for pt in space.iter() {
+        for edge in DeltaEdge::ALL {
+            if let Some(neighbour) = space.neighbour(pt, edge) {
+                assert_eq!(Some(edge.opposite()), space.edge_between(neighbour, pt));
+                assert!(pt.points_up() != neighbour.points_up());
+                assert!(space.are_adjacent(neighbour, pt));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_iteration_order() {
+    for [columns, rows] in [[1, 1], [1, 4], [2, 3], [5, 4], [6, 5]] {
+        let space = DeltaCoordinateSpace::new_checked(columns, rows);
+
+        let points: Vec<_> = space.iter().collect();
+
+        assert_eq!(usize::from(space.logical_size()), points.iter().collect::<HashSet<_>>().len());
+        assert_eq!(usize::from(space.logical_size()), points.len());
+
+        // Every point must be adjacent to a point already yielded, unless it can't be
+        if columns > 1 {
+            for (i, pt) in points.iter().enumerate().skip(1) {
+                assert!(points[..i].iter().any(|prev| space.are_adjacent(*prev, *pt)), "{:?} is not adjacent to any previous point", pt);
+            }
+        }
+
+        for (i, pt) in points.iter().enumerate() {
+            assert_eq!(points[i + 1..], space.iter_from(*pt).collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn test_generated_maze_is_perfect() {
+    let space = DeltaCoordinateSpace::new_checked(11, 6);
+
+    let mut maze = DeltaMaze::builder(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+    assert_perfect(&maze);
+
+    let mut maze = DeltaMaze::builder(space).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+    assert_perfect(&maze);
+}
+
+#[test]
+fn test_text_export() {
+    let maze = DeltaMaze::builder(DeltaCoordinateSpace::new_checked(3, 2)).build();
+
+    let mut text = Vec::new();
+    DeltaSpaceDeltaCellTextMazeExporter::export(&maze, &mut text).unwrap();
+
+    let expected = "   __
+ /\\  /\\
+/__\\/__\\
+\\  /\\  /
+ \\/__\\/
+";
+
+    assert_eq!(expected, String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::interface::point::CoordinateSpace;
+
+#[test]
+fn test_all_directions() {
+    let directions: Vec<BoxDirection<3>> = BoxDirection::all().collect();
+
+    assert_eq!(6, directions.len());
+    assert_eq!(6, directions.iter().collect::<HashSet<_>>().len());
+
+    for direction in directions {
+        assert_eq!(direction, direction.opposite().opposite());
+        assert_eq!(direction.axis(), direction.opposite().axis());
+        assert_eq!(-direction.delta(), direction.opposite().delta());
+    }
+
+    assert_eq!(BoxDirection::<3>::new(2, true), BoxDirection::UP);
+    assert_eq!(BoxDirection::new(0, false), BoxDirection::<3>::WEST);
+}
+
+#[test]
+fn test_neighbour_matches_neighbours_of_pt() {
+    for space in [BoxCoordinateSpace::new_checked([4, 3, 2]), BoxCoordinateSpace::new_wrapping_checked([4, 3, 2], [true, true, true])] {
+        for pt in space.iter() {
+            let by_direction: HashSet<CoordinateTuplet<3>> = BoxDirection::all().filter_map(|direction| space.neighbour(pt, direction)).collect();
+            let expected: HashSet<CoordinateTuplet<3>> = space.neighbours_of_pt(pt).into_iter().collect();
+
+            assert_eq!(expected, by_direction, "{:?}", pt);
+
+            for direction in BoxDirection::all() {
+                if let Some(neighbour) = space.neighbour(pt, direction) {
+                    assert_eq!(Some(direction), space.direction_between(pt, neighbour));
+                    assert_eq!(Some(pt), space.neighbour(neighbour, direction.opposite()));
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+#![cfg(feature = "draw")]
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::export::draw::{BoxSpaceMazeDrawer, MazeDrawer, MazeElement, Shape};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::path::Path;
+use crate::util::apply_solid_border;
+
+#[test]
+fn test_block_cells_are_drawn_as_rects() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([2, 1])).build();
+
+    apply_solid_border(&mut maze);
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+    let drawer = BoxSpaceMazeDrawer::builder().origin([100.0, 50.0]).cell_size(10.0).path_thickness(2.0).build();
+
+    let mut shapes = Vec::new();
+    drawer.draw_with_path(&maze, &Path::from_vec(vec![[0, 0].into(), [1, 0].into()]), &mut |shape, element| shapes.push((shape, element)));
+
+    let passages = maze.buffer().iter_cells().filter(|(_, value)| value.cell_type == BlockCellPrimaryValue::PASSAGE).count();
+    let cells = usize::from(maze.buffer().cell_count());
+
+    // Every cell but the passages, then the path
+    assert_eq!(cells - passages + 1, shapes.len());
+    assert!(shapes[..cells - passages].iter().all(|(shape, element)| matches!(shape, Shape::Rect { size: [10.0, 10.0], .. }) && *element == MazeElement::Boundary));
+
+    assert_eq!((Shape::Rect { position: [100.0, 50.0], size: [10.0, 10.0] }, MazeElement::Boundary), shapes[0]);
+    assert_eq!((Shape::Line { from: [115.0, 65.0], to: [135.0, 65.0], thickness: 2.0 }, MazeElement::Path), shapes[cells - passages]);
+}
+
+#[test]
+fn test_inline_edges_are_drawn_once() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    apply_solid_border(&mut maze);
+
+    for pt in [[0, 0], [1, 0], [2, 0]] {
+        maze.make_passage_between(pt.into(), [pt[0], 1].into());
+    }
+
+    let mut shapes = Vec::new();
+    BoxSpaceMazeDrawer::builder().build().draw(&maze, &mut |shape, element| shapes.push((shape, element)));
+
+    let count = |element| shapes.iter().filter(|(_, e)| *e == element).count();
+
+    // The outline, the walls between the corridors, and the edges of the unvisited bottom row
+    assert_eq!(12, count(MazeElement::Boundary));
+    assert_eq!(4, count(MazeElement::Wall));
+    assert_eq!(5, count(MazeElement::Unvisited));
+    assert!(shapes.iter().all(|(shape, _)| matches!(shape, Shape::Line { thickness: 2.0, .. })));
+}
\ No newline at end of file
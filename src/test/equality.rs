@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::{ChunkedBuffer, RleBuffer, VecBuffer};
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+
+type InlineMaze = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<2>>, 2>;
+
+fn generate(seed: u64) -> InlineMaze {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([6, 5])).build();
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+
+    return maze
+}
+
+#[test]
+fn test_clone_equals_original() {
+    let maze = generate(1);
+    let mut copy = maze.clone();
+
+    assert!(maze == copy);
+
+    copy.make_wall_between([0, 0].into(), [1, 0].into());
+    copy.make_wall_between([0, 0].into(), [0, 1].into());
+
+    assert!(maze != copy);
+}
+
+#[test]
+fn test_configuration_is_compared() {
+    let space = BoxCoordinateSpace::new_checked([3, 3]);
+
+    let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).build();
+    let padded = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).padding([[2, 2], [2, 2]]).build();
+    let scaled = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).scale_factors_checked([3, 1]).build();
+
+    assert!(maze == BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).build());
+    assert!(maze != padded);
+    assert!(maze != scaled);
+
+    let mut named = maze.clone();
+    named.named_points_mut().set_start([0, 0].into());
+
+    assert!(maze != named);
+}
+
+#[test]
+fn test_identical_mazes_are_deduplicated() {
+    let mazes: HashSet<InlineMaze> = [1, 2, 1, 3, 2].into_iter().map(generate).collect();
+
+    assert_eq!(3, mazes.len());
+    assert!(mazes.contains(&generate(3)));
+}
+
+#[test]
+fn test_sparse_buffers_compare_by_content() {
+    let passage = BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, ..Default::default() };
+    let size = NonZeroUsize::new(20).unwrap();
+
+    // Allocating a chunk without changing any of its cells doesn't make the buffer unequal
+    let mut chunked = ChunkedBuffer::<BlockCellValue, 4>::new(size);
+    let _ = chunked.get_mut(CellID(2));
+
+    assert!(chunked == ChunkedBuffer::new(size));
+
+    // Nor does splitting a run
+    let mut rle = RleBuffer::<BlockCellValue>::new(size);
+    rle.set(CellID(7), passage);
+    let _ = rle.get_mut(CellID(3));
+
+    let mut other = RleBuffer::new(size);
+    other.set(CellID(7), passage);
+
+    assert!(rle.run_count() != other.run_count());
+    assert!(rle == other);
+
+    let mut hashes = HashSet::new();
+    hashes.insert(rle);
+
+    assert!(hashes.contains(&other));
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use crate::error::MazeError;
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+
+type BlockMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[test]
+fn test_try_new_box_coordinate_space() {
+    assert_eq!(MazeError::ZeroDimension, BoxCoordinateSpace::try_new_checked([0, 5]).unwrap_err());
+    assert_eq!(MazeError::TooManyPoints, BoxCoordinateSpace::try_new_checked([usize::MAX, usize::MAX]).unwrap_err());
+    assert_eq!([3, 5], BoxCoordinateSpace::try_new_checked([3, 5]).unwrap().dimensions().map(usize::from));
+}
+
+#[test]
+fn test_try_build() {
+    let coord_space = BoxCoordinateSpace::new_checked([5, 5]);
+
+    assert_eq!(MazeError::ScaledDimensionsTooLarge { axis: 0 }, BlockMaze::builder(coord_space).scale_factors_checked([usize::MAX, 1]).try_build().unwrap_err());
+    assert_eq!(MazeError::FullDimensionsTooLarge { axis: 0 }, BlockMaze::builder(coord_space).padding([[usize::MAX, 1], [1, 1]]).try_build().unwrap_err());
+    assert_eq!(MazeError::TooManyCells, BlockMaze::builder(coord_space).scale_factors_checked([usize::MAX / 16, usize::MAX / 16]).try_build().unwrap_err());
+    assert_eq!(MazeError::ZeroScaleFactor { axis: 1 }, BlockMaze::builder(coord_space).scale_factors_checked([2, 0]).try_build().unwrap_err());
+    assert!(BlockMaze::builder(coord_space).try_build().is_ok());
+
+    let zeta_space = BoxCoordinateSpace::new_zeta_checked([2, 2]);
+
+    assert_eq!(MazeError::DiagonalScaleFactorTooSmall { axis: 0 }, BlockMaze::builder(zeta_space).scale_factors_checked([2, 3]).try_build().unwrap_err());
+    assert_eq!(MazeError::DiagonalSpacingTooSmall { axis: 1, gap: 0 }, BlockMaze::builder(zeta_space).scale_factors_checked([3, 3]).spacing_checked(1, vec![2]).try_build().unwrap_err());
+    assert_eq!(MazeError::DiagonalsUnsupported, BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(zeta_space).try_build().unwrap_err());
+}
+
+#[test]
+fn test_build_errors_name_the_axis() {
+    let coord_space = BoxCoordinateSpace::new_checked([5, 5]);
+
+    assert_eq!(MazeError::ScaledDimensionsTooLarge { axis: 1 }, BlockMaze::builder(coord_space).scale_factors_checked([1, usize::MAX]).try_build().unwrap_err());
+    assert_eq!(MazeError::FullDimensionsTooLarge { axis: 1 }, BlockMaze::builder(coord_space).padding([[1, 1], [usize::MAX, 0]]).try_build().unwrap_err());
+
+    assert_eq!(
+        "All scale factors must be non-zero (the scale factor of axis 0 is zero)",
+        BlockMaze::builder(coord_space).scale_factors_checked([0, 0]).try_build().unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_try_make_between() {
+    let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    let err = maze.try_make_passage_between([0, 0].into(), [2, 0].into()).unwrap_err();
+
+    assert_eq!("(0, 0) and (2, 0) are not adjacent", err.to_string());
+    assert_eq!(BlockCellPrimaryValue::UNVISITED, maze.get([0, 0].into()).cell_type);
+
+    assert_eq!(Ok(()), maze.try_make_wall_between([0, 0].into(), [0, 1].into()));
+    assert_eq!(BlockCellPrimaryValue::WALL, maze.get([0, 0].into()).cell_type);
+}
\ No newline at end of file
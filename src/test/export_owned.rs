@@ -0,0 +1,48 @@
+use crate::implm::export::binary::BoxSpaceBinaryMazeExporter;
+use crate::implm::export::text::{BoxSpaceBlockCellTextMazeExporter, BoxSpaceInlineCellTextMazeExporter, ExportToString};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::{Inline2DMazeBuilder, Standard2DMaze};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::{ExportToVec, MazeExporter};
+
+#[test]
+fn test_export_to_string_matches_export() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+    let exporter = BoxSpaceInlineCellTextMazeExporter::default();
+
+    let mut output = Vec::new();
+    exporter.export(&maze, &mut output).unwrap();
+
+    assert_eq!(String::from_utf8(output).unwrap(), exporter.export_to_string(&maze).unwrap());
+}
+
+#[test]
+fn test_export_to_vec_matches_export() {
+    let maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([4, 3])).build();
+
+    let mut output = Vec::new();
+    BoxSpaceBinaryMazeExporter::new().export(&maze, &mut output).unwrap();
+
+    assert_eq!(output, BoxSpaceBinaryMazeExporter::new().export_to_vec(&maze).unwrap());
+
+    // Text exporters can be exported to bytes too
+    let text = BoxSpaceBlockCellTextMazeExporter::default().export_to_string(&maze).unwrap();
+
+    assert_eq!(text.into_bytes(), BoxSpaceBlockCellTextMazeExporter::default().export_to_vec(&maze).unwrap());
+}
+
+#[cfg(feature = "img")]
+#[test]
+fn test_export_to_vec_supports_images() {
+    use image::ImageFormat;
+
+    use crate::implm::export::img::BoxSpaceImageMazeExporter;
+
+    let maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([4, 3])).build();
+
+    let png = BoxSpaceImageMazeExporter::new(ImageFormat::Png).export_to_vec(&maze).unwrap();
+
+    assert!(image::load_from_memory_with_format(&png, ImageFormat::Png).is_ok());
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::{Inline2DMazeBuilder, Standard2DMaze};
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::DefaultMazeGenerator;
+use crate::util::{convert_unvisited_cells_to_walls, fingerprint};
+
+#[test]
+fn test_fingerprint_ignores_cell_type_and_scale() {
+    let space = BoxCoordinateSpace::new_checked([6, 5]);
+
+    let mut block = Standard2DMaze::builder(space).scale_factors_checked([3, 2]).build();
+    let mut inline = Inline2DMazeBuilder::new(space).build();
+
+    RecursiveBacktrackerGenerator::generate_with_rng(&mut inline, &mut ChaCha8Rng::seed_from_u64(4944));
+
+    for (from, to, connection) in inline.connections() {
+        if connection == ConnectionType::PASSAGE {
+            block.make_passage_between(from, to);
+        }
+    }
+
+    convert_unvisited_cells_to_walls(&mut block);
+
+    assert_eq!(fingerprint(&block), fingerprint(&inline));
+}
+
+#[test]
+fn test_fingerprint_is_reproducible() {
+    let generate = |seed| {
+        let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([8, 8])).build();
+
+        RecursiveBacktrackerGenerator::generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+
+        fingerprint(&maze)
+    };
+
+    assert_eq!(generate(1), generate(1));
+    assert_ne!(generate(1), generate(2));
+}
+
+#[test]
+fn test_fingerprint_depends_on_shape() {
+    let wide = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+    let tall = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([2, 3])).build();
+
+    assert_ne!(fingerprint(&wide), fingerprint(&tall));
+}
+
+#[test]
+fn test_fingerprint_includes_portals() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    let before = fingerprint(&maze);
+
+    maze.portals_mut().insert([0, 0].into(), [2, 2].into());
+
+    assert_ne!(before, fingerprint(&maze));
+}
+
+#[test]
+fn test_fingerprint_ignores_portal_order() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+    let mut reordered = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    maze.portals_mut().insert([0, 0].into(), [2, 2].into());
+    maze.portals_mut().insert([0, 0].into(), [2, 0].into());
+
+    reordered.portals_mut().insert([0, 0].into(), [2, 0].into());
+    reordered.portals_mut().insert([0, 0].into(), [2, 2].into());
+
+    assert_eq!(fingerprint(&maze), fingerprint(&reordered));
+}
+
+#[test]
+fn test_fingerprint_is_stable() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([2, 2])).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+    // If this changes, stored fingerprints will no longer match
+    assert_eq!(2169110459390061777, fingerprint(&maze));
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::{FloorsGenerator, HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::util::{fill_unreachable_regions, set_longest_path_endpoints};
+
+/// Return every pair of points joined between floors, lower point first.
+fn stairwells(maze: &impl MazeCoordinator<CoordSpace = BoxCoordinateSpace<3>>) -> Vec<(CoordinateTuplet<3>, CoordinateTuplet<3>)> {
+    maze.connections()
+        .filter(|(from, to, _)| from[2] < to[2] && maze.is_passage_between(*from, *to))
+        .map(|(from, to, _)| (from, to))
+        .collect()
+}
+
+#[test]
+fn test_one_connector_per_region() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<3>>, 3>::new(BoxCoordinateSpace::new_checked([12, 7, 4])).build();
+
+    FloorsGenerator::new(HuntAndKillGenerator::new(), [5, 5], 1).generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(3));
+
+    let stairwells = stairwells(&maze);
+
+    // Regions are 3 wide (the last narrower) by 2 high (the last shorter), and there are 3 gaps
+    assert_eq!(3 * 2 * 3, stairwells.len());
+
+    for z in 0..3 {
+        for region in 0..6 {
+            let in_region = stairwells.iter().filter(|(below, _)| below[2] == z && below[0] / 5 + 3 * (below[1] / 5) == region).count();
+
+            assert_eq!(1, in_region, "floor {} region {}", z, region);
+        }
+    }
+
+    // Every floor is reachable
+    assert_eq!(0, fill_unreachable_regions(&mut maze, [0, 0, 0].into()));
+}
+
+#[test]
+fn test_whole_floor_region_is_perfect() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 3>::builder(BoxCoordinateSpace::new_checked([6, 6, 3])).build();
+
+    FloorsGenerator::new(RecursiveBacktrackerGenerator::new(), [6, 6], 1).generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(5));
+
+    assert_eq!(2, stairwells(&maze).len());
+
+    // A perfect maze has one fewer passage than it has points
+    let passages = maze.connections().filter(|(from, to, _)| maze.is_passage_between(*from, *to)).count() / 2;
+
+    assert_eq!(6 * 6 * 3 - 1, passages);
+    assert!(set_longest_path_endpoints(&mut maze).is_some());
+}
+
+#[test]
+fn test_small_regions_cap_connectors() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<3>>, 3>::new(BoxCoordinateSpace::new_checked([2, 2, 2])).build();
+
+    // Each region only has one point to spare
+    FloorsGenerator::new(HuntAndKillGenerator::new(), [1, 1], 3).generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(1));
+
+    assert_eq!(4, stairwells(&maze).len());
+}
+
+#[test]
+#[should_panic(expected = "There must be at least one connector per region")]
+fn test_zero_connectors_panics() {
+    let _ = FloorsGenerator::new(HuntAndKillGenerator::new(), [5, 5], 0);
+}
\ No newline at end of file
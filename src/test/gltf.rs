@@ -0,0 +1,85 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellLocation, BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::gltf::GltfMazeExporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::export::MazeExporter;
+
+/// Check the structure of a binary glTF file, returning its JSON chunk and the length of its
+/// binary chunk.
+fn parse(glb: &[u8]) -> (String, usize) {
+    let u32_at = |offset: usize| u32::from_le_bytes(glb[offset..offset + 4].try_into().unwrap()) as usize;
+
+    assert_eq!(b"glTF", &glb[0..4]);
+    assert_eq!(2, u32_at(4));
+    assert_eq!(glb.len(), u32_at(8));
+
+    let json_length = u32_at(12);
+    assert_eq!(b"JSON", &glb[16..20]);
+    assert_eq!(0, json_length % 4);
+
+    let json = String::from_utf8(glb[20..20 + json_length].to_vec()).unwrap();
+
+    if 20 + json_length == glb.len() {
+        return (json, 0)
+    }
+
+    let bin_length = u32_at(20 + json_length);
+    assert_eq!(b"BIN\0", &glb[24 + json_length..28 + json_length]);
+    assert_eq!(glb.len(), 28 + json_length + bin_length);
+
+    return (json, bin_length)
+}
+
+#[test]
+fn test_touching_faces_are_culled() {
+    // Two walls side by side, and a boundary on its own
+    let walls = [[true, true, false, false]];
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::from_walls(&walls);
+    maze.set_cell_value_type(BlockCellLocation([3, 0].into()), BlockCellPrimaryValue::BOUNDARY);
+
+    let mut glb = Vec::new();
+    GltfMazeExporter::builder().cell_size(2.0).wall_height(3.0).build().export(&maze, &mut glb).unwrap();
+
+    let (json, bin_length) = parse(&glb);
+
+    // Ten faces for the walls and six for the boundary, with four vertices each
+    assert!(json.contains(r#""count":64,"type":"VEC3","min":[0,0,0],"max":[8,3,2]"#), "{}", json);
+    assert!(json.contains(r#""componentType":5125,"count":60,"#), "{}", json);
+    assert!(json.contains(r#""componentType":5125,"count":36,"#), "{}", json);
+    assert!(json.contains(r#""primitives":[{"attributes":{"POSITION":0,"NORMAL":1},"indices":2,"material":0},{"attributes":{"POSITION":0,"NORMAL":1},"indices":3,"material":1}]"#), "{}", json);
+
+    // Positions and normals, then indices
+    assert_eq!(64 * 12 * 2 + 96 * 4, bin_length);
+    assert!(json.contains(&format!(r#""buffers":[{{"byteLength":{}}}]"#, bin_length)));
+}
+
+#[test]
+fn test_layers_stack_upwards() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 3>::builder(BoxCoordinateSpace::new_checked([1, 1, 2]))
+        .scale_factors_checked([1, 1, 1])
+        .build();
+
+    maze.set_cell_value_type(BlockCellLocation([0, 0, 1].into()), BlockCellPrimaryValue::WALL);
+
+    let mut glb = Vec::new();
+    GltfMazeExporter::default().export(&maze, &mut glb).unwrap();
+
+    let (json, _) = parse(&glb);
+
+    assert!(json.contains(r#""count":24,"type":"VEC3","min":[0,1,0],"max":[1,2,1]"#), "{}", json);
+}
+
+#[test]
+fn test_empty_maze_has_no_mesh() {
+    let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([2, 2])).build();
+
+    let mut glb = Vec::new();
+    GltfMazeExporter::default().export(&maze, &mut glb).unwrap();
+
+    let (json, bin_length) = parse(&glb);
+
+    assert_eq!(0, bin_length);
+    assert!(json.contains(r#""nodes":[{"name":"Maze"}]"#));
+    assert!(json.contains("meshes") == false);
+}
\ No newline at end of file
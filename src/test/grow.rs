@@ -0,0 +1,112 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::cell::block::BlockCellPrimaryValue;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::implm::presets::{Inline2DMazeBuilder, Standard2DMaze};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::DefaultMazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::{apply_solid_border, count_unvisited, grow};
+
+#[test]
+fn test_block_maze_keeps_contents_when_grown_on_positive_side() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([4, 3])).build();
+
+    RecursiveBacktrackerGenerator::generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4942));
+
+    let before = maze.clone();
+
+    grow(&mut maze, BoxDirection::SOUTH, 2);
+
+    assert_eq!([4, 5], maze.coord_space().dimensions().map(usize::from));
+    assert_eq!(before.get_full_dimensions()[0], maze.get_full_dimensions()[0]);
+
+    for pt in before.coord_space().iter() {
+        assert_eq!(before.get(pt), maze.get(pt));
+
+        for neighbour in before.coord_space().neighbours_of_pt(pt) {
+            assert_eq!(before.is_passage_between(pt, neighbour), maze.is_passage_between(pt, neighbour));
+        }
+    }
+
+    assert_eq!(8, count_unvisited(&maze));
+}
+
+#[test]
+fn test_block_maze_shifts_contents_when_grown_on_negative_side() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    maze.make_passage_between([0, 1].into(), [1, 1].into());
+    maze.set_start([0, 1].into());
+
+    grow(&mut maze, BoxDirection::WEST, 3);
+
+    assert_eq!([6, 3], maze.coord_space().dimensions().map(usize::from));
+    assert!(maze.is_passage_between([3, 1].into(), [4, 1].into()));
+    assert_eq!(Some(CoordinateTuplet([3, 1])), maze.start());
+    assert_eq!(BlockCellPrimaryValue::UNVISITED, maze.get([0, 1].into()).cell_type);
+}
+
+#[test]
+fn test_block_border_stays_on_grown_side() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([2, 2])).build();
+
+    apply_solid_border(&mut maze);
+
+    grow(&mut maze, BoxDirection::EAST, 1);
+
+    let [_, height] = maze.get_full_dimensions().map(usize::from);
+
+    // The old border along the east side is now between the old points and the new ones
+    let old_edge = maze.map_pt_to_cell_loc([1, 0].into())[0] + 1;
+
+    for y in 1..height - 1 {
+        assert_eq!(BlockCellPrimaryValue::BOUNDARY, maze.get_cell_value([old_edge, y].into()).cell_type);
+    }
+
+    let new_edge = maze.get_full_dimensions()[0].get() - 1;
+
+    assert_eq!(BlockCellPrimaryValue::UNVISITED, maze.get_cell_value([new_edge, 1].into()).cell_type);
+}
+
+#[test]
+fn test_inline_maze_grows_and_can_be_carved_into() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+
+    maze.make_passage_between([0, 0].into(), [0, 1].into());
+    maze.make_wall([2, 0].into());
+
+    let before = maze.clone();
+
+    grow(&mut maze, BoxDirection::NORTH, 1);
+
+    assert_eq!([3, 3], maze.coord_space().dimensions().map(usize::from));
+    assert!(maze.is_passage_between([0, 1].into(), [0, 2].into()));
+    assert_eq!(before.get([2, 0].into()), maze.get([2, 1].into()));
+
+    maze.make_passage_between([0, 0].into(), [0, 1].into());
+
+    assert!(maze.is_passage_between([0, 0].into(), [0, 1].into()));
+}
+
+#[test]
+fn test_growing_by_zero_does_nothing() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+    maze.make_passage([1, 1].into());
+
+    let before = maze.clone();
+
+    grow(&mut maze, BoxDirection::NORTH, 0);
+
+    assert_eq!(before, maze);
+}
+
+#[test]
+#[should_panic(expected = "Axes that wrap around can't be grown")]
+fn test_wrapping_axes_cannot_be_grown() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_cylinder_checked([3, 3])).build();
+
+    grow(&mut maze, BoxDirection::EAST, 1);
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::hex::HexCellValue;
+use crate::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+use crate::implm::export::text::HexSpaceHexCellTextMazeExporter;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::hex::{HexCoordinate, HexCoordinateSpace, HexDirection};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::DefaultMazeExporter;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::test::assert_perfect;
+
+type HexMaze = HexSpaceHexCellMazeCoordinator<VecBuffer<HexCellValue>>;
+
+#[test]
+fn test_adjacency() {
+    let space = HexCoordinateSpace::new_checked(5, 4);
+
+    assert_eq!(2, space.neighbours_of_pt(HexCoordinate { column: 0, row: 0 }).len());
+    assert_eq!(5, space.neighbours_of_pt(HexCoordinate { column: 1, row: 0 }).len());
+    assert_eq!(6, space.neighbours_of_pt(HexCoordinate { column: 1, row: 1 }).len());
+    assert_eq!(6, space.neighbours_of_pt(HexCoordinate { column: 2, row: 1 }).len());
+    assert_eq!(3, space.neighbours_of_pt(HexCoordinate { column: 4, row: 3 }).len());
+
+    // Adjacency must be symmetric, in opposite directions
+    for pt in space.iter() {
+        for direction in HexDirection::ALL {
+            if let Some(neighbour) = space.neighbour(pt, direction) {
+                assert_eq!(Some(direction.opposite()), space.direction_between(neighbour, pt));
+                assert!(space.are_adjacent(neighbour, pt));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generated_maze_is_perfect() {
+    let space = HexCoordinateSpace::new_checked(9, 7);
+
+    let mut maze = HexMaze::builder(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+    assert_perfect(&maze);
+
+    let mut maze = HexMaze::builder(space).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+    assert_perfect(&maze);
+}
+
+#[test]
+fn test_text_export() {
+    let maze = HexMaze::builder(HexCoordinateSpace::new_checked(2, 2)).build();
+
+    let mut text = Vec::new();
+    HexSpaceHexCellTextMazeExporter::export(&maze, &mut text).unwrap();
+
+    let expected = " __
+/  \\__
+\\__/  \\
+/  \\__/
+\\__/  \\
+   \\__/
+";
+
+    assert_eq!(expected, String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+}
+
+#[test]
+fn test_svg_export() {
+    use crate::implm::export::svg::HexSvgMazeExporter;
+    use crate::interface::export::MazeExporter;
+
+    let mut maze = HexMaze::builder(HexCoordinateSpace::new_checked(2, 1)).build();
+
+    let export = |maze: &HexMaze| {
+        let mut svg = Vec::new();
+        HexSvgMazeExporter::builder().build().export(maze, &mut svg).unwrap();
+        String::from_utf8(svg).unwrap()
+    };
+
+    // Six edges each, one of them shared
+    assert_eq!(11, export(&maze).matches('M').count());
+
+    maze.make_passage_between(HexCoordinate { column: 0, row: 0 }, HexCoordinate { column: 1, row: 0 });
+
+    let svg = export(&maze);
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(10, svg.matches('M').count());
+}
+
+#[cfg(feature = "img")]
+#[test]
+fn test_image_export() {
+    use std::io::Cursor;
+
+    use image::{ImageFormat, Rgba, RgbaImage};
+
+    use crate::implm::export::hex::HexOrientation;
+    use crate::implm::export::img::HexSpaceImageMazeExporter;
+    use crate::interface::export::MazeExporter;
+
+    let export = |maze: &HexMaze, orientation: HexOrientation| -> RgbaImage {
+        let mut output = Cursor::new(Vec::new());
+        HexSpaceImageMazeExporter::new(ImageFormat::Png).with_orientation(orientation).export(maze, &mut output).unwrap();
+
+        image::load_from_memory_with_format(output.get_ref(), ImageFormat::Png).unwrap().to_rgba8()
+    };
+
+    let mut maze = HexMaze::builder(HexCoordinateSpace::new_checked(2, 1)).build();
+
+    let black = Rgba([0, 0, 0, 255]);
+    let white = Rgba([255, 255, 255, 255]);
+
+    let flat = export(&maze, HexOrientation::FlatTop);
+    let pointy = export(&maze, HexOrientation::PointyTop);
+
+    // Pointy-topped hexagons are drawn transposed
+    assert_eq!((37, 28), flat.dimensions());
+    assert_eq!((28, 37), pointy.dimensions());
+
+    // The centres of the hexagons, and the middle of the edge between them
+    assert_eq!(white, *flat.get_pixel(11, 9));
+    assert_eq!(black, *flat.get_pixel(18, 13));
+    assert_eq!(black, *pointy.get_pixel(13, 18));
+
+    maze.make_passage_between(HexCoordinate { column: 0, row: 0 }, HexCoordinate { column: 1, row: 0 });
+
+    assert_eq!(white, *export(&maze, HexOrientation::FlatTop).get_pixel(18, 13));
+    assert_eq!(white, *export(&maze, HexOrientation::PointyTop).get_pixel(13, 18));
+}
\ No newline at end of file
@@ -0,0 +1,257 @@
+use std::io::ErrorKind;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
+use crate::implm::export::binary::BoxSpaceBinaryMazeExporter;
+use crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::import::binary::BoxSpaceBinaryMazeImporter;
+use crate::implm::import::text::BoxSpaceBlockCellTextMazeImporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::{DefaultMazeExporter, MazeExporter};
+use crate::interface::generate::MazeGenerator;
+use crate::interface::import::{DefaultMazeImporter, MazeImporter};
+
+type BlockMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[test]
+fn test_block_cell_text_round_trip() {
+    let mut maze = get_generated_block_cell_maze();
+
+    let mut text = Vec::<u8>::new();
+    <BoxSpaceBlockCellTextMazeExporter as DefaultMazeExporter<_, _>>::export(&maze, &mut text).unwrap();
+
+    let imported: BlockMaze = <BoxSpaceBlockCellTextMazeImporter as DefaultMazeImporter<_, _>>::import(&mut text.as_slice()).unwrap();
+
+    // The text exporter doesn't distinguish walls from boundaries
+    for y in 0..usize::from(maze.get_full_dimensions()[1]) {
+        for x in 0..usize::from(maze.get_full_dimensions()[0]) {
+            let cell = maze.get_cell_value_mut([x, y].into());
+
+            if cell.cell_type == BlockCellPrimaryValue::BOUNDARY {
+                cell.cell_type = BlockCellPrimaryValue::WALL;
+            }
+        }
+    }
+
+    assert_eq!(maze.get_full_dimensions(), imported.get_full_dimensions());
+    assert_eq!(get_cell_types(&maze), get_cell_types(&imported));
+}
+
+#[test]
+fn test_block_cell_text_ascii_conventions() {
+    let text = "\
++--+--+
+|  |  |
++  +  +
+|     |
++--+--+
+";
+
+    let importer = BoxSpaceBlockCellTextMazeImporter::builder().chars_per_cell_horizontally_checked(1).build();
+
+    let maze: BlockMaze = importer.import(&mut text.as_bytes()).unwrap();
+
+    assert_eq!([7, 5], maze.get_full_dimensions().map(usize::from));
+
+    assert_eq!(BlockCellPrimaryValue::WALL, maze.get_cell_value([0, 0].into()).cell_type);
+    assert_eq!(BlockCellPrimaryValue::WALL, maze.get_cell_value([3, 1].into()).cell_type);
+    assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get_cell_value([1, 2].into()).cell_type);
+    assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get_cell_value([3, 3].into()).cell_type);
+}
+
+#[test]
+fn test_block_cell_text_ragged_lines() {
+    // Trailing whitespace has been stripped from the middle line
+    let text = "###\n#\n###";
+
+    let importer = BoxSpaceBlockCellTextMazeImporter::builder().chars_per_cell_horizontally_checked(1).build();
+
+    let maze: BlockMaze = importer.import(&mut text.as_bytes()).unwrap();
+
+    assert_eq!([3, 3], maze.get_full_dimensions().map(usize::from));
+    assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get_cell_value([2, 1].into()).cell_type);
+}
+
+#[test]
+fn test_block_cell_text_invalid_input() {
+    let importer = BoxSpaceBlockCellTextMazeImporter::builder().chars_per_cell_horizontally_checked(1).build();
+
+    let result: std::io::Result<BlockMaze> = importer.import(&mut "##\n#?".as_bytes());
+    let err = result.unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+    assert!(err.to_string().contains("line 2, column 2"));
+
+    let result: std::io::Result<BlockMaze> = importer.import(&mut "\n\n".as_bytes());
+    assert_eq!(ErrorKind::InvalidData, result.unwrap_err().kind());
+}
+
+#[test]
+fn test_block_cell_text_structure_inference() {
+    let configurations = [
+        ([9, 7], [2, 2], [[1, 1], [1, 1]]),
+        ([6, 4], [3, 2], [[2, 0], [0, 3]]),
+        ([5, 5], [4, 4], [[1, 1], [1, 1]]),
+        ([4, 8], [2, 3], [[0, 0], [2, 2]]),
+    ];
+
+    for (dimensions, scale_factors, padding) in configurations {
+        let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked(dimensions))
+            .scale_factors_checked(scale_factors)
+            .padding(padding)
+            .build();
+
+        RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut get_test_rng());
+
+        let mut text = Vec::<u8>::new();
+        <BoxSpaceBlockCellTextMazeExporter as DefaultMazeExporter<_, _>>::export(&maze, &mut text).unwrap();
+
+        let imported: BlockMaze = <BoxSpaceBlockCellTextMazeImporter as DefaultMazeImporter<_, _>>::import(&mut text.as_slice()).unwrap();
+
+        assert_eq!(dimensions, imported.coord_space().dimensions().map(usize::from));
+        assert_eq!(scale_factors, imported.scale_factors().map(usize::from));
+        assert_eq!(padding, imported.padding());
+
+        let imported: BlockMaze = BoxSpaceBlockCellTextMazeImporter::builder().infer_structure(false).build().import(&mut text.as_slice()).unwrap();
+
+        assert_eq!(maze.get_full_dimensions(), imported.coord_space().dimensions());
+        assert_eq!([[0, 0], [0, 0]], imported.padding());
+    }
+}
+
+#[test]
+fn test_binary_integrity_checks() {
+    let maze = get_generated_block_cell_maze();
+
+    let mut bytes = Vec::new();
+    BoxSpaceBinaryMazeExporter::new().export(&maze, &mut bytes).unwrap();
+
+    let import = |bytes: &[u8]| -> std::io::Result<BlockMaze> { BoxSpaceBinaryMazeImporter::new().import(&mut &bytes[..]) };
+
+    assert!(import(&bytes).is_ok());
+
+    // Corrupt a cell
+    let mut corrupt = bytes.clone();
+    corrupt[100] ^= 0b1;
+    assert!(import(&corrupt).unwrap_err().to_string().contains("Checksum"));
+
+    // Truncate
+    assert_eq!(ErrorKind::InvalidData, import(&bytes[..bytes.len() - 10]).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidData, import(&bytes[..3]).unwrap_err().kind());
+
+    // A future version, with a valid checksum
+    let mut future = bytes[..bytes.len() - 4].to_vec();
+    future[4] = 2;
+    let checksum = crate::implm::export::binary::format::crc32(&future);
+    future.extend_from_slice(&checksum.to_le_bytes());
+    assert!(import(&future).unwrap_err().to_string().contains("Unsupported version 2"));
+
+    // Wrong cell class
+    let result: std::io::Result<BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<2>>, 2>> = BoxSpaceBinaryMazeImporter::new().import(&mut bytes.as_slice());
+    assert!(result.unwrap_err().to_string().contains("cell class"));
+
+    // Wrong dimension
+    let result: std::io::Result<BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 3>> = BoxSpaceBinaryMazeImporter::new().import(&mut bytes.as_slice());
+    assert!(result.unwrap_err().to_string().contains("2-dimensional"));
+}
+
+#[test]
+fn test_binary_forged_headers() {
+    let import_block = |bytes: &[u8]| -> std::io::Result<BlockMaze> { BoxSpaceBinaryMazeImporter::new().import(&mut &bytes[..]) };
+    let import_inline = |bytes: &[u8]| -> std::io::Result<BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<2>>, 2>> { BoxSpaceBinaryMazeImporter::new().import(&mut &bytes[..]) };
+
+    let huge = 1u64 << 40;
+
+    // Too many points
+    assert_eq!(ErrorKind::InvalidData, import_block(&forge_binary(0, &[u64::MAX, u64::MAX, 1, 1, 0, 0, 0, 0])).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidData, import_inline(&forge_binary(1, &[u64::MAX, u64::MAX])).unwrap_err().kind());
+
+    // Far more points than there are cells in the input
+    assert!(import_block(&forge_binary(0, &[huge, huge >> 20, 1, 1, 0, 0, 0, 0])).unwrap_err().to_string().contains("truncated"));
+    assert!(import_inline(&forge_binary(1, &[huge, huge >> 20])).unwrap_err().to_string().contains("truncated"));
+
+    // Few points, but far more cells than there are in the input
+    assert!(import_block(&forge_binary(0, &[2, 2, huge, huge, 0, 0, 0, 0])).unwrap_err().to_string().contains("truncated"));
+    assert!(import_block(&forge_binary(0, &[2, 2, 1, 1, huge, huge, 0, 0])).unwrap_err().to_string().contains("truncated"));
+
+    // Cell space overflows
+    assert_eq!(ErrorKind::InvalidData, import_block(&forge_binary(0, &[2, 2, u64::MAX, 1, 0, 0, 0, 0])).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidData, import_block(&forge_binary(0, &[1, 1, 1, 1, u64::MAX, u64::MAX, 0, 0])).unwrap_err().kind());
+
+    // Zero dimensions and scale factors
+    assert_eq!(ErrorKind::InvalidData, import_block(&forge_binary(0, &[0, 1, 1, 1, 0, 0, 0, 0])).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidData, import_block(&forge_binary(0, &[1, 1, 0, 1, 0, 0, 0, 0])).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidData, import_inline(&forge_binary(1, &[1, 0])).unwrap_err().kind());
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_json_forged_dimensions() {
+    use crate::implm::import::json::BoxSpaceBlockCellJsonMazeImporter;
+
+    let import = |json: &str| -> std::io::Result<BlockMaze> { BoxSpaceBlockCellJsonMazeImporter::new().import(&mut json.as_bytes()) };
+
+    let document = |dimensions: &str, scale_factors: &str, padding: &str| format!(r#"{{"format":"mazelib/box-space-block-cell","version":1,"dimensions":{},"scale_factors":{},"padding":{},"cells":["@@@","@ @","@@@"],"marked":[]}}"#, dimensions, scale_factors, padding);
+
+    assert!(import(&document("[1,1]", "[2,2]", "[[1,1],[1,1]]")).is_ok());
+
+    // Too many points
+    assert_eq!(ErrorKind::InvalidData, import(&document("[4294967296,4294967296]", "[2,2]", "[[1,1],[1,1]]")).unwrap_err().kind());
+
+    // Far more points or cells than the document has
+    assert!(import(&document("[1099511627776,1]", "[2,2]", "[[1,1],[1,1]]")).unwrap_err().to_string().contains("cells"));
+    assert!(import(&document("[2,2]", "[1099511627776,2]", "[[1,1],[1,1]]")).unwrap_err().to_string().contains("cells"));
+
+    // Cell space overflows
+    assert_eq!(ErrorKind::InvalidData, import(&document("[2,1]", &format!("[{},1]", usize::MAX), "[[0,0],[0,0]]")).unwrap_err().kind());
+    assert_eq!(ErrorKind::InvalidData, import(&document("[1,1]", "[1,1]", &format!("[[{},{}],[0,0]]", usize::MAX, usize::MAX))).unwrap_err().kind());
+}
+
+/// Build a 2D input in the current version with the given header values, no cells, and a valid
+/// checksum.
+fn forge_binary(cell_class: u8, values: &[u64]) -> Vec<u8> {
+    let mut bytes = b"MZLB".to_vec();
+    bytes.extend_from_slice(&[crate::implm::export::binary::format::CURRENT_VERSION, cell_class, 2]);
+
+    for value in values {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    let checksum = crate::implm::export::binary::format::crc32(&bytes);
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+
+    return bytes
+}
+
+fn get_test_rng() -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(0)
+}
+
+fn get_generated_block_cell_maze() -> BlockMaze {
+    let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([9, 7])).build();
+
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut get_test_rng());
+
+    return maze
+}
+
+fn get_cell_types(maze: &BlockMaze) -> Vec<BlockCellPrimaryValue> {
+    let [width, height] = maze.get_full_dimensions().map(usize::from);
+
+    let mut cell_types = Vec::with_capacity(width * height);
+
+    for y in 0..height {
+        for x in 0..width {
+            cell_types.push(maze.get_cell_value([x, y].into()).cell_type);
+        }
+    }
+
+    return cell_types
+}
@@ -0,0 +1,107 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::implm::cell::inline::InlineCellValueEdge;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::infinite::{InfiniteMaze, InfinitePt};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+#[test]
+fn test_chunks_are_the_same_in_any_order() {
+    let mut forwards = InfiniteMaze::new(RecursiveBacktrackerGenerator::new(), [5, 4], 4943);
+    let mut backwards = InfiniteMaze::new(RecursiveBacktrackerGenerator::new(), [5, 4], 4943);
+
+    let points: Vec<InfinitePt> = (-12..12).flat_map(|x| (-9..9).map(move |y| [x, y])).collect();
+
+    let forwards_cells: Vec<_> = points.iter().map(|pt| forwards.get(*pt)).collect();
+    let backwards_cells: Vec<_> = points.iter().rev().map(|pt| backwards.get(*pt)).collect();
+
+    assert!(forwards_cells.into_iter().eq(backwards_cells.into_iter().rev()));
+}
+
+#[test]
+fn test_different_seeds_give_different_mazes() {
+    let mut first = InfiniteMaze::new(HuntAndKillGenerator::new(), [6, 6], 1);
+    let mut second = InfiniteMaze::new(HuntAndKillGenerator::new(), [6, 6], 2);
+
+    let points: Vec<InfinitePt> = (0..6).flat_map(|x| (0..6).map(move |y| [x, y])).collect();
+
+    assert!(points.iter().any(|pt| first.get(*pt) != second.get(*pt)));
+}
+
+#[test]
+fn test_borders_are_consistent() {
+    let mut maze = InfiniteMaze::new(HuntAndKillGenerator::new(), [4, 3], 7);
+
+    for x in -8..8 {
+        for y in -6..6 {
+            for neighbour in [[x + 1, y], [x, y + 1]] {
+                assert_eq!(maze.is_passage_between([x, y], neighbour), maze.is_passage_between(neighbour, [x, y]), "{:?} {:?}", [x, y], neighbour);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_every_point_is_reachable() {
+    let mut maze = InfiniteMaze::new(RecursiveBacktrackerGenerator::new(), [4, 4], 99);
+
+    // Explore the three by three chunks around the origin, without leaving them
+    let in_bounds = |pt: InfinitePt| pt.iter().all(|coord| (-4..8).contains(coord));
+
+    let mut visited = HashSet::from([[0, 0]]);
+    let mut queue = VecDeque::from([[0, 0]]);
+
+    while let Some(pt) = queue.pop_front() {
+        for neighbour in maze.connected_neighbours(pt) {
+            if in_bounds(neighbour) && visited.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    assert_eq!(144, visited.len());
+}
+
+#[test]
+fn test_only_chunks_looked_at_are_generated() {
+    let mut maze = InfiniteMaze::new(HuntAndKillGenerator::new(), [8, 8], 0);
+
+    assert_eq!(0, maze.chunk_count());
+    assert!(maze.explored().is_none());
+
+    let _ = maze.get([3, 3]);
+    let _ = maze.get([-1, 100]);
+
+    assert_eq!(2, maze.chunk_count());
+    assert_eq!(HashSet::from([[0, 0], [-1, 12]]), maze.generated_chunks().collect());
+}
+
+#[test]
+fn test_explored_region_matches() {
+    let mut maze = InfiniteMaze::new(HuntAndKillGenerator::new(), [3, 2], 5);
+
+    let _ = maze.chunk([-1, 0]);
+    let _ = maze.chunk([1, 1]);
+
+    let (explored, origin) = maze.explored().unwrap();
+
+    assert_eq!([-3, 0], origin);
+    assert_eq!([9, 4], explored.coord_space().dimensions().map(usize::from));
+
+    for pt in explored.coord_space().iter() {
+        let infinite_pt = [origin[0] + pt[0] as i64, origin[1] + pt[1] as i64];
+
+        if maze.is_generated(maze.chunk_of(infinite_pt)) {
+            assert_eq!(maze.get(infinite_pt), explored.get(pt));
+        } else {
+            assert_eq!([[InlineCellValueEdge::UNVISITED; 2]; 2], explored.get(pt).edges);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "The chunk size must be non-zero")]
+fn test_chunk_size_must_be_non_zero() {
+    let _ = InfiniteMaze::new(HuntAndKillGenerator::new(), [0, 4], 0);
+}
\ No newline at end of file
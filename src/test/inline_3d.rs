@@ -0,0 +1,87 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::export::text::BoxSpaceInlineCellTextMazeExporter;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::DefaultMazeExporter;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::test::assert_perfect;
+
+type Maze3D = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<3>>, 3>;
+
+#[test]
+fn test_3d_cells_are_distinct() {
+    let space = BoxCoordinateSpace::new_checked([3, 4, 5]);
+
+    let mut maze: Maze3D = BoxSpaceInlineCellMazeCoordinatorBuilder::new(space).build();
+
+    for pt in space.iter() {
+        maze.make_wall(pt);
+    }
+
+    maze.make_passage_between([1, 2, 3].into(), [1, 2, 4].into());
+
+    for pt in space.iter() {
+        let has_passage = space.neighbours_of_pt(pt).into_iter().any(|neighbour| maze.is_passage_between(pt, neighbour));
+
+        assert_eq!(pt == [1, 2, 3].into() || pt == [1, 2, 4].into(), has_passage, "{:?}", pt);
+    }
+}
+
+#[test]
+fn test_3d_generated_maze_is_perfect() {
+    let space = BoxCoordinateSpace::new_checked([4, 3, 5]);
+
+    let mut maze: Maze3D = BoxSpaceInlineCellMazeCoordinatorBuilder::new(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+
+    assert_perfect(&maze);
+}
+
+#[test]
+fn test_3d_text_export_is_layered() {
+    let space = BoxCoordinateSpace::new_checked([2, 2, 3]);
+
+    let mut maze: Maze3D = BoxSpaceInlineCellMazeCoordinatorBuilder::new(space).build();
+
+    for pt in space.iter() {
+        maze.make_wall(pt);
+    }
+
+    maze.make_passage_between([0, 0, 0].into(), [1, 0, 0].into());
+    maze.make_passage_between([1, 0, 0].into(), [1, 0, 1].into());
+    maze.make_passage_between([1, 0, 1].into(), [1, 0, 2].into());
+    maze.make_passage_between([1, 0, 2].into(), [1, 1, 2].into());
+
+    let mut text = Vec::new();
+    BoxSpaceInlineCellTextMazeExporter::export(&maze, &mut text).unwrap();
+    let text = String::from_utf8(text).unwrap();
+
+    let expected = concat!(
+        "┌───────┐\n",
+        "│     ▲ │\n",
+        "├───┬───┤\n",
+        "│   │   │\n",
+        "└───┴───┘\n",
+        "\n",
+        "┌───┬───┐\n",
+        "│   │ ◆ │\n",
+        "├───┼───┤\n",
+        "│   │   │\n",
+        "└───┴───┘\n",
+        "\n",
+        "┌───┬───┐\n",
+        "│   │ ▼ │\n",
+        "├───┤   │\n",
+        "│   │   │\n",
+        "└───┴───┘\n",
+    );
+
+    assert_eq!(expected, text.replace("\r\n", "\n"));
+}
\ No newline at end of file
@@ -0,0 +1,48 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::export::text::BoxSpaceInlineCellIsometricTextMazeExporter;
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::export::MazeExporter;
+use crate::interface::generate::MazeGenerator;
+
+#[test]
+fn test_isometric_floors_are_slanted_and_stacked() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<3>>, 3>::new(BoxCoordinateSpace::new_checked([4, 3, 3])).build();
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(2));
+
+    let mut text = Vec::new();
+    BoxSpaceInlineCellIsometricTextMazeExporter::new().export(&maze, &mut text).unwrap();
+
+    let text = String::from_utf8(text).unwrap().replace("\r\n", "\n");
+    let floors: Vec<&str> = text.trim_end().split("\n\n").collect();
+
+    assert_eq!(3, floors.len());
+
+    for floor in floors {
+        let lines: Vec<&str> = floor.lines().collect();
+
+        assert_eq!(7, lines.len());
+
+        // Each line starts one character further left than the line above, and the outer walls
+        // are always drawn
+        for (index, line) in lines.iter().enumerate() {
+            assert_eq!(6 - index, line.len() - line.trim_start().len());
+        }
+
+        assert!(lines[0].trim() == "+--+--+--+--+");
+        assert!(lines[6].trim() == "+--+--+--+--+");
+        assert!(lines[1].trim_start().starts_with('/') && lines[1].ends_with('/'));
+    }
+
+    // Every passage between floors is marked on both floors
+    let markers = |c: char| text.chars().filter(|&other| other == c).count();
+
+    assert_eq!(markers('^') + markers('X'), markers('v') + markers('X'));
+    assert!(markers('^') + markers('X') > 0);
+}
\ No newline at end of file
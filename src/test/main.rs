@@ -1,7 +1,8 @@
 #![allow(dead_code)]
 
 use std::fs::File;
-use std::io::{BufWriter, Result, Write};
+use std::io::{BufWriter, Error, ErrorKind, Result, Write};
+use std::time::Instant;
 
 use image::ImageFormat;
 
@@ -14,12 +15,21 @@ use mazelib::implm::export::img::BoxSpaceImageMazeExporter;
 use mazelib::implm::export::text::{BoxSpaceBlockCellTextMazeExporter, BoxSpaceInlineCellTextMazeExporter};
 use mazelib::implm::generate::HuntAndKillGenerator;
 use mazelib::implm::point::boxy::BoxCoordinateSpace;
+use mazelib::interface::coordinate::MazeCoordinator;
 use mazelib::interface::export::MazeExporter;
 use mazelib::interface::generate::DefaultMazeGenerator;
+use mazelib::interface::point::CoordinateSpace;
 use mazelib::util::apply_solid_border;
 
+/// Pass the name of a benchmark (e.g. `bench-text-export`) to run it instead of the default
+/// example.
 fn main() -> Result<()> {
-    generate_maze_box_img()
+    match std::env::args().nth(1).as_deref() {
+        Some("bench-text-export") => benchmark_text_export(),
+        Some("bench-block-carving") => benchmark_block_carving(),
+        Some(other) => Err(Error::new(ErrorKind::InvalidInput, format!("Unknown benchmark {:?}", other))),
+        None => generate_maze_box_img(),
+    }
 }
 
 fn generate_maze_box_text() -> Result<()> {
@@ -68,4 +78,50 @@ fn generate_maze_box_img() -> Result<()> {
     BoxSpaceImageMazeExporter::new(ImageFormat::Bmp).export(&maze, &mut output)?;
 
     output.flush()
+}
+
+/// Time exporting large mazes to text, for comparing the performance of the text exporters.
+///
+/// Run with `cargo run --release -- bench-text-export`. The output is kept in memory, so
+/// no IO is measured.
+fn benchmark_text_export() -> Result<()> {
+    let space = BoxCoordinateSpace::<2>::new_checked([1000, 1000]);
+
+    let mut block_maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).build();
+    HuntAndKillGenerator::generate(&mut block_maze);
+
+    let mut output = Vec::new();
+    let start = Instant::now();
+    BoxSpaceBlockCellTextMazeExporter::default().export(&block_maze, &mut output)?;
+    println!("Block cell text export: {:?} ({} bytes)", start.elapsed(), output.len());
+
+    let mut inline_maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(space).build();
+    HuntAndKillGenerator::generate(&mut inline_maze);
+
+    let mut output = Vec::new();
+    let start = Instant::now();
+    BoxSpaceInlineCellTextMazeExporter::default().export(&inline_maze, &mut output)?;
+    println!("Inline cell text export: {:?} ({} bytes)", start.elapsed(), output.len());
+
+    Ok(())
+}
+
+/// Time carving every passage of a maze with large scale factors, for comparing the performance
+/// of carving passages between points.
+///
+/// Run with `cargo run --release -- bench-block-carving`.
+fn benchmark_block_carving() -> Result<()> {
+    let space = BoxCoordinateSpace::<2>::new_checked([250, 250]);
+
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).scale_factors_checked([16, 16]).build();
+
+    let start = Instant::now();
+    for pt in space.iter() {
+        for neighbour in space.neighbours_of_pt(pt) {
+            maze.make_passage_between(pt, neighbour);
+        }
+    }
+    println!("Block cell carving (scale factor 16): {:?}", start.elapsed());
+
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::mark::{MarkLayer, MarkLayers};
+
+#[test]
+fn test_layers_are_independent() {
+    let mut layers = MarkLayers::<CoordinateTuplet<2>, usize>::new();
+
+    layers.layer_mut("distance").set([1, 1].into(), 4);
+    layers.layer_mut("region").set([1, 1].into(), 2);
+
+    assert_eq!(Some(4), layers.layer_mut("distance").set([1, 1].into(), 5));
+
+    assert_eq!(Some(&5), layers.layer("distance").unwrap().get([1, 1].into()));
+    assert_eq!(Some(&2), layers.layer("region").unwrap().get([1, 1].into()));
+
+    layers.layer_mut("region").clear();
+
+    assert!(layers.layer("region").unwrap().is_empty());
+    assert_eq!(vec![("distance", &5)], layers.layers_marking([1, 1].into()).collect::<Vec<_>>());
+
+    assert!(layers.remove_layer("region").is_some());
+    assert_eq!(vec!["distance"], layers.names().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_layer_from_marked_cells() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    maze.set([0, 1].into(), InlineCellValue { marked: true, ..Default::default() });
+    maze.set([2, 2].into(), InlineCellValue { marked: true, ..Default::default() });
+
+    let layer = MarkLayer::from_marked_cells(&maze);
+
+    assert_eq!(2, layer.len());
+    assert!(layer.is_marked([0, 1].into()));
+    assert!(layer.is_marked([2, 2].into()));
+    assert!(layer.is_marked([1, 1].into()) == false);
+}
+
+#[test]
+fn test_iter_marked_only_yields_marked_cells() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    maze.set([2, 0].into(), InlineCellValue { marked: true, ..Default::default() });
+    maze.set([1, 2].into(), InlineCellValue { marked: true, ..Default::default() });
+    maze.make_passage_between([0, 0].into(), [0, 1].into());
+
+    let marked: Vec<_> = maze.iter_marked().map(|(pt, _)| pt).collect();
+
+    assert_eq!(vec![CoordinateTuplet::from([2, 0]), CoordinateTuplet::from([1, 2])], marked);
+
+    let visited = maze.iter_where(|value| value.edges[1][1] == InlineCellValueEdge::PASSAGE).count();
+
+    assert_eq!(1, visited);
+}
\ No newline at end of file
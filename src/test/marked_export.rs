@@ -0,0 +1,105 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellValue, VerticalConnector};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+
+type BlockMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+type BlockMaze3D = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 3>;
+
+fn corridor_with_marked_middle() -> BlockMaze {
+    let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([3, 1])).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+    maze.make_passage_between([1, 0].into(), [2, 0].into());
+
+    let loc = maze.map_pt_to_cell_loc([1, 0].into());
+    maze.get_cell_value_mut(loc).marked = true;
+
+    return maze
+}
+
+fn export_text(exporter: &BoxSpaceBlockCellTextMazeExporter, maze: &BlockMaze) -> String {
+    let mut output = Vec::new();
+    exporter.export(maze, &mut output).unwrap();
+
+    return String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn test_marked_passages_are_plain_by_default() {
+    let maze = corridor_with_marked_middle();
+
+    let exporter = BoxSpaceBlockCellTextMazeExporter::default();
+
+    assert_eq!(None, exporter.marked_char());
+    assert_eq!("..████████....\n██          ..\n..████████....\n", export_text(&exporter, &maze));
+}
+
+#[test]
+fn test_marked_passages_use_marked_char() {
+    let maze = corridor_with_marked_middle();
+
+    let exporter = BoxSpaceBlockCellTextMazeExporter::builder().marked_char('*').build();
+
+    assert_eq!(Some('*'), exporter.marked_char());
+    assert_eq!("..████████....\n██    **    ..\n..████████....\n", export_text(&exporter, &maze));
+}
+
+#[test]
+fn test_marked_char_is_drawn_under_endpoints() {
+    let mut maze = corridor_with_marked_middle();
+    maze.set_start([1, 0].into());
+
+    let exporter = BoxSpaceBlockCellTextMazeExporter::builder().marked_char('*').build();
+
+    assert_eq!("..████████....\n██    SS    ..\n..████████....\n", export_text(&exporter, &maze));
+}
+
+#[test]
+fn test_marked_stairs_use_marked_char() {
+    let mut maze = BlockMaze3D::builder(BoxCoordinateSpace::new_checked([1, 1, 2])).build();
+
+    maze.make_passage_between([0, 0, 0].into(), [0, 0, 1].into());
+
+    let loc = maze.map_pt_to_cell_loc([0, 0, 0].into());
+    assert_eq!(VerticalConnector::UP, maze.get_cell_value(loc).connector);
+    maze.get_cell_value_mut(loc).marked = true;
+
+    let exporter = BoxSpaceBlockCellTextMazeExporter::builder().marked_char('*').build();
+
+    let mut output = Vec::new();
+    exporter.export(&maze, &mut output).unwrap();
+    let text = String::from_utf8(output).unwrap();
+
+    assert!(text.contains("**"));
+    assert!(text.contains("▼▼"));
+    assert!(text.contains('▲') == false);
+}
+
+#[cfg(feature = "img")]
+#[test]
+fn test_marked_passages_use_marked_colour() {
+    use std::io::Cursor;
+
+    use image::{ImageFormat, Rgba};
+
+    use crate::implm::export::img::BoxSpaceImageMazeExporter;
+
+    let maze = corridor_with_marked_middle();
+
+    let exporter = BoxSpaceImageMazeExporter::new(ImageFormat::Png).with_marked_colour(Rgba::from([255, 0, 0, 255]));
+
+    assert_eq!(Some(Rgba::from([255, 0, 0, 255])), exporter.marked_colour());
+
+    let mut output = Cursor::new(Vec::new());
+    exporter.export(&maze, &mut output).unwrap();
+
+    let img = image::load_from_memory_with_format(output.get_ref(), ImageFormat::Png).unwrap().to_rgba8();
+
+    assert_eq!(&Rgba::from([255, 255, 255, 255]), img.get_pixel(2, 1));
+    assert_eq!(&Rgba::from([255, 0, 0, 255]), img.get_pixel(3, 1));
+    assert_eq!(&Rgba::from([255, 255, 255, 255]), img.get_pixel(4, 1));
+}
\ No newline at end of file
@@ -0,0 +1,141 @@
+use std::collections::{HashSet, VecDeque};
+
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::coordinate::masked::MaskedMazeCoordinator;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::point::masked::{BoxPointMask, MaskedBoxCoordinateSpace};
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+/// A ring, which can't be iterated over in box order without leaving the mask.
+fn get_ring_mask() -> BoxPointMask<2> {
+    BoxPointMask::from_fn(BoxCoordinateSpace::new_checked([11, 11]), |pt| {
+        let distance_squared = (pt[0] as isize - 5).pow(2) + (pt[1] as isize - 5).pow(2);
+
+        (9..=25).contains(&distance_squared)
+    })
+}
+
+#[test]
+fn test_iteration() {
+    let mask = get_ring_mask();
+    let space = MaskedBoxCoordinateSpace::new(&mask);
+
+    let points: Vec<_> = space.iter().collect();
+
+    assert_eq!(usize::from(space.logical_size()), points.len());
+    assert_eq!(points.len(), points.iter().collect::<HashSet<_>>().len());
+    assert_eq!(usize::from(space.logical_size()), space.unmasked().iter().filter(|pt| space.contains(*pt)).count());
+
+    // Every point must be adjacent to a point already yielded
+    for (i, pt) in points.iter().enumerate().skip(1) {
+        assert!(points[..i].iter().any(|prev| space.are_adjacent(*prev, *pt)), "{:?} is not adjacent to any previous point", pt);
+    }
+
+    assert_eq!(points[4..], space.iter_from(points[3]).collect::<Vec<_>>());
+    assert_eq!(0, space.iter_from([5, 5].into()).count());
+
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    for _ in 0..100 {
+        assert!(space.contains(space.choose(&mut rng)));
+    }
+}
+
+#[test]
+fn test_from_grid() {
+    let mask = BoxPointMask::from_grid(&[
+        vec![true,  false, true],
+        vec![false, false, true],
+    ]);
+
+    assert_eq!(3, usize::from(mask.point_count()));
+    assert!(mask.contains([0, 0].into()));
+    assert!(mask.contains([1, 0].into()) == false);
+    assert!(mask.contains([2, 1].into()));
+    assert!(mask.contains([3, 1].into()) == false);
+
+    // Two separate regions
+    assert_eq!(vec![[0, 0], [2, 0], [2, 1]], MaskedBoxCoordinateSpace::new(&mask).iter().map(<[usize; 2]>::from).collect::<Vec<_>>());
+
+    assert_that_code!(|| BoxPointMask::from_grid(&[vec![true, true], vec![true]])).panics().with_having_message("All rows must be the same length");
+
+    assert_that_code!(|| BoxPointMask::from_grid(&[[false, false]])).panics().with_having_message("The mask must include at least one point");
+}
+
+#[test]
+fn test_generated_maze_stays_in_mask() {
+    let mask = get_ring_mask();
+
+    for seed in 0..5 {
+        let maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(mask.space()).build();
+        let mut maze = MaskedMazeCoordinator::new(maze, &mask);
+
+        if seed % 2 == 0 {
+            HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+        } else {
+            RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+        }
+
+        let space = *maze.coord_space();
+
+        for pt in space.unmasked().iter() {
+            assert_eq!(space.contains(pt), maze.get(pt).is_fully_visited());
+        }
+
+        // Every point in the mask must be reachable, without any loops
+        let mut passages = 0;
+
+        for pt in space.iter() {
+            passages += space.neighbours_of_pt(pt).into_iter().filter(|neighbour| maze.is_passage_between(pt, *neighbour)).count();
+        }
+
+        assert_eq!(usize::from(space.logical_size()) - 1, passages / 2);
+
+        let origin = space.iter().next().unwrap();
+
+        let mut visited = HashSet::from([origin]);
+        let mut queue = VecDeque::from([origin]);
+
+        while let Some(pt) = queue.pop_front() {
+            for neighbour in space.neighbours_of_pt(pt) {
+                if maze.is_passage_between(pt, neighbour) && visited.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        assert_eq!(usize::from(space.logical_size()), visited.len());
+    }
+}
+
+#[test]
+fn test_mismatched_mask() {
+    let mask = get_ring_mask();
+
+    assert_that_code!(|| MaskedMazeCoordinator::new(BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([11, 10])).build(), &mask)).panics().with_having_message("The mask must have the same dimensions as the maze");
+}
+
+#[test]
+fn test_neighbours_into() {
+    let mask = get_ring_mask();
+    let space = MaskedBoxCoordinateSpace::new(&mask);
+
+    let mut neighbours = Vec::new();
+
+    for pt in space.unmasked().iter() {
+        neighbours.clear();
+        space.neighbours_into(pt, &mut neighbours);
+
+        assert_eq!(space.neighbours_of_pt(pt), neighbours);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,156 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use crate::implm::export::metadata::{format_timestamp, MazeMetadata};
+
+fn metadata() -> MazeMetadata {
+    MazeMetadata {
+        title: Some("Fish & Chips".to_string()),
+        seed: Some(4946),
+        algorithm: Some("Hunt-and-kill".to_string()),
+        timestamp: Some(UNIX_EPOCH + Duration::from_secs(1_714_566_600)),
+        author: Some("Zoë".to_string()),
+    }
+}
+
+#[test]
+fn test_timestamps_are_formatted_in_utc() {
+    assert_eq!("1970-01-01T00:00:00Z", format_timestamp(UNIX_EPOCH));
+    assert_eq!("2024-05-01T12:30:00Z", format_timestamp(UNIX_EPOCH + Duration::from_secs(1_714_566_600)));
+    assert_eq!("1969-07-20T20:17:40Z", format_timestamp(UNIX_EPOCH - Duration::from_secs(14_182_940)));
+}
+
+#[test]
+fn test_empty_metadata() {
+    assert!(MazeMetadata::default().is_empty());
+    assert!(metadata().is_empty() == false);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_json_metadata() {
+    use serde_json::{json, Value};
+
+    use crate::implm::buffer::VecBuffer;
+    use crate::implm::cell::block::BlockCellValue;
+    use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+    use crate::implm::export::json::BoxSpaceBlockCellJsonMazeExporter;
+    use crate::implm::import::json::BoxSpaceBlockCellJsonMazeImporter;
+    use crate::implm::point::boxy::BoxCoordinateSpace;
+    use crate::interface::export::MazeExporter;
+    use crate::interface::import::MazeImporter;
+
+    type BlockMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+    let maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([2, 2])).build();
+
+    let mut plain = Vec::new();
+    BoxSpaceBlockCellJsonMazeExporter::new().export(&maze, &mut plain).unwrap();
+
+    assert!(serde_json::from_slice::<Value>(&plain).unwrap().get("metadata").is_none());
+
+    let mut described = Vec::new();
+    BoxSpaceBlockCellJsonMazeExporter::new().with_metadata(metadata()).export(&maze, &mut described).unwrap();
+
+    let expected = json!({
+        "title": "Fish & Chips",
+        "seed": 4946,
+        "algorithm": "Hunt-and-kill",
+        "timestamp": "2024-05-01T12:30:00Z",
+        "author": "Zoë",
+    });
+
+    assert_eq!(expected, serde_json::from_slice::<Value>(&described).unwrap()["metadata"]);
+
+    // The metadata doesn't get in the way of importing
+    let imported: BlockMaze = BoxSpaceBlockCellJsonMazeImporter::new().import(&mut described.as_slice()).unwrap();
+
+    assert!(imported == maze);
+}
+
+#[cfg(feature = "img")]
+#[test]
+fn test_png_metadata() {
+    use std::io::Cursor;
+
+    use image::ImageFormat;
+
+    use crate::implm::export::img::BoxSpaceImageMazeExporter;
+    use crate::implm::point::boxy::BoxCoordinateSpace;
+    use crate::implm::presets::Standard2DMaze;
+    use crate::interface::export::MazeExporter;
+
+    let maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    let mut png = Cursor::new(Vec::new());
+    BoxSpaceImageMazeExporter::new(ImageFormat::Png).with_metadata(metadata()).export(&maze, &mut png).unwrap();
+    let png = png.into_inner();
+
+    let contains = |needle: &[u8]| png.windows(needle.len()).any(|window| window == needle);
+
+    assert!(contains(b"tEXtTitle\0Fish & Chips"));
+    assert!(contains(b"tEXtCreation Time\x002024-05-01T12:30:00Z"));
+    assert!(contains(b"tEXtSeed\x004946"));
+    assert!(contains(b"tEXtAuthor\0Zo\xEB"));
+
+    // The image is still valid, checksums included
+    let decoded = image::load_from_memory_with_format(&png, ImageFormat::Png).unwrap();
+
+    assert_eq!(maze.get_full_dimensions().map(|dim| usize::from(dim) as u32), [decoded.width(), decoded.height()]);
+}
+
+#[cfg(feature = "img")]
+#[test]
+fn test_png_metadata_falls_back_to_utf8() {
+    use std::io::Cursor;
+
+    use image::ImageFormat;
+
+    use crate::implm::export::img::BoxSpaceImageMazeExporter;
+    use crate::implm::point::boxy::BoxCoordinateSpace;
+    use crate::implm::presets::Standard2DMaze;
+    use crate::interface::export::MazeExporter;
+
+    let maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([2, 2])).build();
+
+    let metadata = MazeMetadata { title: Some("迷路".to_string()), ..MazeMetadata::default() };
+
+    let mut png = Cursor::new(Vec::new());
+    BoxSpaceImageMazeExporter::new(ImageFormat::Png).with_metadata(metadata).export(&maze, &mut png).unwrap();
+    let png = png.into_inner();
+
+    let needle = [b"iTXtTitle\0\0\0\0\0".as_slice(), "迷路".as_bytes()].concat();
+
+    assert!(png.windows(needle.len()).any(|window| window == needle));
+    assert!(image::load_from_memory_with_format(&png, ImageFormat::Png).is_ok());
+}
+
+#[test]
+fn test_svg_metadata() {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::implm::buffer::VecBuffer;
+    use crate::implm::cell::block::BlockCellValue;
+    use crate::implm::coordinate::graph::GraphSpaceGraphCellMazeCoordinator;
+    use crate::implm::export::svg::VoronoiSvgMazeExporter;
+    use crate::implm::point::graph::VoronoiDiagram;
+    use crate::interface::export::MazeExporter;
+
+    let diagram = VoronoiDiagram::random_checked([10.0, 10.0], 20, &mut ChaCha8Rng::seed_from_u64(4946));
+    let maze = GraphSpaceGraphCellMazeCoordinator::<VecBuffer<BlockCellValue>>::builder(diagram.space()).build();
+
+    let mut svg = Vec::new();
+    VoronoiSvgMazeExporter::builder(&diagram).metadata(metadata()).build().export(&maze, &mut svg).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+
+    assert!(svg.contains("<title>Fish &amp; Chips</title>"));
+    assert!(svg.contains("<dc:creator>Zoë</dc:creator>"));
+    assert!(svg.contains("<dc:date>2024-05-01T12:30:00Z</dc:date>"));
+    assert!(svg.contains("<mazelib:seed>4946</mazelib:seed>"));
+    assert!(svg.ends_with("</svg>\n"));
+
+    let mut plain = Vec::new();
+    VoronoiSvgMazeExporter::builder(&diagram).build().export(&maze, &mut plain).unwrap();
+
+    assert!(String::from_utf8(plain).unwrap().contains("<metadata>") == false);
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+#![cfg(feature = "mmap")]
+
+use std::io::ErrorKind;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::{MmapBuffer, VecBuffer};
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+#[test]
+fn test_reopened_buffer_keeps_generated_maze() {
+    let path = std::env::temp_dir().join(format!("mazelib-test-mmap-reopen-{}.bin", std::process::id()));
+
+    let builder = BoxSpaceBlockCellMazeCoordinator::<MmapBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([6, 5]));
+
+    let mut maze = builder.build_with_buffer(MmapBuffer::create(&path, builder.cells_required()).unwrap());
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(3));
+    drop(maze);
+
+    let mut expected = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([6, 5])).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut expected, &mut ChaCha8Rng::seed_from_u64(3));
+
+    let reopened = builder.build_with_buffer(unsafe { MmapBuffer::open(&path) }.unwrap());
+
+    std::fs::remove_file(&path).unwrap();
+
+    for pt in expected.coord_space().iter() {
+        assert_eq!(expected.get(pt), reopened.get(pt));
+    }
+}
+
+#[test]
+fn test_open_rejects_other_files() {
+    let path = std::env::temp_dir().join(format!("mazelib-test-mmap-reject-{}.bin", std::process::id()));
+
+    std::fs::write(&path, b"this is not a maze buffer, just some text").unwrap();
+
+    let result = unsafe { MmapBuffer::<BlockCellValue>::open(&path) };
+
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(ErrorKind::InvalidData, result.unwrap_err().kind());
+}
\ No newline at end of file
@@ -0,0 +1,94 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::mobius::MobiusSpaceInlineCellMazeCoordinator;
+use crate::implm::export::text::MobiusSpaceInlineCellTextMazeExporter;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::mobius::MobiusCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::DefaultMazeExporter;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::test::assert_perfect;
+
+type Maze = MobiusSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<2>>>;
+
+#[test]
+fn test_mobius_adjacency_is_symmetric() {
+    let space = MobiusCoordinateSpace::new_checked([6, 4]);
+
+    for pt in space.iter() {
+        for neighbour in space.neighbours_of_pt(pt) {
+            assert!(space.are_adjacent(pt, neighbour));
+            assert!(space.neighbours_of_pt(neighbour).contains(&pt), "{:?} is not a neighbour of {:?}", pt, neighbour);
+        }
+    }
+
+    // Every point has four neighbours except for the corners of the top and bottom
+    assert_eq!(3, space.neighbours_of_pt([0, 0].into()).len());
+    assert_eq!(4, space.neighbours_of_pt([0, 1].into()).len());
+    assert_eq!(4, space.neighbours_of_pt([2, 1].into()).len());
+
+    assert_eq!(Some([0, 3].into()), space.across_join([5, 0].into()));
+    assert_eq!(Some([5, 2].into()), space.across_join([0, 1].into()));
+
+    // Short strips aren't joined
+    let space = MobiusCoordinateSpace::new_checked([2, 4]);
+
+    assert!(space.are_adjacent([1, 0].into(), [0, 3].into()) == false);
+}
+
+#[test]
+fn test_mobius_passage_across_join() {
+    let mut maze = Maze::builder(MobiusCoordinateSpace::new_checked([4, 3])).build();
+
+    maze.make_passage_between([0, 0].into(), [3, 2].into());
+
+    assert!(maze.is_passage_between([3, 2].into(), [0, 0].into()));
+    assert!(maze.is_passage_between([3, 0].into(), [0, 2].into()) == false);
+
+    assert_eq!(InlineCellValueEdge::PASSAGE, maze.get([0, 0].into()).edges[0][0]);
+    assert_eq!(InlineCellValueEdge::PASSAGE, maze.get([3, 2].into()).edges[0][1]);
+    assert_eq!(InlineCellValueEdge::WALL, maze.get([0, 0].into()).edges[0][1]);
+
+    maze.make_wall_between([3, 2].into(), [0, 0].into());
+
+    assert!(maze.is_passage_between([0, 0].into(), [3, 2].into()) == false);
+}
+
+#[test]
+fn test_mobius_generated_is_perfect() {
+    for seed in 0..4 {
+        let mut maze = Maze::builder(MobiusCoordinateSpace::new_checked([9, 5])).build();
+        RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+        assert_perfect(&maze);
+
+        let mut maze = Maze::builder(MobiusCoordinateSpace::new_checked([9, 5])).build();
+        HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+        assert_perfect(&maze);
+    }
+}
+
+#[test]
+fn test_mobius_text_export() {
+    let mut maze = Maze::builder(MobiusCoordinateSpace::new_checked([3, 2])).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+    maze.make_passage_between([1, 0].into(), [2, 0].into());
+    maze.make_passage_between([2, 0].into(), [0, 1].into());
+    maze.make_passage_between([0, 1].into(), [1, 1].into());
+    maze.make_passage_between([1, 1].into(), [2, 1].into());
+
+    let mut text = Vec::new();
+    MobiusSpaceInlineCellTextMazeExporter::export(&maze, &mut text).unwrap();
+
+    // The join is drawn as a gap on the right of the top row and on the left of the bottom row
+    assert_eq!("┌───────────╴
+│           
+└───────────┐
+            │
+╶───────────┘
+", String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+}
\ No newline at end of file
@@ -1,7 +1,124 @@
+mod agent;
+mod animation;
+mod annotations;
+mod ascii_mask;
+mod binary_round_trip;
 mod box_space_block_cell_maze;
+mod border;
+mod braid;
+mod bulk;
 mod box_space_iterator;
 mod box_coordinate_space;
+mod cave;
+mod checkpoint;
+mod chunked;
+mod colour_fn;
+mod components;
+mod composite;
+mod constrained;
+mod corridor;
+mod crop;
+mod cube;
+mod delta;
+mod direction;
+mod draw;
+mod equality;
+mod error;
+mod export_owned;
+mod fingerprint;
+mod floors;
 mod generator;
+mod gltf;
+mod grow;
+mod hex;
+mod import;
+mod infinite;
+mod inline_3d;
+mod isometric;
+mod mark;
+mod marked_export;
+mod masked;
+mod metadata;
+mod mmap;
+mod mobius;
+mod named_points;
+mod nested;
+mod noise;
+mod observed;
+mod occupancy;
+mod par_iter;
 mod path;
+mod petgraph;
+mod placement;
+mod polar;
 mod polar_coordinate_space;
-mod implm;
\ No newline at end of file
+mod portals;
+mod quality;
+mod quick;
+mod region;
+mod rle;
+mod rng;
+mod ros_export;
+mod slice;
+mod spiral;
+mod solid_border;
+mod spacing;
+mod stairs;
+mod stamp;
+mod sync_buffer;
+mod template;
+mod terrain;
+mod testing;
+mod text_layout;
+mod themes;
+mod tiled_export;
+mod transform;
+mod undo;
+mod unity_export;
+mod unvisited;
+mod upsilon;
+mod voronoi;
+mod vox;
+mod wasm;
+mod wavefront;
+mod weave;
+mod wrapping;
+mod zeta;
+mod implm;
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Assert every point is fully visited and reachable, and that there are no loops.
+fn assert_perfect<M: MazeCoordinator>(maze: &M) {
+    let space = maze.coord_space();
+
+    let mut passages = 0;
+
+    for pt in space.iter() {
+        assert!(maze.get(pt).is_fully_visited(), "{:?} is not fully visited", pt);
+
+        passages += space.neighbours_of_pt(pt).into_iter().filter(|neighbour| maze.is_passage_between(pt, *neighbour)).count();
+    }
+
+    // Every passage is counted from both ends. A spanning tree has one fewer edge than it has points.
+    assert_eq!(usize::from(space.logical_size()) - 1, passages / 2);
+
+    let origin = space.iter().next().expect("[Bug] Coordinate spaces are never empty");
+
+    let mut reached = HashSet::from([origin]);
+    let mut queue = VecDeque::from([origin]);
+
+    while let Some(pt) = queue.pop_front() {
+        for neighbour in space.neighbours_of_pt(pt) {
+            if maze.is_passage_between(pt, neighbour) && reached.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    assert_eq!(usize::from(space.logical_size()), reached.len());
+}
@@ -0,0 +1,87 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::point::CoordinateSpace;
+use crate::mark::{Marks, NamedPoints};
+use crate::quick::{self, Algorithm};
+use crate::util::set_longest_path_endpoints;
+
+type BlockMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[test]
+fn test_named_points_on_coordinator() {
+    let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([4, 4])).build();
+
+    assert!(maze.named_points().is_empty());
+
+    assert_eq!(None, maze.set_start([0, 0].into()));
+    assert_eq!(Some([0, 0].into()), maze.set_start([1, 0].into()));
+    maze.named_points_mut().set("key", [2, 3].into());
+
+    assert_eq!(Some([1, 0].into()), maze.start());
+    assert_eq!(None, maze.goal());
+    assert_eq!(Some([1, 0].into()), maze.named_points().get(NamedPoints::<CoordinateTuplet<2>>::START));
+    assert_eq!(Some([2, 3].into()), maze.named_points().get("key"));
+    assert_eq!(2, maze.named_points().len());
+}
+
+#[test]
+fn test_marks_on_coordinator() {
+    let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([4, 4])).build();
+
+    assert_eq!(&Marks::new(), maze.marks());
+
+    maze.set_goal([3, 3].into());
+    maze.portals_mut().insert([0, 0].into(), [3, 0].into());
+    maze.marks_mut().named_points.set("key", [2, 3].into());
+
+    assert_eq!(Some([3, 3].into()), maze.marks().named_points.goal());
+    assert!(maze.marks().portals.contains([3, 0].into(), [0, 0].into()));
+    assert_eq!(Some([2, 3].into()), maze.named_points().get("key"));
+    assert!(maze.annotations().is_empty());
+}
+
+#[test]
+fn test_text_export_draws_start_and_goal() {
+    let mut maze = BlockMaze::from_walls(&[
+        [true, true, true, true],
+        [true, false, false, true],
+        [true, true, true, true],
+    ]);
+
+    maze.set_start([1, 1].into());
+    maze.set_goal([2, 1].into());
+
+    let mut text = Vec::new();
+    BoxSpaceBlockCellTextMazeExporter::builder().chars_per_cell_horizontally_checked(1).goal_char('E').build().export(&maze, &mut text).unwrap();
+
+    assert_eq!("████\n█SE█\n████\n", String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+}
+
+#[test]
+fn test_longest_path_endpoints_of_generated_maze() {
+    for algorithm in [Algorithm::HuntAndKill, Algorithm::RecursiveBacktracker, Algorithm::NAryTree] {
+        let maze = quick::generate_2d_inline(6, 5, algorithm, 17);
+
+        let (start, goal) = (maze.start().unwrap(), maze.goal().unwrap());
+
+        // Both ends of the longest path of a perfect maze are dead ends
+        for endpoint in [start, goal] {
+            assert_eq!(1, maze.coord_space().neighbours_of_pt(endpoint).into_iter().filter(|neighbour| maze.is_passage_between(endpoint, *neighbour)).count());
+        }
+
+        assert_ne!(start, goal);
+    }
+}
+
+#[test]
+fn test_longest_path_endpoints_of_unvisited_maze() {
+    let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    assert_eq!(None, set_longest_path_endpoints(&mut maze));
+    assert_eq!(None, maze.start());
+}
\ No newline at end of file
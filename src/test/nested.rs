@@ -0,0 +1,105 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::implm::presets::{Inline2DMaze, Inline2DMazeBuilder};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::nested::{NestedMaze, NestedPoint};
+
+fn generate(seed: u64) -> NestedMaze<Inline2DMaze, Inline2DMaze, 2> {
+    let outer = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([4, 3])).build();
+
+    let mut maze = NestedMaze::new(outer, |_| Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([5, 4])).build());
+
+    maze.generate_with_rng(&mut HuntAndKillGenerator::new(), &mut RecursiveBacktrackerGenerator::new(), &mut ChaCha8Rng::seed_from_u64(seed));
+
+    return maze
+}
+
+#[test]
+fn test_global_coordinates() {
+    let maze = generate(0);
+
+    assert_eq!([20, 12], maze.global_dimensions());
+
+    let pt = NestedPoint::new([3, 1], [2, 3]);
+
+    assert_eq!(CoordinateTuplet([17, 7]), maze.to_global(pt));
+    assert_eq!(pt, maze.from_global([17, 7].into()));
+
+    for global in BoxCoordinateSpace::new_checked(maze.global_dimensions()).iter() {
+        assert_eq!(global, maze.to_global(maze.from_global(global)));
+    }
+}
+
+#[test]
+fn test_doorways_follow_the_overworld() {
+    let maze = generate(1);
+    let outer_space = *maze.outer().coord_space();
+
+    for from in outer_space.iter() {
+        for to in outer_space.neighbours_of_pt(from) {
+            let doorway = maze.doorway(from, to);
+
+            assert_eq!(maze.outer().is_passage_between(from, to), doorway.is_some());
+
+            // Either side of a doorway is next to the other in the whole maze
+            if let Some((exit, entry)) = doorway {
+                let exit = maze.to_global(exit);
+                let entry = maze.to_global(entry);
+
+                assert_eq!(1, (0..2).map(|axis| exit[axis].abs_diff(entry[axis])).sum::<usize>());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_resolve_path() {
+    for seed in 0..5 {
+        let maze = generate(seed);
+
+        let start = NestedPoint::new([0, 0], [0, 0]);
+        let goal = NestedPoint::new([3, 2], [4, 3]);
+
+        let path = maze.resolve_path(start, goal).unwrap();
+
+        assert_eq!(start, path[0]);
+        assert_eq!(goal, *path.last().unwrap());
+
+        for step in path.windows(2) {
+            if step[0].outer == step[1].outer {
+                assert!(maze.inner(step[0].outer).is_passage_between(step[0].inner, step[1].inner));
+            } else {
+                assert_eq!(Some((step[0], step[1])), maze.doorway(step[0].outer, step[1].outer));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_resolve_path_without_doorways() {
+    let mut maze = generate(2);
+
+    maze.place_doorways(&mut ChaCha8Rng::seed_from_u64(0));
+    assert!(maze.resolve_path(NestedPoint::new([0, 0], [0, 0]), NestedPoint::new([1, 0], [0, 0])).is_some());
+
+    // The overworld has no passages, so there are no doorways
+    *maze.outer_mut() = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([4, 3])).build();
+    maze.place_doorways(&mut ChaCha8Rng::seed_from_u64(0));
+
+    assert!(maze.resolve_path(NestedPoint::new([0, 0], [0, 0]), NestedPoint::new([1, 0], [0, 0])).is_none());
+    assert!(maze.resolve_path(NestedPoint::new([0, 0], [0, 0]), NestedPoint::new([0, 0], [4, 3])).is_some());
+}
+
+#[test]
+fn test_mismatched_sub_mazes() {
+    assert_that_code!(|| {
+        let outer = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([2, 1])).build();
+
+        let _ = NestedMaze::new(outer, |pt| Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3 + pt[0], 3])).build());
+    }).panics().with_having_message("Every sub-maze must have the same dimensions");
+}
\ No newline at end of file
@@ -0,0 +1,48 @@
+#![cfg(feature = "noise")]
+
+use noise::Constant;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::generate::NoiseBiasedGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::{Inline2DMaze, Inline2DMazeBuilder, Inline3DMazeBuilder};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::count_unvisited;
+
+/// Count the points where a passage turns or forks, rather than running straight through.
+fn count_turns(maze: &Inline2DMaze) -> usize {
+    maze.coord_space().iter().filter(|&pt| {
+        let neighbours: Vec<_> = maze.coord_space().neighbours_of_pt(pt).into_iter().filter(|&neighbour| maze.is_passage_between(pt, neighbour)).collect();
+
+        !(neighbours.len() == 2 && (0..2).all(|axis| neighbours[0][axis] + neighbours[1][axis] == 2 * pt[axis]))
+    }).count()
+}
+
+#[test]
+fn test_noise_biased_generator_visits_every_point() {
+    let mut maze = Inline3DMazeBuilder::new(BoxCoordinateSpace::new_checked([5, 4, 3])).build();
+
+    NoiseBiasedGenerator::new(noise::Perlin::new(3), 4.0).generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(3));
+
+    assert_eq!(0, count_unvisited(&maze));
+}
+
+#[test]
+fn test_high_noise_runs_straight() {
+    let mut straight = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([20, 20])).build();
+    let mut twisty = straight.clone();
+
+    NoiseBiasedGenerator::new(Constant::new(1.0), 1.0).generate_with_rng(&mut straight, &mut ChaCha8Rng::seed_from_u64(8));
+    NoiseBiasedGenerator::new(Constant::new(-1.0), 1.0).generate_with_rng(&mut twisty, &mut ChaCha8Rng::seed_from_u64(8));
+
+    assert!(count_turns(&straight) < count_turns(&twisty));
+}
+
+#[test]
+#[should_panic(expected = "The scale must be positive")]
+fn test_zero_scale_panics() {
+    let _ = NoiseBiasedGenerator::new(Constant::new(0.0), 0.0);
+}
\ No newline at end of file
@@ -0,0 +1,83 @@
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::{ObservedBuffer, VecBuffer};
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+type Log<CellVal> = Arc<Mutex<Vec<(CellID, CellVal, CellVal)>>>;
+
+fn recorder<CellVal: Send + 'static>() -> (Log<CellVal>, impl FnMut(CellID, CellVal, CellVal) + Send + 'static) {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&log);
+
+    return (log, move |cell, old, new| sink.lock().unwrap().push((cell, old, new)))
+}
+
+#[test]
+fn test_replaying_changes_reproduces_generation() {
+    let space = BoxCoordinateSpace::new_checked([6, 5]);
+    let (log, observer) = recorder();
+
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<ObservedBuffer<InlineCellValue<2>>, 2>::new(space)
+        .build_with_buffer(ObservedBuffer::wrap(VecBuffer::new(space.logical_size()), observer));
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4));
+    maze.buffer().flush();
+
+    let mut replay = VecBuffer::<InlineCellValue<2>>::new(space.logical_size());
+
+    for (cell, old, new) in log.lock().unwrap().iter() {
+        assert_ne!(old, new);
+        assert_eq!(*old, replay.get(*cell));
+
+        replay.set(*cell, *new);
+    }
+
+    for pt in space.iter() {
+        assert_eq!(maze.get(pt), replay.get(CellID(pt[0] + pt[1] * 6)));
+    }
+}
+
+#[test]
+fn test_changes_through_each_write_are_reported() {
+    let (log, observer) = recorder();
+
+    let mut buffer = ObservedBuffer::wrap(VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(5).unwrap()), observer);
+
+    let wall = BlockCellValue { cell_type: BlockCellPrimaryValue::WALL, ..Default::default() };
+    let passage = BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, ..Default::default() };
+
+    buffer.set(CellID(0), wall);
+    buffer.set(CellID(0), wall);
+    buffer.set_range(CellID(0)..CellID(2), wall);
+    buffer.get_mut(CellID(3)).cell_type = BlockCellPrimaryValue::PASSAGE;
+
+    // The change through get_mut() is reported on the next access
+    assert_eq!(2, log.lock().unwrap().len());
+    assert_eq!(passage, buffer.get(CellID(3)));
+
+    assert_eq!(vec![
+        (CellID(0), BlockCellValue::default(), wall),
+        (CellID(1), BlockCellValue::default(), wall),
+        (CellID(3), BlockCellValue::default(), passage),
+    ], *log.lock().unwrap());
+
+    assert!(buffer.remove_observer());
+
+    buffer.set(CellID(4), wall);
+    buffer.flush();
+
+    assert_eq!(3, log.lock().unwrap().len());
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use std::num::NonZeroUsize;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::{CountingBuffer, Occupancy, VecBuffer};
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+use crate::interface::generate::MazeGenerator;
+use crate::util::apply_solid_border;
+
+#[test]
+fn test_counts_match_scan_after_generation() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<CountingBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([7, 5])).build();
+
+    apply_solid_border(&mut maze);
+
+    let occupancy = maze.buffer().occupancy();
+    assert_eq!(2 * (15 + 11) - 4, occupancy.boundary);
+    assert_eq!(15 * 11 - occupancy.boundary, occupancy.unvisited);
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(9));
+
+    let occupancy = maze.buffer().occupancy();
+    assert_eq!(Occupancy::of(maze.buffer()), occupancy);
+    assert_eq!(15 * 11, occupancy.total());
+}
+
+#[test]
+fn test_inline_counts_match_scan_after_generation() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<CountingBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([6, 6])).build();
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(9));
+
+    assert_eq!(Occupancy::of(maze.buffer()), maze.buffer().occupancy());
+    assert_eq!(36, maze.buffer().occupancy().passage);
+}
+
+#[test]
+fn test_wrap_counts_existing_cells() {
+    let mut buffer = VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(10).unwrap());
+
+    buffer.set_range(CellID(2)..CellID(5), BlockCellValue { cell_type: BlockCellPrimaryValue::WALL, ..Default::default() });
+    buffer.set(CellID(7), BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, ..Default::default() });
+
+    let mut buffer = CountingBuffer::wrap(buffer);
+
+    assert_eq!(Occupancy { unvisited: 6, passage: 1, wall: 3, boundary: 0 }, buffer.occupancy());
+
+    buffer.get_mut(CellID(7)).cell_type = BlockCellPrimaryValue::BOUNDARY;
+    buffer.fill(BlockCellValue { cell_type: BlockCellPrimaryValue::WALL, ..Default::default() });
+
+    assert_eq!(Occupancy { unvisited: 0, passage: 0, wall: 10, boundary: 0 }, buffer.occupancy());
+}
\ No newline at end of file
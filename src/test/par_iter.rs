@@ -0,0 +1,44 @@
+#![cfg(feature = "rayon")]
+
+use rayon::prelude::*;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::par_convert_unvisited_points_to_walls;
+
+#[test]
+fn test_par_iter_matches_iter() {
+    let space = BoxCoordinateSpace::new_checked([7, 1, 5, 3]);
+
+    let expected: Vec<_> = space.iter().collect();
+
+    assert_eq!(expected.len(), space.par_iter().len());
+    assert_eq!(expected, space.par_iter().collect::<Vec<_>>());
+    assert_eq!(expected.iter().rev().copied().collect::<Vec<_>>(), space.par_iter().rev().collect::<Vec<_>>());
+    assert_eq!(expected.iter().copied().enumerate().collect::<Vec<_>>(), space.par_iter().enumerate().collect::<Vec<_>>());
+
+    // Force the points to be split between many producers
+    assert_eq!(expected, space.par_iter().with_max_len(2).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_par_convert_unvisited_points_to_walls() {
+    let space = BoxCoordinateSpace::new_checked([9, 9]);
+
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).scale_factors_checked([2, 2]).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+    maze.make_passage([1, 0].into());
+
+    par_convert_unvisited_points_to_walls(&mut maze);
+
+    for pt in space.iter() {
+        let expected = if pt[1] == 0 && pt[0] <= 1 { BlockCellPrimaryValue::PASSAGE } else { BlockCellPrimaryValue::WALL };
+
+        assert_eq!(expected, maze.get(pt).cell_type);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+#![cfg(feature = "petgraph")]
+
+use petgraph::algo::{astar, connected_components};
+use petgraph::graph::{NodeIndex, UnGraph};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::coordinate::graph::GraphSpaceGraphCellMazeCoordinator;
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::point::graph::{Graph, GraphCoordinateSpace};
+use crate::implm::presets::Standard2DMaze;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::to_petgraph;
+
+#[test]
+fn test_shortest_path_follows_passages() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([3, 2])).build();
+
+    // A U-shaped corridor, so [0, 0] and [2, 0] are four steps apart despite being close
+    maze.make_passage_between([0, 0].into(), [0, 1].into());
+    maze.make_passage_between([0, 1].into(), [1, 1].into());
+    maze.make_passage_between([1, 1].into(), [2, 1].into());
+    maze.make_passage_between([2, 1].into(), [2, 0].into());
+
+    let graph = to_petgraph(&maze);
+
+    assert_eq!(4, graph.edge_count());
+    assert_eq!(2, connected_components(&graph));
+
+    let (cost, path) = astar(&graph, NodeIndex::new(0), |node| node == NodeIndex::new(2), |_| 1, |_| 0).unwrap();
+
+    assert_eq!(4, cost);
+    assert_eq!(maze.coord_space().iter().nth(5), path.get(3).map(|node| graph[*node]));
+}
+
+#[test]
+fn test_graph_round_trip() {
+    // A wheel with six spokes
+    let petgraph = UnGraph::<(), ()>::from_edges((1..7).flat_map(|i| [(0, i), (i, i % 6 + 1)]));
+
+    let graph = Graph::from(&petgraph);
+
+    assert_eq!(12, graph.edge_count());
+
+    let mut maze = GraphSpaceGraphCellMazeCoordinator::<VecBuffer<BlockCellValue>>::builder(GraphCoordinateSpace::new(&graph)).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(2));
+
+    let passages = to_petgraph(&maze);
+
+    assert_eq!(7, passages.node_count());
+    assert_eq!(6, passages.edge_count());
+    assert_eq!(1, connected_components(&passages));
+
+    for edge in passages.raw_edges() {
+        assert!(petgraph.find_edge(edge.source(), edge.target()).is_some());
+    }
+}
\ No newline at end of file
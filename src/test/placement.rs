@@ -0,0 +1,78 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::implm::presets::{Inline2DMaze, Inline2DMazeBuilder};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::{dead_ends_by_depth, farthest_from, pockets};
+
+fn exits(maze: &Inline2DMaze, pt: CoordinateTuplet<2>) -> usize {
+    maze.neighbours_of_pt(pt).into_iter().filter(|neighbour| maze.is_passage_between(pt, *neighbour)).count()
+}
+
+fn generate(seed: u64) -> Inline2DMaze {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([12, 10])).build();
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+
+    return maze
+}
+
+#[test]
+fn test_farthest_from_covers_the_maze() {
+    let maze = generate(4961);
+
+    let ranked = farthest_from(&maze, [0, 0].into());
+
+    assert_eq!(120, ranked.len());
+    assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    assert_eq!((CoordinateTuplet([0, 0]), 0), *ranked.last().unwrap());
+}
+
+#[test]
+fn test_dead_ends_by_depth() {
+    let maze = generate(1);
+
+    let dead_ends = dead_ends_by_depth(&maze);
+
+    assert!(dead_ends.is_empty() == false);
+    assert!(dead_ends.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    assert!(dead_ends.iter().all(|(pt, depth)| exits(&maze, *pt) == 1 && *depth >= 1));
+    assert_eq!(dead_ends.len(), maze.coord_space().iter().filter(|pt| exits(&maze, *pt) == 1).count());
+}
+
+#[test]
+fn test_pockets() {
+    let maze = generate(2);
+
+    let pockets = pockets(&maze);
+    let dead_ends = dead_ends_by_depth(&maze);
+
+    assert!(pockets.windows(2).all(|pair| pair[0].len() >= pair[1].len()));
+
+    // In a perfect maze with junctions, every pocket is the corridor to a dead end
+    assert_eq!(dead_ends.len(), pockets.len());
+
+    for pocket in &pockets {
+        assert_eq!(1, exits(&maze, pocket[0]));
+        assert!(pocket.iter().all(|pt| exits(&maze, *pt) <= 2));
+
+        let depth = dead_ends.iter().find(|(pt, _)| *pt == pocket[0]).unwrap().1;
+
+        assert_eq!(depth, pocket.len());
+    }
+}
+
+#[test]
+fn test_pocket_without_junctions() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 1])).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+    maze.make_passage_between([1, 0].into(), [2, 0].into());
+
+    assert_eq!(vec![vec![CoordinateTuplet([0, 0]), CoordinateTuplet([1, 0]), CoordinateTuplet([2, 0])]], pockets(&maze));
+    assert_eq!(vec![(CoordinateTuplet([0, 0]), 2), (CoordinateTuplet([2, 0]), 2)], dead_ends_by_depth(&maze));
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::inline::InlineCellValueEdge;
+use crate::implm::cell::polar::PolarCellValue;
+use crate::implm::coordinate::polar::PolarSpaceInlineCellMazeCoordinator;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::polar::{PolarCoordinate, PolarCoordinateSpace, PolarEdge};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::test::assert_perfect;
+
+type PolarMaze = PolarSpaceInlineCellMazeCoordinator<VecBuffer<PolarCellValue>>;
+
+#[test]
+fn test_subdivided_adjacency() {
+    let space = PolarCoordinateSpace::new_subdivided_checked(8, 3);
+
+    assert_eq!([3, 6, 6, 12, 12, 12, 12, 24], [0, 1, 2, 3, 4, 5, 6, 7].map(|ring| usize::from(space.sectors_in_ring(ring))));
+    assert_eq!(87, usize::from(space.logical_size()));
+    assert_eq!(87, space.iter().count());
+
+    // Ring 0 has no inward neighbour, and ring 1 is subdivided
+    assert_eq!(4, space.neighbours_of_pt(PolarCoordinate { ring: 0, sector: 0 }).len());
+    // Ring 2 is not subdivided
+    assert_eq!(4, space.neighbours_of_pt(PolarCoordinate { ring: 1, sector: 0 }).len());
+    // Ring 3 is subdivided
+    assert_eq!(5, space.neighbours_of_pt(PolarCoordinate { ring: 2, sector: 0 }).len());
+    // The outer ring has no outward neighbours
+    assert_eq!(3, space.neighbours_of_pt(PolarCoordinate { ring: 7, sector: 23 }).len());
+
+    // Adjacency must be symmetric
+    for pt in space.iter() {
+        for edge in PolarEdge::ALL {
+            if let Some(neighbour) = space.neighbour(pt, edge) {
+                assert!(space.contains(neighbour));
+                assert!(space.edge_between(neighbour, pt).is_some());
+                assert!(space.are_adjacent(neighbour, pt));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generated_maze_is_perfect() {
+    for space in [PolarCoordinateSpace::new_checked(6, 10), PolarCoordinateSpace::new_subdivided_checked(9, 4), PolarCoordinateSpace::new_subdivided_checked(3, 1)] {
+        let mut maze = PolarMaze::builder(space).build();
+        RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+        assert_perfect(&maze);
+
+        let mut maze = PolarMaze::builder(space).build();
+        HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+        assert_perfect(&maze);
+    }
+}
+
+#[test]
+fn test_outward_edges() {
+    let space = PolarCoordinateSpace::new_subdivided_checked(2, 4);
+
+    let mut maze = PolarMaze::builder(space).build();
+
+    let inner = PolarCoordinate { ring: 0, sector: 2 };
+    let outer = PolarCoordinate { ring: 1, sector: 5 };
+
+    maze.make_passage_between(inner, outer);
+
+    assert!(maze.is_passage_between(inner, outer));
+    assert!(maze.is_passage_between(outer, inner));
+    assert!(maze.is_passage_between(inner, PolarCoordinate { ring: 1, sector: 4 }) == false);
+
+    assert_eq!(InlineCellValueEdge::PASSAGE, maze.get(inner).get_edge(PolarEdge::OUTWARD_CLOCKWISE));
+    assert_eq!(InlineCellValueEdge::WALL, maze.get(inner).get_edge(PolarEdge::OUTWARD));
+    assert_eq!(InlineCellValueEdge::PASSAGE, maze.get(outer).get_edge(PolarEdge::INWARD));
+}
\ No newline at end of file
@@ -57,4 +57,24 @@ fn test_adjacency() {
             }
         }
     }
+}
+
+#[test]
+fn test_neighbours() {
+    // More rings than sectors, so the outermost rings must still have inward neighbours and no
+    // outward neighbours
+    let space = PolarCoordinateSpace::new_checked(6, 3);
+
+    for pt in space.iter() {
+        let neighbours = space.neighbours_of_pt(pt);
+
+        let expected = 2 + usize::from(pt.ring > 0) + usize::from(pt.ring < 5);
+
+        assert_eq!(expected, neighbours.len());
+        assert!(neighbours.iter().all(|neighbour| space.are_adjacent(pt, *neighbour)));
+    }
+
+    // A single sector is not its own neighbour, and two sectors are only neighbours once
+    assert_eq!(vec![PolarCoordinate { ring: 1, sector: 0 }], PolarCoordinateSpace::new_checked(2, 1).neighbours_of_pt(PolarCoordinate { ring: 0, sector: 0 }));
+    assert_eq!(2, PolarCoordinateSpace::new_checked(2, 2).neighbours_of_pt(PolarCoordinate { ring: 0, sector: 0 }).len());
 }
\ No newline at end of file
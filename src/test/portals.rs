@@ -0,0 +1,119 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::hex::HexCellValue;
+use crate::implm::coordinate::hex::HexSpaceHexCellMazeCoordinator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::implm::point::hex::{HexCoordinate, HexCoordinateSpace};
+use crate::implm::presets::Inline2DMazeBuilder;
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::mark::Portals;
+use crate::util::{crop, fill_unreachable_regions, rotate90, set_longest_path_endpoints};
+
+#[test]
+fn test_portals_are_directional() {
+    let mut portals = Portals::<CoordinateTuplet<2>>::new();
+
+    assert!(portals.insert_one_way([0, 0].into(), [3, 3].into()));
+    assert!(portals.insert_one_way([0, 0].into(), [3, 3].into()) == false);
+    assert!(portals.insert([0, 0].into(), [3, 3].into()));
+
+    assert_eq!(2, portals.len());
+
+    assert!(portals.remove_one_way([3, 3].into(), [0, 0].into()));
+    assert!(portals.contains([0, 0].into(), [3, 3].into()));
+    assert!(portals.contains([3, 3].into(), [0, 0].into()) == false);
+
+    portals.insert([1, 1].into(), [3, 3].into());
+    portals.remove_all([3, 3].into());
+
+    assert!(portals.is_empty());
+}
+
+#[test]
+fn test_portal_equality_ignores_order() {
+    let mut a = Portals::<CoordinateTuplet<2>>::new();
+    a.insert_one_way([0, 0].into(), [1, 1].into());
+    a.insert_one_way([0, 0].into(), [2, 2].into());
+
+    let mut b = Portals::<CoordinateTuplet<2>>::new();
+    b.insert_one_way([0, 0].into(), [2, 2].into());
+    b.insert_one_way([0, 0].into(), [1, 1].into());
+
+    assert_eq!(a, b);
+
+    b.remove_one_way([0, 0].into(), [1, 1].into());
+
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_portals_are_passages() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    maze.portals_mut().insert_one_way([0, 0].into(), [2, 2].into());
+
+    assert!(maze.is_passage_between([0, 0].into(), [2, 2].into()));
+
+    let portal_connections: Vec<_> = maze.connections().filter(|(from, to, _)| maze.coord_space().are_adjacent(*from, *to) == false).collect();
+
+    assert_eq!(vec![([0, 0].into(), [2, 2].into(), ConnectionType::PASSAGE)], portal_connections);
+}
+
+#[test]
+fn test_portal_connections_between_distant_points() {
+    let mut maze = HexSpaceHexCellMazeCoordinator::<VecBuffer<HexCellValue>>::builder(HexCoordinateSpace::new_checked(5, 4)).build();
+
+    let from = HexCoordinate { column: 0, row: 0 };
+    let to = HexCoordinate { column: 4, row: 3 };
+
+    maze.portals_mut().insert_one_way(from, to);
+
+    assert_eq!(ConnectionType::PASSAGE, maze.get_connection(from, to));
+    assert!(maze.is_wall_between(from, to) == false);
+    assert!(maze.is_boundary_between(from, to) == false);
+    assert!(maze.is_unvisited_between(from, to) == false);
+
+    // A portal between adjacent points overrides whatever is between them
+    let neighbour = HexCoordinate { column: 1, row: 0 };
+
+    assert_eq!(ConnectionType::UNVISITED, maze.get_connection(from, neighbour));
+
+    maze.portals_mut().insert_one_way(from, neighbour);
+
+    assert_eq!(ConnectionType::PASSAGE, maze.get_connection(from, neighbour));
+    assert_eq!(ConnectionType::UNVISITED, maze.get_adjacent_connection(from, neighbour));
+}
+
+#[test]
+fn test_portals_are_followed_by_analysis() {
+    // Two separate corridors, joined only by a portal
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+    maze.make_passage_between([1, 0].into(), [2, 0].into());
+    maze.make_passage_between([0, 1].into(), [1, 1].into());
+    maze.make_passage_between([1, 1].into(), [2, 1].into());
+    maze.portals_mut().insert([2, 0].into(), [0, 1].into());
+
+    assert_eq!(Some(([2, 1].into(), [0, 0].into())), set_longest_path_endpoints(&mut maze));
+    assert_eq!(0, fill_unreachable_regions(&mut maze, [0, 0].into()));
+}
+
+#[test]
+fn test_portals_carry_over() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+
+    maze.portals_mut().insert([0, 0].into(), [2, 1].into());
+    maze.portals_mut().insert([1, 0].into(), [2, 0].into());
+
+    let rotated = rotate90(&maze);
+
+    assert!(rotated.portals().contains([1, 0].into(), [0, 2].into()));
+    assert_eq!(4, rotated.portals().len());
+
+    let cropped = crop(&maze, &maze.coord_space().sub_region_checked([1, 0].into(), [2, 1]));
+
+    assert_eq!(2, cropped.portals().len());
+    assert!(cropped.portals().contains([0, 0].into(), [1, 0].into()));
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::Inline2DMazeBuilder;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::quality::{MazeStats, QualityGate};
+use crate::quick::{self, Algorithm, InlineMaze2D};
+
+#[test]
+fn test_stats_of_corridor() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([4, 1])).build();
+
+    for x in 0..3 {
+        maze.make_passage_between([x, 0].into(), [x + 1, 0].into());
+    }
+
+    let stats = MazeStats::of(&maze);
+
+    assert_eq!(4, stats.passages);
+    assert_eq!(2, stats.dead_ends);
+    assert_eq!(0, stats.junctions);
+    assert_eq!(0.5, stats.dead_end_ratio());
+    assert_eq!(None, stats.solution_length);
+
+    maze.set_start([0, 0].into());
+    maze.set_goal([3, 0].into());
+
+    let stats = MazeStats::of(&maze);
+
+    assert_eq!(Some(3), stats.solution_length);
+    assert_eq!(Some(0), stats.solution_decisions);
+}
+
+#[test]
+fn test_stats_without_path_to_goal() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 1])).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+    maze.set_start([0, 0].into());
+    maze.set_goal([2, 0].into());
+
+    let stats = MazeStats::of(&maze);
+
+    assert_eq!(None, stats.solution_length);
+    assert_eq!(None, stats.solution_decisions);
+}
+
+#[test]
+fn test_gate_returns_passing_maze_and_its_seed() {
+    let gate = QualityGate::new()
+        .min_solution_length(20)
+        .difficulty(2..=usize::MAX)
+        .require(|maze: &InlineMaze2D| maze.start() != maze.goal());
+
+    let (maze, seed) = gate.generate(4953, |seed| quick::generate_2d_inline(8, 8, Algorithm::HuntAndKill, seed)).unwrap();
+
+    assert!(gate.is_met_by(&maze));
+    assert!(MazeStats::of(&maze).solution_length.unwrap() >= 20);
+    assert!(maze == quick::generate_2d_inline(8, 8, Algorithm::HuntAndKill, seed));
+
+    // The search is reproducible
+    let (_, again) = gate.generate(4953, |seed| quick::generate_2d_inline(8, 8, Algorithm::HuntAndKill, seed)).unwrap();
+
+    assert_eq!(seed, again);
+}
+
+#[test]
+fn test_gate_gives_up() {
+    let mut attempts = 0;
+
+    let result = QualityGate::new()
+        .dead_end_ratio(2.0..=3.0)
+        .max_attempts(5)
+        .generate(0, |seed| {
+            attempts += 1;
+
+            quick::generate_2d_inline(4, 4, Algorithm::NAryTree, seed)
+        });
+
+    assert!(result.is_none());
+    assert_eq!(5, attempts);
+}
\ No newline at end of file
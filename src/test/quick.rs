@@ -0,0 +1,27 @@
+use crate::implm::cell::block::BlockCellPrimaryValue;
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::quick::{self, Algorithm};
+
+#[test]
+fn test_generate_2d_is_deterministic() {
+    for algorithm in [Algorithm::HuntAndKill, Algorithm::RecursiveBacktracker, Algorithm::NAryTree] {
+        let first = quick::generate_2d(8, 6, algorithm, 1234);
+        let second = quick::generate_2d(8, 6, algorithm, 1234);
+
+        assert_eq!([8, 6], first.coord_space().dimensions().map(usize::from));
+
+        for pt in first.coord_space().iter() {
+            assert_eq!(first.get(pt), second.get(pt));
+            assert_eq!(BlockCellPrimaryValue::PASSAGE, first.get(pt).cell_type);
+        }
+    }
+}
+
+#[test]
+fn test_generate_2d_inline_visits_every_point() {
+    let maze = quick::generate_2d_inline(7, 3, Algorithm::default(), 99);
+
+    assert!(maze.coord_space().iter().all(|pt| maze.get(pt).is_fully_visited()));
+}
\ No newline at end of file
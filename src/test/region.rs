@@ -0,0 +1,86 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::region::SubRegionMazeCoordinator;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+type Maze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[test]
+fn test_sub_region_mapping() {
+    let space = BoxCoordinateSpace::new_zeta_checked([10, 8]);
+
+    let region = space.sub_region_checked([3, 2].into(), [4, 5]);
+
+    assert_eq!([4, 5], region.space().dimensions().map(usize::from));
+    assert!(region.space().diagonal());
+
+    assert_eq!(CoordinateTuplet([3, 2]), region.to_parent([0, 0].into()));
+    assert_eq!(CoordinateTuplet([6, 6]), region.to_parent([3, 4].into()));
+
+    assert_eq!(Some([3, 4].into()), region.from_parent([6, 6].into()));
+    assert_eq!(None, region.from_parent([7, 6].into()));
+    assert_eq!(None, region.from_parent([2, 6].into()));
+
+    assert_eq!(20, region.iter_parent().filter(|pt| region.contains(*pt)).count());
+}
+
+#[test]
+fn test_sub_region_does_not_wrap() {
+    let space = BoxCoordinateSpace::new_wrapping_checked([10, 8], [true, true]);
+
+    let region = space.sub_region_checked([0, 0].into(), [10, 4]);
+
+    assert!(region.space().are_adjacent([0, 0].into(), [9, 0].into()) == false);
+}
+
+#[test]
+fn test_sub_region_must_fit() {
+    let space = BoxCoordinateSpace::new_checked([10, 8]);
+
+    assert_that_code!(|| space.sub_region_checked([6, 0].into(), [5, 8]))
+        .panics()
+        .with_having_message("The sub-region does not fit within the coordinate space");
+}
+
+#[test]
+fn test_sub_region_generation_writes_through() {
+    let mut maze = Maze::builder(BoxCoordinateSpace::new_checked([12, 6])).build();
+
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut SubRegionMazeCoordinator::new_checked(&mut maze, [0, 0].into(), [6, 6]), &mut ChaCha8Rng::seed_from_u64(0));
+
+    // The right half hasn't been touched
+    for pt in maze.coord_space().sub_region_checked([6, 0].into(), [6, 6]).iter_parent() {
+        assert_eq!(BlockCellPrimaryValue::UNVISITED, maze.get(pt).cell_type);
+    }
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut SubRegionMazeCoordinator::new_checked(&mut maze, [6, 0].into(), [6, 6]), &mut ChaCha8Rng::seed_from_u64(0));
+
+    // Both halves are perfect mazes, but they aren't joined
+    for y in 0..6 {
+        assert!(maze.is_passage_between([5, y].into(), [6, y].into()) == false);
+    }
+
+    for origin in [[0, 0], [6, 0]] {
+        let view = SubRegionMazeCoordinator::new_checked(&mut maze, origin.into(), [6, 6]);
+        let space = *view.coord_space();
+
+        let mut passages = 0;
+
+        for pt in space.iter() {
+            assert_eq!(BlockCellPrimaryValue::PASSAGE, view.get(pt).cell_type);
+
+            passages += space.neighbours_of_pt(pt).into_iter().filter(|neighbour| view.is_passage_between(pt, *neighbour)).count();
+        }
+
+        assert_eq!(35, passages / 2);
+    }
+}
\ No newline at end of file
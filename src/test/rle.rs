@@ -0,0 +1,48 @@
+use std::num::NonZeroUsize;
+
+use crate::implm::buffer::RleBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+
+fn cell(cell_type: BlockCellPrimaryValue) -> BlockCellValue {
+    BlockCellValue { cell_type, ..Default::default() }
+}
+
+#[test]
+fn test_compress() {
+    let cells = [BlockCellPrimaryValue::WALL, BlockCellPrimaryValue::WALL, BlockCellPrimaryValue::PASSAGE, BlockCellPrimaryValue::WALL, BlockCellPrimaryValue::WALL, BlockCellPrimaryValue::WALL].map(cell);
+
+    let buffer = RleBuffer::compress(cells);
+
+    assert_eq!(3, buffer.run_count());
+
+    for (i, value) in cells.into_iter().enumerate() {
+        assert_eq!(value, buffer.get(CellID(i)));
+    }
+}
+
+#[test]
+fn test_writes_split_and_merge_runs() {
+    let mut buffer = RleBuffer::<BlockCellValue>::new(NonZeroUsize::new(10).unwrap());
+
+    buffer.set(CellID(5), cell(BlockCellPrimaryValue::WALL));
+    assert_eq!(3, buffer.run_count());
+
+    buffer.set(CellID(4), cell(BlockCellPrimaryValue::WALL));
+    assert_eq!(3, buffer.run_count());
+
+    buffer.set(CellID(5), BlockCellValue::default());
+    assert_eq!(3, buffer.run_count());
+
+    buffer.get_mut(CellID(4)).cell_type = BlockCellPrimaryValue::UNVISITED;
+    assert_eq!(BlockCellValue::default(), buffer.get(CellID(4)));
+
+    buffer.compact();
+    assert_eq!(1, buffer.run_count());
+
+    buffer.get_mut(CellID(9)).marked = true;
+    assert_eq!(2, buffer.run_count());
+    assert!(buffer.get(CellID(9)).marked);
+    assert!(buffer.get(CellID(8)).marked == false);
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::Standard2DMaze;
+use crate::interface::generate::DefaultMazeGenerator;
+use crate::quick::{self, Algorithm};
+use crate::rng::{PortableRng, RngCore};
+use crate::util::fingerprint;
+
+#[test]
+fn test_trait_object_rngs_are_accepted() {
+    let mut concrete = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([6, 6])).build();
+    let mut dynamic = concrete.clone();
+
+    RecursiveBacktrackerGenerator::generate_with_rng(&mut concrete, &mut ChaCha8Rng::seed_from_u64(5));
+
+    let rng: &mut dyn RngCore = &mut ChaCha8Rng::seed_from_u64(5);
+    RecursiveBacktrackerGenerator::generate_with_rng(&mut dynamic, rng);
+
+    assert!(concrete == dynamic);
+}
+
+#[test]
+fn test_default_rng_generates() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([4, 4])).build();
+
+    Algorithm::HuntAndKill.generate(&mut maze);
+
+    assert_eq!(0, crate::util::count_unvisited(&maze));
+}
+
+#[test]
+fn test_portable_rng_matches_reference() {
+    let mut seed = [0; 32];
+
+    for (i, bytes) in seed.chunks_exact_mut(8).enumerate() {
+        bytes.copy_from_slice(&(i as u64 + 1).to_le_bytes());
+    }
+
+    let mut rng = PortableRng::from_seed(seed);
+
+    // The first outputs of the reference implementation with a state of [1, 2, 3, 4]
+    assert_eq!(11520, rng.next_u64());
+    assert_eq!(0, rng.next_u64());
+    assert_eq!(1_509_978_240, rng.next_u64());
+    assert_eq!(1_215_971_899_390_074_240, rng.next_u64());
+}
+
+#[test]
+fn test_portable_rng_rejects_zero_state() {
+    assert_eq!(PortableRng::seed_from_u64(0), PortableRng::from_seed([0; 32]));
+}
+
+#[test]
+fn test_seeded_generation_is_unchanged() {
+    // If these change, mazes generated from the same seed by earlier versions will no longer match
+    assert_eq!(13_102_055_435_002_749_846, fingerprint(&quick::generate_2d(12, 9, Algorithm::HuntAndKill, 4945)));
+    assert_eq!(585_223_606_550_184_046, fingerprint(&quick::generate_2d_inline(12, 9, Algorithm::RecursiveBacktracker, 4945)));
+}
\ No newline at end of file
@@ -0,0 +1,39 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::ros::BoxSpaceBlockCellRosMapExporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::util::apply_solid_border;
+
+#[test]
+fn test_pgm_marks_walls_occupied_and_passages_free() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([2, 1])).build();
+
+    apply_solid_border(&mut maze);
+    maze.make_passage([0, 0].into());
+
+    let mut pgm = Vec::new();
+    BoxSpaceBlockCellRosMapExporter::new().export(&maze, &mut pgm).unwrap();
+
+    let header = b"P5\n5 3\n255\n";
+    assert_eq!(header, &pgm[..header.len()]);
+
+    // The passage, its walls, and the unvisited point beside it, inside the boundary
+    assert_eq!(&[
+        0,   0,   0,   0,   0,
+        0, 254,   0, 205,   0,
+        0,   0,   0,   0,   0,
+    ], &pgm[header.len()..]);
+}
+
+#[test]
+fn test_metadata_records_configuration() {
+    let exporter = BoxSpaceBlockCellRosMapExporter::builder().resolution(0.25).origin([-1.0, 2.5, 0.0]).build();
+
+    let mut yaml = Vec::new();
+    exporter.export_metadata("maze.pgm", &mut yaml).unwrap();
+
+    assert_eq!("image: maze.pgm\nmode: trinary\nresolution: 0.25\norigin: [-1.0, 2.5, 0.0]\nnegate: 0\noccupied_thresh: 0.65\nfree_thresh: 0.196\n", String::from_utf8(yaml).unwrap());
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::export::slice::BoxSliceMazeExporter;
+use crate::implm::export::text::{BoxSpaceBlockCellTextMazeExporter, BoxSpaceInlineCellTextMazeExporter};
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::{crop, slice};
+
+#[test]
+fn test_inline_slice_keeps_connections() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<4>>, 4>::new(BoxCoordinateSpace::new_checked([3, 4, 2, 3])).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4));
+
+    // The y-w plane at x = 1, z = 1, with the axes swapped
+    let plane = slice(&maze, [3, 1], [1, 0, 1, 0].into());
+
+    assert_eq!([3, 4], plane.coord_space().dimensions().map(usize::from));
+
+    for (from, to, connection) in plane.connections() {
+        assert_eq!(maze.get_connection([1, from[1], 1, from[0]].into(), [1, to[1], 1, to[0]].into()), connection);
+    }
+}
+
+#[test]
+fn test_block_slice_matches_crop() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 3>::builder(BoxCoordinateSpace::new_checked([4, 3, 3])).scale_factors_checked([2, 1, 1]).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(2));
+
+    maze.set_goal([3, 2, 1].into());
+    maze.set_start([0, 0, 0].into());
+
+    let floor = slice(&maze, [0, 1], [0, 0, 1].into());
+    let layer = crop(&maze, &maze.coord_space().sub_region_checked([0, 0, 1].into(), [4, 3, 1]));
+
+    let mut sliced_text = Vec::new();
+    BoxSpaceBlockCellTextMazeExporter::default().export(&floor, &mut sliced_text).unwrap();
+
+    let mut exported_text = Vec::new();
+    BoxSliceMazeExporter::new(BoxSpaceBlockCellTextMazeExporter::default(), [0, 1], [0, 0, 1].into()).export(&maze, &mut exported_text).unwrap();
+
+    assert_eq!(sliced_text, exported_text);
+
+    for pt in floor.coord_space().iter() {
+        assert_eq!(layer.get([pt[0], pt[1], 0].into()), floor.get(pt));
+    }
+
+    assert_eq!(Some([3, 2].into()), floor.goal());
+    assert_eq!(None, floor.start());
+}
+
+#[test]
+fn test_slice_exporter_draws_any_dimension() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<5>>, 5>::new(BoxCoordinateSpace::new_checked([2, 2, 2, 2, 2])).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(9));
+
+    let mut text = Vec::new();
+    BoxSliceMazeExporter::new(BoxSpaceInlineCellTextMazeExporter::default(), [2, 3, 4], [1, 1, 0, 0, 0].into()).export(&maze, &mut text).unwrap();
+
+    // Two layers, each five lines tall, with a blank line between
+    assert_eq!(11, String::from_utf8(text).unwrap().lines().count());
+}
+
+#[test]
+#[should_panic(expected = "The axes must be distinct")]
+fn test_repeated_axes_panic() {
+    let _ = BoxSliceMazeExporter::new(BoxSpaceInlineCellTextMazeExporter::default(), [1, 1], [0, 0, 0].into());
+}
\ No newline at end of file
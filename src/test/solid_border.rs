@@ -0,0 +1,32 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::util::apply_solid_border;
+
+#[test]
+fn test_inline_cell_solid_border_preserves_existing_edges() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+    apply_solid_border(&mut maze);
+
+    assert!(maze.is_passage_between([0, 0].into(), [1, 0].into()));
+
+    assert_eq!([[InlineCellValueEdge::BOUNDARY, InlineCellValueEdge::PASSAGE], [InlineCellValueEdge::BOUNDARY, InlineCellValueEdge::WALL]], maze.get([0, 0].into()).edges);
+    assert_eq!([[InlineCellValueEdge::UNVISITED, InlineCellValueEdge::BOUNDARY], [InlineCellValueEdge::UNVISITED, InlineCellValueEdge::BOUNDARY]], maze.get([2, 2].into()).edges);
+    assert_eq!([[InlineCellValueEdge::UNVISITED; 2]; 2], maze.get([1, 1].into()).edges);
+}
+
+#[test]
+fn test_inline_cell_set_all_edges() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([2, 2])).build();
+
+    maze.set_all_edges([0, 0].into(), InlineCellValueEdge::WALL);
+
+    assert!(maze.is_wall_between([1, 0].into(), [0, 0].into()));
+    assert!(maze.is_wall_between([0, 1].into(), [0, 0].into()));
+    assert!(maze.is_unvisited_between([1, 0].into(), [1, 1].into()));
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use std::io::ErrorKind;
+
+use fluent_asserter::prelude::*;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::binary::BoxSpaceBinaryMazeExporter;
+use crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::internal::util::get_line_sep;
+
+type Maze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[test]
+fn test_passages_span_variable_spacing() {
+    // The first row is all spaces, which a string continuation would strip
+    let expected = [" ".repeat(16), "██████████████..".to_string(), ".".repeat(16)].map(|row| row + get_line_sep()).concat();
+
+    let mut maze = Maze::builder(BoxCoordinateSpace::new_checked([3, 2]))
+        .spacing_checked(0, vec![3, 4])
+        .padding([[0, 0], [0, 0]])
+        .build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+    maze.make_passage_between([1, 0].into(), [2, 0].into());
+
+    assert!(maze.is_uniformly_spaced() == false);
+    assert!(maze.is_passage_between([1, 0].into(), [2, 0].into()));
+    assert!(maze.is_unvisited_between([0, 0].into(), [0, 1].into()));
+
+    let mut actual = Vec::new();
+    BoxSpaceBlockCellTextMazeExporter::default().export(&maze, &mut actual).unwrap();
+
+    assert_eq!(expected, String::from_utf8(actual).unwrap());
+    assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get_cell_value([6, 0].into()).cell_type);
+}
+
+#[test]
+fn test_spacing_must_match_the_coordinate_space() {
+    assert_that_code!(|| Maze::builder(BoxCoordinateSpace::new_checked([3, 3])).spacing_checked(0, vec![2, 2, 2])).panics().with_having_message("There must be exactly one spacing for each gap between points");
+    assert_that_code!(|| Maze::builder(BoxCoordinateSpace::new_checked([3, 3])).spacing_checked(1, vec![2, 0])).panics().with_having_message("All spacings must be non-zero");
+    assert_that_code!(|| Maze::builder(BoxCoordinateSpace::new_zeta_checked([3, 3])).spacing_checked(1, vec![3, 2]).build()).panics().with_having_message("Diagonal mazes must have spacings of at least 3");
+}
+
+#[test]
+fn test_binary_export_rejects_variable_spacing() {
+    let maze = Maze::builder(BoxCoordinateSpace::new_checked([3, 3])).spacing_checked(1, vec![2, 3]).build();
+
+    let err = BoxSpaceBinaryMazeExporter::default().export(&maze, &mut Vec::new()).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidInput, err.kind());
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::generate::{Rotation, SpiralGenerator};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::Standard2DMaze;
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::quality::MazeStats;
+
+fn generate(bias: f64, rotation: Rotation, seed: u64) -> Standard2DMaze {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([15, 15])).build();
+
+    SpiralGenerator::new(bias).rotation(rotation).generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+
+    return maze
+}
+
+/// Return how many passages run around the centre and how many run towards or away from it.
+fn count_passages(maze: &Standard2DMaze) -> (usize, usize) {
+    let mut around = 0;
+    let mut across = 0;
+
+    for (from, to, connection) in maze.connections() {
+        if connection != ConnectionType::PASSAGE {
+            continue
+        }
+
+        let axis = if from[0] == to[0] { 1 } else { 0 };
+        let other_axis = 1 - axis;
+
+        // Moving along an axis goes around the centre when you are further from the centre on
+        // the other axis
+        if from[other_axis].abs_diff(7) >= from[axis].abs_diff(7).max(to[axis].abs_diff(7)) {
+            around += 1;
+        } else {
+            across += 1;
+        }
+    }
+
+    return (around, across)
+}
+
+#[test]
+fn test_spiral_mazes_are_perfect() {
+    for rotation in [Rotation::Clockwise, Rotation::Anticlockwise] {
+        let maze = generate(0.9, rotation, 4958);
+        let stats = MazeStats::of(&maze);
+
+        assert_eq!(15 * 15, stats.passages);
+
+        // Each passage is seen from both sides
+        assert_eq!(2 * (15 * 15 - 1), maze.connections().filter(|(_, _, connection)| *connection == ConnectionType::PASSAGE).count());
+    }
+}
+
+#[test]
+fn test_spiral_passages_wind_around_the_centre() {
+    let (around, across) = count_passages(&generate(1.0, Rotation::Clockwise, 1));
+
+    assert!(around > across * 3);
+}
+
+#[test]
+fn test_bias_out_of_range() {
+    assert_that_code!(|| SpiralGenerator::new(-0.1)).panics().with_having_message("The bias must be between 0 and 1");
+}
\ No newline at end of file
@@ -0,0 +1,140 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue, VerticalConnector};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+type Maze3D = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 3>;
+
+#[test]
+fn test_3d_block_cells_are_distinct() {
+    let space = BoxCoordinateSpace::new_checked([3, 4, 5]);
+
+    let mut maze = Maze3D::builder(space).build();
+
+    for pt in space.iter() {
+        maze.make_wall(pt);
+    }
+
+    maze.make_passage_between([1, 2, 3].into(), [1, 2, 4].into());
+
+    for pt in space.iter() {
+        let is_passage = maze.get(pt).cell_type == BlockCellPrimaryValue::PASSAGE;
+
+        assert_eq!(pt == [1, 2, 3].into() || pt == [1, 2, 4].into(), is_passage, "{:?}", pt);
+    }
+}
+
+#[test]
+fn test_stairs_between_layers() {
+    let mut maze = Maze3D::builder(BoxCoordinateSpace::new_checked([2, 1, 2])).build();
+
+    maze.make_passage_between([0, 0, 0].into(), [1, 0, 0].into());
+    maze.make_passage_between([1, 0, 0].into(), [1, 0, 1].into());
+
+    let connector_at = |maze: &Maze3D, loc: [usize; 3]| maze.get_cell_value(loc.into()).connector;
+
+    assert_eq!(VerticalConnector::NONE, connector_at(&maze, [1, 1, 1]));
+    assert_eq!(VerticalConnector::UP, connector_at(&maze, [3, 1, 1]));
+    assert_eq!(VerticalConnector::UP_AND_DOWN, connector_at(&maze, [3, 1, 2]));
+    assert_eq!(VerticalConnector::DOWN, connector_at(&maze, [3, 1, 3]));
+
+    // Carving downwards gives the same connectors
+    let mut reversed = Maze3D::builder(BoxCoordinateSpace::new_checked([2, 1, 2])).build();
+    reversed.make_passage_between([1, 0, 1].into(), [1, 0, 0].into());
+
+    for z in 1..=3 {
+        assert_eq!(connector_at(&maze, [3, 1, z]), connector_at(&reversed, [3, 1, z]));
+    }
+
+    maze.make_wall_between([1, 0, 0].into(), [1, 0, 1].into());
+
+    for z in 1..=3 {
+        assert_eq!(VerticalConnector::NONE, connector_at(&maze, [3, 1, z]));
+    }
+}
+
+#[test]
+fn test_generated_stairs_are_consistent() {
+    let space = BoxCoordinateSpace::new_checked([4, 4, 3]);
+
+    let mut maze = Maze3D::builder(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+
+    let mut vertical_passages = 0;
+
+    for pt in space.iter() {
+        if pt[2] + 1 < 3 && maze.is_passage_between(pt, pt.offset(2, 1)) {
+            vertical_passages += 1;
+
+            assert!(maze.get(pt).connector.goes_up());
+            assert!(maze.get(pt.offset(2, 1)).connector.goes_down());
+        }
+    }
+
+    assert!(vertical_passages > 0);
+
+    let [width, length, height] = maze.get_full_dimensions().map(usize::from);
+
+    for z in 0..height {
+        for y in 0..length {
+            for x in 0..width {
+                let connector = maze.get_cell_value([x, y, z].into()).connector;
+
+                if connector.goes_up() {
+                    assert!(maze.get_cell_value([x, y, z + 1].into()).connector.goes_down());
+                }
+
+                if connector != VerticalConnector::NONE {
+                    assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get_cell_value([x, y, z].into()).cell_type);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_3d_block_text_export() {
+    let mut maze = Maze3D::builder(BoxCoordinateSpace::new_checked([2, 1, 2])).build();
+
+    maze.make_passage_between([0, 0, 0].into(), [1, 0, 0].into());
+    maze.make_passage_between([1, 0, 0].into(), [1, 0, 1].into());
+    maze.make_passage_between([1, 0, 1].into(), [0, 0, 1].into());
+
+    let exporter = BoxSpaceBlockCellTextMazeExporter::builder().chars_per_cell_horizontally_checked(1).wall_char('#').unvisited_char('#').build();
+
+    let mut text = Vec::new();
+    exporter.export(&maze, &mut text).unwrap();
+
+    let expected = "\
+#####
+#####
+#####
+
+#####
+#  ▲#
+#####
+
+#####
+###◆#
+#####
+
+#####
+#  ▼#
+#####
+
+#####
+#####
+#####
+";
+
+    assert_eq!(expected, String::from_utf8(text).unwrap());
+}
\ No newline at end of file
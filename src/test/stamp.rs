@@ -0,0 +1,126 @@
+use fluent_asserter::prelude::*;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellLocation, BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::{stamp, StampMerge};
+
+type BlockMaze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+type InlineMaze = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<2>>, 2>;
+
+fn room() -> BlockMaze {
+    let mut room = BlockMaze::builder(BoxCoordinateSpace::new_checked([2, 2])).build();
+
+    for pt in room.coord_space().iter() {
+        room.make_passage(pt);
+    }
+
+    room.make_passage_between([0, 0].into(), [1, 0].into());
+    room.make_boundary_between([0, 1].into(), [1, 1].into());
+
+    // An opening in the room's left wall
+    room.set_cell_value_type(BlockCellLocation([0, 1].into()), BlockCellPrimaryValue::PASSAGE);
+
+    return room
+}
+
+#[test]
+fn test_block_cell_stamp() {
+    for merge in [StampMerge::Overwrite, StampMerge::Keep, StampMerge::Open, StampMerge::Closed] {
+        let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([5, 5])).build();
+
+        for cell in BoxCoordinateSpace::new(maze.get_full_dimensions()).iter() {
+            maze.set_cell_value_type(BlockCellLocation(cell), BlockCellPrimaryValue::WALL);
+        }
+
+        stamp(&mut maze, &room(), [2, 1].into(), merge);
+
+        assert!(maze.is_passage_between([2, 1].into(), [3, 1].into()));
+        assert!(maze.is_boundary_between([2, 2].into(), [3, 2].into()));
+
+        // The room's opening is in its padding, over the maze's wall
+        let opening = maze.get_cell_value(BlockCellLocation([4, 3].into())).cell_type;
+
+        match merge {
+            StampMerge::Overwrite | StampMerge::Open => assert_eq!(BlockCellPrimaryValue::PASSAGE, opening),
+            StampMerge::Keep | StampMerge::Closed => assert_eq!(BlockCellPrimaryValue::WALL, opening),
+        }
+
+        // Unvisited padding only replaces the maze's cells when overwriting
+        let corner = maze.get_cell_value(BlockCellLocation([4, 2].into())).cell_type;
+
+        match merge {
+            StampMerge::Overwrite => assert_eq!(BlockCellPrimaryValue::UNVISITED, corner),
+            _ => assert_eq!(BlockCellPrimaryValue::WALL, corner),
+        }
+
+        assert_eq!(BlockCellPrimaryValue::WALL, maze.get([1, 1].into()).cell_type);
+    }
+}
+
+#[test]
+fn test_block_cell_stamp_at_edge() {
+    let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([4, 4])).padding([[0, 0], [0, 0]]).build();
+
+    stamp(&mut maze, &room(), [0, 0].into(), StampMerge::Overwrite);
+
+    assert!(maze.is_passage_between([0, 0].into(), [1, 0].into()));
+    assert!(maze.is_boundary_between([0, 1].into(), [1, 1].into()));
+}
+
+#[test]
+fn test_block_cell_stamp_mismatched_spacing() {
+    assert_that_code!(|| {
+        let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([5, 5])).spacing_checked(0, vec![2, 3, 2, 2]).build();
+
+        stamp(&mut maze, &room(), [1, 1].into(), StampMerge::Overwrite);
+    }).panics().with_message("The stamp's spacing must match the maze's where it is placed");
+}
+
+#[test]
+fn test_stamp_does_not_fit() {
+    assert_that_code!(|| {
+        let mut maze = BlockMaze::builder(BoxCoordinateSpace::new_checked([5, 5])).build();
+
+        stamp(&mut maze, &room(), [4, 0].into(), StampMerge::Overwrite);
+    }).panics().with_message("The stamp does not fit within the maze");
+}
+
+#[test]
+fn test_inline_cell_stamp() {
+    let mut room: InlineMaze = BoxSpaceInlineCellMazeCoordinatorBuilder::new(BoxCoordinateSpace::new_checked([2, 2])).build();
+
+    room.make_passage_between([0, 0].into(), [1, 0].into());
+    room.make_passage_between([0, 1].into(), [1, 1].into());
+    room.make_wall_between([0, 0].into(), [0, 1].into());
+    room.make_wall_between([1, 0].into(), [1, 1].into());
+
+    let mut doorway = room.get([0, 1].into());
+    doorway.edges[0][0] = InlineCellValueEdge::PASSAGE;
+    room.set([0, 1].into(), doorway);
+
+    for merge in [StampMerge::Overwrite, StampMerge::Keep, StampMerge::Open, StampMerge::Closed] {
+        let mut maze: InlineMaze = BoxSpaceInlineCellMazeCoordinatorBuilder::new(BoxCoordinateSpace::new_checked([4, 4])).build();
+
+        for pt in maze.coord_space().iter() {
+            for neighbour in maze.coord_space().neighbours_of_pt(pt) {
+                maze.make_wall_between(pt, neighbour);
+            }
+        }
+
+        stamp(&mut maze, &room, [1, 1].into(), merge);
+
+        assert!(maze.is_passage_between([1, 1].into(), [2, 1].into()));
+        assert!(maze.is_wall_between([1, 1].into(), [1, 2].into()));
+
+        let doorway_open = maze.is_passage_between([0, 2].into(), [1, 2].into());
+
+        assert_eq!(matches!(merge, StampMerge::Overwrite | StampMerge::Open), doorway_open);
+        assert!(maze.is_wall_between([2, 0].into(), [2, 1].into()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use std::num::NonZeroUsize;
+
+use crate::implm::buffer::SyncBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::interface::buffer::{MazeBuffer, SyncMazeBuffer};
+use crate::interface::cell::CellID;
+
+#[test]
+fn test_concurrent_disjoint_writes() {
+    // Not a multiple of the shard size, so the last shard is partial
+    let buffer = SyncBuffer::<BlockCellValue, 16>::new(NonZeroUsize::new(1000).unwrap());
+
+    std::thread::scope(|scope| {
+        for worker in 0..4 {
+            let buffer = &buffer;
+
+            // Interleave the workers so they all contend for the same shards
+            scope.spawn(move || {
+                for cell in (worker..1000).step_by(4) {
+                    buffer.update_shared(CellID(cell), |value| value.cell_type = BlockCellPrimaryValue::PASSAGE);
+                    buffer.update_shared(CellID(cell), |value| value.marked = cell % 3 == 0);
+                }
+            });
+        }
+    });
+
+    for cell in 0..1000 {
+        assert_eq!(BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, marked: cell % 3 == 0, ..Default::default() }, buffer.get(CellID(cell)));
+    }
+}
\ No newline at end of file
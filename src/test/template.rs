@@ -0,0 +1,110 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::implm::template::{CrossroadsTemplate, MazeTemplate, Orientation, RoomTemplate, SpiralTemplate};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+#[test]
+fn test_orientations_are_distinct() {
+    let dimensions = [3, 2];
+
+    let corners: Vec<_> = Orientation::all().map(|orientation| {
+        let oriented_dimensions = orientation.oriented_dimensions(dimensions);
+        let corner = orientation.orient_pt([0, 0].into(), dimensions);
+        let neighbour = orientation.orient_pt([1, 0].into(), dimensions);
+
+        // Every point must stay within the oriented template
+        for y in 0..dimensions[1] {
+            for x in 0..dimensions[0] {
+                let pt = orientation.orient_pt([x, y].into(), dimensions);
+
+                assert!(pt[0] < oriented_dimensions[0] && pt[1] < oriented_dimensions[1]);
+            }
+        }
+
+        // Directions must turn with points
+        assert_eq!(Some(neighbour), corner.neighbour(BoxDirection::new(orientation.orient_direction(BoxDirection::EAST).axis(), orientation.orient_direction(BoxDirection::EAST).is_positive())));
+
+        (corner, neighbour)
+    }).collect();
+
+    for (i, a) in corners.iter().enumerate() {
+        for b in corners[i + 1..].iter() {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn test_room_is_reachable_only_through_its_door() {
+    for orientation in Orientation::all() {
+        let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([8, 8])).build();
+
+        RoomTemplate::new([3, 2])
+            .door([2, 1].into(), BoxDirection::SOUTH)
+            .apply(&mut maze, [2, 2].into(), orientation);
+
+        HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(9));
+
+        let room: Vec<CoordinateTuplet<2>> = maze.coord_space().iter().filter(|pt| (2..5).contains(&pt[0]) && (2..5).contains(&pt[1])).collect();
+        let is_room = |pt: CoordinateTuplet<2>| orientation.oriented_dimensions([3, 2]).iter().enumerate().all(|(axis, size)| pt[axis] >= 2 && pt[axis] < 2 + size);
+
+        let exits: Vec<_> = room.iter().copied().filter(|pt| is_room(*pt))
+            .flat_map(|pt| maze.coord_space().neighbours_of_pt(pt).into_iter().map(move |neighbour| (pt, neighbour)))
+            .filter(|(pt, neighbour)| is_room(*neighbour) == false && maze.is_passage_between(*pt, *neighbour))
+            .collect();
+
+        let door = orientation.orient_pt([2, 1].into(), [3, 2]);
+
+        assert_eq!(vec![CoordinateTuplet([door[0] + 2, door[1] + 2])], exits.iter().map(|(pt, _)| *pt).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_spiral_is_a_single_passage() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([4, 5])).build();
+
+    SpiralTemplate::new([4, 5]).apply(&mut maze, [0, 0].into(), Orientation::new(0, true));
+
+    // A spiral of 20 points has 19 passages between them
+    assert_eq!(19 * 2, maze.connections().filter(|(from, to, _)| maze.is_passage_between(*from, *to)).count());
+
+    // Mirrored, the entrance is on the right
+    assert!(maze.is_passage_between([3, 0].into(), [2, 0].into()));
+    assert!(maze.is_passage_between([3, 0].into(), [3, 1].into()) == false);
+}
+
+#[test]
+fn test_crossroads_in_3d() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<3>>, 3>::new(BoxCoordinateSpace::new_checked([5, 5, 2])).build();
+
+    CrossroadsTemplate::new(1).apply(&mut maze, [1, 1, 1].into(), Orientation::IDENTITY);
+
+    assert!(maze.is_passage_between([2, 2, 1].into(), [2, 1, 1].into()));
+    assert!(maze.is_passage_between([2, 1, 1].into(), [2, 0, 1].into()));
+    assert!(maze.is_wall_between([2, 1, 1].into(), [1, 1, 1].into()));
+    assert!(maze.is_unvisited_between([2, 2, 0].into(), [2, 1, 0].into()));
+}
+
+#[test]
+fn test_template_must_fit() {
+    assert_that_code!(|| {
+        let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([4, 3])).build();
+
+        RoomTemplate::new([3, 4]).apply(&mut maze, [0, 0].into(), Orientation::new(1, false));
+        RoomTemplate::new([3, 4]).apply(&mut maze, [0, 0].into(), Orientation::IDENTITY);
+    }).panics().with_message("The template does not fit within the maze");
+
+    assert_that_code!(|| RoomTemplate::new([3, 3]).door([1, 1].into(), BoxDirection::NORTH))
+        .panics().with_message("Gates must face out of the template");
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::cell::{CellValue, Terrain};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+const MUD: Terrain = Terrain(1);
+const WATER: Terrain = Terrain(2);
+
+#[test]
+fn test_generation_preserves_inline_cell_terrain() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([4, 4])).build();
+
+    for pt in maze.coord_space().iter() {
+        let terrain = if pt.0[0] < 2 { MUD } else { WATER };
+
+        maze.set(pt, InlineCellValue { terrain, ..maze.get(pt) });
+    }
+
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(7));
+
+    for pt in maze.coord_space().iter() {
+        assert!(maze.get(pt).is_fully_visited());
+        assert_eq!(if pt.0[0] < 2 { MUD } else { WATER }, maze.get(pt).terrain());
+    }
+}
+
+#[test]
+fn test_generation_preserves_block_cell_terrain() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+
+    maze.get_cell_value_mut([3, 3].into()).terrain = MUD;
+
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(7));
+
+    assert_eq!(MUD, maze.get_cell_value([3, 3].into()).terrain());
+    assert_eq!(Terrain::DEFAULT, maze.get_cell_value([1, 1].into()).terrain());
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+#![cfg(feature = "testing")]
+
+use proptest::prelude::*;
+
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::testing::{box_coordinate_spaces, points_in, small_block_mazes, small_inline_mazes};
+use crate::internal::util::shortest_path;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn test_spaces_are_within_the_maximum_size(space in box_coordinate_spaces::<3>(5)) {
+        prop_assert!(space.dimensions().iter().all(|side| (1..=5).contains(&usize::from(*side))));
+        prop_assert!(space.wrapping().iter().all(|wraps| *wraps == false));
+    }
+
+    #[test]
+    fn test_points_are_in_their_space((space, pt) in any::<BoxCoordinateSpace<2>>().prop_flat_map(|space| (Just(space), points_in(space)))) {
+        prop_assert!(space.iter().any(|other| other == pt));
+    }
+
+    #[test]
+    fn test_inline_tunnels_are_on_an_axis(value in any::<InlineCellValue<2>>()) {
+        prop_assert!(value.tunnel.map_or(true, |axis| axis < 2));
+    }
+
+    #[test]
+    fn test_small_block_mazes_are_fully_generated(maze in small_block_mazes::<2>()) {
+        // A lone point has nothing to carve a passage to, so it's left unvisited
+        prop_assume!(maze.coord_space().logical_size().get() > 1);
+
+        prop_assert!(maze.iter().all(|(_, value)| value.is_fully_visited()));
+
+        let space = *maze.coord_space();
+        prop_assert!(space.iter().all(|pt| shortest_path(&maze, space.iter().next().unwrap(), pt).is_some()));
+    }
+
+    #[test]
+    fn test_small_inline_mazes_are_fully_generated(maze in small_inline_mazes::<3>()) {
+        // A lone point has nothing to carve a passage to, so it's left unvisited
+        prop_assume!(maze.coord_space().logical_size().get() > 1);
+
+        prop_assert!(maze.iter().all(|(_, value)| value.is_fully_visited()));
+
+        let space = *maze.coord_space();
+        prop_assert!(space.iter().all(|pt| shortest_path(&maze, space.iter().next().unwrap(), pt).is_some()));
+    }
+}
\ No newline at end of file
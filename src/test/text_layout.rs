@@ -0,0 +1,93 @@
+use crate::implm::export::text::{BoxSpaceBlockCellTextMazeExporter, BoxSpaceInlineCellTextMazeExporter, InlineCellTextTheme, LineBreak, Overflow};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::{Inline2DMaze, Inline2DMazeBuilder, Standard2DMaze};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+
+fn corridor() -> Inline2DMaze {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([2, 1])).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+    return maze
+}
+
+fn export(exporter: &BoxSpaceInlineCellTextMazeExporter, maze: &Inline2DMaze) -> String {
+    let mut text = Vec::new();
+    exporter.export(maze, &mut text).unwrap();
+
+    return String::from_utf8(text).unwrap()
+}
+
+#[test]
+fn test_line_breaks() {
+    let maze = corridor();
+
+    let builder = || BoxSpaceInlineCellTextMazeExporter::builder().theme(InlineCellTextTheme::Ascii);
+
+    assert_eq!("+---+---+\n|       |\n+---+---+\n", export(&builder().line_break(LineBreak::Lf).build(), &maze));
+    assert_eq!("+---+---+\r\n|       |\r\n+---+---+\r\n", export(&builder().line_break(LineBreak::CrLf).build(), &maze));
+    assert_eq!("+---+---+|       |+---+---+", export(&builder().line_break(LineBreak::None).build(), &maze));
+}
+
+#[test]
+fn test_native_line_break_is_default() {
+    let maze = corridor();
+
+    let exporter = BoxSpaceInlineCellTextMazeExporter::default();
+
+    assert_eq!(LineBreak::Native, exporter.line_break());
+    assert_eq!(None, exporter.max_width());
+
+    let native = export(&exporter, &maze);
+
+    assert_eq!(3, native.matches(LineBreak::Native.as_str()).count());
+}
+
+#[test]
+fn test_long_lines_are_truncated() {
+    let maze = corridor();
+
+    let exporter = BoxSpaceInlineCellTextMazeExporter::builder().theme(InlineCellTextTheme::Ascii).line_break(LineBreak::Lf).max_width(6, Overflow::Truncate('>')).build();
+
+    assert_eq!("+---+>\n|    >\n+---+>\n", export(&exporter, &maze));
+}
+
+#[test]
+fn test_long_lines_are_wrapped() {
+    let maze = corridor();
+
+    let exporter = BoxSpaceInlineCellTextMazeExporter::builder().theme(InlineCellTextTheme::Ascii).line_break(LineBreak::CrLf).max_width(4, Overflow::Wrap('\\')).build();
+
+    assert_eq!("+--\\\r\n-+-\\\r\n--+\r\n|  \\\r\n   \\\r\n  |\r\n+--\\\r\n-+-\\\r\n--+\r\n", export(&exporter, &maze));
+}
+
+#[test]
+fn test_lines_that_fit_are_untouched() {
+    let maze = corridor();
+
+    let exporter = BoxSpaceInlineCellTextMazeExporter::builder().theme(InlineCellTextTheme::Ascii).line_break(LineBreak::Lf).max_width(9, Overflow::Truncate('>')).build();
+
+    assert_eq!("+---+---+\n|       |\n+---+---+\n", export(&exporter, &maze));
+}
+
+#[test]
+fn test_width_is_counted_in_characters() {
+    let maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([1, 1])).build();
+
+    let exporter = BoxSpaceBlockCellTextMazeExporter::builder().line_break(LineBreak::Lf).max_width(4, Overflow::Truncate('…')).build();
+
+    let mut text = Vec::new();
+    exporter.export(&maze, &mut text).unwrap();
+
+    let text = String::from_utf8(text).unwrap();
+
+    assert!(text.lines().all(|line| line.chars().count() <= 4));
+    assert!(text.lines().all(|line| line.ends_with('…')));
+}
+
+#[test]
+#[should_panic(expected = "The maximum width must be at least 2")]
+fn test_max_width_must_leave_room_for_the_indicator() {
+    let _ = BoxSpaceInlineCellTextMazeExporter::builder().max_width(1, Overflow::Wrap('>'));
+}
\ No newline at end of file
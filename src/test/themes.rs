@@ -0,0 +1,78 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::export::text::{BlockCellTextTheme, BoxSpaceBlockCellTextMazeExporter, BoxSpaceInlineCellTextMazeExporter, InlineCellTextTheme};
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::{Inline2DMazeBuilder, Standard2DMaze};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::generate::DefaultMazeGenerator;
+
+#[test]
+fn test_inline_ascii_theme() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([2, 1])).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+    let mut text = Vec::new();
+    BoxSpaceInlineCellTextMazeExporter::builder().theme(InlineCellTextTheme::Ascii).build().export(&maze, &mut text).unwrap();
+
+    assert_eq!("+---+---+\n|       |\n+---+---+\n", String::from_utf8(text).unwrap());
+}
+
+#[test]
+fn test_inline_themes_only_use_their_characters() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([8, 6])).build();
+
+    RecursiveBacktrackerGenerator::generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4940));
+
+    let mut ascii = Vec::new();
+    BoxSpaceInlineCellTextMazeExporter::builder().theme(InlineCellTextTheme::Ascii).build().export(&maze, &mut ascii).unwrap();
+
+    assert!(ascii.is_ascii());
+
+    let mut heavy = Vec::new();
+    BoxSpaceInlineCellTextMazeExporter::builder().theme(InlineCellTextTheme::Heavy).build().export(&maze, &mut heavy).unwrap();
+
+    assert!(String::from_utf8(heavy).unwrap().chars().all(|char| " \n━┃┏┓┗┛┣┫┳┻╋╸╹╺╻".contains(char)));
+}
+
+#[test]
+fn test_inline_default_theme_is_light() {
+    assert_eq!(InlineCellTextTheme::Light, BoxSpaceInlineCellTextMazeExporter::default().theme());
+}
+
+#[test]
+fn test_block_ascii_theme() {
+    let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([5, 4])).build();
+
+    RecursiveBacktrackerGenerator::generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4940));
+
+    let mut text = Vec::new();
+    BoxSpaceBlockCellTextMazeExporter::builder().theme(BlockCellTextTheme::Ascii).build().export(&maze, &mut text).unwrap();
+
+    let text = String::from_utf8(text).unwrap();
+
+    assert!(text.is_ascii());
+    assert!(text.contains('#'));
+}
+
+#[test]
+fn test_block_theme_chars_can_be_overridden() {
+    let exporter = BoxSpaceBlockCellTextMazeExporter::builder().unvisited_char('?').theme(BlockCellTextTheme::Shaded).passage_char('_').build();
+
+    assert_eq!('_', exporter.passage_char());
+    assert_eq!('▓', exporter.wall_char());
+    assert_eq!('█', exporter.boundary_char());
+    assert_eq!('░', exporter.unvisited_char());
+}
+
+#[test]
+fn test_block_default_theme_is_solid() {
+    let default = BoxSpaceBlockCellTextMazeExporter::default();
+    let solid = BoxSpaceBlockCellTextMazeExporter::builder().theme(BlockCellTextTheme::Solid).build();
+
+    assert_eq!([default.passage_char(), default.wall_char(), default.boundary_char(), default.unvisited_char()], [solid.passage_char(), solid.wall_char(), solid.boundary_char(), solid.unvisited_char()]);
+    assert_eq!([default.stairs_up_char(), default.stairs_down_char(), default.stairs_up_and_down_char()], [solid.stairs_up_char(), solid.stairs_down_char(), solid.stairs_up_and_down_char()]);
+}
\ No newline at end of file
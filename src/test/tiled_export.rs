@@ -0,0 +1,117 @@
+use std::io::ErrorKind;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::text::BoxSpaceBlockCellTextMazeExporter;
+use crate::implm::export::tiled::BoxSpaceBlockCellTiledMazeExporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::export::MazeExporter;
+use crate::internal::util::get_line_sep;
+
+type Maze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+fn get_maze() -> Maze {
+    // 9x5 cells, with every cell in the first column and first row marked as a passage
+    let mut maze = Maze::builder(BoxCoordinateSpace::new_checked([5, 3])).padding([[0, 0]; 2]).build();
+
+    for x in 0..9 {
+        maze.set_cell_value_type([x, 0].into(), BlockCellPrimaryValue::PASSAGE);
+    }
+
+    for y in 0..5 {
+        maze.set_cell_value_type([0, y].into(), BlockCellPrimaryValue::PASSAGE);
+    }
+
+    return maze
+}
+
+#[test]
+fn test_tile_layout() {
+    let maze = get_maze();
+
+    let exporter = BoxSpaceBlockCellTiledMazeExporter::builder(BoxSpaceBlockCellTextMazeExporter::default())
+        .rows_checked(2)
+        .columns_checked(3)
+        .overlap(1)
+        .build();
+
+    let tiles = exporter.tiles(&maze).unwrap();
+
+    let labels: Vec<String> = tiles.iter().map(|tile| tile.label().to_string()).collect();
+    assert_eq!(vec!["A1", "A2", "A3", "B1", "B2", "B3"], labels);
+
+    let origins: Vec<[usize; 2]> = tiles.iter().map(|tile| tile.origin().into()).collect();
+    assert_eq!(vec![[0, 0], [3, 0], [6, 0], [0, 2], [3, 2], [6, 2]], origins);
+
+    let dimensions: Vec<[usize; 2]> = tiles.iter().map(|tile| tile.dimensions().map(usize::from)).collect();
+    assert_eq!(vec![[4, 3], [4, 3], [3, 3], [4, 3], [4, 3], [3, 3]], dimensions);
+}
+
+#[test]
+fn test_tile_export() {
+    let maze = get_maze();
+
+    let exporter = BoxSpaceBlockCellTiledMazeExporter::builder(BoxSpaceBlockCellTextMazeExporter::builder().chars_per_cell_horizontally_checked(1).build())
+        .columns_checked(2)
+        .build();
+
+    let mut labels = Vec::new();
+
+    exporter.export_tiles(&maze, |tile| {
+        labels.push(tile.label().to_string());
+
+        return Ok(Vec::<u8>::new())
+    }).unwrap();
+
+    assert_eq!(vec!["A1", "A2"], labels);
+
+    let tiles = exporter.tiles(&maze).unwrap();
+    let page = BoxSpaceBlockCellTiledMazeExporter::<BoxSpaceBlockCellTextMazeExporter>::extract_tile(&maze, &tiles[1]);
+
+    let mut text = Vec::new();
+    exporter.exporter().export(&page, &mut text).unwrap();
+
+    let expected = "\
+\x20    
+.....
+.....
+.....
+.....
+".replace('\n', get_line_sep());
+
+    assert_eq!(expected, String::from_utf8(text).unwrap());
+}
+
+#[test]
+fn test_too_many_pages() {
+    let maze = get_maze();
+
+    let exporter = BoxSpaceBlockCellTiledMazeExporter::builder(BoxSpaceBlockCellTextMazeExporter::default())
+        .columns_checked(10)
+        .build();
+
+    let error = exporter.tiles(&maze).unwrap_err();
+
+    assert_eq!(ErrorKind::InvalidInput, error.kind());
+    assert_eq!("Cannot split 9 cells into 10 pages", error.to_string());
+
+    let result = exporter.export_tiles(&maze, |_| Ok(Vec::<u8>::new()));
+
+    assert_eq!(ErrorKind::InvalidInput, result.unwrap_err().kind());
+}
+
+#[test]
+fn test_labels_past_z() {
+    let maze = Maze::builder(BoxCoordinateSpace::new_checked([1, 20])).build();
+
+    let exporter = BoxSpaceBlockCellTiledMazeExporter::builder(BoxSpaceBlockCellTextMazeExporter::default())
+        .rows_checked(28)
+        .build();
+
+    let tiles = exporter.tiles(&maze).unwrap();
+
+    assert_eq!("Z1", tiles[25].label().to_string());
+    assert_eq!("AA1", tiles[26].label().to_string());
+    assert_eq!("AB1", tiles[27].label().to_string());
+}
@@ -0,0 +1,56 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::{apply_solid_border, mirror, rotate90, rotate180, rotate270};
+
+#[test]
+fn test_block_cell_rotations_compose() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([5, 3]))
+        .spacing_checked(0, vec![2, 3, 2, 4])
+        .padding([[1, 2], [3, 1]])
+        .build();
+
+    apply_solid_border(&mut maze);
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(5));
+
+    let rotated = rotate90(&maze);
+
+    assert_eq!([3, 5], rotated.coord_space().dimensions().map(usize::from));
+    assert_eq!([[1, 3], [1, 2]], rotated.padding());
+    assert_eq!(maze.spacing(0), rotated.spacing(1));
+
+    assert_eq!(format!("{:?}", rotate180(&maze)), format!("{:?}", rotate90(&rotated)));
+    assert_eq!(format!("{:?}", rotate270(&maze)), format!("{:?}", rotate180(&rotated)));
+    assert_eq!(format!("{:?}", maze), format!("{:?}", rotate270(&rotated)));
+    assert_eq!(format!("{:?}", maze), format!("{:?}", mirror(&mirror(&maze, 1), 1)));
+}
+
+#[test]
+fn test_inline_cell_mirror_preserves_connections() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<3>>, 3>::new(BoxCoordinateSpace::new_checked([3, 2, 2])).build();
+
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(11));
+
+    let mirrored = mirror(&maze, 2);
+    let rotated = rotate90(&maze);
+
+    for pt in maze.coord_space().iter() {
+        for neighbour in maze.coord_space().neighbours_of_pt(pt) {
+            let mirror_pt = |[x, y, z]: [usize; 3]| [x, y, 1 - z].into();
+            let rotate_pt = |[x, y, z]: [usize; 3]| [1 - y, x, z].into();
+
+            assert_eq!(maze.get_connection(pt, neighbour), mirrored.get_connection(mirror_pt(pt.into()), mirror_pt(neighbour.into())));
+            assert_eq!(maze.get_connection(pt, neighbour), rotated.get_connection(rotate_pt(pt.into()), rotate_pt(neighbour.into())));
+        }
+    }
+}
\ No newline at end of file
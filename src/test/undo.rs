@@ -0,0 +1,121 @@
+use std::num::NonZeroUsize;
+
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::{UndoBuffer, VecBuffer};
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::CellID;
+use crate::interface::generate::MazeGenerator;
+
+#[test]
+fn test_undo_and_redo_generation() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<UndoBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([6, 5])).build();
+    let empty = format!("{:?}", maze);
+
+    maze.buffer_mut().begin();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4));
+    assert!(maze.buffer_mut().commit());
+
+    let generated = format!("{:?}", maze);
+
+    assert!(maze.buffer_mut().undo());
+    assert_eq!(empty, format!("{:?}", maze));
+    assert!(maze.buffer().can_undo() == false);
+
+    assert!(maze.buffer_mut().redo());
+    assert_eq!(generated, format!("{:?}", maze));
+    assert!(maze.buffer().can_redo() == false);
+}
+
+#[test]
+fn test_transactions_through_each_write() {
+    let mut buffer = UndoBuffer::wrap(VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(4).unwrap()));
+
+    let wall = BlockCellValue { cell_type: BlockCellPrimaryValue::WALL, ..Default::default() };
+    let passage = BlockCellValue { cell_type: BlockCellPrimaryValue::PASSAGE, ..Default::default() };
+
+    buffer.begin();
+    buffer.set_range(CellID(0)..CellID(3), wall);
+    buffer.commit();
+
+    buffer.begin();
+    buffer.set(CellID(0), passage);
+    buffer.get_mut(CellID(1)).cell_type = BlockCellPrimaryValue::PASSAGE;
+    buffer.commit();
+
+    assert_eq!(vec![passage, passage, wall, BlockCellValue::default()], buffer.get_slice(CellID(0)..CellID(4)).into_owned());
+
+    buffer.undo();
+
+    assert_eq!(vec![wall, wall, wall, BlockCellValue::default()], buffer.get_slice(CellID(0)..CellID(4)).into_owned());
+
+    buffer.undo();
+
+    assert_eq!(vec![BlockCellValue::default(); 4], buffer.get_slice(CellID(0)..CellID(4)).into_owned());
+    assert!(buffer.undo() == false);
+}
+
+#[test]
+fn test_rollback() {
+    let mut buffer = UndoBuffer::wrap(VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(2).unwrap()));
+
+    let wall = BlockCellValue { cell_type: BlockCellPrimaryValue::WALL, ..Default::default() };
+
+    buffer.begin();
+    buffer.set(CellID(0), wall);
+    buffer.get_mut(CellID(1)).cell_type = BlockCellPrimaryValue::WALL;
+    buffer.rollback();
+
+    assert_eq!(vec![BlockCellValue::default(); 2], buffer.get_slice(CellID(0)..CellID(2)).into_owned());
+    assert!(buffer.in_transaction() == false);
+    assert!(buffer.can_undo() == false);
+}
+
+#[test]
+fn test_new_changes_clear_redo_history() {
+    let mut buffer = UndoBuffer::wrap(VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(2).unwrap()));
+
+    let wall = BlockCellValue { cell_type: BlockCellPrimaryValue::WALL, ..Default::default() };
+
+    buffer.begin();
+    buffer.set(CellID(0), wall);
+    buffer.commit();
+    buffer.undo();
+
+    // Empty transactions aren't kept, and don't affect the history
+    buffer.begin();
+    assert!(buffer.commit() == false);
+    assert!(buffer.can_redo());
+
+    buffer.begin();
+    buffer.set(CellID(1), wall);
+    buffer.commit();
+
+    assert!(buffer.can_redo() == false);
+
+    // Changes outside of a transaction clear everything
+    buffer.set(CellID(0), wall);
+
+    assert!(buffer.can_undo() == false);
+}
+
+#[test]
+fn test_unbalanced_transactions_panic() {
+    assert_that_code!(|| {
+        let mut buffer = UndoBuffer::wrap(VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(2).unwrap()));
+        buffer.begin();
+        buffer.begin();
+    }).panics().with_message("A transaction is already open");
+
+    assert_that_code!(|| {
+        let mut buffer = UndoBuffer::wrap(VecBuffer::<BlockCellValue>::new(NonZeroUsize::new(2).unwrap()));
+        buffer.commit();
+    }).panics().with_message("No transaction is open");
+}
\ No newline at end of file
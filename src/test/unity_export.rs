@@ -0,0 +1,47 @@
+#![cfg(feature = "json")]
+
+use serde_json::{json, Value};
+
+use crate::implm::export::json::BoxSpaceInlineCellUnityJsonMazeExporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::implm::presets::Inline2DMazeBuilder;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::DefaultMazeExporter;
+use crate::util::apply_solid_border;
+
+#[test]
+fn test_walls_are_merged_into_segments() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+
+    apply_solid_border(&mut maze);
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+    maze.make_passage_between([1, 0].into(), [2, 0].into());
+    maze.make_passage_between([2, 0].into(), [2, 1].into());
+    maze.make_passage_between([2, 1].into(), [1, 1].into());
+    maze.make_passage_between([1, 1].into(), [0, 1].into());
+    maze.set_start([0, 0].into());
+    maze.set_goal([0, 1].into());
+    maze.named_points_mut().set("key", [2, 1].into());
+
+    let mut output = Vec::new();
+    BoxSpaceInlineCellUnityJsonMazeExporter::export(&maze, &mut output).unwrap();
+
+    let level: Value = serde_json::from_slice(&output).unwrap();
+
+    let segment = |start: [f32; 2], end: [f32; 2], kind: &str| json!({ "start": { "x": start[0], "y": start[1] }, "end": { "x": end[0], "y": end[1] }, "kind": kind });
+
+    assert_eq!(json!("mazelib/unity-level"), level["format"]);
+    assert_eq!(json!([3, 2]), json!([level["width"], level["height"]]));
+    assert_eq!(json!([
+        segment([0.0, 0.0], [3.0, 0.0], "boundary"),
+        segment([0.0, 1.0], [2.0, 1.0], "wall"),
+        segment([0.0, 2.0], [3.0, 2.0], "boundary"),
+        segment([0.0, 0.0], [0.0, 2.0], "boundary"),
+        segment([3.0, 0.0], [3.0, 2.0], "boundary"),
+    ]), level["walls"]);
+    assert_eq!(json!([
+        { "kind": "spawn", "name": "start", "position": { "x": 0.5, "y": 0.5 } },
+        { "kind": "goal", "name": "goal", "position": { "x": 0.5, "y": 1.5 } },
+        { "kind": "point", "name": "key", "position": { "x": 2.5, "y": 1.5 } },
+    ]), level["markers"]);
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, CellValue};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::util::{convert_unvisited_cells_to_walls, convert_unvisited_points_to_walls, count_unvisited, fill_unreachable_regions};
+
+#[test]
+fn test_cells_converts_padding_that_points_miss() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3]))
+        .padding([[2, 2], [2, 2]])
+        .build();
+
+    // A padding cell
+    maze.buffer_mut().get_mut(CellID(0)).set_marked(true);
+
+    convert_unvisited_points_to_walls(&mut maze);
+
+    assert_eq!(0, count_unvisited(&maze));
+    assert!(maze.buffer().iter_cells().any(|(_, value)| value.cell_type == BlockCellPrimaryValue::UNVISITED));
+
+    convert_unvisited_cells_to_walls(&mut maze);
+
+    assert!(maze.buffer().iter_cells().all(|(_, value)| value.cell_type == BlockCellPrimaryValue::WALL));
+
+    // Only the cell type changes
+    assert!(maze.buffer().get(CellID(0)).is_marked());
+}
+
+#[test]
+fn test_fill_unreachable_regions_leaves_generated_maze_alone() {
+    let space = BoxCoordinateSpace::new_checked([6, 5]);
+
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(space).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(3));
+
+    let generated = maze.clone();
+
+    assert_eq!(0, fill_unreachable_regions(&mut maze, [2, 2].into()));
+    assert!(maze == generated);
+}
+
+#[test]
+fn test_fill_unreachable_regions_closes_isolated_pockets() {
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([4, 2])).build();
+
+    // Two separate corridors, and three untouched points
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+    maze.make_passage_between([1, 0].into(), [1, 1].into());
+    maze.make_passage_between([2, 0].into(), [3, 0].into());
+
+    assert_eq!(3, count_unvisited(&maze));
+    assert_eq!(5, fill_unreachable_regions(&mut maze, [0, 0].into()));
+    assert_eq!(0, count_unvisited(&maze));
+
+    assert!(maze.is_passage_between([1, 0].into(), [1, 1].into()));
+    assert!(maze.is_wall_between([2, 0].into(), [3, 0].into()));
+    assert!(maze.is_wall_between([1, 0].into(), [2, 0].into()));
+}
\ No newline at end of file
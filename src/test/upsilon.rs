@@ -0,0 +1,58 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::inline::InlineCellValueEdge;
+use crate::implm::cell::upsilon::UpsilonCellValue;
+use crate::implm::coordinate::upsilon::UpsilonSpaceUpsilonCellMazeCoordinator;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::upsilon::{UpsilonCoordinate, UpsilonCoordinateSpace, UpsilonEdge};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::test::assert_perfect;
+
+type UpsilonMaze = UpsilonSpaceUpsilonCellMazeCoordinator<VecBuffer<UpsilonCellValue>>;
+
+#[test]
+fn test_adjacency() {
+    let space = UpsilonCoordinateSpace::new_checked(5, 4);
+
+    assert_eq!(3, space.neighbours_of_pt(UpsilonCoordinate { column: 0, row: 0 }).len());
+    assert_eq!(3, space.neighbours_of_pt(UpsilonCoordinate { column: 1, row: 0 }).len());
+    assert_eq!(3, space.neighbours_of_pt(UpsilonCoordinate { column: 0, row: 1 }).len());
+    assert_eq!(8, space.neighbours_of_pt(UpsilonCoordinate { column: 1, row: 1 }).len());
+    assert_eq!(4, space.neighbours_of_pt(UpsilonCoordinate { column: 2, row: 1 }).len());
+    assert_eq!(2, space.neighbours_of_pt(UpsilonCoordinate { column: 4, row: 3 }).len());
+
+    // Adjacency must be symmetric, across opposite edges
+    for pt in space.iter() {
+        for edge in UpsilonEdge::ALL {
+            if let Some(neighbour) = space.neighbour(pt, edge) {
+                assert_eq!(Some(edge.opposite()), space.edge_between(neighbour, pt));
+                assert!(space.are_adjacent(neighbour, pt));
+
+                // Squares only ever neighbour octagons
+                assert!(pt.is_octagon() || neighbour.is_octagon());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generated_maze_is_perfect() {
+    let space = UpsilonCoordinateSpace::new_checked(9, 7);
+
+    let mut maze = UpsilonMaze::builder(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+    assert_perfect(&maze);
+
+    let mut maze = UpsilonMaze::builder(space).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+    assert_perfect(&maze);
+
+    // Squares never gain diagonal edges
+    for pt in space.iter().filter(|pt| pt.is_octagon() == false) {
+        assert_eq!([InlineCellValueEdge::BOUNDARY; 4], maze.get(pt).edges[4..]);
+    }
+}
\ No newline at end of file
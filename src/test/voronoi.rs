@@ -0,0 +1,112 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::coordinate::graph::GraphSpaceGraphCellMazeCoordinator;
+use crate::implm::export::svg::VoronoiSvgMazeExporter;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::graph::{Graph, GraphCoordinateSpace, GraphPoint, VoronoiDiagram};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::MazeExporter;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::test::assert_perfect;
+
+type Maze<'graph> = GraphSpaceGraphCellMazeCoordinator<'graph, VecBuffer<BlockCellValue>>;
+
+#[test]
+fn test_graph_from_edges() {
+    // A square with a duplicated edge (in both directions)
+    let graph = Graph::from_edges(4, [[0, 1], [1, 2], [2, 3], [3, 0], [1, 0]]);
+
+    assert_eq!(4, usize::from(graph.node_count()));
+    assert_eq!(4, graph.edge_count());
+    assert_eq!(&[1, 3], graph.neighbours(0));
+    assert_eq!(Some(0), graph.edge_between(1, 0));
+    assert_eq!(None, graph.edge_between(0, 2));
+
+    let space = GraphCoordinateSpace::new(&graph);
+
+    assert_eq!(vec![GraphPoint(0), GraphPoint(1), GraphPoint(2), GraphPoint(3)], space.iter().collect::<Vec<_>>());
+    assert!(space.are_adjacent(GraphPoint(2), GraphPoint(3)));
+    assert!(space.are_adjacent(GraphPoint(1), GraphPoint(3)) == false);
+
+    assert_that_code!(|| Graph::from_edges(2, [[0, 0]])).panics().with_having_message("The edge [0, 0] joins a node to itself");
+}
+
+#[test]
+fn test_voronoi_grid() {
+    // A 3x2 grid of sites, which should have the same adjacency as a 3x2 box space
+    let sites = vec![[0.5, 0.5], [1.5, 0.5], [2.5, 0.5], [0.5, 1.5], [1.5, 1.5], [2.5, 1.5]];
+    let diagram = VoronoiDiagram::new([3.0, 2.0], sites);
+
+    assert_eq!(7, diagram.graph().edge_count());
+    assert_eq!(&[1, 3], diagram.graph().neighbours(0));
+    assert_eq!(&[0, 2, 4], diagram.graph().neighbours(1));
+
+    assert_eq!(4, diagram.cell(4).len());
+
+    // The cells should tile the rectangle
+    let area: f64 = (0..6).map(|site| polygon_area(&diagram.cell(site))).sum();
+    assert!((area - 6.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_voronoi_maze_is_perfect() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    for _ in 0..5 {
+        let diagram = VoronoiDiagram::random_checked([20.0, 15.0], 150, &mut rng);
+
+        let area: f64 = (0..150).map(|site| polygon_area(&diagram.cell(site))).sum();
+        assert!((area - 300.0).abs() < 1e-6);
+
+        let mut maze = Maze::builder(diagram.space()).build();
+        RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut rng);
+        assert_perfect(&maze);
+
+        let mut maze = Maze::builder(diagram.space()).build();
+        HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut rng);
+        assert_perfect(&maze);
+    }
+}
+
+#[test]
+fn test_voronoi_svg_export() {
+    let diagram = VoronoiDiagram::new([2.0, 1.0], vec![[0.5, 0.5], [1.5, 0.5]]);
+
+    let mut maze = Maze::builder(diagram.space()).build();
+
+    let export = |maze: &Maze| {
+        let mut svg = Vec::new();
+        VoronoiSvgMazeExporter::builder(&diagram).build().export(maze, &mut svg).unwrap();
+        String::from_utf8(svg).unwrap()
+    };
+
+    // Four outer edges on each side, plus the wall between them
+    assert_eq!(7, export(&maze).matches('M').count());
+
+    maze.make_passage_between(GraphPoint(0), GraphPoint(1));
+
+    let svg = export(&maze);
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(6, svg.matches('M').count());
+
+    let other = VoronoiDiagram::new([2.0, 1.0], vec![[0.5, 0.5], [1.5, 0.5]]);
+    assert_that_code!(|| VoronoiSvgMazeExporter::builder(&other).build().export(&maze, &mut Vec::new()).unwrap())
+        .panics()
+        .with_having_message("The maze was not built from this exporter's diagram");
+}
+
+
+fn polygon_area(polygon: &[[f64; 2]]) -> f64 {
+    (0..polygon.len()).map(|i| {
+        let [x1, y1] = polygon[i];
+        let [x2, y2] = polygon[(i + 1) % polygon.len()];
+
+        x1 * y2 - x2 * y1
+    }).sum::<f64>() / 2.0
+}
\ No newline at end of file
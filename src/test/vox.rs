@@ -0,0 +1,100 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellLocation, BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::export::vox::VoxMazeExporter;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::export::MazeExporter;
+use crate::interface::point::CoordinateSpace;
+
+/// Check the structure of a `.vox` file, returning its size, its voxels, and its palette.
+fn parse(vox: &[u8]) -> ([u32; 3], Vec<[u8; 4]>, Vec<[u8; 4]>) {
+    let u32_at = |offset: usize| u32::from_le_bytes(vox[offset..offset + 4].try_into().unwrap());
+
+    assert_eq!(b"VOX ", &vox[0..4]);
+    assert_eq!(150, u32_at(4));
+    assert_eq!(b"MAIN", &vox[8..12]);
+    assert_eq!(0, u32_at(12));
+    assert_eq!(vox.len(), 20 + u32_at(16) as usize);
+
+    let mut chunks = Vec::new();
+    let mut offset = 20;
+
+    while offset < vox.len() {
+        let length = u32_at(offset + 4) as usize;
+        assert_eq!(0, u32_at(offset + 8));
+
+        chunks.push((&vox[offset..offset + 4], &vox[offset + 12..offset + 12 + length]));
+
+        offset += 12 + length;
+    }
+
+    assert_eq!(vec![&b"SIZE"[..], b"XYZI", b"RGBA"], chunks.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+
+    let size = [0, 1, 2].map(|i| u32::from_le_bytes(chunks[0].1[i * 4..i * 4 + 4].try_into().unwrap()));
+
+    let voxel_count = u32::from_le_bytes(chunks[1].1[0..4].try_into().unwrap()) as usize;
+    let voxels = chunks[1].1[4..].chunks(4).map(|voxel| voxel.try_into().unwrap()).collect::<Vec<[u8; 4]>>();
+    assert_eq!(voxel_count, voxels.len());
+
+    let palette = chunks[2].1.chunks(4).map(|colour| colour.try_into().unwrap()).collect::<Vec<[u8; 4]>>();
+    assert_eq!(256, palette.len());
+
+    return (size, voxels, palette)
+}
+
+#[test]
+fn test_2d_maze_is_extruded() {
+    let walls = [[true, false, false], [false, false, false]];
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::from_walls(&walls);
+    maze.set_cell_value_type(BlockCellLocation([2, 1].into()), BlockCellPrimaryValue::BOUNDARY);
+    maze.set_cell_value_type(BlockCellLocation([1, 0].into()), BlockCellPrimaryValue::UNVISITED);
+
+    let mut vox = Vec::new();
+    VoxMazeExporter::builder().wall_height(2).floor(true).build().export(&maze, &mut vox).unwrap();
+
+    let (size, mut voxels, palette) = parse(&vox);
+    voxels.sort_unstable();
+
+    assert_eq!([3, 2, 2], size);
+
+    // The maze's second axis is flipped, and unvisited cells have no floor
+    assert_eq!(vec![
+        [0, 0, 0, 3],
+        [0, 1, 0, 1],
+        [0, 1, 1, 1],
+        [1, 0, 0, 3],
+        [2, 0, 0, 2],
+        [2, 0, 1, 2],
+        [2, 1, 0, 3],
+    ], voxels);
+
+    assert_eq!(VoxMazeExporter::default().palette().to_vec(), palette[0..3]);
+}
+
+#[test]
+fn test_3d_maze_is_stacked() {
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 3>::builder(BoxCoordinateSpace::new_checked([2, 2, 2])).build();
+
+    for cell in BoxCoordinateSpace::new(maze.get_full_dimensions()).iter() {
+        maze.set_cell_value_type(BlockCellLocation(cell), BlockCellPrimaryValue::PASSAGE);
+    }
+
+    maze.set_cell_value_type(BlockCellLocation([1, 0, 2].into()), BlockCellPrimaryValue::WALL);
+
+    let mut vox = Vec::new();
+    VoxMazeExporter::builder().floor(true).build().export(&maze, &mut vox).unwrap();
+
+    let (size, voxels, _) = parse(&vox);
+    let dimensions = maze.get_full_dimensions().map(|length| usize::from(length) as u32);
+
+    assert_eq!(dimensions, size);
+    assert_eq!(vec![[1, dimensions[1] as u8 - 1, 2, 1]], voxels);
+}
+
+#[test]
+#[should_panic(expected = "Cannot export mazes larger than 256 voxels along any axis")]
+fn test_too_large_maze_panics() {
+    let maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([200, 2])).build();
+
+    VoxMazeExporter::default().export(&maze, &mut Vec::new()).unwrap();
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+#![cfg(feature = "wasm")]
+
+use crate::quick::Algorithm;
+use crate::wasm::WasmMaze;
+
+#[test]
+fn test_seeded_generation() {
+    let maze = WasmMaze::new(9, 7, Algorithm::HuntAndKill, 3).unwrap();
+
+    assert_eq!([9, 7], [maze.width(), maze.height()]);
+
+    assert_eq!(maze.to_svg(1.0), WasmMaze::new(9, 7, Algorithm::HuntAndKill, 3).unwrap().to_svg(1.0));
+    assert_ne!(maze.to_svg(1.0), WasmMaze::new(9, 7, Algorithm::HuntAndKill, 4).unwrap().to_svg(1.0));
+}
+
+#[test]
+fn test_solution_is_drawn_once_solved() {
+    let mut maze = WasmMaze::new(6, 6, Algorithm::RecursiveBacktracker, 1).unwrap();
+
+    assert_eq!(0, maze.solution_length());
+    assert!(maze.to_svg(4.0).contains("solution") == false);
+
+    assert!(maze.solve());
+
+    let svg = maze.to_svg(4.0);
+
+    assert!(maze.solution_length() >= 2);
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains(r#"<polyline class="solution""#));
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn test_invalid_dimensions() {
+    assert!(WasmMaze::new(0, 5, Algorithm::NAryTree, 0).is_err());
+    assert!(WasmMaze::new(usize::MAX, usize::MAX, Algorithm::NAryTree, 0).is_err());
+}
@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::implm::presets::Inline2DMazeBuilder;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::DefaultMazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::wavefronts;
+
+#[test]
+fn test_wavefronts_are_distance_bands() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([7, 5])).build();
+    RecursiveBacktrackerGenerator::generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(4951));
+
+    let fronts = wavefronts(&maze, [3, 2].into());
+
+    assert_eq!(vec![CoordinateTuplet([3, 2])], fronts[0]);
+
+    // Every point is reached exactly once
+    let reached: HashSet<_> = fronts.iter().flatten().copied().collect();
+
+    assert_eq!(35, fronts.iter().map(Vec::len).sum::<usize>());
+    assert_eq!(35, reached.len());
+
+    // Every point is one step further than a point in the previous wavefront
+    for (distance, front) in fronts.iter().enumerate().skip(1) {
+        for pt in front {
+            assert!(fronts[distance - 1].iter().any(|previous| maze.coord_space().are_adjacent(*previous, *pt) && maze.is_passage_between(*previous, *pt)));
+        }
+    }
+}
+
+#[test]
+fn test_wavefronts_skip_unreachable_points() {
+    let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+    maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+    let fronts = wavefronts(&maze, [0, 0].into());
+
+    assert_eq!(vec![vec![CoordinateTuplet([0, 0])], vec![CoordinateTuplet([1, 0])]], fronts);
+}
+
+#[cfg(feature = "img")]
+mod image_export {
+    use std::io::Cursor;
+
+    use image::{ImageFormat, Rgba, RgbaImage};
+
+    use crate::implm::export::img::BoxSpaceWavefrontImageExporter;
+    use crate::implm::point::boxy::BoxCoordinateSpace;
+    use crate::implm::presets::Standard2DMaze;
+    use crate::interface::coordinate::MazeCoordinator;
+    use crate::interface::export::MazeExporter;
+
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+    const GREEN: Rgba<u8> = Rgba([0, 255, 0, 255]);
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+    fn decode(png: &[u8]) -> RgbaImage {
+        image::load_from_memory_with_format(png, ImageFormat::Png).unwrap().to_rgba8()
+    }
+
+    /// A 3x1 maze with a passage from `[0, 0]` to `[1, 0]`, and a wall before `[2, 0]`.
+    fn corridor() -> Standard2DMaze {
+        let mut maze = Standard2DMaze::builder(BoxCoordinateSpace::new_checked([3, 1])).build();
+
+        maze.make_passage([2, 0].into());
+        maze.make_passage_between([0, 0].into(), [1, 0].into());
+
+        return maze
+    }
+
+    #[test]
+    fn test_wavefront_image_colours_bands() {
+        let maze = corridor();
+        let exporter = BoxSpaceWavefrontImageExporter::new(ImageFormat::Png, [0, 0].into()).with_palette(vec![RED, GREEN]);
+
+        let mut output = Cursor::new(Vec::new());
+        exporter.export(&maze, &mut output).unwrap();
+
+        let img = decode(output.get_ref());
+
+        // The cells of [0, 0], the passage, and [1, 0] in the middle row
+        assert_eq!(&RED, img.get_pixel(1, 1));
+        assert_eq!(&GREEN, img.get_pixel(2, 1));
+        assert_eq!(&GREEN, img.get_pixel(3, 1));
+
+        // [2, 0] isn't reached, so it's drawn as usual
+        assert_eq!(&WHITE, img.get_pixel(5, 1));
+    }
+
+    #[test]
+    fn test_wavefront_frames_spread() {
+        let maze = corridor();
+        let exporter = BoxSpaceWavefrontImageExporter::new(ImageFormat::Png, [0, 0].into()).with_palette(vec![RED, GREEN]);
+
+        assert_eq!(2, exporter.frame_count(&maze));
+
+        let mut frames = vec![Cursor::new(Vec::new()); 3];
+        let mut outputs = frames.iter_mut();
+        let mut frame_numbers = Vec::new();
+
+        exporter.export_frames(&maze, |frame| {
+            frame_numbers.push(frame);
+
+            Ok(outputs.next().unwrap())
+        }).unwrap();
+
+        assert_eq!(vec![0, 1], frame_numbers);
+        assert!(frames[2].get_ref().is_empty());
+
+        // The first frame only has the origin
+        let first = decode(frames[0].get_ref());
+
+        assert_eq!(&RED, first.get_pixel(1, 1));
+        assert_eq!(&WHITE, first.get_pixel(2, 1));
+        assert_eq!(&WHITE, first.get_pixel(3, 1));
+
+        // The last frame is the same as the full export
+        let mut full = Cursor::new(Vec::new());
+        exporter.export(&maze, &mut full).unwrap();
+
+        assert_eq!(full.get_ref(), frames[1].get_ref());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wavefront_image_rejects_origin_outside_maze() {
+        let maze = corridor();
+
+        let _ = BoxSpaceWavefrontImageExporter::new(ImageFormat::Png, [3, 0].into()).frame_count(&maze);
+    }
+}
\ No newline at end of file
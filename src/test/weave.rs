@@ -0,0 +1,119 @@
+use std::collections::{HashSet, VecDeque};
+
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::export::text::BoxSpaceInlineCellTextMazeExporter;
+use crate::implm::generate::WeaveGenerator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinatePair};
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::DefaultMazeExporter;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+
+type WeaveMaze = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<2>>, 2>;
+
+fn get_maze(dimensions: [usize; 2]) -> WeaveMaze {
+    BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked(dimensions)).build()
+}
+
+#[test]
+fn test_tunnel() {
+    let mut maze = get_maze([3, 3]);
+
+    // Not two apart
+    assert!(maze.can_tunnel_between([0, 0].into(), [1, 0].into()) == false);
+    assert!(maze.can_tunnel_between([0, 0].into(), [2, 2].into()) == false);
+
+    // The centre is not a passage yet
+    assert!(maze.can_tunnel_between([1, 0].into(), [1, 2].into()) == false);
+
+    maze.make_passage_between([0, 1].into(), [1, 1].into());
+    maze.make_passage_between([1, 1].into(), [2, 1].into());
+
+    // Only across the passage, not along it
+    assert!(maze.can_tunnel_between([0, 1].into(), [2, 1].into()) == false);
+    assert!(maze.can_tunnel_between([1, 0].into(), [1, 2].into()));
+
+    maze.make_tunnel_between([1, 0].into(), [1, 2].into());
+
+    assert_eq!(Some(1), maze.get([1, 1].into()).tunnel);
+    assert!(maze.is_tunnel_between([1, 2].into(), [1, 0].into()));
+    assert!(maze.is_passage_between([1, 0].into(), [1, 1].into()) == false);
+    assert!(maze.is_passage_between([0, 1].into(), [1, 1].into()));
+    assert_eq!(vec![CoordinatePair::from([1, 2])], maze.tunnel_neighbours_of_pt([1, 0].into()));
+
+    // Only one tunnel per point
+    assert!(maze.can_tunnel_between([1, 0].into(), [1, 2].into()) == false);
+
+    assert_that_code!(|| get_maze([3, 3]).make_tunnel_between([1, 0].into(), [1, 2].into()))
+        .panics()
+        .with_having_message("A tunnel cannot be carved between (1, 0) and (1, 2)");
+
+    let mut text = Vec::new();
+    BoxSpaceInlineCellTextMazeExporter::export(&maze, &mut text).unwrap();
+
+    let expected = "\
+┌┄┄┄┬───┬┄┄┄┐
+┆   │   │   ┆
+├───┴╌╌╌┴───┤
+│           │
+├───┬╌╌╌┬───┤
+┆   │   │   ┆
+└┄┄┄┴───┴┄┄┄┘
+";
+
+    assert_eq!(expected, String::from_utf8(text).unwrap().replace("\r\n", "\n"));
+}
+
+#[test]
+fn test_generated_weave_maze_is_perfect() {
+    let space = BoxCoordinateSpace::new_checked([12, 10]);
+
+    let mut tunnels = 0;
+
+    for seed in 0..5 {
+        let mut maze = get_maze([12, 10]);
+        WeaveGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(seed));
+
+        let mut connections = 0;
+
+        for pt in space.iter() {
+            assert!(maze.get(pt).is_fully_visited());
+
+            connections += space.neighbours_of_pt(pt).into_iter().filter(|neighbour| maze.is_passage_between(pt, *neighbour)).count();
+            connections += maze.tunnel_neighbours_of_pt(pt).len();
+        }
+
+        tunnels += space.iter().filter(|pt| maze.get(*pt).tunnel.is_some()).count();
+
+        // Every connection is counted from both ends. A spanning tree has one fewer edge than it has
+        // points.
+        assert_eq!(usize::from(space.logical_size()) - 1, connections / 2);
+
+        let origin = [0, 0].into();
+
+        let mut visited = HashSet::from([origin]);
+        let mut queue = VecDeque::from([origin]);
+
+        while let Some(pt) = queue.pop_front() {
+            let neighbours = space.neighbours_of_pt(pt).into_iter().filter(|neighbour| maze.is_passage_between(pt, *neighbour));
+
+            for neighbour in neighbours.chain(maze.tunnel_neighbours_of_pt(pt)) {
+                if visited.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        assert_eq!(usize::from(space.logical_size()), visited.len());
+    }
+
+    // Make sure we actually tested some tunnels
+    assert!(tunnels > 0);
+}
\ No newline at end of file
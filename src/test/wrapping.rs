@@ -0,0 +1,85 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::export::text::BoxSpaceInlineCellTextMazeExporter;
+use crate::implm::generate::RecursiveBacktrackerGenerator;
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::export::DefaultMazeExporter;
+use crate::interface::generate::MazeGenerator;
+use crate::test::assert_perfect;
+
+#[test]
+fn test_inline_torus_is_perfect() {
+    let space = BoxCoordinateSpace::new_wrapping_checked([8, 6], [true, true]);
+
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+
+    assert_perfect(&maze);
+
+    // Passages are seen the same way from both sides of the wrap
+    for y in 0..6 {
+        assert_eq!(maze.is_passage_between([7, y].into(), [0, y].into()), maze.is_passage_between([0, y].into(), [7, y].into()));
+    }
+
+    assert!((0..6).any(|y| maze.is_passage_between([7, y].into(), [0, y].into())));
+}
+
+#[test]
+fn test_block_torus_is_perfect() {
+    let space = BoxCoordinateSpace::new_wrapping_checked([8, 6], [true, true]);
+
+    let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+
+    assert_perfect(&maze);
+
+    // Wrapped passages are carved through the padding on both sides
+    let [width, _] = maze.get_full_dimensions().map(usize::from);
+
+    for y in 0..6 {
+        let loc = maze.map_pt_to_cell_loc([0, y].into());
+
+        let left = maze.get_cell_value(loc.at(0, 0)).cell_type == BlockCellPrimaryValue::PASSAGE;
+        let right = maze.get_cell_value(loc.at(0, width - 1)).cell_type == BlockCellPrimaryValue::PASSAGE;
+
+        assert_eq!(maze.is_passage_between([7, y].into(), [0, y].into()), left);
+        assert_eq!(left, right);
+    }
+}
+
+#[test]
+fn test_cylinder_text_export() {
+    let space = BoxCoordinateSpace::new_cylinder_checked([8, 3]);
+
+    let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+
+    assert_perfect(&maze);
+
+    let mut text = Vec::new();
+    BoxSpaceInlineCellTextMazeExporter::export(&maze, &mut text).unwrap();
+    let text = String::from_utf8(text).unwrap();
+
+    let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+
+    // The seam is drawn as matching gaps on the left and right sides
+    for y in 0..3 {
+        let line = &lines[2 * y + 1];
+
+        let is_open = maze.is_passage_between([7, y].into(), [0, y].into());
+
+        assert_eq!(is_open, line[0] == ' ', "{}", text);
+        assert_eq!(is_open, line[line.len() - 1] == ' ' || line.len() < lines[0].len(), "{}", text);
+    }
+
+    // The top and bottom don't wrap
+    assert!(lines[0].iter().all(|c| *c != ' '));
+    assert!(lines[lines.len() - 1].iter().all(|c| *c != ' '));
+}
\ No newline at end of file
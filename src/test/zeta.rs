@@ -0,0 +1,94 @@
+use fluent_asserter::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::BlockCellValue;
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+use crate::implm::generate::{HuntAndKillGenerator, RecursiveBacktrackerGenerator};
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::interface::point::CoordinateSpace;
+use crate::test::assert_perfect;
+
+type Maze = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, 2>;
+
+#[test]
+fn test_zeta_adjacency() {
+    let space = BoxCoordinateSpace::new_zeta_checked([4, 3]);
+
+    assert_eq!(8, space.neighbours_of_pt([1, 1].into()).len());
+    assert_eq!(5, space.neighbours_of_pt([1, 0].into()).len());
+    assert_eq!(3, space.neighbours_of_pt([3, 2].into()).len());
+
+    for pt in space.iter() {
+        for neighbour in space.neighbours_of_pt(pt) {
+            assert!(space.are_adjacent(pt, neighbour));
+            assert!(space.neighbours_of_pt(neighbour).contains(&pt));
+        }
+    }
+
+    assert!(space.are_adjacent([0, 0].into(), [2, 2].into()) == false);
+    assert!(BoxCoordinateSpace::new_checked([4, 3]).are_adjacent([0, 0].into(), [1, 1].into()) == false);
+}
+
+#[test]
+fn test_zeta_maze_is_perfect() {
+    let space = BoxCoordinateSpace::new_zeta_checked([9, 7]);
+
+    let mut maze = Maze::builder(space).build();
+    RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+
+    assert_perfect(&maze);
+
+    // Some diagonals were carved, and none of them cross
+    let mut diagonals = 0;
+
+    for x in 0..8 {
+        for y in 0..6 {
+            let down_right = maze.is_passage_between([x, y].into(), [x + 1, y + 1].into());
+            let down_left = maze.is_passage_between([x + 1, y].into(), [x, y + 1].into());
+
+            assert!((down_right && down_left) == false);
+
+            diagonals += usize::from(down_right) + usize::from(down_left);
+        }
+    }
+
+    assert!(diagonals > 0);
+
+    let mut maze = Maze::builder(space).build();
+    HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(0));
+
+    assert_perfect(&maze);
+}
+
+#[test]
+fn test_crossing_diagonals() {
+    let space = BoxCoordinateSpace::new_zeta_checked([2, 2]);
+
+    let mut maze = Maze::builder(space).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 1].into());
+    assert!(maze.is_boundary_between([1, 0].into(), [0, 1].into()));
+
+    let mut maze = Maze::builder(space).diagonals_may_cross(true).build();
+
+    maze.make_passage_between([0, 0].into(), [1, 1].into());
+    assert!(maze.is_unvisited_between([1, 0].into(), [0, 1].into()));
+
+    maze.make_passage_between([1, 0].into(), [0, 1].into());
+    assert!(maze.is_passage_between([1, 0].into(), [0, 1].into()));
+    assert!(maze.is_passage_between([0, 0].into(), [1, 1].into()));
+    assert!(maze.is_passage_between([0, 0].into(), [1, 0].into()) == false);
+}
+
+#[test]
+fn test_zeta_maze_needs_room_to_cross() {
+    assert_that_code!(|| Maze::builder(BoxCoordinateSpace::new_zeta_checked([2, 2])).scale_factors_checked([2, 2]).build()).panics().with_having_message("Diagonal mazes must have scale factors of at least 3");
+
+    assert_that_code!(|| BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_zeta_checked([2, 2])).build()).panics().with_having_message("Inline cells do not support diagonal adjacency");
+}
\ No newline at end of file
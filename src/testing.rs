@@ -0,0 +1,222 @@
+//! [Proptest](https://docs.rs/proptest) strategies for property-testing code built on this
+//! library.
+//!
+//! If you implement this library's interface traits (e.g. a new generator, solver, or exporter),
+//! these let you check that your implementation works on mazes of every shape, and not just the
+//! ones you thought to write tests for. When a test fails, proptest shrinks the maze down to the
+//! smallest one that still fails.
+//!
+//! Cell values and coordinate spaces implement [`Arbitrary`], so they can be used with
+//! [`any()`][proptest::prelude::any]. Randomly shaped, fully generated mazes come from
+//! [`generated_mazes()`], or [`small_block_mazes()`] and [`small_inline_mazes()`] for the common
+//! cases.
+//!
+//! Requires the `testing` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use mazelib::interface::cell::CellValue;
+//! use mazelib::interface::coordinate::MazeCoordinator;
+//! use mazelib::interface::point::CoordinateSpace;
+//! use mazelib::testing::small_block_mazes;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     # #![proptest_config(ProptestConfig::with_cases(16))]
+//!     fn every_point_is_visited(maze in small_block_mazes::<2>()) {
+//!         // A lone point has nothing to carve a passage to
+//!         prop_assume!(maze.coord_space().logical_size().get() > 1);
+//!
+//!         prop_assert!(maze.iter().all(|(_, value)| value.is_fully_visited()));
+//!     }
+//! }
+//! # every_point_is_visited();
+//! ```
+#![cfg(any(feature = "testing", doc))]
+
+use std::fmt::Debug;
+
+use proptest::prelude::*;
+use proptest::sample::Index;
+use rand::SeedableRng;
+
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue, VerticalConnector};
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::generate::HuntAndKillGenerator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::cell::Terrain;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::generate::MazeGenerator;
+use crate::rng::PortableRng;
+
+/// The most points along each axis of the coordinate spaces from [`any()`][proptest::prelude::any],
+/// [`small_block_mazes()`], and [`small_inline_mazes()`].
+pub const DEFAULT_MAX_SIZE: usize = 8;
+
+/// Return a strategy for box coordinate spaces with between 1 and `max_size` points along each
+/// axis.
+///
+/// Spaces shrink towards a single point. None of their axes wrap around.
+///
+/// # Panics
+///
+/// If `max_size` is zero.
+pub fn box_coordinate_spaces<const DIMENSION: usize>(max_size: usize) -> impl Strategy<Value = BoxCoordinateSpace<DIMENSION>> {
+    assert!(max_size > 0, "The maximum size must be non-zero");
+
+    proptest::collection::vec(1..=max_size, DIMENSION)
+        .prop_map(|dimensions| BoxCoordinateSpace::new_checked(std::array::from_fn(|axis| dimensions[axis])))
+}
+
+/// Return a strategy for points in `space`.
+///
+/// Points shrink towards the origin.
+pub fn points_in<const DIMENSION: usize>(space: BoxCoordinateSpace<DIMENSION>) -> impl Strategy<Value = CoordinateTuplet<DIMENSION>> {
+    proptest::collection::vec(any::<Index>(), DIMENSION)
+        .prop_map(move |indices| CoordinateTuplet(std::array::from_fn(|axis| indices[axis].index(usize::from(space[axis])))))
+}
+
+/// Return a strategy for mazes built by `build` over a space from `spaces`, and then generated by
+/// a generator from `generator`.
+///
+/// Each maze is generated from its own seed, so a failing maze can be reproduced exactly. Mazes
+/// shrink along with their spaces.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// use mazelib::implm::generate::RecursiveBacktrackerGenerator;
+/// use mazelib::testing::{box_coordinate_spaces, generated_mazes};
+///
+/// let mazes = generated_mazes(
+///     box_coordinate_spaces::<3>(4),
+///     |space| BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<3>>, 3>::new(space).build(),
+///     RecursiveBacktrackerGenerator::new,
+/// );
+/// ```
+pub fn generated_mazes<M, Generator, const DIMENSION: usize>(
+    spaces: impl Strategy<Value = BoxCoordinateSpace<DIMENSION>>,
+    build: impl Fn(BoxCoordinateSpace<DIMENSION>) -> M,
+    generator: impl Fn() -> Generator,
+) -> impl Strategy<Value = M>
+    where M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>> + Debug,
+          Generator: MazeGenerator<M> {
+    (spaces, any::<u64>()).prop_map(move |(space, seed)| {
+        let mut maze = build(space);
+
+        generator().generate_with_rng(&mut maze, &mut PortableRng::seed_from_u64(seed));
+
+        maze
+    })
+}
+
+/// Return a strategy for [block cell mazes][BoxSpaceBlockCellMazeCoordinator] with up to
+/// [`DEFAULT_MAX_SIZE`] points along each axis, generated with the
+/// [*Hunt-and-Kill* algorithm][HuntAndKillGenerator].
+pub fn small_block_mazes<const DIMENSION: usize>() -> impl Strategy<Value = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, DIMENSION>> {
+    generated_mazes(box_coordinate_spaces(DEFAULT_MAX_SIZE), |space| BoxSpaceBlockCellMazeCoordinator::builder(space).build(), HuntAndKillGenerator::new)
+}
+
+/// Return a strategy for [inline cell mazes][BoxSpaceInlineCellMazeCoordinator] with up to
+/// [`DEFAULT_MAX_SIZE`] points along each axis, generated with the
+/// [*Hunt-and-Kill* algorithm][HuntAndKillGenerator].
+pub fn small_inline_mazes<const DIMENSION: usize>() -> impl Strategy<Value = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<DIMENSION>>, DIMENSION>> {
+    generated_mazes(box_coordinate_spaces(DEFAULT_MAX_SIZE), |space| BoxSpaceInlineCellMazeCoordinatorBuilder::new(space).build(), HuntAndKillGenerator::new)
+}
+
+impl <const DIMENSION: usize> Arbitrary for BoxCoordinateSpace<DIMENSION> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// *See [`box_coordinate_spaces()`], with a maximum size of [`DEFAULT_MAX_SIZE`].*
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        box_coordinate_spaces(DEFAULT_MAX_SIZE).boxed()
+    }
+}
+
+impl Arbitrary for Terrain {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<u8>().prop_map(Terrain).boxed()
+    }
+}
+
+impl Arbitrary for BlockCellPrimaryValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(Self::UNVISITED),
+            Just(Self::BOUNDARY),
+            Just(Self::WALL),
+            Just(Self::PASSAGE),
+        ].boxed()
+    }
+}
+
+impl Arbitrary for VerticalConnector {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(Self::NONE),
+            Just(Self::UP),
+            Just(Self::DOWN),
+            Just(Self::UP_AND_DOWN),
+        ].boxed()
+    }
+}
+
+impl Arbitrary for BlockCellValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (any::<BlockCellPrimaryValue>(), any::<bool>(), any::<VerticalConnector>(), any::<Terrain>())
+            .prop_map(|(cell_type, marked, connector, terrain)| Self { cell_type, marked, connector, terrain })
+            .boxed()
+    }
+}
+
+impl Arbitrary for InlineCellValueEdge {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(Self::UNVISITED),
+            Just(Self::BOUNDARY),
+            Just(Self::WALL),
+            Just(Self::PASSAGE),
+        ].boxed()
+    }
+}
+
+impl <const DIMENSION: usize> Arbitrary for InlineCellValue<DIMENSION> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        let tunnels = if DIMENSION == 0 { Just(None).boxed() } else { proptest::option::of(0..DIMENSION).boxed() };
+
+        (proptest::collection::vec(any::<[InlineCellValueEdge; 2]>(), DIMENSION), any::<bool>(), tunnels, any::<Terrain>())
+            .prop_map(|(edges, marked, tunnel, terrain)| Self {
+                edges: std::array::from_fn(|axis| edges[axis]),
+                marked,
+                tunnel,
+                terrain,
+            })
+            .boxed()
+    }
+}
\ No newline at end of file
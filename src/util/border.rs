@@ -0,0 +1,340 @@
+use crate::implm::cell::block::{BlockCellLocation, BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Border the given sides of a box maze with boundaries `thickness` cells thick.
+///
+/// This is sugar for [`Border`], which also lets you choose between boundaries and walls and
+/// leave gaps for entrances.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+/// use mazelib::util::apply_border;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3]))
+///     .padding([[2, 2], [2, 2]])
+///     .build();
+///
+/// apply_border(&mut maze, &[BoxDirection::NORTH, BoxDirection::SOUTH], 2);
+///
+/// assert_eq!(BlockCellPrimaryValue::BOUNDARY, maze.get_cell_value([4, 1].into()).cell_type);
+/// assert_eq!(BlockCellPrimaryValue::UNVISITED, maze.get_cell_value([1, 4].into()).cell_type);
+/// ```
+pub fn apply_border<M: MazeCoordinator<CoordSpace=BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(maze: &mut M, sides: &[BoxDirection<DIMENSION>], thickness: usize) {
+    Border::builder().sides(sides).thickness(thickness).build().apply(maze)
+}
+
+/// What a [`Border`] is made of.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum BorderKind {
+    /// [Boundaries][crate::interface::cell::ConnectionType::BOUNDARY], which generators will never
+    /// carve through. This is the default.
+    #[default]
+    Boundary,
+    /// [Walls][crate::interface::cell::ConnectionType::WALL], which generators may carve through
+    /// like any other wall.
+    Wall,
+}
+
+/// A configurable border for box mazes.
+///
+/// Each [side][BoxDirection] of the maze can be bordered independently. Borders may be made of
+/// [boundaries or walls][BorderKind], and may have gaps in front of chosen points along a side to
+/// act as entrances.
+///
+/// How a border is applied depends on the maze's cells:
+/// * For [block cells][BoxSpaceBlockCellMazeCoordinator], the border is a band of cells
+///   [`thickness`][BorderBuilder::thickness] cells thick along the outer edge of the maze. It
+///   covers the padding, so it should be no thicker than the padding on that side. A gap is a
+///   passage running from the point to the edge of the maze.
+/// * For [inline cells][BoxSpaceInlineCellMazeCoordinator], the border is the outer edge of every
+///   point along the side, so the thickness is ignored. A gap is a passage through that edge.
+/// * For all other maze coordinators, the border is made of the connections between the points
+///   along the side, as in [`apply_solid_border()`][super::apply_solid_border]. The thickness is
+///   ignored, and the connections of a gap's point are left untouched.
+///
+/// # Examples
+///
+/// A border with an entrance at the top left and an exit at the bottom right:
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+/// use mazelib::util::{Border, BorderKind};
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+///
+/// Border::builder()
+///     .kind(BorderKind::Wall)
+///     .gap(BoxDirection::NORTH, [0, 0].into())
+///     .gap(BoxDirection::SOUTH, [2, 2].into())
+///     .build()
+///     .apply(&mut maze);
+///
+/// assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get_cell_value([1, 0].into()).cell_type);
+/// assert_eq!(BlockCellPrimaryValue::WALL, maze.get_cell_value([3, 0].into()).cell_type);
+/// assert_eq!(BlockCellPrimaryValue::PASSAGE, maze.get_cell_value([5, 6].into()).cell_type);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Border<const DIMENSION: usize> {
+    /// The sides of the maze to border.
+    sides: Vec<BoxDirection<DIMENSION>>,
+    /// How many cells thick the border is.
+    thickness: usize,
+    /// What the border is made of.
+    kind: BorderKind,
+    /// The points to leave a gap in front of, and the side the gap is on.
+    gaps: Vec<(BoxDirection<DIMENSION>, CoordinateTuplet<DIMENSION>)>,
+}
+
+impl <const DIMENSION: usize> Border<DIMENSION> {
+    /// Construct a new builder for a `Border`.
+    pub fn builder() -> BorderBuilder<DIMENSION> {
+        BorderBuilder::new()
+    }
+
+    /// Return the sides of the maze to border.
+    #[must_use]
+    pub fn sides(&self) -> &[BoxDirection<DIMENSION>] {
+        &self.sides
+    }
+
+    /// Return how many cells thick the border is.
+    #[must_use]
+    pub fn thickness(&self) -> usize {
+        self.thickness
+    }
+
+    /// Return what the border is made of.
+    #[must_use]
+    pub fn kind(&self) -> BorderKind {
+        self.kind
+    }
+
+    /// Return the points to leave a gap in front of, and the side each gap is on.
+    #[must_use]
+    pub fn gaps(&self) -> &[(BoxDirection<DIMENSION>, CoordinateTuplet<DIMENSION>)] {
+        &self.gaps
+    }
+
+    /// Apply the border to a maze.
+    ///
+    /// # Panics
+    ///
+    /// If a gap's point is not on the side of the maze the gap is on.
+    pub fn apply<M: MazeCoordinator<CoordSpace=BoxCoordinateSpace<DIMENSION>>>(&self, maze: &mut M) {
+        for (side, pt) in &self.gaps {
+            assert!(pt[side.axis()] == edge_position(maze.coord_space(), *side), "The point {:?} is not on the side of the maze its gap is on", pt);
+        }
+
+        <FixSpecialisationPls as ApplyBorder<M, DIMENSION>>::apply(self, maze);
+    }
+
+    /// Return the points with gaps on `side`.
+    fn gaps_on(&self, side: BoxDirection<DIMENSION>) -> impl Iterator<Item = CoordinateTuplet<DIMENSION>> + '_ {
+        self.gaps.iter().filter(move |(gap_side, _)| *gap_side == side).map(|(_, pt)| *pt)
+    }
+}
+
+impl <const DIMENSION: usize> Default for Border<DIMENSION> {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// A builder for a [`Border`].
+#[must_use]
+pub struct BorderBuilder<const DIMENSION: usize> {
+    /// The sides of the maze to border.
+    sides: Vec<BoxDirection<DIMENSION>>,
+    /// How many cells thick the border is.
+    thickness: usize,
+    /// What the border is made of.
+    kind: BorderKind,
+    /// The points to leave a gap in front of, and the side the gap is on.
+    gaps: Vec<(BoxDirection<DIMENSION>, CoordinateTuplet<DIMENSION>)>,
+}
+
+impl <const DIMENSION: usize> BorderBuilder<DIMENSION> {
+    /// Create a new builder for a [`Border`].
+    fn new() -> Self {
+        Self {
+            sides: BoxDirection::all().collect(),
+            thickness: 1,
+            kind: BorderKind::default(),
+            gaps: Vec::new(),
+        }
+    }
+
+    /// Set the sides of the maze to border. Every side is bordered by default.
+    pub fn sides(mut self, sides: &[BoxDirection<DIMENSION>]) -> Self {
+        self.sides = sides.to_vec();
+
+        return self
+    }
+
+    /// Set how many cells thick the border is. The default is 1.
+    ///
+    /// Only mazes with [block cells][BoxSpaceBlockCellMazeCoordinator] have thick borders. A
+    /// thickness of zero leaves the sides untouched, apart from the gaps.
+    pub fn thickness(mut self, thickness: usize) -> Self {
+        self.thickness = thickness;
+
+        return self
+    }
+
+    /// Set what the border is made of. The default is [`BorderKind::Boundary`].
+    pub fn kind(mut self, kind: BorderKind) -> Self {
+        self.kind = kind;
+
+        return self
+    }
+
+    /// Leave a gap in the border on `side`, in front of `pt`.
+    ///
+    /// `pt` must be on that side of the maze. This is checked when the border is
+    /// [applied][Border::apply].
+    pub fn gap(mut self, side: BoxDirection<DIMENSION>, pt: CoordinateTuplet<DIMENSION>) -> Self {
+        self.gaps.push((side, pt));
+
+        return self
+    }
+
+    /// Finalise the [`Border`].
+    #[must_use]
+    pub fn build(self) -> Border<DIMENSION> {
+        Border {
+            sides: self.sides,
+            thickness: self.thickness,
+            kind: self.kind,
+            gaps: self.gaps,
+        }
+    }
+}
+
+/// The position along its axis of the points on `side` of `space`.
+fn edge_position<const DIMENSION: usize>(space: &BoxCoordinateSpace<DIMENSION>, side: BoxDirection<DIMENSION>) -> usize {
+    if side.is_positive() { usize::from(space[side.axis()]) - 1 } else { 0 }
+}
+
+// Same trick as for apply_solid_border()
+
+trait ApplyBorder<M: MazeCoordinator, const DIMENSION: usize> {
+    fn apply(border: &Border<DIMENSION>, maze: &mut M);
+}
+
+struct FixSpecialisationPls {}
+
+impl <M: MazeCoordinator<CoordSpace=BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize> ApplyBorder<M, DIMENSION> for FixSpecialisationPls {
+    default fn apply(border: &Border<DIMENSION>, maze: &mut M) {
+        let space = *maze.coord_space();
+
+        for side in &border.sides {
+            let position = edge_position(&space, *side);
+            let gaps: Vec<_> = border.gaps_on(*side).collect();
+
+            for pt in space.iter().filter(|pt| pt[side.axis()] == position && gaps.contains(pt) == false) {
+                for neighbour in space.neighbours_of_pt(pt) {
+                    if neighbour[side.axis()] != position || gaps.contains(&neighbour) {
+                        continue
+                    }
+
+                    match border.kind {
+                        BorderKind::Boundary => maze.make_boundary_between(pt, neighbour),
+                        BorderKind::Wall => maze.make_wall_between(pt, neighbour),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> ApplyBorder<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>, DIMENSION> for FixSpecialisationPls {
+    fn apply(border: &Border<DIMENSION>, maze: &mut BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>) {
+        let full_dimensions = maze.get_full_dimensions();
+        let cell_space = BoxCoordinateSpace::new(full_dimensions);
+
+        let cell_type = match border.kind {
+            BorderKind::Boundary => BlockCellPrimaryValue::BOUNDARY,
+            BorderKind::Wall => BlockCellPrimaryValue::WALL,
+        };
+
+        // The cells of the gaps' points are never part of the border
+        let gap_cells: Vec<_> = border.gaps.iter().map(|(_, pt)| maze.map_pt_to_cell_loc(*pt)).collect();
+
+        for side in &border.sides {
+            let axis = side.axis();
+            let length = usize::from(full_dimensions[axis]);
+            let thickness = border.thickness.min(length);
+
+            if thickness == 0 {
+                continue
+            }
+
+            let mut origin = [0; DIMENSION];
+            let mut dimensions = full_dimensions.map(usize::from);
+
+            origin[axis] = if side.is_positive() { length - thickness } else { 0 };
+            dimensions[axis] = thickness;
+
+            for cell in cell_space.sub_region_checked(origin.into(), dimensions).iter_parent() {
+                let loc = BlockCellLocation(cell);
+
+                if gap_cells.contains(&loc) == false {
+                    maze.set_cell_value_type(loc, cell_type);
+                }
+            }
+        }
+
+        for (side, pt) in &border.gaps {
+            let axis = side.axis();
+            let mut loc = maze.map_pt_to_cell_loc(*pt);
+
+            let outside = if side.is_positive() { loc[axis] + 1..usize::from(full_dimensions[axis]) } else { 0..loc[axis] };
+
+            for position in outside {
+                loc[axis] = position;
+
+                maze.set_cell_value_type(loc, BlockCellPrimaryValue::PASSAGE);
+            }
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> ApplyBorder<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>, DIMENSION> for FixSpecialisationPls {
+    fn apply(border: &Border<DIMENSION>, maze: &mut BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>) {
+        let space = *maze.coord_space();
+
+        let edge = match border.kind {
+            BorderKind::Boundary => InlineCellValueEdge::BOUNDARY,
+            BorderKind::Wall => InlineCellValueEdge::WALL,
+        };
+
+        for side in &border.sides {
+            let position = edge_position(&space, *side);
+
+            for pt in space.iter().filter(|pt| pt[side.axis()] == position) {
+                let mut value = maze.get(pt);
+                value.edges[side.axis()][usize::from(side.is_positive())] = edge;
+                maze.set(pt, value);
+            }
+        }
+
+        for (side, pt) in &border.gaps {
+            let mut value = maze.get(*pt);
+            value.edges[side.axis()][usize::from(side.is_positive())] = InlineCellValueEdge::PASSAGE;
+            maze.set(*pt, value);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,160 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::interface::cell::{CellValue, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::util::exit_count;
+use crate::util::dead_ends_by_depth;
+
+/// Which dead ends [`braid()`] removes first.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum BraidPolicy {
+    /// Pick dead ends uniformly at random.
+    #[default]
+    Random,
+    /// Pick the [deepest][dead_ends_by_depth] dead ends first. Dead ends of the same depth are
+    /// picked at random.
+    ///
+    /// Long dead ends are the most frustrating to walk into, while short ones are barely noticed,
+    /// so this takes out the most backtracking for the fewest new loops.
+    DeepestFirst,
+    /// Only pick dead ends deeper than the given depth, at random. Shorter dead ends are always
+    /// kept.
+    DeeperThan(usize),
+    /// Pick dead ends spread out as evenly over the maze as possible.
+    ///
+    /// The first dead end is picked at random. Each one after it is the dead end farthest from all
+    /// of those already picked, counting steps through the coordinate space (ignoring walls). This
+    /// stops the new loops from bunching up, leaving whole areas of the maze untouched.
+    Spread,
+}
+
+/// Remove some of the dead ends of a maze by knocking through their walls, returning the number of
+/// dead ends removed.
+///
+/// A braided maze has loops, so there is more than one way around it, and fewer places to get
+/// stuck. `proportion` of the maze's [dead ends][dead_ends_by_depth] are picked according to
+/// `policy`, and each is joined to one of the passage points it has a wall to, preferring another
+/// dead end (which removes both at once). Joining one dead end may remove another, so fewer dead
+/// ends than picked may need to be joined. Dead ends with only boundaries around them are left as
+/// they are.
+///
+/// Passages are carved with [`MazeCoordinator::make_passage_between()`].
+///
+/// # Parameters
+///
+/// * `proportion` --- the proportion of the dead ends to remove, between `0` and `1`. Rounded to
+///   the nearest dead end.
+/// * `policy`     --- which dead ends to remove first.
+/// * `rng`        --- the source of randomness.
+///
+/// # Panics
+///
+/// If `proportion` is not between `0` and `1`.
+///
+/// # Examples
+///
+/// ```
+/// # use rand::SeedableRng;
+/// # use rand_chacha::ChaCha8Rng;
+/// # use mazelib::implm::generate::RecursiveBacktrackerGenerator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::implm::presets::Inline2DMazeBuilder;
+/// # use mazelib::interface::generate::MazeGenerator;
+/// use mazelib::util::{braid, dead_ends_by_depth, BraidPolicy};
+///
+/// let mut rng = ChaCha8Rng::seed_from_u64(4964);
+/// let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([10, 10])).build();
+/// RecursiveBacktrackerGenerator::new().generate_with_rng(&mut maze, &mut rng);
+///
+/// // Only take out the long dead ends, keeping the short ones as they are
+/// braid(&mut maze, 1.0, BraidPolicy::DeeperThan(3), &mut rng);
+///
+/// assert!(dead_ends_by_depth(&maze).iter().all(|(_, depth)| *depth <= 3));
+/// ```
+pub fn braid<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &mut M, proportion: f64, policy: BraidPolicy, rng: &mut (impl Rng + ?Sized)) -> usize {
+    assert!((0.0..=1.0).contains(&proportion), "The proportion must be between 0 and 1");
+
+    let mut dead_ends = dead_ends_by_depth(maze);
+
+    // Shuffle first so that ties are broken at random, then keep the order stable from there
+    dead_ends.shuffle(rng);
+
+    let candidates: Vec<_> = match policy {
+        BraidPolicy::Random => dead_ends.into_iter().map(|(pt, _)| pt).collect(),
+        BraidPolicy::DeepestFirst => {
+            dead_ends.sort_by_key(|(_, depth)| Reverse(*depth));
+
+            dead_ends.into_iter().map(|(pt, _)| pt).collect()
+        },
+        BraidPolicy::DeeperThan(min_depth) => dead_ends.into_iter().filter(|(_, depth)| *depth > min_depth).map(|(pt, _)| pt).collect(),
+        BraidPolicy::Spread => spread_out(maze, dead_ends.into_iter().map(|(pt, _)| pt).collect()),
+    };
+
+    let count = (proportion * candidates.len() as f64).round() as usize;
+
+    let mut removed = 0;
+
+    for dead_end in candidates.into_iter().take(count) {
+        // An earlier join may have already opened this one up
+        if exit_count(maze, dead_end) != 1 {
+            continue
+        }
+
+        let options: Vec<_> = maze.coord_space().neighbours_of_pt(dead_end).into_iter()
+            .filter(|neighbour| maze.is_wall_between(dead_end, *neighbour) && maze.get(*neighbour).state() == ConnectionType::PASSAGE)
+            .collect();
+
+        let other_dead_ends: Vec<_> = options.iter().copied().filter(|neighbour| exit_count(maze, *neighbour) == 1).collect();
+
+        let target = other_dead_ends.choose(rng).or_else(|| options.choose(rng));
+
+        if let Some(target) = target {
+            maze.make_passage_between(dead_end, *target);
+
+            removed += 1;
+        }
+    }
+
+    return removed
+}
+
+/// Reorder `pts` so that each point is the one farthest (through the coordinate space) from all of
+/// those before it. The first point stays first.
+fn spread_out<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M, mut pts: Vec<Space::PtType>) -> Vec<Space::PtType> {
+    let mut ordered = Vec::with_capacity(pts.len());
+
+    // The distance from each point of the space to the nearest point picked so far
+    let mut nearest: HashMap<Space::PtType, usize> = HashMap::new();
+
+    while pts.is_empty() == false {
+        // Points that haven't been reached yet are infinitely far away
+        let (index, _) = pts.iter()
+            .enumerate()
+            .max_by_key(|(index, pt)| (nearest.get(*pt).map_or(usize::MAX, |distance| *distance), Reverse(*index)))
+            .expect("[Bug] There is at least one point left");
+
+        let picked = pts.remove(index);
+
+        // Flood out from the picked point, only as far as it is the nearest picked point
+        let mut queue = VecDeque::from([(picked, 0)]);
+        nearest.insert(picked, 0);
+
+        while let Some((pt, distance)) = queue.pop_front() {
+            for neighbour in maze.coord_space().neighbours_of_pt(pt) {
+                if nearest.get(&neighbour).map_or(true, |existing| *existing > distance + 1) {
+                    nearest.insert(neighbour, distance + 1);
+                    queue.push_back((neighbour, distance + 1));
+                }
+            }
+        }
+
+        ordered.push(picked);
+    }
+
+    return ordered
+}
\ No newline at end of file
@@ -0,0 +1,167 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::interface::cell::{CellValue, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Join every disconnected region of a maze to the rest, carving as few passages as possible,
+/// returning the number of regions that were joined.
+///
+/// A region is a set of passage points (points whose [state][CellValue::state] is
+/// [`PASSAGE`][ConnectionType::PASSAGE]) that can all be reached from each other. Masking,
+/// [importing][crate::interface::import], or [stamping][crate::util::stamp()] can leave a maze
+/// split into several regions, with some parts cut off from others.
+///
+/// The regions are joined one at a time, starting from the first. Each time, the region nearest to
+/// those joined so far is connected to them by the shortest possible line of new passages, through
+/// walls and unvisited points. Where there are several equally short lines, one is picked at random.
+/// Boundaries are never carved through, so a region sealed off by boundaries is left as it is, and
+/// isn't counted.
+///
+/// Passages are carved with [`MazeCoordinator::make_passage_between()`].
+///
+/// # Examples
+///
+/// ```
+/// # use rand::SeedableRng;
+/// # use rand_chacha::ChaCha8Rng;
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::{connect_components, convert_unvisited_points_to_walls};
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([5, 1])).build();
+///
+/// // Two separate corridors
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+/// maze.make_passage_between([3, 0].into(), [4, 0].into());
+/// convert_unvisited_points_to_walls(&mut maze);
+///
+/// assert_eq!(1, connect_components(&mut maze, &mut ChaCha8Rng::seed_from_u64(0)));
+///
+/// assert!(maze.is_passage_between([1, 0].into(), [2, 0].into()));
+/// assert!(maze.is_passage_between([2, 0].into(), [3, 0].into()));
+/// ```
+pub fn connect_components<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &mut M, rng: &mut (impl Rng + ?Sized)) -> usize {
+    let regions = find_regions(maze);
+
+    let Some(first) = regions.first() else { return 0 };
+
+    let mut region_of: HashMap<Space::PtType, usize> = HashMap::new();
+
+    for (i, region) in regions.iter().enumerate() {
+        region_of.extend(region.iter().map(|pt| (*pt, i)));
+    }
+
+    let mut joined: HashSet<Space::PtType> = first.iter().copied().collect();
+    let mut joined_count = 0;
+
+    while joined_count + 1 < regions.len() {
+        let Some(line) = shortest_line(maze, &joined, &region_of, rng) else { break };
+
+        for step in line.windows(2) {
+            if maze.is_passage_between(step[0], step[1]) == false {
+                maze.make_passage_between(step[0], step[1]);
+            }
+        }
+
+        let reached = region_of[line.last().expect("[Bug] A line always reaches a region")];
+
+        joined.extend(line);
+        joined.extend(regions[reached].iter().copied());
+        joined_count += 1;
+    }
+
+    return joined_count
+}
+
+/// Return every region of passage points, in the order their first points are iterated.
+fn find_regions<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M) -> Vec<Vec<Space::PtType>> {
+    let mut seen = HashSet::new();
+    let mut regions = Vec::new();
+
+    for origin in maze.coord_space().iter() {
+        if maze.get(origin).state() != ConnectionType::PASSAGE || seen.insert(origin) == false {
+            continue
+        }
+
+        let mut region = vec![origin];
+        let mut queue = VecDeque::from([origin]);
+
+        while let Some(pt) = queue.pop_front() {
+            for neighbour in maze.neighbours_of_pt(pt) {
+                if maze.is_passage_between(pt, neighbour) && maze.get(neighbour).state() == ConnectionType::PASSAGE && seen.insert(neighbour) {
+                    region.push(neighbour);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        regions.push(region);
+    }
+
+    return regions
+}
+
+/// Return the shortest line of points from a `joined` point to a point of a region that hasn't
+/// been joined, measured by the number of passages that would need to be carved along it.
+///
+/// Returns `None` if no unjoined region can be reached without carving through a boundary.
+fn shortest_line<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M, joined: &HashSet<Space::PtType>, region_of: &HashMap<Space::PtType, usize>, rng: &mut (impl Rng + ?Sized)) -> Option<Vec<Space::PtType>> {
+    // A 0-1 breadth-first search, as stepping along an existing passage costs nothing
+    let mut costs: HashMap<Space::PtType, usize> = joined.iter().map(|pt| (*pt, 0)).collect();
+    let mut previous: HashMap<Space::PtType, Space::PtType> = HashMap::new();
+
+    let mut queue: VecDeque<(Space::PtType, usize)> = joined.iter().map(|pt| (*pt, 0)).collect();
+
+    while let Some((pt, cost)) = queue.pop_front() {
+        if costs[&pt] < cost {
+            continue
+        }
+
+        if joined.contains(&pt) == false && region_of.contains_key(&pt) {
+            let mut line = vec![pt];
+
+            while let Some(before) = previous.get(line.last().expect("[Bug] The line is never empty")) {
+                line.push(*before);
+            }
+
+            line.reverse();
+
+            return Some(line)
+        }
+
+        let mut neighbours = maze.neighbours_of_pt(pt);
+        neighbours.shuffle(rng);
+
+        for neighbour in neighbours {
+            let step_cost = if maze.is_passage_between(pt, neighbour) {
+                0
+            } else if maze.get_connection(pt, neighbour) == ConnectionType::BOUNDARY || maze.get(neighbour).state() == ConnectionType::BOUNDARY {
+                continue
+            } else {
+                1
+            };
+
+            let new_cost = cost + step_cost;
+
+            if costs.get(&neighbour).map_or(true, |old_cost| new_cost < *old_cost) {
+                costs.insert(neighbour, new_cost);
+                previous.insert(neighbour, pt);
+
+                if step_cost == 0 {
+                    queue.push_front((neighbour, new_cost));
+                } else {
+                    queue.push_back((neighbour, new_cost));
+                }
+            }
+        }
+    }
+
+    return None
+}
\ No newline at end of file
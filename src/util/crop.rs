@@ -0,0 +1,132 @@
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxSubRegion};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::transform::carry_over_points;
+
+/// Copy a rectangular region of a box maze out into a new, smaller maze.
+///
+/// The new maze's coordinate space is the region's own space, so the region's origin becomes the
+/// new maze's origin. Every point carries over its cells exactly.
+///
+/// For [block cells][BoxSpaceBlockCellMazeCoordinator], the scale factors and spacing carry over
+/// too. Along each side of the region that lies inside the maze, the new maze is padded with the
+/// cells between the region and the next point over (typically the walls around it). Along sides
+/// on the edge of the maze, the maze's own padding is kept.
+///
+/// [Named points][crate::mark::NamedPoints], [annotations][crate::mark::Annotations], and
+/// [portals][crate::mark::Portals] within the region carry over. Those outside it (including
+/// portals with only one end inside it) are dropped.
+///
+/// # Panics
+///
+/// If the region does not fit within the maze.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// # use mazelib::interface::point::CoordinateSpace;
+/// use mazelib::util::crop;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([10, 10])).build();
+/// maze.make_passage_between([4, 3].into(), [5, 3].into());
+///
+/// let region = maze.coord_space().sub_region_checked([4, 2].into(), [3, 3]);
+/// let cropped = crop(&maze, &region);
+///
+/// assert_eq!([3, 3], cropped.coord_space().dimensions().map(usize::from));
+/// assert!(cropped.is_passage_between([0, 1].into(), [1, 1].into()));
+/// ```
+///
+/// # See Also
+///
+/// [`BoxSpaceBlockCellTiledMazeExporter`][crate::implm::export::tiled::BoxSpaceBlockCellTiledMazeExporter],
+/// which splits a maze into pages by cells rather than points.
+#[must_use]
+pub fn crop<M: BoxCrop<DIMENSION>, const DIMENSION: usize>(maze: &M, region: &BoxSubRegion<DIMENSION>) -> M {
+    for axis in 0..DIMENSION {
+        assert!(region.origin()[axis] + usize::from(region.space()[axis]) <= usize::from(maze.coord_space()[axis]), "The region does not fit within the maze");
+    }
+
+    maze.crop(region)
+}
+
+/// Box mazes that regions can be [cropped][crop()] out of.
+///
+/// This is implemented for the box-space coordinators with
+/// [block cells][BoxSpaceBlockCellMazeCoordinator] and
+/// [inline cells][BoxSpaceInlineCellMazeCoordinator].
+pub trait BoxCrop<const DIMENSION: usize>: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>> + Sized {
+    /// *See [`crop()`].*
+    ///
+    /// The region may be assumed to fit within the maze.
+    #[must_use]
+    fn crop(&self, region: &BoxSubRegion<DIMENSION>) -> Self;
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxCrop<DIMENSION> for BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
+    fn crop(&self, region: &BoxSubRegion<DIMENSION>) -> Self {
+        let origin = region.origin();
+        let dimensions = region.space().dimensions().map(usize::from);
+        let maze_dimensions = self.coord_space().dimensions().map(usize::from);
+
+        let mut builder = Self::builder(*region.space()).scale_factors(self.scale_factors()).diagonals_may_cross(self.diagonals_may_cross());
+
+        let mut padding = self.padding();
+
+        for axis in 0..DIMENSION {
+            let spacing = self.spacing(axis);
+            let first = origin[axis];
+            let last = first + dimensions[axis] - 1;
+
+            if first > 0 {
+                padding[axis][0] = usize::from(spacing[first - 1]) - 1;
+            }
+
+            if last + 1 < maze_dimensions[axis] {
+                padding[axis][1] = usize::from(spacing[last]) - 1;
+            }
+
+            builder = builder.spacing(axis, spacing[first..last].to_vec());
+        }
+
+        let mut maze = builder.padding(padding).build();
+
+        // The cell of the new maze's origin, in this maze
+        let origin_cell = self.map_pt_to_cell_loc(origin);
+        let cell_offset: [usize; DIMENSION] = std::array::from_fn(|axis| origin_cell[axis] - padding[axis][0]);
+
+        for cell in BoxCoordinateSpace::new(maze.get_full_dimensions()).iter() {
+            let source = BlockCellLocation(std::array::from_fn(|axis| cell[axis] + cell_offset[axis]).into());
+
+            *maze.get_cell_value_mut(BlockCellLocation(cell)) = self.get_cell_value(source);
+        }
+
+        carry_over_points(self, &mut maze, |pt| region.from_parent(pt));
+
+        return maze
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> BoxCrop<DIMENSION> for BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION> {
+    fn crop(&self, region: &BoxSubRegion<DIMENSION>) -> Self {
+        let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::new(*region.space()).build();
+
+        for pt in region.space().iter() {
+            maze.set(pt, self.get(region.to_parent(pt)));
+        }
+
+        carry_over_points(self, &mut maze, |pt| region.from_parent(pt));
+
+        return maze
+    }
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::interface::cell::CellValue;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Set a maze's [start][MazeCoordinator::start] and [goal][MazeCoordinator::goal] to the two ends
+/// of its longest path, returning them.
+///
+/// Passages are followed from the first visited point of the maze. For a perfect maze (one with
+/// exactly one path between any two points) this finds the two points furthest apart. For other
+/// mazes, the path found will be long, but may not be the longest. Only the part of the maze
+/// reachable from the first visited point is considered.
+///
+/// If the maze has no visited points, it is left as it is and `None` is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::set_longest_path_endpoints;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+///
+/// // A corridor from [0, 1] around to [2, 1]
+/// maze.make_passage_between([0, 1].into(), [0, 0].into());
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+/// maze.make_passage_between([1, 0].into(), [2, 0].into());
+/// maze.make_passage_between([2, 0].into(), [2, 1].into());
+///
+/// set_longest_path_endpoints(&mut maze);
+///
+/// let endpoints = [maze.start().unwrap(), maze.goal().unwrap()];
+///
+/// assert!(endpoints.contains(&[0, 1].into()));
+/// assert!(endpoints.contains(&[2, 1].into()));
+/// ```
+pub fn set_longest_path_endpoints<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &mut M) -> Option<(Space::PtType, Space::PtType)> {
+    let (first, _) = maze.iter().find(|(_, value)| value.is_fully_visited())?;
+
+    let start = furthest_from(maze, first);
+    let goal = furthest_from(maze, start);
+
+    maze.set_start(start);
+    maze.set_goal(goal);
+
+    return Some((start, goal))
+}
+
+/// Return the point furthest from `origin` by passage, breaking ties by whichever was reached
+/// first.
+fn furthest_from<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M, origin: Space::PtType) -> Space::PtType {
+    let mut visited = HashSet::from([origin]);
+    let mut queue = VecDeque::from([origin]);
+    let mut furthest = origin;
+
+    while let Some(pt) = queue.pop_front() {
+        furthest = pt;
+
+        for neighbour in maze.neighbours_of_pt(pt) {
+            if maze.is_passage_between(pt, neighbour) && visited.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    return furthest
+}
\ No newline at end of file
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Return a hash of the logical structure of a maze.
+///
+/// Two mazes have the same fingerprint when their coordinate spaces have the same shape and every
+/// pair of adjacent points has the same [connection][ConnectionType] between them, with
+/// [portals][crate::mark::Portals] counting as passages. How the maze is stored doesn't matter, so
+/// the same maze in different [buffers][crate::interface::buffer::MazeBuffer], or with block cells
+/// scaled or padded differently, has the same fingerprint. [Named points][crate::mark::NamedPoints],
+/// [annotations][crate::mark::Annotations], and the marks on cells are not included.
+///
+/// This is useful for finding duplicates among generated mazes, or checking that a seed still
+/// generates the same maze. The hash is computed the same way on every platform and will stay the
+/// same between versions of this library, so fingerprints can be stored and compared later.
+///
+/// It is not a cryptographic hash, and different mazes may (very rarely) share a fingerprint.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::fingerprint;
+///
+/// let space = BoxCoordinateSpace::new_checked([3, 3]);
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).build();
+/// let mut padded = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(space).padding([[3, 3], [0, 0]]).build();
+///
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+/// padded.make_passage_between([0, 0].into(), [1, 0].into());
+///
+/// assert_eq!(fingerprint(&maze), fingerprint(&padded));
+///
+/// padded.make_passage_between([1, 0].into(), [1, 1].into());
+///
+/// assert_ne!(fingerprint(&maze), fingerprint(&padded));
+/// ```
+#[must_use]
+pub fn fingerprint(maze: &impl MazeCoordinator) -> u64 {
+    let coord_space = maze.coord_space();
+
+    // Points are identified by their position in the coordinate space's iteration order, so that
+    // the hash doesn't depend on how the points themselves are represented
+    let indices: HashMap<_, _> = coord_space.iter().enumerate().map(|(i, pt)| (pt, i as u64)).collect();
+
+    let mut hasher = Fnv1a::new();
+
+    hasher.write_u64(indices.len() as u64);
+
+    for pt in coord_space.iter() {
+        // Portals are listed in the order they were made, so sort the neighbours to keep the same
+        // portals made in a different order from changing the hash
+        let mut neighbours: Vec<(u64, u8)> = maze.neighbours_of_pt(pt).into_iter().map(|neighbour| (indices[&neighbour], connection_id(maze.get_connection(pt, neighbour)))).collect();
+
+        neighbours.sort_unstable();
+
+        hasher.write_u64(neighbours.len() as u64);
+
+        for (index, connection) in neighbours {
+            hasher.write_u64(index);
+            hasher.write_u8(connection);
+        }
+    }
+
+    return hasher.finish()
+}
+
+/// Return the number each type of connection is hashed as. These must never change.
+fn connection_id(connection: ConnectionType) -> u8 {
+    match connection {
+        ConnectionType::PASSAGE   => 0,
+        ConnectionType::WALL      => 1,
+        ConnectionType::BOUNDARY  => 2,
+        ConnectionType::UNVISITED => 3,
+    }
+}
+
+/// The 64-bit FNV-1a hash.
+///
+/// [`DefaultHasher`][std::collections::hash_map::DefaultHasher] isn't used as its algorithm may change between Rust releases.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xCBF2_9CE4_8422_2325)
+    }
+
+    fn write_u8(&mut self, byte: u8) {
+        self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        for byte in value.to_le_bytes() {
+            self.write_u8(byte);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
\ No newline at end of file
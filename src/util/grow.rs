@@ -0,0 +1,153 @@
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::point::boxy::{BoxCoordinateSpace, BoxDirection, CoordinateTuplet};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::util::transform::carry_over_points;
+
+/// Extend a box maze by `count` rows (or columns, or layers, ...) on `side`, keeping everything
+/// already in it.
+///
+/// This is for mazes that grow as they are explored, such as in endless-runner style games. The
+/// new points are unvisited, ready to be generated or carved into. The old points keep their
+/// cells exactly, so any walls or boundaries along the side that was grown stay where they are
+/// until a passage is carved through them.
+///
+/// Growing on a negative side shifts every old point along by `count`, as the new points take the
+/// lowest coordinates. [Named points][crate::mark::NamedPoints],
+/// [annotations][crate::mark::Annotations], and [portals][crate::mark::Portals] move with them.
+///
+/// For [block cells][BoxSpaceBlockCellMazeCoordinator], the new points are spaced by the axis's
+/// scale factor, and the padding stays the same. The old padding on the side that was grown is
+/// kept as far as it fits between the old points and the new ones.
+///
+/// The maze is rebuilt with a new buffer, so buffers that keep state of their own (e.g. an
+/// [`UndoBuffer`][crate::implm::buffer::UndoBuffer]'s history) start over.
+///
+/// # Panics
+///
+/// If the axis of `side` wraps around, or the grown maze would be too large.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, BoxDirection};
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// # use mazelib::interface::point::CoordinateSpace;
+/// use mazelib::util::grow;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([4, 3])).build();
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+///
+/// grow(&mut maze, BoxDirection::WEST, 2);
+///
+/// assert_eq!([6, 3], maze.coord_space().dimensions().map(usize::from));
+/// assert!(maze.is_passage_between([2, 0].into(), [3, 0].into()));
+/// assert!(maze.is_unvisited_between([0, 0].into(), [1, 0].into()));
+/// ```
+pub fn grow<M: BoxGrow<DIMENSION>, const DIMENSION: usize>(maze: &mut M, side: BoxDirection<DIMENSION>, count: usize) {
+    assert!(maze.coord_space().wrapping()[side.axis()] == false, "Axes that wrap around can't be grown");
+
+    if count > 0 {
+        maze.grow(side, count)
+    }
+}
+
+/// Box mazes that can be [grown][grow()].
+///
+/// This is implemented for the box-space coordinators with
+/// [block cells][BoxSpaceBlockCellMazeCoordinator] and
+/// [inline cells][BoxSpaceInlineCellMazeCoordinator].
+pub trait BoxGrow<const DIMENSION: usize>: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>> + Sized {
+    /// *See [`grow()`].*
+    ///
+    /// The axis of `side` may be assumed not to wrap, and `count` to be non-zero.
+    fn grow(&mut self, side: BoxDirection<DIMENSION>, count: usize);
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxGrow<DIMENSION> for BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
+    fn grow(&mut self, side: BoxDirection<DIMENSION>, count: usize) {
+        let axis = side.axis();
+        let gap = usize::from(self.scale_factors()[axis]);
+
+        let mut builder = Self::builder(grown_space(self.coord_space(), side, count))
+            .scale_factors(self.scale_factors())
+            .padding(self.padding())
+            .diagonals_may_cross(self.diagonals_may_cross());
+
+        for spacing_axis in 0..DIMENSION {
+            let mut spacing = self.spacing(spacing_axis);
+
+            if spacing_axis == axis {
+                let new_gaps = std::iter::repeat(self.scale_factors()[axis]).take(count);
+
+                if side.is_positive() {
+                    spacing.extend(new_gaps);
+                } else {
+                    spacing.splice(0..0, new_gaps);
+                }
+            }
+
+            builder = builder.spacing(spacing_axis, spacing);
+        }
+
+        let mut maze = builder.build();
+
+        let full_dimensions = self.get_full_dimensions().map(usize::from);
+        let padding = self.padding();
+
+        // How far the old cells move along the axis
+        let offset = if side.is_positive() { 0 } else { count * gap };
+
+        for cell in BoxCoordinateSpace::new(self.get_full_dimensions()).iter() {
+            // Only keep as much of the old padding as fits before the new points
+            let fits = if side.is_positive() {
+                cell[axis] + 1 < full_dimensions[axis] - padding[axis][1] + gap
+            } else {
+                cell[axis] + gap > padding[axis][0]
+            };
+
+            if fits {
+                *maze.get_cell_value_mut(BlockCellLocation(cell.at(axis, cell[axis] + offset))) = self.get_cell_value(BlockCellLocation(cell));
+            }
+        }
+
+        carry_over_points(self, &mut maze, |pt| Some(shift(pt, side, count)));
+
+        *self = maze;
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> BoxGrow<DIMENSION> for BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION> {
+    fn grow(&mut self, side: BoxDirection<DIMENSION>, count: usize) {
+        let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::new(grown_space(self.coord_space(), side, count)).build();
+
+        for pt in self.coord_space().iter() {
+            maze.set(shift(pt, side, count), self.get(pt));
+        }
+
+        carry_over_points(self, &mut maze, |pt| Some(shift(pt, side, count)));
+
+        *self = maze;
+    }
+}
+
+/// Return `space` with `count` more positions along the axis of `side`.
+fn grown_space<const DIMENSION: usize>(space: &BoxCoordinateSpace<DIMENSION>, side: BoxDirection<DIMENSION>, count: usize) -> BoxCoordinateSpace<DIMENSION> {
+    let mut dimensions = space.dimensions();
+
+    dimensions[side.axis()] = dimensions[side.axis()].checked_add(count).expect("The grown maze is too large");
+
+    return space.resize(dimensions)
+}
+
+/// Return where `pt` ends up once its maze has grown by `count` on `side`.
+fn shift<const DIMENSION: usize>(pt: CoordinateTuplet<DIMENSION>, side: BoxDirection<DIMENSION>, count: usize) -> CoordinateTuplet<DIMENSION> {
+    if side.is_positive() { pt } else { pt.at(side.axis(), pt[side.axis()] + count) }
+}
\ No newline at end of file
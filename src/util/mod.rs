@@ -1,12 +1,49 @@
 //! A variety of basic helper functions provided for your convenience.
 
-use crate::interface::cell::CellValue;
+#[cfg(feature = "rayon")]
+use rayon::iter::ParallelIterator;
+
+use crate::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+#[cfg(feature = "rayon")]
+use crate::implm::point::boxy::BoxCoordinateSpace;
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellID, CellValue};
 use crate::interface::coordinate::MazeCoordinator;
 use crate::interface::point::CoordinateSpace;
 
+pub use self::border::{apply_border, Border, BorderBuilder, BorderKind};
+pub use self::braid::{braid, BraidPolicy};
+pub use self::components::connect_components;
+pub use self::crop::{crop, BoxCrop};
+pub use self::endpoints::set_longest_path_endpoints;
+pub use self::fingerprint::fingerprint;
+pub use self::grow::{grow, BoxGrow};
+#[cfg(any(feature = "petgraph", doc))]
+pub use self::petgraph::to_petgraph;
+pub use self::placement::{dead_ends_by_depth, farthest_from, pockets};
+pub use self::slice::{slice, BoxSlice};
 pub use self::solid_border::apply_solid_border;
+pub use self::stamp::{stamp, BoxStamp, StampMerge};
+pub use self::transform::{mirror, rotate90, rotate180, rotate270, BoxTransform};
+pub use self::unreachable::fill_unreachable_regions;
+pub use self::wavefront::wavefronts;
 
+mod border;
+mod braid;
+mod components;
+mod crop;
+mod endpoints;
+mod fingerprint;
+mod grow;
+mod petgraph;
+mod placement;
+mod slice;
 mod solid_border;
+mod stamp;
+mod transform;
+mod unreachable;
+mod wavefront;
 
 /// Convert all unvisited *points* (not cells) in a maze into wall cells.
 ///
@@ -21,4 +58,106 @@ pub fn convert_unvisited_points_to_walls(maze: &mut impl MazeCoordinator) {
             maze.make_wall(pt)
         }
     }
+}
+
+/// Convert all unvisited *cells* in a block cell maze into wall cells.
+///
+/// Unlike [`convert_unvisited_points_to_walls()`], this also covers the cells that don't belong to
+/// any point, such as padding and the cells between points.
+///
+/// ```text
+/// for cell in maze:
+///     if cell is unvisited:
+///         cell = wall
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::buffer::MazeBuffer;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::convert_unvisited_cells_to_walls;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 3])).build();
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+///
+/// convert_unvisited_cells_to_walls(&mut maze);
+///
+/// assert!(maze.buffer().iter_cells().all(|(_, value)| value.cell_type != BlockCellPrimaryValue::UNVISITED));
+/// assert!(maze.is_passage_between([0, 0].into(), [1, 0].into()));
+/// ```
+pub fn convert_unvisited_cells_to_walls<Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize>(maze: &mut BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>) {
+    let buffer = maze.buffer_mut();
+
+    for cell in (0..usize::from(buffer.cell_count())).map(CellID) {
+        if buffer.get(cell).cell_type == BlockCellPrimaryValue::UNVISITED {
+            buffer.get_mut(cell).cell_type = BlockCellPrimaryValue::WALL;
+        }
+    }
+}
+
+/// Return the number of points in a maze that are not [fully visited][CellValue::is_fully_visited].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::count_unvisited;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 3])).build();
+///
+/// assert_eq!(9, count_unvisited(&maze));
+///
+/// maze.make_wall([1, 1].into());
+///
+/// assert_eq!(8, count_unvisited(&maze));
+/// ```
+#[must_use]
+pub fn count_unvisited(maze: &impl MazeCoordinator) -> usize {
+    maze.iter().filter(|(_, value)| value.is_fully_visited() == false).count()
+}
+
+/// Like [`convert_unvisited_points_to_walls()`], but finds the unvisited points of a box-space maze
+/// on every core.
+///
+/// Only finding the points is done in parallel. They are converted to walls one at a time
+/// afterwards, so this is fastest for mazes that are mostly visited.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// # use mazelib::interface::point::CoordinateSpace;
+/// use mazelib::util::par_convert_unvisited_points_to_walls;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([5, 5])).build();
+/// maze.make_passage([2, 2].into());
+///
+/// par_convert_unvisited_points_to_walls(&mut maze);
+///
+/// assert!(maze.coord_space().iter().all(|pt| maze.get(pt).cell_type != BlockCellPrimaryValue::UNVISITED));
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_convert_unvisited_points_to_walls<const DIMENSION: usize>(maze: &mut (impl MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>> + Sync)) {
+    let unvisited: Vec<_> = {
+        let maze = &*maze;
+
+        maze.coord_space().par_iter().filter(|pt| maze.get(*pt).is_fully_visited() == false).collect()
+    };
+
+    for pt in unvisited {
+        maze.make_wall(pt)
+    }
 }
\ No newline at end of file
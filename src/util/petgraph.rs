@@ -0,0 +1,56 @@
+#![cfg(any(feature = "petgraph", doc))]
+
+use std::collections::HashMap;
+
+use petgraph::graph::UnGraph;
+
+use crate::interface::cell::ConnectionType;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Convert a maze into a [`petgraph`] graph of its points and the passages between them, so that
+/// petgraph's graph algorithms can be run on it.
+///
+/// Every point of the maze becomes a node, weighted with the point itself. Nodes are added in the
+/// order of [`CoordinateSpace::iter()`], so the index of a point's node is its position in that
+/// order. Two nodes are joined by an edge if there is a passage between their points in either
+/// direction.
+///
+/// Requires the `petgraph` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use rand::SeedableRng;
+/// # use rand_chacha::ChaCha8Rng;
+/// # use mazelib::implm::generate::HuntAndKillGenerator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::implm::presets::Inline2DMazeBuilder;
+/// # use mazelib::interface::generate::MazeGenerator;
+/// use petgraph::algo::connected_components;
+/// use mazelib::util::to_petgraph;
+///
+/// let mut maze = Inline2DMazeBuilder::new(BoxCoordinateSpace::new_checked([8, 8])).build();
+/// HuntAndKillGenerator::new().generate_with_rng(&mut maze, &mut ChaCha8Rng::seed_from_u64(1));
+///
+/// let graph = to_petgraph(&maze);
+///
+/// // A perfect maze is a spanning tree of its points
+/// assert_eq!(64, graph.node_count());
+/// assert_eq!(63, graph.edge_count());
+/// assert_eq!(1, connected_components(&graph));
+/// ```
+#[must_use]
+pub fn to_petgraph<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M) -> UnGraph<Space::PtType, ()> {
+    let mut graph = UnGraph::with_capacity(usize::from(maze.coord_space().logical_size()), 0);
+
+    let nodes: HashMap<_, _> = maze.coord_space().iter().map(|pt| (pt, graph.add_node(pt))).collect();
+
+    for (from, to, connection) in maze.connections() {
+        if connection == ConnectionType::PASSAGE && graph.find_edge(nodes[&from], nodes[&to]).is_none() {
+            graph.add_edge(nodes[&from], nodes[&to], ());
+        }
+    }
+
+    return graph
+}
\ No newline at end of file
@@ -0,0 +1,195 @@
+use std::cmp::Reverse;
+use std::collections::{HashSet, VecDeque};
+
+use crate::interface::cell::{CellValue, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+use crate::internal::util::exit_count;
+use crate::util::wavefronts;
+
+/// Return every dead end of a maze along with its depth, deepest first.
+///
+/// A dead end is a passage point with a passage to exactly one of its neighbours. Its depth is the
+/// number of steps back along its corridor to the nearest junction (a point with passages to three
+/// or more neighbours), or to the other end of the corridor if it doesn't lead to one. Deep dead
+/// ends are out of the way, and take a long detour to reach and come back from, which makes them
+/// good places for treasure. Dead ends of the same depth are in iteration order.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::dead_ends_by_depth;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([4, 2])).build();
+///
+/// // A T-junction at [1, 0], with arms of length 1, 2, and 1
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+/// maze.make_passage_between([1, 0].into(), [2, 0].into());
+/// maze.make_passage_between([2, 0].into(), [3, 0].into());
+/// maze.make_passage_between([1, 0].into(), [1, 1].into());
+///
+/// assert_eq!(vec![
+///     (CoordinateTuplet([3, 0]), 2),
+///     (CoordinateTuplet([0, 0]), 1),
+///     (CoordinateTuplet([1, 1]), 1),
+/// ], dead_ends_by_depth(&maze));
+/// ```
+///
+/// # See Also
+///
+/// * [`pockets()`] --- for the whole corridor leading to each dead end.
+#[must_use]
+pub fn dead_ends_by_depth<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M) -> Vec<(Space::PtType, usize)> {
+    let mut dead_ends: Vec<_> = passage_points(maze)
+        .filter(|pt| exit_count(maze, *pt) == 1)
+        .map(|dead_end| {
+            let mut previous = dead_end;
+            let mut current = dead_end;
+            let mut depth = 0;
+
+            // Walk back along the corridor until it branches or ends
+            loop {
+                let exits: Vec<_> = maze.neighbours_of_pt(current).into_iter()
+                    .filter(|neighbour| *neighbour != previous && maze.is_passage_between(current, *neighbour))
+                    .collect();
+
+                if exits.len() != 1 {
+                    break
+                }
+
+                previous = current;
+                current = exits[0];
+                depth += 1;
+            }
+
+            (dead_end, depth)
+        })
+        .collect();
+
+    dead_ends.sort_by_key(|(_, depth)| Reverse(*depth));
+
+    return dead_ends
+}
+
+/// Return every point that can be reached from `origin` along with its distance from it, farthest
+/// first.
+///
+/// Distances are the number of steps along the shortest path. The points farthest from the start
+/// take the longest to reach, which makes them good places for the goal, keys, or the best
+/// treasure. Points at the same distance are in the order a breadth-first search finds them.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::farthest_from;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 1])).build();
+///
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+/// maze.make_passage_between([1, 0].into(), [2, 0].into());
+///
+/// assert_eq!((CoordinateTuplet([2, 0]), 2), farthest_from(&maze, [0, 0].into())[0]);
+/// ```
+#[must_use]
+pub fn farthest_from<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M, origin: Space::PtType) -> Vec<(Space::PtType, usize)> {
+    wavefronts(maze, origin).into_iter()
+        .enumerate()
+        .rev()
+        .flat_map(|(distance, front)| front.into_iter().map(move |pt| (pt, distance)))
+        .collect()
+}
+
+/// Return every junction-free pocket of a maze, largest first.
+///
+/// A pocket is a group of connected passage points, none of which are junctions (points with
+/// passages to three or more neighbours), that can only be entered from at most one junction.
+/// Usually this is the corridor leading to a dead end, but a region of the maze without any
+/// junctions at all is a pocket too. Everything in a pocket is off the beaten track, as the only
+/// reason to go in is to come back out again.
+///
+/// Each pocket starts with its dead end (if it has one), and then follows the corridor back
+/// towards its entrance. Pockets of the same size are in iteration order of their first points.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::pockets;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([4, 2])).build();
+///
+/// // A T-junction at [1, 0], with arms of length 1, 2, and 1
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+/// maze.make_passage_between([1, 0].into(), [2, 0].into());
+/// maze.make_passage_between([2, 0].into(), [3, 0].into());
+/// maze.make_passage_between([1, 0].into(), [1, 1].into());
+///
+/// let pockets = pockets(&maze);
+///
+/// assert_eq!(3, pockets.len());
+/// assert_eq!(vec![CoordinateTuplet([3, 0]), CoordinateTuplet([2, 0])], pockets[0]);
+/// ```
+#[must_use]
+pub fn pockets<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M) -> Vec<Vec<Space::PtType>> {
+    let is_junction = |pt: Space::PtType| exit_count(maze, pt) >= 3;
+
+    // Start from the dead ends, so that each pocket is listed from its dead end outwards
+    let (dead_ends, others): (Vec<_>, Vec<_>) = passage_points(maze)
+        .filter(|pt| is_junction(*pt) == false)
+        .partition(|pt| exit_count(maze, *pt) <= 1);
+
+    let mut seen = HashSet::new();
+    let mut pockets = Vec::new();
+
+    for origin in dead_ends.into_iter().chain(others) {
+        if seen.insert(origin) == false {
+            continue
+        }
+
+        let mut pocket = vec![origin];
+        let mut entrances = HashSet::new();
+        let mut queue = VecDeque::from([origin]);
+
+        while let Some(pt) = queue.pop_front() {
+            for neighbour in maze.neighbours_of_pt(pt) {
+                if maze.is_passage_between(pt, neighbour) == false {
+                    continue
+                }
+
+                if is_junction(neighbour) {
+                    entrances.insert(neighbour);
+                } else if seen.insert(neighbour) {
+                    pocket.push(neighbour);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        if entrances.len() <= 1 {
+            pockets.push(pocket);
+        }
+    }
+
+    pockets.sort_by_key(|pocket| Reverse(pocket.len()));
+
+    return pockets
+}
+
+/// Return every point of a maze that is a passage.
+fn passage_points<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M) -> impl Iterator<Item = Space::PtType> + '_ {
+    maze.iter().filter(|(_, value)| value.state() == ConnectionType::PASSAGE).map(|(pt, _)| pt)
+}
\ No newline at end of file
@@ -0,0 +1,152 @@
+use crate::implm::buffer::VecBuffer;
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Copy a lower-dimensional slice of a box maze out into a new maze, such as a single 3D "floor"
+/// of a 4D maze.
+///
+/// The slice is spanned by `axes`, and passes through `at`. Every other axis is fixed at `at`'s
+/// coordinate along it (`at`'s coordinates along `axes` are ignored). The new maze's axes are
+/// `axes`, in the order given, so slices can be reordered too. Every point carries over its cells
+/// exactly, except that passages along the fixed axes are lost.
+///
+/// [Named points][crate::mark::NamedPoints] within the slice carry over. Annotations and portals
+/// don't.
+///
+/// This is mostly useful for looking at mazes with more dimensions than any exporter can draw.
+/// See [`BoxSliceMazeExporter`][crate::implm::export::slice::BoxSliceMazeExporter], which slices
+/// mazes as it exports them.
+///
+/// # Panics
+///
+/// If `axes` has any repeated axes or axes the maze doesn't have, or if `at` does not lie within
+/// the maze.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::slice;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<4>>, 4>::new(BoxCoordinateSpace::new_checked([3, 3, 3, 3])).build();
+///
+/// maze.make_passage_between([0, 1, 2, 2].into(), [1, 1, 2, 2].into());
+///
+/// // The plane z = 2, w = 2
+/// let plane = slice(&maze, [0, 1], [0, 0, 2, 2].into());
+///
+/// assert_eq!([3, 3], plane.coord_space().dimensions().map(usize::from));
+/// assert!(plane.is_passage_between([0, 1].into(), [1, 1].into()));
+/// ```
+#[must_use]
+pub fn slice<M: BoxSlice<DIMENSION, SLICE_DIMENSION>, const DIMENSION: usize, const SLICE_DIMENSION: usize>(maze: &M, axes: [usize; SLICE_DIMENSION], at: CoordinateTuplet<DIMENSION>) -> M::Slice {
+    assert!(axes.iter().all(|&axis| axis < DIMENSION), "Every axis must be an axis of the maze");
+    assert!((0..SLICE_DIMENSION).all(|i| axes[..i].contains(&axes[i]) == false), "The axes must be distinct");
+    assert!((0..DIMENSION).all(|axis| at[axis] < usize::from(maze.coord_space()[axis])), "The slice must pass through a point within the maze");
+
+    maze.slice(axes, at)
+}
+
+/// Box mazes that lower-dimensional [slices][slice()] can be taken of.
+///
+/// This is implemented for the box-space coordinators with
+/// [block cells][BoxSpaceBlockCellMazeCoordinator] and
+/// [inline cells][BoxSpaceInlineCellMazeCoordinator].
+pub trait BoxSlice<const DIMENSION: usize, const SLICE_DIMENSION: usize>: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>> {
+    /// The type of maze the slices are.
+    type Slice: MazeCoordinator<CoordSpace = BoxCoordinateSpace<SLICE_DIMENSION>>;
+
+    /// *See [`slice()`].*
+    ///
+    /// The axes may be assumed to be valid, and `at` to lie within the maze.
+    #[must_use]
+    fn slice(&self, axes: [usize; SLICE_DIMENSION], at: CoordinateTuplet<DIMENSION>) -> Self::Slice;
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize, const SLICE_DIMENSION: usize> BoxSlice<DIMENSION, SLICE_DIMENSION> for BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
+    type Slice = BoxSpaceBlockCellMazeCoordinator<VecBuffer<BlockCellValue>, SLICE_DIMENSION>;
+
+    fn slice(&self, axes: [usize; SLICE_DIMENSION], at: CoordinateTuplet<DIMENSION>) -> Self::Slice {
+        let scale_factors = self.scale_factors();
+        let padding = self.padding();
+
+        let mut builder = BoxSpaceBlockCellMazeCoordinator::builder(self.coord_space().project(axes))
+            .scale_factors(axes.map(|axis| scale_factors[axis]))
+            .padding(axes.map(|axis| padding[axis]))
+            .diagonals_may_cross(self.diagonals_may_cross());
+
+        for (slice_axis, axis) in axes.into_iter().enumerate() {
+            builder = builder.spacing(slice_axis, self.spacing(axis));
+        }
+
+        let mut maze = builder.build();
+
+        // The cell of `at`, which fixes the cell coordinates along the other axes
+        let at_cell = self.map_pt_to_cell_loc(at).0;
+
+        for cell in BoxCoordinateSpace::new(maze.get_full_dimensions()).iter() {
+            *maze.get_cell_value_mut(BlockCellLocation(cell)) = self.get_cell_value(BlockCellLocation(to_parent(cell, axes, at_cell)));
+        }
+
+        carry_over_named_points(self, &mut maze, axes, at);
+
+        return maze
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize, const SLICE_DIMENSION: usize> BoxSlice<DIMENSION, SLICE_DIMENSION> for BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION> {
+    type Slice = BoxSpaceInlineCellMazeCoordinator<VecBuffer<InlineCellValue<SLICE_DIMENSION>>, SLICE_DIMENSION>;
+
+    fn slice(&self, axes: [usize; SLICE_DIMENSION], at: CoordinateTuplet<DIMENSION>) -> Self::Slice {
+        let space = self.coord_space().project(axes);
+
+        let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::new(space).build();
+
+        for pt in space.iter() {
+            let value = self.get(to_parent(pt, axes, at));
+
+            maze.set(pt, InlineCellValue {
+                edges: axes.map(|axis| value.edges[axis]),
+                marked: value.marked,
+                tunnel: value.tunnel.and_then(|tunnel| axes.iter().position(|&axis| axis == tunnel)),
+                terrain: value.terrain,
+            });
+        }
+
+        carry_over_named_points(self, &mut maze, axes, at);
+
+        return maze
+    }
+}
+
+/// Return the position in the parent maze of `pt` in the slice spanned by `axes` through `at`.
+fn to_parent<const DIMENSION: usize, const SLICE_DIMENSION: usize>(pt: CoordinateTuplet<SLICE_DIMENSION>, axes: [usize; SLICE_DIMENSION], at: CoordinateTuplet<DIMENSION>) -> CoordinateTuplet<DIMENSION> {
+    let mut parent = at;
+
+    for (slice_axis, axis) in axes.into_iter().enumerate() {
+        parent[axis] = pt[slice_axis];
+    }
+
+    return parent
+}
+
+/// Copy the named points of `from` that lie within the slice over to `to`.
+fn carry_over_named_points<const DIMENSION: usize, const SLICE_DIMENSION: usize>(from: &impl MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, to: &mut impl MazeCoordinator<CoordSpace = BoxCoordinateSpace<SLICE_DIMENSION>>, axes: [usize; SLICE_DIMENSION], at: CoordinateTuplet<DIMENSION>) {
+    for (name, pt) in from.named_points().iter() {
+        let in_slice = (0..DIMENSION).all(|axis| axes.contains(&axis) || pt[axis] == at[axis]);
+
+        if in_slice {
+            to.named_points_mut().set(name, axes.map(|axis| pt[axis]).into());
+        }
+    }
+}
\ No newline at end of file
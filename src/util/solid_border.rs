@@ -1,5 +1,5 @@
 use crate::implm::cell::block::{BlockCellValue, BlockCellPrimaryValue};
-use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::cell::inline::InlineCellValue;
 use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
 use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
 use crate::implm::point::boxy::BoxCoordinateSpace;
@@ -41,22 +41,31 @@ impl <M: MazeCoordinator<CoordSpace=BoxCoordinateSpace<DIMENSION>>, const DIMENS
 // or scaling effects.
 impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> SolidBorder<BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>> for FixSpecialisationPls {
     fn apply(maze: &mut BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION>) {
-        // TODO this can probably be optimised even further
-        //  and rewritten to not be ugly
+        let full_dimensions = maze.get_full_dimensions().map(usize::from);
+        let row_length = full_dimensions[0];
+
+        // Walk the start of every row along the first axis. Rows on the outside of the other axes
+        // are entirely border, so they can be set in bulk. All other rows only have a border cell
+        // at either end.
         let mut cell = [0usize; DIMENSION];
 
         'outer: loop {
-            for (i, dim) in cell.iter().enumerate() {
-                if *dim == 0 || *dim == usize::from(maze.get_full_dimensions()[i]) - 1 {
-                    maze.set_cell_value_type(cell.into(), BlockCellPrimaryValue::BOUNDARY);
-                    break
-                }
+            let is_outer_row = (1..DIMENSION).any(|i| cell[i] == 0 || cell[i] == full_dimensions[i] - 1);
+
+            if is_outer_row {
+                maze.set_cell_value_types_along(cell.into(), row_length, BlockCellPrimaryValue::BOUNDARY);
+            } else {
+                maze.set_cell_value_type(cell.into(), BlockCellPrimaryValue::BOUNDARY);
+
+                let mut end = cell;
+                end[0] = row_length - 1;
+                maze.set_cell_value_type(end.into(), BlockCellPrimaryValue::BOUNDARY);
             }
 
-            for i in 0..DIMENSION {
+            for i in 1..DIMENSION {
                 cell[i] += 1;
 
-                if cell[i] != usize::from(maze.get_full_dimensions()[i]) {
+                if cell[i] != full_dimensions[i] {
                     continue 'outer
                 } else {
                     cell[i] = 0;
@@ -68,45 +77,12 @@ impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> SolidBorder<Bo
     }
 }
 
+// Closing each side in turn preserves the existing edges of the border points
 impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> SolidBorder<BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>> for FixSpecialisationPls {
     fn apply(maze: &mut BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION>) {
-        // TODO this can probably be optimised even further
-        //  and rewritten to not be ugly
-
-        // TODO preserve existing walls on the edge (dont overwrite them)
-        let mut cell = [0usize; DIMENSION];
-
-        'outer: loop {
-            let mut walls = [[InlineCellValueEdge::UNVISITED; 2]; DIMENSION];
-            let mut on_boundary = false;
-
-            for (i, dim) in cell.iter().enumerate() {
-                if dim == &0 {
-                    walls[i][0] = InlineCellValueEdge::BOUNDARY;
-                    on_boundary = true;
-                }
-
-                if *dim == usize::from(maze.coord_space().dimensions()[i]) - 1 {
-                    walls[i][1] = InlineCellValueEdge::BOUNDARY;
-                    on_boundary = true;
-                }
-            }
-
-            if on_boundary {
-                maze.set(cell.into(), InlineCellValue { edges: walls, marked: false });
-            }
-
-            for i in 0..DIMENSION {
-                cell[i] += 1;
-
-                if cell[i] != usize::from(maze.coord_space().dimensions()[i]) {
-                    continue 'outer
-                } else {
-                    cell[i] = 0;
-                }
-            }
-
-            break 'outer
+        for axis in 0..DIMENSION {
+            maze.close_side(axis, false);
+            maze.close_side(axis, true);
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,201 @@
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue};
+use crate::implm::cell::inline::{InlineCellValue, InlineCellValueEdge};
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinator;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::cell::{CellValue, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Copy a smaller box maze into a larger one, with the smaller maze's origin placed at `offset`.
+///
+/// This is useful for placing prefabricated rooms and hand-authored set pieces inside a maze. The
+/// points of `src` replace the points they land on exactly. Along the outside of `src` (its
+/// padding for [block cells][BoxSpaceBlockCellMazeCoordinator], its outer edges for
+/// [inline cells][BoxSpaceInlineCellMazeCoordinator]), the stamp overlaps the cells around it.
+/// These are merged with `dest` as per `merge`. Any of the stamp's padding that falls outside
+/// `dest` is dropped.
+///
+/// For block cells, the stamp's scale factors and spacing must match `dest`'s where it is placed.
+///
+/// # Panics
+///
+/// If `src` does not fit within `dest` at `offset`.
+///
+/// If `src` has block cells and its scale factors or spacing do not match `dest`'s.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::BlockCellValue;
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::{stamp, StampMerge};
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([10, 10])).build();
+///
+/// let mut room = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([2, 2])).build();
+/// room.make_passage_between([0, 0].into(), [1, 0].into());
+///
+/// stamp(&mut maze, &room, [4, 4].into(), StampMerge::Overwrite);
+///
+/// assert!(maze.is_passage_between([4, 4].into(), [5, 4].into()));
+/// ```
+pub fn stamp<M: BoxStamp<Source, DIMENSION>, Source: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(dest: &mut M, src: &Source, offset: CoordinateTuplet<DIMENSION>, merge: StampMerge) {
+    for axis in 0..DIMENSION {
+        assert!(offset[axis] + usize::from(src.coord_space()[axis]) <= usize::from(dest.coord_space()[axis]), "The stamp does not fit within the maze");
+    }
+
+    dest.stamp(src, offset, merge)
+}
+
+/// How the outside of a [stamp][stamp()] is merged with the cells it overlaps.
+///
+/// The inside of a stamp always replaces what was there.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub enum StampMerge {
+    /// The stamp's cells replace the maze's.
+    #[default]
+    Overwrite,
+
+    /// The maze's cells are kept.
+    Keep,
+
+    /// Whichever cell is more open is kept. Passages are preferred over walls, and walls over
+    /// boundaries. Unvisited cells never replace visited ones.
+    Open,
+
+    /// Whichever cell is more closed is kept. Boundaries are preferred over walls, and walls over
+    /// passages. Unvisited cells never replace visited ones.
+    Closed,
+}
+
+impl StampMerge {
+    /// Whether the stamp's cell should replace the maze's.
+    fn stamp_wins(self, existing: ConnectionType, stamped: ConnectionType) -> bool {
+        let openness = |connection| match connection {
+            ConnectionType::PASSAGE   => 2,
+            ConnectionType::WALL      => 1,
+            ConnectionType::BOUNDARY  => 0,
+            ConnectionType::UNVISITED => unreachable!(),
+        };
+
+        return match (self, existing, stamped) {
+            (StampMerge::Overwrite, _, _) => true,
+            (StampMerge::Keep, _, _) => false,
+            (_, _, ConnectionType::UNVISITED) => false,
+            (_, ConnectionType::UNVISITED, _) => true,
+            (StampMerge::Open, _, _) => openness(stamped) > openness(existing),
+            (StampMerge::Closed, _, _) => openness(stamped) < openness(existing),
+        }
+    }
+}
+
+/// Box mazes that other box mazes can be [stamped][stamp()] into.
+///
+/// This is implemented for the box-space coordinators with
+/// [block cells][BoxSpaceBlockCellMazeCoordinator] and
+/// [inline cells][BoxSpaceInlineCellMazeCoordinator], for stamps with the same kind of cells.
+pub trait BoxStamp<Source, const DIMENSION: usize>: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>> {
+    /// *See [`stamp()`].*
+    ///
+    /// The stamp may be assumed to fit within the maze.
+    fn stamp(&mut self, src: &Source, offset: CoordinateTuplet<DIMENSION>, merge: StampMerge);
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, SourceBuffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxStamp<BoxSpaceBlockCellMazeCoordinator<SourceBuffer, DIMENSION>, DIMENSION> for BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
+    fn stamp(&mut self, src: &BoxSpaceBlockCellMazeCoordinator<SourceBuffer, DIMENSION>, offset: CoordinateTuplet<DIMENSION>, merge: StampMerge) {
+        assert_eq!(self.scale_factors(), src.scale_factors(), "The stamp's scale factors must match the maze's");
+
+        let src_dimensions = src.coord_space().dimensions().map(usize::from);
+
+        for axis in 0..DIMENSION {
+            let first = offset[axis];
+            let last = first + src_dimensions[axis] - 1;
+
+            assert!(self.spacing(axis)[first..last] == src.spacing(axis)[..], "The stamp's spacing must match the maze's where it is placed");
+        }
+
+        let src_padding = src.padding();
+        let src_full_dimensions = src.get_full_dimensions().map(usize::from);
+        let full_dimensions = self.get_full_dimensions().map(usize::from);
+
+        // The cell of the stamp's origin, in this maze
+        let origin_cell = self.map_pt_to_cell_loc(offset);
+
+        'cells: for cell in BoxCoordinateSpace::new(src.get_full_dimensions()).iter() {
+            let mut target = [0; DIMENSION];
+            let mut is_padding = false;
+
+            for axis in 0..DIMENSION {
+                match (origin_cell[axis] + cell[axis]).checked_sub(src_padding[axis][0]) {
+                    Some(coordinate) if coordinate < full_dimensions[axis] => target[axis] = coordinate,
+                    _ => continue 'cells,
+                }
+
+                is_padding |= cell[axis] < src_padding[axis][0] || cell[axis] >= src_full_dimensions[axis] - src_padding[axis][1];
+            }
+
+            let value = src.get_cell_value(BlockCellLocation(cell));
+            let existing = self.get_cell_value_mut(BlockCellLocation(target.into()));
+
+            if is_padding == false || merge.stamp_wins(existing.state(), value.state()) {
+                *existing = value;
+            }
+        }
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, SourceBuffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> BoxStamp<BoxSpaceInlineCellMazeCoordinator<SourceBuffer, DIMENSION>, DIMENSION> for BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION> {
+    fn stamp(&mut self, src: &BoxSpaceInlineCellMazeCoordinator<SourceBuffer, DIMENSION>, offset: CoordinateTuplet<DIMENSION>, merge: StampMerge) {
+        let src_dimensions = src.coord_space().dimensions().map(usize::from);
+
+        for src_pt in src.coord_space().iter() {
+            let pt: CoordinateTuplet<DIMENSION> = std::array::from_fn(|axis| src_pt[axis] + offset[axis]).into();
+
+            let existing = self.get(pt);
+            let mut value = src.get(src_pt);
+
+            for axis in 0..DIMENSION {
+                for side in 0..2 {
+                    let on_outside = if side == 0 { src_pt[axis] == 0 } else { src_pt[axis] == src_dimensions[axis] - 1 };
+
+                    if on_outside == false {
+                        continue
+                    }
+
+                    if merge.stamp_wins(edge_state(existing.edges[axis][side]), edge_state(value.edges[axis][side])) == false {
+                        value.edges[axis][side] = existing.edges[axis][side];
+                        continue
+                    }
+
+                    // The neighbour's edge must match too for the connection to change
+                    let neighbour = if side == 0 { pt[axis].checked_sub(1) } else { Some(pt[axis] + 1) };
+
+                    if let Some(neighbour) = neighbour.filter(|neighbour| *neighbour < usize::from(self.coord_space()[axis])) {
+                        let mut neighbour_pt = pt;
+                        neighbour_pt[axis] = neighbour;
+
+                        let mut neighbour_value = self.get(neighbour_pt);
+                        neighbour_value.edges[axis][1 - side] = value.edges[axis][side];
+                        self.set(neighbour_pt, neighbour_value);
+                    }
+                }
+            }
+
+            self.set(pt, value);
+        }
+    }
+}
+
+fn edge_state(edge: InlineCellValueEdge) -> ConnectionType {
+    match edge {
+        InlineCellValueEdge::PASSAGE   => ConnectionType::PASSAGE,
+        InlineCellValueEdge::WALL      => ConnectionType::WALL,
+        InlineCellValueEdge::BOUNDARY  => ConnectionType::BOUNDARY,
+        InlineCellValueEdge::UNVISITED => ConnectionType::UNVISITED,
+    }
+}
\ No newline at end of file
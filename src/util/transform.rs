@@ -0,0 +1,265 @@
+use crate::implm::cell::block::{BlockCellLocation, BlockCellValue, VerticalConnector};
+use crate::implm::cell::inline::InlineCellValue;
+use crate::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+use crate::implm::coordinate::inline::{BoxSpaceInlineCellMazeCoordinator, BoxSpaceInlineCellMazeCoordinatorBuilder};
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::buffer::MazeBuffer;
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Return a copy of a box maze rotated a quarter turn clockwise.
+///
+/// The maze is rotated in the plane of the first two axes, with the x-axis pointing right and the
+/// y-axis pointing down (as in the text exporters). All other axes are left as they are. The
+/// scale factors, spacing, and padding rotate with the maze.
+///
+/// # Panics
+///
+/// If the maze has fewer than two dimensions.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// # use mazelib::interface::point::CoordinateSpace;
+/// use mazelib::util::rotate90;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 2])).build();
+/// maze.make_passage([0, 0].into());
+///
+/// let rotated = rotate90(&maze);
+///
+/// assert_eq!([2, 3], rotated.coord_space().dimensions().map(usize::from));
+/// assert_eq!(BlockCellPrimaryValue::PASSAGE, rotated.get([1, 0].into()).cell_type);
+/// ```
+#[must_use]
+pub fn rotate90<M: BoxTransform<DIMENSION>, const DIMENSION: usize>(maze: &M) -> M {
+    assert!(DIMENSION >= 2, "Only mazes with at least two dimensions can be rotated");
+
+    let mut axes: [usize; DIMENSION] = std::array::from_fn(|axis| axis);
+    axes.swap(0, 1);
+
+    let mut flipped = [false; DIMENSION];
+    flipped[0] = true;
+
+    maze.transform(axes, flipped)
+}
+
+/// Return a copy of a box maze rotated a half turn.
+///
+/// *See [`rotate90()`].*
+///
+/// # Panics
+///
+/// If the maze has fewer than two dimensions.
+#[must_use]
+pub fn rotate180<M: BoxTransform<DIMENSION>, const DIMENSION: usize>(maze: &M) -> M {
+    assert!(DIMENSION >= 2, "Only mazes with at least two dimensions can be rotated");
+
+    let mut flipped = [false; DIMENSION];
+    flipped[0] = true;
+    flipped[1] = true;
+
+    maze.transform(std::array::from_fn(|axis| axis), flipped)
+}
+
+/// Return a copy of a box maze rotated a quarter turn anticlockwise.
+///
+/// *See [`rotate90()`].*
+///
+/// # Panics
+///
+/// If the maze has fewer than two dimensions.
+#[must_use]
+pub fn rotate270<M: BoxTransform<DIMENSION>, const DIMENSION: usize>(maze: &M) -> M {
+    assert!(DIMENSION >= 2, "Only mazes with at least two dimensions can be rotated");
+
+    let mut axes: [usize; DIMENSION] = std::array::from_fn(|axis| axis);
+    axes.swap(0, 1);
+
+    let mut flipped = [false; DIMENSION];
+    flipped[1] = true;
+
+    maze.transform(axes, flipped)
+}
+
+/// Return a copy of a box maze reflected along `axis`.
+///
+/// The first point along the axis becomes the last, and vice versa. The spacing and padding along
+/// the axis are reversed to match.
+///
+/// # Panics
+///
+/// If `axis` is not less than `DIMENSION`.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::block::{BlockCellPrimaryValue, BlockCellValue};
+/// # use mazelib::implm::coordinate::block::BoxSpaceBlockCellMazeCoordinator;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::mirror;
+///
+/// let mut maze = BoxSpaceBlockCellMazeCoordinator::<VecBuffer<BlockCellValue>, 2>::builder(BoxCoordinateSpace::new_checked([3, 2]))
+///     .padding([[1, 3], [1, 1]])
+///     .build();
+/// maze.make_passage([0, 1].into());
+///
+/// let mirrored = mirror(&maze, 0);
+///
+/// assert_eq!([[3, 1], [1, 1]], mirrored.padding());
+/// assert_eq!(BlockCellPrimaryValue::PASSAGE, mirrored.get([2, 1].into()).cell_type);
+/// ```
+#[must_use]
+pub fn mirror<M: BoxTransform<DIMENSION>, const DIMENSION: usize>(maze: &M, axis: usize) -> M {
+    assert!(axis < DIMENSION, "The axis must be less than the dimension");
+
+    let mut flipped = [false; DIMENSION];
+    flipped[axis] = true;
+
+    maze.transform(std::array::from_fn(|axis| axis), flipped)
+}
+
+/// Box mazes that can be rotated and mirrored.
+///
+/// This is implemented for the box-space coordinators with
+/// [block cells][BoxSpaceBlockCellMazeCoordinator] and
+/// [inline cells][BoxSpaceInlineCellMazeCoordinator]. You will usually want to use
+/// [`rotate90()`], [`rotate180()`], [`rotate270()`], or [`mirror()`] rather than this directly.
+pub trait BoxTransform<const DIMENSION: usize>: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>> + Sized {
+    /// Return a copy of this maze with its axes reordered and reflected.
+    ///
+    /// Every rotation and reflection of a box is a combination of reordering its axes and
+    /// reflecting some of them.
+    ///
+    /// [Vertical connectors][VerticalConnector] are flipped if the third axis is reflected. They
+    /// are left as they are if the third axis is moved elsewhere, as they always run along the
+    /// third axis.
+    ///
+    /// [Named points][crate::mark::NamedPoints], [annotations][crate::mark::Annotations], and
+    /// [portals][crate::mark::Portals] move with their points.
+    ///
+    /// # Parameters
+    ///
+    /// `axes`    --- the axis of this maze that each axis of the new maze corresponds to. Must
+    ///               be a permutation of `0..DIMENSION`.
+    /// `flipped` --- whether each axis of the new maze runs in the opposite direction to its
+    ///               corresponding axis in this maze.
+    ///
+    /// # Panics
+    ///
+    /// If `axes` is not a permutation of `0..DIMENSION`.
+    #[must_use]
+    fn transform(&self, axes: [usize; DIMENSION], flipped: [bool; DIMENSION]) -> Self;
+}
+
+impl <Buffer: MazeBuffer<BlockCellValue>, const DIMENSION: usize> BoxTransform<DIMENSION> for BoxSpaceBlockCellMazeCoordinator<Buffer, DIMENSION> {
+    fn transform(&self, axes: [usize; DIMENSION], flipped: [bool; DIMENSION]) -> Self {
+        assert_permutation(axes);
+
+        let old_padding = self.padding();
+
+        let mut builder = Self::builder(self.coord_space().permute_axes(axes))
+            .scale_factors(axes.map(|axis| self.scale_factors()[axis]))
+            .padding(std::array::from_fn(|i| {
+                let [before, after] = old_padding[axes[i]];
+
+                if flipped[i] { [after, before] } else { [before, after] }
+            }))
+            .diagonals_may_cross(self.diagonals_may_cross());
+
+        for i in 0..DIMENSION {
+            let mut spacing = self.spacing(axes[i]);
+
+            if flipped[i] {
+                spacing.reverse();
+            }
+
+            builder = builder.spacing(i, spacing);
+        }
+
+        let mut maze = builder.build();
+
+        let full_dimensions = self.get_full_dimensions();
+
+        // Whether up and down are swapped
+        let vertical_flipped = DIMENSION >= 3 && (0..DIMENSION).any(|i| axes[i] == 2 && flipped[i]);
+
+        for cell in BoxCoordinateSpace::new(full_dimensions).iter() {
+            let mut value = self.get_cell_value(BlockCellLocation(cell));
+
+            if vertical_flipped {
+                value.connector = match value.connector {
+                    VerticalConnector::UP => VerticalConnector::DOWN,
+                    VerticalConnector::DOWN => VerticalConnector::UP,
+                    connector => connector,
+                };
+            }
+
+            *maze.get_cell_value_mut(BlockCellLocation(transform_position(cell, full_dimensions.map(usize::from), axes, flipped))) = value;
+        }
+
+        let dimensions = self.coord_space().dimensions().map(usize::from);
+        carry_over_points(self, &mut maze, |pt| Some(transform_position(pt, dimensions, axes, flipped)));
+
+        return maze
+    }
+}
+
+impl <Buffer: MazeBuffer<InlineCellValue<DIMENSION>>, const DIMENSION: usize> BoxTransform<DIMENSION> for BoxSpaceInlineCellMazeCoordinator<Buffer, DIMENSION> {
+    fn transform(&self, axes: [usize; DIMENSION], flipped: [bool; DIMENSION]) -> Self {
+        assert_permutation(axes);
+
+        let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::new(self.coord_space().permute_axes(axes)).build();
+
+        let dimensions = self.coord_space().dimensions().map(usize::from);
+
+        for pt in self.coord_space().iter() {
+            let old_value = self.get(pt);
+
+            let value = InlineCellValue {
+                edges: std::array::from_fn(|i| {
+                    let [before, after] = old_value.edges[axes[i]];
+
+                    if flipped[i] { [after, before] } else { [before, after] }
+                }),
+                tunnel: old_value.tunnel.map(|tunnel| axes.iter().position(|axis| *axis == tunnel).expect("axes is a permutation")),
+                ..old_value
+            };
+
+            maze.set(transform_position(pt, dimensions, axes, flipped), value);
+        }
+
+        carry_over_points(self, &mut maze, |pt| Some(transform_position(pt, dimensions, axes, flipped)));
+
+        return maze
+    }
+}
+
+/// Map a position in the old maze to its position in the transformed maze. See
+/// [`BoxTransform::transform()`] for the parameters.
+fn transform_position<const DIMENSION: usize>(position: CoordinateTuplet<DIMENSION>, dimensions: [usize; DIMENSION], axes: [usize; DIMENSION], flipped: [bool; DIMENSION]) -> CoordinateTuplet<DIMENSION> {
+    CoordinateTuplet(std::array::from_fn(|i| {
+        let coordinate = position[axes[i]];
+
+        if flipped[i] { dimensions[axes[i]] - 1 - coordinate } else { coordinate }
+    }))
+}
+
+/// Copy the named points, annotations, and portals of `from` over to `to`, moving each point to
+/// `f(pt)`.
+pub(super) fn carry_over_points<M: MazeCoordinator<CoordSpace = BoxCoordinateSpace<DIMENSION>>, const DIMENSION: usize>(from: &M, to: &mut M, f: impl Fn(CoordinateTuplet<DIMENSION>) -> Option<CoordinateTuplet<DIMENSION>>) {
+    *to.named_points_mut() = from.named_points().map_points(&f);
+    *to.annotations_mut() = from.annotations().map_points(&f);
+    *to.portals_mut() = from.portals().map_points(&f);
+}
+
+fn assert_permutation<const DIMENSION: usize>(axes: [usize; DIMENSION]) {
+    assert!((0..DIMENSION).all(|axis| axes.contains(&axis)), "The axes must be a permutation of 0..DIMENSION");
+}
\ No newline at end of file
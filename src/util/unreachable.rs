@@ -0,0 +1,65 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::interface::cell::{CellValue, ConnectionType};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Make a wall of every point that can't be reached from `origin` by passage, returning the number
+/// of points that were changed.
+///
+/// This closes off any part of a maze that a generator didn't connect, such as isolated pockets
+/// left by a [mask][crate::implm::point::masked] or a partial generation, so that the only open
+/// parts of the maze are those connected to `origin`. Points that are already walls or boundaries
+/// are left untouched.
+///
+/// Points are made into walls with [`MazeCoordinator::make_wall()`].
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::BoxCoordinateSpace;
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::fill_unreachable_regions;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 1])).build();
+///
+/// maze.make_passage_between([0, 0].into(), [1, 0].into());
+///
+/// assert_eq!(1, fill_unreachable_regions(&mut maze, [0, 0].into()));
+///
+/// assert!(maze.is_passage_between([0, 0].into(), [1, 0].into()));
+/// assert!(maze.is_wall_between([1, 0].into(), [2, 0].into()));
+/// ```
+pub fn fill_unreachable_regions<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &mut M, origin: Space::PtType) -> usize {
+    let reachable = reachable_from(maze, origin);
+
+    let unreachable: Vec<_> = maze.iter()
+        .filter(|(pt, value)| reachable.contains(pt) == false && matches!(value.state(), ConnectionType::WALL | ConnectionType::BOUNDARY) == false)
+        .map(|(pt, _)| pt)
+        .collect();
+
+    for pt in unreachable.iter() {
+        maze.make_wall(*pt);
+    }
+
+    return unreachable.len()
+}
+
+/// Return every point reachable from `origin` by passage, including `origin` itself.
+fn reachable_from<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M, origin: Space::PtType) -> HashSet<Space::PtType> {
+    let mut visited = HashSet::from([origin]);
+    let mut queue = VecDeque::from([origin]);
+
+    while let Some(pt) = queue.pop_front() {
+        for neighbour in maze.neighbours_of_pt(pt) {
+            if maze.is_passage_between(pt, neighbour) && visited.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    return visited
+}
\ No newline at end of file
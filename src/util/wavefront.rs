@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use crate::interface::coordinate::MazeCoordinator;
+use crate::interface::point::CoordinateSpace;
+
+/// Flood the maze from `origin`, returning the wavefronts of the flood in order.
+///
+/// This is a breadth-first search. Wavefront `n` holds every point whose shortest path from
+/// `origin` takes `n` steps, so the first wavefront is just `origin` itself. Points that can't be
+/// reached from `origin` aren't in any wavefront. Within each wavefront, points are in the order
+/// the search found them.
+///
+/// Flooding a maze is the simplest way to solve it: the goal is reached in the first wavefront
+/// that contains it.
+///
+/// # Examples
+///
+/// ```
+/// # use mazelib::implm::buffer::VecBuffer;
+/// # use mazelib::implm::cell::inline::InlineCellValue;
+/// # use mazelib::implm::coordinate::inline::BoxSpaceInlineCellMazeCoordinatorBuilder;
+/// # use mazelib::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+/// # use mazelib::interface::coordinate::MazeCoordinator;
+/// use mazelib::util::wavefronts;
+///
+/// let mut maze = BoxSpaceInlineCellMazeCoordinatorBuilder::<VecBuffer<InlineCellValue<2>>, 2>::new(BoxCoordinateSpace::new_checked([3, 2])).build();
+///
+/// maze.make_passage_between([1, 0].into(), [0, 0].into());
+/// maze.make_passage_between([1, 0].into(), [2, 0].into());
+/// maze.make_passage_between([2, 0].into(), [2, 1].into());
+///
+/// let fronts = wavefronts(&maze, [1, 0].into());
+///
+/// assert_eq!(vec![
+///     vec![CoordinateTuplet([1, 0])],
+///     vec![CoordinateTuplet([0, 0]), CoordinateTuplet([2, 0])],
+///     vec![CoordinateTuplet([2, 1])],
+/// ], fronts);
+/// ```
+#[must_use]
+pub fn wavefronts<M: MazeCoordinator<CoordSpace = Space>, Space: CoordinateSpace>(maze: &M, origin: Space::PtType) -> Vec<Vec<Space::PtType>> {
+    let mut visited = HashSet::from([origin]);
+    let mut fronts = vec![vec![origin]];
+
+    loop {
+        let mut next = Vec::new();
+
+        for pt in fronts.last().expect("[Bug] There is always at least one wavefront") {
+            for neighbour in maze.neighbours_of_pt(*pt) {
+                if maze.is_passage_between(*pt, neighbour) && visited.insert(neighbour) {
+                    next.push(neighbour);
+                }
+            }
+        }
+
+        if next.is_empty() {
+            return fronts
+        }
+
+        fronts.push(next);
+    }
+}
\ No newline at end of file
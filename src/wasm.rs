@@ -0,0 +1,169 @@
+//! A [`wasm-bindgen`](https://rustwasm.github.io/docs/wasm-bindgen/) facade for generating,
+//! solving, and drawing mazes in the browser.
+//!
+//! The rest of the library is generic over coordinate spaces, cells, and buffers, none of which
+//! can cross the boundary into JavaScript. [`WasmMaze`] picks the common case --- a 2D maze where
+//! walls take up whole cells, as made by [`quick::generate_2d()`][crate::quick::generate_2d] ---
+//! and exposes it as a JavaScript class.
+//!
+//! ```js
+//! import init, { WasmMaze, Algorithm } from "./pkg/mazelib.js";
+//!
+//! await init();
+//!
+//! const maze = new WasmMaze(20, 15, Algorithm.HuntAndKill, 42);
+//! maze.solve();
+//!
+//! document.getElementById("maze").innerHTML = maze.toSvg(10);
+//! ```
+//!
+//! Build with the `wasm` feature and without the default `thread-rng` feature, as
+//! `wasm32-unknown-unknown` has no operating system to seed an RNG from. Mazes are seeded
+//! explicitly instead, so the same seed draws the same maze on every platform.
+//!
+//! Requires the `wasm` feature.
+#![cfg(any(feature = "wasm", doc))]
+
+use std::fmt::Write;
+
+use rand::SeedableRng;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::implm::cell::block::BlockCellPrimaryValue;
+use crate::implm::point::boxy::{BoxCoordinateSpace, CoordinateTuplet};
+use crate::interface::coordinate::MazeCoordinator;
+use crate::internal::util::shortest_path;
+use crate::path::Path;
+use crate::quick::{Algorithm, BlockMaze2D};
+use crate::rng::PortableRng;
+use crate::util::{apply_solid_border, set_longest_path_endpoints};
+
+/// A generated 2D maze, and its solution once [solved][Self::solve].
+///
+/// *See the [module documentation][self].*
+///
+/// # Examples
+///
+/// ```
+/// use mazelib::quick::Algorithm;
+/// use mazelib::wasm::WasmMaze;
+///
+/// let mut maze = WasmMaze::new(8, 6, Algorithm::RecursiveBacktracker, 7).unwrap();
+///
+/// assert!(maze.solve());
+/// assert!(maze.to_svg(10.0).starts_with("<svg"));
+/// ```
+#[wasm_bindgen]
+pub struct WasmMaze {
+    maze: BlockMaze2D,
+    /// The points from the start to the goal, if the maze has been solved.
+    solution: Option<Vec<CoordinateTuplet<2>>>,
+}
+
+#[wasm_bindgen]
+impl WasmMaze {
+    /// Generate a `width` by `height` maze with a solid border. Its start and goal are the ends of
+    /// its longest path.
+    ///
+    /// The same seed always produces the same maze.
+    /// *See [`quick::generate_2d()`][crate::quick::generate_2d].*
+    ///
+    /// # Errors
+    ///
+    /// If `width` or `height` is zero, or the maze is too large. In JavaScript, the message is
+    /// thrown.
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: usize, height: usize, algorithm: Algorithm, seed: u32) -> Result<WasmMaze, String> {
+        let space = BoxCoordinateSpace::try_new_checked([width, height]).map_err(|err| err.to_string())?;
+
+        let mut maze = BlockMaze2D::builder(space).try_build().map_err(|err| err.to_string())?;
+
+        apply_solid_border(&mut maze);
+
+        algorithm.generate_with_rng(&mut maze, &mut PortableRng::seed_from_u64(u64::from(seed)));
+        set_longest_path_endpoints(&mut maze);
+
+        return Ok(Self { maze, solution: None })
+    }
+
+    /// Return the number of points along the x-axis.
+    #[must_use]
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> usize {
+        usize::from(self.maze.coord_space().dimensions()[0])
+    }
+
+    /// Return the number of points along the y-axis.
+    #[must_use]
+    #[wasm_bindgen(getter)]
+    pub fn height(&self) -> usize {
+        usize::from(self.maze.coord_space().dimensions()[1])
+    }
+
+    /// Find the shortest path from the start to the goal, returning whether there is one.
+    ///
+    /// The solution is drawn by [`to_svg()`][Self::to_svg] from then on.
+    pub fn solve(&mut self) -> bool {
+        self.solution = self.maze.start().zip(self.maze.goal()).and_then(|(start, goal)| shortest_path(&self.maze, start, goal));
+
+        return self.solution.is_some()
+    }
+
+    /// Return the number of points along the solution, including the start and goal, or zero if
+    /// the maze hasn't been solved.
+    #[must_use]
+    #[wasm_bindgen(js_name = solutionLength)]
+    pub fn solution_length(&self) -> usize {
+        self.solution.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Draw the maze as an SVG image, with its solution if it has been [solved][Self::solve].
+    ///
+    /// Each cell is a `cell_size` by `cell_size` square. Walls are drawn as a single `<path>` with
+    /// the class `walls`, and the solution as a `<polyline>` with the class `solution`, so that
+    /// both can be restyled with CSS.
+    #[must_use]
+    #[wasm_bindgen(js_name = toSvg)]
+    pub fn to_svg(&self, cell_size: f64) -> String {
+        let [width, height] = self.maze.get_full_dimensions().map(usize::from);
+
+        let mut svg = String::new();
+
+        // Writing to a String can't fail
+        let _ = writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {} {}" width="{}" height="{}">"#, width as f64 * cell_size, height as f64 * cell_size, width as f64 * cell_size, height as f64 * cell_size);
+
+        let _ = write!(svg, r#"<path class="walls" fill="black" d=""#);
+
+        for y in 0..height {
+            for x in 0..width {
+                let cell_type = self.maze.get_cell_value([x, y].into()).cell_type;
+
+                if cell_type == BlockCellPrimaryValue::WALL || cell_type == BlockCellPrimaryValue::BOUNDARY {
+                    let _ = write!(svg, "M{} {}h{}v{}h{}z", x as f64 * cell_size, y as f64 * cell_size, cell_size, cell_size, -cell_size);
+                }
+            }
+        }
+
+        let _ = writeln!(svg, r#""/>"#);
+
+        if let Some(solution) = &self.solution {
+            let cells = self.maze.map_path_to_cell_path(&Path::from_vec(solution.clone()));
+
+            let _ = write!(svg, r#"<polyline class="solution" fill="none" stroke="red" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" points=""#, cell_size / 2.0);
+
+            for (i, cell_loc) in cells.0[..].iter().enumerate() {
+                if i > 0 {
+                    let _ = write!(svg, " ");
+                }
+
+                let _ = write!(svg, "{},{}", (cell_loc.0[0] as f64 + 0.5) * cell_size, (cell_loc.0[1] as f64 + 0.5) * cell_size);
+            }
+
+            let _ = writeln!(svg, r#""/>"#);
+        }
+
+        let _ = writeln!(svg, "</svg>");
+
+        return svg
+    }
+}